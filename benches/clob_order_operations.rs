@@ -15,7 +15,7 @@ use polymarket_client_sdk::auth::Normal;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::clob::Client;
 use polymarket_client_sdk::clob::types::{OrderType, Side, TickSize};
-use polymarket_client_sdk::types::{Decimal, U256};
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use rust_decimal_macros::dec;
 
 const TOKEN_ID: &str =
@@ -27,7 +27,7 @@ const BENCH_PRIVATE_KEY: &str =
 
 /// Helper to create an authenticated client with cached tick size and fee rate
 async fn setup_client() -> (Client<Authenticated<Normal>>, PrivateKeySigner) {
-    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+    let token_id = TokenId::from_str(TOKEN_ID).expect("valid token ID");
     let signer = PrivateKeySigner::from_str(BENCH_PRIVATE_KEY)
         .expect("valid key")
         .with_chain_id(Some(POLYGON));
@@ -50,7 +50,7 @@ async fn setup_client() -> (Client<Authenticated<Normal>>, PrivateKeySigner) {
 fn bench_order_building(c: &mut Criterion) {
     let runtime = tokio::runtime::Runtime::new().expect("runtime");
     let (client, _) = runtime.block_on(setup_client());
-    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+    let token_id = TokenId::from_str(TOKEN_ID).expect("valid token ID");
 
     let mut group = c.benchmark_group("clob_order_operations/order_building");
 
@@ -93,7 +93,7 @@ fn bench_order_building(c: &mut Criterion) {
 fn bench_order_signing(c: &mut Criterion) {
     let runtime = tokio::runtime::Runtime::new().expect("runtime");
     let (client, signer) = runtime.block_on(setup_client());
-    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+    let token_id = TokenId::from_str(TOKEN_ID).expect("valid token ID");
 
     let mut group = c.benchmark_group("clob_order_operations/order_signing");
 
@@ -128,7 +128,7 @@ fn bench_order_signing(c: &mut Criterion) {
 /// Benchmark order serialization
 fn bench_order_serializing(c: &mut Criterion) {
     let runtime = tokio::runtime::Runtime::new().expect("runtime");
-    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+    let token_id = TokenId::from_str(TOKEN_ID).expect("valid token ID");
 
     let mut group = c.benchmark_group("clob_order_operations/order_serializing");
 
@@ -0,0 +1,140 @@
+/// Benchmarks comparing `serde_json` against `simd-json` on the response bodies that dominate
+/// crawling workloads: large orderbooks and pages of markets.
+///
+/// Each iteration deserializes from a fresh copy of the body, since `simd-json` parses in place.
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use polymarket_client_sdk::clob::types::response::{
+    MarketResponse, OrderBookSummaryResponse, Page,
+};
+
+fn orderbook(levels: usize) -> String {
+    let side = |offset: usize| {
+        (0..levels)
+            .map(|i| {
+                format!(
+                    r#"{{"price": "0.{:02}", "size": "{}.5"}}"#,
+                    (offset + i) % 100,
+                    i
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    format!(
+        r#"{{
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "asset_id": "123456789",
+            "timestamp": "1234567890123",
+            "hash": "abc123def456",
+            "bids": [{}],
+            "asks": [{}],
+            "min_order_size": "10.0",
+            "neg_risk": false,
+            "tick_size": "0.01"
+        }}"#,
+        side(0),
+        side(50)
+    )
+}
+
+fn markets_page(count: usize) -> String {
+    let market = r#"{
+        "enable_order_book": true,
+        "active": true,
+        "closed": false,
+        "archived": false,
+        "accepting_orders": true,
+        "accepting_order_timestamp": null,
+        "minimum_order_size": "1.0",
+        "minimum_tick_size": "0.01",
+        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "question_id": "0x0000000000000000000000000000000000000000000000000000000000000002",
+        "question": "Will X happen?",
+        "description": "Test market for benchmarking",
+        "market_slug": "test-market-2024",
+        "end_date_iso": "2024-12-31T23:59:59Z",
+        "game_start_time": null,
+        "seconds_delay": 0,
+        "fpmm": "0x1234567890123456789012345678901234567890",
+        "maker_base_fee": "0.001",
+        "taker_base_fee": "0.002",
+        "notifications_enabled": true,
+        "neg_risk": false,
+        "neg_risk_market_id": "",
+        "neg_risk_request_id": "",
+        "icon": "https://polymarket.com/icon.png",
+        "image": "https://polymarket.com/image.png",
+        "rewards": {"rates": [], "min_size": "0", "max_spread": "0"},
+        "is_50_50_outcome": true,
+        "tokens": [
+            {"token_id": "123456789", "outcome": "Yes", "price": "0.55", "winner": false},
+            {"token_id": "987654321", "outcome": "No", "price": "0.45", "winner": false}
+        ],
+        "tags": ["politics", "2024"]
+    }"#;
+
+    format!(
+        r#"{{"data": [{}], "next_cursor": "MTAwMA==", "limit": {count}, "count": {count}}}"#,
+        vec![market; count].join(",")
+    )
+}
+
+fn bench_orderbook(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simd/orderbook");
+
+    for levels in [10, 100, 500] {
+        let body = orderbook(levels);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("serde_json", levels), &body, |b, body| {
+            b.iter(|| {
+                let _: OrderBookSummaryResponse =
+                    serde_json::from_slice(std::hint::black_box(body.as_bytes()))
+                        .expect("Deserialization should succeed");
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("simd_json", levels), &body, |b, body| {
+            b.iter(|| {
+                let mut bytes = body.clone().into_bytes();
+                let _: OrderBookSummaryResponse =
+                    simd_json::serde::from_slice(std::hint::black_box(&mut bytes))
+                        .expect("Deserialization should succeed");
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_markets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simd/markets");
+
+    for count in [10, 100, 500] {
+        let body = markets_page(count);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("serde_json", count), &body, |b, body| {
+            b.iter(|| {
+                let _: Page<MarketResponse> =
+                    serde_json::from_slice(std::hint::black_box(body.as_bytes()))
+                        .expect("Deserialization should succeed");
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("simd_json", count), &body, |b, body| {
+            b.iter(|| {
+                let mut bytes = body.clone().into_bytes();
+                let _: Page<MarketResponse> =
+                    simd_json::serde::from_slice(std::hint::black_box(&mut bytes))
+                        .expect("Deserialization should succeed");
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(simd_benches, bench_orderbook, bench_markets);
+criterion_main!(simd_benches);
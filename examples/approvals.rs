@@ -19,30 +19,28 @@
 //!
 //! Run with tracing enabled:
 //! ```sh
-//! RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features tracing
+//! RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features clob,tracing
 //! ```
 //!
 //! Dry run (no transactions executed):
 //! ```sh
-//! RUST_LOG=info cargo run --example approvals --features tracing -- --dry-run
+//! RUST_LOG=info cargo run --example approvals --features clob,tracing -- --dry-run
 //! ```
 //!
 //! Optionally log to a file:
 //! ```sh
-//! LOG_FILE=approvals.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features tracing
+//! LOG_FILE=approvals.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features clob,tracing
 //! ```
 
 use std::env;
 use std::fs::File;
-use std::str::FromStr as _;
 
 use alloy::primitives::U256;
 use alloy::providers::ProviderBuilder;
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
 use alloy::sol;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::types::{Address, address};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR, contract_config};
+use polymarket_client_sdk::{POLYGON, contract_config};
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -110,8 +108,7 @@ async fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let private_key = env::var(PRIVATE_KEY_VAR).expect("Need a private key");
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(chain));
+    let signer = Settings::builder().chain_id(chain).build().signer()?;
 
     let provider = ProviderBuilder::new()
         .wallet(signer.clone())
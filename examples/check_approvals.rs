@@ -29,7 +29,7 @@ use std::fs::File;
 use alloy::primitives::U256;
 use alloy::providers::ProviderBuilder;
 use alloy::sol;
-use polymarket_client_sdk::types::{Address, address};
+use polymarket_client_sdk::types::{Address, Usdc, address};
 use polymarket_client_sdk::{POLYGON, contract_config};
 use tracing::{debug, info};
 use tracing_subscriber::EnvFilter;
@@ -156,9 +156,9 @@ fn format_allowance(allowance: U256) -> String {
     } else if allowance == U256::ZERO {
         "0".to_owned()
     } else {
-        // USDC has 6 decimals
-        let usdc_decimals = U256::from(1_000_000);
-        let whole = allowance / usdc_decimals;
-        format!("{whole} USDC")
+        Usdc::from_base_units(allowance).map_or_else(
+            |_| format!("{allowance} base units"),
+            |usdc| format!("{usdc} USDC"),
+        )
     }
 }
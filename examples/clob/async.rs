@@ -15,16 +15,15 @@
 //! LOG_FILE=async.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example async --features clob,tracing
 //! ```
 //!
-//! For authenticated endpoints, set the `POLY_PRIVATE_KEY` environment variable.
+//! For authenticated endpoints, set the `POLYMARKET_PRIVATE_KEY` environment variable.
 
 use std::fs::File;
 use std::str::FromStr as _;
 
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
+use polymarket_client_sdk::PRIVATE_KEY_VAR;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::U256;
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+use polymarket_client_sdk::types::TokenId;
 use tokio::join;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
@@ -53,10 +52,10 @@ async fn main() -> anyhow::Result<()> {
 }
 
 async fn unauthenticated() -> anyhow::Result<()> {
-    let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    let client = Client::production(Config::default())?;
     let client_clone = client.clone();
 
-    let token_id = U256::from_str(
+    let token_id = TokenId::from_str(
         "42334954850219754195241248003172889699504912694714162671145392673031415571339",
     )?;
 
@@ -106,19 +105,15 @@ async fn unauthenticated() -> anyhow::Result<()> {
 }
 
 async fn authenticated() -> anyhow::Result<()> {
-    let Ok(private_key) = std::env::var(PRIVATE_KEY_VAR) else {
+    if std::env::var_os(PRIVATE_KEY_VAR).is_none() {
         info!(
             endpoint = "authenticated",
-            "skipped - POLY_PRIVATE_KEY not set"
+            "skipped - {} not set", PRIVATE_KEY_VAR
         );
         return Ok(());
-    };
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+    }
 
-    let client = Client::new("https://clob.polymarket.com", Config::default())?
-        .authentication_builder(&signer)
-        .authenticate()
-        .await?;
+    let (client, _signer) = Settings::builder().build().connect().await?;
     let client_clone = client.clone();
 
     let thread = tokio::spawn(async move {
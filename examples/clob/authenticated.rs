@@ -17,22 +17,20 @@
 //! LOG_FILE=authenticated.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example authenticated --features clob,tracing
 //! ```
 //!
-//! Requires `POLY_PRIVATE_KEY` environment variable to be set.
+//! Requires the `POLYMARKET_PRIVATE_KEY` environment variable to be set, see
+//! `polymarket_client_sdk::clob::bootstrap` for the other supported variables.
 
 use std::fs::File;
 use std::str::FromStr as _;
 
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
 use chrono::{TimeDelta, Utc};
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::types::request::{
     BalanceAllowanceRequest, OrdersRequest, TradesRequest, UpdateBalanceAllowanceRequest,
     UserRewardsEarningRequest,
 };
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side};
-use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::{Decimal, U256};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use rust_decimal_macros::dec;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
@@ -55,17 +53,14 @@ async fn main() -> anyhow::Result<()> {
         tracing_subscriber::fmt::init();
     }
 
-    let token_id = U256::from_str(
+    let token_id = TokenId::from_str(
         "15871154585880608648532107628464183779895785213830018178010423617714102767076",
     )?;
 
-    let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need POLY_PRIVATE_KEY");
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
-
-    let config = Config::builder().use_server_time(true).build();
-    let client = Client::new("https://clob.polymarket.com", config)?
-        .authentication_builder(&signer)
-        .authenticate()
+    let (client, signer) = Settings::builder()
+        .use_server_time(true)
+        .build()
+        .connect()
         .await?;
 
     match client.api_keys().await {
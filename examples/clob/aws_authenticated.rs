@@ -55,7 +55,7 @@ async fn main() -> anyhow::Result<()> {
         .await?
         .with_chain_id(Some(POLYGON));
 
-    let client = Client::new("https://clob.polymarket.com", Config::default())?
+    let client = Client::production(Config::default())?
         .authentication_builder(&alloy_signer)
         .authenticate()
         .await?;
@@ -16,18 +16,16 @@
 //! LOG_FILE=builder_authenticated.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example builder_authenticated --features clob,tracing
 //! ```
 //!
-//! Requires `POLY_PRIVATE_KEY` environment variable to be set.
+//! Requires the `POLYMARKET_PRIVATE_KEY` environment variable to be set, see
+//! `polymarket_client_sdk::clob::bootstrap` for the other supported variables.
 
 use std::fs::File;
 use std::str::FromStr as _;
 
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
 use polymarket_client_sdk::auth::builder::Config as BuilderConfig;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::types::request::TradesRequest;
-use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::U256;
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+use polymarket_client_sdk::types::TokenId;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -49,13 +47,7 @@ async fn main() -> anyhow::Result<()> {
         tracing_subscriber::fmt::init();
     }
 
-    let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need POLY_PRIVATE_KEY");
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
-
-    let client = Client::new("https://clob.polymarket.com", Config::default())?
-        .authentication_builder(&signer)
-        .authenticate()
-        .await?;
+    let (client, _signer) = Settings::builder().build().connect().await?;
 
     // Create builder credentials and promote to builder client
     let builder_credentials = client.create_builder_api_key().await?;
@@ -76,7 +68,7 @@ async fn main() -> anyhow::Result<()> {
         Err(e) => error!(endpoint = "builder_api_keys", error = %e),
     }
 
-    let token_id = U256::from_str(
+    let token_id = TokenId::from_str(
         "15871154585880608648532107628464183779895785213830018178010423617714102767076",
     )?;
     let request = TradesRequest::builder().asset_id(token_id).build();
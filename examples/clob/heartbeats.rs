@@ -5,25 +5,22 @@
 //! RUST_LOG=debug,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example heartbeats --features heartbeats,tracing
 //! ```
 //!
-use std::str::FromStr as _;
 use std::time::Duration;
 
-use polymarket_client_sdk::auth::{LocalSigner, Signer as _};
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need a private key");
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+    let signer = Settings::builder().build().signer()?;
 
     let config = Config::builder()
         .use_server_time(true)
         .heartbeat_interval(Duration::from_secs(1))
         .build();
-    let client = Client::new("https://clob.polymarket.com", config)?
+    let client = Client::production(config)?
         .authentication_builder(&signer)
         .authenticate()
         .await?;
@@ -15,18 +15,15 @@
 //! LOG_FILE=rfq_requests.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example rfq_requests --features clob,rfq,tracing
 //! ```
 //!
-//! Requires `POLY_PRIVATE_KEY` environment variable to be set.
+//! Requires the `POLYMARKET_PRIVATE_KEY` environment variable to be set, see
+//! `polymarket_client_sdk::clob::bootstrap` for the other supported variables.
 
 #![cfg(feature = "rfq")]
 
 use std::fs::File;
-use std::str::FromStr as _;
 
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::types::{RfqRequestsRequest, RfqSortBy, RfqSortDir, RfqState};
-use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 use tracing::{debug, error, info};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -48,13 +45,7 @@ async fn main() -> anyhow::Result<()> {
         tracing_subscriber::fmt::init();
     }
 
-    let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need POLY_PRIVATE_KEY");
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
-
-    let client = Client::new("https://clob.polymarket.com", Config::default())?
-        .authentication_builder(&signer)
-        .authenticate()
-        .await?;
+    let (client, _signer) = Settings::builder().build().connect().await?;
 
     let request = RfqRequestsRequest::builder()
         .state(RfqState::Active)
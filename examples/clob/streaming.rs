@@ -14,20 +14,18 @@
 //! LOG_FILE=streaming.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example streaming --features tracing
 //! ```
 //!
-//! For authenticated streaming, set the `POLY_PRIVATE_KEY` environment variable:
+//! For authenticated streaming, set the `POLYMARKET_PRIVATE_KEY` environment variable:
 //! ```sh
-//! POLY_PRIVATE_KEY=0x... RUST_LOG=info cargo run --example streaming --features tracing
+//! POLYMARKET_PRIVATE_KEY=0x... RUST_LOG=info cargo run --example streaming --features tracing
 //! ```
 
 use std::fs::File;
-use std::str::FromStr as _;
 
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
 use futures::{StreamExt as _, future};
+use polymarket_client_sdk::PRIVATE_KEY_VAR;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::clob::types::request::TradesRequest;
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 use tokio::join;
 use tracing::{debug, info, warn};
 use tracing_subscriber::EnvFilter;
@@ -56,7 +54,7 @@ async fn main() -> anyhow::Result<()> {
 }
 
 async fn unauthenticated() -> anyhow::Result<()> {
-    let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    let client = Client::production(Config::default())?;
 
     info!(
         stream = "sampling_markets",
@@ -104,20 +102,15 @@ async fn unauthenticated() -> anyhow::Result<()> {
 }
 
 async fn authenticated() -> anyhow::Result<()> {
-    let Ok(private_key) = std::env::var(PRIVATE_KEY_VAR) else {
+    if std::env::var_os(PRIVATE_KEY_VAR).is_none() {
         warn!(
             stream = "trades",
             "skipping authenticated stream - {} not set", PRIVATE_KEY_VAR
         );
         return Ok(());
-    };
-
-    let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+    }
 
-    let client = Client::new("https://clob.polymarket.com", Config::default())?
-        .authentication_builder(&signer)
-        .authenticate()
-        .await?;
+    let (client, _signer) = Settings::builder().build().connect().await?;
 
     info!(stream = "trades", "starting authenticated stream");
 
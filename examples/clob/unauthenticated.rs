@@ -23,7 +23,7 @@ use polymarket_client_sdk::clob::types::request::{
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, PriceRequest, SpreadRequest,
 };
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::{B256, Decimal, U256};
+use polymarket_client_sdk::types::{B256, Decimal, TokenId};
 use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -38,7 +38,7 @@ use tracing_subscriber::util::SubscriberInitExt as _;
 /// - Has tokens with non-zero prices
 ///
 /// This ensures subsequent price/midpoint/orderbook API calls will succeed.
-async fn find_market_with_orderbook(client: &Client) -> anyhow::Result<(U256, B256)> {
+async fn find_market_with_orderbook(client: &Client) -> anyhow::Result<(TokenId, B256)> {
     info!("Searching for a market with an active orderbook...");
 
     let mut stream = Box::pin(client.stream_data(Client::markets));
@@ -103,7 +103,7 @@ async fn main() -> anyhow::Result<()> {
         tracing_subscriber::fmt::init();
     }
 
-    let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    let client = Client::production(Config::default())?;
 
     // Health check endpoints
     match client.ok().await {
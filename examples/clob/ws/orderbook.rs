@@ -20,7 +20,7 @@ use std::str::FromStr as _;
 
 use futures::StreamExt as _;
 use polymarket_client_sdk::clob::ws::Client;
-use polymarket_client_sdk::types::U256;
+use polymarket_client_sdk::types::TokenId;
 use tracing::{debug, error, info};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -46,10 +46,10 @@ async fn main() -> anyhow::Result<()> {
     info!(endpoint = "websocket", "connected to CLOB WebSocket API");
 
     let asset_ids = vec![
-        U256::from_str(
+        TokenId::from_str(
             "92703761682322480664976766247614127878023988651992837287050266308961660624165",
         )?,
-        U256::from_str(
+        TokenId::from_str(
             "34551606549875928972193520396544368029176529083448203019529657908155427866742",
         )?,
     ];
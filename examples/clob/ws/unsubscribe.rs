@@ -26,7 +26,7 @@ use std::time::Duration;
 
 use futures::StreamExt as _;
 use polymarket_client_sdk::clob::ws::Client;
-use polymarket_client_sdk::types::U256;
+use polymarket_client_sdk::types::TokenId;
 use tokio::time::timeout;
 use tracing::{debug, error, info};
 use tracing_subscriber::EnvFilter;
@@ -52,7 +52,7 @@ async fn main() -> anyhow::Result<()> {
     let client = Client::default();
     info!(endpoint = "websocket", "connected to CLOB WebSocket API");
 
-    let asset_ids = vec![U256::from_str(
+    let asset_ids = vec![TokenId::from_str(
         "92703761682322480664976766247614127878023988651992837287050266308961660624165",
     )?];
 
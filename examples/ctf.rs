@@ -13,30 +13,28 @@
 //!
 //! For read-only operations (ID calculations):
 //! ```sh
-//! cargo run --example ctf --features ctf
+//! cargo run --example ctf --features clob,ctf,tracing
 //! ```
 //!
 //! For write operations (split, merge, redeem), you need a private key:
 //! ```sh
 //! export POLYMARKET_PRIVATE_KEY="your_private_key"
-//! cargo run --example ctf --features ctf -- --write
+//! cargo run --example ctf --features clob,ctf,tracing -- --write
 //! ```
 
 use std::env;
-use std::str::FromStr as _;
 
 use alloy::primitives::{B256, U256};
 use alloy::providers::ProviderBuilder;
-use alloy::signers::Signer as _;
-use alloy::signers::local::LocalSigner;
 use anyhow::Result;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::clob::bootstrap::Settings;
 use polymarket_client_sdk::ctf::Client;
 use polymarket_client_sdk::ctf::types::{
     CollectionIdRequest, ConditionIdRequest, MergePositionsRequest, PositionIdRequest,
     RedeemPositionsRequest, SplitPositionRequest,
 };
-use polymarket_client_sdk::types::address;
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+use polymarket_client_sdk::types::{Decimal, Shares, Usdc, address};
 use tracing::{error, info};
 
 const RPC_URL: &str = "https://polygon-rpc.com";
@@ -132,9 +130,7 @@ async fn main() -> Result<()> {
     if write_mode {
         info!("--- Write Operations (requires wallet) ---");
 
-        let private_key =
-            env::var(PRIVATE_KEY_VAR).expect("Need a private key for write operations");
-        let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(chain));
+        let signer = Settings::builder().chain_id(chain).build().signer()?;
 
         let provider = ProviderBuilder::new()
             .wallet(signer.clone())
@@ -155,7 +151,7 @@ async fn main() -> Result<()> {
         let split_req = SplitPositionRequest::for_binary_market(
             usdc,
             condition_resp.condition_id,
-            U256::from(1_000_000), // 1 USDC (6 decimals)
+            Usdc::new(Decimal::ONE)?.to_base_units(),
         );
 
         match client.split_position(&split_req).await {
@@ -178,7 +174,7 @@ async fn main() -> Result<()> {
         let merge_req = MergePositionsRequest::for_binary_market(
             usdc,
             condition_resp.condition_id,
-            U256::from(1_000_000), // 1 full set
+            Shares::new(Decimal::ONE)?.to_base_units(), // 1 full set
         );
 
         match client.merge_positions(&merge_req).await {
@@ -216,7 +212,7 @@ async fn main() -> Result<()> {
         info!("--- Write Operations ---");
         info!("To test write operations (split, merge, redeem), run with --write flag:");
         info!("  export POLYMARKET_PRIVATE_KEY=\"your_private_key\"");
-        info!("  cargo run --example ctf --features ctf -- --write");
+        info!("  cargo run --example ctf --features clob,ctf,tracing -- --write");
     }
 
     info!("=== Example Complete ===");
@@ -0,0 +1,166 @@
+//! Addresses of the contracts Polymarket deploys on each supported chain.
+//!
+//! The [`polygon`] and [`amoy`] modules hold the built-in addresses as constants, which are also
+//! what [`contract_config`] and [`wallet_contract_config`] return unless another config was
+//! registered for the chain. [`lookup`] and [`contracts`] resolve a [`Contract`] through those
+//! configs, so on-chain tooling picks up registered configs, e.g. for a local fork, the same way
+//! the rest of the SDK does.
+//!
+//! ```
+//! use polymarket_client_sdk::POLYGON;
+//! use polymarket_client_sdk::addresses::{self, Contract, polygon};
+//!
+//! assert_eq!(
+//!     addresses::lookup(POLYGON, Contract::Collateral),
+//!     Some(polygon::COLLATERAL)
+//! );
+//! ```
+
+use alloy::primitives::ChainId;
+
+use crate::types::Address;
+use crate::{contract_config, wallet_contract_config};
+
+/// Contract addresses on Polygon mainnet, see [`POLYGON`](crate::POLYGON).
+pub mod polygon {
+    use crate::types::{Address, address};
+
+    /// The USDC.e token used as collateral.
+    pub const COLLATERAL: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+    /// The Conditional Tokens Framework (CTF) contract holding the outcome tokens.
+    pub const CONDITIONAL_TOKENS: Address = address!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045");
+    /// The CTF exchange settling orders of regular markets.
+    pub const EXCHANGE: Address = address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+    /// The exchange settling orders of neg-risk markets.
+    pub const NEG_RISK_EXCHANGE: Address = address!("0xC5d563A36AE78145C45a50134d48A1215220f80a");
+    /// The adapter converting positions of neg-risk markets.
+    pub const NEG_RISK_ADAPTER: Address = address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296");
+    /// The factory deploying Polymarket Proxy wallets (Magic/email wallets).
+    pub const PROXY_FACTORY: Address = address!("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052");
+    /// The factory deploying Gnosis Safe wallets (browser wallets).
+    pub const SAFE_FACTORY: Address = address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b");
+}
+
+/// Contract addresses on the Amoy testnet, see [`AMOY`](crate::AMOY).
+///
+/// Proxy wallets are not supported on Amoy, so there is no proxy factory.
+pub mod amoy {
+    use crate::types::{Address, address};
+
+    /// The token used as collateral.
+    pub const COLLATERAL: Address = address!("0x9c4e1703476e875070ee25b56a58b008cfb8fa78");
+    /// The Conditional Tokens Framework (CTF) contract holding the outcome tokens.
+    pub const CONDITIONAL_TOKENS: Address = address!("0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB");
+    /// The CTF exchange settling orders of regular markets.
+    pub const EXCHANGE: Address = address!("0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40");
+    /// The exchange settling orders of neg-risk markets.
+    pub const NEG_RISK_EXCHANGE: Address = address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296");
+    /// The adapter converting positions of neg-risk markets.
+    pub const NEG_RISK_ADAPTER: Address = address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296");
+    /// The factory deploying Gnosis Safe wallets (browser wallets).
+    pub const SAFE_FACTORY: Address = address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b");
+}
+
+/// A contract Polymarket deploys on each chain.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Contract {
+    /// The token used as collateral, i.e. USDC.e on Polygon.
+    Collateral,
+    /// The Conditional Tokens Framework (CTF) contract holding the outcome tokens.
+    ConditionalTokens,
+    /// The CTF exchange settling orders of regular markets.
+    Exchange,
+    /// The exchange settling orders of neg-risk markets.
+    NegRiskExchange,
+    /// The adapter converting positions of neg-risk markets.
+    NegRiskAdapter,
+    /// The factory deploying Polymarket Proxy wallets.
+    ProxyFactory,
+    /// The factory deploying Gnosis Safe wallets.
+    SafeFactory,
+}
+
+impl Contract {
+    /// Every contract, in declaration order.
+    pub const ALL: [Self; 7] = [
+        Self::Collateral,
+        Self::ConditionalTokens,
+        Self::Exchange,
+        Self::NegRiskExchange,
+        Self::NegRiskAdapter,
+        Self::ProxyFactory,
+        Self::SafeFactory,
+    ];
+}
+
+/// Returns the address of `contract` on `chain_id`, or `None` if it is not deployed there or no
+/// config is known for the chain.
+///
+/// Addresses are resolved through [`contract_config`] and [`wallet_contract_config`], so configs
+/// registered at runtime take precedence over the built-in constants.
+#[must_use]
+pub fn lookup(chain_id: ChainId, contract: Contract) -> Option<Address> {
+    let address = match contract {
+        Contract::Collateral => contract_config(chain_id, false)?.collateral,
+        Contract::ConditionalTokens => contract_config(chain_id, false)?.conditional_tokens,
+        Contract::Exchange => contract_config(chain_id, false)?.exchange,
+        Contract::NegRiskExchange => contract_config(chain_id, true)?.exchange,
+        Contract::NegRiskAdapter => contract_config(chain_id, true)?.neg_risk_adapter?,
+        Contract::ProxyFactory => wallet_contract_config(chain_id)?.proxy_factory?,
+        Contract::SafeFactory => wallet_contract_config(chain_id)?.safe_factory,
+    };
+
+    Some(address)
+}
+
+/// Returns every contract known on `chain_id` together with its address, see [`lookup`].
+///
+/// As some contracts share an address, e.g. the neg-risk exchange and adapter on Amoy, an address
+/// may appear more than once.
+pub fn contracts(chain_id: ChainId) -> impl Iterator<Item = (Contract, Address)> {
+    Contract::ALL
+        .into_iter()
+        .filter_map(move |contract| lookup(chain_id, contract).map(|address| (contract, address)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AMOY, POLYGON};
+
+    #[test]
+    fn lookup_should_return_built_in_addresses() {
+        assert_eq!(lookup(POLYGON, Contract::Exchange), Some(polygon::EXCHANGE));
+        assert_eq!(
+            lookup(POLYGON, Contract::NegRiskAdapter),
+            Some(polygon::NEG_RISK_ADAPTER)
+        );
+        assert_eq!(
+            lookup(POLYGON, Contract::ProxyFactory),
+            Some(polygon::PROXY_FACTORY)
+        );
+        assert_eq!(
+            lookup(AMOY, Contract::NegRiskExchange),
+            Some(amoy::NEG_RISK_EXCHANGE)
+        );
+        assert_eq!(lookup(AMOY, Contract::ProxyFactory), None);
+        assert_eq!(lookup(1, Contract::Collateral), None);
+    }
+
+    #[test]
+    fn contracts_should_skip_missing_addresses() {
+        assert_eq!(contracts(POLYGON).count(), Contract::ALL.len());
+        assert!(
+            contracts(AMOY).all(|(contract, _)| contract != Contract::ProxyFactory),
+            "Amoy has no proxy factory"
+        );
+        assert_eq!(contracts(1).count(), 0);
+    }
+
+    #[test]
+    fn contract_should_display_as_snake_case() {
+        assert_eq!(Contract::NegRiskAdapter.to_string(), "neg_risk_adapter");
+    }
+}
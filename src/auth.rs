@@ -1,16 +1,21 @@
 // Re-exported types for public API convenience
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
 /// The [`Signer`] trait from alloy for signing operations.
 /// Implement this trait or use provided signers like [`LocalSigner`] or AWS KMS signers.
+#[cfg(feature = "signing")]
 pub use alloy::signers::Signer;
 /// Local wallet signer for signing with a private key.
 /// This is the most common signer implementation.
+#[cfg(feature = "signing")]
 pub use alloy::signers::local::LocalSigner;
 use async_trait::async_trait;
 use base64::Engine as _;
 use base64::engine::general_purpose::URL_SAFE;
 use hmac::{Hmac, Mac as _};
+use reqwest::Request;
 use reqwest::header::HeaderMap;
-use reqwest::{Body, Request};
 /// Secret string types that redact values in debug output for security.
 pub use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
@@ -18,7 +23,8 @@ use sha2::Sha256;
 /// UUID type used for API keys and identifiers.
 pub use uuid::Uuid;
 
-use crate::{Result, Timestamp};
+use crate::error::Error;
+use crate::{Result, UnixSeconds};
 
 /// Type alias for API keys, which are UUIDs.
 pub type ApiKey = Uuid;
@@ -33,6 +39,10 @@ pub struct Credentials {
     pub(crate) key: ApiKey,
     pub(crate) secret: SecretString,
     pub(crate) passphrase: SecretString,
+    /// The HMAC keyed with the decoded `secret`, computed on first use so that signing a request
+    /// does not have to base64-decode the secret and rerun the key schedule every time.
+    #[serde(skip)]
+    pub(crate) mac: OnceLock<Hmac<Sha256>>,
 }
 
 impl Credentials {
@@ -42,6 +52,7 @@ impl Credentials {
             key,
             secret: SecretString::from(secret),
             passphrase: SecretString::from(passphrase),
+            mac: OnceLock::new(),
         }
     }
 
@@ -62,6 +73,18 @@ impl Credentials {
     pub fn passphrase(&self) -> &SecretString {
         &self.passphrase
     }
+
+    /// Returns a fresh HMAC keyed with the decoded secret, decoding it on first use.
+    fn mac(&self) -> Result<Hmac<Sha256>> {
+        if let Some(mac) = self.mac.get() {
+            return Ok(mac.clone());
+        }
+
+        let decoded_secret = URL_SAFE.decode(self.secret.expose_secret())?;
+        let mac = Hmac::<Sha256>::new_from_slice(&decoded_secret)?;
+
+        Ok(self.mac.get_or_init(|| mac).clone())
+    }
 }
 
 /// Each client can exist in one state at a time, i.e. [`state::Unauthenticated`] or
@@ -118,7 +141,7 @@ pub mod state {
 /// L2 headers.
 #[async_trait]
 pub trait Kind: sealed::Sealed + Clone + Send + Sync + 'static {
-    async fn extra_headers(&self, request: &Request, timestamp: Timestamp) -> Result<HeaderMap>;
+    async fn extra_headers(&self, request: &Request, timestamp: UnixSeconds) -> Result<HeaderMap>;
 }
 
 /// Non-special, generic authentication. Sometimes referred to as L2 authentication.
@@ -128,7 +151,11 @@ pub struct Normal;
 
 #[async_trait]
 impl Kind for Normal {
-    async fn extra_headers(&self, _request: &Request, _timestamp: Timestamp) -> Result<HeaderMap> {
+    async fn extra_headers(
+        &self,
+        _request: &Request,
+        _timestamp: UnixSeconds,
+    ) -> Result<HeaderMap> {
         Ok(HeaderMap::new())
     }
 }
@@ -137,7 +164,7 @@ impl sealed::Sealed for Normal {}
 
 #[async_trait]
 impl Kind for builder::Builder {
-    async fn extra_headers(&self, request: &Request, timestamp: Timestamp) -> Result<HeaderMap> {
+    async fn extra_headers(&self, request: &Request, timestamp: UnixSeconds) -> Result<HeaderMap> {
         self.create_headers(request, timestamp).await
     }
 }
@@ -160,7 +187,7 @@ pub(crate) mod l1 {
     use alloy::sol_types::SolStruct as _;
     use reqwest::header::HeaderMap;
 
-    use crate::{Result, Timestamp};
+    use crate::{Result, UnixSeconds};
 
     pub(crate) const POLY_ADDRESS: &str = "POLY_ADDRESS";
     pub(crate) const POLY_NONCE: &str = "POLY_NONCE";
@@ -181,7 +208,7 @@ pub(crate) mod l1 {
     pub(crate) async fn create_headers<S: Signer>(
         signer: &S,
         chain_id: ChainId,
-        timestamp: Timestamp,
+        timestamp: UnixSeconds,
         nonce: Option<u32>,
     ) -> Result<HeaderMap> {
         let naive_nonce = nonce.unwrap_or(0);
@@ -224,8 +251,8 @@ pub(crate) mod l2 {
     use secrecy::ExposeSecret as _;
 
     use crate::auth::state::Authenticated;
-    use crate::auth::{Kind, hmac, to_message};
-    use crate::{Result, Timestamp};
+    use crate::auth::{Kind, sign};
+    use crate::{Result, UnixSeconds};
 
     pub(crate) const POLY_ADDRESS: &str = "POLY_ADDRESS";
     pub(crate) const POLY_API_KEY: &str = "POLY_API_KEY";
@@ -237,10 +264,9 @@ pub(crate) mod l2 {
     pub(crate) async fn create_headers<K: Kind>(
         state: &Authenticated<K>,
         request: &Request,
-        timestamp: Timestamp,
+        timestamp: UnixSeconds,
     ) -> Result<HeaderMap> {
-        let credentials = &state.credentials;
-        let signature = hmac(&credentials.secret, &to_message(request, timestamp))?;
+        let signature = sign(&state.credentials, request, timestamp)?;
 
         let mut map = HeaderMap::new();
 
@@ -264,8 +290,20 @@ pub(crate) mod l2 {
     }
 }
 
-/// Specific structs and methods used in configuring and authenticating the Builder flow
+/// Specific structs and methods used in configuring and authenticating the Builder flow.
+///
+/// Orders posted by a [`Builder`](builder::Builder) client carry four `POLY_BUILDER_*` headers next to the regular
+/// L2 ones, which attribute their volume to the builder. The headers are either signed locally
+/// with the builder's own API credentials ([`Config::Local`](builder::Config::Local)), or obtained from a remote signing
+/// server so that the credentials never leave it ([`Config::Remote`](builder::Config::Remote)).
+///
+/// A [`Config`](builder::Config) can be read from `POLYMARKET_BUILDER_*` environment variables with
+/// [`Config::from_env`](builder::Config::from_env), or from a JSON document with [`Config::from_json`](builder::Config::from_json). Once a client has
+/// been promoted with `Client::promote_to_builder`, `Client::verify_builder_attribution` checks
+/// that the headers are produced and accepted by the CLOB.
 pub mod builder {
+    use std::env::{self, VarError};
+
     use reqwest::header::HeaderMap;
     use reqwest::{Client, Request};
     use secrecy::ExposeSecret as _;
@@ -273,9 +311,23 @@ pub mod builder {
     use serde_json::json;
     /// URL type for remote builder host configuration.
     pub use url::Url;
+    use uuid::Uuid;
 
-    use crate::auth::{Credentials, body_to_string, hmac, to_message};
-    use crate::{Result, Timestamp};
+    use crate::auth::{Credentials, sign, signed_body};
+    use crate::error::Error;
+    use crate::{Result, UnixSeconds};
+
+    /// Environment variable holding the builder API key, for a [`Config::Local`].
+    pub const API_KEY_VAR: &str = "POLYMARKET_BUILDER_API_KEY";
+    /// Environment variable holding the builder API secret, for a [`Config::Local`].
+    pub const SECRET_VAR: &str = "POLYMARKET_BUILDER_SECRET";
+    /// Environment variable holding the builder API passphrase, for a [`Config::Local`].
+    pub const PASSPHRASE_VAR: &str = "POLYMARKET_BUILDER_PASSPHRASE";
+    /// Environment variable holding the URL of a remote signing server, for a
+    /// [`Config::Remote`]. Takes precedence over local credentials when set.
+    pub const SIGNER_URL_VAR: &str = "POLYMARKET_BUILDER_SIGNER_URL";
+    /// Environment variable holding the optional bearer token of the remote signing server.
+    pub const SIGNER_TOKEN_VAR: &str = "POLYMARKET_BUILDER_SIGNER_TOKEN";
 
     pub(crate) const POLY_BUILDER_API_KEY: &str = "POLY_BUILDER_API_KEY";
     pub(crate) const POLY_BUILDER_PASSPHRASE: &str = "POLY_BUILDER_PASSPHRASE";
@@ -297,10 +349,18 @@ pub mod builder {
 
     /// Configuration used to authenticate as a [Builder](https://docs.polymarket.com/developers/builders/builder-intro). Can either be [`Config::local`]
     /// or [`Config::remote`]. Local uses locally accessible Builder credentials to generate builder headers. Remote obtains them from a signing server
+    ///
+    /// It deserializes from either the builder credentials, as in
+    /// `{"key": "...", "secret": "...", "passphrase": "..."}`, or a signing server, as in
+    /// `{"host": "https://...", "token": "..."}` where `token` is optional.
     #[non_exhaustive]
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(try_from = "RawConfig")]
     pub enum Config {
+        /// Sign the builder headers locally with these builder API credentials.
         Local(Credentials),
+        /// Obtain the builder headers from the signing server at `host`, authenticating with
+        /// `token` as a bearer token if set.
         Remote { host: Url, token: Option<String> },
     }
 
@@ -310,10 +370,96 @@ pub mod builder {
             Config::Local(credentials)
         }
 
+        /// Creates a configuration obtaining the builder headers from the signing server at
+        /// `host`.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if `host` is not a valid URL.
         pub fn remote(host: &str, token: Option<String>) -> Result<Self> {
             let host = Url::parse(host)?;
             Ok(Config::Remote { host, token })
         }
+
+        /// Reads the configuration from `POLYMARKET_BUILDER_*` environment variables, see the
+        /// constants in this module.
+        ///
+        /// A [`Config::Remote`] is returned if [`SIGNER_URL_VAR`] is set, and a
+        /// [`Config::Local`] otherwise.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if a required variable is missing, or if a variable is not valid
+        /// unicode or cannot be parsed.
+        pub fn from_env() -> Result<Self> {
+            Self::from_vars(|var| match env::var(var) {
+                Ok(value) => Ok(Some(value)),
+                Err(VarError::NotPresent) => Ok(None),
+                Err(e) => Err(Error::validation(format!("Unable to read {var}: {e}"))),
+            })
+        }
+
+        /// Parses the configuration from a JSON document, see [`Config`] for its shape.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the document is not valid JSON or describes neither credentials
+        /// nor a valid signing server.
+        pub fn from_json(json: &str) -> Result<Self> {
+            Ok(serde_json::from_str(json)?)
+        }
+
+        /// Returns the builder API key the headers are signed with, which is only known
+        /// upfront for a [`Config::Local`].
+        #[must_use]
+        pub fn api_key(&self) -> Option<Uuid> {
+            match self {
+                Config::Local(credentials) => Some(credentials.key),
+                Config::Remote { .. } => None,
+            }
+        }
+
+        pub(crate) fn from_vars<F: Fn(&str) -> Result<Option<String>>>(var: F) -> Result<Self> {
+            if let Some(host) = var(SIGNER_URL_VAR)? {
+                return Self::remote(host.trim(), var(SIGNER_TOKEN_VAR)?);
+            }
+
+            let required = |name: &str| {
+                var(name)?.ok_or_else(|| {
+                    Error::validation(format!(
+                        "Missing {name}, set either {SIGNER_URL_VAR} or {API_KEY_VAR}, {SECRET_VAR} and {PASSPHRASE_VAR}"
+                    ))
+                })
+            };
+            let key = required(API_KEY_VAR)?;
+            let key = Uuid::parse_str(key.trim())
+                .map_err(|e| Error::validation(format!("Invalid value for {API_KEY_VAR}: {e}")))?;
+
+            Ok(Self::local(Credentials::new(
+                key,
+                required(SECRET_VAR)?,
+                required(PASSPHRASE_VAR)?,
+            )))
+        }
+    }
+
+    /// The serialized shapes of a [`Config`].
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawConfig {
+        Local(Credentials),
+        Remote { host: String, token: Option<String> },
+    }
+
+    impl TryFrom<RawConfig> for Config {
+        type Error = Error;
+
+        fn try_from(raw: RawConfig) -> Result<Self> {
+            match raw {
+                RawConfig::Local(credentials) => Ok(Self::local(credentials)),
+                RawConfig::Remote { host, token } => Self::remote(&host, token),
+            }
+        }
     }
 
     /// Used to generate the Builder headers
@@ -328,11 +474,11 @@ pub mod builder {
         pub(crate) async fn create_headers(
             &self,
             request: &Request,
-            timestamp: Timestamp,
+            timestamp: UnixSeconds,
         ) -> Result<HeaderMap> {
             match &self.config {
                 Config::Local(credentials) => {
-                    let signature = hmac(&credentials.secret, &to_message(request, timestamp))?;
+                    let signature = sign(credentials, request, timestamp)?;
 
                     let mut map = HeaderMap::new();
 
@@ -350,7 +496,7 @@ pub mod builder {
                     let payload = json!({
                         "method": request.method().as_str(),
                         "path": request.url().path(),
-                        "body": &request.body().and_then(body_to_string).unwrap_or_default(),
+                        "body": signed_body(request)?,
                         "timestamp": timestamp,
                     });
 
@@ -395,29 +541,44 @@ pub mod builder {
     }
 }
 
-#[must_use]
-fn to_message(request: &Request, timestamp: Timestamp) -> String {
-    let method = request.method();
-    let body = request.body().and_then(body_to_string).unwrap_or_default();
-    let path = request.url().path();
-
-    format!("{timestamp}{method}{path}{body}")
-}
-
-#[must_use]
-fn body_to_string(body: &Body) -> Option<String> {
-    body.as_bytes()
-        .map(String::from_utf8_lossy)
-        .map(|b| b.replace('\'', "\""))
+/// Signs `request` with `credentials`, returning the base64 encoded HMAC of
+/// `{timestamp}{method}{path}{body}`.
+///
+/// The message is streamed into the HMAC piece by piece rather than assembled up front, and the
+/// body is taken from the very bytes that will be sent, so the signature and the outgoing body
+/// can never diverge.
+fn sign(credentials: &Credentials, request: &Request, timestamp: UnixSeconds) -> Result<String> {
+    let mut mac = credentials.mac()?;
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(request.method().as_str().as_bytes());
+    mac.update(request.url().path().as_bytes());
+    mac.update(signed_body(request)?.as_bytes());
+
+    Ok(URL_SAFE.encode(mac.finalize().into_bytes()))
 }
 
-fn hmac(secret: &SecretString, message: &str) -> Result<String> {
-    let decoded_secret = URL_SAFE.decode(secret.expose_secret())?;
-    let mut mac = Hmac::<Sha256>::new_from_slice(&decoded_secret)?;
-    mac.update(message.as_bytes());
+/// Returns the body of `request` as it appears in the signed message, borrowing the serialized
+/// bytes whenever no normalization is needed.
+///
+/// Bodies must be fully buffered (as produced by [`reqwest::RequestBuilder::json`]), since a
+/// streaming body cannot be read without consuming it and would otherwise be signed as empty.
+fn signed_body(request: &Request) -> Result<Cow<'_, str>> {
+    let Some(body) = request.body() else {
+        return Ok(Cow::Borrowed(""));
+    };
+    let Some(bytes) = body.as_bytes() else {
+        return Err(Error::validation(
+            "Unable to sign a request with a streaming body",
+        ));
+    };
+
+    let body = String::from_utf8_lossy(bytes);
+    if body.contains('\'') {
+        return Ok(Cow::Owned(body.replace('\'', "\"")));
+    }
 
-    let result = mac.finalize().into_bytes();
-    Ok(URL_SAFE.encode(result))
+    Ok(body)
 }
 
 #[cfg(test)]
@@ -426,7 +587,7 @@ mod tests {
 
     #[cfg(feature = "clob")]
     use alloy::signers::local::LocalSigner;
-    use reqwest::{Client, Method, RequestBuilder};
+    use reqwest::{Body, Client, Method, RequestBuilder};
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
@@ -484,6 +645,7 @@ mod tests {
                 secret: SecretString::from(
                     "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
                 ),
+                mac: OnceLock::new(),
             },
             kind: Normal,
         };
@@ -517,6 +679,7 @@ mod tests {
                 "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned(),
             ),
             secret: SecretString::from("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned()),
+            mac: OnceLock::new(),
         };
         let config = Config::local(credentials);
         let request = Request::new(Method::GET, Url::parse("http://localhost/")?);
@@ -542,6 +705,88 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn builder_config_should_read_vars() -> Result<()> {
+        use std::collections::HashMap;
+
+        let from = |vars: &[(&str, &str)]| {
+            let vars: HashMap<String, String> = vars
+                .iter()
+                .map(|(var, value)| ((*var).to_owned(), (*value).to_owned()))
+                .collect();
+            Config::from_vars(|var| Ok(vars.get(var).cloned()))
+        };
+
+        let config = from(&[
+            (
+                builder::API_KEY_VAR,
+                " 00000000-0000-0000-0000-000000000000 ",
+            ),
+            (builder::SECRET_VAR, "secret"),
+            (builder::PASSPHRASE_VAR, "passphrase"),
+        ])?;
+        assert_eq!(config.api_key(), Some(Uuid::nil()));
+
+        // A signing server takes precedence over local credentials
+        let config = from(&[
+            (builder::API_KEY_VAR, "00000000-0000-0000-0000-000000000000"),
+            (builder::SIGNER_URL_VAR, "https://signer.example.com/sign"),
+            (builder::SIGNER_TOKEN_VAR, "token"),
+        ])?;
+        let Config::Remote { host, token } = config else {
+            panic!("expected a remote configuration");
+        };
+        assert_eq!(host.as_str(), "https://signer.example.com/sign");
+        assert_eq!(token.as_deref(), Some("token"));
+
+        let err =
+            from(&[(builder::API_KEY_VAR, "00000000-0000-0000-0000-000000000000")]).unwrap_err();
+        assert!(err.to_string().contains(builder::SECRET_VAR));
+        from(&[
+            (builder::API_KEY_VAR, "not-a-uuid"),
+            (builder::SECRET_VAR, "secret"),
+            (builder::PASSPHRASE_VAR, "passphrase"),
+        ])
+        .unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_config_should_parse_json() -> Result<()> {
+        let config = Config::from_json(
+            r#"{"key": "00000000-0000-0000-0000-000000000000", "secret": "secret", "passphrase": "passphrase"}"#,
+        )?;
+        assert_eq!(config.api_key(), Some(Uuid::nil()));
+
+        let config = Config::from_json(r#"{"host": "http://localhost:8080/sign"}"#)?;
+        let Config::Remote { host, token } = config else {
+            panic!("expected a remote configuration");
+        };
+        assert_eq!(host.as_str(), "http://localhost:8080/sign");
+        assert_eq!(token, None);
+
+        Config::from_json(r#"{"host": "not a url"}"#).unwrap_err();
+        Config::from_json(r#"{"key": "00000000-0000-0000-0000-000000000000"}"#).unwrap_err();
+
+        Ok(())
+    }
+
+    fn test_credentials() -> Credentials {
+        Credentials::new(
+            Uuid::nil(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
+            String::new(),
+        )
+    }
+
+    fn expected_signature(message: &str) -> Result<String> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&[0; 32])?;
+        mac.update(message.as_bytes());
+
+        Ok(URL_SAFE.encode(mac.finalize().into_bytes()))
+    }
+
     #[test]
     fn request_args_should_succeed() -> Result<()> {
         let request = Request::new(Method::POST, Url::parse("http://localhost/path")?);
@@ -552,13 +797,38 @@ mod tests {
         let timestamp = 1;
 
         assert_eq!(
-            to_message(&request, timestamp),
-            r#"1POST/path{"foo":"bar"}"#
+            sign(&test_credentials(), &request, timestamp)?,
+            expected_signature(r#"1POST/path{"foo":"bar"}"#)?
         );
 
         Ok(())
     }
 
+    #[test]
+    fn sign_should_normalize_single_quotes() -> Result<()> {
+        let request = Request::new(Method::POST, Url::parse("http://localhost/path")?);
+        let request = RequestBuilder::from_parts(Client::new(), request)
+            .body("{'foo': 'bar'}")
+            .build()?;
+
+        assert_eq!(
+            sign(&test_credentials(), &request, 1)?,
+            expected_signature(r#"1POST/path{"foo": "bar"}"#)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_should_fail_for_streaming_body() -> Result<()> {
+        let mut request = Request::new(Method::POST, Url::parse("http://localhost/path")?);
+        *request.body_mut() = Some(Body::wrap(r#"{"foo":"bar"}"#.to_owned()));
+
+        sign(&test_credentials(), &request, 1).unwrap_err();
+
+        Ok(())
+    }
+
     #[test]
     fn hmac_succeeds() -> Result<()> {
         let json = json!({
@@ -572,14 +842,16 @@ mod tests {
             .json(&json)
             .build()?;
 
-        let message = to_message(&request, 1_000_000);
-        let signature = hmac(
-            &SecretString::from("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned()),
-            &message,
-        )?;
+        let credentials = test_credentials();
+        let signature = sign(&credentials, &request, 1_000_000)?;
 
-        assert_eq!(message, r#"1000000test-sign/orders{"hash":"0x123"}"#);
         assert_eq!(signature, "4gJVbox-R6XlDK4nlaicig0_ANVL1qdcahiL8CXfXLM=");
+        assert_eq!(
+            signature,
+            expected_signature(r#"1000000test-sign/orders{"hash":"0x123"}"#)?
+        );
+        // The cached key must produce identical signatures on subsequent requests
+        assert_eq!(sign(&credentials, &request, 1_000_000)?, signature);
 
         Ok(())
     }
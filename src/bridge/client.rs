@@ -1,13 +1,20 @@
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use bon::bon;
+#[cfg(feature = "polling")]
+use futures::Stream;
+use reqwest::Method;
 use url::Url;
 
+#[cfg(feature = "polling")]
+use super::tracking::{self, TrackConfig};
+#[cfg(feature = "polling")]
+use super::types::DepositTransaction;
 use super::types::{
     DepositRequest, DepositResponse, StatusRequest, StatusResponse, SupportedAssetsResponse,
 };
 use crate::Result;
+use crate::transport::{self, HttpClient};
+
+const DEFAULT_HOST: &str = "https://bridge.polymarket.com";
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -37,16 +44,16 @@ use crate::Result;
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    client: HttpClient,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://bridge.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
+#[bon]
 impl Client {
     /// Creates a new Bridge API client with a custom host.
     ///
@@ -54,17 +61,40 @@ impl Client {
     ///
     /// Returns an error if the host URL is invalid or the HTTP client fails to build.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        Self::builder().host(host).build()
+    }
 
+    /// Returns a builder for a Bridge API client with custom transport options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use polymarket_client_sdk::bridge::Client;
+    /// use polymarket_client_sdk::transport::Config;
+    ///
+    /// let client = Client::builder()
+    ///     .transport(Config::builder().timeout(Duration::from_secs(10)).build())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The base URL for the Bridge API. Defaults to `https://bridge.polymarket.com`.
+        #[builder(default = DEFAULT_HOST)]
+        host: &str,
+        /// Options for the underlying HTTP transport.
+        #[builder(default)]
+        transport: transport::Config,
+    ) -> Result<Client> {
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: transport.build_client()?,
         })
     }
 
@@ -75,7 +105,7 @@ impl Client {
     }
 
     #[must_use]
-    fn client(&self) -> &ReqwestClient {
+    fn client(&self) -> &HttpClient {
         &self.client
     }
 
@@ -188,4 +218,44 @@ impl Client {
 
         crate::request(&self.client, request, None).await
     }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of the deposits to the deposit address in `request`, yielding every
+    /// transaction when it is first seen and whenever its status changes. The status is polled at
+    /// the interval configured in `config`, see [`tracking`] for details.
+    ///
+    /// Failed polls are yielded as errors and retried at the next interval.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn track_deposits<'client>(
+        &'client self,
+        request: &'client StatusRequest,
+        config: TrackConfig,
+    ) -> impl Stream<Item = Result<DepositTransaction>> + 'client {
+        tracking::track(self, request, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Waits until a deposit to the deposit address in `request` completes, and returns its
+    /// transaction. Deposits that had already completed or failed when this was called are
+    /// ignored, so this can be called right after sending funds to the deposit address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new deposit fails, the status cannot be fetched, or, with a
+    /// `timeout` configured in `config`, a [`Timeout`](crate::error::Timeout) error if no
+    /// deposit completes in time.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn wait_for_deposit(
+        &self,
+        request: &StatusRequest,
+        config: TrackConfig,
+    ) -> Result<DepositTransaction> {
+        tracking::wait(self, request, config).await
+    }
 }
@@ -11,6 +11,7 @@
 //! The Bridge API is a read/write HTTP API that provides:
 //! - Deposit address generation for multi-chain asset bridging
 //! - Supported asset and chain information
+//! - Deposit status tracking
 //!
 //! ## Available Endpoints
 //!
@@ -18,6 +19,10 @@
 //! |----------|--------|-------------|
 //! | `/deposit` | POST | Create deposit addresses for a wallet |
 //! | `/supported-assets` | GET | Get supported chains and tokens |
+//! | `/status/{address}` | GET | Get the status of deposits to a deposit address |
+//!
+//! With the `polling` feature, [`Client::track_deposits`] and [`Client::wait_for_deposit`] poll
+//! the status of in-flight deposits, see [`tracking`] for details.
 //!
 //! # Example
 //!
@@ -47,6 +52,8 @@
 //! The default API endpoint is `https://bridge.polymarket.com`.
 
 pub mod client;
+#[cfg(feature = "polling")]
+pub mod tracking;
 pub mod types;
 
 pub use client::Client;
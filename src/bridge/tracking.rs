@@ -0,0 +1,165 @@
+//! Deposit tracking.
+//!
+//! A bridged deposit moves through several [`DepositTransactionStatus`]es, from being detected on
+//! the source chain to being [`Completed`](DepositTransactionStatus::Completed) on Polygon.
+//! [`Client::track_deposits`](crate::bridge::Client::track_deposits) repeatedly polls
+//! [`Client::status`](crate::bridge::Client::status) for a deposit address and yields every
+//! transaction whose status changed, while
+//! [`Client::wait_for_deposit`](crate::bridge::Client::wait_for_deposit) resolves once a new
+//! deposit completes.
+//!
+//! A completed deposit has arrived on Polygon, but the CLOB may not reflect it yet. Together with
+//! the `clob` and `ctf` features, `clob::Client::watch_deposits` detects the balance increase and
+//! refreshes the CLOB's view of the account, after which the funds can be traded.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use async_stream::stream;
+use bon::Builder;
+use futures::Stream;
+use tokio::time::{self, Instant};
+
+use super::Client;
+use super::types::{DepositTransaction, DepositTransactionStatus, StatusRequest};
+use crate::Result;
+use crate::error::Error;
+use crate::types::{ChainId, U256};
+
+/// Configuration for [`Client::track_deposits`](crate::bridge::Client::track_deposits) and
+/// [`Client::wait_for_deposit`](crate::bridge::Client::wait_for_deposit).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct TrackConfig {
+    /// How often the deposit status is polled. The default is ten (10) seconds.
+    #[builder(default = Duration::from_secs(10))]
+    interval: Duration,
+    /// How long [`Client::wait_for_deposit`](crate::bridge::Client::wait_for_deposit) waits for a
+    /// deposit to complete. Waits indefinitely by default.
+    timeout: Option<Duration>,
+}
+
+/// Identifies a transaction across polls. The status endpoint does not return IDs, so
+/// transactions are identified by their hash once it is set. Before that, they are told apart by
+/// their source and, for identical deposits from the same chain and token, the order in which they
+/// are listed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Key {
+    Hash(String),
+    Source {
+        chain_id: ChainId,
+        token: String,
+        amount: U256,
+        occurrence: usize,
+    },
+}
+
+/// Returns the [`Key`] of every transaction in `transactions`, in order.
+fn keys(transactions: &[DepositTransaction]) -> Vec<Key> {
+    let mut occurrences: HashMap<(ChainId, &str, U256), usize> = HashMap::new();
+
+    transactions
+        .iter()
+        .map(|transaction| {
+            if let Some(hash) = &transaction.tx_hash {
+                return Key::Hash(hash.clone());
+            }
+
+            let occurrence = occurrences
+                .entry((
+                    transaction.from_chain_id,
+                    &transaction.from_token_address,
+                    transaction.from_amount_base_unit,
+                ))
+                .or_default();
+            *occurrence += 1;
+
+            Key::Source {
+                chain_id: transaction.from_chain_id,
+                token: transaction.from_token_address.clone(),
+                amount: transaction.from_amount_base_unit,
+                occurrence: *occurrence,
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn track<'client>(
+    client: &'client Client,
+    request: &'client StatusRequest,
+    config: TrackConfig,
+) -> impl Stream<Item = Result<DepositTransaction>> + 'client {
+    stream! {
+        let mut known: HashMap<Key, DepositTransactionStatus> = HashMap::new();
+
+        loop {
+            match client.status(request).await {
+                Ok(response) => {
+                    let keys = keys(&response.transactions);
+                    for (key, transaction) in keys.into_iter().zip(response.transactions) {
+                        let status = transaction.status.clone();
+                        if known.insert(key, status.clone()) != Some(status) {
+                            yield Ok(transaction);
+                        }
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
+
+pub(crate) async fn wait(
+    client: &Client,
+    request: &StatusRequest,
+    config: TrackConfig,
+) -> Result<DepositTransaction> {
+    let deadline = config
+        .timeout
+        .map(|timeout| (Instant::now() + timeout, timeout));
+
+    // Deposits that already completed or failed before waiting started are not awaited
+    let transactions = client.status(request).await?.transactions;
+    let settled: HashSet<Key> = keys(&transactions)
+        .into_iter()
+        .zip(&transactions)
+        .filter(|(_, transaction)| transaction.status.is_terminal())
+        .map(|(key, _)| key)
+        .collect();
+
+    loop {
+        let wake = Instant::now() + config.interval;
+        time::sleep_until(deadline.map_or(wake, |(deadline, _)| wake.min(deadline))).await;
+
+        let transactions = client.status(request).await?.transactions;
+        for (key, transaction) in keys(&transactions).into_iter().zip(transactions) {
+            if settled.contains(&key) {
+                continue;
+            }
+
+            match transaction.status {
+                DepositTransactionStatus::Completed => return Ok(transaction),
+                DepositTransactionStatus::Failed => {
+                    return Err(Error::validation(format!(
+                        "Deposit of {} from chain {} to {} failed",
+                        transaction.from_amount_base_unit,
+                        transaction.from_chain_id,
+                        request.address
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((deadline, timeout)) = deadline
+            && Instant::now() >= deadline
+        {
+            return Err(Error::timeout(
+                timeout,
+                format!("no deposit to {} has completed", request.address),
+            ));
+        }
+    }
+}
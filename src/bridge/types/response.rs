@@ -122,3 +122,11 @@ pub enum DepositTransactionStatus {
     Completed,
     Failed,
 }
+
+impl DepositTransactionStatus {
+    /// Returns whether the deposit has either completed or failed, and will not change further.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
@@ -0,0 +1,380 @@
+//! Order book alerts.
+//!
+//! A [`Monitor`] evaluates the books of a set of tokens against configurable [`Thresholds`]: a
+//! spread wider than `max_spread`, a best bid or ask smaller than `min_top_size`, and an
+//! imbalance between the bid and ask depth beyond `max_imbalance`. It yields an [`Alert`] when a
+//! condition starts to hold for a token and another when it clears, rather than on every book.
+//!
+//! Books can come from either layer:
+//!
+//! - With the `polling` feature, [`Client::watch_book_alerts`](crate::clob::Client::watch_book_alerts)
+//!   fetches the books via [`Client::order_books`](crate::clob::Client::order_books) at an
+//!   interval and feeds them to a monitor.
+//! - With the `ws` feature, [`alert_stream`] feeds the [`BookUpdate`]s of
+//!   [`ws::Client::subscribe_orderbook`](crate::clob::ws::Client::subscribe_orderbook) to a
+//!   monitor.
+//!
+//! A [`Monitor`] can also be fed books manually via [`Monitor::observe`].
+//!
+//! The imbalance is `(bids - asks) / (bids + asks)` over the sizes of the best `depth` levels of
+//! each side, ranging from `-1` (only asks) to `1` (only bids). A one-sided book has no spread and
+//! is treated as wider than any `max_spread`; an empty side has a top size of zero.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+#[cfg(feature = "polling")]
+use std::time::Duration;
+
+#[cfg(feature = "polling")]
+use async_stream::stream;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "ws")]
+use futures::{StreamExt as _, stream};
+#[cfg(any(feature = "polling", feature = "ws"))]
+use {crate::Result, futures::Stream};
+#[cfg(feature = "polling")]
+use {
+    crate::auth::state::State, crate::clob::Client,
+    crate::clob::types::request::OrderBookSummaryRequest, tokio::time,
+};
+
+use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+#[cfg(feature = "ws")]
+use crate::clob::ws::types::response::{BookUpdate, OrderBookLevel};
+use crate::types::{Decimal, TokenId};
+
+/// The conditions a [`Monitor`] checks. Conditions left unset are not checked.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Thresholds {
+    /// The spread above which [`Condition::WideSpread`] holds.
+    max_spread: Option<Decimal>,
+    /// The size of the best bid or ask below which [`Condition::ThinBid`] or
+    /// [`Condition::ThinAsk`] holds.
+    min_top_size: Option<Decimal>,
+    /// The absolute imbalance, between `0` and `1`, above which [`Condition::Imbalance`] holds.
+    max_imbalance: Option<Decimal>,
+    /// The number of levels per side the imbalance is computed over. The default is five (5).
+    #[builder(default = 5)]
+    depth: usize,
+}
+
+/// A condition checked by a [`Monitor`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Condition {
+    /// The spread is wider than [`Thresholds`]' `max_spread`, or one side of the book is empty.
+    WideSpread,
+    /// The best bid is smaller than [`Thresholds`]' `min_top_size`, or there are no bids.
+    ThinBid,
+    /// The best ask is smaller than [`Thresholds`]' `min_top_size`, or there are no asks.
+    ThinAsk,
+    /// The imbalance is beyond [`Thresholds`]' `max_imbalance` in either direction.
+    Imbalance,
+}
+
+/// A condition that started or stopped holding for a token.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alert {
+    /// The token whose book triggered the alert.
+    pub asset_id: TokenId,
+    /// The condition that changed.
+    pub condition: Condition,
+    /// Whether the condition started (`true`) or stopped (`false`) holding.
+    pub triggered: bool,
+    /// The measured value: the spread, the top size or the imbalance. `None` for the spread of a
+    /// one-sided book and the imbalance of an empty book.
+    pub value: Option<Decimal>,
+    /// The timestamp of the book.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Evaluates books against [`Thresholds`], see the [module docs](self).
+#[derive(Debug)]
+pub struct Monitor {
+    thresholds: Thresholds,
+    /// The conditions currently holding, per token.
+    active: HashSet<(TokenId, Condition)>,
+}
+
+impl Monitor {
+    /// Creates a monitor checking `thresholds`, for which no condition holds yet.
+    #[must_use]
+    pub fn new(thresholds: Thresholds) -> Self {
+        Self {
+            thresholds,
+            active: HashSet::new(),
+        }
+    }
+
+    /// Returns whether `condition` currently holds for `asset_id`.
+    #[must_use]
+    pub fn is_active(&self, asset_id: TokenId, condition: Condition) -> bool {
+        self.active.contains(&(asset_id, condition))
+    }
+
+    /// Evaluates `book` and returns the conditions that started or stopped holding since the
+    /// previous book of the same token.
+    pub fn observe(&mut self, book: &OrderBookSummaryResponse) -> Vec<Alert> {
+        let level = |level: &OrderSummary| (level.price, level.size);
+        self.evaluate(
+            book.asset_id,
+            book.timestamp,
+            book.bids.iter().map(level).collect(),
+            book.asks.iter().map(level).collect(),
+        )
+    }
+
+    /// Evaluates the book of `update` like [`Self::observe`].
+    ///
+    /// # Note
+    ///
+    /// Requires the `ws` feature to be enabled.
+    #[cfg(feature = "ws")]
+    pub fn observe_update(&mut self, update: &BookUpdate) -> Vec<Alert> {
+        let level = |level: &OrderBookLevel| (level.price, level.size);
+        self.evaluate(
+            update.asset_id,
+            DateTime::from_timestamp_millis(update.timestamp).unwrap_or_default(),
+            update.bids.iter().map(level).collect(),
+            update.asks.iter().map(level).collect(),
+        )
+    }
+
+    fn evaluate(
+        &mut self,
+        asset_id: TokenId,
+        timestamp: DateTime<Utc>,
+        mut bids: Vec<(Decimal, Decimal)>,
+        mut asks: Vec<(Decimal, Decimal)>,
+    ) -> Vec<Alert> {
+        // Best levels first, regardless of the order the book was delivered in
+        bids.sort_by_key(|(price, _)| Reverse(*price));
+        asks.sort_by_key(|(price, _)| *price);
+
+        let thresholds = self.thresholds;
+        let mut checks = Vec::new();
+        if let Some(max_spread) = thresholds.max_spread {
+            let spread = bids
+                .first()
+                .zip(asks.first())
+                .map(|(bid, ask)| (ask.0 - bid.0).normalize());
+            checks.push((
+                Condition::WideSpread,
+                spread.is_none_or(|spread| spread > max_spread),
+                spread,
+            ));
+        }
+        if let Some(min_top_size) = thresholds.min_top_size {
+            for (condition, levels) in [(Condition::ThinBid, &bids), (Condition::ThinAsk, &asks)] {
+                let size = levels.first().map_or(Decimal::ZERO, |level| level.1);
+                checks.push((condition, size < min_top_size, Some(size)));
+            }
+        }
+        if let Some(max_imbalance) = thresholds.max_imbalance {
+            let depth = |levels: &[(Decimal, Decimal)]| -> Decimal {
+                levels
+                    .iter()
+                    .take(thresholds.depth)
+                    .map(|level| level.1)
+                    .sum()
+            };
+            let (bid_depth, ask_depth) = (depth(&bids), depth(&asks));
+            let total = bid_depth + ask_depth;
+            let imbalance =
+                (!total.is_zero()).then(|| ((bid_depth - ask_depth) / total).normalize());
+            checks.push((
+                Condition::Imbalance,
+                imbalance.is_some_and(|imbalance| imbalance.abs() > max_imbalance),
+                imbalance,
+            ));
+        }
+
+        checks
+            .into_iter()
+            .filter_map(|(condition, holds, value)| {
+                let changed = if holds {
+                    self.active.insert((asset_id, condition))
+                } else {
+                    self.active.remove(&(asset_id, condition))
+                };
+
+                changed.then_some(Alert {
+                    asset_id,
+                    condition,
+                    triggered: holds,
+                    value,
+                    timestamp,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Configuration for [`Client::watch_book_alerts`](crate::clob::Client::watch_book_alerts).
+#[cfg(feature = "polling")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct WatchConfig {
+    /// The tokens whose books are watched.
+    token_ids: Vec<TokenId>,
+    /// The conditions to check.
+    thresholds: Thresholds,
+    /// How often the books are fetched. The default is five (5) seconds.
+    #[builder(default = Duration::from_secs(5))]
+    interval: Duration,
+}
+
+#[cfg(feature = "polling")]
+pub(crate) fn watch<S: State>(
+    client: &Client<S>,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<Alert>> + '_ {
+    stream! {
+        let mut monitor = Monitor::new(config.thresholds);
+        let requests: Vec<OrderBookSummaryRequest> = config
+            .token_ids
+            .iter()
+            .map(|token_id| OrderBookSummaryRequest::builder().token_id(*token_id).build())
+            .collect();
+        if requests.is_empty() {
+            return;
+        }
+
+        loop {
+            match client.order_books(&requests).await {
+                Ok(books) => {
+                    for book in &books {
+                        for alert in monitor.observe(book) {
+                            yield Ok(alert);
+                        }
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
+
+/// Feeds the book updates of `updates`, e.g. from
+/// [`ws::Client::subscribe_orderbook`](crate::clob::ws::Client::subscribe_orderbook), to a
+/// [`Monitor`] with `thresholds` and returns a stream of its alerts. Errors of `updates` are
+/// passed through.
+///
+/// # Note
+///
+/// Requires the `ws` feature to be enabled.
+#[cfg(feature = "ws")]
+pub fn alert_stream<U>(updates: U, thresholds: Thresholds) -> impl Stream<Item = Result<Alert>>
+where
+    U: Stream<Item = Result<BookUpdate>>,
+{
+    let mut monitor = Monitor::new(thresholds);
+    updates.flat_map(move |update| {
+        let alerts = match update {
+            Ok(update) => monitor
+                .observe_update(&update)
+                .into_iter()
+                .map(Ok)
+                .collect(),
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(alerts)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::types::TickSize;
+    use crate::types::B256;
+
+    fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBookSummaryResponse {
+        let levels = |levels: &[(Decimal, Decimal)]| {
+            levels
+                .iter()
+                .map(|&(price, size)| OrderSummary::builder().price(price).size(size).build())
+                .collect::<Vec<_>>()
+        };
+
+        OrderBookSummaryResponse::builder()
+            .market(B256::ZERO)
+            .asset_id(TokenId::from(crate::types::U256::from(1)))
+            .timestamp(DateTime::UNIX_EPOCH)
+            .bids(levels(bids))
+            .asks(levels(asks))
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .tick_size(TickSize::Hundredth)
+            .build()
+    }
+
+    #[test]
+    fn observe_should_alert_on_transitions_only() {
+        let mut monitor = Monitor::new(Thresholds::builder().max_spread(dec!(0.05)).build());
+
+        let alerts = monitor.observe(&book(&[(dec!(0.40), dec!(10))], &[(dec!(0.50), dec!(10))]));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].condition, Condition::WideSpread);
+        assert!(alerts[0].triggered);
+        assert_eq!(alerts[0].value, Some(dec!(0.1)));
+
+        // Still wide, so no new alert
+        let alerts = monitor.observe(&book(&[(dec!(0.41), dec!(10))], &[(dec!(0.50), dec!(10))]));
+        assert!(alerts.is_empty());
+
+        let alerts = monitor.observe(&book(&[(dec!(0.48), dec!(10))], &[(dec!(0.50), dec!(10))]));
+        assert_eq!(alerts.len(), 1);
+        assert!(!alerts[0].triggered);
+        assert!(!monitor.is_active(alerts[0].asset_id, Condition::WideSpread));
+    }
+
+    #[test]
+    fn observe_should_check_top_size_and_imbalance() {
+        let thresholds = Thresholds::builder()
+            .min_top_size(dec!(5))
+            .max_imbalance(dec!(0.5))
+            .depth(2)
+            .build();
+        let mut monitor = Monitor::new(thresholds);
+
+        // Levels in CLOB order, best price last; the third bid level is beyond the depth
+        let alerts = monitor.observe(&book(
+            &[
+                (dec!(0.30), dec!(100)),
+                (dec!(0.40), dec!(50)),
+                (dec!(0.45), dec!(40)),
+            ],
+            &[(dec!(0.55), dec!(5)), (dec!(0.50), dec!(3))],
+        ));
+
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].condition, Condition::ThinAsk);
+        assert_eq!(alerts[0].value, Some(dec!(3)));
+        assert_eq!(alerts[1].condition, Condition::Imbalance);
+        // (90 - 8) / 98
+        assert_eq!(alerts[1].value, Some((dec!(82) / dec!(98)).normalize()));
+    }
+
+    #[test]
+    fn observe_should_treat_one_sided_books_as_wide_and_thin() {
+        let thresholds = Thresholds::builder()
+            .max_spread(dec!(0.05))
+            .min_top_size(dec!(1))
+            .build();
+        let mut monitor = Monitor::new(thresholds);
+
+        let alerts = monitor.observe(&book(&[(dec!(0.40), dec!(10))], &[]));
+        let conditions: Vec<_> = alerts.iter().map(|alert| alert.condition).collect();
+
+        assert_eq!(conditions, vec![Condition::WideSpread, Condition::ThinAsk]);
+        assert_eq!(alerts[0].value, None);
+        assert_eq!(alerts[1].value, Some(Decimal::ZERO));
+    }
+}
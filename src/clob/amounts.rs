@@ -0,0 +1,311 @@
+//! Maker and taker amounts of orders.
+//!
+//! An [`Order`] does not carry a price or a size: it is signed with a `makerAmount` the maker
+//! gives up and a `takerAmount` it receives, both in base units with six decimals. A buy gives
+//! up USDC for shares, and a sell gives up shares for USDC.
+//!
+//! [`Amounts`] computes these the same way the [`OrderBuilder`](crate::clob::order_builder::OrderBuilder)
+//! does, so that risk engines and databases can reproduce the exact amounts of an order, and
+//! converts them back into shares, USDC and a price. The notional value is truncated to the
+//! combined precision of the tick size and the lot size, so that orders snap to the precision of
+//! resting orders on the book.
+//!
+//! ```rust
+//! use alloy::primitives::U256;
+//! use polymarket_client_sdk::clob::amounts::Amounts;
+//! use polymarket_client_sdk::clob::types::{Price, Side, TickSize};
+//! use rust_decimal_macros::dec;
+//!
+//! # fn main() -> polymarket_client_sdk::Result<()> {
+//! // Buying 100 shares at 0.34 gives up 34 USDC
+//! let price = Price::new(dec!(0.34), TickSize::Hundredth)?;
+//! let amounts = Amounts::limit(Side::Buy, price, dec!(100))?;
+//! assert_eq!(amounts.maker, U256::from(34_000_000));
+//! assert_eq!(amounts.taker, U256::from(100_000_000));
+//!
+//! assert_eq!(amounts.price(Side::Buy)?, dec!(0.34));
+//! # Ok(())
+//! # }
+//! ```
+
+use alloy::primitives::U256;
+use rust_decimal::prelude::ToPrimitive as _;
+
+use crate::Result;
+use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
+use crate::clob::types::{Amount, AmountInner, Order, Price, Side};
+use crate::error::Error;
+use crate::types::{Decimal, Shares, Usdc};
+
+/// The maker and taker amounts of an order, in base units.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Amounts {
+    /// The amount the maker gives up: USDC for buys and shares for sells.
+    pub maker: U256,
+    /// The amount the maker receives: shares for buys and USDC for sells.
+    pub taker: U256,
+}
+
+impl Amounts {
+    /// Returns the amounts of a limit order for `size` shares at `price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `size` is not positive or has more than two decimal places, or if
+    /// `side` is unknown.
+    pub fn limit(side: Side, price: Price, size: Decimal) -> Result<Self> {
+        if size.normalize().scale() > LOT_SIZE_SCALE {
+            return Err(Error::validation(format!(
+                "Unable to compute amounts: Size {size} has {} decimal places. Maximum lot size is {LOT_SIZE_SCALE}",
+                size.normalize().scale()
+            )));
+        }
+        if size <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Unable to compute amounts for non-positive size {size}"
+            )));
+        }
+
+        let notional = notional(size * price.as_decimal(), price);
+        match side {
+            Side::Buy => Self::from_decimals(notional, size),
+            Side::Sell => Self::from_decimals(size, notional),
+            side => Err(Error::validation(format!("Invalid side: {side}"))),
+        }
+    }
+
+    /// Returns the amounts of a market order for `amount` at `price`. Buys may be given in USDC
+    /// or shares, sells only in shares.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is not positive, if a sell is given in USDC, or if `side` is
+    /// unknown.
+    pub fn market(side: Side, price: Price, amount: Amount) -> Result<Self> {
+        let raw_amount = amount.as_inner();
+        if raw_amount <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Unable to compute amounts for non-positive amount {raw_amount}"
+            )));
+        }
+
+        match (side, amount.0) {
+            // Spend USDC to buy shares
+            (Side::Buy, AmountInner::Usdc(_)) => {
+                Self::from_decimals(raw_amount, notional(raw_amount / price.as_decimal(), price))
+            }
+            // Buy N shares
+            (Side::Buy, AmountInner::Shares(_)) => {
+                Self::from_decimals(notional(raw_amount * price.as_decimal(), price), raw_amount)
+            }
+            // Sell N shares for USDC
+            (Side::Sell, AmountInner::Shares(_)) => {
+                Self::from_decimals(raw_amount, notional(raw_amount * price.as_decimal(), price))
+            }
+            (Side::Sell, AmountInner::Usdc(_)) => Err(Error::validation(
+                "Sell Orders must specify their `amount`s in shares",
+            )),
+            (side, _) => Err(Error::validation(format!("Invalid side: {side}"))),
+        }
+    }
+
+    /// Returns the amounts of a built order.
+    #[must_use]
+    pub fn of(order: &Order) -> Self {
+        Self {
+            maker: order.makerAmount,
+            taker: order.takerAmount,
+        }
+    }
+
+    /// Returns the shares bought or sold by an order on `side` with these amounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is unknown or the amount is too large.
+    pub fn shares(&self, side: Side) -> Result<Shares> {
+        Shares::from_base_units(self.split(side)?.0)
+    }
+
+    /// Returns the USDC paid or received by an order on `side` with these amounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is unknown or the amount is too large.
+    pub fn usdc(&self, side: Side) -> Result<Usdc> {
+        Usdc::from_base_units(self.split(side)?.1)
+    }
+
+    /// Returns the price per share implied by these amounts, i.e. the USDC over the shares. For
+    /// limit orders this is exactly the price they were computed at, while market orders may
+    /// imply a slightly better price due to the truncation of the notional value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is unknown, an amount is too large, or there are no shares.
+    pub fn price(&self, side: Side) -> Result<Decimal> {
+        let shares = self.shares(side)?.as_decimal();
+        if shares.is_zero() {
+            return Err(Error::validation(
+                "Unable to compute the price of an order without shares",
+            ));
+        }
+
+        Ok((self.usdc(side)?.as_decimal() / shares).normalize())
+    }
+
+    /// Returns the shares and USDC base units for an order on `side`.
+    fn split(&self, side: Side) -> Result<(U256, U256)> {
+        match side {
+            Side::Buy => Ok((self.taker, self.maker)),
+            Side::Sell => Ok((self.maker, self.taker)),
+            side => Err(Error::validation(format!("Invalid side: {side}"))),
+        }
+    }
+
+    fn from_decimals(maker: Decimal, taker: Decimal) -> Result<Self> {
+        Ok(Self {
+            maker: U256::from(to_fixed_u128(maker)?),
+            taker: U256::from(to_fixed_u128(taker)?),
+        })
+    }
+}
+
+/// Truncates a notional value to the combined precision of the tick size of `price` and the lot
+/// size.
+fn notional(value: Decimal, price: Price) -> Decimal {
+    value.trunc_with_scale(price.tick_size().as_decimal().scale() + LOT_SIZE_SCALE)
+}
+
+/// Removes trailing zeros, truncates to [`USDC_DECIMALS`] decimal places, and quantizes as an
+/// integer.
+pub(crate) fn to_fixed_u128(d: Decimal) -> Result<u128> {
+    d.normalize()
+        .trunc_with_scale(USDC_DECIMALS)
+        .mantissa()
+        .to_u128()
+        .ok_or_else(|| Error::validation(format!("Unable to represent {d} in base units")))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::types::TickSize;
+
+    const TICK_SIZES: [TickSize; 4] = [
+        TickSize::Tenth,
+        TickSize::Hundredth,
+        TickSize::Thousandth,
+        TickSize::TenThousandth,
+    ];
+
+    /// A price on the tick grid of one of [`TICK_SIZES`], chosen by `tick` and `index`.
+    fn price(tick: usize, index: u32) -> Price {
+        let tick_size = TICK_SIZES[tick];
+        let ticks = (Decimal::ONE / tick_size.as_decimal())
+            .to_u32()
+            .expect("small number of ticks");
+        let value = tick_size.as_decimal() * Decimal::from(1 + index % (ticks - 1));
+
+        Price::new(value, tick_size).expect("price on the tick grid")
+    }
+
+    #[test]
+    fn to_fixed_u128_should_succeed() -> Result<()> {
+        assert_eq!(to_fixed_u128(dec!(123.456))?, 123_456_000);
+        assert_eq!(to_fixed_u128(dec!(123.456789))?, 123_456_789);
+        assert_eq!(to_fixed_u128(dec!(123.456789111111111))?, 123_456_789);
+        assert_eq!(to_fixed_u128(dec!(3.456789111111111))?, 3_456_789);
+        assert_eq!(to_fixed_u128(Decimal::ZERO)?, 0);
+        to_fixed_u128(dec!(-1)).unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn amounts_should_match_examples() -> Result<()> {
+        let price = Price::new(dec!(0.34), TickSize::Hundredth)?;
+
+        let amounts = Amounts::market(Side::Buy, price, Amount::usdc(dec!(100))?)?;
+        assert_eq!(amounts.maker, U256::from(100_000_000));
+        assert_eq!(amounts.taker, U256::from(294_117_600));
+
+        let amounts = Amounts::market(Side::Sell, price, Amount::shares(dec!(100))?)?;
+        assert_eq!(amounts.maker, U256::from(100_000_000));
+        assert_eq!(amounts.taker, U256::from(34_000_000));
+        assert_eq!(amounts.usdc(Side::Sell)?, Usdc::new(dec!(34))?);
+
+        Amounts::market(Side::Sell, price, Amount::usdc(dec!(100))?).unwrap_err();
+        Amounts::limit(Side::Buy, price, dec!(1.234)).unwrap_err();
+        Amounts::limit(Side::Buy, price, Decimal::ZERO).unwrap_err();
+        Amounts::default().price(Side::Buy).unwrap_err();
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn limit_amounts_should_round_trip(
+            tick in 0..TICK_SIZES.len(),
+            index: u32,
+            cents in 1_u64..1_000_000_000,
+            buy: bool,
+        ) {
+            let side = if buy { Side::Buy } else { Side::Sell };
+            let price = price(tick, index);
+            let size = Decimal::new(i64::try_from(cents).expect("small size"), LOT_SIZE_SCALE);
+
+            let amounts = Amounts::limit(side, price, size)?;
+
+            prop_assert_eq!(amounts.shares(side)?.as_decimal(), size.normalize());
+            prop_assert_eq!(amounts.price(side)?, price.as_decimal().normalize());
+            prop_assert_eq!(
+                amounts.usdc(side)?.as_decimal(),
+                (size * price.as_decimal()).normalize()
+            );
+        }
+
+        #[test]
+        fn market_buy_amounts_should_never_overspend(
+            tick in 0..TICK_SIZES.len(),
+            index: u32,
+            micros in 1_u64..1_000_000_000_000,
+        ) {
+            let price = price(tick, index);
+            let usdc = Decimal::new(i64::try_from(micros).expect("small amount"), USDC_DECIMALS);
+
+            let amounts = Amounts::market(Side::Buy, price, Amount::usdc(usdc)?)?;
+
+            // The full amount is spent on at most as many shares as it buys at `price`, short
+            // of it by less than the notional precision
+            let precision = Decimal::new(1, price.tick_size().as_decimal().scale() + LOT_SIZE_SCALE);
+            let exact = usdc / price.as_decimal();
+            let shares = amounts.shares(Side::Buy)?.as_decimal();
+            prop_assert_eq!(amounts.usdc(Side::Buy)?.as_decimal(), usdc.normalize());
+            prop_assert!(shares <= exact);
+            prop_assert!(exact - shares < precision);
+            prop_assert!(amounts.price(Side::Buy)? >= price.as_decimal());
+        }
+
+        #[test]
+        fn market_share_amounts_should_match_limit_amounts(
+            tick in 0..TICK_SIZES.len(),
+            index: u32,
+            cents in 1_u64..1_000_000_000,
+            buy: bool,
+        ) {
+            let side = if buy { Side::Buy } else { Side::Sell };
+            let price = price(tick, index);
+            let size = Decimal::new(i64::try_from(cents).expect("small size"), LOT_SIZE_SCALE);
+
+            prop_assert_eq!(
+                Amounts::market(side, price, Amount::shares(size)?)?,
+                Amounts::limit(side, price, size)?
+            );
+        }
+    }
+}
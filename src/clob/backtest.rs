@@ -0,0 +1,472 @@
+//! Backtesting on historical prices.
+//!
+//! [`run`] replays the points of a [`PriceHistoryResponse`](crate::clob::types::response::PriceHistoryResponse),
+//! as returned by [`Client::price_history`](crate::clob::Client::price_history), through the same
+//! [`Strategy`] that a live [`Runner`](crate::clob::strategy::Runner) drives, calling
+//! [`Strategy::on_price`] for every point. Orders the strategy posts via
+//! [`Context::post_order`](crate::clob::strategy::Context::post_order) are filled immediately at
+//! the current price, adjusted by the configured slippage, if that is within the order's price, and
+//! the resulting [`Fill`]s, the profit and loss curve and a [`Summary`] are collected into a
+//! [`Report`].
+//!
+//! There is no client in a backtest, so [`Context::client`](crate::clob::strategy::Context::client)
+//! returns `None`; orders can be built with [`order`] instead, and the simulated position is
+//! available from [`Context::simulated_position`](crate::clob::strategy::Context::simulated_position).
+//!
+//! The simulation covers a single outcome token. Positions cannot go short, so sells are capped
+//! at the size currently held. Price history carries no depth, so fills are never partial, and
+//! orders that cannot be filled immediately are not kept.
+//!
+//! ```rust
+//! use async_trait::async_trait;
+//! use polymarket_client_sdk::Result;
+//! use polymarket_client_sdk::clob::backtest::{self, Config};
+//! use polymarket_client_sdk::clob::strategy::{Context, Price, Strategy};
+//! use polymarket_client_sdk::clob::types::Side;
+//! use polymarket_client_sdk::clob::types::response::PricePoint;
+//! use rust_decimal_macros::dec;
+//!
+//! // Buy 10 shares whenever the price drops below 0.4, sell everything above 0.5
+//! struct Swing;
+//!
+//! #[async_trait]
+//! impl Strategy for Swing {
+//!     async fn on_price(&mut self, ctx: &Context<'_>, price: &Price) -> Result<()> {
+//!         let held = ctx.simulated_position().map(|position| position.size).unwrap_or_default();
+//!         if price.price < dec!(0.4) {
+//!             ctx.post_order(backtest::order(Side::Buy, dec!(10), dec!(0.4))?).await?;
+//!         } else if price.price > dec!(0.5) && !held.is_zero() {
+//!             ctx.post_order(backtest::order(Side::Sell, held, dec!(0.5))?).await?;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<()> {
+//! let history: Vec<PricePoint> = [dec!(0.40), dec!(0.35), dec!(0.55)]
+//!     .into_iter()
+//!     .enumerate()
+//!     .map(|(t, p)| PricePoint::builder().t(t as i64).p(p).build())
+//!     .collect();
+//!
+//! let report = backtest::run(&mut Swing, &history, &Config::builder().build()).await?;
+//! assert_eq!(report.summary.total_pnl, dec!(2));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use bon::Builder;
+use chrono::DateTime;
+use tokio::sync::broadcast;
+
+use crate::Result;
+use crate::auth::ApiKey;
+use crate::clob::strategy::{Context, Price, Strategy};
+use crate::clob::types::response::{PostOrderResponse, PricePoint};
+use crate::clob::types::{Order, OrderStatusType, OrderType, Side, SignedOrder};
+use crate::error::Error;
+use crate::types::{Decimal, Shares, Signature, TokenId, U256, Usdc};
+
+/// Configuration for [`run`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Config {
+    /// The token the price history is for, passed to the strategy with every [`Price`]. The
+    /// default is the zero token ID.
+    #[builder(default)]
+    token_id: TokenId,
+    /// The fee charged on every fill, in basis points of its notional. The default is zero (0).
+    #[builder(default)]
+    fee_rate_bps: u32,
+    /// How far from the current price every fill is executed, against the strategy. The default
+    /// is zero (0).
+    #[builder(default)]
+    slippage: Decimal,
+}
+
+/// The position held in the simulated token.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Position {
+    /// The number of shares held.
+    pub size: Decimal,
+    /// The average price paid for the shares held.
+    pub average_price: Decimal,
+    /// The profit and loss realized so far, net of fees.
+    pub realized_pnl: Decimal,
+}
+
+impl Position {
+    /// Returns the profit and loss not yet realized if the position were valued at `price`.
+    #[must_use]
+    pub fn unrealized_pnl(&self, price: Decimal) -> Decimal {
+        (price - self.average_price) * self.size
+    }
+
+    fn apply(&mut self, side: Side, price: Decimal, size: Decimal, fee: Decimal) {
+        match side {
+            Side::Buy => {
+                let total = self.size + size;
+                self.average_price = (self.average_price * self.size + price * size) / total;
+                self.size = total;
+            }
+            Side::Sell => {
+                self.realized_pnl += (price - self.average_price) * size;
+                self.size -= size;
+                if self.size.is_zero() {
+                    self.average_price = Decimal::ZERO;
+                }
+            }
+            Side::Unknown => return,
+        }
+        self.realized_pnl -= fee;
+    }
+}
+
+/// A simulated fill.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    /// The timestamp of the price point the fill happened at.
+    pub timestamp: i64,
+    /// Whether shares were bought or sold.
+    pub side: Side,
+    /// The execution price, including slippage.
+    pub price: Decimal,
+    /// The number of shares.
+    pub size: Decimal,
+    /// The fee charged, in USDC.
+    pub fee: Decimal,
+}
+
+/// The total profit and loss after a price point.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PnlPoint {
+    /// The timestamp of the price point.
+    pub timestamp: i64,
+    /// The realized and unrealized profit and loss, net of fees.
+    pub pnl: Decimal,
+}
+
+/// Summary statistics of a backtest.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    /// The realized and unrealized profit and loss at the last price point, net of fees.
+    pub total_pnl: Decimal,
+    /// The realized profit and loss, net of fees.
+    pub realized_pnl: Decimal,
+    /// The profit and loss of the position still held, valued at the last price.
+    pub unrealized_pnl: Decimal,
+    /// The total fees paid.
+    pub fees: Decimal,
+    /// The total notional traded.
+    pub volume: Decimal,
+    /// The number of fills.
+    pub trades: usize,
+    /// The largest decline of the profit and loss curve from a preceding peak.
+    pub max_drawdown: Decimal,
+}
+
+/// The outcome of a backtest.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Every simulated fill, in order.
+    pub fills: Vec<Fill>,
+    /// The profit and loss after every price point.
+    pub pnl: Vec<PnlPoint>,
+    /// The position held after the last price point.
+    pub position: Position,
+    /// Summary statistics.
+    pub summary: Summary,
+}
+
+/// The state of a running backtest, which the [`Context`] posts orders to.
+#[derive(Debug)]
+pub(crate) struct Simulation {
+    config: Config,
+    /// The price point orders are currently filled at
+    point: Option<PricePoint>,
+    /// How many orders were posted, to give each a unique ID
+    orders: u64,
+    report: Report,
+}
+
+impl Simulation {
+    /// Returns the position held.
+    pub(crate) fn position(&self) -> Position {
+        self.report.position.clone()
+    }
+
+    /// Fills `order` at the current price point, if it is within the order's price.
+    pub(crate) fn post(&mut self, order: &SignedOrder) -> Result<PostOrderResponse> {
+        let point = self
+            .point
+            .as_ref()
+            .ok_or_else(|| Error::validation("Unable to fill orders before the first price"))?;
+        let side = Side::try_from(order.order.side)?;
+        let (usdc, shares) = match side {
+            Side::Buy => (order.order.makerAmount, order.order.takerAmount),
+            _ => (order.order.takerAmount, order.order.makerAmount),
+        };
+        let size = Shares::from_base_units(shares)?.as_decimal();
+        let limit = (!size.is_zero())
+            .then(|| Usdc::from_base_units(usdc))
+            .transpose()?;
+        let limit = limit.map(|usdc| usdc.as_decimal() / size);
+
+        self.orders += 1;
+        let response = PostOrderResponse::builder()
+            .order_id(format!("backtest-{}", self.orders))
+            .success(true);
+
+        let Some(fill) = execute(
+            side,
+            size,
+            limit,
+            point,
+            &self.report.position,
+            &self.config,
+        ) else {
+            return Ok(response
+                .making_amount(Decimal::ZERO)
+                .taking_amount(Decimal::ZERO)
+                .status(OrderStatusType::Unmatched)
+                .build());
+        };
+
+        let notional = fill.price * fill.size;
+        let (making_amount, taking_amount) = match side {
+            Side::Buy => (notional, fill.size),
+            _ => (fill.size, notional),
+        };
+        self.report
+            .position
+            .apply(fill.side, fill.price, fill.size, fill.fee);
+        self.report.summary.fees += fill.fee;
+        self.report.summary.volume += notional;
+        self.report.fills.push(fill);
+
+        Ok(response
+            .making_amount(making_amount)
+            .taking_amount(taking_amount)
+            .status(OrderStatusType::Matched)
+            .build())
+    }
+}
+
+/// Builds an unsigned order to buy or sell `size` shares at `price` or better, for a strategy to
+/// post via [`Context::post_order`] during a backtest.
+///
+/// # Errors
+///
+/// Returns an error if `size` or its notional at `price` cannot be represented on chain.
+pub fn order(side: Side, size: Decimal, price: Decimal) -> Result<SignedOrder> {
+    let shares = Shares::new(size)?.to_base_units();
+    let usdc = Usdc::new((size * price).round_dp(Usdc::DECIMALS))?.to_base_units();
+    let (maker_amount, taker_amount) = match side {
+        Side::Buy => (usdc, shares),
+        _ => (shares, usdc),
+    };
+
+    Ok(SignedOrder::builder()
+        .order(Order {
+            side: side as u8,
+            makerAmount: maker_amount,
+            takerAmount: taker_amount,
+            ..Order::default()
+        })
+        .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+        .order_type(OrderType::FOK)
+        .owner(ApiKey::nil())
+        .build())
+}
+
+/// Replays `history` through `strategy`, until it calls [`Context::stop`]. Points are expected in
+/// chronological order, as returned by
+/// [`Client::price_history`](crate::clob::Client::price_history).
+///
+/// # Errors
+///
+/// Returns the first error returned by `strategy`.
+pub async fn run<S: Strategy + ?Sized>(
+    strategy: &mut S,
+    history: &[PricePoint],
+    config: &Config,
+) -> Result<Report> {
+    let simulation = Mutex::new(Simulation {
+        config: config.clone(),
+        point: None,
+        orders: 0,
+        report: Report::default(),
+    });
+    let (events, _) = broadcast::channel(1);
+    let stopped = AtomicBool::new(false);
+    let ctx = Context::simulated(&simulation, &events, &stopped);
+    let lock = || simulation.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let mut peak = Decimal::ZERO;
+    let mut last_price = Decimal::ZERO;
+    for point in history {
+        lock().point = Some(point.clone());
+        last_price = point.p;
+
+        let price = Price {
+            token_id: config.token_id,
+            price: point.p,
+            timestamp: DateTime::from_timestamp(point.t, 0).unwrap_or_default(),
+        };
+        strategy.on_price(&ctx, &price).await?;
+
+        let mut simulation = lock();
+        let report = &mut simulation.report;
+        let pnl = report.position.realized_pnl + report.position.unrealized_pnl(point.p);
+        peak = peak.max(pnl);
+        report.summary.max_drawdown = report.summary.max_drawdown.max(peak - pnl);
+        report.pnl.push(PnlPoint {
+            timestamp: point.t,
+            pnl,
+        });
+        drop(simulation);
+
+        if stopped.load(Ordering::Relaxed) {
+            break;
+        }
+    }
+
+    let mut report = simulation
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner)
+        .report;
+    report.summary.realized_pnl = report.position.realized_pnl;
+    report.summary.unrealized_pnl = report.position.unrealized_pnl(last_price);
+    report.summary.total_pnl = report.summary.realized_pnl + report.summary.unrealized_pnl;
+    report.summary.trades = report.fills.len();
+
+    Ok(report)
+}
+
+/// Fills `size` shares on `side` at `point`, or returns `None` if they cannot be filled within
+/// `limit`.
+fn execute(
+    side: Side,
+    size: Decimal,
+    limit: Option<Decimal>,
+    point: &PricePoint,
+    position: &Position,
+    config: &Config,
+) -> Option<Fill> {
+    let (price, size) = match side {
+        Side::Buy => ((point.p + config.slippage).min(Decimal::ONE), size),
+        Side::Sell => (
+            (point.p - config.slippage).max(Decimal::ZERO),
+            size.min(position.size),
+        ),
+        Side::Unknown => return None,
+    };
+
+    let within_limit = limit.is_none_or(|limit| match side {
+        Side::Buy => price <= limit,
+        _ => price >= limit,
+    });
+    if size <= Decimal::ZERO || !within_limit {
+        return None;
+    }
+
+    Some(Fill {
+        timestamp: point.t,
+        side,
+        price,
+        size,
+        fee: price * size * Decimal::from(config.fee_rate_bps) / Decimal::from(10_000),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn history(prices: &[Decimal]) -> Vec<PricePoint> {
+        prices
+            .iter()
+            .zip(0..)
+            .map(|(p, t)| PricePoint::builder().t(t).p(*p).build())
+            .collect()
+    }
+
+    /// Posts the orders scheduled for every point, by its timestamp.
+    struct Scripted(Vec<(i64, SignedOrder)>);
+
+    #[async_trait]
+    impl Strategy for Scripted {
+        async fn on_price(&mut self, ctx: &Context<'_>, price: &Price) -> Result<()> {
+            let (due, later) = std::mem::take(&mut self.0)
+                .into_iter()
+                .partition(|(t, _)| *t == price.timestamp.timestamp());
+            self.0 = later;
+            for (_, order) in due {
+                ctx.post_order(order).await?;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn run_should_track_pnl_and_drawdown() -> Result<()> {
+        let history = history(&[dec!(0.5), dec!(0.3), dec!(0.6), dec!(0.4)]);
+        let mut strategy = Scripted(vec![
+            (0, order(Side::Buy, dec!(10), Decimal::ONE)?),
+            (2, order(Side::Sell, dec!(5), Decimal::ZERO)?),
+        ]);
+
+        let report = run(&mut strategy, &history, &Config::default()).await?;
+
+        let pnl: Vec<_> = report.pnl.iter().map(|point| point.pnl).collect();
+        assert_eq!(pnl, [dec!(0), dec!(-2), dec!(1), dec!(0)]);
+        assert_eq!(report.fills.len(), 2);
+        assert_eq!(report.position.size, dec!(5));
+        assert_eq!(report.summary.realized_pnl, dec!(0.5));
+        assert_eq!(report.summary.unrealized_pnl, dec!(-0.5));
+        assert_eq!(report.summary.total_pnl, dec!(0));
+        assert_eq!(report.summary.volume, dec!(8));
+        assert_eq!(report.summary.max_drawdown, dec!(2));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_should_apply_fees_slippage_and_limits() -> Result<()> {
+        let history = history(&[dec!(0.5), dec!(0.5)]);
+        let config = Config::builder()
+            .fee_rate_bps(100)
+            .slippage(dec!(0.01))
+            .build();
+        let mut strategy = Scripted(vec![
+            (0, order(Side::Buy, dec!(10), Decimal::ONE)?),
+            (0, order(Side::Buy, dec!(10), dec!(0.5))?),
+            (1, order(Side::Sell, dec!(100), Decimal::ZERO)?),
+        ]);
+
+        let report = run(&mut strategy, &history, &config).await?;
+
+        assert_eq!(report.fills.len(), 2);
+        assert_eq!(report.fills[0].price, dec!(0.51));
+        assert_eq!(report.fills[0].fee, dec!(0.051));
+        assert_eq!(report.fills[1].price, dec!(0.49));
+        assert_eq!(report.fills[1].size, dec!(10));
+        assert!(report.position.size.is_zero());
+        assert_eq!(report.summary.fees, dec!(0.1));
+        assert_eq!(report.summary.total_pnl, dec!(-0.3));
+
+        Ok(())
+    }
+}
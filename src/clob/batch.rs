@@ -0,0 +1,156 @@
+//! Refreshing several kinds of state at once.
+//!
+//! Most trading loops start every iteration by fetching the same handful of things: the books of
+//! the markets they quote, a few prices, the collateral balance and the open orders.
+//! [`Client::batch`](crate::clob::Client::batch) runs the calls described by a [`Batch`]
+//! concurrently and collects their results into a [`Snapshot`].
+//!
+//! At most [`Batch`]'s `concurrency` calls are in flight at once. Every call still goes through the
+//! client's [rate limiters](crate::transport::RateLimiters), so a low limit delays the calls
+//! rather than failing them. Because a book may thus have waited on a limiter for a while, books
+//! are returned as [`Fresh`] values whose age can be checked before they are used to price an
+//! order.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::clob::batch::Batch;
+//! use polymarket_client_sdk::clob::types::AssetType;
+//! use polymarket_client_sdk::clob::types::request::{BalanceAllowanceRequest, OrdersRequest};
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # use polymarket_client_sdk::types::TokenId;
+//!
+//! # async fn example(client: Client<Authenticated<Normal>>, yes: TokenId, no: TokenId) -> Result<(), Box<dyn std::error::Error>> {
+//! let batch = Batch::builder()
+//!     .books(vec![yes, no])
+//!     .balance(
+//!         BalanceAllowanceRequest::builder()
+//!             .asset_type(AssetType::Collateral)
+//!             .build(),
+//!     )
+//!     .open_orders(OrdersRequest::default())
+//!     .build();
+//!
+//! let snapshot = client.batch(&batch).await?;
+//! let yes_book = &snapshot.books[&yes];
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use bon::Builder;
+use futures::future::BoxFuture;
+use futures::{StreamExt as _, TryStreamExt as _, stream};
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::freshness::Fresh;
+use crate::clob::types::request::{
+    BalanceAllowanceRequest, OrderBookSummaryRequest, OrdersRequest, PriceRequest,
+};
+use crate::clob::types::response::{
+    BalanceAllowanceResponse, OpenOrderResponse, OrderBookSummaryResponse, PricesResponse,
+};
+use crate::types::TokenId;
+
+/// The calls run by [`Client::batch`](crate::clob::Client::batch). Every kind of call is skipped
+/// unless it is set.
+#[non_exhaustive]
+#[derive(Debug, Builder)]
+pub struct Batch {
+    /// Tokens whose order books are fetched, one call per token.
+    #[builder(default)]
+    pub books: Vec<TokenId>,
+    /// Prices fetched together in a single call, see
+    /// [`Client::prices`](crate::clob::Client::prices).
+    #[builder(default)]
+    pub prices: Vec<PriceRequest>,
+    /// Balance and allowance to fetch, see
+    /// [`Client::balance_allowance`](crate::clob::Client::balance_allowance).
+    pub balance: Option<BalanceAllowanceRequest>,
+    /// Filter of the open orders to fetch, every page of which is fetched.
+    pub open_orders: Option<OrdersRequest>,
+    /// The maximum number of calls in flight at once, at least one. The default is four (4).
+    #[builder(default = 4)]
+    pub concurrency: usize,
+}
+
+/// The results of a [`Batch`]. A field is empty or `None` if the batch did not request it.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default)]
+pub struct Snapshot {
+    /// The order books, by token, together with when each was received.
+    pub books: HashMap<TokenId, Fresh<OrderBookSummaryResponse>>,
+    /// The prices.
+    pub prices: Option<PricesResponse>,
+    /// The balance and allowance.
+    pub balance: Option<BalanceAllowanceResponse>,
+    /// The open orders, in the order returned by the CLOB.
+    pub open_orders: Option<Vec<OpenOrderResponse>>,
+}
+
+/// The result of a single call of a [`Batch`].
+enum Output {
+    Book(TokenId, Fresh<OrderBookSummaryResponse>),
+    Prices(PricesResponse),
+    Balance(BalanceAllowanceResponse),
+    OpenOrders(Vec<OpenOrderResponse>),
+}
+
+pub(crate) async fn run<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    batch: &Batch,
+) -> Result<Snapshot> {
+    let mut calls: Vec<BoxFuture<'_, Result<Output>>> = Vec::new();
+
+    for &token_id in &batch.books {
+        calls.push(Box::pin(async move {
+            let request = OrderBookSummaryRequest::builder()
+                .token_id(token_id)
+                .build();
+            let book = client.order_book_with_metadata(&request).await?;
+            Ok(Output::Book(token_id, book.into()))
+        }));
+    }
+    if !batch.prices.is_empty() {
+        calls.push(Box::pin(async move {
+            client.prices(&batch.prices).await.map(Output::Prices)
+        }));
+    }
+    if let Some(request) = &batch.balance {
+        calls.push(Box::pin(async move {
+            client
+                .balance_allowance(request.clone())
+                .await
+                .map(Output::Balance)
+        }));
+    }
+    if let Some(request) = &batch.open_orders {
+        calls.push(Box::pin(async move {
+            client
+                .stream_data(|client, cursor| client.orders(request, cursor))
+                .try_collect()
+                .await
+                .map(Output::OpenOrders)
+        }));
+    }
+
+    let mut outputs = stream::iter(calls).buffer_unordered(batch.concurrency.max(1));
+    let mut snapshot = Snapshot::default();
+    while let Some(output) = outputs.try_next().await? {
+        match output {
+            Output::Book(token_id, book) => {
+                snapshot.books.insert(token_id, book);
+            }
+            Output::Prices(prices) => snapshot.prices = Some(prices),
+            Output::Balance(balance) => snapshot.balance = Some(balance),
+            Output::OpenOrders(orders) => snapshot.open_orders = Some(orders),
+        }
+    }
+
+    Ok(snapshot)
+}
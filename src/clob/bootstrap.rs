@@ -0,0 +1,388 @@
+//! Bootstrapping an authenticated [`Client`] from the environment or a config file.
+//!
+//! Almost every program starts the same way: read a private key, build a signer for the right
+//! chain, configure the [`Client`] and authenticate it. [`Settings`] captures those inputs and
+//! [`Settings::connect`] performs the steps, returning the authenticated [`Client`] together with
+//! its signer, which is still needed to sign orders.
+//!
+//! Settings can be read from `POLYMARKET_*` environment variables with [`Settings::from_env`], or
+//! from a JSON (or, with the `toml` feature, TOML) file with [`Settings::from_file`]. Every field
+//! except the private key is optional:
+//!
+//! ```toml
+//! host = "https://clob.polymarket.com"
+//! chain_id = 137
+//! private_key = { env = "POLYMARKET_PRIVATE_KEY" } # or { file = "/run/secrets/polymarket" }
+//! funder = "0x995c9b1f779c04e65AF8ea3360F96c43b5e62316"
+//! signature_type = "proxy"
+//! use_server_time = true
+//! enforce_geoblock = false
+//! ```
+//!
+//! Private keys are never read inline from a config file, only from an environment variable or a
+//! separate file, so the config itself can be committed safely.
+//!
+//! ```rust,no_run
+//! use polymarket_client_sdk::clob::Client;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let (client, signer) = Client::from_env().await?;
+//!
+//!     let ok = client.ok().await?;
+//!     println!("Ok: {ok}");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::env::{self, VarError};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr as _;
+
+use alloy::signers::Signer as _;
+use alloy::signers::local::PrivateKeySigner;
+use bon::Builder;
+use serde::Deserialize;
+
+use crate::auth::Normal;
+use crate::auth::state::Authenticated;
+use crate::clob::types::SignatureType;
+use crate::clob::{Client, Config};
+use crate::error::Error;
+use crate::types::{Address, ChainId};
+use crate::{POLYGON, PRIVATE_KEY_VAR, Result};
+
+/// Environment variable holding the CLOB API host.
+pub const HOST_VAR: &str = "POLYMARKET_HOST";
+/// Environment variable holding the chain ID, e.g. `137` for Polygon.
+pub const CHAIN_ID_VAR: &str = "POLYMARKET_CHAIN_ID";
+/// Environment variable holding the path of a file containing the private key. Takes precedence
+/// over [`PRIVATE_KEY_VAR`].
+pub const PRIVATE_KEY_FILE_VAR: &str = "POLYMARKET_PRIVATE_KEY_FILE";
+/// Environment variable holding the funder address.
+pub const FUNDER_VAR: &str = "POLYMARKET_FUNDER";
+/// Environment variable holding the signature type, either `eoa`, `proxy` or `gnosis-safe` (or
+/// their numeric values).
+pub const SIGNATURE_TYPE_VAR: &str = "POLYMARKET_SIGNATURE_TYPE";
+/// Environment variable toggling [`Config`]'s `use_server_time`.
+pub const USE_SERVER_TIME_VAR: &str = "POLYMARKET_USE_SERVER_TIME";
+/// Environment variable toggling [`Config`]'s `enforce_geoblock`.
+pub const ENFORCE_GEOBLOCK_VAR: &str = "POLYMARKET_ENFORCE_GEOBLOCK";
+
+const DEFAULT_HOST: &str = "https://clob.polymarket.com";
+
+/// Where the private key used to authenticate is read from.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrivateKeySource {
+    /// The named environment variable.
+    Env(String),
+    /// The file at the given path, with surrounding whitespace trimmed.
+    File(PathBuf),
+}
+
+impl Default for PrivateKeySource {
+    fn default() -> Self {
+        Self::Env(PRIVATE_KEY_VAR.to_owned())
+    }
+}
+
+impl PrivateKeySource {
+    fn read(&self) -> Result<String> {
+        match self {
+            Self::Env(var) => env::var(var).map_err(|e| var_error(var, &e)),
+            Self::File(path) => fs::read_to_string(path)
+                .map(|key| key.trim().to_owned())
+                .map_err(|e| {
+                    Error::validation(format!(
+                        "Unable to read private key from {}: {e}",
+                        path.display()
+                    ))
+                }),
+        }
+    }
+}
+
+/// The inputs needed to bootstrap an authenticated [`Client`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Deserialize, Builder)]
+#[serde(deny_unknown_fields)]
+pub struct Settings {
+    /// The CLOB API host. Defaults to <https://clob.polymarket.com>.
+    #[serde(default = "default_host")]
+    #[builder(into, default = DEFAULT_HOST.to_owned())]
+    host: String,
+    /// The chain ID the signer signs for. Defaults to [`POLYGON`].
+    #[serde(default = "default_chain_id")]
+    #[builder(default = POLYGON)]
+    chain_id: ChainId,
+    /// Where to read the private key from. Defaults to the [`PRIVATE_KEY_VAR`] environment
+    /// variable.
+    #[serde(default)]
+    #[builder(default)]
+    private_key: PrivateKeySource,
+    /// The funder address, for proxy and Safe wallets.
+    #[serde(default)]
+    funder: Option<Address>,
+    /// The signature type. When unset it is inferred as usual by
+    /// [`AuthenticationBuilder`](crate::clob::client::AuthenticationBuilder).
+    #[serde(default)]
+    signature_type: Option<SignatureType>,
+    /// See [`Config`]'s `use_server_time`.
+    #[serde(default)]
+    #[builder(default)]
+    use_server_time: bool,
+    /// See [`Config`]'s `enforce_geoblock`.
+    #[serde(default)]
+    #[builder(default)]
+    enforce_geoblock: bool,
+}
+
+impl Settings {
+    /// Reads settings from `POLYMARKET_*` environment variables, see the constants in this module.
+    /// Unset variables fall back to their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable is set but cannot be parsed.
+    pub fn from_env() -> Result<Self> {
+        let private_key = match optional_var(PRIVATE_KEY_FILE_VAR)? {
+            Some(path) => PrivateKeySource::File(path.into()),
+            None => PrivateKeySource::default(),
+        };
+
+        Ok(Self {
+            host: optional_var(HOST_VAR)?.unwrap_or_else(default_host),
+            chain_id: parse_var(CHAIN_ID_VAR, |value| value.parse().ok())?.unwrap_or(POLYGON),
+            private_key,
+            funder: parse_var(FUNDER_VAR, |value| Address::from_str(value).ok())?,
+            signature_type: parse_var(SIGNATURE_TYPE_VAR, |value| value.parse().ok())?,
+            use_server_time: parse_var(USE_SERVER_TIME_VAR, parse_bool)?.unwrap_or_default(),
+            enforce_geoblock: parse_var(ENFORCE_GEOBLOCK_VAR, parse_bool)?.unwrap_or_default(),
+        })
+    }
+
+    /// Parses settings from a JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not valid JSON or contains unknown fields.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Parses settings from a TOML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not valid TOML or contains unknown fields.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Reads settings from a `.json` or, with the `toml` feature, a `.toml` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, has an unsupported extension, or cannot be
+    /// parsed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            Error::validation(format!(
+                "Unable to read settings from {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(Error::validation(format!(
+                "Unsupported settings file {}, expected a .json{} file",
+                path.display(),
+                if cfg!(feature = "toml") {
+                    " or .toml"
+                } else {
+                    ""
+                }
+            ))),
+        }
+    }
+
+    /// Builds the signer from the configured private key source and chain ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the private key cannot be read or is invalid.
+    pub fn signer(&self) -> Result<PrivateKeySigner> {
+        let signer = PrivateKeySigner::from_str(&self.private_key.read()?)
+            .map_err(|e| Error::validation(format!("Invalid private key: {e}")))?;
+
+        Ok(signer.with_chain_id(Some(self.chain_id)))
+    }
+
+    /// Returns the [`Config`] described by these settings.
+    #[must_use]
+    pub fn config(&self) -> Config {
+        Config::builder()
+            .use_server_time(self.use_server_time)
+            .enforce_geoblock(self.enforce_geoblock)
+            .build()
+    }
+
+    /// Builds the signer, then creates and authenticates a [`Client`], deriving (or creating)
+    /// API credentials as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer cannot be built or authentication fails.
+    pub async fn connect(&self) -> Result<(Client<Authenticated<Normal>>, PrivateKeySigner)> {
+        let signer = self.signer()?;
+
+        let mut builder = Client::new(&self.host, self.config())?.authentication_builder(&signer);
+        if let Some(funder) = self.funder {
+            builder = builder.funder(funder);
+        }
+        if let Some(signature_type) = self.signature_type {
+            builder = builder.signature_type(signature_type);
+        }
+        let client = builder.authenticate().await?;
+
+        Ok((client, signer))
+    }
+}
+
+fn default_host() -> String {
+    DEFAULT_HOST.to_owned()
+}
+
+const fn default_chain_id() -> ChainId {
+    POLYGON
+}
+
+fn var_error(var: &str, error: &VarError) -> Error {
+    Error::validation(format!("Unable to read {var}: {error}"))
+}
+
+fn optional_var(var: &str) -> Result<Option<String>> {
+    match env::var(var) {
+        Ok(value) => Ok(Some(value)),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(e) => Err(var_error(var, &e)),
+    }
+}
+
+fn parse_var<T>(var: &str, parse: fn(&str) -> Option<T>) -> Result<Option<T>> {
+    optional_var(var)?
+        .map(|value| {
+            parse(value.trim())
+                .ok_or_else(|| Error::validation(format!("Invalid value for {var}: {value}")))
+        })
+        .transpose()
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::address;
+
+    #[test]
+    fn from_json_should_apply_defaults() -> Result<()> {
+        let settings = Settings::from_json("{}")?;
+
+        assert_eq!(settings.host, DEFAULT_HOST);
+        assert_eq!(settings.chain_id, POLYGON);
+        assert_eq!(settings.private_key, PrivateKeySource::default());
+        assert_eq!(settings.funder, None);
+        assert_eq!(settings.signature_type, None);
+        assert!(!settings.use_server_time);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_json_should_succeed() -> Result<()> {
+        let settings = Settings::from_json(
+            r#"{
+                "host": "http://localhost",
+                "chain_id": 80002,
+                "private_key": { "file": "/run/secrets/key" },
+                "funder": "0x995c9b1f779c04e65AF8ea3360F96c43b5e62316",
+                "signature_type": "gnosis-safe",
+                "use_server_time": true,
+                "enforce_geoblock": true
+            }"#,
+        )?;
+
+        assert_eq!(settings.host, "http://localhost");
+        assert_eq!(settings.chain_id, 80002);
+        assert_eq!(
+            settings.private_key,
+            PrivateKeySource::File("/run/secrets/key".into())
+        );
+        assert_eq!(
+            settings.funder,
+            Some(address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316"))
+        );
+        assert_eq!(settings.signature_type, Some(SignatureType::GnosisSafe));
+        assert!(settings.use_server_time);
+        assert!(settings.enforce_geoblock);
+
+        Settings::from_json(r#"{ "signature_type": 1 }"#)?;
+        Settings::from_json(r#"{ "unknown": 1 }"#).unwrap_err();
+        Settings::from_json(r#"{ "private_key": "0xabc" }"#).unwrap_err();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml_should_succeed() -> Result<()> {
+        let settings = Settings::from_toml(
+            r#"
+                chain_id = 80002
+                private_key = { env = "MY_KEY" }
+                signature_type = "proxy"
+            "#,
+        )?;
+
+        assert_eq!(settings.chain_id, 80002);
+        assert_eq!(
+            settings.private_key,
+            PrivateKeySource::Env("MY_KEY".to_owned())
+        );
+        assert_eq!(settings.signature_type, Some(SignatureType::Proxy));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_helpers_should_succeed() {
+        assert_eq!(parse_bool("TRUE"), Some(true));
+        assert_eq!(parse_bool("off"), Some(false));
+        assert_eq!(parse_bool("maybe"), None);
+        assert_eq!(
+            "Eoa".parse::<SignatureType>().ok(),
+            Some(SignatureType::Eoa)
+        );
+        assert_eq!(
+            "GNOSIS_SAFE".parse::<SignatureType>().ok(),
+            Some(SignatureType::GnosisSafe)
+        );
+        assert_eq!("3".parse::<SignatureType>().ok(), None);
+    }
+}
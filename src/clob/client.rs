@@ -1,48 +1,109 @@
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, hash_map};
+use std::iter;
 use std::marker::PhantomData;
 use std::mem;
-use std::sync::Arc;
-use std::time::Duration;
+#[cfg(feature = "metrics")]
+use std::net::SocketAddr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
 
 use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
+use alloy::primitives::{ChainId, U256};
 use alloy::signers::Signer;
+use alloy::signers::local::PrivateKeySigner;
 use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
-use bon::Builder;
-use chrono::{NaiveDate, Utc};
+use bon::{Builder, bon};
+use chrono::{DateTime, NaiveDate, Utc};
 use dashmap::DashMap;
-use futures::Stream;
+use futures::{Stream, TryStreamExt as _, future};
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client as ReqwestClient, Method, Request};
-use serde_json::json;
+use reqwest::{Client as ReqwestClient, Method, Request, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
 #[cfg(all(feature = "tracing", feature = "heartbeats"))]
 use tracing::{debug, error};
 use url::Url;
 use uuid::Uuid;
 #[cfg(feature = "heartbeats")]
-use {tokio::sync::oneshot::Receiver, tokio::time, tokio_util::sync::CancellationToken};
+use {
+    chrono::TimeDelta, rand::Rng as _, tokio::sync::oneshot::Receiver, tokio::time,
+    tokio_util::sync::CancellationToken,
+};
 
-use crate::auth::builder::{Builder, Config as BuilderConfig};
+use crate::auth::builder::{self, Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind, Normal};
+use crate::auth::{ApiKey, Credentials, Kind, Normal};
+#[cfg(feature = "polling")]
+use crate::clob::alerts::{self, Alert, WatchConfig as AlertsWatchConfig};
+use crate::clob::batch::{self, Batch, Snapshot};
+use crate::clob::bootstrap::Settings;
+#[cfg(feature = "polling")]
+use crate::clob::closing::{self, Warning, WatchConfig as ClosingWatchConfig};
+#[cfg(all(feature = "ctf", feature = "polling"))]
+use crate::clob::deposits::{self, Deposit, WatchConfig as DepositWatchConfig};
+use crate::clob::earnings::Ledger;
+use crate::clob::endpoint::{self, Endpoint, Route};
+#[cfg(feature = "gamma")]
+use crate::clob::enrichment::EnrichedMarket;
+#[cfg(feature = "polling")]
+use crate::clob::expirations::{self, Expiry, WatchConfig as ExpiryWatchConfig};
+use crate::clob::fees;
+#[cfg(feature = "polling")]
+use crate::clob::fills::{self, Fill};
+use crate::clob::health::{self, Report as HealthReport};
+use crate::clob::hooks::{OrderValidator, Verdict};
+use crate::clob::ladder::{self, Config as LadderConfig, Ladder};
+#[cfg(feature = "polling")]
+use crate::clob::lifecycle::{Shutdown, ShutdownConfig};
+#[cfg(feature = "metrics")]
+use crate::clob::metrics::{self, CacheStats, Server as MetricsServer};
+#[cfg(feature = "polling")]
+use crate::clob::notifications::{self, StreamConfig};
+#[cfg(feature = "polling")]
+use crate::clob::open_orders::{self, Event as OrderEvent};
 use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::order_handle::OrderHandle;
+#[cfg(feature = "data")]
+use crate::clob::positions::PositionTracker;
+#[cfg(feature = "polling")]
+use crate::clob::recorder::{self, Record, RecordConfig, Writer};
+#[cfg(feature = "polling")]
+use crate::clob::resolution::{self, StatusChange, WatchConfig};
+#[cfg(feature = "polling")]
+use crate::clob::restrictions::{self, Monitor, MonitorConfig};
+use crate::clob::revenue::Report;
+use crate::clob::risk::Engine as RiskEngine;
+#[cfg(all(feature = "rfq", feature = "polling"))]
+use crate::clob::routing::{self, Constraints, Execution};
+#[cfg(feature = "polling")]
+use crate::clob::scoring::{self, WatchConfig as ScoringWatchConfig};
+use crate::clob::session::Session;
+#[cfg(feature = "polling")]
+use crate::clob::strategy::{Config as StrategyConfig, Runner};
+use crate::clob::switchboard::Switchboard;
+#[cfg(feature = "polling")]
+use crate::clob::timing::{self, ScheduledOrder, ServerClock, SyncConfig};
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
     PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UpdateBalanceAllowanceRequest,
     UserRewardsEarningRequest,
 };
+use crate::clob::types::response::Notification;
 use crate::clob::types::response::{
     ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
-    BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, FeeRateResponse,
-    GeoblockResponse, HeartbeatResponse, LastTradePriceResponse, LastTradesPricesResponse,
-    MarketResponse, MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-    NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
-    SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
-    UserEarningResponse, UserRewardsEarningResponse,
+    BuilderTradeResponse, CancelOrdersResponse, CancelTokensResponse, CurrentRewardResponse,
+    FeeRateResponse, GeoblockResponse, HeartbeatResponse, LastTradePriceResponse,
+    LastTradesPricesResponse, MarketResponse, MarketRewardResponse, MarketRewardsConfig,
+    MidpointResponse, MidpointsResponse, NegRiskResponse, NotificationResponse, OpenOrderResponse,
+    OrderBookSummaryResponse, OrderScoringResponse, OrdersScoringResponse, Page, PostOrderResponse,
+    PriceHistoryResponse, PriceResponse, PricesResponse, RawResponse, RewardsPercentagesResponse,
+    SimplifiedMarketResponse, SpreadResponse, SpreadsResponse, TickSizeResponse,
+    TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
 };
 #[cfg(feature = "rfq")]
 use crate::clob::types::{
@@ -51,12 +112,22 @@ use crate::clob::types::{
     CreateRfqRequestRequest, CreateRfqRequestResponse, RfqQuote, RfqQuotesRequest, RfqRequest,
     RfqRequestsRequest,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Kind as ErrorKind, Synchronization};
-use crate::types::Address;
+use crate::clob::types::{
+    AssetType, OrderDomain, OrderStatusType, Side, SignableOrder, SignatureType, SignedOrder,
+    TickSize,
+};
+#[cfg(feature = "data")]
+use crate::data::types::request::PositionsRequest;
+use crate::error::{
+    Error, Geoblock, InsufficientBalance, Kind as ErrorKind, OrderDenied, Status, Synchronization,
+};
+#[cfg(feature = "gamma")]
+use crate::gamma::types::request::MarketsRequest as GammaMarketsRequest;
+use crate::transport::{self, HttpClient, ResponseEnvelope};
+use crate::types::{Address, B256, Decimal, Timestamp, TokenId};
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
-    derive_proxy_wallet, derive_safe_wallet,
+    AMOY, POLYGON, Result, UnixSeconds, auth, contract_config, derive_proxy_wallet,
+    derive_safe_wallet,
 };
 
 const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
@@ -64,6 +135,9 @@ const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
 
 const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
 
+/// The maximum number of order IDs sent in a single [`Client::are_orders_scoring`] request.
+const ORDERS_SCORING_CHUNK: usize = 100;
+
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
 pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
@@ -87,6 +161,9 @@ pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
     signature_type: Option<SignatureType>,
     /// The optional salt/seed generator for use in creating [`SignableOrder`]s
     salt_generator: Option<fn() -> u64>,
+    /// The optional JSON-RPC endpoint used to verify that the funder wallet is deployed.
+    #[cfg(feature = "ctf")]
+    wallet_rpc_url: Option<Url>,
 }
 
 impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
@@ -120,20 +197,57 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         self
     }
 
+    /// Verifies, via the JSON-RPC endpoint at `rpc_url`, that the funder wallet of a
+    /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`] client is deployed before
+    /// authenticating. Without this check, an undeployed wallet only surfaces later as rejected
+    /// orders.
+    ///
+    /// # Note
+    ///
+    /// Requires the `ctf` feature to be enabled.
+    #[cfg(feature = "ctf")]
+    #[must_use]
+    pub fn verify_wallet_deployed(mut self, rpc_url: Url) -> Self {
+        self.wallet_rpc_url = Some(rpc_url);
+        self
+    }
+
     /// Attempt to elevate the inner `client` to [`Client<Authenticated<K>>`] using the optional
     /// fields supplied in the builder.
-    #[expect(
-        clippy::missing_panics_doc,
-        reason = "chain_id panic is guarded by prior validation"
-    )]
+    ///
+    /// This requires exclusive ownership of the inner `client`, and fails with a
+    /// [`Synchronization`] error if any clone of it is still alive. See
+    /// [`Self::authenticate_shared`] to authenticate regardless.
     pub async fn authenticate(self) -> Result<Client<Authenticated<K>>> {
-        let inner = Arc::into_inner(self.client.inner).ok_or(Synchronization)?;
+        self.elevate(|inner| Arc::into_inner(inner).ok_or_else(|| Synchronization.into()))
+            .await
+    }
+
+    /// Attempt to elevate the inner `client` to [`Client<Authenticated<K>>`] without requiring
+    /// exclusive ownership of it.
+    ///
+    /// If the inner `client` has no other clones this behaves exactly like [`Self::authenticate`].
+    /// Otherwise its configuration and local caches (tick sizes, neg risk flags, fee rates and the
+    /// last geoblock check) are cloned into the authenticated client, leaving the existing clones
+    /// untouched and usable. From then on the caches of the two clients are independent.
+    pub async fn authenticate_shared(self) -> Result<Client<Authenticated<K>>> {
+        self.elevate(|inner| Ok(Arc::try_unwrap(inner).unwrap_or_else(|shared| shared.duplicate())))
+            .await
+    }
+
+    async fn elevate<F>(self, take_inner: F) -> Result<Client<Authenticated<K>>>
+    where
+        F: FnOnce(Arc<ClientInner<Unauthenticated>>) -> Result<ClientInner<Unauthenticated>>,
+    {
+        let use_server_time = self.client.uses_server_time();
+        let inner = take_inner(self.client.inner)?;
 
         match self.signer.chain_id() {
-            Some(chain) if chain == POLYGON || chain == AMOY => {}
+            Some(chain) if contract_config(chain, false).is_some() => {}
             Some(chain) => {
                 return Err(Error::validation(format!(
-                    "Only Polygon and AMOY are supported, got {chain}"
+                    "Only Polygon, AMOY and chains registered via `register_contract_config` \
+                     are supported, got {chain}"
                 )));
             }
             None => {
@@ -143,9 +257,21 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
             }
         }
 
-        // SAFETY: chain_id is validated above to be either POLYGON or AMOY
+        // SAFETY: chain_id is validated above to have a contract config
         let chain_id = self.signer.chain_id().expect("validated above");
 
+        // A signer for the wrong network otherwise only surfaces as an opaque signature rejection
+        // once orders are posted
+        if let Some(expected) = expected_chain_id(&inner.host)
+            && expected != chain_id
+        {
+            return Err(Error::validation(format!(
+                "Signer chain id {chain_id} does not match {host}, which expects chain id \
+                 {expected}",
+                host = inner.host
+            )));
+        }
+
         // Auto-derive funder from signer using CREATE2 when using proxy signature types
         // without explicit funder. This computes the deterministic wallet address that
         // Polymarket deploys for the user.
@@ -173,22 +299,24 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
             (funder, _) => funder,
         };
 
-        match (funder, self.signature_type) {
-            (Some(_), Some(sig @ SignatureType::Eoa)) => {
-                return Err(Error::validation(format!(
-                    "Cannot have a funder address with a {sig} signature type"
-                )));
-            }
-            (
-                Some(Address::ZERO),
-                Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe)),
-            ) => {
+        validate_funder(funder, self.signature_type)?;
+
+        #[cfg(feature = "ctf")]
+        if let (
+            Some(rpc_url),
+            Some(funder),
+            Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe)),
+        ) = (self.wallet_rpc_url, funder, self.signature_type)
+        {
+            let provider = alloy::providers::ProviderBuilder::new().connect_http(rpc_url);
+            if !crate::onchain::is_wallet_deployed(&provider, funder).await? {
                 return Err(Error::validation(format!(
-                    "Cannot have a zero funder address with a {sig} signature type"
+                    "The {sig} funder wallet {funder} is not deployed on chain {chain_id}. \
+                     Polymarket deploys this wallet on your first login to polymarket.com, so log \
+                     in (or deposit) once before trading, or provide the funder address shown in \
+                     your Polymarket profile."
                 )));
             }
-            // Note: (None, Some(Proxy/GnosisSafe)) is unreachable due to auto-derivation above
-            _ => {}
         }
 
         let credentials = match self.credentials {
@@ -200,7 +328,7 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
             Some(credentials) => credentials,
             None => {
                 inner
-                    .create_or_derive_api_key(self.signer, self.nonce)
+                    .create_or_derive_api_key(self.signer, self.nonce, use_server_time)
                     .await?
             }
         };
@@ -228,10 +356,24 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                cache_counters: inner.cache_counters,
+                geoblock: inner.geoblock,
+                balances: DashMap::new(),
+                reward_percentages: RwLock::new(None),
+                current_rewards: DashMap::new(),
+                submitted_orders: DashMap::new(),
+                order_ids: OrderIds::default(),
+                in_flight: InFlight::default(),
+                expirations: DashMap::new(),
+                simulated_orders: DashMap::new(),
+                #[cfg(feature = "gamma")]
+                enriched_markets: DashMap::new(),
+                closed_only: AtomicBool::new(false),
                 funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
             }),
+            use_server_time: None,
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
         };
@@ -243,6 +385,26 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
     }
 }
 
+/// Fails if `funder` does not fit `signature_type`: [`SignatureType::Eoa`] orders are funded by
+/// the signer itself, while proxy and Safe orders need a non-zero funder.
+fn validate_funder(funder: Option<Address>, signature_type: Option<SignatureType>) -> Result<()> {
+    match (funder, signature_type) {
+        (Some(_), Some(sig @ SignatureType::Eoa)) => Err(Error::validation(format!(
+            "Cannot have a funder address with a {sig} signature type"
+        ))),
+        (Some(Address::ZERO), Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe))) => {
+            Err(Error::validation(format!(
+                "Cannot have a zero funder address with a {sig} signature type"
+            )))
+        }
+        // Unreachable when authenticating, where the funder is derived if missing
+        (None, Some(sig @ (SignatureType::Proxy | SignatureType::GnosisSafe))) => Err(
+            Error::validation(format!("A {sig} signature type requires a funder address")),
+        ),
+        _ => Ok(()),
+    }
+}
+
 /// The main way for API users to interact with the Polymarket CLOB.
 ///
 /// A [`Client`] can either be [`Unauthenticated`] or [`Authenticated`], that is, authenticated
@@ -262,7 +424,7 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<()> {
-///     let client = Client::new("https://clob.polymarket.com", Config::default())?;
+///     let client = Client::production(Config::default())?;
 ///
 ///     let ok = client.ok().await?;
 ///     println!("Ok: {ok}");
@@ -284,7 +446,7 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 /// async fn main() -> anyhow::Result<()> {
 ///     let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need a private key");
 ///     let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
-///     let client = Client::new("https://clob.polymarket.com", Config::default())?
+///     let client = Client::production(Config::default())?
 ///         .authentication_builder(&signer)
 ///         .authenticate()
 ///         .await?;
@@ -301,64 +463,89 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 #[derive(Clone, Debug)]
 pub struct Client<S: State = Unauthenticated> {
     inner: Arc<ClientInner<S>>,
+    /// Overrides [`Config`]'s `use_server_time` for the calls made through this handle, see
+    /// [`Client::with_server_time`].
+    use_server_time: Option<bool>,
     #[cfg(feature = "heartbeats")]
     /// When the `heartbeats` feature is enabled, the authenticated [`Client`] will automatically
     /// send heartbeats at the default cadence. See [`Config`] for more details.
+    ///
+    /// The background task is shared by every clone of this [`Client`] and keeps running until
+    /// the last of them is dropped.
     heartbeat_token: DroppingCancellationToken,
 }
 
 #[cfg(feature = "heartbeats")]
-/// A specific wrapper type to invoke the inner [`CancellationToken`] (if it's present) to:
+/// Supervises a running heartbeat task. It is shared between all clones of a [`Client`] through
+/// [`DroppingCancellationToken`], and cancels the task once the last reference is dropped.
+///
+/// The [`Receiver`] is notified once the task has released its own [`Client`] so that we can
+/// avoid a race condition when calling [`Arc::into_inner`] on promotion and demotion methods. It
+/// also carries the last heartbeat ID, so that a new task can continue the same session.
+#[derive(Debug)]
+struct HeartbeatSupervisor {
+    token: CancellationToken,
+    done: Option<Receiver<Option<Uuid>>>,
+    /// When the task last sent a heartbeat that was accepted, see
+    /// [`Client::last_heartbeat_sent_at`].
+    last_sent: Arc<RwLock<Option<DateTime<Utc>>>>,
+}
+
+#[cfg(feature = "heartbeats")]
+impl Drop for HeartbeatSupervisor {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+#[cfg(feature = "heartbeats")]
+/// A specific wrapper type to hold the [`HeartbeatSupervisor`] (if it's present) to:
 ///  1. Avoid manually implementing [`Drop`] for [`Client`] which causes issues with moving values
 ///     out of such a type <https://doc.rust-lang.org/error_codes/E0509.html>
 ///  2. Replace the (currently non-existent) ability of specialized implementations of [`Drop`]
 ///     <https://github.com/rust-lang/rust/issues/46893>
 ///
-/// This way, the inner token is expressly cancelled when [`DroppingCancellationToken`] is dropped.
-/// We also have a [`Receiver<()>`] to notify when the inner [`Client`] has been dropped so that
-/// we can avoid a race condition when calling [`Arc::into_inner`] on promotion and demotion methods.
+/// Cloning it only bumps the reference count of the supervisor, so dropping a single clone of a
+/// [`Client`] leaves heartbeats running for the others.
 #[derive(Clone, Debug, Default)]
-struct DroppingCancellationToken(Option<(CancellationToken, Arc<Receiver<()>>)>);
+struct DroppingCancellationToken(Option<Arc<HeartbeatSupervisor>>);
 
 #[cfg(feature = "heartbeats")]
 impl DroppingCancellationToken {
-    /// Cancel the inner [`CancellationToken`] and wait to be notified of the relevant cleanup via
+    /// Cancel the heartbeat task and wait to be notified of the relevant cleanup via
     /// [`Receiver`]. This is primarily used by the authentication methods when promoting [`Client`]s
     /// to ensure that we do not error when transferring ownership of [`ClientInner`].
-    pub(crate) async fn cancel_and_wait(&mut self) -> Result<()> {
-        if let Some((token, rx)) = self.0.take() {
-            return match Arc::try_unwrap(rx) {
+    ///
+    /// Returns the last heartbeat ID sent by the task, if any, so that the session can be handed
+    /// off to a new task. Fails with [`Synchronization`] if other clones still share the task.
+    pub(crate) async fn cancel_and_wait(&mut self) -> Result<Option<Uuid>> {
+        if let Some(supervisor) = self.0.take() {
+            return match Arc::try_unwrap(supervisor) {
                 // If this is the only reference, cancel the token and wait for the resources to be
                 // cleaned up.
-                Ok(inner) => {
-                    token.cancel();
-                    _ = inner.await;
-                    Ok(())
+                Ok(mut supervisor) => {
+                    supervisor.token.cancel();
+                    let heartbeat_id = match supervisor.done.take() {
+                        Some(done) => done.await.ok().flatten(),
+                        None => None,
+                    };
+                    Ok(heartbeat_id)
                 }
-                // If not, _save_ the original token and receiver to re-use later if desired
+                // If not, _save_ the original supervisor to re-use later if desired
                 Err(original) => {
-                    *self = DroppingCancellationToken(Some((token, original)));
+                    *self = DroppingCancellationToken(Some(original));
                     Err(Synchronization.into())
                 }
             };
         }
 
-        Ok(())
-    }
-}
-
-#[cfg(feature = "heartbeats")]
-impl Drop for DroppingCancellationToken {
-    fn drop(&mut self) {
-        if let Some((token, _)) = self.0.take() {
-            token.cancel();
-        }
+        Ok(None)
     }
 }
 
 impl Default for Client<Unauthenticated> {
     fn default() -> Self {
-        Client::new("https://clob.polymarket.com", Config::default())
+        Client::new(DEFAULT_HOST, Config::default())
             .expect("Client with default endpoint should succeed")
     }
 }
@@ -367,22 +554,305 @@ impl Default for Client<Unauthenticated> {
 #[derive(Clone, Debug, Default, Builder)]
 pub struct Config {
     /// Whether the [`Client`] will use the server time provided by Polymarket when creating auth
-    /// headers. This adds another round trip to the requests.
+    /// headers. This adds another round trip to the requests. It can be overridden per call via
+    /// [`Client::with_server_time`].
     #[builder(default)]
     use_server_time: bool,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// Whether the authenticated [`Client`] checks the geoblock endpoint before submitting
+    /// orders or RFQ actions, returning a [`Geoblock`] error instead of sending the request when
+    /// trading is blocked from the current location.
+    #[builder(default)]
+    enforce_geoblock: bool,
+    /// How long a geoblock check is reused when `enforce_geoblock` is enabled. The default is
+    /// five (5) minutes.
+    #[builder(default = Duration::from_secs(300))]
+    geoblock_ttl: Duration,
+    /// How long a balance and allowance fetched via [`Client::balance_allowance`] is reused by
+    /// [`Client::cached_balance`]. The default is five (5) seconds.
+    #[builder(default = Duration::from_secs(5))]
+    balance_ttl: Duration,
+    /// How long the results of [`Client::reward_percentages`] and [`Client::current_rewards`]
+    /// are reused. The default is sixty (60) seconds.
+    #[builder(default = Duration::from_secs(60))]
+    rewards_ttl: Duration,
+    /// Overrides for the EIP-712 domain used by [`Client::sign`]. By default, orders are signed
+    /// against the production Polymarket exchange for the signer's chain.
+    order_domain: Option<OrderDomain>,
+    /// How long orders submitted via [`Client::post_order`] or [`Client::post_orders`] are
+    /// remembered. Resubmitting the same signed order (see [`SignedOrder::idempotency_key`])
+    /// within this window fails with a [`Validation`](crate::error::Validation) error instead of being sent, protecting
+    /// against double fills when a request is retried. Disabled by default.
+    ///
+    /// An order is remembered as soon as it is sent, even if the request fails, since the CLOB
+    /// may have accepted it regardless. Check [`Client::order`] before re-signing such an order.
+    order_dedup_window: Option<Duration>,
+    /// Pre-trade risk limits that every order passed to [`Client::post_order`] or
+    /// [`Client::post_orders`] is checked against before being sent, see [`risk`](crate::clob::risk).
+    /// Position limits are checked when an order is built instead. The engine is shared, so it can
+    /// be fed with profit and loss and positions while the client is in use.
+    risk: Option<Arc<RiskEngine>>,
+    /// Per token, market and event switches that every order passed to [`Client::sign`],
+    /// [`Client::post_order`] or [`Client::post_orders`] is checked against, see
+    /// [`switchboard`](crate::clob::switchboard). The switchboard is shared, so order submission
+    /// can be disabled and enabled again while the client is in use.
+    switchboard: Option<Arc<Switchboard>>,
+    /// Validators that every order must pass before [`Client::sign`] signs it and before
+    /// [`Client::post_order`] or [`Client::post_orders`] send it, see
+    /// [`hooks`](crate::clob::hooks).
+    #[builder(default)]
+    validators: Vec<Arc<dyn OrderValidator>>,
+    /// Whether order-mutating requests are simulated instead of sent. Posting an order returns a
+    /// synthetic accepted response and cancellations only affect simulated orders, while every
+    /// check before sending still runs and read-only endpoints still hit the API. With the `rfq`
+    /// feature, creating, canceling, accepting and approving RFQ requests, quotes and orders is
+    /// simulated too, so that `execute_best` never trades. See
+    /// [`Client::simulated_orders`].
+    #[builder(default)]
+    dry_run: bool,
+    /// How long a market joined via [`Client::enriched_market`] is reused. The default is five (5)
+    /// minutes.
+    #[cfg(feature = "gamma")]
+    #[builder(default = Duration::from_secs(300))]
+    enriched_market_ttl: Duration,
     #[cfg(feature = "heartbeats")]
     #[builder(default = Duration::from_secs(5))]
     /// How often the [`Client`] will automatically submit heartbeats. The default is five (5) seconds.
     heartbeat_interval: Duration,
+    /// A random delay of up to this duration added to every automatic heartbeat, drawn anew each
+    /// time, so that many clients on the same schedule do not send in bursts. It should be well
+    /// below `heartbeat_interval`. The default is none.
+    #[cfg(feature = "heartbeats")]
+    #[builder(default)]
+    heartbeat_jitter: Duration,
+    /// Aligns automatic heartbeats to fire this far past every multiple of `heartbeat_interval`
+    /// since the Unix epoch, e.g. at :00, :05, :10 with a zero phase and a five second interval.
+    /// Giving each client of a fleet a different phase spreads their heartbeats evenly. By default
+    /// heartbeats are not aligned, and are sent every `heartbeat_interval` from when they start.
+    #[cfg(feature = "heartbeats")]
+    heartbeat_phase: Option<Duration>,
+    /// How far the server clock is ahead of the local clock, applied when aligning heartbeats
+    /// with `heartbeat_phase`, e.g. the `offset` of a `ServerClock` returned by
+    /// `Client::sync_clock`. The default is zero, aligning to the local clock.
+    #[cfg(feature = "heartbeats")]
+    #[builder(default)]
+    heartbeat_clock_offset: TimeDelta,
+}
+
+/// Identifies a cached balance and allowance by asset type, token ID, and signature type
+type BalanceKey = (AssetType, Option<TokenId>, SignatureType);
+
+/// The largest page of positions the Data API returns, see [`Client::position_tracker`].
+#[cfg(feature = "data")]
+const POSITIONS_PAGE_SIZE: i32 = 500;
+
+/// Returns how far `now` is past the last multiple of `interval` since the Unix epoch.
+#[cfg(feature = "heartbeats")]
+fn heartbeat_phase_of(now: DateTime<Utc>, interval: Duration) -> Duration {
+    let interval = i128::try_from(interval.as_nanos())
+        .unwrap_or(i128::MAX)
+        .max(1);
+    let now = i128::from(now.timestamp_nanos_opt().unwrap_or_default());
+
+    // The remainder is below `interval`, which came from a `Duration`
+    Duration::from_nanos(u64::try_from(now.rem_euclid(interval)).unwrap_or(u64::MAX))
+}
+
+/// Returns the delay from `now` until the next time, strictly after `now`, that is `phase` past a
+/// multiple of `interval` since the Unix epoch.
+#[cfg(feature = "heartbeats")]
+fn next_heartbeat_delay(now: DateTime<Utc>, interval: Duration, phase: Duration) -> Duration {
+    if interval.is_zero() {
+        return Duration::ZERO;
+    }
+
+    let phase = Duration::from_nanos(
+        u64::try_from(phase.as_nanos() % interval.as_nanos()).unwrap_or_default(),
+    );
+    let elapsed = heartbeat_phase_of(now, interval);
+    if elapsed < phase {
+        phase.saturating_sub(elapsed)
+    } else {
+        interval.saturating_sub(elapsed.saturating_sub(phase))
+    }
+}
+
+/// Converts the [`Verdict`] of an [`OrderValidator`] into a result.
+fn verdict(verdict: Verdict) -> Result<()> {
+    match verdict {
+        Verdict::Allow => Ok(()),
+        Verdict::Deny { reason } => Err(OrderDenied { reason }.into()),
+    }
+}
+
+/// Replaces a [`Status`] error reporting an insufficient balance or allowance with an
+/// [`InsufficientBalance`] error, attributed to the asset spent by `order` if given. The
+/// [`Status`] is kept as its source, so the error still downcasts to it.
+fn insufficient_balance(error: Error, order: Option<&SignedOrder>) -> Error {
+    let Some(mut insufficient) = error.downcast_ref::<Status>().and_then(|status| {
+        InsufficientBalance::parse(&status.message).map(|parsed| parsed.with_status(status.clone()))
+    }) else {
+        return error;
+    };
+
+    match order.map(|order| (Side::try_from(order.order.side), order.order.tokenId)) {
+        Some((Ok(Side::Buy), _)) => insufficient.asset_type = Some(AssetType::Collateral),
+        Some((Ok(Side::Sell), token_id)) => {
+            insufficient.asset_type = Some(AssetType::Conditional);
+            insufficient.token_id = Some(TokenId::from(token_id));
+        }
+        _ => {}
+    }
+
+    insufficient.into()
+}
+
+/// Counts order submissions and cancellations in flight, so that a [`Shutdown`] can wait for
+/// them to complete. Once closed, new order submissions are rejected.
+#[derive(Debug, Default)]
+struct InFlight {
+    closed: AtomicBool,
+    count: AtomicUsize,
+}
+
+/// Marks a request as in flight until dropped
+struct InFlightGuard<'tracker>(&'tracker AtomicUsize);
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl InFlight {
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Tracks a request that may proceed during shutdown, such as a cancellation.
+    fn track(&self) -> InFlightGuard<'_> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard(&self.count)
+    }
+
+    /// Tracks an order submission, failing if a shutdown has started.
+    fn track_order(&self) -> Result<InFlightGuard<'_>> {
+        // Count the request before checking, so that a concurrent shutdown either rejects it or
+        // waits for it
+        let guard = self.track();
+        if self.is_closed() {
+            return Err(Error::validation(
+                "Unable to post orders after shutdown has started",
+            ));
+        }
+
+        Ok(guard)
+    }
+
+    #[cfg(feature = "polling")]
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+
+    #[cfg(feature = "polling")]
+    fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+/// Counts the hits and misses of a local cache.
+#[derive(Debug, Default)]
+struct HitCounter {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl HitCounter {
+    fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "metrics")]
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Hits and misses of the caches filled on demand while building orders.
+#[derive(Debug, Default)]
+struct CacheCounters {
+    tick_size: HitCounter,
+    neg_risk: HitCounter,
+    fee_rate: HitCounter,
+}
+
+/// Bidirectional mapping between client order IDs and exchange order IDs, see
+/// [`SignableOrder::client_order_id`].
+#[derive(Clone, Debug, Default)]
+struct OrderIds {
+    /// Exchange order ID per client order ID
+    by_client_id: DashMap<String, String>,
+    /// Client order ID per exchange order ID
+    by_order_id: DashMap<String, String>,
+}
+
+impl OrderIds {
+    fn insert(&self, client_order_id: String, order_id: String) {
+        if let Some(previous) = self
+            .by_client_id
+            .insert(client_order_id.clone(), order_id.clone())
+        {
+            self.by_order_id.remove(&previous);
+        }
+        self.by_order_id.insert(order_id, client_order_id);
+    }
+
+    /// Forgets the client order ID of the exchange order `order_id`, if any.
+    fn remove(&self, order_id: &str) {
+        if let Some((_, client_order_id)) = self.by_order_id.remove(order_id) {
+            self.by_client_id
+                .remove_if(&client_order_id, |_, mapped| mapped == order_id);
+        }
+    }
+
+    /// Returns the exchange order ID for `id` if it is a known client order ID, or `id` itself
+    /// otherwise.
+    fn resolve<'id>(&self, id: &'id str) -> Cow<'id, str> {
+        self.by_client_id
+            .get(id)
+            .map_or(Cow::Borrowed(id), |order_id| Cow::Owned(order_id.clone()))
+    }
 }
 
+/// The default CLOB API host
+const DEFAULT_HOST: &str = "https://clob.polymarket.com";
+
+/// The staging CLOB API host, backed by the Amoy testnet
+const STAGING_HOST: &str = "https://clob-staging.polymarket.com";
+
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
+/// Returns the chain that orders must be signed for when `host` is a known Polymarket CLOB
+/// deployment, or `None` for any other host (e.g. a local mock or fork).
+fn expected_chain_id(host: &Url) -> Option<ChainId> {
+    match host.host_str()? {
+        "clob.polymarket.com" => Some(POLYGON),
+        "clob-staging.polymarket.com" => Some(AMOY),
+        _ => None,
+    }
+}
+
 #[derive(Debug)]
 struct ClientInner<S: State> {
     config: Config,
@@ -392,14 +862,46 @@ struct ClientInner<S: State> {
     host: Url,
     /// The [`Url`] for the geoblock API endpoint.
     geoblock_host: Url,
-    /// The inner [`ReqwestClient`] used to make requests to `host`.
-    client: ReqwestClient,
+    /// The inner [`HttpClient`] used to make requests to `host`.
+    client: HttpClient,
     /// Local cache of [`TickSize`] per token ID
-    tick_sizes: DashMap<U256, TickSize>,
+    tick_sizes: DashMap<TokenId, TickSize>,
     /// Local cache representing whether this token is part of a `neg_risk` market
-    neg_risk: DashMap<U256, bool>,
+    neg_risk: DashMap<TokenId, bool>,
     /// Local cache representing the fee rate in basis points per token ID
-    fee_rate_bps: DashMap<U256, u32>,
+    fee_rate_bps: DashMap<TokenId, u32>,
+    /// Hits and misses of the tick size, neg risk and fee rate caches
+    cache_counters: CacheCounters,
+    /// Local cache of the last geoblock check and when it was made, used when
+    /// [`Config`]'s `enforce_geoblock` is enabled
+    geoblock: RwLock<Option<(Instant, GeoblockResponse)>>,
+    /// Local cache of the last balance and allowance per asset and when it was fetched, used by
+    /// [`Client::cached_balance`]. Cleared after every successful order post or cancellation.
+    balances: DashMap<BalanceKey, (Instant, BalanceAllowanceResponse)>,
+    /// Local cache of the last [`Client::reward_percentages`] and when it was fetched
+    reward_percentages: RwLock<Option<(Instant, RewardsPercentagesResponse)>>,
+    /// Local cache of the pages of [`Client::current_rewards`] per cursor and when they were
+    /// fetched
+    current_rewards: DashMap<Option<String>, (Instant, Page<CurrentRewardResponse>)>,
+    /// When each order was submitted, keyed by [`SignedOrder::idempotency_key`], used when
+    /// [`Config`]'s `order_dedup_window` is set.
+    submitted_orders: DashMap<B256, Instant>,
+    /// Mapping between the client order IDs of posted orders and their exchange order IDs
+    order_ids: OrderIds,
+    /// Order submissions and cancellations currently in flight, drained on shutdown
+    in_flight: InFlight,
+    /// Expiration per order ID of posted orders that expire, used by
+    /// [`Client::watch_expirations`]. Orders are removed once canceled via this client.
+    expirations: DashMap<String, DateTime<Utc>>,
+    /// Token ID per order ID of the open orders posted in dry-run mode
+    simulated_orders: DashMap<String, TokenId>,
+    /// Local cache of joined markets per condition ID and when they were joined, used by
+    /// [`Client::enriched_market`]
+    #[cfg(feature = "gamma")]
+    enriched_markets: DashMap<B256, (Instant, EnrichedMarket)>,
+    /// Whether the account was in closed-only mode at the last [`Client::closed_only_mode`]
+    /// check. Buy orders are rejected while this is set.
+    closed_only: AtomicBool,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -411,27 +913,228 @@ struct ClientInner<S: State> {
 }
 
 impl<S: State> ClientInner<S> {
-    pub async fn server_time(&self) -> Result<Timestamp> {
+    /// Builds a request to the raw `path` on the CLOB host, see [`Client::send_raw`].
+    fn raw_request(&self, method: Method, path: &str, body: Option<&Value>) -> Result<Request> {
         let request = self
             .client
-            .request(Method::GET, format!("{}time", self.host))
-            .build()?;
+            .request(method, endpoint::raw_url(&self.host, path));
+
+        Ok(match body {
+            Some(body) => request.json(body),
+            None => request,
+        }
+        .build()?)
+    }
+
+    /// Sends `request` and returns the raw response, whatever its status.
+    async fn send_raw(&self, request: Request) -> Result<RawResponse> {
+        let response = self.client.execute(request).await?;
+
+        Ok(RawResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?.to_vec(),
+        })
+    }
+
+    /// Records `order` as posted in dry-run mode and returns a synthetic accepted response, using
+    /// [`SignedOrder::idempotency_key`] as the order ID.
+    fn simulate_post(&self, order: &SignedOrder) -> PostOrderResponse {
+        let order_id = order.idempotency_key().to_string();
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(order_id = %order_id, order = ?order, "dry run: simulated order post");
+
+        self.simulated_orders
+            .insert(order_id.clone(), TokenId::from(order.order.tokenId));
+
+        PostOrderResponse::builder()
+            .making_amount(Decimal::ZERO)
+            .taking_amount(Decimal::ZERO)
+            .order_id(order_id)
+            .status(OrderStatusType::Live)
+            .success(true)
+            .build()
+    }
+
+    /// Cancels the simulated orders among `order_ids` in dry-run mode.
+    fn simulate_cancel<I: IntoIterator<Item = O>, O: AsRef<str>>(
+        &self,
+        order_ids: I,
+    ) -> CancelOrdersResponse {
+        let mut canceled = Vec::new();
+        let mut not_canceled = HashMap::new();
+        for order_id in order_ids {
+            let order_id = order_id.as_ref();
+            if self.simulated_orders.remove(order_id).is_some() {
+                canceled.push(order_id.to_owned());
+            } else {
+                not_canceled.insert(
+                    order_id.to_owned(),
+                    "order is not a simulated open order".to_owned(),
+                );
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(canceled = ?canceled, not_canceled = ?not_canceled, "dry run: simulated cancel");
+
+        CancelOrdersResponse::builder()
+            .canceled(canceled)
+            .not_canceled(not_canceled)
+            .build()
+    }
+
+    /// Runs the configured validators on `orders`, failing on the first denial.
+    async fn validate(&self, orders: &[SignedOrder]) -> Result<()> {
+        for validator in &self.config.validators {
+            for order in orders {
+                verdict(validator.validate_signed(order).await)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks `orders` against the switchboard, the closed-only status and the configured risk
+    /// limits and records them as submitted, see [`Self::record_submission`].
+    fn admit(&self, orders: &[SignedOrder]) -> Result<()> {
+        if let Some(switchboard) = &self.config.switchboard {
+            switchboard.check_orders(orders)?;
+        }
+        if self.closed_only.load(Ordering::Relaxed)
+            && orders
+                .iter()
+                .any(|order| matches!(Side::try_from(order.order.side), Ok(Side::Buy)))
+        {
+            return Err(Error::validation(
+                "Unable to post buy orders while the account is in closed-only mode",
+            ));
+        }
+        if let Some(risk) = &self.config.risk {
+            risk.check(orders)?;
+        }
+        self.record_submission(orders)
+            .inspect_err(|_| self.release(orders))
+    }
 
-        crate::request(&self.client, request, None).await
+    /// Releases the risk exposure of `orders` that were not accepted by the exchange.
+    fn release<'order, I: IntoIterator<Item = &'order SignedOrder>>(&self, orders: I) {
+        if let Some(risk) = &self.config.risk {
+            risk.release(orders);
+        }
+    }
+
+    /// Stops tracking the expiration and client order ID, and releases the risk exposure of the
+    /// `canceled` orders.
+    fn forget_canceled(&self, canceled: &[String]) {
+        for order_id in canceled {
+            self.expirations.remove(order_id);
+            self.order_ids.remove(order_id);
+            if let Some(risk) = &self.config.risk {
+                risk.release_order(order_id);
+            }
+        }
+    }
+
+    /// Records `orders` as submitted, failing without recording any of them if one was already
+    /// submitted within [`Config`]'s `order_dedup_window`.
+    fn record_submission(&self, orders: &[SignedOrder]) -> Result<()> {
+        let Some(window) = self.config.order_dedup_window else {
+            return Ok(());
+        };
+
+        self.submitted_orders
+            .retain(|_, submitted_at| submitted_at.elapsed() < window);
+
+        let now = Instant::now();
+        let mut recorded = Vec::with_capacity(orders.len());
+        for order in orders {
+            let key = order.idempotency_key();
+            if let Some(submitted_at) = self.submitted_orders.insert(key, now) {
+                self.submitted_orders.insert(key, submitted_at);
+                for key in recorded {
+                    self.submitted_orders.remove(&key);
+                }
+                return Err(Error::validation(format!(
+                    "Order {key} was already submitted within the last {window:?}"
+                )));
+            }
+            recorded.push(key);
+        }
+
+        Ok(())
+    }
+
+    /// Starts building a request to `route` on the CLOB host.
+    fn request<'route, R: Into<Route<'route>>>(&self, route: R) -> RequestBuilder {
+        self.request_to(&self.host, route)
+    }
+
+    /// Starts building a request to `route` on `host`.
+    fn request_to<'route, R: Into<Route<'route>>>(&self, host: &Url, route: R) -> RequestBuilder {
+        let route = route.into();
+        self.client.request(route.method(), route.url(host))
+    }
+
+    pub async fn server_time(&self) -> Result<Timestamp> {
+        let request = self.request(Endpoint::ServerTime).build()?;
+
+        let secs: UnixSeconds = crate::request(&self.client, request, None).await?;
+        Ok(Timestamp::from_secs(secs))
     }
 }
 
 impl ClientInner<Unauthenticated> {
+    /// Copies this [`ClientInner`], cloning its local caches.
+    fn duplicate(&self) -> Self {
+        let geoblock = self
+            .geoblock
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let reward_percentages = self
+            .reward_percentages
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        Self {
+            config: self.config.clone(),
+            state: self.state.clone(),
+            host: self.host.clone(),
+            geoblock_host: self.geoblock_host.clone(),
+            client: self.client.clone(),
+            tick_sizes: self.tick_sizes.clone(),
+            neg_risk: self.neg_risk.clone(),
+            fee_rate_bps: self.fee_rate_bps.clone(),
+            cache_counters: CacheCounters::default(),
+            geoblock: RwLock::new(geoblock),
+            balances: self.balances.clone(),
+            reward_percentages: RwLock::new(reward_percentages),
+            current_rewards: self.current_rewards.clone(),
+            submitted_orders: self.submitted_orders.clone(),
+            order_ids: self.order_ids.clone(),
+            in_flight: InFlight::default(),
+            expirations: self.expirations.clone(),
+            simulated_orders: self.simulated_orders.clone(),
+            #[cfg(feature = "gamma")]
+            enriched_markets: self.enriched_markets.clone(),
+            closed_only: AtomicBool::new(false),
+            funder: self.funder,
+            signature_type: self.signature_type,
+            salt_generator: self.salt_generator,
+        }
+    }
+
     pub async fn create_api_key<S: Signer>(
         &self,
         signer: &S,
         nonce: Option<u32>,
+        use_server_time: bool,
     ) -> Result<Credentials> {
-        let request = self
-            .client
-            .request(Method::POST, format!("{}auth/api-key", self.host))
-            .build()?;
-        let headers = self.create_headers(signer, nonce).await?;
+        let request = self.request(Endpoint::CreateApiKey).build()?;
+        let headers = self.create_headers(signer, nonce, use_server_time).await?;
 
         crate::request(&self.client, request, Some(headers)).await
     }
@@ -440,12 +1143,10 @@ impl ClientInner<Unauthenticated> {
         &self,
         signer: &S,
         nonce: Option<u32>,
+        use_server_time: bool,
     ) -> Result<Credentials> {
-        let request = self
-            .client
-            .request(Method::GET, format!("{}auth/derive-api-key", self.host))
-            .build()?;
-        let headers = self.create_headers(signer, nonce).await?;
+        let request = self.request(Endpoint::DeriveApiKey).build()?;
+        let headers = self.create_headers(signer, nonce, use_server_time).await?;
 
         crate::request(&self.client, request, Some(headers)).await
     }
@@ -454,25 +1155,31 @@ impl ClientInner<Unauthenticated> {
         &self,
         signer: &S,
         nonce: Option<u32>,
+        use_server_time: bool,
     ) -> Result<Credentials> {
-        match self.create_api_key(signer, nonce).await {
+        match self.create_api_key(signer, nonce, use_server_time).await {
             Ok(creds) => Ok(creds),
             Err(err) if err.kind() == ErrorKind::Status => {
                 // Only fall back to derive_api_key for HTTP status errors (server responded
                 // with an error, e.g., key already exists). Propagate network/internal errors.
-                self.derive_api_key(signer, nonce).await
+                self.derive_api_key(signer, nonce, use_server_time).await
             }
             Err(err) => Err(err),
         }
     }
 
-    async fn create_headers<S: Signer>(&self, signer: &S, nonce: Option<u32>) -> Result<HeaderMap> {
+    async fn create_headers<S: Signer>(
+        &self,
+        signer: &S,
+        nonce: Option<u32>,
+        use_server_time: bool,
+    ) -> Result<HeaderMap> {
         let chain_id = signer.chain_id().ok_or(Error::validation(
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
+        let timestamp = if use_server_time {
+            self.server_time().await?.as_secs()
         } else {
             Utc::now().timestamp()
         };
@@ -489,7 +1196,7 @@ impl<S: State> Client<S> {
     /// ```no_run
     /// # use polymarket_client_sdk::clob::{Client, Config};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    /// let client = Client::production(Config::default())?;
     /// println!("Host: {}", client.host());
     /// # Ok(())
     /// # }
@@ -499,7 +1206,68 @@ impl<S: State> Client<S> {
         &self.inner.host
     }
 
-    /// Invalidates all internal caches (tick sizes, neg risk flags, and fee rates).
+    /// Returns a clone of this client that fetches the server time for its auth headers when
+    /// `use_server_time` is set, and uses the local clock otherwise, overriding the
+    /// `use_server_time` option of [`Config`] for the calls made through it.
+    ///
+    /// Skipping the server time saves a round trip on latency-sensitive paths such as posting
+    /// orders, while fetching it protects calls such as API key creation against local clock
+    /// skew. The clone shares its state and caches with this client.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_client_sdk::clob::{Client, Config};
+    /// # use alloy::signers::local::LocalSigner;
+    /// # use std::str::FromStr;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::production(Config::default())?;
+    /// let signer = LocalSigner::from_str("0x...")?;
+    ///
+    /// let credentials = client
+    ///     .with_server_time(true)
+    ///     .create_or_derive_api_key(&signer, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_server_time(&self, use_server_time: bool) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            use_server_time: Some(use_server_time),
+            #[cfg(feature = "heartbeats")]
+            heartbeat_token: self.heartbeat_token.clone(),
+        }
+    }
+
+    /// Whether calls made through this handle use the server time for their auth headers.
+    fn uses_server_time(&self) -> bool {
+        self.use_server_time
+            .unwrap_or(self.inner.config.use_server_time)
+    }
+
+    /// Returns the chain that orders must be signed for when this client targets a known
+    /// Polymarket CLOB deployment ([`POLYGON`] for production, [`AMOY`] for staging), or `None`
+    /// for any other host.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_client_sdk::clob::{Client, Config};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::amoy(Config::default())?;
+    /// assert_eq!(client.chain_id(), Some(polymarket_client_sdk::AMOY));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn chain_id(&self) -> Option<ChainId> {
+        expected_chain_id(&self.inner.host)
+    }
+
+    /// Invalidates all internal caches (tick sizes, neg risk flags, fee rates, balances, and
+    /// rewards).
     ///
     /// This method clears the cached market configuration data, forcing subsequent
     /// requests to fetch fresh data from the API. Use this when you suspect
@@ -508,6 +1276,11 @@ impl<S: State> Client<S> {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.balances.clear();
+        self.inner.current_rewards.clear();
+        if let Ok(mut reward_percentages) = self.inner.reward_percentages.write() {
+            *reward_percentages = None;
+        }
     }
 
     /// Pre-populates the tick size cache for a token, avoiding the HTTP call.
@@ -520,14 +1293,14 @@ impl<S: State> Client<S> {
     /// ```no_run
     /// # use polymarket_client_sdk::clob::{Client, Config, types::TickSize};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use polymarket_client_sdk::types::U256;
+    /// use polymarket_client_sdk::types::TokenId;
     ///
-    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
-    /// client.set_tick_size(U256::ZERO, TickSize::Hundredth);
+    /// let client = Client::production(Config::default())?;
+    /// client.set_tick_size(TokenId::default(), TickSize::Hundredth);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_tick_size(&self, token_id: U256, tick_size: TickSize) {
+    pub fn set_tick_size(&self, token_id: TokenId, tick_size: TickSize) {
         self.inner.tick_sizes.insert(token_id, tick_size);
     }
 
@@ -541,14 +1314,14 @@ impl<S: State> Client<S> {
     /// ```no_run
     /// # use polymarket_client_sdk::clob::{Client, Config};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use polymarket_client_sdk::types::U256;
+    /// use polymarket_client_sdk::types::TokenId;
     ///
-    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
-    /// client.set_neg_risk(U256::ZERO, true);
+    /// let client = Client::production(Config::default())?;
+    /// client.set_neg_risk(TokenId::default(), true);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_neg_risk(&self, token_id: U256, neg_risk: bool) {
+    pub fn set_neg_risk(&self, token_id: TokenId, neg_risk: bool) {
         self.inner.neg_risk.insert(token_id, neg_risk);
     }
 
@@ -563,14 +1336,14 @@ impl<S: State> Client<S> {
     /// ```no_run
     /// # use polymarket_client_sdk::clob::{Client, Config};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use polymarket_client_sdk::types::U256;
+    /// use polymarket_client_sdk::types::TokenId;
     ///
-    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
-    /// client.set_fee_rate_bps(U256::ZERO, 10); // 0.10% fee
+    /// let client = Client::production(Config::default())?;
+    /// client.set_fee_rate_bps(TokenId::default(), 10); // 0.10% fee
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_fee_rate_bps(&self, token_id: U256, fee_rate_bps: u32) {
+    pub fn set_fee_rate_bps(&self, token_id: TokenId, fee_rate_bps: u32) {
         self.inner.fee_rate_bps.insert(token_id, fee_rate_bps);
     }
 
@@ -583,15 +1356,29 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the network request fails or the API is unreachable.
     pub async fn ok(&self) -> Result<String> {
-        let request = self
-            .client()
-            .request(Method::GET, self.host().to_owned())
-            .build()?;
+        let request = self.inner.request(Endpoint::Health).build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
 
-    /// Returns the current server timestamp in milliseconds since Unix epoch.
+    /// Opens up to `connections` pooled connections to the CLOB API ahead of time by issuing
+    /// concurrent health checks, so that the first requests after startup or an idle period
+    /// don't pay the TCP and TLS handshake latency.
+    ///
+    /// Connections stay in the pool for the transport's `pool_idle_timeout` (see
+    /// [`transport::Config`]), so call this again periodically to keep them warm during long
+    /// idle stretches. Over HTTP/2 the requests may be multiplexed onto fewer connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the health checks fail.
+    pub async fn prewarm(&self, connections: usize) -> Result<()> {
+        future::try_join_all(iter::repeat_with(|| self.ok()).take(connections)).await?;
+
+        Ok(())
+    }
+
+    /// Returns the current server time. The server reports it in whole seconds.
     ///
     /// # Errors
     ///
@@ -600,21 +1387,52 @@ impl<S: State> Client<S> {
         self.inner.server_time().await
     }
 
-    /// Retrieves the midpoint price for a single market outcome token.
-    ///
-    /// The midpoint is the average of the best bid and best ask prices,
-    /// calculated as `(best_bid + best_ask) / 2`. This represents a fair
-    /// market price estimate for the token.
+    #[cfg(feature = "polling")]
+    /// Estimates the offset between the server clock and the local clock by sampling
+    /// [`Self::server_time`] as configured in `config`, see [`timing`] for details.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the token ID is invalid.
-    pub async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
-        let params = request.query_params(None);
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}midpoint{params}", self.host()))
-            .build()?;
+    /// Returns an error if a request fails or the samples are inconsistent.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn sync_clock(&self, config: SyncConfig) -> Result<ServerClock> {
+        timing::sync(self, config).await
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream that polls the market data selected in `config`, appends every
+    /// observation to `writer` and then yields it, see [`recorder`] for details.
+    ///
+    /// Failed polls and writes are yielded as errors without ending the stream.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn record_market_data(
+        &self,
+        config: RecordConfig,
+        writer: Writer,
+    ) -> impl Stream<Item = Result<Record>> + '_ {
+        recorder::record(self, config, writer)
+    }
+
+    /// Retrieves the midpoint price for a single market outcome token.
+    ///
+    /// The midpoint is the average of the best bid and best ask prices,
+    /// calculated as `(best_bid + best_ask) / 2`. This represents a fair
+    /// market price estimate for the token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the token ID is invalid.
+    pub async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
+        let request = self
+            .inner
+            .request(Endpoint::Midpoint.query(request))
+            .build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
@@ -629,8 +1447,8 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn midpoints(&self, requests: &[MidpointRequest]) -> Result<MidpointsResponse> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}midpoints", self.host()))
+            .inner
+            .request(Endpoint::Midpoints)
             .json(requests)
             .build()?;
 
@@ -646,11 +1464,7 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
-        let params = request.query_params(None);
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}price{params}", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::Price.query(request)).build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
@@ -665,8 +1479,8 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn prices(&self, requests: &[PriceRequest]) -> Result<PricesResponse> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}prices", self.host()))
+            .inner
+            .request(Endpoint::Prices)
             .json(requests)
             .build()?;
 
@@ -682,10 +1496,7 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails.
     pub async fn all_prices(&self) -> Result<PricesResponse> {
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}prices", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::AllPrices).build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
@@ -702,11 +1513,7 @@ impl<S: State> Client<S> {
         &self,
         request: &PriceHistoryRequest,
     ) -> Result<PriceHistoryResponse> {
-        let params = request.query_params(None);
-        let req = self.client().request(
-            Method::GET,
-            format!("{}prices-history{params}", self.host()),
-        );
+        let req = self.inner.request(Endpoint::PriceHistory.query(request));
 
         crate::request(&self.inner.client, req.build()?, None).await
     }
@@ -721,10 +1528,9 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn spread(&self, request: &SpreadRequest) -> Result<SpreadResponse> {
-        let params = request.query_params(None);
         let request = self
-            .client()
-            .request(Method::GET, format!("{}spread{params}", self.host()))
+            .inner
+            .request(Endpoint::Spread.query(request))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -740,8 +1546,8 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn spreads(&self, requests: &[SpreadRequest]) -> Result<SpreadsResponse> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}spreads", self.host()))
+            .inner
+            .request(Endpoint::Spreads)
             .json(requests)
             .build()?;
 
@@ -757,8 +1563,9 @@ impl<S: State> Client<S> {
     /// # Errors
     ///
     /// Returns an error if the request fails or the token ID is invalid.
-    pub async fn tick_size(&self, token_id: U256) -> Result<TickSizeResponse> {
+    pub async fn tick_size(&self, token_id: TokenId) -> Result<TickSizeResponse> {
         if let Some(tick_size) = self.inner.tick_sizes.get(&token_id) {
+            self.inner.cache_counters.tick_size.hit();
             #[cfg(feature = "tracing")]
             tracing::trace!(token_id = %token_id, tick_size = ?tick_size.value(), "cache hit: tick_size");
             return Ok(TickSizeResponse {
@@ -768,10 +1575,11 @@ impl<S: State> Client<S> {
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cache miss: tick_size");
+        self.inner.cache_counters.tick_size.miss();
 
         let request = self
-            .client()
-            .request(Method::GET, format!("{}tick-size", self.host()))
+            .inner
+            .request(Endpoint::TickSize)
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
@@ -797,8 +1605,9 @@ impl<S: State> Client<S> {
     /// # Errors
     ///
     /// Returns an error if the request fails or the token ID is invalid.
-    pub async fn neg_risk(&self, token_id: U256) -> Result<NegRiskResponse> {
+    pub async fn neg_risk(&self, token_id: TokenId) -> Result<NegRiskResponse> {
         if let Some(neg_risk) = self.inner.neg_risk.get(&token_id) {
+            self.inner.cache_counters.neg_risk.hit();
             #[cfg(feature = "tracing")]
             tracing::trace!(token_id = %token_id, neg_risk = *neg_risk, "cache hit: neg_risk");
             return Ok(NegRiskResponse {
@@ -808,10 +1617,11 @@ impl<S: State> Client<S> {
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cache miss: neg_risk");
+        self.inner.cache_counters.neg_risk.miss();
 
         let request = self
-            .client()
-            .request(Method::GET, format!("{}neg-risk", self.host()))
+            .inner
+            .request(Endpoint::NegRisk)
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
@@ -833,8 +1643,9 @@ impl<S: State> Client<S> {
     /// # Errors
     ///
     /// Returns an error if the request fails or the token ID is invalid.
-    pub async fn fee_rate_bps(&self, token_id: U256) -> Result<FeeRateResponse> {
+    pub async fn fee_rate_bps(&self, token_id: TokenId) -> Result<FeeRateResponse> {
         if let Some(base_fee) = self.inner.fee_rate_bps.get(&token_id) {
+            self.inner.cache_counters.fee_rate.hit();
             #[cfg(feature = "tracing")]
             tracing::trace!(token_id = %token_id, base_fee = *base_fee, "cache hit: fee_rate_bps");
             return Ok(FeeRateResponse {
@@ -844,10 +1655,11 @@ impl<S: State> Client<S> {
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cache miss: fee_rate_bps");
+        self.inner.cache_counters.fee_rate.miss();
 
         let request = self
-            .client()
-            .request(Method::GET, format!("{}fee-rate", self.host()))
+            .inner
+            .request(Endpoint::FeeRate)
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
@@ -861,6 +1673,23 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Returns the effective price of trading `token_id` at `price` on `side`, including the
+    /// token's trading fee, see [`fees`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fee rate cannot be retrieved.
+    pub async fn effective_price(
+        &self,
+        token_id: TokenId,
+        side: Side,
+        price: Decimal,
+    ) -> Result<Decimal> {
+        let fee_rate = self.fee_rate_bps(token_id).await?;
+
+        Ok(fees::effective_price(side, price, fee_rate.base_fee))
+    }
+
     /// Checks if the current IP address is geoblocked from accessing Polymarket.
     ///
     /// This method queries the Polymarket geoblock endpoint to determine if access
@@ -875,6 +1704,9 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the HTTP request fails or the response cannot be parsed.
     ///
+    /// To have the authenticated client perform this check automatically before trading, enable
+    /// `enforce_geoblock` on [`Config`].
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -883,7 +1715,7 @@ impl<S: State> Client<S> {
     ///
     /// #[tokio::main]
     /// async fn main() -> anyhow::Result<()> {
-    ///     let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    ///     let client = Client::production(Config::default())?;
     ///
     ///     let geoblock = client.check_geoblock().await?;
     ///
@@ -907,11 +1739,8 @@ impl<S: State> Client<S> {
     /// ```
     pub async fn check_geoblock(&self) -> Result<GeoblockResponse> {
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}api/geoblock", self.inner.geoblock_host),
-            )
+            .inner
+            .request_to(&self.inner.geoblock_host, Endpoint::Geoblock)
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -930,13 +1759,52 @@ impl<S: State> Client<S> {
         &self,
         request: &OrderBookSummaryRequest,
     ) -> Result<OrderBookSummaryResponse> {
-        let params = request.query_params(None);
+        self.order_book_with_metadata(request)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::order_book`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn order_book_with_metadata(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> Result<ResponseEnvelope<OrderBookSummaryResponse>> {
         let request = self
-            .client()
-            .request(Method::GET, format!("{}book{params}", self.host()))
+            .inner
+            .request(Endpoint::OrderBook.query(request))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request_with_metadata(&self.inner.client, request, None).await
+    }
+
+    /// Retrieves the orderbooks of `token_id` and `complement_id`, the two outcome tokens of a
+    /// binary market, and merges them into a synthetic orderbook for `token_id`, see
+    /// [`OrderBookSummaryResponse::merge_complement`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request fails, or if the tokens are not complementary.
+    pub async fn synthetic_order_book(
+        &self,
+        token_id: TokenId,
+        complement_id: TokenId,
+    ) -> Result<OrderBookSummaryResponse> {
+        let (book, complement) = future::try_join(
+            self.order_book(
+                &OrderBookSummaryRequest::builder()
+                    .token_id(token_id)
+                    .build(),
+            ),
+            self.order_book(
+                &OrderBookSummaryRequest::builder()
+                    .token_id(complement_id)
+                    .build(),
+            ),
+        )
+        .await?;
+
+        book.merge_complement(&complement)
     }
 
     /// Retrieves orderbooks for multiple market outcome tokens.
@@ -952,8 +1820,8 @@ impl<S: State> Client<S> {
         requests: &[OrderBookSummaryRequest],
     ) -> Result<Vec<OrderBookSummaryResponse>> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}books", self.host()))
+            .inner
+            .request(Endpoint::OrderBooks)
             .json(requests)
             .build()?;
 
@@ -972,13 +1840,9 @@ impl<S: State> Client<S> {
         &self,
         request: &LastTradePriceRequest,
     ) -> Result<LastTradePriceResponse> {
-        let params = request.query_params(None);
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}last-trade-price{params}", self.host()),
-            )
+            .inner
+            .request(Endpoint::LastTradePrice.query(request))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -997,8 +1861,8 @@ impl<S: State> Client<S> {
         token_ids: &[LastTradePriceRequest],
     ) -> Result<Vec<LastTradesPricesResponse>> {
         let request = self
-            .client()
-            .request(Method::GET, format!("{}last-trades-prices", self.host()))
+            .inner
+            .request(Endpoint::LastTradesPrices)
             .json(token_ids)
             .build()?;
 
@@ -1014,17 +1878,54 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the condition ID is invalid.
     pub async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
-        let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}markets/{condition_id}", self.host()),
-            )
-            .build()?;
+        let request = self.inner.request(Endpoint::Market(condition_id)).build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
 
+    #[cfg(feature = "gamma")]
+    /// Retrieves the market with `condition_id` joined with the Gamma metadata of its event and
+    /// series via `gamma`, see [`enrichment`](crate::clob::enrichment).
+    ///
+    /// Joined markets are cached for [`Config`]'s `enriched_market_ttl`. Markets unknown to the
+    /// Gamma API are returned with the metadata of the CLOB market only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request fails.
+    ///
+    /// # Note
+    ///
+    /// Requires the `gamma` feature to be enabled.
+    pub async fn enriched_market(
+        &self,
+        gamma: &crate::gamma::Client,
+        condition_id: B256,
+    ) -> Result<EnrichedMarket> {
+        if let Some(entry) = self.inner.enriched_markets.get(&condition_id) {
+            let (joined_at, market) = entry.value();
+            if joined_at.elapsed() < self.inner.config.enriched_market_ttl {
+                return Ok(market.clone());
+            }
+        }
+
+        let request = GammaMarketsRequest::builder()
+            .condition_ids(vec![condition_id])
+            .build();
+        let (market, metadata) = future::try_join(
+            self.market(&condition_id.to_string()),
+            gamma.markets(&request),
+        )
+        .await?;
+
+        let enriched = EnrichedMarket::new(market, metadata.first());
+        self.inner
+            .enriched_markets
+            .insert(condition_id, (Instant::now(), enriched.clone()));
+
+        Ok(enriched)
+    }
+
     /// Retrieves a page of all active markets.
     ///
     /// Returns a paginated list of all markets with their full details.
@@ -1035,10 +1936,9 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails.
     pub async fn markets(&self, next_cursor: Option<String>) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(Method::GET, format!("{}markets{cursor}", self.host()))
+            .inner
+            .request(Endpoint::Markets.cursor(next_cursor.as_deref()))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -1057,13 +1957,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}sampling-markets{cursor}", self.host()),
-            )
+            .inner
+            .request(Endpoint::SamplingMarkets.cursor(next_cursor.as_deref()))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -1082,13 +1978,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}simplified-markets{cursor}", self.host()),
-            )
+            .inner
+            .request(Endpoint::SimplifiedMarkets.cursor(next_cursor.as_deref()))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -1107,13 +1999,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}sampling-simplified-markets{cursor}", self.host()),
-            )
+            .inner
+            .request(Endpoint::SamplingSimplifiedMarkets.cursor(next_cursor.as_deref()))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
@@ -1150,11 +2038,70 @@ impl<S: State> Client<S> {
         }
     }
 
-    fn client(&self) -> &ReqwestClient {
+    #[cfg(feature = "polling")]
+    /// Returns a stream of resolution [`StatusChange`]s for the markets in `config`.
+    ///
+    /// Each market is polled via [`Self::market`] at its configured interval. The first
+    /// observation of every market is always yielded (with `previous` set to `None`), after which
+    /// an item is only yielded when the market's [`Status`](crate::clob::resolution::Status)
+    /// changes. If `config` has a Gamma client, the UMA resolution status is fetched alongside
+    /// every market, so that proposed and disputed resolutions are yielded too. Markets stop being
+    /// polled once they are finalized, and the stream ends when no markets remain. Failed polls
+    /// are yielded as errors and retried at the next interval.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_resolutions(
+        &self,
+        config: WatchConfig,
+    ) -> impl Stream<Item = Result<StatusChange>> + '_ {
+        resolution::watch(self, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of [`Warning`]s as the markets in `config` approach their end dates and
+    /// close, so that strategies can stop quoting and flatten their positions in time.
+    ///
+    /// All markets are polled via [`Self::market`] at the configured interval. Each lead time is
+    /// warned about once per market, and the market stops being polled once it is closed. The
+    /// stream ends when no markets remain. Failed polls are yielded as errors and retried at the
+    /// next interval. See [`closing`] for details.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_closing(
+        &self,
+        config: ClosingWatchConfig,
+    ) -> impl Stream<Item = Result<Warning>> + '_ {
+        closing::watch(self, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of [`Alert`]s as the books of the tokens in `config` start or stop
+    /// meeting its [`Thresholds`](crate::clob::alerts::Thresholds), e.g. a spread wider than a
+    /// maximum or a best bid smaller than a minimum size.
+    ///
+    /// All books are fetched via [`Self::order_books`] at the configured interval. Failed fetches
+    /// are yielded as errors and retried at the next interval. See [`alerts`] for details.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_book_alerts(
+        &self,
+        config: AlertsWatchConfig,
+    ) -> impl Stream<Item = Result<Alert>> + '_ {
+        alerts::watch(self, config)
+    }
+
+    fn client(&self) -> &HttpClient {
         &self.inner.client
     }
 }
 
+#[bon]
 impl Client<Unauthenticated> {
     /// Creates a new unauthenticated CLOB client.
     ///
@@ -1182,46 +2129,254 @@ impl Client<Unauthenticated> {
     /// # }
     /// ```
     pub fn new(host: &str, config: Config) -> Result<Client<Unauthenticated>> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-
-        let client = ReqwestClient::builder()
-            .tcp_nodelay(true) // Disable Nagle's algorithm
-            .tcp_keepalive(Some(Duration::from_secs(30))) // Aggressive keepalive
-            .pool_idle_timeout(Some(Duration::from_secs(90)))
-            .default_headers(headers)
-            .build()?;
+        Self::builder().host(host).config(config).build()
+    }
+
+    /// Creates a new unauthenticated CLOB client for the production deployment at
+    /// <https://clob.polymarket.com>, whose orders are signed for [`POLYGON`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be initialized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::clob::{Client, Config};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::production(Config::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn production(config: Config) -> Result<Client<Unauthenticated>> {
+        Self::new(DEFAULT_HOST, config)
+    }
+
+    /// Creates a new unauthenticated CLOB client for the staging deployment at
+    /// <https://clob-staging.polymarket.com>, whose orders are signed for the [`AMOY`] testnet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be initialized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use alloy::signers::Signer as _;
+    /// use alloy::signers::local::LocalSigner;
+    /// use polymarket_client_sdk::clob::{Client, Config};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::amoy(Config::default())?;
+    /// let signer = LocalSigner::random().with_chain_id(client.chain_id());
+    /// let client = client.authentication_builder(&signer).authenticate().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn amoy(config: Config) -> Result<Client<Unauthenticated>> {
+        Self::new(STAGING_HOST, config)
+    }
 
+    /// Returns a builder for an unauthenticated CLOB client, covering the host, [`Config`] and
+    /// the options of the underlying HTTP transport such as timeouts, default headers, the
+    /// `User-Agent`, a proxy, rate limiters and a retry policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid or the HTTP client cannot be initialized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use polymarket_client_sdk::clob::{Client, Config};
+    /// use polymarket_client_sdk::transport::{Config as TransportConfig, RetryPolicy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder()
+    ///     .host("https://clob.polymarket.com")
+    ///     .config(Config::builder().use_server_time(true).build())
+    ///     .transport(
+    ///         TransportConfig::builder()
+    ///             .timeout(Duration::from_secs(10))
+    ///             .retry_policy(RetryPolicy::default())
+    ///             .build(),
+    ///     )
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The CLOB API URL. Defaults to <https://clob.polymarket.com>.
+        #[builder(default = DEFAULT_HOST)]
+        host: &str,
+        /// Client configuration options.
+        #[builder(default)]
+        config: Config,
+        /// Options for the underlying HTTP transport.
+        #[builder(default)]
+        transport: transport::Config,
+    ) -> Result<Client<Unauthenticated>> {
+        let host = Url::parse(host)?;
         let geoblock_host = Url::parse(
             config
                 .geoblock_host
                 .as_deref()
                 .unwrap_or(DEFAULT_GEOBLOCK_HOST),
         )?;
+        // Endpoint paths are appended to the host's path, which requires a base URL
+        if let Some(url) = [&host, &geoblock_host]
+            .into_iter()
+            .find(|url| url.cannot_be_a_base())
+        {
+            return Err(Error::validation(format!("{url} cannot be used as a host")));
+        }
+        let client = transport
+            .build_client()?
+            .with_idempotent_posts(endpoint::idempotent_posts(&host));
 
         Ok(Self {
             inner: Arc::new(ClientInner {
                 config,
-                host: Url::parse(host)?,
+                host,
                 geoblock_host,
                 client,
                 tick_sizes: DashMap::new(),
                 neg_risk: DashMap::new(),
                 fee_rate_bps: DashMap::new(),
+                cache_counters: CacheCounters::default(),
+                geoblock: RwLock::new(None),
+                balances: DashMap::new(),
+                reward_percentages: RwLock::new(None),
+                current_rewards: DashMap::new(),
+                submitted_orders: DashMap::new(),
+                order_ids: OrderIds::default(),
+                in_flight: InFlight::default(),
+                expirations: DashMap::new(),
+                simulated_orders: DashMap::new(),
+                #[cfg(feature = "gamma")]
+                enriched_markets: DashMap::new(),
+                closed_only: AtomicBool::new(false),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
             }),
+            use_server_time: None,
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
         })
     }
 
+    /// Creates an authenticated client and its signer from `POLYMARKET_*` environment variables,
+    /// such as [`PRIVATE_KEY_VAR`](crate::PRIVATE_KEY_VAR) for the private key.
+    ///
+    /// This is a shorthand for [`Settings::from_env`] followed by [`Settings::connect`]. See
+    /// [`bootstrap`](crate::clob::bootstrap) for the supported variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable cannot be parsed, the private key is missing or invalid, or
+    /// authentication fails.
+    pub async fn from_env() -> Result<(Client<Authenticated<Normal>>, PrivateKeySigner)> {
+        Settings::from_env()?.connect().await
+    }
+
+    /// Creates an authenticated client from a [`Session`] previously obtained with
+    /// [`Client::export_session`], without re-deriving API keys.
+    ///
+    /// This is a shorthand for [`Self::new`] followed by [`Self::resume_session`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid, the HTTP client cannot be initialized, or the
+    /// session is invalid, see [`Self::resume_session`].
+    pub async fn from_session(
+        host: &str,
+        config: Config,
+        session: Session,
+    ) -> Result<Client<Authenticated<Normal>>> {
+        Self::new(host, config)?.resume_session(session).await
+    }
+
+    /// Elevates this client to [`Client<Authenticated<Normal>>`] using a [`Session`] previously
+    /// obtained with [`Client::export_session`], without re-deriving API keys.
+    ///
+    /// A [`Builder`] client can be restored by calling [`Client::promote_to_builder`] on the
+    /// result.
+    ///
+    /// The session's funder and signature type are validated the same way as when
+    /// [authenticating](AuthenticationBuilder::authenticate). Like authenticating, this starts
+    /// heartbeats if enabled, hence it must be awaited within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Synchronization`] error if any clone of this client is still alive, or a
+    /// [`Validation`](crate::error::Validation) error if the funder does not fit the signature
+    /// type.
+    #[expect(
+        clippy::unused_async,
+        reason = "Async so that heartbeats are only ever spawned from within a Tokio runtime"
+    )]
+    pub async fn resume_session(self, session: Session) -> Result<Client<Authenticated<Normal>>> {
+        validate_funder(session.funder, Some(session.signature_type))?;
+
+        let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
+
+        let state = Authenticated {
+            address: session.address,
+            credentials: session.credentials,
+            kind: Normal,
+        };
+
+        #[cfg_attr(
+            not(feature = "heartbeats"),
+            expect(
+                unused_mut,
+                reason = "Modifier only needed when heartbeats feature is enabled"
+            )
+        )]
+        let mut client = Client {
+            inner: Arc::new(ClientInner {
+                state,
+                config: inner.config,
+                host: inner.host,
+                geoblock_host: inner.geoblock_host,
+                client: inner.client,
+                tick_sizes: inner.tick_sizes,
+                neg_risk: inner.neg_risk,
+                fee_rate_bps: inner.fee_rate_bps,
+                cache_counters: inner.cache_counters,
+                geoblock: inner.geoblock,
+                balances: DashMap::new(),
+                reward_percentages: RwLock::new(None),
+                current_rewards: DashMap::new(),
+                submitted_orders: DashMap::new(),
+                order_ids: OrderIds::default(),
+                in_flight: InFlight::default(),
+                expirations: DashMap::new(),
+                simulated_orders: DashMap::new(),
+                #[cfg(feature = "gamma")]
+                enriched_markets: DashMap::new(),
+                closed_only: AtomicBool::new(false),
+                funder: session.funder,
+                signature_type: session.signature_type,
+                salt_generator: inner.salt_generator,
+            }),
+            use_server_time: None,
+            #[cfg(feature = "heartbeats")]
+            heartbeat_token: DroppingCancellationToken(None),
+        };
+
+        #[cfg(feature = "heartbeats")]
+        Client::<Authenticated<Normal>>::start_heartbeats(&mut client)?;
+
+        Ok(client)
+    }
+
     /// Creates an authentication builder to upgrade this client to authenticated mode.
     ///
     /// Returns an [`AuthenticationBuilder`] that can be configured with credentials
@@ -1240,7 +2395,7 @@ impl Client<Unauthenticated> {
     /// use std::str::FromStr;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    /// let client = Client::production(Config::default())?;
     /// let signer = LocalSigner::from_str("0x...")?;
     ///
     /// let authenticated_client = client
@@ -1263,6 +2418,8 @@ impl Client<Unauthenticated> {
             signature_type: Some(self.inner.signature_type),
             client: self,
             salt_generator: None,
+            #[cfg(feature = "ctf")]
+            wallet_rpc_url: None,
         }
     }
 
@@ -1273,7 +2430,9 @@ impl Client<Unauthenticated> {
         signer: &S,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
-        self.inner.create_api_key(signer, nonce).await
+        self.inner
+            .create_api_key(signer, nonce, self.uses_server_time())
+            .await
     }
 
     /// Attempts to derive an existing set of [`Credentials`] and returns an error if there
@@ -1283,7 +2442,9 @@ impl Client<Unauthenticated> {
         signer: &S,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
-        self.inner.derive_api_key(signer, nonce).await
+        self.inner
+            .derive_api_key(signer, nonce, self.uses_server_time())
+            .await
     }
 
     /// Idempotent alternative to [`Self::create_api_key`] and [`Self::derive_api_key`], which will
@@ -1294,12 +2455,62 @@ impl Client<Unauthenticated> {
         signer: &S,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
-        self.inner.create_or_derive_api_key(signer, nonce).await
+        self.inner
+            .create_or_derive_api_key(signer, nonce, self.uses_server_time())
+            .await
+    }
+
+    /// Sends an unsigned request to `path` (relative to [`Self::host`], optionally including a
+    /// query string) with an optional JSON `body`, returning the raw response.
+    ///
+    /// This is an escape hatch for endpoints the SDK does not cover yet. Unlike the typed methods,
+    /// a non-success status is not turned into an error. Use the [`Client<Authenticated<K>>`]
+    /// variant for endpoints that require authentication.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be built or sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::clob::{Client, Config};
+    /// use polymarket_client_sdk::error::Method;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::production(Config::default())?;
+    /// let response = client.send_raw(Method::GET, "/time", None).await?;
+    /// println!("{}: {}", response.status, String::from_utf8_lossy(&response.body));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<RawResponse> {
+        let request = self.inner.raw_request(method, path, body)?;
+
+        self.inner.send_raw(request).await
+    }
+
+    /// Concurrently checks the CLOB health endpoint, the server clock skew and the geoblock
+    /// status, returning a readiness [`HealthReport`], see [`health`] for details. Use the
+    /// [`Client<Authenticated<K>>`] variant to also check the API key.
+    ///
+    /// Failed checks are recorded in the report rather than returned as errors.
+    pub async fn health_report(&self) -> HealthReport {
+        Box::pin(health::report(self, None::<future::Ready<Result<()>>>)).await
     }
 }
 
 impl<K: Kind> Client<Authenticated<K>> {
     /// Demotes this authenticated [`Client<Authenticated<K>>`] to an unauthenticated one
+    ///
+    /// Note: If `heartbeats` feature flag is enabled, this stops the background heartbeats task.
+    /// An unauthenticated client cannot continue the heartbeat session, so outstanding orders will
+    /// be cancelled by the server once it lapses.
     #[cfg_attr(
         not(feature = "heartbeats"),
         expect(
@@ -1324,11 +2535,26 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                cache_counters: inner.cache_counters,
+                geoblock: inner.geoblock,
+                // Balances belong to the previously authenticated user
+                balances: DashMap::new(),
+                reward_percentages: RwLock::new(None),
+                current_rewards: DashMap::new(),
+                submitted_orders: DashMap::new(),
+                order_ids: OrderIds::default(),
+                in_flight: InFlight::default(),
+                expirations: DashMap::new(),
+                simulated_orders: DashMap::new(),
+                #[cfg(feature = "gamma")]
+                enriched_markets: DashMap::new(),
+                closed_only: AtomicBool::new(false),
                 // Reset the order parameters that were previously stored on the client
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
             }),
+            use_server_time: None,
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
         })
@@ -1361,18 +2587,85 @@ impl<K: Kind> Client<Authenticated<K>> {
         &self.state().credentials
     }
 
+    /// Exports the state needed to resume this client later with [`Client::from_session`], i.e.
+    /// its address, credentials, funder and signature type.
+    ///
+    /// The exported [`Session`] contains the API secret and passphrase, so treat it as sensitive.
+    /// With the `session-encryption` feature enabled, see `Session::encrypt`.
+    #[must_use]
+    pub fn export_session(&self) -> Session {
+        Session {
+            address: self.address(),
+            credentials: self.credentials().clone(),
+            funder: self.inner.funder,
+            signature_type: self.inner.signature_type,
+        }
+    }
+
+    /// Sends a request to `path` (relative to [`Self::host`], optionally including a query
+    /// string) with an optional JSON `body`, signed with the same L2 headers as every other
+    /// authenticated request, and returns the raw response.
+    ///
+    /// This is an escape hatch for endpoints the SDK does not cover yet. Unlike the typed methods,
+    /// a non-success status is not turned into an error, and no local caches are invalidated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be built, signed or sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::auth::Normal;
+    /// use polymarket_client_sdk::auth::state::Authenticated;
+    /// use polymarket_client_sdk::clob::Client;
+    /// use polymarket_client_sdk::error::Method;
+    ///
+    /// # async fn run(client: Client<Authenticated<Normal>>) -> polymarket_client_sdk::Result<()> {
+    /// let response = client
+    ///     .send_raw(Method::GET, "/data/orders?market=0x1", None)
+    ///     .await?;
+    /// if response.status.is_success() {
+    ///     println!("{}", String::from_utf8_lossy(&response.body));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<RawResponse> {
+        let mut request = self.inner.raw_request(method, path, body)?;
+        let headers = self.create_headers(&request).await?;
+        request.headers_mut().extend(headers);
+
+        self.inner.send_raw(request).await
+    }
+
     /// Return all API keys associated with the address corresponding to the inner signer in
     /// [`Authenticated<K>`].
     pub async fn api_keys(&self) -> Result<ApiKeysResponse> {
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}auth/api-keys", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::ApiKeys).build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Concurrently checks the CLOB health endpoint, the server clock skew, the geoblock status
+    /// and whether the API key is still accepted by fetching [`Self::api_keys`], returning a
+    /// readiness [`HealthReport`], see [`health`] for details.
+    ///
+    /// Failed checks are recorded in the report rather than returned as errors.
+    pub async fn health_report(&self) -> HealthReport {
+        Box::pin(health::report(
+            self,
+            Some(async { self.api_keys().await.map(|_| ()) }),
+        ))
+        .await
+    }
+
     /// Deletes the current API key used by this authenticated client.
     ///
     /// After deletion, this client will no longer be able to access authenticated
@@ -1382,10 +2675,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Returns an error if the request fails or the API key cannot be deleted.
     pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
-        let request = self
-            .client()
-            .request(Method::DELETE, format!("{}auth/api-key", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::DeleteApiKey).build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
@@ -1400,17 +2690,29 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// # Errors
     ///
     /// Returns an error if the request fails.
+    ///
+    /// The result is also recorded for [`Self::is_closed_only`], so that buy orders are rejected
+    /// locally while the account is in closed-only mode.
     pub async fn closed_only_mode(&self) -> Result<BanStatusResponse> {
-        let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}auth/ban-status/closed-only", self.host()),
-            )
-            .build()?;
+        let request = self.inner.request(Endpoint::ClosedOnlyMode).build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: BanStatusResponse =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        self.inner
+            .closed_only
+            .store(response.closed_only, Ordering::Relaxed);
+
+        Ok(response)
+    }
+
+    /// Returns whether the account was in closed-only mode at the last
+    /// [`Self::closed_only_mode`] check. This is `false` until the first check.
+    ///
+    /// Use [`Self::monitor_closed_only`] to keep this up to date in the background.
+    #[must_use]
+    pub fn is_closed_only(&self) -> bool {
+        self.inner.closed_only.load(Ordering::Relaxed)
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
@@ -1426,33 +2728,63 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 
     /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
+    ///
+    /// Fails without signing if the order's [`TimeInForce`](crate::clob::types::TimeInForce) is
+    /// contradictory, e.g. a post-only FOK order or a GTD order without an expiration, or if
+    /// order submission is disabled for its token by the configured
+    /// [`Switchboard`](crate::clob::switchboard::Switchboard).
     #[expect(
         clippy::missing_panics_doc,
         reason = "No need to publicly document as we are guarded by the typestate pattern. \
         We cannot call `sign` without first calling `authenticate`"
     )]
-    pub async fn sign<S: Signer>(
-        &self,
-        signer: &S,
-        SignableOrder {
+    pub async fn sign<S: Signer>(&self, signer: &S, order: SignableOrder) -> Result<SignedOrder> {
+        for validator in &self.inner.config.validators {
+            verdict(validator.validate_signable(&order).await)?;
+        }
+        order.time_in_force()?;
+        if let Some(switchboard) = &self.inner.config.switchboard {
+            switchboard.check(TokenId::from(order.order.tokenId))?;
+        }
+
+        let SignableOrder {
             order,
             order_type,
             post_only,
-        }: SignableOrder,
-    ) -> Result<SignedOrder> {
-        let token_id = order.tokenId;
+            client_order_id,
+        } = order;
+        let token_id = TokenId::from(order.tokenId);
         let neg_risk = self.neg_risk(token_id).await?.neg_risk;
         let chain_id = signer
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
+        let overrides = self.inner.config.order_domain.as_ref();
+        let verifying_contract = overrides.and_then(|domain| {
+            if neg_risk {
+                domain.neg_risk_verifying_contract
+            } else {
+                domain.verifying_contract
+            }
+        });
+        let exchange_contract = match verifying_contract {
+            Some(contract) => contract,
+            None => {
+                contract_config(chain_id, neg_risk)
+                    .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+                    .exchange
+            }
+        };
 
         let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
+            name: overrides
+                .and_then(|domain| domain.name.clone())
+                .map(Cow::Owned)
+                .or(ORDER_NAME),
+            version: overrides
+                .and_then(|domain| domain.version.clone())
+                .map(Cow::Owned)
+                .or(VERSION),
             chain_id: Some(U256::from(chain_id)),
             verifying_contract: Some(exchange_contract),
             ..Eip712Domain::default()
@@ -1468,6 +2800,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             order_type,
             owner: self.state().credentials.key,
             post_only,
+            client_order_id,
         })
     }
 
@@ -1484,15 +2817,48 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// - The user has insufficient balance or allowance
     /// - The order price/size violates market rules
     /// - The request fails
-    pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+    ///
+    /// The returned [`OrderHandle`] dereferences to the [`PostOrderResponse`] and can cancel,
+    /// query and follow the order through this client.
+    pub async fn post_order(&self, order: SignedOrder) -> Result<OrderHandle<'_, K>> {
+        self.post_order_with_metadata(order)
+            .await
+            .map(|envelope| OrderHandle::new(self, envelope.into_body()))
+    }
+
+    /// Like [`Self::post_order`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn post_order_with_metadata(
+        &self,
+        order: SignedOrder,
+    ) -> Result<ResponseEnvelope<PostOrderResponse>> {
+        let _in_flight = self.inner.in_flight.track_order()?;
+        self.inner.validate(slice::from_ref(&order)).await?;
+
         let request = self
-            .client()
-            .request(Method::POST, format!("{}order", self.host()))
+            .inner
+            .request(Endpoint::PostOrder)
             .json(&order)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&request).await?;
+        self.inner.admit(slice::from_ref(&order))?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: ResponseEnvelope<PostOrderResponse> = if self.inner.config.dry_run {
+            ResponseEnvelope::simulated(self.inner.simulate_post(&order))
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers))
+                .await
+                .inspect_err(|_| self.inner.release(slice::from_ref(&order)))
+                .map_err(|e| insufficient_balance(e, Some(&order)))?
+        };
+        self.inner.balances.clear();
+        if !response.body.is_success() {
+            self.inner.release(slice::from_ref(&order));
+        }
+        self.record_posted_orders(iter::once(order), slice::from_ref(&response.body));
+
+        Ok(response)
     }
 
     /// Posts multiple signed orders to the orderbook in a single request.
@@ -1505,25 +2871,218 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Returns an error if any order fails validation or the request fails.
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        self.post_orders_with_metadata(orders)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::post_orders`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn post_orders_with_metadata(
+        &self,
+        orders: Vec<SignedOrder>,
+    ) -> Result<ResponseEnvelope<Vec<PostOrderResponse>>> {
+        let _in_flight = self.inner.in_flight.track_order()?;
+        self.inner.validate(&orders).await?;
+
         let request = self
-            .client()
-            .request(Method::POST, format!("{}orders", self.host()))
+            .inner
+            .request(Endpoint::PostOrders)
             .json(&orders)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&request).await?;
+        self.inner.admit(&orders)?;
+
+        let response: ResponseEnvelope<Vec<PostOrderResponse>> = if self.inner.config.dry_run {
+            ResponseEnvelope::simulated(
+                orders
+                    .iter()
+                    .map(|order| self.inner.simulate_post(order))
+                    .collect(),
+            )
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers))
+                .await
+                .inspect_err(|_| self.inner.release(&orders))
+                .map_err(|e| {
+                    let order = match orders.as_slice() {
+                        [order] => Some(order),
+                        _ => None,
+                    };
+                    insufficient_balance(e, order)
+                })?
+        };
+        self.inner.balances.clear();
+        self.inner.release(
+            orders
+                .iter()
+                .zip(&response.body)
+                .filter(|(_, response)| !response.is_success())
+                .map(|(order, _)| order),
+        );
+        self.record_posted_orders(orders, &response.body);
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        Ok(response)
     }
 
-    /// Attempts to return the corresponding order at the provided `order_id`
+    /// Splits a limit order across several price levels as configured in `config`, signs every
+    /// level with `signer` and posts them via [`Self::post_orders`], see [`ladder`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` is invalid, or if building, signing or posting any of the
+    /// orders fails.
+    pub async fn post_ladder<S: Signer>(
+        &self,
+        signer: &S,
+        config: LadderConfig,
+    ) -> Result<Ladder<K>> {
+        ladder::post(self, signer, config).await
+    }
+
+    /// Returns the IDs of the orders posted in dry-run mode (see [`Config`]'s `dry_run`) that
+    /// have not been canceled since.
+    #[must_use]
+    pub fn simulated_orders(&self) -> Vec<String> {
+        self.inner
+            .simulated_orders
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect()
+    }
+
+    /// Returns the exchange order ID of the order posted by this client with `client_order_id`,
+    /// see [`SignableOrder::client_order_id`].
+    #[must_use]
+    pub fn exchange_order_id(&self, client_order_id: &str) -> Option<String> {
+        self.inner
+            .order_ids
+            .by_client_id
+            .get(client_order_id)
+            .map(|order_id| order_id.clone())
+    }
+
+    /// Returns the client order ID of the order posted by this client with `order_id`, see
+    /// [`SignableOrder::client_order_id`].
+    #[must_use]
+    pub fn client_order_id(&self, order_id: &str) -> Option<String> {
+        self.inner
+            .order_ids
+            .by_order_id
+            .get(order_id)
+            .map(|client_order_id| client_order_id.clone())
+    }
+
+    /// Records the exchange order IDs assigned to `orders` that carry a client order ID and rest on
+    /// the book, the expiration of those that expire, and the risk exposure of those that rest on
+    /// the book.
+    fn record_posted_orders<I: IntoIterator<Item = SignedOrder>>(
+        &self,
+        orders: I,
+        responses: &[PostOrderResponse],
+    ) {
+        for (order, response) in orders.into_iter().zip(responses) {
+            if let Some(risk) = self.risk() {
+                match response.order_id() {
+                    Some(order_id) if !response.status.is_terminal() => {
+                        risk.open_order(order_id, &order);
+                    }
+                    _ => risk.release(iter::once(&order)),
+                }
+            }
+
+            let Some(order_id) = response.order_id() else {
+                continue;
+            };
+
+            if let Some(expiration) = u64::try_from(order.order.expiration)
+                .ok()
+                .filter(|seconds| *seconds > 0)
+                .and_then(|seconds| i64::try_from(seconds).ok())
+                .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            {
+                self.inner
+                    .expirations
+                    .insert(order_id.to_owned(), expiration);
+            }
+            if let Some(client_order_id) = order.client_order_id
+                && !response.status.is_terminal()
+            {
+                self.inner
+                    .order_ids
+                    .insert(client_order_id, order_id.to_owned());
+            }
+        }
+    }
+
+    /// Attempts to return the corresponding order at the provided `order_id`, which may also be
+    /// the client order ID of an order posted by this client (see
+    /// [`SignableOrder::client_order_id`]). The client order ID is forgotten once the order is
+    /// returned in a terminal state (see [`OrderStatusType::is_terminal`]).
     pub async fn order(&self, order_id: &str) -> Result<OpenOrderResponse> {
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}data/order/{order_id}", self.host()))
-            .build()?;
+        let order_id = self.inner.order_ids.resolve(order_id);
+        let request = self.inner.request(Endpoint::Order(&order_id)).build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let order: OpenOrderResponse =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        if order.status.is_terminal() {
+            self.inner.order_ids.remove(&order.id);
+        }
+
+        Ok(order)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Polls [`Self::order`] until the order at `order_id` reaches one of `target_states` or a
+    /// terminal state (see [`OrderStatusType::is_terminal`]), and returns the last observed order.
+    ///
+    /// The poll interval starts at 250 milliseconds and doubles after every poll, up to a maximum
+    /// of five (5) seconds. Callers should check the returned order's `status`, since a terminal
+    /// state outside of `target_states` (e.g. a cancellation while waiting for a match) also ends
+    /// the wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Timeout`](crate::error::Timeout) error if neither condition is met within
+    /// `timeout`, or the first error returned by [`Self::order`].
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn wait_for_order(
+        &self,
+        order_id: &str,
+        target_states: &[OrderStatusType],
+        timeout: Duration,
+    ) -> Result<OpenOrderResponse> {
+        const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+        const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            let order = self.order(order_id).await?;
+            if order.status.is_terminal() || target_states.contains(&order.status) {
+                return Ok(order);
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(Error::timeout(
+                    timeout,
+                    format!(
+                        "order {order_id} is still {} and has not reached {target_states:?}",
+                        order.status
+                    ),
+                ));
+            }
+
+            tokio::time::sleep_until((now + backoff).min(deadline)).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
     }
 
     /// Retrieves a paginated list of orders matching the specified criteria.
@@ -1540,54 +3099,117 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &OrdersRequest,
         next_cursor: Option<String>,
     ) -> Result<Page<OpenOrderResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
         let request = self
-            .client()
-            .request(Method::GET, format!("{}data/orders{params}", self.host()))
+            .inner
+            .request(
+                Endpoint::Orders
+                    .query(request)
+                    .cursor(next_cursor.as_deref()),
+            )
             .build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Runs the calls of `batch` concurrently and collects their results, see [`batch`] for
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by any of the calls, in which case the calls still in
+    /// flight are dropped.
+    pub async fn batch(&self, batch: &Batch) -> Result<Snapshot> {
+        batch::run(self, batch).await
+    }
+
     /// Cancels a single order by its order ID.
     ///
     /// Removes an open order from the orderbook. The order must belong to
     /// the authenticated user and must still be active (not filled or expired).
+    /// Client order IDs of orders posted by this client (see
+    /// [`SignableOrder::client_order_id`]) are accepted in place of order IDs.
     ///
     /// # Errors
     ///
     /// Returns an error if the order ID is invalid, the order doesn't exist,
     /// or the request fails.
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        self.cancel_order_with_metadata(order_id)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::cancel_order`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn cancel_order_with_metadata(
+        &self,
+        order_id: &str,
+    ) -> Result<ResponseEnvelope<CancelOrdersResponse>> {
+        let _in_flight = self.inner.in_flight.track();
+
+        let order_id = self.inner.order_ids.resolve(order_id);
         let request = self
-            .client()
-            .request(Method::DELETE, format!("{}order", self.host()))
+            .inner
+            .request(Endpoint::CancelOrder)
             .json(&json!({ "orderId": order_id }))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: ResponseEnvelope<CancelOrdersResponse> = if self.inner.config.dry_run {
+            ResponseEnvelope::simulated(self.inner.simulate_cancel(iter::once(order_id)))
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers)).await?
+        };
+        self.inner.balances.clear();
+        self.inner.forget_canceled(&response.body.canceled);
+
+        Ok(response)
     }
 
     /// Cancels multiple orders by their order IDs in a single request.
     ///
     /// This is the batch version of [`Self::cancel_order`], allowing efficient
     /// cancellation of many orders at once. All specified orders must belong
-    /// to the authenticated user.
+    /// to the authenticated user. Client order IDs are accepted as in [`Self::cancel_order`].
     ///
     /// # Errors
     ///
     /// Returns an error if any order ID is invalid or the request fails.
     pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
+        self.cancel_orders_with_metadata(order_ids)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::cancel_orders`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn cancel_orders_with_metadata(
+        &self,
+        order_ids: &[&str],
+    ) -> Result<ResponseEnvelope<CancelOrdersResponse>> {
+        let _in_flight = self.inner.in_flight.track();
+
+        let order_ids: Vec<_> = order_ids
+            .iter()
+            .map(|order_id| self.inner.order_ids.resolve(order_id))
+            .collect();
         let request = self
-            .client()
-            .request(Method::DELETE, format!("{}orders", self.host()))
+            .inner
+            .request(Endpoint::CancelOrders)
             .json(&json!(order_ids))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: ResponseEnvelope<CancelOrdersResponse> = if self.inner.config.dry_run {
+            ResponseEnvelope::simulated(self.inner.simulate_cancel(order_ids))
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers)).await?
+        };
+        self.inner.balances.clear();
+        self.inner.forget_canceled(&response.body.canceled);
+
+        Ok(response)
     }
 
     /// Cancels all open orders for the authenticated user.
@@ -1599,13 +3221,36 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Returns an error if the request fails.
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
-        let request = self
-            .client()
-            .request(Method::DELETE, format!("{}cancel-all", self.host()))
-            .build()?;
+        self.cancel_all_orders_with_metadata()
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::cancel_all_orders`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn cancel_all_orders_with_metadata(
+        &self,
+    ) -> Result<ResponseEnvelope<CancelOrdersResponse>> {
+        let _in_flight = self.inner.in_flight.track();
+
+        let request = self.inner.request(Endpoint::CancelAllOrders).build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: ResponseEnvelope<CancelOrdersResponse> = if self.inner.config.dry_run {
+            let order_ids: Vec<String> = self
+                .inner
+                .simulated_orders
+                .iter()
+                .map(|entry| entry.key().clone())
+                .collect();
+            ResponseEnvelope::simulated(self.inner.simulate_cancel(order_ids))
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers)).await?
+        };
+        self.inner.balances.clear();
+        self.inner.forget_canceled(&response.body.canceled);
+
+        Ok(response)
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1614,17 +3259,79 @@ impl<K: Kind> Client<Authenticated<K>> {
         &self,
         request: &CancelMarketOrderRequest,
     ) -> Result<CancelOrdersResponse> {
+        self.cancel_market_orders_with_metadata(request)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`Self::cancel_market_orders`], but also returns the status code, headers and latency of the
+    /// response.
+    pub async fn cancel_market_orders_with_metadata(
+        &self,
+        request: &CancelMarketOrderRequest,
+    ) -> Result<ResponseEnvelope<CancelOrdersResponse>> {
+        let _in_flight = self.inner.in_flight.track();
+
+        let asset_id = request.asset_id;
         let request = self
-            .client()
-            .request(
-                Method::DELETE,
-                format!("{}cancel-market-orders", self.host()),
-            )
+            .inner
+            .request(Endpoint::CancelMarketOrders)
             .json(&request)
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: ResponseEnvelope<CancelOrdersResponse> = if self.inner.config.dry_run {
+            // Markets of simulated orders are not known locally, so only `asset_id` is matched
+            let order_ids: Vec<String> = self
+                .inner
+                .simulated_orders
+                .iter()
+                .filter(|entry| Some(*entry.value()) == asset_id)
+                .map(|entry| entry.key().clone())
+                .collect();
+            ResponseEnvelope::simulated(self.inner.simulate_cancel(order_ids))
+        } else {
+            crate::request_with_metadata(&self.inner.client, request, Some(headers)).await?
+        };
+        self.inner.balances.clear();
+        self.inner.forget_canceled(&response.body.canceled);
+
+        Ok(response)
+    }
+
+    /// Cancels all open orders for each of `token_ids`, e.g. when a strategy rotates out of a set
+    /// of markets.
+    ///
+    /// One [`Self::cancel_market_orders`] request is sent per distinct token, concurrently, and
+    /// their responses are merged into a single [`CancelTokensResponse`]. A failed request does
+    /// not abort the others; the token is recorded in [`CancelTokensResponse::failed`] instead, so
+    /// the caller can retry just the tokens whose orders may still be open.
+    pub async fn cancel_orders_for_tokens(&self, token_ids: &[TokenId]) -> CancelTokensResponse {
+        let mut seen = HashSet::new();
+        let requests = token_ids
+            .iter()
+            .filter(|token_id| seen.insert(**token_id))
+            .map(|&token_id| async move {
+                let request = CancelMarketOrderRequest::builder()
+                    .asset_id(token_id)
+                    .build();
+                (token_id, self.cancel_market_orders(&request).await)
+            });
+
+        let mut consolidated = CancelTokensResponse::default();
+        for (token_id, result) in future::join_all(requests).await {
+            match result {
+                Ok(response) => {
+                    consolidated.canceled.extend(response.canceled);
+                    consolidated.not_canceled.extend(response.not_canceled);
+                }
+                Err(e) => {
+                    consolidated.failed.insert(token_id, e);
+                }
+            }
+        }
+
+        consolidated
     }
 
     /// Retrieves a paginated list of trades for the authenticated user.
@@ -1633,6 +3340,10 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// market, maker/taker side, etc.). Use the `next_cursor` from the response
     /// to fetch subsequent pages.
     ///
+    /// The `side` and `trader_side` filters are not supported by the API and are applied to each
+    /// page locally, see [`TradesRequest::matches`], so a page may hold fewer trades than its
+    /// `limit` even if more follow.
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails.
@@ -1641,14 +3352,129 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &TradesRequest,
         next_cursor: Option<String>,
     ) -> Result<Page<TradeResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}data/trades{params}", self.host()))
+        let http_request = self
+            .inner
+            .request(
+                Endpoint::Trades
+                    .query(request)
+                    .cursor(next_cursor.as_deref()),
+            )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let mut page: Page<TradeResponse> =
+            crate::request(&self.inner.client, http_request, Some(headers)).await?;
+        page.data.retain(|trade| request.matches(trade));
+        page.count = u64::try_from(page.data.len()).unwrap_or(u64::MAX);
+
+        Ok(page)
+    }
+
+    /// Returns a stream of every trade matching `request`, paging through [`Self::trades`].
+    pub fn stream_trades<'client>(
+        &'client self,
+        request: &'client TradesRequest,
+    ) -> impl Stream<Item = Result<TradeResponse>> + 'client {
+        self.stream_data(move |client, cursor| client.trades(request, cursor))
+    }
+
+    #[cfg(feature = "data")]
+    /// Returns a [`PositionTracker`] starting from the current positions of the funder, or the
+    /// signer if there is none, as reported by `data`, see [`positions`](crate::clob::positions).
+    ///
+    /// Every page of positions is fetched, including those below the Data API's default size
+    /// threshold. Fills should then be applied from trades matched after
+    /// [`PositionTracker::as_of`]. If [`Config`]'s `risk` is set, the tracker keeps it up to date,
+    /// see [`PositionTracker::with_risk`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails.
+    ///
+    /// # Note
+    ///
+    /// Requires the `data` feature to be enabled.
+    pub async fn position_tracker(&self, data: &crate::data::Client) -> Result<PositionTracker> {
+        let user = self.inner.funder.unwrap_or_else(|| self.address());
+        let as_of = Utc::now();
+
+        let mut positions = Vec::new();
+        loop {
+            let offset = i32::try_from(positions.len()).unwrap_or(i32::MAX);
+            let request = PositionsRequest::builder()
+                .user(user)
+                .size_threshold(Decimal::ZERO)
+                .map_err(|e| Error::validation(e.to_string()))?
+                .limit(POSITIONS_PAGE_SIZE)
+                .and_then(|builder| builder.offset(offset))
+                .map_err(|e| Error::validation(e.to_string()))?
+                .build();
+
+            let page = data.positions(&request).await?;
+            let done = page.len() < usize::try_from(POSITIONS_PAGE_SIZE).unwrap_or_default();
+            positions.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        let tracker =
+            PositionTracker::from_positions(self.state().credentials.key, as_of, &positions);
+
+        Ok(match &self.inner.config.risk {
+            Some(risk) => tracker.with_risk(Arc::clone(risk)),
+            None => tracker,
+        })
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of [`Fill`]s for the order at `order_id`.
+    ///
+    /// The order is polled via [`Self::order`] every `interval`, and every newly associated trade
+    /// is looked up via [`Self::trades`] and yielded once, with the size and price at which it
+    /// filled this order. The stream ends once the order reaches a terminal state (see
+    /// [`OrderStatusType::is_terminal`]) and all of its trades have been yielded. Failed polls
+    /// are yielded as errors and retried at the next interval.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn stream_fills(
+        &self,
+        order_id: &str,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Fill>> + '_ {
+        fills::stream(self, order_id.to_owned(), interval)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a [`Runner`] that drives a [`Strategy`](crate::clob::strategy::Strategy) with the
+    /// books, fills, timer ticks and RFQ requests configured in `config`, see
+    /// [`strategy`](crate::clob::strategy).
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    #[must_use]
+    pub fn strategy_runner(&self, config: StrategyConfig) -> Runner<'_, K> {
+        Runner::new(self, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of the changes to the open orders, see [`open_orders`] for details.
+    ///
+    /// The open orders are paged through via [`Self::orders`] every `interval`. Failed polls are
+    /// yielded as errors, and the next successful poll is compared against the last successful
+    /// one.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_open_orders(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<OrderEvent>> + '_ {
+        open_orders::watch(self, interval)
     }
 
     /// Retrieves all notifications for the authenticated user.
@@ -1660,9 +3486,33 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Returns an error if the request fails.
     pub async fn notifications(&self) -> Result<Vec<NotificationResponse>> {
+        self.fetch_notifications().await
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of newly received [`Notification`]s, with payloads parsed into typed
+    /// [`NotificationKind`](crate::clob::types::response::NotificationKind)s.
+    ///
+    /// Notifications are polled at the interval configured in `config` and deduplicated by ID, so
+    /// each notification is yielded once even if it remains in the user's list across polls. If
+    /// `config` enables deletion, yielded notifications are then removed via
+    /// [`Self::delete_notifications`]. Failed polls and deletions are yielded as errors and
+    /// retried at the next interval.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn stream_notifications(
+        &self,
+        config: StreamConfig,
+    ) -> impl Stream<Item = Result<Notification>> + '_ {
+        notifications::stream(self, config)
+    }
+
+    pub(crate) async fn fetch_notifications<T: DeserializeOwned>(&self) -> Result<Vec<T>> {
         let request = self
-            .client()
-            .request(Method::GET, format!("{}notifications", self.host()))
+            .inner
+            .request(Endpoint::Notifications)
             .query(&[("signature_type", self.inner.signature_type as u8)])
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1670,23 +3520,58 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
-    /// Deletes notifications matching the specified IDs.
+    /// Deletes the notifications matching the specified IDs, or every notification if the
+    /// request's `all` flag is set, and returns how many were deleted.
     ///
     /// Removes notifications from the user's notification list. This is useful
-    /// for cleaning up old notifications after they've been processed.
+    /// for cleaning up old notifications after they've been processed. Since the CLOB does not
+    /// report what it deleted, the notifications are fetched before and after the deletion, and
+    /// only those that were listed before but are gone afterwards are counted.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the notification IDs are invalid.
-    pub async fn delete_notifications(&self, request: &DeleteNotificationsRequest) -> Result<()> {
-        let params = request.query_params(None);
+    /// Returns an error if any request fails or the notification IDs are invalid.
+    pub async fn delete_notifications(
+        &self,
+        request: &DeleteNotificationsRequest,
+    ) -> Result<usize> {
+        let before = self.notification_ids().await?;
+        let ids: Vec<String> = if request.all {
+            before.iter().cloned().collect()
+        } else {
+            request.notification_ids.clone()
+        };
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        self.send_delete_notifications(ids.clone()).await?;
+
+        let after = self.notification_ids().await?;
+        Ok(ids
+            .iter()
+            .filter(|id| before.contains(*id) && !after.contains(*id))
+            .count())
+    }
+
+    /// Returns the IDs of the user's notifications.
+    async fn notification_ids(&self) -> Result<HashSet<String>> {
+        let notifications: Vec<Notification> = self.fetch_notifications().await?;
+
+        Ok(notifications
+            .into_iter()
+            .filter_map(|notification| notification.id)
+            .collect())
+    }
+
+    /// Deletes the notifications with the given IDs, without checking what was deleted.
+    pub(crate) async fn send_delete_notifications(&self, ids: Vec<String>) -> Result<()> {
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids(ids)
+            .build();
         let mut request = self
-            .client()
-            .request(
-                Method::DELETE,
-                format!("{}notifications{params}", self.host()),
-            )
-            .json(&request)
+            .inner
+            .request(Endpoint::DeleteNotifications.query(&request))
             .build()?;
         let headers = self.create_headers(&request).await?;
         *request.headers_mut() = headers;
@@ -1715,53 +3600,257 @@ impl<K: Kind> Client<Authenticated<K>> {
             request.signature_type = Some(self.inner.signature_type);
         }
 
-        let params = request.query_params(None);
+        let key = (
+            request.asset_type.clone(),
+            request.token_id,
+            request.signature_type.unwrap_or_default(),
+        );
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}balance-allowance{params}", self.host()),
-            )
+            .inner
+            .request(Endpoint::BalanceAllowance.query(&request))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: BalanceAllowanceResponse =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        self.inner
+            .balances
+            .insert(key, (Instant::now(), response.clone()));
+
+        Ok(response)
+    }
+
+    /// Returns the user's balance and allowances for the asset in `request`, reusing the last
+    /// result of [`Self::balance_allowance`] if it is younger than [`Config`]'s `balance_ttl`.
+    ///
+    /// The cache is cleared after every successful [`Self::post_order`], [`Self::post_orders`],
+    /// [`Self::update_balance_allowance`], or cancellation, so pre-trade checks can call this
+    /// before every order without adding a round trip, while still observing the effects of
+    /// the client's own trades.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cached value is missing or stale and the request fails.
+    pub async fn cached_balance(
+        &self,
+        mut request: BalanceAllowanceRequest,
+    ) -> Result<BalanceAllowanceResponse> {
+        if request.signature_type.is_none() {
+            request.signature_type = Some(self.inner.signature_type);
+        }
+
+        let key = (
+            request.asset_type.clone(),
+            request.token_id,
+            request.signature_type.unwrap_or_default(),
+        );
+        if let Some(entry) = self.inner.balances.get(&key) {
+            let (fetched_at, response) = entry.value();
+            if fetched_at.elapsed() < self.inner.config.balance_ttl {
+                return Ok(response.clone());
+            }
+        }
+
+        self.balance_allowance(request).await
+    }
+
+    /// Forces an update of the cached balance and allowance data.
+    ///
+    /// Triggers the CLOB backend to refresh its cached view of the user's
+    /// on-chain balance and allowances. Use this after approving tokens or
+    /// depositing USDC to ensure the exchange recognizes the updated state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn update_balance_allowance(
+        &self,
+        mut request: UpdateBalanceAllowanceRequest,
+    ) -> Result<()> {
+        if request.signature_type.is_none() {
+            request.signature_type = Some(self.inner.signature_type);
+        }
+        let mut request = self
+            .inner
+            .request(Endpoint::UpdateBalanceAllowance.query(&request))
+            .build()?;
+        let headers = self.create_headers(&request).await?;
+
+        *request.headers_mut() = headers;
+
+        // We have to send the request separately from `self.request` because this endpoint does
+        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
+        self.client().execute(request).await?;
+        self.inner.balances.clear();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    /// Spawns a background task that checks [`Self::closed_only_mode`] as configured in `config`,
+    /// see [`restrictions`] for details.
+    ///
+    /// The task holds a clone of this [`Client`] and runs until the returned [`Monitor`] is
+    /// dropped.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    #[must_use]
+    pub fn monitor_closed_only(&self, config: MonitorConfig) -> Monitor {
+        restrictions::monitor(self.clone(), config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of the scoring status of all open orders, polled as configured in
+    /// `config`, see [`scoring`] for details.
+    ///
+    /// Failed polls are yielded as errors without ending the stream.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_scoring(
+        &self,
+        config: ScoringWatchConfig,
+    ) -> impl Stream<Item = Result<OrdersScoringResponse>> + '_ {
+        scoring::watch(self, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of [`Expiry`]s for orders posted via this client that carry an
+    /// `expiration`, i.e. good-til-date orders.
+    ///
+    /// Every interval configured in `config`, orders whose expiration is within the configured
+    /// lead time are canceled (unless disabled in `config`) and yielded, so that strategies can
+    /// replace them without relying on the exchange's own expiry handling. Orders canceled via
+    /// this client are no longer tracked. Failed cancellations are yielded as errors, after
+    /// which the affected orders are no longer tracked.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn watch_expirations(
+        &self,
+        config: ExpiryWatchConfig,
+    ) -> impl Stream<Item = Result<Expiry>> + '_ {
+        expirations::watch(self, config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Removes and returns the tracked orders that expire at or before `deadline`.
+    pub(crate) fn take_expiring(&self, deadline: DateTime<Utc>) -> Vec<(String, DateTime<Utc>)> {
+        let expiring: Vec<String> = self
+            .inner
+            .expirations
+            .iter()
+            .filter(|entry| *entry.value() <= deadline)
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        expiring
+            .into_iter()
+            .filter_map(|order_id| self.inner.expirations.remove(&order_id))
+            .collect()
+    }
+
+    #[cfg(feature = "polling")]
+    /// Posts `order` in the background once `clock` reaches the server time `at`, or immediately
+    /// if `at` has already passed.
+    ///
+    /// The order should be signed ahead of time, so that only the request itself is sent at
+    /// `at`. Use [`Self::schedule_signable_order`] to sign and schedule in one go.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    #[must_use]
+    pub fn schedule_order(
+        &self,
+        order: SignedOrder,
+        at: DateTime<Utc>,
+        clock: &ServerClock,
+    ) -> ScheduledOrder {
+        timing::schedule(self.clone(), order, at, clock)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Signs `order` right away and schedules it via [`Self::schedule_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing the order fails.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn schedule_signable_order<S: Signer>(
+        &self,
+        signer: &S,
+        order: SignableOrder,
+        at: DateTime<Utc>,
+        clock: &ServerClock,
+    ) -> Result<ScheduledOrder> {
+        let order = self.sign(signer, order).await?;
+        Ok(self.schedule_order(order, at, clock))
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a [`Shutdown`] handle that gracefully shuts this client down, e.g. on SIGTERM.
+    ///
+    /// The handle holds a clone of this [`Client`], so it keeps a running heartbeat task alive
+    /// until [`Shutdown::shutdown`] stops it.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    #[must_use]
+    pub fn shutdown_handle(&self, config: ShutdownConfig) -> Shutdown<K> {
+        Shutdown::new(self.clone(), config)
+    }
+
+    #[cfg(feature = "polling")]
+    /// Rejects new order submissions and stops automatic heartbeats.
+    pub(crate) fn begin_shutdown(&self) {
+        self.inner.in_flight.close();
+
+        #[cfg(feature = "heartbeats")]
+        if let Some(supervisor) = &self.heartbeat_token.0 {
+            supervisor.token.cancel();
+        }
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns the number of order submissions and cancellations currently in flight.
+    pub(crate) fn in_flight(&self) -> usize {
+        self.inner.in_flight.count()
+    }
+
+    /// Returns the configured risk engine, if any.
+    pub(crate) fn risk(&self) -> Option<&RiskEngine> {
+        self.inner.config.risk.as_deref()
     }
 
-    /// Forces an update of the cached balance and allowance data.
+    #[cfg(all(feature = "ctf", feature = "polling"))]
+    /// Returns a stream of [`Deposit`]s into the account's funder, or into the signer's address if
+    /// no funder is configured.
     ///
-    /// Triggers the CLOB backend to refresh its cached view of the user's
-    /// on-chain balance and allowances. Use this after approving tokens or
-    /// depositing USDC to ensure the exchange recognizes the updated state.
+    /// The on-chain collateral balance is read via `ctf` at the interval configured in `config`.
+    /// The first read establishes a baseline; afterwards, every increase triggers
+    /// [`Self::update_balance_allowance`] for the collateral and is then yielded as a [`Deposit`].
+    /// Decreases (e.g. withdrawals) silently move the baseline. Failed reads and refreshes are
+    /// yielded as errors and retried at the next interval.
     ///
-    /// # Errors
+    /// # Note
     ///
-    /// Returns an error if the request fails.
-    pub async fn update_balance_allowance(
-        &self,
-        mut request: UpdateBalanceAllowanceRequest,
-    ) -> Result<()> {
-        if request.signature_type.is_none() {
-            request.signature_type = Some(self.inner.signature_type);
-        }
-
-        let params = request.query_params(None);
-        let mut request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}balance-allowance/update{params}", self.host()),
-            )
-            .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        *request.headers_mut() = headers;
-
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
-
-        Ok(())
+    /// Requires the `ctf` and `polling` features to be enabled.
+    pub fn watch_deposits<'client, P: alloy::providers::Provider + Clone>(
+        &'client self,
+        ctf: &'client crate::ctf::Client<P>,
+        config: DepositWatchConfig,
+    ) -> impl Stream<Item = Result<Deposit>> + 'client {
+        let owner = self.inner.funder.unwrap_or_else(|| self.address());
+        deposits::watch(self, ctf, owner, config)
     }
 
     /// Checks if an order is eligible for market maker rewards.
@@ -1775,8 +3864,8 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the order ID is invalid or the request fails.
     pub async fn is_order_scoring(&self, order_id: &str) -> Result<OrderScoringResponse> {
         let request = self
-            .client()
-            .request(Method::GET, format!("{}order-scoring", self.host()))
+            .inner
+            .request(Endpoint::OrderScoring)
             .query(&[("order_id", order_id)])
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1784,18 +3873,75 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    #[cfg(feature = "polling")]
+    /// Polls [`Self::is_order_scoring`] until the order at `order_id` is scoring for market maker
+    /// rewards, e.g. to confirm that a freshly posted quote is earning.
+    ///
+    /// The poll interval starts at 250 milliseconds and doubles after every poll, up to a maximum
+    /// of five (5) seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Timeout`](crate::error::Timeout) error if the order is not scoring within
+    /// `timeout`, or the first error returned by [`Self::is_order_scoring`].
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn wait_until_scoring(&self, order_id: &str, timeout: Duration) -> Result<()> {
+        const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+        const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            if self.is_order_scoring(order_id).await?.scoring {
+                return Ok(());
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(Error::timeout(
+                    timeout,
+                    format!("order {order_id} is not scoring"),
+                ));
+            }
+
+            tokio::time::sleep_until((now + backoff).min(deadline)).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
     /// Checks if multiple orders are eligible for market maker rewards.
     ///
     /// This is the batch version of [`Self::is_order_scoring`], allowing efficient
-    /// checking of reward eligibility for many orders at once.
+    /// checking of reward eligibility for many orders at once. Large sets of order IDs are split
+    /// into chunks of one hundred (100), which are requested concurrently, subject to the
+    /// transport's rate limiters.
     ///
     /// # Errors
     ///
-    /// Returns an error if any order ID is invalid or the request fails.
+    /// Returns an error if any order ID is invalid or any request fails.
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
+        if order_ids.len() <= ORDERS_SCORING_CHUNK {
+            return self.orders_scoring_chunk(order_ids).await;
+        }
+
+        let chunks = future::try_join_all(
+            order_ids
+                .chunks(ORDERS_SCORING_CHUNK)
+                .map(|chunk| self.orders_scoring_chunk(chunk)),
+        )
+        .await?;
+
+        Ok(chunks.into_iter().flatten().collect())
+    }
+
+    async fn orders_scoring_chunk(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}orders-scoring", self.host()))
+            .inner
+            .request(Endpoint::OrdersScoring)
             .json(&order_ids)
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1816,10 +3962,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         date: NaiveDate,
         next_cursor: Option<String>,
     ) -> Result<Page<UserEarningResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(Method::GET, format!("{}rewards/user{cursor}", self.host()))
+            .inner
+            .request(Endpoint::UserEarnings.cursor(next_cursor.as_deref()))
             .query(&[
                 ("date", date.to_string()),
                 (
@@ -1846,8 +3991,8 @@ impl<K: Kind> Client<Authenticated<K>> {
         date: NaiveDate,
     ) -> Result<Vec<TotalUserEarningResponse>> {
         let request = self
-            .client()
-            .request(Method::GET, format!("{}rewards/user/total", self.host()))
+            .inner
+            .request(Endpoint::UserTotalEarnings)
             .query(&[
                 ("date", date.to_string()),
                 (
@@ -1874,12 +4019,12 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &UserRewardsEarningRequest,
         next_cursor: Option<String>,
     ) -> Result<Vec<UserRewardsEarningResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
         let request = self
-            .client()
+            .inner
             .request(
-                Method::GET,
-                format!("{}rewards/user/total{params}", self.host()),
+                Endpoint::UserTotalEarnings
+                    .query(request)
+                    .cursor(next_cursor.as_deref()),
             )
             .query(&[(
                 "signature_type",
@@ -1896,16 +4041,33 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns the percentage of total rewards the user is earning across
     /// different markets, indicating market making performance relative to others.
     ///
+    /// Results are cached for [`Config`]'s `rewards_ttl`.
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails.
     pub async fn reward_percentages(&self) -> Result<RewardsPercentagesResponse> {
+        let cached = self
+            .inner
+            .reward_percentages
+            .read()
+            .map_err(|_poisoned| Synchronization)?
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.inner.config.rewards_ttl)
+            .map(|(_, response)| response.clone());
+        if let Some(response) = cached {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("cache hit: reward percentages");
+
+            return Ok(response);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!("cache miss: reward percentages");
+
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}rewards/user/percentages", self.host()),
-            )
+            .inner
+            .request(Endpoint::RewardPercentages)
             .query(&[(
                 "signature_type",
                 (self.inner.signature_type as u8).to_string(),
@@ -1913,14 +4075,23 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: RewardsPercentagesResponse =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        *self
+            .inner
+            .reward_percentages
+            .write()
+            .map_err(|_poisoned| Synchronization)? = Some((Instant::now(), response.clone()));
+
+        Ok(response)
     }
 
     /// Retrieves current active reward programs and their configurations.
     ///
     /// Returns information about ongoing reward programs, including eligible markets,
     /// reward amounts, and program parameters. Use this to discover opportunities
-    /// for earning market maker rewards.
+    /// for earning market maker rewards. Pages are cached per cursor for [`Config`]'s
+    /// `rewards_ttl`, see [`rewards`](crate::clob::rewards) for choosing quotes from them.
     ///
     /// # Errors
     ///
@@ -1929,17 +4100,32 @@ impl<K: Kind> Client<Authenticated<K>> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<CurrentRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        if let Some(entry) = self.inner.current_rewards.get(&next_cursor) {
+            let (fetched_at, page) = entry.value();
+            if fetched_at.elapsed() < self.inner.config.rewards_ttl {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(cursor = ?next_cursor, "cache hit: current rewards");
+
+                return Ok(page.clone());
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(cursor = ?next_cursor, "cache miss: current rewards");
+
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}rewards/markets/current{cursor}", self.host()),
-            )
+            .inner
+            .request(Endpoint::CurrentRewards.cursor(next_cursor.as_deref()))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let page: Page<CurrentRewardResponse> =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        self.inner
+            .current_rewards
+            .insert(next_cursor, (Instant::now(), page.clone()));
+
+        Ok(page)
     }
 
     /// Retrieves detailed reward data for a specific market.
@@ -1955,19 +4141,71 @@ impl<K: Kind> Client<Authenticated<K>> {
         condition_id: &str,
         next_cursor: Option<String>,
     ) -> Result<Page<MarketRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
         let request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}rewards/markets/{condition_id}{cursor}", self.host()),
-            )
+            .inner
+            .request(Endpoint::MarketRewards(condition_id).cursor(next_cursor.as_deref()))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Returns the liquidity rewards earned on every day from `start` to `end`, inclusive, per
+    /// market and reward token. See [`earnings`](crate::clob::earnings) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `end` is before `start`, or if fetching the earnings of any day or the
+    /// reward programs of any market fails.
+    pub async fn rewards_ledger(&self, start: NaiveDate, end: NaiveDate) -> Result<Ledger> {
+        if end < start {
+            return Err(Error::validation(format!(
+                "Unable to export rewards: end date {end} is before start date {start}"
+            )));
+        }
+
+        let mut ledger = Ledger::default();
+        let mut programs: HashMap<B256, Vec<MarketRewardsConfig>> = HashMap::new();
+        for date in start.iter_days().take_while(|date| *date <= end) {
+            let request = UserRewardsEarningRequest::builder().date(date).build();
+            let markets = self
+                .user_earnings_and_markets_config(&request, None)
+                .await?;
+            for market in markets {
+                if market
+                    .earnings
+                    .iter()
+                    .all(|earning| earning.earnings.is_zero())
+                {
+                    continue;
+                }
+
+                let config = match programs.entry(market.condition_id) {
+                    hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                    hash_map::Entry::Vacant(entry) => {
+                        let condition_id = market.condition_id.to_string();
+                        let rewards: Vec<_> = self
+                            .stream_data(|client, cursor| {
+                                client.raw_rewards_for_market(&condition_id, cursor)
+                            })
+                            .try_collect()
+                            .await?;
+                        entry.insert(
+                            rewards
+                                .into_iter()
+                                .filter(|reward| reward.condition_id == market.condition_id)
+                                .flat_map(|reward| reward.rewards_config)
+                                .collect(),
+                        )
+                    }
+                };
+                ledger.add(date, &market, config);
+            }
+        }
+
+        Ok(ledger)
+    }
+
     /// Creates a new Builder API key for order attribution.
     ///
     /// Builder API keys allow you to attribute orders to your builder account,
@@ -1978,10 +4216,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Returns an error if the request fails or the account is not eligible for builder keys.
     pub async fn create_builder_api_key(&self) -> Result<Credentials> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}auth/builder-api-key", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::CreateBuilderApiKey).build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
@@ -1998,8 +4233,8 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn post_heartbeat(&self, heartbeat_id: Option<Uuid>) -> Result<HeartbeatResponse> {
         let request = self
-            .client()
-            .request(Method::POST, format!("{}v1/heartbeats", self.host()))
+            .inner
+            .request(Endpoint::Heartbeat)
             .json(&json!({ "heartbeat_id": heartbeat_id }))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -2007,6 +4242,79 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    #[cfg(feature = "metrics")]
+    /// Takes a [`Snapshot`](metrics::Snapshot) of the rate limiters, caches, heartbeats and open
+    /// orders of this client, see [`metrics`]. The open orders are omitted if they cannot be
+    /// fetched.
+    ///
+    /// # Note
+    ///
+    /// Requires the `metrics` feature to be enabled.
+    pub async fn metrics(&self) -> metrics::Snapshot {
+        let open_orders = self.count_open_orders().await;
+        self.metrics_with(open_orders)
+    }
+
+    #[cfg(feature = "metrics")]
+    /// Counts the open orders, or returns `None` if they cannot be fetched.
+    async fn count_open_orders(&self) -> Option<u64> {
+        let request = OrdersRequest::default();
+        self.stream_data(|client, cursor| client.orders(&request, cursor))
+            .try_fold(0_u64, |count, _| future::ready(Ok(count + 1)))
+            .await
+            .ok()
+    }
+
+    #[cfg(feature = "metrics")]
+    /// Takes a [`Snapshot`](metrics::Snapshot) with the given count of `open_orders`.
+    fn metrics_with(&self, open_orders: Option<u64>) -> metrics::Snapshot {
+        #[cfg(feature = "heartbeats")]
+        let (heartbeats_active, last_heartbeat_sent_at) =
+            (self.heartbeats_active(), self.last_heartbeat_sent_at());
+        #[cfg(not(feature = "heartbeats"))]
+        let (heartbeats_active, last_heartbeat_sent_at) = (false, None);
+
+        let counters = &self.inner.cache_counters;
+        metrics::Snapshot {
+            rate_limiter: self.inner.client.rate_limiters().stats(),
+            tick_size_cache: counters.tick_size.stats(),
+            neg_risk_cache: counters.neg_risk.stats(),
+            fee_rate_cache: counters.fee_rate.stats(),
+            heartbeats_active,
+            last_heartbeat_sent_at,
+            open_orders,
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    /// Serves the [`metrics`](Self::metrics) of this client in the Prometheus text format on
+    /// `http://{addr}/metrics`, until the returned [`MetricsServer`] is dropped.
+    ///
+    /// The endpoint keeps a clone of this client, and thus its automatic heartbeats, alive for as
+    /// long as it is served. The open orders are counted at most once every
+    /// [`OPEN_ORDERS_REFRESH`](metrics::OPEN_ORDERS_REFRESH), see [`metrics`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `addr` cannot be bound.
+    ///
+    /// # Note
+    ///
+    /// Requires the `metrics` feature to be enabled.
+    pub async fn serve_metrics(&self, addr: SocketAddr) -> Result<MetricsServer> {
+        let client = self.clone();
+        let open_orders = metrics::OpenOrdersCache::default();
+        metrics::serve(addr, move || {
+            let client = client.clone();
+            let open_orders = open_orders.clone();
+            async move {
+                let count = open_orders.get(|| client.count_open_orders()).await;
+                client.metrics_with(count)
+            }
+        })
+        .await
+    }
+
     #[cfg(feature = "heartbeats")]
     /// Checks if automatic heartbeats are currently active.
     ///
@@ -2024,6 +4332,9 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// interval. This maintains order priority without manual intervention. The heartbeat
     /// interval is configured in [`Config`]'s `heartbeat_interval`.
     ///
+    /// The task is shared by every clone of `client` made afterwards, and is only cancelled once
+    /// the last of them is dropped or [`Self::stop_heartbeats`] is called on the sole remaining one.
+    ///
     /// # Errors
     ///
     /// Returns an error if heartbeats are already active.
@@ -2032,52 +4343,114 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// Requires the `heartbeats` feature to be enabled.
     pub fn start_heartbeats(client: &mut Client<Authenticated<K>>) -> Result<()> {
+        Self::spawn_heartbeats(client, None)
+    }
+
+    #[cfg(feature = "heartbeats")]
+    /// Returns when the most recent automatic heartbeat accepted by the CLOB was sent, by the
+    /// local clock, or `None` if none has been sent yet or heartbeats are not active.
+    ///
+    /// # Note
+    ///
+    /// Requires the `heartbeats` feature to be enabled.
+    #[must_use]
+    pub fn last_heartbeat_sent_at(&self) -> Option<DateTime<Utc>> {
+        let supervisor = self.heartbeat_token.0.as_ref()?;
+        *supervisor
+            .last_sent
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(feature = "heartbeats")]
+    /// Spawns the heartbeat task, continuing the session identified by `heartbeat_id` if present.
+    /// A continued session sends its first heartbeat immediately, rather than after a full
+    /// interval, so that it does not lapse while being handed off.
+    fn spawn_heartbeats(
+        client: &mut Client<Authenticated<K>>,
+        heartbeat_id: Option<Uuid>,
+    ) -> Result<()> {
         if client.heartbeats_active() {
             return Err(Error::validation("Unable to create another heartbeat task"));
         }
 
         let token = CancellationToken::new();
-        let duration = client.inner.config.heartbeat_interval;
-        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let config = &client.inner.config;
+        let interval = config.heartbeat_interval;
+        let jitter = config.heartbeat_jitter;
+        let clock_offset = config.heartbeat_clock_offset;
+        // Without an explicit phase, align to the current time so that heartbeats are sent every
+        // interval from now on
+        let phase = config
+            .heartbeat_phase
+            .unwrap_or_else(|| heartbeat_phase_of(Utc::now() + clock_offset, interval));
+        let (tx, rx) = tokio::sync::oneshot::channel::<Option<Uuid>>();
+        let last_sent = Arc::new(RwLock::new(None));
 
         let token_clone = token.clone();
         let client_clone = client.clone();
+        let last_sent_clone = Arc::clone(&last_sent);
 
         tokio::task::spawn(async move {
-            let mut heartbeat_id: Option<Uuid> = None;
+            let mut heartbeat_id = heartbeat_id;
 
-            let mut ticker = time::interval(duration);
-            ticker.tick().await;
+            let mut delay = if heartbeat_id.is_some() {
+                Duration::ZERO
+            } else {
+                next_heartbeat_delay(Utc::now() + clock_offset, interval, phase)
+            };
 
             loop {
+                if !jitter.is_zero() {
+                    delay += rand::rng().random_range(Duration::ZERO..=jitter);
+                }
+
                 tokio::select! {
                     () = token_clone.cancelled() => {
                         #[cfg(feature = "tracing")]
                         debug!("Heartbeat cancellation requested, terminating...");
                         break
                     },
-                    _ = ticker.tick() => {
+                    () = time::sleep(delay) => {
+                        if client_clone.inner.in_flight.is_closed() {
+                            #[cfg(feature = "tracing")]
+                            debug!("Client is shutting down, terminating heartbeats...");
+                            break
+                        }
+
+                        let sent_at = Utc::now();
                         match client_clone.post_heartbeat(heartbeat_id).await {
                             Ok(response) => {
                                 #[cfg(feature = "tracing")]
-                                debug!("Heartbeat successfully sent: {response:?}");
+                                debug!(%sent_at, "Heartbeat successfully sent: {response:?}");
                                 heartbeat_id = Some(response.heartbeat_id);
+                                *last_sent_clone
+                                    .write()
+                                    .unwrap_or_else(PoisonError::into_inner) = Some(sent_at);
                             },
                             Err(e) => {
                                 #[cfg(feature = "tracing")]
                                 error!("Unable to post heartbeat: {e:?}");
                                 #[cfg(not(feature = "tracing"))]
-                                let _ = &e;
+                                let _: &Error = &e;
                             }
                         }
+
+                        delay = next_heartbeat_delay(Utc::now() + clock_offset, interval, phase);
                     }
                 }
             }
 
-            tx.send(())
+            // Release our reference to the inner client before signalling completion
+            drop(client_clone);
+            tx.send(heartbeat_id)
         });
 
-        client.heartbeat_token = DroppingCancellationToken(Some((token, Arc::new(rx))));
+        client.heartbeat_token = DroppingCancellationToken(Some(Arc::new(HeartbeatSupervisor {
+            token,
+            done: Some(rx),
+            last_sent,
+        })));
 
         Ok(())
     }
@@ -2090,18 +4463,67 @@ impl<K: Kind> Client<Authenticated<K>> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the heartbeat task cannot be stopped cleanly.
+    /// Returns a [`Synchronization`] error if other clones of this [`Client`] still share the
+    /// heartbeat task, or another error if it cannot be stopped cleanly.
     ///
     /// # Note
     ///
     /// Requires the `heartbeats` feature to be enabled.
     pub async fn stop_heartbeats(&mut self) -> Result<()> {
-        self.heartbeat_token.cancel_and_wait().await
+        self.heartbeat_token.cancel_and_wait().await?;
+        Ok(())
+    }
+
+    /// Returns a [`Geoblock`] error if [`Config`]'s `enforce_geoblock` is enabled and the most
+    /// recent geoblock check, refreshed once older than `geoblock_ttl`, reports trading as blocked.
+    async fn ensure_not_geoblocked(&self) -> Result<()> {
+        if !self.inner.config.enforce_geoblock {
+            return Ok(());
+        }
+
+        let cached = self
+            .inner
+            .geoblock
+            .read()
+            .map_err(|_poisoned| Synchronization)?
+            .as_ref()
+            .filter(|(checked_at, _)| checked_at.elapsed() < self.inner.config.geoblock_ttl)
+            .map(|(_, response)| response.clone());
+
+        let response = if let Some(response) = cached {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(blocked = response.blocked, "cache hit: geoblock");
+
+            response
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("cache miss: geoblock");
+
+            let response = self.check_geoblock().await?;
+            *self
+                .inner
+                .geoblock
+                .write()
+                .map_err(|_poisoned| Synchronization)? = Some((Instant::now(), response.clone()));
+
+            response
+        };
+
+        if response.blocked {
+            return Err(Geoblock {
+                ip: response.ip,
+                country: response.country,
+                region: response.region,
+            }
+            .into());
+        }
+
+        Ok(())
     }
 
     async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
+        let timestamp = if self.uses_server_time() {
+            self.server_time().await?.as_secs()
         } else {
             Utc::now().timestamp()
         };
@@ -2125,8 +4547,13 @@ impl<K: Kind> Client<Authenticated<K>> {
             taker: None,
             order_type: None,
             post_only: Some(false),
+            client_order_id: None,
+            rounding: None,
+            price_received_at: None,
+            max_age: None,
             client: Client {
                 inner: Arc::clone(&self.inner),
+                use_server_time: self.use_server_time,
                 #[cfg(feature = "heartbeats")]
                 heartbeat_token: self.heartbeat_token.clone(),
             },
@@ -2139,9 +4566,9 @@ impl Client<Authenticated<Normal>> {
     /// Convert this [`Client<Authenticated<Normal>>`] to [`Client<Authenticated<Builder>>`] using
     /// the provided `config`.
     ///
-    /// Note: If `heartbeats` feature flag is enabled, then this method _will_ cancel all
-    /// outstanding orders since it will disable the background heartbeats task and then
-    /// re-enable it.
+    /// Note: If `heartbeats` feature flag is enabled, the background heartbeats task is handed off
+    /// to the promoted client: it continues the same heartbeat session, sending its first
+    /// heartbeat immediately, so outstanding orders are not cancelled by the promotion.
     #[cfg_attr(
         not(feature = "heartbeats"),
         expect(
@@ -2155,7 +4582,7 @@ impl Client<Authenticated<Normal>> {
         config: BuilderConfig,
     ) -> Result<Client<Authenticated<Builder>>> {
         #[cfg(feature = "heartbeats")]
-        self.heartbeat_token.cancel_and_wait().await?;
+        let heartbeat_id = self.heartbeat_token.cancel_and_wait().await?;
 
         let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
 
@@ -2164,7 +4591,7 @@ impl Client<Authenticated<Normal>> {
             credentials: inner.state.credentials,
             kind: Builder {
                 config,
-                client: inner.client.clone(),
+                client: ReqwestClient::clone(&inner.client),
             },
         };
 
@@ -2177,6 +4604,19 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            cache_counters: inner.cache_counters,
+            geoblock: inner.geoblock,
+            balances: inner.balances,
+            reward_percentages: inner.reward_percentages,
+            current_rewards: inner.current_rewards,
+            submitted_orders: inner.submitted_orders,
+            order_ids: inner.order_ids,
+            in_flight: inner.in_flight,
+            expirations: inner.expirations,
+            simulated_orders: inner.simulated_orders,
+            #[cfg(feature = "gamma")]
+            enriched_markets: inner.enriched_markets,
+            closed_only: inner.closed_only,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
@@ -2191,36 +4631,109 @@ impl Client<Authenticated<Normal>> {
         )]
         let mut client = Client {
             inner: Arc::new(new_inner),
+            use_server_time: None,
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
         };
 
         #[cfg(feature = "heartbeats")]
-        Client::<Authenticated<Builder>>::start_heartbeats(&mut client)?;
+        Client::<Authenticated<Builder>>::spawn_heartbeats(&mut client, heartbeat_id)?;
 
         Ok(client)
     }
 }
 
+/// Methods of clients attributing their orders to a builder, see [`auth::builder`]. Every
+/// authenticated request, including posting orders, carries the builder headers.
 impl Client<Authenticated<Builder>> {
+    /// Returns the builder configuration the attribution headers are produced with.
+    #[must_use]
+    pub fn builder_config(&self) -> &BuilderConfig {
+        &self.state().kind.config
+    }
+
+    /// Lists the builder API keys of the builder the attribution headers authenticate as,
+    /// including revoked ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the builder headers cannot be produced or the request fails.
     pub async fn builder_api_keys(&self) -> Result<Vec<BuilderApiKeyResponse>> {
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}auth/builder-api-key", self.host()))
-            .build()?;
+        let request = self.inner.request(Endpoint::BuilderApiKeys).build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Checks that orders posted by this client are attributed to the builder, returning the
+    /// builder API key they are attributed to.
+    ///
+    /// The headers of a request are first inspected locally: all four builder headers must be
+    /// present and, for a [`BuilderConfig::Local`], carry the configured API key. The same
+    /// request then lists the builder's API keys, which only succeeds if the CLOB accepts the
+    /// headers, and the key must be among the active ones. Nothing is posted or changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error describing the problem if a header is missing or
+    /// mismatched, or if the key is unknown or revoked, and any error of the request itself.
+    pub async fn verify_builder_attribution(&self) -> Result<ApiKey> {
+        let request = self.inner.request(Endpoint::BuilderApiKeys).build()?;
+        let headers = self.create_headers(&request).await?;
+
+        for name in [
+            builder::POLY_BUILDER_API_KEY,
+            builder::POLY_BUILDER_PASSPHRASE,
+            builder::POLY_BUILDER_SIGNATURE,
+            builder::POLY_BUILDER_TIMESTAMP,
+        ] {
+            if headers.get(name).is_none_or(HeaderValue::is_empty) {
+                return Err(Error::validation(format!(
+                    "Builder header {name} is missing from signed requests"
+                )));
+            }
+        }
+
+        let key = headers[builder::POLY_BUILDER_API_KEY]
+            .to_str()
+            .ok()
+            .and_then(|key| Uuid::parse_str(key).ok())
+            .ok_or_else(|| {
+                Error::validation(format!(
+                    "Builder header {} is not a valid API key",
+                    builder::POLY_BUILDER_API_KEY
+                ))
+            })?;
+        if let Some(configured) = self.builder_config().api_key()
+            && configured != key
+        {
+            return Err(Error::validation(format!(
+                "Builder header {} is {key}, expected the configured key {configured}",
+                builder::POLY_BUILDER_API_KEY
+            )));
+        }
+
+        let keys: Vec<BuilderApiKeyResponse> =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        match keys.iter().find(|response| response.key == key) {
+            Some(response) if response.revoked_at.is_none() => Ok(key),
+            Some(_) => Err(Error::validation(format!(
+                "Builder API key {key} has been revoked"
+            ))),
+            None => Err(Error::validation(format!(
+                "Builder API key {key} is not registered to this builder"
+            ))),
+        }
+    }
+
+    /// Revokes the builder API key the attribution headers are signed with. Orders posted
+    /// afterwards are no longer attributed to the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the builder headers cannot be produced or the request fails.
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
-        let mut request = self
-            .client()
-            .request(
-                Method::DELETE,
-                format!("{}auth/builder-api-key", self.host()),
-            )
-            .build()?;
+        let mut request = self.inner.request(Endpoint::RevokeBuilderApiKey).build()?;
         let headers = self.create_headers(&request).await?;
 
         *request.headers_mut() = headers;
@@ -2232,24 +4745,44 @@ impl Client<Authenticated<Builder>> {
         Ok(())
     }
 
+    /// Returns a page of the trades attributed to the builder. See
+    /// [`BuilderTradeResponse::builder_fee`] for the fee the builder earned on each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the builder headers cannot be produced or the request fails.
     pub async fn builder_trades(
         &self,
         request: &TradesRequest,
         next_cursor: Option<String>,
     ) -> Result<Page<BuilderTradeResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
-
         let request = self
-            .client()
+            .inner
             .request(
-                Method::GET,
-                format!("{}builder/trades{params}", self.host()),
+                Endpoint::BuilderTrades
+                    .query(request)
+                    .cursor(next_cursor.as_deref()),
             )
             .build()?;
         let headers = self.create_headers(&request).await?;
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
+
+    /// Returns the volume and fees of every builder trade matching `request`, overall, by day
+    /// and by market. See [`revenue`](crate::clob::revenue) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching any page of trades fails.
+    pub async fn builder_revenue(&self, request: &TradesRequest) -> Result<Report> {
+        self.stream_data(|client, cursor| client.builder_trades(request, cursor))
+            .try_fold(Report::default(), |mut report, trade| {
+                report.add(&trade);
+                future::ready(Ok(report))
+            })
+            .await
+    }
 }
 
 #[cfg(feature = "rfq")]
@@ -2266,12 +4799,25 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &CreateRfqRequestRequest,
     ) -> Result<CreateRfqRequestResponse> {
         let http_request = self
-            .client()
-            .request(Method::POST, format!("{}rfq/request", self.host()))
+            .inner
+            .request(Endpoint::CreateRfqRequest)
             .json(request)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&http_request).await?;
 
+        if self.inner.config.dry_run {
+            let request_id = Uuid::now_v7().to_string();
+
+            #[cfg(feature = "tracing")]
+            tracing::info!(request_id = %request_id, request = ?request, "dry run: simulated RFQ request");
+
+            return Ok(CreateRfqRequestResponse::builder()
+                .request_id(request_id)
+                .expiry(Timestamp::now())
+                .build());
+        }
+
         crate::request(&self.inner.client, http_request, Some(headers)).await
     }
 
@@ -2284,12 +4830,19 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the HTTP request fails or the request cannot be canceled.
     pub async fn cancel_request(&self, request: &CancelRfqRequestRequest) -> Result<()> {
         let http_request = self
-            .client()
-            .request(Method::DELETE, format!("{}rfq/request", self.host()))
+            .inner
+            .request(Endpoint::CancelRfqRequest)
             .json(request)
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
+        if self.inner.config.dry_run {
+            #[cfg(feature = "tracing")]
+            tracing::info!(request = ?request, "dry run: simulated RFQ request cancel");
+
+            return Ok(());
+        }
+
         self.rfq_request_text(http_request, headers).await
     }
 
@@ -2306,13 +4859,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &RfqRequestsRequest,
         next_cursor: Option<&str>,
     ) -> Result<Page<RfqRequest>> {
-        let params = request.query_params(next_cursor);
         let http_request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}rfq/data/requests{params}", self.host()),
-            )
+            .inner
+            .request(Endpoint::RfqRequests.query(request).cursor(next_cursor))
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
@@ -2329,12 +4878,22 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &CreateRfqQuoteRequest,
     ) -> Result<CreateRfqQuoteResponse> {
         let http_request = self
-            .client()
-            .request(Method::POST, format!("{}rfq/quote", self.host()))
+            .inner
+            .request(Endpoint::CreateRfqQuote)
             .json(request)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&http_request).await?;
 
+        if self.inner.config.dry_run {
+            let quote_id = Uuid::now_v7().to_string();
+
+            #[cfg(feature = "tracing")]
+            tracing::info!(quote_id = %quote_id, request = ?request, "dry run: simulated RFQ quote");
+
+            return Ok(CreateRfqQuoteResponse::builder().quote_id(quote_id).build());
+        }
+
         crate::request(&self.inner.client, http_request, Some(headers)).await
     }
 
@@ -2345,12 +4904,19 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the HTTP request fails or the quote cannot be canceled.
     pub async fn cancel_quote(&self, request: &CancelRfqQuoteRequest) -> Result<()> {
         let http_request = self
-            .client()
-            .request(Method::DELETE, format!("{}rfq/quote", self.host()))
+            .inner
+            .request(Endpoint::CancelRfqQuote)
             .json(request)
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
+        if self.inner.config.dry_run {
+            #[cfg(feature = "tracing")]
+            tracing::info!(request = ?request, "dry run: simulated RFQ quote cancel");
+
+            return Ok(());
+        }
+
         self.rfq_request_text(http_request, headers).await
     }
 
@@ -2367,13 +4933,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &RfqQuotesRequest,
         next_cursor: Option<&str>,
     ) -> Result<Page<RfqQuote>> {
-        let params = request.query_params(next_cursor);
         let http_request = self
-            .client()
-            .request(
-                Method::GET,
-                format!("{}rfq/data/quotes{params}", self.host()),
-            )
+            .inner
+            .request(Endpoint::RfqQuotes.query(request).cursor(next_cursor))
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
@@ -2393,13 +4955,19 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &AcceptRfqQuoteRequest,
     ) -> Result<AcceptRfqQuoteResponse> {
         let http_request = self
-            .client()
-            .request(Method::POST, format!("{}rfq/request/accept", self.host()))
+            .inner
+            .request(Endpoint::AcceptRfqQuote)
             .json(request)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&http_request).await?;
 
-        self.rfq_request_text(http_request, headers).await?;
+        if self.inner.config.dry_run {
+            #[cfg(feature = "tracing")]
+            tracing::info!(request = ?request, "dry run: simulated RFQ quote acceptance");
+        } else {
+            self.rfq_request_text(http_request, headers).await?;
+        }
         Ok(AcceptRfqQuoteResponse)
     }
 
@@ -2415,15 +4983,71 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &ApproveRfqOrderRequest,
     ) -> Result<ApproveRfqOrderResponse> {
         let http_request = self
-            .client()
-            .request(Method::POST, format!("{}rfq/quote/approve", self.host()))
+            .inner
+            .request(Endpoint::ApproveRfqOrder)
             .json(request)
             .build()?;
+        self.ensure_not_geoblocked().await?;
         let headers = self.create_headers(&http_request).await?;
 
+        if self.inner.config.dry_run {
+            #[cfg(feature = "tracing")]
+            tracing::info!(request = ?request, "dry run: simulated RFQ order approval");
+
+            return Ok(ApproveRfqOrderResponse::builder()
+                .trade_ids(Vec::new())
+                .build());
+        }
+
         crate::request(&self.inner.client, http_request, Some(headers)).await
     }
 
+    #[cfg(feature = "polling")]
+    /// Executes `size` shares of `token_id` on whichever of the order book and RFQ offers the
+    /// better price within `constraints`, see [`routing`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither venue can fill `size` within `constraints`, or if any of the
+    /// underlying requests fails.
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn execute_best<S: Signer>(
+        &self,
+        signer: &S,
+        token_id: TokenId,
+        side: Side,
+        size: Decimal,
+        constraints: Constraints,
+    ) -> Result<Execution> {
+        routing::execute_best(
+            self,
+            signer,
+            token_id,
+            side,
+            size,
+            constraints,
+            self.inner.signature_type,
+        )
+        .await
+    }
+
+    #[cfg(feature = "polling")]
+    /// Runs `order`, which is submitted by accepting an RFQ quote, through the same validators
+    /// and checks as [`Self::post_order`] and records it as submitted.
+    pub(crate) async fn admit_quote_order(&self, order: &SignedOrder) -> Result<()> {
+        self.inner.validate(slice::from_ref(order)).await?;
+        self.inner.admit(slice::from_ref(order))
+    }
+
+    #[cfg(feature = "polling")]
+    /// Releases the risk exposure of an `order` admitted via [`Self::admit_quote_order`].
+    pub(crate) fn release_quote_order(&self, order: &SignedOrder) {
+        self.inner.release(slice::from_ref(order));
+    }
+
     /// Helper method for RFQ endpoints that return plain text instead of JSON.
     ///
     /// This is used for cancel operations (`cancel_request`, `cancel_quote`)
@@ -2450,10 +5074,61 @@ impl<K: Kind> Client<Authenticated<K>> {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "heartbeats")]
+    #[test]
+    fn next_heartbeat_delay_should_align_to_phase() {
+        let interval = Duration::from_secs(5);
+        let at = |millis: i64| DateTime::from_timestamp_millis(millis).expect("valid time");
+
+        // 12.3s is 2.3s past the boundary at 10s
+        assert_eq!(
+            heartbeat_phase_of(at(12_300), interval),
+            Duration::from_millis(2_300)
+        );
+        assert_eq!(
+            next_heartbeat_delay(at(12_300), interval, Duration::ZERO),
+            Duration::from_millis(2_700)
+        );
+        assert_eq!(
+            next_heartbeat_delay(at(12_300), interval, Duration::from_secs(3)),
+            Duration::from_millis(700)
+        );
+        // Phases wrap around the interval
+        assert_eq!(
+            next_heartbeat_delay(at(12_300), interval, Duration::from_secs(8)),
+            Duration::from_millis(700)
+        );
+        // A time exactly on a boundary waits for the next one
+        assert_eq!(
+            next_heartbeat_delay(at(15_000), interval, Duration::ZERO),
+            interval
+        );
+    }
+
     use super::*;
 
     #[test]
     fn client_default_should_succeed() {
         _ = Client::default();
     }
+
+    #[test]
+    fn environment_constructors_should_succeed() {
+        let production = Client::production(Config::default()).unwrap();
+        assert_eq!(production.host().as_str(), "https://clob.polymarket.com/");
+        assert_eq!(production.chain_id(), Some(POLYGON));
+
+        let amoy = Client::amoy(Config::default()).unwrap();
+        assert_eq!(amoy.host().as_str(), "https://clob-staging.polymarket.com/");
+        assert_eq!(amoy.chain_id(), Some(AMOY));
+    }
+
+    #[test]
+    fn expected_chain_id_should_match_known_hosts() {
+        let chain = |host: &str| expected_chain_id(&Url::parse(host).unwrap());
+
+        assert_eq!(chain(DEFAULT_HOST), Some(POLYGON));
+        assert_eq!(chain(STAGING_HOST), Some(AMOY));
+        assert_eq!(chain("http://127.0.0.1:8080"), None);
+    }
 }
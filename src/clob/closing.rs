@@ -0,0 +1,253 @@
+//! Market close tracking.
+//!
+//! Strategies have to stop quoting and flatten their positions before a market stops trading.
+//! [`Client::watch_closing`](crate::clob::Client::watch_closing) polls
+//! [`Client::market`](crate::clob::Client::market) for a set of condition IDs and yields a
+//! [`Warning`] once each configured lead time before a market's `end_date_iso` is reached, by
+//! default 24 hours and 1 hour, and once more when the market is closed.
+//!
+//! Markets without an end date only warn when they are closed. A market that is first observed
+//! within several lead times only warns about the shortest of them, so that a watcher started
+//! late does not replay stale warnings. The stream ends once every market is closed.
+
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+use async_stream::stream;
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::Stream;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob::Client;
+use crate::clob::timing::ServerClock;
+use crate::clob::types::response::MarketResponse;
+use crate::types::B256;
+
+/// What a [`Warning`] is about.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    /// The market's end date is at most `lead` away.
+    Approaching {
+        /// The lead time that was reached.
+        lead: Duration,
+    },
+    /// The market is closed and no longer trades.
+    Closed,
+}
+
+/// A market that is about to close, or has closed.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    /// The condition ID of the market.
+    pub condition_id: B256,
+    /// The end date of the market, if it has one.
+    pub end_date: Option<DateTime<Utc>>,
+    /// Whether the market is approaching its end date or closed.
+    pub stage: Stage,
+}
+
+/// Configuration for [`Client::watch_closing`](crate::clob::Client::watch_closing).
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct WatchConfig {
+    /// The condition IDs of the markets to watch.
+    condition_ids: Vec<B256>,
+    /// How long before a market's end date to warn. The default is twenty-four (24) hours and
+    /// one (1) hour.
+    #[builder(default = vec![Duration::from_secs(24 * 60 * 60), Duration::from_secs(60 * 60)])]
+    leads: Vec<Duration>,
+    /// How often the markets are polled. The default is sixty (60) seconds.
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+    /// The server clock to compare end dates against, see
+    /// [`Client::sync_clock`](crate::clob::Client::sync_clock). Defaults to the local clock.
+    clock: Option<ServerClock>,
+}
+
+/// The warnings already yielded for a market.
+#[derive(Debug, Default)]
+struct Warned {
+    /// The lead times that were reached.
+    leads: BTreeSet<Duration>,
+}
+
+impl Warned {
+    /// Returns the stage of `market` to warn about at `now`, if it was not warned about yet.
+    fn update(
+        &mut self,
+        market: &MarketResponse,
+        leads: &BTreeSet<Duration>,
+        now: DateTime<Utc>,
+    ) -> Option<Stage> {
+        if market.closed {
+            return Some(Stage::Closed);
+        }
+
+        let remaining = market.end_date_iso?.signed_duration_since(now);
+        let reached: Vec<Duration> = leads
+            .iter()
+            .copied()
+            .filter(|lead| {
+                !self.leads.contains(lead)
+                    && TimeDelta::from_std(*lead).is_ok_and(|lead| remaining <= lead)
+            })
+            .collect();
+        let shortest = reached.first().copied()?;
+        self.leads.extend(reached);
+
+        Some(Stage::Approaching { lead: shortest })
+    }
+}
+
+pub(crate) fn watch<S: State>(
+    client: &Client<S>,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<Warning>> + '_ {
+    stream! {
+        let leads: BTreeSet<Duration> = config.leads.iter().copied().collect();
+        let mut warned: HashMap<B256, Warned> = config
+            .condition_ids
+            .iter()
+            .map(|id| (*id, Warned::default()))
+            .collect();
+
+        while !warned.is_empty() {
+            let condition_ids: Vec<B256> = warned.keys().copied().collect();
+            for condition_id in condition_ids {
+                let market = match client.market(&condition_id.to_string()).await {
+                    Ok(market) => market,
+                    Err(e) => {
+                        yield Err(e);
+                        continue;
+                    }
+                };
+
+                let now = config.clock.as_ref().map_or_else(Utc::now, ServerClock::now);
+                let Some(stage) = warned
+                    .get_mut(&condition_id)
+                    .and_then(|warned| warned.update(&market, &leads, now))
+                else {
+                    continue;
+                };
+                if stage == Stage::Closed {
+                    warned.remove(&condition_id);
+                }
+
+                yield Ok(Warning {
+                    condition_id,
+                    end_date: market.end_date_iso,
+                    stage,
+                });
+            }
+
+            if !warned.is_empty() {
+                time::sleep(config.interval).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn market(closed: bool, end_date: Option<&str>) -> MarketResponse {
+        serde_json::from_value(json!({
+            "enable_order_book": true,
+            "active": !closed,
+            "closed": closed,
+            "archived": false,
+            "accepting_orders": !closed,
+            "accepting_order_timestamp": null,
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "question_id": "",
+            "question": "",
+            "description": "",
+            "market_slug": "",
+            "end_date_iso": end_date,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": false,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false,
+            "tokens": [],
+            "tags": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn update_should_warn_once_per_lead() {
+        let leads = BTreeSet::from([24 * HOUR, HOUR]);
+        let end_date = "2025-01-02T00:00:00Z";
+        let at = |time: &str| time.parse::<DateTime<Utc>>().unwrap();
+        let open = market(false, Some(end_date));
+
+        let mut warned = Warned::default();
+        assert_eq!(
+            warned.update(&open, &leads, at("2024-12-30T00:00:00Z")),
+            None
+        );
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-01T00:00:00Z")),
+            Some(Stage::Approaching { lead: 24 * HOUR })
+        );
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-01T12:00:00Z")),
+            None
+        );
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-01T23:30:00Z")),
+            Some(Stage::Approaching { lead: HOUR })
+        );
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-02T01:00:00Z")),
+            None
+        );
+        assert_eq!(
+            warned.update(
+                &market(true, Some(end_date)),
+                &leads,
+                at("2025-01-02T01:00:00Z")
+            ),
+            Some(Stage::Closed)
+        );
+
+        // A late start only warns about the shortest lead time reached
+        let mut warned = Warned::default();
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-01T23:30:00Z")),
+            Some(Stage::Approaching { lead: HOUR })
+        );
+        assert_eq!(
+            warned.update(&open, &leads, at("2025-01-01T23:45:00Z")),
+            None
+        );
+
+        // Without an end date, only closing is warned about
+        let mut warned = Warned::default();
+        assert_eq!(
+            warned.update(&market(false, None), &leads, at("2025-01-01T23:30:00Z")),
+            None
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Deposit detection.
+//!
+//! [`Client::watch_deposits`](crate::clob::Client::watch_deposits) repeatedly reads the on-chain
+//! collateral balance of the account's funder via [`ctf::Client::collateral_balance`]. Whenever
+//! the balance increases, it asks the CLOB to refresh its view of the account via
+//! [`Client::update_balance_allowance`](crate::clob::Client::update_balance_allowance) and yields
+//! a [`Deposit`], so that bots can scale up quoting as capital arrives.
+
+use std::time::Duration;
+
+use alloy::providers::Provider;
+use async_stream::stream;
+use bon::Builder;
+use futures::Stream;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::AssetType;
+use crate::clob::types::request::UpdateBalanceAllowanceRequest;
+use crate::ctf;
+use crate::ctf::types::CollateralBalanceRequest;
+use crate::types::{Address, U256, Usdc};
+
+/// An increase in the on-chain collateral balance of the account's funder.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deposit {
+    /// The address whose balance increased.
+    pub owner: Address,
+    /// The amount by which the balance increased.
+    pub amount: Usdc,
+    /// The balance after the deposit.
+    pub balance: Usdc,
+}
+
+impl Deposit {
+    fn new(owner: Address, previous: U256, current: U256) -> Result<Self> {
+        Ok(Self {
+            owner,
+            amount: Usdc::from_base_units(current - previous)?,
+            balance: Usdc::from_base_units(current)?,
+        })
+    }
+}
+
+/// Configuration for [`Client::watch_deposits`](crate::clob::Client::watch_deposits).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct WatchConfig {
+    /// How often the on-chain balance is read. The default is thirty (30) seconds.
+    #[builder(default = Duration::from_secs(30))]
+    interval: Duration,
+}
+
+pub(crate) fn watch<'client, K: Kind, P: Provider + Clone>(
+    client: &'client Client<Authenticated<K>>,
+    ctf: &'client ctf::Client<P>,
+    owner: Address,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<Deposit>> + 'client {
+    stream! {
+        let request = CollateralBalanceRequest::builder().owner(owner).build();
+        let mut previous: Option<U256> = None;
+
+        loop {
+            let balance = match ctf.collateral_balance(&request).await {
+                Ok(response) => response.balance,
+                Err(e) => {
+                    yield Err(e);
+                    time::sleep(config.interval).await;
+                    continue;
+                }
+            };
+
+            match previous {
+                Some(last) if balance > last => {
+                    let update = UpdateBalanceAllowanceRequest::builder()
+                        .asset_type(AssetType::Collateral)
+                        .build();
+                    // Keep the old baseline on failure so that the refresh is retried next poll
+                    if let Err(e) = client.update_balance_allowance(update).await {
+                        yield Err(e);
+                    } else {
+                        previous = Some(balance);
+                        yield Deposit::new(owner, last, balance);
+                    }
+                }
+                _ => previous = Some(balance),
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
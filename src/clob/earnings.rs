@@ -0,0 +1,296 @@
+//! Ledgers of liquidity rewards earned by a maker.
+//!
+//! Liquidity mining is paid out per day and per market, in one or more reward tokens. For
+//! accounting, [`Client::rewards_ledger`](crate::clob::Client::rewards_ledger) walks
+//! [`user_earnings_and_markets_config`](crate::clob::Client::user_earnings_and_markets_config)
+//! over a range of days and joins every earning with the reward program that paid it, from
+//! [`raw_rewards_for_market`](crate::clob::Client::raw_rewards_for_market), into a [`Ledger`] of
+//! one [`Record`] per day, market and reward token.
+//!
+//! Markets in which nothing was earned are left out. A [`Ledger`] keeps its records ordered by
+//! day, market and reward token, serializes to JSON, and exports to CSV with [`Ledger::to_csv`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use chrono::NaiveDate;
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//!
+//! # async fn example(client: Client<Authenticated<Normal>>) -> Result<(), Box<dyn std::error::Error>> {
+//! let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+//! let ledger = client.rewards_ledger(start, end).await?;
+//!
+//! for (asset, earnings) in ledger.totals() {
+//!     println!("{asset}: {earnings}");
+//! }
+//! std::fs::write("rewards.csv", ledger.to_csv())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::clob::types::response::{MarketRewardsConfig, UserRewardsEarningResponse};
+use crate::types::{Address, B256, Decimal};
+
+/// The columns of [`Ledger::to_csv`].
+const CSV_HEADER: &str = "date,condition_id,market_slug,event_slug,maker_address,asset_address,earnings,asset_rate,earning_percentage,rate_per_day";
+
+/// The rewards earned in one market on one day, in one reward token.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    /// The day the rewards were earned on.
+    pub date: NaiveDate,
+    /// The market condition ID.
+    pub condition_id: B256,
+    /// The slug of the market.
+    pub market_slug: String,
+    /// The slug of the market's event.
+    pub event_slug: String,
+    /// The maker that earned the rewards.
+    pub maker_address: Address,
+    /// The token the rewards are paid in.
+    pub asset_address: Address,
+    /// The rewards earned, in units of the reward token.
+    pub earnings: Decimal,
+    /// The rate of the reward token reported with the earnings.
+    pub asset_rate: Decimal,
+    /// The maker's share of the market's rewards.
+    pub earning_percentage: Decimal,
+    /// The rewards the market paid per day in this token, or `None` if no reward program was
+    /// found for the day.
+    pub rate_per_day: Option<Decimal>,
+}
+
+impl Record {
+    fn key(&self) -> (NaiveDate, B256, Address) {
+        (self.date, self.condition_id, self.asset_address)
+    }
+}
+
+/// Liquidity rewards earned per day, market and reward token, see the [module docs](self).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ledger {
+    /// The records, ordered by day, market and reward token.
+    pub records: Vec<Record>,
+}
+
+impl Ledger {
+    /// Adds the earnings of `market` on `date` to the ledger, taking the daily rate of each
+    /// reward token from the program in `rewards_config` that was running on `date`. Earnings of
+    /// zero are skipped.
+    pub fn add(
+        &mut self,
+        date: NaiveDate,
+        market: &UserRewardsEarningResponse,
+        rewards_config: &[MarketRewardsConfig],
+    ) {
+        for earning in market.earnings.iter().filter(|e| !e.earnings.is_zero()) {
+            let rate_per_day = rewards_config
+                .iter()
+                .find(|config| {
+                    config.asset_address == earning.asset_address
+                        && config.start_date <= date
+                        && date <= config.end_date
+                })
+                .map(|config| config.rate_per_day);
+            let record = Record {
+                date,
+                condition_id: market.condition_id,
+                market_slug: market.market_slug.clone(),
+                event_slug: market.event_slug.clone(),
+                maker_address: market.maker_address,
+                asset_address: earning.asset_address,
+                earnings: earning.earnings,
+                asset_rate: earning.asset_rate,
+                earning_percentage: market.earning_percentage,
+                rate_per_day,
+            };
+
+            let index = self
+                .records
+                .partition_point(|existing| existing.key() <= record.key());
+            self.records.insert(index, record);
+        }
+    }
+
+    /// Returns the rewards earned in each reward token.
+    #[must_use]
+    pub fn totals(&self) -> BTreeMap<Address, Decimal> {
+        let mut totals = BTreeMap::new();
+        for record in &self.records {
+            *totals.entry(record.asset_address).or_default() += record.earnings;
+        }
+
+        totals
+    }
+
+    /// Formats the ledger as CSV with a header row, one line per [`Record`]. Missing daily rates
+    /// are left empty.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for record in &self.records {
+            // Writing to a `String` cannot fail
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{}",
+                record.date,
+                record.condition_id,
+                escape(&record.market_slug),
+                escape(&record.event_slug),
+                record.maker_address,
+                record.asset_address,
+                record.earnings,
+                record.asset_rate,
+                record.earning_percentage,
+                record
+                    .rate_per_day
+                    .map(|rate| rate.to_string())
+                    .unwrap_or_default(),
+            );
+        }
+
+        csv
+    }
+}
+
+/// Quotes a CSV field if it contains a delimiter, a quote or a line break.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::types::response::Earning;
+    use crate::types::{address, b256};
+
+    const USDC: Address = address!("0x0000000000000000000000000000000000000001");
+    const BONUS: Address = address!("0x0000000000000000000000000000000000000002");
+
+    fn day(date: &str) -> NaiveDate {
+        date.parse().expect("valid date")
+    }
+
+    fn market(
+        condition_id: B256,
+        slug: &str,
+        earnings: &[(Address, Decimal)],
+    ) -> UserRewardsEarningResponse {
+        UserRewardsEarningResponse::builder()
+            .condition_id(condition_id)
+            .question("Question?")
+            .market_slug(slug)
+            .event_slug("event")
+            .image("")
+            .rewards_max_spread(dec!(3))
+            .rewards_min_size(dec!(50))
+            .market_competitiveness(dec!(1))
+            .tokens(Vec::new())
+            .rewards_config(Vec::new())
+            .maker_address(Address::ZERO)
+            .earning_percentage(dec!(0.25))
+            .earnings(
+                earnings
+                    .iter()
+                    .map(|&(asset_address, earnings)| {
+                        Earning::builder()
+                            .asset_address(asset_address)
+                            .earnings(earnings)
+                            .asset_rate(dec!(1))
+                            .build()
+                    })
+                    .collect(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn ledger_should_order_records_and_export_csv() {
+        let first = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let second = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+        let config = MarketRewardsConfig::builder()
+            .id("1")
+            .asset_address(USDC)
+            .start_date(day("2025-01-01"))
+            .end_date(day("2025-01-31"))
+            .rate_per_day(dec!(10))
+            .total_rewards(dec!(310))
+            .total_days(dec!(31))
+            .build();
+
+        let mut ledger = Ledger::default();
+        ledger.add(
+            day("2025-01-02"),
+            &market(first, "first", &[(USDC, dec!(1.5))]),
+            std::slice::from_ref(&config),
+        );
+        ledger.add(
+            day("2025-01-01"),
+            &market(
+                second,
+                "second, again",
+                &[(BONUS, dec!(0.5)), (USDC, dec!(2))],
+            ),
+            std::slice::from_ref(&config),
+        );
+        ledger.add(
+            day("2025-01-01"),
+            &market(first, "first", &[(USDC, dec!(3)), (BONUS, Decimal::ZERO)]),
+            &[],
+        );
+
+        let keys: Vec<_> = ledger
+            .records
+            .iter()
+            .map(|record| (record.date, record.condition_id, record.asset_address))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                (day("2025-01-01"), first, USDC),
+                (day("2025-01-01"), second, USDC),
+                (day("2025-01-01"), second, BONUS),
+                (day("2025-01-02"), first, USDC),
+            ]
+        );
+        assert_eq!(ledger.records[0].rate_per_day, None);
+        assert_eq!(ledger.records[1].rate_per_day, Some(dec!(10)));
+        assert_eq!(ledger.records[2].rate_per_day, None);
+        assert_eq!(
+            ledger.totals(),
+            BTreeMap::from([(USDC, dec!(6.5)), (BONUS, dec!(0.5))])
+        );
+
+        let csv = ledger.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[2],
+            format!(
+                "2025-01-01,{second},\"second, again\",event,{},{USDC},2,1,0.25,10",
+                Address::ZERO
+            )
+        );
+        assert!(lines[1].ends_with(",3,1,0.25,"));
+    }
+}
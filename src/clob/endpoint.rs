@@ -0,0 +1,461 @@
+//! Typed CLOB REST endpoints.
+//!
+//! Every request made by [`Client`](crate::clob::Client) targets an [`Endpoint`], which owns the
+//! endpoint's HTTP method, its path relative to the client's host and the key under which
+//! [`RateLimiters`] limits it. Paths are joined onto the host segment by segment, so a host with
+//! or without a trailing slash (or with a path prefix such as `https://proxy.example.com/clob`)
+//! always yields the same URL.
+//!
+//! # Example
+//!
+//! ```
+//! use std::num::NonZeroU32;
+//!
+//! use polymarket_client_sdk::clob::endpoint::Endpoint;
+//! use polymarket_client_sdk::transport::{Quota, RateLimiters};
+//!
+//! let quota = Quota::per_second(NonZeroU32::new(10).expect("non-zero"));
+//! let rate_limiters = RateLimiters::default()
+//!     .with_path(Endpoint::PostOrder.rate_limit_key(), quota)
+//!     .with_path(Endpoint::Market("").rate_limit_key(), quota);
+//! ```
+
+use reqwest::Method;
+use serde::Serialize;
+use url::Url;
+
+use crate::ToQueryParams as _;
+#[cfg(doc)]
+use crate::transport::{RateLimiters, RetryPolicy};
+
+/// Endpoints that are sent as `POST` but are nonetheless safe to repeat, because they only read
+/// data or, for the heartbeat, only refresh a deadline.
+const IDEMPOTENT_POSTS: [Endpoint<'static>; 6] = [
+    Endpoint::Midpoints,
+    Endpoint::Prices,
+    Endpoint::Spreads,
+    Endpoint::OrderBooks,
+    Endpoint::OrdersScoring,
+    Endpoint::Heartbeat,
+];
+
+/// A CLOB REST endpoint.
+///
+/// Variants carrying a `&str` fill in a path parameter, such as the condition ID in
+/// `/markets/{condition_id}`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint<'path> {
+    Health,
+    ServerTime,
+    CreateApiKey,
+    DeriveApiKey,
+    ApiKeys,
+    DeleteApiKey,
+    ClosedOnlyMode,
+    CreateBuilderApiKey,
+    BuilderApiKeys,
+    RevokeBuilderApiKey,
+    Midpoint,
+    Midpoints,
+    Price,
+    Prices,
+    AllPrices,
+    PriceHistory,
+    Spread,
+    Spreads,
+    TickSize,
+    NegRisk,
+    FeeRate,
+    Geoblock,
+    OrderBook,
+    OrderBooks,
+    LastTradePrice,
+    LastTradesPrices,
+    Market(&'path str),
+    Markets,
+    SamplingMarkets,
+    SimplifiedMarkets,
+    SamplingSimplifiedMarkets,
+    PostOrder,
+    PostOrders,
+    Order(&'path str),
+    Orders,
+    CancelOrder,
+    CancelOrders,
+    CancelAllOrders,
+    CancelMarketOrders,
+    Trades,
+    BuilderTrades,
+    Notifications,
+    DeleteNotifications,
+    BalanceAllowance,
+    UpdateBalanceAllowance,
+    OrderScoring,
+    OrdersScoring,
+    UserEarnings,
+    UserTotalEarnings,
+    RewardPercentages,
+    CurrentRewards,
+    MarketRewards(&'path str),
+    Heartbeat,
+    #[cfg(feature = "rfq")]
+    CreateRfqRequest,
+    #[cfg(feature = "rfq")]
+    CancelRfqRequest,
+    #[cfg(feature = "rfq")]
+    RfqRequests,
+    #[cfg(feature = "rfq")]
+    CreateRfqQuote,
+    #[cfg(feature = "rfq")]
+    CancelRfqQuote,
+    #[cfg(feature = "rfq")]
+    RfqQuotes,
+    #[cfg(feature = "rfq")]
+    AcceptRfqQuote,
+    #[cfg(feature = "rfq")]
+    ApproveRfqOrder,
+}
+
+impl<'path> Endpoint<'path> {
+    /// Returns the HTTP method of this endpoint.
+    #[must_use]
+    pub fn method(self) -> Method {
+        match self {
+            Self::CreateApiKey
+            | Self::CreateBuilderApiKey
+            | Self::Midpoints
+            | Self::Prices
+            | Self::Spreads
+            | Self::OrderBooks
+            | Self::PostOrder
+            | Self::PostOrders
+            | Self::OrdersScoring
+            | Self::Heartbeat => Method::POST,
+            #[cfg(feature = "rfq")]
+            Self::CreateRfqRequest
+            | Self::CreateRfqQuote
+            | Self::AcceptRfqQuote
+            | Self::ApproveRfqOrder => Method::POST,
+            Self::DeleteApiKey
+            | Self::RevokeBuilderApiKey
+            | Self::CancelOrder
+            | Self::CancelOrders
+            | Self::CancelAllOrders
+            | Self::CancelMarketOrders
+            | Self::DeleteNotifications => Method::DELETE,
+            #[cfg(feature = "rfq")]
+            Self::CancelRfqRequest | Self::CancelRfqQuote => Method::DELETE,
+            _ => Method::GET,
+        }
+    }
+
+    /// Returns whether sending a request to this endpoint more than once has the same effect as
+    /// sending it once.
+    ///
+    /// Reads and cancellations are idempotent, whereas order submissions and the creation of API
+    /// keys, RFQ requests and RFQ quotes are not. A [`RetryPolicy`] only ever retries requests to
+    /// idempotent endpoints, so that an order cannot be submitted twice.
+    #[must_use]
+    pub fn is_idempotent(self) -> bool {
+        self.method() != Method::POST || IDEMPOTENT_POSTS.contains(&self)
+    }
+
+    /// Returns the path template of this endpoint, e.g. `/markets/{condition_id}`.
+    ///
+    /// This is the key to pass to [`RateLimiters::with_path`] to limit requests to this endpoint,
+    /// regardless of the value of any path parameter.
+    #[must_use]
+    pub fn rate_limit_key(self) -> &'static str {
+        match self {
+            Self::Health => "/",
+            Self::ServerTime => "/time",
+            Self::CreateApiKey | Self::DeleteApiKey => "/auth/api-key",
+            Self::DeriveApiKey => "/auth/derive-api-key",
+            Self::ApiKeys => "/auth/api-keys",
+            Self::ClosedOnlyMode => "/auth/ban-status/closed-only",
+            Self::CreateBuilderApiKey | Self::BuilderApiKeys | Self::RevokeBuilderApiKey => {
+                "/auth/builder-api-key"
+            }
+            Self::Midpoint => "/midpoint",
+            Self::Midpoints => "/midpoints",
+            Self::Price => "/price",
+            Self::Prices | Self::AllPrices => "/prices",
+            Self::PriceHistory => "/prices-history",
+            Self::Spread => "/spread",
+            Self::Spreads => "/spreads",
+            Self::TickSize => "/tick-size",
+            Self::NegRisk => "/neg-risk",
+            Self::FeeRate => "/fee-rate",
+            Self::Geoblock => "/api/geoblock",
+            Self::OrderBook => "/book",
+            Self::OrderBooks => "/books",
+            Self::LastTradePrice => "/last-trade-price",
+            Self::LastTradesPrices => "/last-trades-prices",
+            Self::Market(_) => "/markets/{condition_id}",
+            Self::Markets => "/markets",
+            Self::SamplingMarkets => "/sampling-markets",
+            Self::SimplifiedMarkets => "/simplified-markets",
+            Self::SamplingSimplifiedMarkets => "/sampling-simplified-markets",
+            Self::PostOrder | Self::CancelOrder => "/order",
+            Self::PostOrders | Self::CancelOrders => "/orders",
+            Self::Order(_) => "/data/order/{order_id}",
+            Self::Orders => "/data/orders",
+            Self::CancelAllOrders => "/cancel-all",
+            Self::CancelMarketOrders => "/cancel-market-orders",
+            Self::Trades => "/data/trades",
+            Self::BuilderTrades => "/builder/trades",
+            Self::Notifications | Self::DeleteNotifications => "/notifications",
+            Self::BalanceAllowance => "/balance-allowance",
+            Self::UpdateBalanceAllowance => "/balance-allowance/update",
+            Self::OrderScoring => "/order-scoring",
+            Self::OrdersScoring => "/orders-scoring",
+            Self::UserEarnings => "/rewards/user",
+            Self::UserTotalEarnings => "/rewards/user/total",
+            Self::RewardPercentages => "/rewards/user/percentages",
+            Self::CurrentRewards => "/rewards/markets/current",
+            Self::MarketRewards(_) => "/rewards/markets/{condition_id}",
+            Self::Heartbeat => "/v1/heartbeats",
+            #[cfg(feature = "rfq")]
+            Self::CreateRfqRequest | Self::CancelRfqRequest => "/rfq/request",
+            #[cfg(feature = "rfq")]
+            Self::RfqRequests => "/rfq/data/requests",
+            #[cfg(feature = "rfq")]
+            Self::CreateRfqQuote | Self::CancelRfqQuote => "/rfq/quote",
+            #[cfg(feature = "rfq")]
+            Self::RfqQuotes => "/rfq/data/quotes",
+            #[cfg(feature = "rfq")]
+            Self::AcceptRfqQuote => "/rfq/request/accept",
+            #[cfg(feature = "rfq")]
+            Self::ApproveRfqOrder => "/rfq/quote/approve",
+        }
+    }
+
+    /// Returns the value of this endpoint's path parameter, if it has one.
+    fn parameter(self) -> Option<&'path str> {
+        match self {
+            Self::Market(value) | Self::Order(value) | Self::MarketRewards(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the URL of this endpoint on `host`, with any path parameter percent-encoded.
+    ///
+    /// `host` must be a base URL, which is ensured when a [`Client`](crate::clob::Client) is
+    /// built.
+    pub(crate) fn url(self, host: &Url) -> Url {
+        let mut url = host.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .expect("Client hosts are validated to be base URLs");
+            segments.pop_if_empty();
+            let path = self.rate_limit_key().trim_start_matches('/');
+            for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+                if segment.starts_with('{') {
+                    segments.push(self.parameter().unwrap_or_default());
+                } else {
+                    segments.push(segment);
+                }
+            }
+        }
+        url
+    }
+
+    /// Starts a [`Route`] to this endpoint whose query string is `query`, encoded with
+    /// [`ToQueryParams`](crate::ToQueryParams).
+    pub(crate) fn query<Q: Serialize + ?Sized>(self, query: &Q) -> Route<'path> {
+        Route::from(self).query(query)
+    }
+
+    /// Starts a [`Route`] to this endpoint with `next_cursor` as its pagination cursor.
+    pub(crate) fn cursor(self, next_cursor: Option<&str>) -> Route<'path> {
+        Route::from(self).cursor(next_cursor)
+    }
+}
+
+/// Returns the exact URL paths on `host` of the `POST` endpoints that are idempotent, see
+/// [`Endpoint::is_idempotent`].
+pub(crate) fn idempotent_posts(host: &Url) -> impl Iterator<Item = String> {
+    IDEMPOTENT_POSTS
+        .into_iter()
+        .map(|endpoint| endpoint.url(host).path().to_owned())
+}
+
+/// Returns the URL of `path_and_query` on `host`. Like an [`Endpoint`], the path is appended to
+/// the host's own path, but it is otherwise used as given.
+pub(crate) fn raw_url(host: &Url, path_and_query: &str) -> Url {
+    let (path, query) = path_and_query
+        .split_once('?')
+        .map_or((path_and_query, None), |(path, query)| (path, Some(query)));
+
+    let mut url = host.clone();
+    url.set_path(&format!(
+        "{}/{}",
+        host.path().trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ));
+    url.set_query(query);
+    url
+}
+
+/// An [`Endpoint`] together with the query string of a particular request to it.
+#[derive(Clone, Debug)]
+pub(crate) struct Route<'path> {
+    endpoint: Endpoint<'path>,
+    query: String,
+}
+
+impl<'path> From<Endpoint<'path>> for Route<'path> {
+    fn from(endpoint: Endpoint<'path>) -> Self {
+        Self {
+            endpoint,
+            query: String::new(),
+        }
+    }
+}
+
+impl Route<'_> {
+    /// Appends `query`, encoded with [`ToQueryParams`](crate::ToQueryParams), to the query string.
+    pub(crate) fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        let params = query.query_params(None);
+        self.append(params.trim_start_matches('?'));
+        self
+    }
+
+    /// Appends `next_cursor`, if any, to the query string.
+    pub(crate) fn cursor(mut self, next_cursor: Option<&str>) -> Self {
+        if let Some(cursor) = next_cursor {
+            self.append(&format!("next_cursor={cursor}"));
+        }
+        self
+    }
+
+    fn append(&mut self, params: &str) {
+        if params.is_empty() {
+            return;
+        }
+        if !self.query.is_empty() {
+            self.query.push('&');
+        }
+        self.query.push_str(params);
+    }
+
+    pub(crate) fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    /// Returns the URL of this route on `host`, including its query string.
+    pub(crate) fn url(&self, host: &Url) -> Url {
+        let mut url = self.endpoint.url(host);
+        if !self.query.is_empty() {
+            url.set_query(Some(&self.query));
+        }
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_should_join_host_and_path() {
+        let expected = "https://clob.polymarket.com/data/order/0x1";
+
+        for host in [
+            "https://clob.polymarket.com",
+            "https://clob.polymarket.com/",
+        ] {
+            let host = Url::parse(host).unwrap();
+            assert_eq!(Endpoint::Order("0x1").url(&host).as_str(), expected);
+        }
+
+        let host = Url::parse("https://clob.polymarket.com").unwrap();
+        assert_eq!(
+            Endpoint::Health.url(&host).as_str(),
+            "https://clob.polymarket.com/"
+        );
+
+        let prefixed = Url::parse("https://proxy.example.com/clob/").unwrap();
+        assert_eq!(
+            Endpoint::PostOrder.url(&prefixed).as_str(),
+            "https://proxy.example.com/clob/order"
+        );
+    }
+
+    #[test]
+    fn url_should_encode_path_parameters() {
+        let host = Url::parse("https://clob.polymarket.com").unwrap();
+
+        assert_eq!(
+            Endpoint::Market("a/b?c").url(&host).path(),
+            "/markets/a%2Fb%3Fc"
+        );
+    }
+
+    #[test]
+    fn route_should_combine_query_and_cursor() {
+        let host = Url::parse("https://clob.polymarket.com").unwrap();
+
+        let route = Endpoint::UserEarnings
+            .query(&[("date", "2025-01-01")])
+            .cursor(Some("MTAw"));
+        assert_eq!(route.method(), Method::GET);
+        assert_eq!(
+            route.url(&host).as_str(),
+            "https://clob.polymarket.com/rewards/user?date=2025-01-01&next_cursor=MTAw"
+        );
+
+        let route = Endpoint::Markets.cursor(None);
+        assert_eq!(
+            route.url(&host).as_str(),
+            "https://clob.polymarket.com/markets"
+        );
+    }
+
+    #[test]
+    fn raw_url_should_keep_path_and_query() {
+        let host = Url::parse("https://proxy.example.com/clob").unwrap();
+
+        assert_eq!(
+            raw_url(&host, "/data/orders?market=0x1").as_str(),
+            "https://proxy.example.com/clob/data/orders?market=0x1"
+        );
+        assert_eq!(
+            raw_url(&host, "time").as_str(),
+            "https://proxy.example.com/clob/time"
+        );
+    }
+
+    #[test]
+    fn is_idempotent_should_exclude_submissions() {
+        assert!(Endpoint::Markets.is_idempotent());
+        assert!(Endpoint::CancelOrders.is_idempotent());
+        assert!(Endpoint::OrderBooks.is_idempotent());
+        assert!(!Endpoint::PostOrder.is_idempotent());
+        assert!(!Endpoint::CreateApiKey.is_idempotent());
+
+        let host = Url::parse("https://proxy.example.com/clob").unwrap();
+        let posts: Vec<_> = idempotent_posts(&host).collect();
+        assert!(posts.contains(&"/clob/books".to_owned()));
+        assert!(!posts.contains(&"/clob/order".to_owned()));
+        assert!(!posts.iter().any(|path| path == "/books"));
+    }
+
+    #[test]
+    fn rate_limit_key_should_match_url_path() {
+        let host = Url::parse("https://clob.polymarket.com").unwrap();
+
+        assert_eq!(Endpoint::PostOrder.method(), Method::POST);
+        assert_eq!(Endpoint::CancelOrder.method(), Method::DELETE);
+        assert_eq!(
+            Endpoint::PostOrder.url(&host).path(),
+            Endpoint::PostOrder.rate_limit_key()
+        );
+        assert_eq!(
+            Endpoint::MarketRewards("0x1").rate_limit_key(),
+            "/rewards/markets/{condition_id}"
+        );
+    }
+}
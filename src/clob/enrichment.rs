@@ -0,0 +1,76 @@
+//! Market metadata enrichment.
+//!
+//! The CLOB API describes a market by its trading parameters, while the presentation metadata of
+//! the event and series a market belongs to lives in the Gamma API.
+//! [`Client::enriched_market`](crate::clob::Client::enriched_market) joins both into a single
+//! [`EnrichedMarket`], so that UI layers can render a market card from one call. Joined markets
+//! are cached per condition ID for [`Config`](crate::clob::Config)'s `enriched_market_ttl`.
+
+use chrono::{DateTime, Utc};
+
+use crate::clob::types::response::MarketResponse;
+use crate::gamma::types::response::Market;
+
+/// A CLOB market joined with the Gamma metadata of its event and series.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnrichedMarket {
+    /// The market as returned by the CLOB API.
+    pub market: MarketResponse,
+    /// The title of the market's event, or the market question for standalone markets.
+    pub title: String,
+    /// The slug of the market's event, or the market slug for standalone markets.
+    pub slug: String,
+    /// The image of the market's event, or of the market itself.
+    pub image: Option<String>,
+    /// The category of the market's event, or of the market itself.
+    pub category: Option<String>,
+    /// When the market's event ends, or the market itself if the event has no end date.
+    pub end_date: Option<DateTime<Utc>>,
+    /// The Gamma ID of the market's event.
+    pub event_id: Option<String>,
+    /// The Gamma ID of the series the market's event belongs to.
+    pub series_id: Option<String>,
+    /// The slug of the series the market's event belongs to.
+    pub series_slug: Option<String>,
+    /// The title of the series the market's event belongs to.
+    pub series_title: Option<String>,
+}
+
+impl EnrichedMarket {
+    /// Joins `market` with `gamma`, the Gamma market of the same condition ID, if found.
+    /// Metadata of the event takes precedence over metadata of the Gamma market, which takes
+    /// precedence over the CLOB market.
+    pub(crate) fn new(market: MarketResponse, gamma: Option<&Market>) -> Self {
+        let event = gamma.and_then(|gamma| gamma.events.as_ref()?.first());
+        let series = event.and_then(|event| event.series.as_ref()?.first());
+        let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_owned());
+
+        Self {
+            title: event
+                .and_then(|event| event.title.clone())
+                .or_else(|| gamma?.question.clone())
+                .unwrap_or_else(|| market.question.clone()),
+            slug: event
+                .and_then(|event| event.slug.clone())
+                .or_else(|| gamma?.slug.clone())
+                .unwrap_or_else(|| market.market_slug.clone()),
+            image: event
+                .and_then(|event| event.image.clone())
+                .or_else(|| gamma?.image.clone())
+                .or_else(|| non_empty(&market.image)),
+            category: event
+                .and_then(|event| event.category.clone())
+                .or_else(|| gamma?.category.clone()),
+            end_date: event
+                .and_then(|event| event.end_date)
+                .or_else(|| gamma?.end_date)
+                .or(market.end_date_iso),
+            event_id: event.map(|event| event.id.clone()),
+            series_id: series.map(|series| series.id.clone()),
+            series_slug: series.and_then(|series| series.slug.clone()),
+            series_title: series.and_then(|series| series.title.clone()),
+            market,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Order expiration tracking.
+//!
+//! [`Client::watch_expirations`](crate::clob::Client::watch_expirations) keeps track of the
+//! `expiration` of every order posted via the client, and cancels each order shortly before the
+//! exchange would expire it, yielding an [`Expiry`] so that good-til-date strategies can replace
+//! the order on their own schedule.
+
+use std::time::Duration;
+
+use async_stream::stream;
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::Stream;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::timing::ServerClock;
+
+/// An order that is about to expire.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expiry {
+    /// The ID of the order.
+    pub order_id: String,
+    /// When the exchange expires the order.
+    pub expiration: DateTime<Utc>,
+    /// Whether the order was canceled. This is `false` if cancellation is disabled, or if the
+    /// exchange reported the order as not canceled, e.g. because it was already filled.
+    pub canceled: bool,
+}
+
+/// Configuration for [`Client::watch_expirations`](crate::clob::Client::watch_expirations).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct WatchConfig {
+    /// How long before its expiration an order is canceled and yielded. The default is ten (10)
+    /// seconds.
+    #[builder(default = Duration::from_secs(10))]
+    lead: Duration,
+    /// How often tracked orders are checked. The default is one (1) second.
+    #[builder(default = Duration::from_secs(1))]
+    interval: Duration,
+    /// Whether expiring orders are canceled before being yielded. The default is `true`.
+    #[builder(default = true)]
+    cancel: bool,
+    /// The server clock to compare expirations against, see
+    /// [`Client::sync_clock`](crate::clob::Client::sync_clock). Defaults to the local clock.
+    clock: Option<ServerClock>,
+}
+
+pub(crate) fn watch<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<Expiry>> + '_ {
+    stream! {
+        let lead = TimeDelta::from_std(config.lead).unwrap_or(TimeDelta::MAX);
+
+        loop {
+            let now = config.clock.as_ref().map_or_else(Utc::now, ServerClock::now);
+            let deadline = now.checked_add_signed(lead).unwrap_or(DateTime::<Utc>::MAX_UTC);
+            let expiring = client.take_expiring(deadline);
+
+            if !expiring.is_empty() {
+                let canceled = if config.cancel {
+                    let order_ids: Vec<&str> = expiring
+                        .iter()
+                        .map(|(order_id, _)| order_id.as_str())
+                        .collect();
+                    match client.cancel_orders(&order_ids).await {
+                        Ok(response) => response.canceled,
+                        Err(e) => {
+                            yield Err(e);
+                            time::sleep(config.interval).await;
+                            continue;
+                        }
+                    }
+                } else {
+                    Vec::new()
+                };
+
+                for (order_id, expiration) in expiring {
+                    if let Some(risk) = client.risk() {
+                        risk.release_order(&order_id);
+                    }
+                    yield Ok(Expiry {
+                        canceled: canceled.contains(&order_id),
+                        order_id,
+                        expiration,
+                    });
+                }
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Fee-adjusted prices.
+//!
+//! Displayed prices do not include the trading fee, so comparing them directly overstates the
+//! edge of a trade on fee-bearing markets. The exchange charges
+//! `fee_rate * min(price, 1 - price)` USDC per share, symmetric around 0.5:
+//!
+//! - A buyer pays the full price but receives fewer shares, as the fee is taken from the
+//!   proceeds in shares. The [effective cost](effective_buy_price) per share received is
+//!   therefore higher than the displayed price.
+//! - A seller delivers the full size but receives less USDC, as the fee is taken from the
+//!   proceeds in USDC. The [effective proceeds](effective_sell_price) per share sold are therefore
+//!   lower than the displayed price.
+//!
+//! [`estimate`] computes the exact fee of an [`Order`] the same way the exchange contract does,
+//! so that strategies can budget fees before submitting.
+//!
+//! The fee rate of a token is returned by
+//! [`Client::fee_rate_bps`](crate::clob::Client::fee_rate_bps), and
+//! [`Client::effective_price`](crate::clob::Client::effective_price) applies it in one go.
+//!
+//! ```rust
+//! use polymarket_client_sdk::clob::fees;
+//! use rust_decimal_macros::dec;
+//!
+//! // A 2% fee at a price of 0.4 costs 0.008 USDC per share
+//! assert_eq!(fees::fee_per_share(dec!(0.4), 200), dec!(0.008));
+//! assert_eq!(fees::effective_sell_price(dec!(0.4), 200), dec!(0.392));
+//! assert_eq!(fees::effective_buy_price(dec!(0.4), 200).round_dp(6), dec!(0.408163));
+//! ```
+
+use alloy::primitives::U256;
+
+use crate::Result;
+use crate::clob::types::{AssetType, Order, Side};
+use crate::error::Error;
+use crate::types::{Decimal, Shares, Usdc};
+
+/// The number of basis points in one.
+const BPS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// The fixed point scale of prices in the exchange contract.
+const ONE: U256 = U256::from_limbs([1_000_000_000_000_000_000, 0, 0, 0]);
+
+/// The fee of an [`Order`] if it is filled in full, see [`estimate`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Estimate {
+    /// The fee, in shares for buys and in USDC for sells.
+    pub fee: Decimal,
+    /// The asset the fee is charged in, [`AssetType::Conditional`] for buys and
+    /// [`AssetType::Collateral`] for sells.
+    pub asset_type: AssetType,
+}
+
+/// Returns the fee the exchange charges if `order` is filled in full at its limit price, given a
+/// fee rate in basis points.
+///
+/// This mirrors the exchange contract's integer arithmetic on base units: the price is derived
+/// from the order's amounts, and the fee of `fee_rate_bps * min(price, 1 - price)` per share is
+/// taken from the proceeds, i.e. from the shares received by a buy and the USDC received by a
+/// sell. Fills at a better price than the limit pay a slightly different fee.
+///
+/// # Errors
+///
+/// Returns an error if the order's side is unknown, or if the fee cannot be represented as a
+/// [`Decimal`].
+pub fn estimate(order: &Order, fee_rate_bps: u32) -> Result<Estimate> {
+    let side = Side::try_from(order.side)?;
+    let (price, outcome_tokens, asset_type) = match side {
+        Side::Buy => (
+            ratio(order.makerAmount, order.takerAmount),
+            order.takerAmount,
+            AssetType::Conditional,
+        ),
+        Side::Sell => (
+            ratio(order.takerAmount, order.makerAmount),
+            order.makerAmount,
+            AssetType::Collateral,
+        ),
+        Side::Unknown => {
+            return Err(Error::validation(format!(
+                "Unable to estimate the fee of an order with side {}",
+                order.side
+            )));
+        }
+    };
+
+    let rate = U256::from(fee_rate_bps);
+    let bps = U256::from(10_000);
+    let fee = if fee_rate_bps == 0 || price.is_zero() || price > ONE {
+        U256::ZERO
+    } else if side == Side::Buy {
+        rate * price.min(ONE - price) * outcome_tokens / (price * bps)
+    } else {
+        rate * price.min(ONE - price) * outcome_tokens / (bps * ONE)
+    };
+
+    let fee = match asset_type {
+        AssetType::Conditional => Shares::from_base_units(fee)?.as_decimal(),
+        _ => Usdc::from_base_units(fee)?.as_decimal(),
+    };
+
+    Ok(Estimate { fee, asset_type })
+}
+
+/// Returns `numerator / denominator` scaled by [`ONE`], or zero if `denominator` is zero.
+fn ratio(numerator: U256, denominator: U256) -> U256 {
+    if denominator.is_zero() {
+        return U256::ZERO;
+    }
+
+    numerator * ONE / denominator
+}
+
+/// Returns the fee in USDC charged per share traded at `price`, given a fee rate in basis points.
+#[must_use]
+pub fn fee_per_share(price: Decimal, fee_rate_bps: u32) -> Decimal {
+    Decimal::from(fee_rate_bps) / BPS * price.min(Decimal::ONE - price)
+}
+
+/// Returns the effective cost per share received of buying at `price`.
+///
+/// Returns `price` unchanged if it is not positive, since no shares would be received.
+#[must_use]
+pub fn effective_buy_price(price: Decimal, fee_rate_bps: u32) -> Decimal {
+    let received = price - fee_per_share(price, fee_rate_bps);
+    if price <= Decimal::ZERO || received <= Decimal::ZERO {
+        return price;
+    }
+
+    price * price / received
+}
+
+/// Returns the effective proceeds per share of selling at `price`.
+#[must_use]
+pub fn effective_sell_price(price: Decimal, fee_rate_bps: u32) -> Decimal {
+    price - fee_per_share(price, fee_rate_bps)
+}
+
+/// Returns the effective price of trading at `price` on `side`, see [`effective_buy_price`] and
+/// [`effective_sell_price`]. Prices of an unknown side are returned unchanged.
+#[must_use]
+pub fn effective_price(side: Side, price: Decimal, fee_rate_bps: u32) -> Decimal {
+    match side {
+        Side::Buy => effective_buy_price(price, fee_rate_bps),
+        Side::Sell => effective_sell_price(price, fee_rate_bps),
+        Side::Unknown => price,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn order(side: Side, maker_amount: u64, taker_amount: u64) -> Order {
+        Order {
+            makerAmount: U256::from(maker_amount),
+            takerAmount: U256::from(taker_amount),
+            side: side as u8,
+            ..Order::default()
+        }
+    }
+
+    #[test]
+    fn fee_per_share_should_be_symmetric() {
+        assert_eq!(fee_per_share(dec!(0.2), 100), dec!(0.002));
+        assert_eq!(fee_per_share(dec!(0.8), 100), dec!(0.002));
+        assert_eq!(fee_per_share(dec!(0.5), 0), dec!(0));
+    }
+
+    #[test]
+    fn effective_price_should_include_fees() {
+        // Buying 100 shares at 0.5 with a 1% fee costs 50 USDC for 99 shares
+        assert_eq!(
+            (effective_price(Side::Buy, dec!(0.5), 100) * dec!(99)).round_dp(6),
+            dec!(50)
+        );
+        assert_eq!(effective_price(Side::Sell, dec!(0.5), 100), dec!(0.495));
+        assert_eq!(effective_price(Side::Buy, dec!(0.5), 0), dec!(0.5));
+        assert_eq!(effective_price(Side::Unknown, dec!(0.5), 100), dec!(0.5));
+    }
+
+    #[test]
+    fn estimate_should_match_exchange_fixtures() {
+        // Buy 100 shares at 0.5 with a 1% fee, charged in shares
+        let estimate = super::estimate(&order(Side::Buy, 50_000_000, 100_000_000), 100).unwrap();
+        assert_eq!(estimate.fee, dec!(1));
+        assert_eq!(estimate.asset_type, AssetType::Conditional);
+
+        // Buy 100 shares at 0.8, where the fee is based on 1 - price
+        let estimate = super::estimate(&order(Side::Buy, 80_000_000, 100_000_000), 100).unwrap();
+        assert_eq!(estimate.fee, dec!(0.25));
+
+        // Sell 100 shares at 0.4 with a 1% fee, charged in USDC
+        let estimate = super::estimate(&order(Side::Sell, 100_000_000, 40_000_000), 100).unwrap();
+        assert_eq!(estimate.fee, dec!(0.4));
+        assert_eq!(estimate.asset_type, AssetType::Collateral);
+
+        // Amounts that do not divide evenly are rounded down, as on chain
+        let estimate = super::estimate(&order(Side::Sell, 3_000_000, 1_000_000), 100).unwrap();
+        assert_eq!(estimate.fee, dec!(0.009999));
+
+        let estimate = super::estimate(&order(Side::Buy, 50_000_000, 100_000_000), 0).unwrap();
+        assert_eq!(estimate.fee, dec!(0));
+        super::estimate(&order(Side::Unknown, 1, 1), 100).unwrap_err();
+    }
+}
@@ -0,0 +1,181 @@
+//! Per-order fill tracking.
+//!
+//! [`Client::stream_fills`](crate::clob::Client::stream_fills) repeatedly polls
+//! [`Client::order`](crate::clob::Client::order) and yields a [`Fill`] for every trade that becomes
+//! associated with the order, looking each trade up via
+//! [`Client::trades`](crate::clob::Client::trades) to determine how much of it filled this
+//! particular order.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::TradeStatusType;
+use crate::clob::types::request::TradesRequest;
+use crate::clob::types::response::TradeResponse;
+use crate::types::Decimal;
+
+/// An incremental fill of a single order.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fill {
+    /// The ID of the order that was filled.
+    pub order_id: String,
+    /// The ID of the trade that filled the order.
+    pub trade_id: String,
+    /// The number of shares of the order filled by this trade.
+    pub size: Decimal,
+    /// The price at which this trade filled the order.
+    pub price: Decimal,
+    /// The status of the trade when it was first observed.
+    pub status: TradeStatusType,
+    /// When the trade was matched.
+    pub match_time: DateTime<Utc>,
+}
+
+impl Fill {
+    /// Extracts the portion of `trade` that filled `order_id`, whether the order was the taker or
+    /// one of the makers. Returns `None` if the order did not participate in the trade.
+    fn from_trade(order_id: &str, trade: &TradeResponse) -> Option<Self> {
+        let (size, price) = if trade.taker_order_id == order_id {
+            (trade.size, trade.price)
+        } else {
+            let maker_order = trade
+                .maker_orders
+                .iter()
+                .find(|maker_order| maker_order.order_id == order_id)?;
+            (maker_order.matched_amount, maker_order.price)
+        };
+
+        Some(Self {
+            order_id: order_id.to_owned(),
+            trade_id: trade.id.clone(),
+            size,
+            price,
+            status: trade.status.clone(),
+            match_time: trade.match_time,
+        })
+    }
+}
+
+pub(crate) fn stream<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    order_id: String,
+    interval: Duration,
+) -> impl Stream<Item = Result<Fill>> + '_ {
+    stream! {
+        let mut seen: HashSet<String> = HashSet::new();
+
+        loop {
+            let order = match client.order(&order_id).await {
+                Ok(order) => order,
+                Err(e) => {
+                    yield Err(e);
+                    time::sleep(interval).await;
+                    continue;
+                }
+            };
+
+            for trade_id in &order.associate_trades {
+                if seen.contains(trade_id) {
+                    continue;
+                }
+
+                let request = TradesRequest::builder().id(trade_id).build();
+                match client.trades(&request, None).await {
+                    Ok(page) => {
+                        // A trade that is not indexed yet is looked up again at the next poll
+                        let Some(trade) = page.data.iter().find(|trade| &trade.id == trade_id) else {
+                            continue;
+                        };
+                        if let Some(fill) = Fill::from_trade(&order_id, trade) {
+                            if let Some(risk) = client.risk() {
+                                risk.record_fill(&order_id, fill.size);
+                            }
+                            yield Ok(fill);
+                        }
+                        seen.insert(trade_id.clone());
+                    }
+                    Err(e) => yield Err(e),
+                }
+            }
+
+            let caught_up = order
+                .associate_trades
+                .iter()
+                .all(|trade_id| seen.contains(trade_id));
+            if order.status.is_terminal() && caught_up {
+                break;
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn trade() -> TradeResponse {
+        serde_json::from_value(json!({
+            "id": "trade-1",
+            "taker_order_id": "taker",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "asset_id": "1",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "price": "0.5",
+            "status": "MATCHED",
+            "match_time": "1705322096",
+            "last_update": "1705322096",
+            "outcome": "Yes",
+            "bucket_index": 0,
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "maker_orders": [{
+                "order_id": "maker",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x3333333333333333333333333333333333333333",
+                "matched_amount": "4",
+                "price": "0.49",
+                "fee_rate_bps": "0",
+                "asset_id": "1",
+                "outcome": "Yes",
+                "side": "SELL"
+            }],
+            "transaction_hash": "0x0000000000000000000000000000000000000000000000000000000000000002",
+            "trader_side": "TAKER"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn from_trade_should_succeed() {
+        let trade = trade();
+
+        let taker = Fill::from_trade("taker", &trade).unwrap();
+        assert_eq!(taker.trade_id, "trade-1");
+        assert_eq!(taker.size, dec!(10));
+        assert_eq!(taker.price, dec!(0.5));
+
+        let maker = Fill::from_trade("maker", &trade).unwrap();
+        assert_eq!(maker.size, dec!(4));
+        assert_eq!(maker.price, dec!(0.49));
+        assert_eq!(maker.status, TradeStatusType::Matched);
+
+        assert!(Fill::from_trade("other", &trade).is_none());
+    }
+}
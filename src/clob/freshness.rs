@@ -0,0 +1,184 @@
+//! Staleness guards for market data used to price orders.
+//!
+//! A quote can be old by the time it is used: the request that fetched it may have waited on a
+//! rate limiter or been retried, and strategies often hold on to books between iterations. A
+//! [`Fresh`] value records when its response was received, so that
+//! [`OrderBuilder::fresh_price`](crate::clob::order_builder::OrderBuilder::fresh_price) can refuse
+//! to build an order from a price older than a given maximum age.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use polymarket_client_sdk::clob::freshness::Fresh;
+//! use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # use polymarket_client_sdk::clob::types::Side;
+//! # use polymarket_client_sdk::types::TokenId;
+//!
+//! # async fn example(client: Client<Authenticated<Normal>>, token_id: TokenId) -> Result<(), Box<dyn std::error::Error>> {
+//! let request = OrderBookSummaryRequest::builder().token_id(token_id).build();
+//! let book = Fresh::from(client.order_book_with_metadata(&request).await?);
+//! let Some(bid) = book.map(|book| book.best_bid().map(|level| level.price)).transpose() else {
+//!     return Ok(());
+//! };
+//!
+//! // Fails with a `StaleData` error if the book is older than 500ms once the order is built
+//! let order = client
+//!     .limit_order()
+//!     .token_id(token_id)
+//!     .side(Side::Buy)
+//!     .size(10)
+//!     .fresh_price(bid, Duration::from_millis(500))
+//!     .build()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::Result;
+use crate::error::StaleData;
+use crate::transport::ResponseEnvelope;
+
+/// A value together with the instant it was received, see the [module docs](self).
+#[derive(Clone, Copy, Debug)]
+pub struct Fresh<T> {
+    value: T,
+    received_at: Instant,
+}
+
+impl<T> Fresh<T> {
+    /// Wraps a value received just now.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self::at(value, Instant::now())
+    }
+
+    /// Wraps a value received at `received_at`.
+    #[must_use]
+    pub const fn at(value: T, received_at: Instant) -> Self {
+        Self { value, received_at }
+    }
+
+    /// Returns the instant the value was received.
+    #[must_use]
+    pub const fn received_at(&self) -> Instant {
+        self.received_at
+    }
+
+    /// Returns how long ago the value was received.
+    #[must_use]
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+
+    /// Returns the value if it was received at most `max_age` ago.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StaleData`] error if the value is older than `max_age`.
+    pub fn get(&self, max_age: Duration) -> Result<&T> {
+        check_age(self.received_at, max_age)?;
+        Ok(&self.value)
+    }
+
+    /// Returns the value regardless of its age.
+    #[must_use]
+    pub const fn get_unchecked(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value regardless of its age, discarding when it was received.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Borrows the value, keeping the instant it was received.
+    #[must_use]
+    pub const fn as_ref(&self) -> Fresh<&T> {
+        Fresh {
+            value: &self.value,
+            received_at: self.received_at,
+        }
+    }
+
+    /// Derives a value, such as a price from a book, that is as old as this one.
+    #[must_use]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Fresh<U> {
+        Fresh {
+            value: f(self.value),
+            received_at: self.received_at,
+        }
+    }
+}
+
+impl<T> Fresh<Option<T>> {
+    /// Moves the option outwards, keeping the instant the value was received.
+    #[must_use]
+    pub fn transpose(self) -> Option<Fresh<T>> {
+        let received_at = self.received_at;
+        self.value.map(|value| Fresh { value, received_at })
+    }
+}
+
+impl<T> From<ResponseEnvelope<T>> for Fresh<T> {
+    fn from(response: ResponseEnvelope<T>) -> Self {
+        Self::at(response.body, response.received_at)
+    }
+}
+
+/// Checks that a value received at `received_at` is at most `max_age` old.
+pub(crate) fn check_age(received_at: Instant, max_age: Duration) -> Result<()> {
+    let age = received_at.elapsed();
+    if age > max_age {
+        return Err(StaleData { age, max_age }.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Kind;
+
+    fn received_ago(value: u32, ago: Duration) -> Fresh<u32> {
+        let received_at = Instant::now().checked_sub(ago).expect("valid instant");
+        Fresh::at(value, received_at)
+    }
+
+    #[test]
+    fn get_should_return_recent_values() -> anyhow::Result<()> {
+        let fresh = Fresh::new(42);
+
+        assert_eq!(*fresh.get(Duration::from_secs(60))?, 42);
+        assert_eq!(fresh.map(|value| value + 1).into_inner(), 43);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_should_reject_stale_values() {
+        let stale = received_ago(42, Duration::from_secs(5));
+
+        let err = stale.get(Duration::from_secs(1)).unwrap_err();
+        assert_eq!(err.kind(), Kind::StaleData);
+
+        let err = err.downcast_ref::<StaleData>().expect("stale data error");
+        assert!(err.age >= Duration::from_secs(5));
+        assert_eq!(err.max_age, Duration::from_secs(1));
+
+        // Derived values keep the age of the value they were derived from
+        stale
+            .map(|value| Some(value * 2))
+            .transpose()
+            .expect("some value")
+            .get(Duration::from_secs(1))
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,247 @@
+//! Readiness reports for services built on a CLOB client.
+//!
+//! [`Client::health_report`](crate::clob::Client::health_report) concurrently checks that the CLOB
+//! API answers its health endpoint, how far the local clock is off from the server clock, whether
+//! the current IP address is geoblocked and, for authenticated clients, whether the API key is
+//! still accepted. The resulting [`Report`] serializes to JSON, so that it can be exposed as is
+//! from a service's own health endpoint, and [`Report::is_ready`] sums it up.
+//!
+//! The CLOB only reports its time with second precision, so the measured clock skew is only
+//! accurate to about half a second. Use [`Client::sync_clock`](crate::clob::Client::sync_clock)
+//! with the `polling` feature for a precise estimate.
+//!
+//! ```no_run
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # async fn example(client: Client<Authenticated<Normal>>) -> Result<(), Box<dyn std::error::Error>> {
+//! let report = client.health_report().await;
+//! if !report.is_ready() {
+//!     eprintln!("{}", serde_json::to_string(&report)?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use serde_with::{DurationMilliSeconds, serde_as};
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob::Client;
+
+/// The outcome of a single check.
+#[non_exhaustive]
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Check {
+    /// Whether the request succeeded.
+    pub ok: bool,
+    /// How long the request took.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub latency: Duration,
+    /// Why the request failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The readiness of a client at one point in time, see the [module docs](self).
+#[non_exhaustive]
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// When the checks were started.
+    pub checked_at: DateTime<Utc>,
+    /// The CLOB health endpoint.
+    pub api: Check,
+    /// The CLOB server time endpoint.
+    pub clock: Check,
+    /// How far the server clock is ahead of the local clock, if the server time was fetched.
+    #[serde_as(as = "Option<DurationMilliSeconds<i64>>")]
+    pub clock_skew: Option<TimeDelta>,
+    /// The geoblock endpoint.
+    pub geoblock: Check,
+    /// Whether the current IP address is blocked from placing orders, if the geoblock status was
+    /// fetched.
+    pub blocked: Option<bool>,
+    /// The API key, or `None` for unauthenticated clients.
+    pub auth: Option<Check>,
+}
+
+impl Report {
+    /// Returns whether every check succeeded and the current IP address is not geoblocked.
+    ///
+    /// The clock skew is not taken into account, as the tolerable skew depends on the service.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.api.ok
+            && self.clock.ok
+            && self.geoblock.ok
+            && self.blocked == Some(false)
+            && self.auth.as_ref().is_none_or(|auth| auth.ok)
+    }
+}
+
+/// Runs `request` and records how long it took and whether it succeeded.
+async fn timed<T>(request: impl Future<Output = Result<T>>) -> (Check, Option<T>) {
+    let started = Instant::now();
+    let result = request.await;
+    let latency = started.elapsed();
+
+    match result {
+        Ok(value) => (
+            Check {
+                ok: true,
+                latency,
+                error: None,
+            },
+            Some(value),
+        ),
+        Err(e) => (
+            Check {
+                ok: false,
+                latency,
+                error: Some(e.to_string()),
+            },
+            None,
+        ),
+    }
+}
+
+/// Returns how far the server clock is ahead of the local clock, comparing the server time with
+/// the local time halfway through the request.
+async fn clock_skew<S: State>(client: &Client<S>) -> Result<TimeDelta> {
+    let sent = Utc::now();
+    let server_time = client.server_time().await?;
+    let received = Utc::now();
+
+    let local = sent + (received - sent) / 2;
+    // The server truncates its time to whole seconds, so on average it is half a second behind
+    let server = TimeDelta::milliseconds(server_time.as_millis()) + TimeDelta::milliseconds(500);
+
+    Ok(server - TimeDelta::milliseconds(local.timestamp_millis()))
+}
+
+pub(crate) async fn report<S: State>(
+    client: &Client<S>,
+    auth: Option<impl Future<Output = Result<()>>>,
+) -> Report {
+    let checked_at = Utc::now();
+    let auth = async {
+        match auth {
+            Some(auth) => Some(timed(auth).await.0),
+            None => None,
+        }
+    };
+
+    let ((api, _), (clock, clock_skew), (geoblock, geoblock_status), auth) = futures::join!(
+        timed(client.ok()),
+        timed(clock_skew(client)),
+        timed(client.check_geoblock()),
+        auth,
+    );
+
+    Report {
+        checked_at,
+        api,
+        clock,
+        clock_skew,
+        geoblock,
+        blocked: geoblock_status.map(|status| status.blocked),
+        auth,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(ok: bool) -> Check {
+        Check {
+            ok,
+            latency: Duration::from_millis(12),
+            error: (!ok).then(|| "unreachable".to_owned()),
+        }
+    }
+
+    fn report() -> Report {
+        Report {
+            checked_at: DateTime::UNIX_EPOCH,
+            api: check(true),
+            clock: check(true),
+            clock_skew: Some(TimeDelta::milliseconds(-250)),
+            geoblock: check(true),
+            blocked: Some(false),
+            auth: None,
+        }
+    }
+
+    #[test]
+    fn is_ready_should_require_every_check() {
+        assert!(report().is_ready());
+        assert!(
+            Report {
+                auth: Some(check(true)),
+                ..report()
+            }
+            .is_ready()
+        );
+
+        assert!(
+            !Report {
+                auth: Some(check(false)),
+                ..report()
+            }
+            .is_ready()
+        );
+        assert!(
+            !Report {
+                api: check(false),
+                ..report()
+            }
+            .is_ready()
+        );
+        assert!(
+            !Report {
+                blocked: Some(true),
+                ..report()
+            }
+            .is_ready()
+        );
+        assert!(
+            !Report {
+                geoblock: check(false),
+                blocked: None,
+                ..report()
+            }
+            .is_ready()
+        );
+    }
+
+    #[test]
+    fn report_should_serialize_to_json() {
+        let report = Report {
+            clock: check(false),
+            clock_skew: None,
+            ..report()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "checkedAt": "1970-01-01T00:00:00Z",
+                "api": { "ok": true, "latency": 12 },
+                "clock": { "ok": false, "latency": 12, "error": "unreachable" },
+                "clockSkew": null,
+                "geoblock": { "ok": true, "latency": 12 },
+                "blocked": false,
+                "auth": null
+            })
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Pre-trade validation hooks.
+//!
+//! [`OrderValidator`]s registered via [`Config`](crate::clob::Config)'s `validators` are consulted
+//! by [`Client::sign`](crate::clob::Client::sign) before an order is signed, and by
+//! [`Client::post_order`](crate::clob::Client::post_order) and
+//! [`Client::post_orders`](crate::clob::Client::post_orders) before it is sent. Validators run in
+//! registration order, and the first [`Verdict::Deny`] fails the call with an [`OrderDenied`]
+//! error, so that compliance or strategy-specific checks live in one place rather than at every
+//! call site.
+//!
+//! ```rust
+//! use async_trait::async_trait;
+//! use polymarket_client_sdk::clob::hooks::{OrderValidator, Verdict};
+//! use polymarket_client_sdk::clob::types::SignableOrder;
+//! use polymarket_client_sdk::types::U256;
+//!
+//! #[derive(Debug)]
+//! struct NoMakerAbove(U256);
+//!
+//! #[async_trait]
+//! impl OrderValidator for NoMakerAbove {
+//!     async fn validate_signable(&self, order: &SignableOrder) -> Verdict {
+//!         if order.order.makerAmount > self.0 {
+//!             Verdict::deny("maker amount too large")
+//!         } else {
+//!             Verdict::Allow
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`OrderDenied`]: crate::error::OrderDenied
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::clob::types::{SignableOrder, SignedOrder};
+
+/// The outcome of an [`OrderValidator`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The order may proceed.
+    Allow,
+    /// The order is rejected for `reason`.
+    Deny {
+        /// Why the order was rejected.
+        reason: String,
+    },
+}
+
+impl Verdict {
+    /// Creates a [`Verdict::Deny`] with `reason`.
+    pub fn deny<S: Into<String>>(reason: S) -> Self {
+        Self::Deny {
+            reason: reason.into(),
+        }
+    }
+}
+
+/// A check that every outgoing order must pass, see the [module docs](self).
+///
+/// Both methods allow every order by default, so implementations only need to override the stage
+/// they care about.
+#[async_trait]
+pub trait OrderValidator: fmt::Debug + Send + Sync {
+    /// Validates `order` before it is signed.
+    async fn validate_signable(&self, _order: &SignableOrder) -> Verdict {
+        Verdict::Allow
+    }
+
+    /// Validates `order` before it is posted.
+    async fn validate_signed(&self, _order: &SignedOrder) -> Verdict {
+        Verdict::Allow
+    }
+}
@@ -0,0 +1,345 @@
+//! Laddered limit orders.
+//!
+//! [`Client::post_ladder`](crate::clob::Client::post_ladder) splits a large limit order into
+//! several smaller ones spread across price levels, starting at a given price and stepping away
+//! from the touch by a fixed spacing, i.e. downwards for buys and upwards for sells. The total
+//! size is distributed across the levels according to a [`Distribution`], and all orders are
+//! submitted in a single [`Client::post_orders`](crate::clob::Client::post_orders) call.
+//!
+//! The returned [`Ladder`] tracks the orders as one logical order, e.g. so that they can be
+//! canceled together with [`Ladder::cancel`].
+//!
+//! ```rust
+//! use polymarket_client_sdk::clob::ladder::{Config, Distribution};
+//! use polymarket_client_sdk::clob::types::Side;
+//! use polymarket_client_sdk::types::{TokenId, U256};
+//! use rust_decimal_macros::dec;
+//!
+//! let config = Config::builder()
+//!     .token_id(TokenId::from(U256::from(1)))
+//!     .side(Side::Buy)
+//!     .price(dec!(0.50))
+//!     .size(dec!(100))
+//!     .levels(4)
+//!     .spacing(dec!(0.01))
+//!     .distribution(Distribution::Increasing)
+//!     .build();
+//!
+//! let sizes: Vec<_> = config.levels().unwrap().iter().map(|level| level.size).collect();
+//! assert_eq!(sizes, [dec!(10), dec!(20), dec!(30), dec!(40)]);
+//! ```
+
+use alloy::signers::Signer;
+use bon::Builder;
+use futures::future;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::order_builder::LOT_SIZE_SCALE;
+use crate::clob::types::response::{CancelOrdersResponse, PostOrderResponse};
+use crate::clob::types::{OrderType, Side};
+use crate::error::Error;
+use crate::types::{Decimal, TokenId};
+
+/// The most levels a ladder can have, as the CLOB accepts at most 15 orders per batch.
+pub const MAX_LEVELS: u32 = 15;
+
+/// How the total size of a ladder is distributed across its levels.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Distribution {
+    /// Every level gets the same size.
+    #[default]
+    Uniform,
+    /// Sizes grow linearly away from the touch, so that the furthest level is the largest.
+    Increasing,
+    /// Sizes shrink linearly away from the touch, so that the nearest level is the largest.
+    Decreasing,
+    /// Sizes are proportional to the given weights, nearest level first. There must be one weight
+    /// per level.
+    Weights(Vec<Decimal>),
+}
+
+/// Configuration for [`Client::post_ladder`](crate::clob::Client::post_ladder).
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// The token to trade.
+    #[builder(into)]
+    token_id: TokenId,
+    /// Whether to buy or sell.
+    side: Side,
+    /// The price of the level nearest to the touch.
+    price: Decimal,
+    /// The total size across all levels.
+    size: Decimal,
+    /// The number of levels, at most [`MAX_LEVELS`].
+    levels: u32,
+    /// The price distance between consecutive levels.
+    spacing: Decimal,
+    /// How the size is distributed across levels. The default is [`Distribution::Uniform`].
+    #[builder(default)]
+    distribution: Distribution,
+    /// The order type of every level. The default is [`OrderType::GTC`].
+    #[builder(default = OrderType::GTC)]
+    order_type: OrderType,
+    /// Whether every level is posted with the `postOnly` flag.
+    post_only: Option<bool>,
+}
+
+/// A single order of a ladder.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Level {
+    /// The limit price.
+    pub price: Decimal,
+    /// The size.
+    pub size: Decimal,
+}
+
+impl Config {
+    /// Returns the levels of the ladder, nearest to the touch first. Sizes are truncated to the
+    /// lot size, the remainder is added to the nearest level with a positive weight, and levels
+    /// whose size is zero are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid, e.g. if it has more than [`MAX_LEVELS`]
+    /// levels or a level's price falls outside of `(0, 1)`.
+    pub fn levels(&self) -> Result<Vec<Level>> {
+        let count = self.levels as usize;
+        if count == 0 || self.levels > MAX_LEVELS {
+            return Err(Error::validation(format!(
+                "A ladder needs between 1 and {MAX_LEVELS} levels, got {}",
+                self.levels
+            )));
+        }
+        if self.size <= Decimal::ZERO || self.spacing <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Ladder size {} and spacing {} must be positive",
+                self.size, self.spacing
+            )));
+        }
+
+        let weights: Vec<Decimal> = match &self.distribution {
+            Distribution::Uniform => vec![Decimal::ONE; count],
+            Distribution::Increasing => (1..=self.levels).map(Decimal::from).collect(),
+            Distribution::Decreasing => (1..=self.levels).rev().map(Decimal::from).collect(),
+            Distribution::Weights(weights) => weights.clone(),
+        };
+        let total: Decimal = weights.iter().sum();
+        if weights.len() != count
+            || weights.iter().any(Decimal::is_sign_negative)
+            || total.is_zero()
+        {
+            return Err(Error::validation(format!(
+                "Ladder weights must be {count} non-negative values with a positive sum"
+            )));
+        }
+
+        let mut sizes: Vec<Decimal> = weights
+            .iter()
+            .map(|weight| (self.size * weight / total).trunc_with_scale(LOT_SIZE_SCALE))
+            .collect();
+        let remainder = self.size - sizes.iter().sum::<Decimal>();
+        if let Some(nearest) = weights.iter().position(|weight| !weight.is_zero()) {
+            sizes[nearest] += remainder;
+        }
+
+        let step = match self.side {
+            Side::Buy => -self.spacing,
+            Side::Sell => self.spacing,
+            side => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+
+        let mut price = self.price;
+        let mut levels = Vec::with_capacity(count);
+        for size in sizes {
+            if !size.is_zero() {
+                if price <= Decimal::ZERO || price >= Decimal::ONE {
+                    return Err(Error::validation(format!(
+                        "Ladder level price {price} is outside of (0, 1)"
+                    )));
+                }
+                levels.push(Level { price, size });
+            }
+            price += step;
+        }
+
+        Ok(levels)
+    }
+}
+
+/// The orders of a ladder posted via [`Client::post_ladder`](crate::clob::Client::post_ladder),
+/// tracked as one logical order.
+#[derive(Clone, Debug)]
+pub struct Ladder<K: Kind> {
+    client: Client<Authenticated<K>>,
+    levels: Vec<Level>,
+    responses: Vec<PostOrderResponse>,
+}
+
+impl<K: Kind> Ladder<K> {
+    /// Returns the levels of the ladder, nearest to the touch first.
+    #[must_use]
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Returns the response to every level's order, in the same order as [`Self::levels`].
+    #[must_use]
+    pub fn responses(&self) -> &[PostOrderResponse] {
+        &self.responses
+    }
+
+    /// Returns whether the order of every level was accepted.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.responses.iter().all(PostOrderResponse::is_success)
+    }
+
+    /// Returns the IDs of the accepted orders.
+    #[must_use]
+    pub fn order_ids(&self) -> Vec<&str> {
+        self.responses
+            .iter()
+            .filter(|response| response.is_success())
+            .filter_map(PostOrderResponse::order_id)
+            .collect()
+    }
+
+    /// Cancels every accepted order of the ladder that is still open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cancellation request fails.
+    pub async fn cancel(&self) -> Result<CancelOrdersResponse> {
+        let order_ids = self.order_ids();
+        if order_ids.is_empty() {
+            return Ok(CancelOrdersResponse::default());
+        }
+
+        self.client.cancel_orders(&order_ids).await
+    }
+}
+
+pub(crate) async fn post<K: Kind, S: Signer>(
+    client: &Client<Authenticated<K>>,
+    signer: &S,
+    config: Config,
+) -> Result<Ladder<K>> {
+    let levels = config.levels()?;
+
+    let orders = future::try_join_all(levels.iter().map(|level| async {
+        let mut builder = client
+            .limit_order()
+            .token_id(config.token_id)
+            .side(config.side)
+            .price(level.price)
+            .size(level.size)
+            .order_type(config.order_type.clone());
+        if let Some(post_only) = config.post_only {
+            builder = builder.post_only(post_only);
+        }
+
+        client.sign(signer, builder.build().await?).await
+    }))
+    .await?;
+
+    let responses = client.post_orders(orders).await?;
+
+    Ok(Ladder {
+        client: client.clone(),
+        levels,
+        responses,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::types::U256;
+
+    fn config(side: Side, distribution: Distribution) -> Config {
+        Config::builder()
+            .token_id(TokenId::from(U256::from(1)))
+            .side(side)
+            .price(dec!(0.50))
+            .size(dec!(10))
+            .levels(3)
+            .spacing(dec!(0.02))
+            .distribution(distribution)
+            .build()
+    }
+
+    #[test]
+    fn levels_should_step_away_from_touch() {
+        let prices = |side| -> Vec<_> {
+            config(side, Distribution::Uniform)
+                .levels()
+                .unwrap()
+                .iter()
+                .map(|level| level.price)
+                .collect()
+        };
+
+        assert_eq!(prices(Side::Buy), [dec!(0.50), dec!(0.48), dec!(0.46)]);
+        assert_eq!(prices(Side::Sell), [dec!(0.50), dec!(0.52), dec!(0.54)]);
+    }
+
+    #[test]
+    fn levels_should_distribute_size() {
+        let sizes = |distribution| -> Vec<_> {
+            config(Side::Buy, distribution)
+                .levels()
+                .unwrap()
+                .iter()
+                .map(|level| level.size)
+                .collect()
+        };
+        let prices: Vec<_> = config(
+            Side::Buy,
+            Distribution::Weights(vec![dec!(0), dec!(1), dec!(4)]),
+        )
+        .levels()
+        .unwrap()
+        .iter()
+        .map(|level| level.price)
+        .collect();
+
+        assert_eq!(
+            sizes(Distribution::Uniform),
+            [dec!(3.34), dec!(3.33), dec!(3.33)]
+        );
+        assert_eq!(
+            sizes(Distribution::Decreasing),
+            [dec!(5.01), dec!(3.33), dec!(1.66)]
+        );
+        assert_eq!(
+            sizes(Distribution::Weights(vec![dec!(0), dec!(1), dec!(4)])),
+            [dec!(2), dec!(8)]
+        );
+        assert_eq!(prices, [dec!(0.48), dec!(0.46)]);
+    }
+
+    #[test]
+    fn levels_should_reject_invalid_ladders() {
+        // The third level would be priced at zero
+        let mut wide = config(Side::Buy, Distribution::Uniform);
+        wide.spacing = dec!(0.25);
+        wide.levels().unwrap_err();
+
+        let mismatched = config(Side::Buy, Distribution::Weights(vec![dec!(1)]));
+        mismatched.levels().unwrap_err();
+
+        // More orders than fit in a single batch
+        let mut deep = config(Side::Buy, Distribution::Uniform);
+        deep.levels = MAX_LEVELS + 1;
+        deep.spacing = dec!(0.01);
+        deep.levels().unwrap_err();
+    }
+}
@@ -0,0 +1,99 @@
+//! Graceful shutdown.
+//!
+//! [`Client::shutdown_handle`](crate::clob::Client::shutdown_handle) returns a [`Shutdown`]
+//! handle, giving services a single place to hook their termination signal into. Once
+//! [`Shutdown::shutdown`] is called, the client rejects new orders, stops sending heartbeats,
+//! waits for in-flight order submissions and cancellations to complete and, if configured,
+//! cancels all open orders.
+//!
+//! ```rust,no_run
+//! # async fn run<K: polymarket_client_sdk::auth::Kind>(
+//! #     client: polymarket_client_sdk::clob::Client<
+//! #         polymarket_client_sdk::auth::state::Authenticated<K>,
+//! #     >,
+//! # ) -> anyhow::Result<()> {
+//! use polymarket_client_sdk::clob::lifecycle::ShutdownConfig;
+//!
+//! let shutdown = client.shutdown_handle(ShutdownConfig::builder().cancel_orders(true).build());
+//!
+//! tokio::signal::ctrl_c().await?;
+//! shutdown.shutdown().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use bon::Builder;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::error::Error;
+
+/// How often the number of in-flight requests is checked while draining.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Configuration for [`Client::shutdown_handle`](crate::clob::Client::shutdown_handle).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct ShutdownConfig {
+    /// Whether all open orders are cancelled once in-flight requests have drained. The default
+    /// is `false`.
+    #[builder(default)]
+    cancel_orders: bool,
+    /// How long to wait for in-flight order submissions and cancellations to complete. The
+    /// default is ten (10) seconds.
+    #[builder(default = Duration::from_secs(10))]
+    drain_timeout: Duration,
+}
+
+/// A handle that gracefully shuts down a [`Client`], see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct Shutdown<K: Kind> {
+    client: Client<Authenticated<K>>,
+    config: ShutdownConfig,
+}
+
+impl<K: Kind> Shutdown<K> {
+    pub(crate) const fn new(client: Client<Authenticated<K>>, config: ShutdownConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Shuts the client down and resolves once it is safe to exit.
+    ///
+    /// New calls to [`Client::post_order`] and [`Client::post_orders`] fail from this point on,
+    /// on every clone of the client. Cancellations are still allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Timeout`](crate::error::Timeout) error if in-flight requests did not complete
+    /// within the configured `drain_timeout`, in which case open orders are still cancelled if
+    /// configured, or an error if cancelling the open orders fails.
+    pub async fn shutdown(&self) -> Result<()> {
+        self.client.begin_shutdown();
+
+        let drained = time::timeout(self.config.drain_timeout, async {
+            while self.client.in_flight() > 0 {
+                time::sleep(DRAIN_POLL_INTERVAL).await;
+            }
+        })
+        .await
+        .is_ok();
+
+        if self.config.cancel_orders {
+            self.client.cancel_all_orders().await?;
+        }
+
+        if !drained {
+            return Err(Error::timeout(
+                self.config.drain_timeout,
+                "Timed out waiting for in-flight order requests to complete",
+            ));
+        }
+
+        Ok(())
+    }
+}
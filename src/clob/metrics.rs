@@ -0,0 +1,420 @@
+//! Prometheus metrics for a CLOB client.
+//!
+//! [`Client::metrics`](crate::clob::Client::metrics) takes a [`Snapshot`] of the client's
+//! operational state: how often the client-side [rate limiters](crate::transport::RateLimiters)
+//! held requests back, the hit ratios of the tick size, neg risk and fee rate caches, the health of
+//! automatic heartbeats, and the number of open orders. [`Snapshot::render`] formats it in the
+//! Prometheus text exposition format, and
+//! [`Client::serve_metrics`](crate::clob::Client::serve_metrics) serves it on an embedded `/metrics`
+//! endpoint, so that the client can be scraped without any further setup.
+//!
+//! Counting the open orders pages through [`Client::orders`](crate::clob::Client::orders), which
+//! draws from the same rate limits as any other request. The endpoint therefore counts them at
+//! most once every [`OPEN_ORDERS_REFRESH`] and serves the cached count in between, so that
+//! scrapes, which are not authenticated, cannot drive authenticated requests. The metric is
+//! omitted if counting fails.
+//!
+//! ```no_run
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # async fn example(client: Client<Authenticated<Normal>>) -> Result<(), Box<dyn std::error::Error>> {
+//! let server = client.serve_metrics("127.0.0.1:9090".parse()?).await?;
+//! println!("Serving metrics on http://{}/metrics", server.local_addr());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
+
+use crate::Result;
+use crate::transport::RateLimiterStats;
+
+/// The largest request head read from a scraper.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// How long a scraper has to send its request head before the connection is closed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long the endpoint waits before accepting connections again after accepting failed, e.g.
+/// because the process ran out of file descriptors.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// How long the open orders counted by the `/metrics` endpoint are served before they are
+/// counted again.
+pub const OPEN_ORDERS_REFRESH: Duration = Duration::from_secs(30);
+
+/// Hits and misses of a local cache.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups answered from the cache.
+    pub hits: u64,
+    /// The number of lookups that had to be requested from the CLOB.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns the share of lookups answered from the cache, or `None` if there were none.
+    #[must_use]
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "Ratios are only exported for monitoring"
+    )]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "A ratio does not need the precision of large counts"
+    )]
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+/// The operational state of a client at one point in time, see the [module docs](self).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    /// How often the client-side rate limiters held requests back.
+    pub rate_limiter: RateLimiterStats,
+    /// Lookups of the tick size cache.
+    pub tick_size_cache: CacheStats,
+    /// Lookups of the neg risk cache.
+    pub neg_risk_cache: CacheStats,
+    /// Lookups of the fee rate cache.
+    pub fee_rate_cache: CacheStats,
+    /// Whether automatic heartbeats are running. Always `false` without the `heartbeats` feature.
+    pub heartbeats_active: bool,
+    /// When the last automatic heartbeat accepted by the CLOB was sent, if any.
+    pub last_heartbeat_sent_at: Option<DateTime<Utc>>,
+    /// The number of open orders, or `None` if they could not be counted.
+    pub open_orders: Option<u64>,
+}
+
+impl Snapshot {
+    /// Formats this snapshot in the Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limiter = &self.rate_limiter;
+        metric(
+            f,
+            "polymarket_rate_limiter_requests_total",
+            "counter",
+            "Requests that passed through the client-side rate limiters.",
+            &[("", limiter.requests.to_string())],
+        )?;
+        metric(
+            f,
+            "polymarket_rate_limiter_throttled_total",
+            "counter",
+            "Requests that had to wait for a client-side rate limiter.",
+            &[("", limiter.throttled.to_string())],
+        )?;
+        metric(
+            f,
+            "polymarket_rate_limiter_wait_seconds_total",
+            "counter",
+            "Time requests waited for the client-side rate limiters.",
+            &[("", limiter.waited.as_secs_f64().to_string())],
+        )?;
+        metric(
+            f,
+            "polymarket_rate_limiter_throttled_ratio",
+            "gauge",
+            "Share of requests that had to wait for a client-side rate limiter.",
+            &[("", limiter.throttled_ratio().to_string())],
+        )?;
+
+        let caches = [
+            ("tick_size", self.tick_size_cache),
+            ("neg_risk", self.neg_risk_cache),
+            ("fee_rate", self.fee_rate_cache),
+        ];
+        let label = |cache: &str| format!("{{cache=\"{cache}\"}}");
+        metric(
+            f,
+            "polymarket_cache_hits_total",
+            "counter",
+            "Lookups answered from a local cache.",
+            &caches.map(|(cache, stats)| (label(cache), stats.hits.to_string())),
+        )?;
+        metric(
+            f,
+            "polymarket_cache_misses_total",
+            "counter",
+            "Lookups that had to be requested from the CLOB.",
+            &caches.map(|(cache, stats)| (label(cache), stats.misses.to_string())),
+        )?;
+        let ratios: Vec<_> = caches
+            .iter()
+            .filter_map(|(cache, stats)| Some((label(cache), stats.hit_ratio()?.to_string())))
+            .collect();
+        metric(
+            f,
+            "polymarket_cache_hit_ratio",
+            "gauge",
+            "Share of lookups answered from a local cache.",
+            &ratios,
+        )?;
+
+        metric(
+            f,
+            "polymarket_heartbeats_active",
+            "gauge",
+            "Whether automatic heartbeats are running.",
+            &[("", u8::from(self.heartbeats_active).to_string())],
+        )?;
+        if let Some(sent_at) = self.last_heartbeat_sent_at {
+            #[expect(
+                clippy::float_arithmetic,
+                reason = "Prometheus expects timestamps in fractional seconds"
+            )]
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "Millisecond timestamps fit into the mantissa of an f64"
+            )]
+            let seconds = sent_at.timestamp_millis() as f64 / 1000.0;
+            metric(
+                f,
+                "polymarket_heartbeat_last_sent_timestamp_seconds",
+                "gauge",
+                "When the last automatic heartbeat accepted by the CLOB was sent.",
+                &[("", seconds.to_string())],
+            )?;
+        }
+
+        if let Some(open_orders) = self.open_orders {
+            metric(
+                f,
+                "polymarket_open_orders",
+                "gauge",
+                "Open orders of the account.",
+                &[("", open_orders.to_string())],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the help and type of the metric `name`, followed by one sample per set of labels.
+fn metric<L: AsRef<str>>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    kind: &str,
+    help: &str,
+    samples: &[(L, String)],
+) -> fmt::Result {
+    writeln!(f, "# HELP {name} {help}")?;
+    writeln!(f, "# TYPE {name} {kind}")?;
+    for (labels, value) in samples {
+        writeln!(f, "{name}{} {value}", labels.as_ref())?;
+    }
+
+    Ok(())
+}
+
+/// An embedded `/metrics` endpoint, returned by
+/// [`Client::serve_metrics`](crate::clob::Client::serve_metrics). The endpoint stops serving when
+/// this is dropped.
+#[derive(Debug)]
+pub struct Server {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl Server {
+    /// Returns the address the endpoint listens on, e.g. to find the port chosen when binding to
+    /// port zero.
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Serves the [`Snapshot`] returned by `snapshot` to every `GET /metrics` request on `addr`.
+pub(crate) async fn serve<F, Fut>(addr: SocketAddr, snapshot: F) -> Result<Server>
+where
+    F: Fn() -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Snapshot> + Send,
+{
+    let listener = TcpListener::bind(addr).await?;
+    let local_addr = listener.local_addr()?;
+    let task = tokio::spawn(async move {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %e, "failed to accept metrics connection");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &std::io::Error = &e;
+
+                    time::sleep(ACCEPT_BACKOFF).await;
+                    continue;
+                }
+            };
+            let snapshot = snapshot.clone();
+            tokio::spawn(async move {
+                if let Err(e) = respond(stream, snapshot).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(error = %e, "failed to serve metrics");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &std::io::Error = &e;
+                }
+            });
+        }
+    });
+
+    Ok(Server { local_addr, task })
+}
+
+/// Answers a single HTTP request on `stream` and closes the connection.
+async fn respond<F, Fut>(mut stream: TcpStream, snapshot: F) -> std::io::Result<()>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Snapshot>,
+{
+    let mut request = Vec::new();
+    let mut buffer = [0_u8; 1024];
+    let read_head = async {
+        while !request.windows(4).any(|window| window == b"\r\n\r\n")
+            && request.len() < MAX_REQUEST_SIZE
+        {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        Ok::<_, std::io::Error>(())
+    };
+    time::timeout(REQUEST_TIMEOUT, read_head)
+        .await
+        .map_err(|_elapsed| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+
+    let (status, body) = match (method, path) {
+        ("GET", "/metrics") => ("200 OK", snapshot().await.render()),
+        ("GET", _) => ("404 Not Found", String::new()),
+        _ => ("405 Method Not Allowed", String::new()),
+    };
+
+    let mut response = String::new();
+    // Writing to a `String` cannot fail
+    let _ = write!(
+        response,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// The open orders last counted for the `/metrics` endpoint, shared by its connections.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct OpenOrdersCache {
+    last: Arc<Mutex<Option<Counted>>>,
+}
+
+/// A count of the open orders, `None` if counting failed.
+#[derive(Clone, Copy, Debug)]
+struct Counted {
+    at: Instant,
+    open_orders: Option<u64>,
+}
+
+impl OpenOrdersCache {
+    /// Returns the cached count, counting the open orders with `count` first if the cache is
+    /// empty or older than [`OPEN_ORDERS_REFRESH`]. Concurrent scrapes wait for a single count.
+    pub(crate) async fn get<F, Fut>(&self, count: F) -> Option<u64>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Option<u64>>,
+    {
+        let mut last = self.last.lock().await;
+        if let Some(counted) = *last
+            && counted.at.elapsed() < OPEN_ORDERS_REFRESH
+        {
+            return counted.open_orders;
+        }
+
+        let open_orders = count().await;
+        *last = Some(Counted {
+            at: Instant::now(),
+            open_orders,
+        });
+        open_orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn render_should_format_prometheus_text() {
+        let snapshot = Snapshot {
+            rate_limiter: RateLimiterStats {
+                requests: 4,
+                throttled: 1,
+                waited: Duration::from_millis(250),
+            },
+            tick_size_cache: CacheStats { hits: 3, misses: 1 },
+            heartbeats_active: true,
+            last_heartbeat_sent_at: DateTime::from_timestamp_millis(1_700_000_000_500),
+            open_orders: Some(2),
+            ..Snapshot::default()
+        };
+
+        let text = snapshot.render();
+
+        assert!(text.contains(
+            "# TYPE polymarket_rate_limiter_requests_total counter\n\
+             polymarket_rate_limiter_requests_total 4\n"
+        ));
+        assert!(text.contains("polymarket_rate_limiter_wait_seconds_total 0.25\n"));
+        assert!(text.contains("polymarket_rate_limiter_throttled_ratio 0.25\n"));
+        assert!(text.contains("polymarket_cache_hits_total{cache=\"tick_size\"} 3\n"));
+        assert!(text.contains("polymarket_cache_misses_total{cache=\"neg_risk\"} 0\n"));
+        assert!(text.contains("polymarket_cache_hit_ratio{cache=\"tick_size\"} 0.75\n"));
+        // Caches without lookups have no ratio
+        assert!(!text.contains("polymarket_cache_hit_ratio{cache=\"fee_rate\"}"));
+        assert!(text.contains("polymarket_heartbeats_active 1\n"));
+        assert!(text.contains("polymarket_heartbeat_last_sent_timestamp_seconds 1700000000.5\n"));
+        assert!(text.contains("polymarket_open_orders 2\n"));
+
+        let text = Snapshot::default().render();
+        assert!(!text.contains("polymarket_open_orders"));
+        assert!(!text.contains("polymarket_heartbeat_last_sent_timestamp_seconds"));
+    }
+}
@@ -74,11 +74,11 @@
 //!
 //! use polymarket_client_sdk::clob::{Client, Config};
 //! use polymarket_client_sdk::clob::types::request::MidpointRequest;
-//! use polymarket_client_sdk::types::U256;
+//! use polymarket_client_sdk::types::TokenId;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // Create an unauthenticated client
-//! let client = Client::new("https://clob.polymarket.com", Config::default())?;
+//! let client = Client::production(Config::default())?;
 //!
 //! // Check API health
 //! let status = client.ok().await?;
@@ -86,7 +86,7 @@
 //!
 //! // Get midpoint price for a token
 //! let request = MidpointRequest::builder()
-//!     .token_id(U256::from_str("15871154585880608648532107628464183779895785213830018178010423617714102767076")?)
+//!     .token_id(TokenId::from_str("15871154585880608648532107628464183779895785213830018178010423617714102767076")?)
 //!     .build();
 //! let midpoint = client.midpoint(&request).await?;
 //! println!("Midpoint: {}", midpoint.mid);
@@ -104,21 +104,21 @@
 //! use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 //! use polymarket_client_sdk::clob::{Client, Config};
 //! use polymarket_client_sdk::clob::types::{Side, SignedOrder};
-//! use polymarket_client_sdk::types::{dec, Decimal, U256};
+//! use polymarket_client_sdk::types::{dec, Decimal, TokenId};
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // Create signer from private key
 //! let private_key = std::env::var(PRIVATE_KEY_VAR)?;
 //! let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
 //!
-//! let client = Client::new("https://clob.polymarket.com", Config::default())?
+//! let client = Client::production(Config::default())?
 //!     .authentication_builder(&signer)
 //!     .authenticate()
 //!     .await?;
 //!
 //! let order = client
 //!     .limit_order()
-//!     .token_id(U256::from_str("15871154585880608648532107628464183779895785213830018178010423617714102767076")?)
+//!     .token_id(TokenId::from_str("15871154585880608648532107628464183779895785213830018178010423617714102767076")?)
 //!     .side(Side::Buy)
 //!     .price(dec!(0.5))
 //!     .size(Decimal::TEN)
@@ -138,15 +138,82 @@
 //! - **`heartbeats`**: Enables automatic heartbeat mechanism for authenticated sessions
 //! - **`tracing`**: Enables detailed request/response tracing
 //! - **`rfq`**: Enables RFQ (Request for Quote) endpoints for institutional trading
+//! - **`polling`**: Enables polling-based watchers such as market resolution tracking,
+//!   `Client::wait_for_order`, per-order fill streams via `Client::stream_fills`, and
+//!   notification streams via `Client::stream_notifications`. Together with `ctf`, also enables
+//!   deposit detection via `Client::watch_deposits`
+//! - **`ctf`**: Enables correlating API trades with on-chain fills via [`settlement`]
+//! - **`gamma`**: Enables joining markets with their Gamma metadata via
+//!   `Client::enriched_market`
+//! - **`session-encryption`**: Enables encrypting exported [`session::Session`]s
+//! - **`toml`**: Enables loading [`bootstrap::Settings`] from TOML files
 //!
 //! # API Base URL
 //!
 //! The default API endpoint is `https://clob.polymarket.com`.
 
+pub mod alerts;
+pub mod amounts;
+#[cfg(feature = "polling")]
+pub mod backtest;
+pub mod batch;
+pub mod bootstrap;
 pub mod client;
+#[cfg(feature = "polling")]
+pub mod closing;
+#[cfg(all(feature = "ctf", feature = "polling"))]
+pub mod deposits;
+pub mod earnings;
+pub mod endpoint;
+#[cfg(feature = "gamma")]
+pub mod enrichment;
+#[cfg(feature = "polling")]
+pub mod expirations;
+pub mod fees;
+#[cfg(feature = "polling")]
+pub mod fills;
+pub mod freshness;
+pub mod health;
+pub mod hooks;
+pub mod ladder;
+#[cfg(feature = "polling")]
+pub mod lifecycle;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "polling")]
+pub mod notifications;
+#[cfg(feature = "polling")]
+pub mod open_orders;
 pub mod order_builder;
+pub mod order_handle;
+#[cfg(feature = "polling")]
+pub mod playback;
+#[cfg(feature = "data")]
+pub mod positions;
+#[cfg(feature = "polling")]
+pub mod recorder;
+pub mod resolution;
+#[cfg(feature = "polling")]
+pub mod restrictions;
+pub mod revenue;
+pub mod rewards;
+pub mod risk;
+#[cfg(all(feature = "rfq", feature = "polling"))]
+pub mod routing;
+#[cfg(feature = "polling")]
+pub mod scoring;
+pub mod session;
+#[cfg(feature = "ctf")]
+pub mod settlement;
+pub mod storage;
+#[cfg(feature = "polling")]
+pub mod strategy;
+pub mod switchboard;
+#[cfg(feature = "polling")]
+pub mod timing;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
 
 pub use client::{Client, Config};
+pub use order_handle::OrderHandle;
@@ -0,0 +1,107 @@
+//! Notification polling.
+//!
+//! [`Client::stream_notifications`](crate::clob::Client::stream_notifications) repeatedly polls
+//! the user's notifications and yields each new one as a typed
+//! [`Notification`], optionally deleting notifications once they have been yielded.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use async_stream::stream;
+use bon::Builder;
+use futures::Stream;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::response::Notification;
+
+/// Configuration for [`Client::stream_notifications`](crate::clob::Client::stream_notifications).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct StreamConfig {
+    /// How often notifications are polled. The default is thirty (30) seconds.
+    #[builder(default = Duration::from_secs(30))]
+    interval: Duration,
+    /// Whether notifications are deleted after they have been yielded. The default is `false`.
+    #[builder(default)]
+    delete: bool,
+}
+
+pub(crate) fn stream<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    config: StreamConfig,
+) -> impl Stream<Item = Result<Notification>> + '_ {
+    stream! {
+        // Notifications seen in the previous poll. Only tracking the latest poll keeps this
+        // bounded while still deduplicating notifications that remain in the user's list.
+        let mut seen: HashSet<Key> = HashSet::new();
+        // IDs of yielded notifications whose deletion has not succeeded yet.
+        let mut pending_deletion: Vec<String> = Vec::new();
+
+        loop {
+            let notifications = match client.fetch_notifications::<Notification>().await {
+                Ok(notifications) => notifications,
+                Err(e) => {
+                    yield Err(e);
+                    time::sleep(config.interval).await;
+                    continue;
+                }
+            };
+
+            let current: HashSet<Key> = notifications.iter().map(Key::of).collect();
+            let fresh: Vec<Notification> = notifications
+                .into_iter()
+                .filter(|notification| !seen.contains(&Key::of(notification)))
+                .collect();
+
+            // Delete before yielding so that a consumer dropping the stream mid-batch does not
+            // leave already yielded notifications behind.
+            let mut deletion_error = None;
+            if config.delete {
+                pending_deletion.retain(|id| current.contains(&Key::Id(id.clone())));
+                pending_deletion.extend(
+                    fresh
+                        .iter()
+                        .filter_map(|notification| notification.id.clone()),
+                );
+
+                if !pending_deletion.is_empty() {
+                    match client.send_delete_notifications(pending_deletion.clone()).await {
+                        Ok(()) => pending_deletion.clear(),
+                        Err(e) => deletion_error = Some(e),
+                    }
+                }
+            }
+            seen = current;
+
+            for notification in fresh {
+                yield Ok(notification);
+            }
+            if let Some(e) = deletion_error {
+                yield Err(e);
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
+
+/// Identifies a notification across polls: by its ID, or by its content if it has none.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum Key {
+    Id(String),
+    Content(String),
+}
+
+impl Key {
+    fn of(notification: &Notification) -> Self {
+        match &notification.id {
+            Some(id) => Self::Id(id.clone()),
+            // `Notification` holds arbitrary JSON payloads, which are not `Hash`
+            None => Self::Content(format!("{notification:?}")),
+        }
+    }
+}
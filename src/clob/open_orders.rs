@@ -0,0 +1,106 @@
+//! Open order diffing.
+//!
+//! [`Client::watch_open_orders`](crate::clob::Client::watch_open_orders) repeatedly pages through
+//! [`Client::orders`](crate::clob::Client::orders) and compares every snapshot against the
+//! previous one, yielding an [`Event`] for every order that appeared, changed or disappeared
+//! rather than the full snapshot. This is a polling substitute for the user websocket channel.
+//!
+//! When an order disappears, it is looked up via [`Client::order`](crate::clob::Client::order)
+//! to tell whether it was filled or canceled.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_stream::stream;
+use futures::{Stream, TryStreamExt as _};
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::OrderStatusType;
+use crate::clob::types::request::OrdersRequest;
+use crate::clob::types::response::OpenOrderResponse;
+
+/// A change between two snapshots of the open orders.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// An order was opened. Every order of the first snapshot is reported as new.
+    New(OpenOrderResponse),
+    /// An open order changed, e.g. because it was partially filled.
+    Updated {
+        /// The order as of the previous snapshot.
+        previous: Box<OpenOrderResponse>,
+        /// The order as of the current snapshot.
+        current: OpenOrderResponse,
+    },
+    /// An order is no longer open because it was fully matched.
+    Filled(OpenOrderResponse),
+    /// An order is no longer open because it was canceled.
+    Canceled(OpenOrderResponse),
+    /// An order is no longer open for another reason, or its final state could not be looked up,
+    /// in which case it is reported as of the last snapshot it appeared in.
+    Removed(OpenOrderResponse),
+}
+
+/// Returns every open order, in the order returned by the CLOB.
+async fn snapshot<K: Kind>(client: &Client<Authenticated<K>>) -> Result<Vec<OpenOrderResponse>> {
+    client
+        .stream_data(|client, cursor| async move {
+            client.orders(&OrdersRequest::default(), cursor).await
+        })
+        .try_collect()
+        .await
+}
+
+/// Looks up the final state of `order`, which is no longer open.
+async fn removal<K: Kind>(client: &Client<Authenticated<K>>, order: OpenOrderResponse) -> Event {
+    match client.order(&order.id).await {
+        Ok(current) => match current.status {
+            OrderStatusType::Matched => Event::Filled(current),
+            OrderStatusType::Canceled => Event::Canceled(current),
+            _ => Event::Removed(current),
+        },
+        Err(_) => Event::Removed(order),
+    }
+}
+
+pub(crate) fn watch<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    interval: Duration,
+) -> impl Stream<Item = Result<Event>> + '_ {
+    stream! {
+        let mut known: HashMap<String, OpenOrderResponse> = HashMap::new();
+
+        loop {
+            match snapshot(client).await {
+                Ok(orders) => {
+                    let mut previous = std::mem::take(&mut known);
+
+                    for order in orders {
+                        match previous.remove(&order.id) {
+                            None => yield Ok(Event::New(order.clone())),
+                            Some(previous) if previous != order => {
+                                yield Ok(Event::Updated {
+                                    previous: Box::new(previous),
+                                    current: order.clone(),
+                                });
+                            }
+                            Some(_) => {}
+                        }
+                        known.insert(order.id.clone(), order);
+                    }
+
+                    for (_, order) in previous {
+                        yield Ok(removal(client, order).await);
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use std::marker::PhantomData;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
@@ -10,14 +10,17 @@ use crate::Result;
 use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
+use crate::clob::amounts::Amounts;
+use crate::clob::freshness::{self, Fresh};
 use crate::clob::types::request::OrderBookSummaryRequest;
 use crate::clob::types::{
-    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
+    Amount, AmountInner, Order, OrderType, Price, RoundingMode, Side, SignableOrder, SignatureType,
+    TimeInForce,
 };
 use crate::error::Error;
-use crate::types::{Address, Decimal};
+use crate::types::{Address, Decimal, Shares, TokenId, Usdc};
 
-pub(crate) const USDC_DECIMALS: u32 = 6;
+pub(crate) const USDC_DECIMALS: u32 = Usdc::DECIMALS;
 
 /// Maximum number of decimal places for `size`
 pub(crate) const LOT_SIZE_SCALE: u32 = 2;
@@ -39,7 +42,7 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) signer: Address,
     pub(crate) signature_type: SignatureType,
     pub(crate) salt_generator: fn() -> u64,
-    pub(crate) token_id: Option<U256>,
+    pub(crate) token_id: Option<TokenId>,
     pub(crate) price: Option<Decimal>,
     pub(crate) size: Option<Decimal>,
     pub(crate) amount: Option<Amount>,
@@ -49,6 +52,12 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) taker: Option<Address>,
     pub(crate) order_type: Option<OrderType>,
     pub(crate) post_only: Option<bool>,
+    pub(crate) client_order_id: Option<String>,
+    pub(crate) rounding: Option<RoundingMode>,
+    /// When the price set through `fresh_price` was received
+    pub(crate) price_received_at: Option<Instant>,
+    /// The maximum age of the price set through `fresh_price` when the order is built
+    pub(crate) max_age: Option<Duration>,
     pub(crate) funder: Option<Address>,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
@@ -56,8 +65,8 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
 impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
     /// Sets the `token_id` for this builder. This is a required field.
     #[must_use]
-    pub fn token_id(mut self, token_id: U256) -> Self {
-        self.token_id = Some(token_id);
+    pub fn token_id<T: Into<TokenId>>(mut self, token_id: T) -> Self {
+        self.token_id = Some(token_id.into());
         self
     }
 
@@ -99,6 +108,43 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self.post_only = Some(post_only);
         self
     }
+
+    /// Sets a caller-chosen identifier for this order, see [`SignableOrder::client_order_id`].
+    #[must_use]
+    pub fn client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Sets how the price is rounded onto the market's tick size. Limit orders reject prices
+    /// that are not a multiple of the tick size unless this is set, market orders default to
+    /// [`RoundingMode::TowardPassive`] so that their price never exceeds the caller's limit.
+    /// [`RoundingMode::TowardAggressive`] is opt-in, as it may fill up to one tick worse.
+    #[must_use]
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Sets the price from a [`Fresh`] value, such as a price read from a fetched book. Building
+    /// the order fails with a [`StaleData`](crate::error::StaleData) error if the price is older
+    /// than `max_age` by then, which includes the time spent fetching the market's tick size and
+    /// fee rate.
+    #[must_use]
+    pub fn fresh_price(mut self, price: Fresh<Decimal>, max_age: Duration) -> Self {
+        self.price_received_at = Some(price.received_at());
+        self.price = Some(price.into_inner());
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Checks the age of the price set through [`Self::fresh_price`], if any.
+    fn check_price_age(&self) -> Result<()> {
+        match (self.price_received_at, self.max_age) {
+            (Some(received_at), Some(max_age)) => freshness::check_age(received_at, max_age),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<K: AuthKind> OrderBuilder<Limit, K> {
@@ -106,22 +152,31 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
     #[must_use]
     pub fn price(mut self, price: Decimal) -> Self {
         self.price = Some(price);
+        self.price_received_at = None;
         self
     }
 
-    /// Sets the size for this limit builder. This is a required field.
+    /// Sets the size for this limit builder, either as a [`Decimal`] or as
+    /// [`Shares`](crate::types::Shares). This is a required field.
     #[must_use]
-    pub fn size(mut self, size: Decimal) -> Self {
-        self.size = Some(size);
+    pub fn size<D: Into<Decimal>>(mut self, size: D) -> Self {
+        self.size = Some(size.into());
         self
     }
 
     /// Validates and transforms this limit builder into a [`SignableOrder`]
+    pub async fn build(self) -> Result<SignableOrder> {
+        Ok(self.normalize().await?.order)
+    }
+
+    /// Validates and transforms this limit builder into a [`SignableOrder`], reporting how the
+    /// price was rounded onto the tick size and the amounts that will be signed, see
+    /// [`Normalized`].
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self), err(level = "warn"))
     )]
-    pub async fn build(self) -> Result<SignableOrder> {
+    pub async fn normalize(self) -> Result<Normalized> {
         let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
@@ -134,11 +189,12 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             ));
         };
 
-        let Some(price) = self.price else {
+        let Some(requested_price) = self.price else {
             return Err(Error::validation(
                 "Unable to build Order due to missing price",
             ));
         };
+        let price = requested_price;
 
         if price.is_sign_negative() {
             return Err(Error::validation(format!(
@@ -147,29 +203,24 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         }
 
         let fee_rate = self.client.fee_rate_bps(token_id).await?;
-        let minimum_tick_size = self
-            .client
-            .tick_size(token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-
-        let decimals = minimum_tick_size.scale();
-
-        if price.scale() > minimum_tick_size.scale() {
-            return Err(Error::validation(format!(
-                "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
-                {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
-                price.scale(),
-                minimum_tick_size.scale()
-            )));
-        }
+        let tick_size = self.client.tick_size(token_id).await?.minimum_tick_size;
+        let minimum_tick_size = tick_size.as_decimal();
+        self.check_price_age()?;
+
+        let price = if let Some(rounding) = self.rounding {
+            Price::round(price, tick_size, side, rounding)?
+        } else {
+            if price.scale() > minimum_tick_size.scale() {
+                return Err(Error::validation(format!(
+                    "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
+                    {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
+                    price.scale(),
+                    minimum_tick_size.scale()
+                )));
+            }
 
-        if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
-            return Err(Error::validation(format!(
-                "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
-            )));
-        }
+            Price::new(price, tick_size)?
+        };
 
         let Some(size) = self.size else {
             return Err(Error::validation(
@@ -195,39 +246,21 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         let taker = self.taker.unwrap_or(Address::ZERO);
         let order_type = self.order_type.unwrap_or(OrderType::GTC);
         let post_only = Some(self.post_only.unwrap_or(false));
+        let expiration = U256::from(expiration.timestamp().to_u64().ok_or(Error::validation(
+            format!("Unable to represent expiration {expiration} as a u64"),
+        ))?);
 
-        if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
-            return Err(Error::validation(
-                "Only GTD orders may have a non-zero expiration",
-            ));
-        }
-
-        if post_only == Some(true) && !matches!(order_type, OrderType::GTC | OrderType::GTD) {
-            return Err(Error::validation(
-                "postOnly is only supported for GTC and GTD orders",
-            ));
-        }
+        // Rejects the same combinations of order type, expiration and `postOnly` as signing does
+        TimeInForce::resolve(&order_type, expiration, post_only)?;
 
         // When buying `YES` tokens, the user will "make" `size` * `price` USDC and "take"
-        // `size` `YES` tokens, and vice versa for sells. We have to truncate the notional values
-        // to the combined precision of the tick size _and_ the lot size. This is to ensure that
-        // this order will "snap" to the precision of resting orders on the book. The returned
-        // values are quantized to `USDC_DECIMALS`.
+        // `size` `YES` tokens, and vice versa for sells, see `Amounts::limit`.
         //
         // e.g. User submits a limit order to buy 100 `YES` tokens at $0.34.
         // This means they will take/receive 100 `YES` tokens, make/give up 34 USDC. This means that
         // the `taker_amount` is `100000000` and the `maker_amount` of `34000000`.
-        let (taker_amount, maker_amount) = match side {
-            Side::Buy => (
-                size,
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-            ),
-            Side::Sell => (
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-                size,
-            ),
-            side => return Err(Error::validation(format!("Invalid side: {side}"))),
-        };
+        let amounts = Amounts::limit(side, price, size)?;
+        let price = price.as_decimal();
 
         let salt = to_ieee_754_int((self.salt_generator)());
 
@@ -235,27 +268,37 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             salt: U256::from(salt),
             maker: self.funder.unwrap_or(self.signer),
             taker,
-            tokenId: token_id,
-            makerAmount: U256::from(to_fixed_u128(maker_amount)),
-            takerAmount: U256::from(to_fixed_u128(taker_amount)),
+            tokenId: token_id.into(),
+            makerAmount: amounts.maker,
+            takerAmount: amounts.taker,
             side: side as u8,
             feeRateBps: U256::from(fee_rate.base_fee),
             nonce: U256::from(nonce),
             signer: self.signer,
-            expiration: U256::from(expiration.timestamp().to_u64().ok_or(Error::validation(
-                format!("Unable to represent expiration {expiration} as a u64"),
-            ))?),
+            expiration,
             signatureType: self.signature_type as u8,
         };
 
         #[cfg(feature = "tracing")]
         tracing::debug!(token_id = %token_id, side = ?side, price = %price, size = %size, "limit order built");
 
-        Ok(SignableOrder {
+        let order = SignableOrder {
             order,
             order_type,
             post_only,
-        })
+            client_order_id: self.client_order_id,
+        };
+        let normalized = Normalized::new(order, Some(requested_price), price)?;
+        if let Some(risk) = self.client.risk() {
+            risk.check_position(
+                token_id,
+                side,
+                normalized.shares.as_decimal(),
+                normalized.usdc.as_decimal(),
+            )?;
+        }
+
+        Ok(normalized)
     }
 }
 
@@ -264,6 +307,7 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
     #[must_use]
     pub fn price(mut self, price: Decimal) -> Self {
         self.price = Some(price);
+        self.price_received_at = None;
         self
     }
 
@@ -341,11 +385,17 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
     }
 
     /// Validates and transforms this market builder into a [`SignableOrder`]
+    pub async fn build(self) -> Result<SignableOrder> {
+        Ok(self.normalize().await?.order)
+    }
+
+    /// Validates and transforms this market builder into a [`SignableOrder`], reporting the
+    /// price it was built at and the amounts that will be signed, see [`Normalized`].
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self), err(level = "warn"))
     )]
-    pub async fn build(self) -> Result<SignableOrder> {
+    pub async fn normalize(self) -> Result<Normalized> {
         let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
@@ -372,35 +422,24 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
                 "postOnly is only supported for limit orders",
             ));
         }
-        let price = match self.price {
+        let requested_price = self.price;
+        let price = match requested_price {
             Some(price) => price,
             None => self.calculate_price(order_type.clone()).await?,
         };
 
-        let minimum_tick_size = self
-            .client
-            .tick_size(token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
+        let tick_size = self.client.tick_size(token_id).await?.minimum_tick_size;
         let fee_rate = self.client.fee_rate_bps(token_id).await?;
+        self.check_price_age()?;
 
-        let decimals = minimum_tick_size.scale();
-
-        // Ensure that the market price returned internally is truncated to our tick size
-        let price = price.trunc_with_scale(decimals);
-        if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
-            return Err(Error::validation(format!(
-                "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
-            )));
-        }
+        // Ensure that the price is on our tick size, rounding away from the book by default so
+        // that the order never fills worse than the price the caller asked for
+        let rounding = self.rounding.unwrap_or(RoundingMode::TowardPassive);
+        let price = Price::round(price, tick_size, side, rounding)?;
 
         // When buying `YES` tokens, the user will "make" `USDC` dollars and "take"
         // `USDC` / `price` `YES` tokens. When selling `YES` tokens, the user will "make" `YES`
-        // token shares, and "take" `YES` shares * `price`. We have to truncate the notional values
-        // to the combined precision of the tick size _and_ the lot size. This is to ensure that
-        // this order will "snap" to the precision of resting orders on the book. The returned
-        // values are quantized to `USDC_DECIMALS`.
+        // token shares, and "take" `YES` shares * `price`, see `Amounts::market`.
         //
         // e.g. User submits a market order to buy $100 worth of `YES` tokens at
         // the current `market_price` of $0.34. This means they will take/receive (100/0.34)
@@ -411,35 +450,8 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         // `market_price` of $0.34. This means that they will take/receive $34, make/give up 100
         // `YES` tokens. This means that the `taker_amount` is `34000000` and the `maker_amount` is
         // `100000000`.
-        let raw_amount = amount.as_inner();
-
-        let (taker_amount, maker_amount) = match (side, amount.0) {
-            // Spend USDC to buy shares
-            (Side::Buy, AmountInner::Usdc(_)) => {
-                let shares = (raw_amount / price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (shares, raw_amount)
-            }
-
-            // Buy N shares: use cutoff `price` derived from ask depth
-            (Side::Buy, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (raw_amount, usdc)
-            }
-
-            // Sell N shares for USDC
-            (Side::Sell, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (usdc, raw_amount)
-            }
-
-            (Side::Sell, AmountInner::Usdc(_)) => {
-                return Err(Error::validation(
-                    "Sell Orders must specify their `amount`s in shares",
-                ));
-            }
-
-            (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
-        };
+        let amounts = Amounts::market(side, price, amount)?;
+        let price = price.as_decimal();
 
         let salt = to_ieee_754_int((self.salt_generator)());
 
@@ -447,9 +459,9 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             salt: U256::from(salt),
             maker: self.funder.unwrap_or(self.signer),
             taker,
-            tokenId: token_id,
-            makerAmount: U256::from(to_fixed_u128(maker_amount)),
-            takerAmount: U256::from(to_fixed_u128(taker_amount)),
+            tokenId: token_id.into(),
+            makerAmount: amounts.maker,
+            takerAmount: amounts.taker,
             side: side as u8,
             feeRateBps: U256::from(fee_rate.base_fee),
             nonce: U256::from(nonce),
@@ -461,22 +473,75 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         #[cfg(feature = "tracing")]
         tracing::debug!(token_id = %token_id, side = ?side, price = %price, amount = %amount.as_inner(), "market order built");
 
-        Ok(SignableOrder {
+        let order = SignableOrder {
             order,
             order_type,
             post_only: None,
-        })
+            client_order_id: self.client_order_id,
+        };
+        let normalized = Normalized::new(order, requested_price, price)?;
+        if let Some(risk) = self.client.risk() {
+            risk.check_position(
+                token_id,
+                side,
+                normalized.shares.as_decimal(),
+                normalized.usdc.as_decimal(),
+            )?;
+        }
+
+        Ok(normalized)
     }
 }
 
-/// Removes trailing zeros, truncates to [`USDC_DECIMALS`] decimal places, and quanitizes as an
-/// integer.
-fn to_fixed_u128(d: Decimal) -> u128 {
-    d.normalize()
-        .trunc_with_scale(USDC_DECIMALS)
-        .mantissa()
-        .to_u128()
-        .expect("The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided")
+/// Exactly what an [`OrderBuilder`] is about to sign, returned by its `normalize` method.
+///
+/// Prices are rounded onto the market's tick size, and the amounts are truncated to the precision
+/// of resting orders and then to base units, so the order that is signed can differ slightly from
+/// the one described to the builder. Logging a [`Normalized`] order, or asserting on it, makes
+/// that difference explicit when reconciling fills.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Normalized {
+    /// The order that will be signed.
+    pub order: SignableOrder,
+    /// The price given to the builder, or `None` if a market order was priced from the book.
+    pub requested_price: Option<Decimal>,
+    /// The price the amounts were computed at, rounded onto the market's tick size.
+    pub price: Decimal,
+    /// The number of shares bought or sold, as signed.
+    pub shares: Shares,
+    /// The USDC paid or received, as signed.
+    pub usdc: Usdc,
+    /// The maker amount, in base units.
+    pub maker_amount: U256,
+    /// The taker amount, in base units.
+    pub taker_amount: U256,
+}
+
+impl Normalized {
+    fn new(order: SignableOrder, requested_price: Option<Decimal>, price: Decimal) -> Result<Self> {
+        let amounts = Amounts::of(&order.order);
+        let side = Side::try_from(order.order.side)?;
+        let shares = amounts.shares(side)?;
+        let usdc = amounts.usdc(side)?;
+
+        Ok(Self {
+            order,
+            requested_price,
+            price,
+            shares,
+            usdc,
+            maker_amount: amounts.maker,
+            taker_amount: amounts.taker,
+        })
+    }
+
+    /// Returns whether the price was changed by rounding it onto the tick size.
+    #[must_use]
+    pub fn is_price_adjusted(&self) -> bool {
+        self.requested_price
+            .is_some_and(|requested| requested != self.price)
+    }
 }
 
 /// Mask the salt to be <= 2^53 - 1, as the backend parses as an IEEE 754.
@@ -507,27 +572,8 @@ pub(crate) fn generate_seed() -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use rust_decimal_macros::dec;
-
     use super::*;
 
-    #[test]
-    fn to_fixed_u128_should_succeed() {
-        assert_eq!(to_fixed_u128(dec!(123.456)), 123_456_000);
-        assert_eq!(to_fixed_u128(dec!(123.456789)), 123_456_789);
-        assert_eq!(to_fixed_u128(dec!(123.456789111111111)), 123_456_789);
-        assert_eq!(to_fixed_u128(dec!(3.456789111111111)), 3_456_789);
-        assert_eq!(to_fixed_u128(Decimal::ZERO), 0);
-    }
-
-    #[test]
-    #[should_panic(
-        expected = "The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided"
-    )]
-    fn to_fixed_u128_panics() {
-        to_fixed_u128(dec!(-123.456));
-    }
-
     #[test]
     fn order_salt_should_be_less_than_or_equal_to_2_to_the_53_minus_1() {
         let raw_salt = u64::MAX;
@@ -0,0 +1,112 @@
+//! A posted order bound to the client that posted it.
+//!
+//! [`Client::post_order`](crate::clob::Client::post_order) returns an [`OrderHandle`], which
+//! dereferences to the [`PostOrderResponse`] and manages the order without passing its ID back
+//! to the client by hand.
+
+use std::ops::Deref;
+#[cfg(feature = "polling")]
+use std::time::Duration;
+
+#[cfg(feature = "polling")]
+use futures::Stream;
+
+use crate::Result;
+use crate::auth::state::Authenticated;
+use crate::auth::{Kind, Normal};
+use crate::clob::Client;
+#[cfg(feature = "polling")]
+use crate::clob::fills::Fill;
+#[cfg(feature = "polling")]
+use crate::clob::types::OrderStatusType;
+use crate::clob::types::response::{CancelOrdersResponse, OpenOrderResponse, PostOrderResponse};
+
+/// The response to a posted order, together with the client that posted it.
+#[derive(Clone, Debug)]
+pub struct OrderHandle<'client, K: Kind = Normal> {
+    client: &'client Client<Authenticated<K>>,
+    response: PostOrderResponse,
+}
+
+impl<'client, K: Kind> OrderHandle<'client, K> {
+    pub(crate) const fn new(
+        client: &'client Client<Authenticated<K>>,
+        response: PostOrderResponse,
+    ) -> Self {
+        Self { client, response }
+    }
+
+    /// Returns the ID of the order.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.response.order_id
+    }
+
+    /// Returns the response the order was posted with.
+    #[must_use]
+    pub const fn response(&self) -> &PostOrderResponse {
+        &self.response
+    }
+
+    /// Releases the client and returns the response the order was posted with.
+    #[must_use]
+    pub fn into_response(self) -> PostOrderResponse {
+        self.response
+    }
+
+    /// Cancels the order, see [`Client::cancel_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn cancel(&self) -> Result<CancelOrdersResponse> {
+        self.client.cancel_order(self.id()).await
+    }
+
+    /// Fetches the current state of the order, see [`Client::order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn status(&self) -> Result<OpenOrderResponse> {
+        self.client.order(self.id()).await
+    }
+
+    #[cfg(feature = "polling")]
+    /// Waits until the order is fully matched or otherwise reaches a terminal state, see
+    /// [`Client::wait_for_order`]. Check the returned order's `status`, since a cancellation also
+    /// ends the wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Timeout`](crate::error::Timeout) error if the order is still open after
+    /// `timeout`, or the first error returned by [`Client::order`].
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub async fn wait_filled(&self, timeout: Duration) -> Result<OpenOrderResponse> {
+        self.client
+            .wait_for_order(self.id(), &[OrderStatusType::Matched], timeout)
+            .await
+    }
+
+    #[cfg(feature = "polling")]
+    /// Returns a stream of the order's [`Fill`]s, polled every `interval`, see
+    /// [`Client::stream_fills`].
+    ///
+    /// # Note
+    ///
+    /// Requires the `polling` feature to be enabled.
+    pub fn fills_stream(&self, interval: Duration) -> impl Stream<Item = Result<Fill>> + 'client {
+        self.client.stream_fills(self.id(), interval)
+    }
+}
+
+impl<K: Kind> Deref for OrderHandle<'_, K> {
+    type Target = PostOrderResponse;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
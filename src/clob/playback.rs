@@ -0,0 +1,320 @@
+//! Replay of recorded market data.
+//!
+//! [`replay`] reads the files written by a [`recorder::Writer`](crate::clob::recorder::Writer)
+//! and yields their [`Record`]s as a stream, the same item type as
+//! [`Client::record_market_data`](crate::clob::Client::record_market_data), so that code consuming
+//! live market data can be exercised deterministically offline.
+//!
+//! Records are yielded at their original pace, an accelerated pace, or as fast as they can be
+//! read, according to [`Pace`]. Files are read on Tokio's blocking thread pool, so that neither
+//! reading nor decompressing them blocks the runtime.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead as _, BufReader, Read};
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_stream::stream;
+use bon::Builder;
+use futures::Stream;
+use tokio::sync::mpsc;
+use tokio::task;
+use tokio::time::{self, Instant};
+
+use crate::Result;
+use crate::clob::recorder::Record;
+
+/// How many lines are read ahead of the record being replayed.
+const READ_AHEAD: usize = 1024;
+
+/// How fast records are replayed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pace {
+    /// Records are yielded as fast as they can be read.
+    #[default]
+    Immediate,
+    /// Records are yielded with the same delays between them as when they were recorded.
+    Original,
+    /// Records are yielded with the delays between them divided by the given factor.
+    Accelerated(NonZeroU32),
+}
+
+/// Unwraps every file read by [`replay`], e.g. with `flate2`'s `GzDecoder` for files written
+/// with a gzip [`Compression`](crate::clob::recorder::Compression).
+#[derive(Clone)]
+pub struct Decompression {
+    decoder: Arc<dyn Fn(File) -> Box<dyn Read + Send> + Send + Sync>,
+}
+
+impl Decompression {
+    /// Creates a decompression that wraps files with `decoder`.
+    pub fn new<D, R>(decoder: D) -> Self
+    where
+        D: Fn(File) -> R + Send + Sync + 'static,
+        R: Read + Send + 'static,
+    {
+        Self {
+            decoder: Arc::new(move |file| Box::new(decoder(file))),
+        }
+    }
+}
+
+impl fmt::Debug for Decompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Decompression").finish_non_exhaustive()
+    }
+}
+
+/// Configuration for [`replay`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct ReplayConfig {
+    /// The files to replay, in order. See [`recorded_files`].
+    files: Vec<PathBuf>,
+    /// How fast records are replayed. The default is [`Pace::Immediate`].
+    #[builder(default)]
+    pace: Pace,
+    /// How files are decompressed. Files are read as is by default.
+    decompression: Option<Decompression>,
+}
+
+/// Returns the files in `directory` whose name starts with `prefix`, sorted so that they replay
+/// in the order they were recorded.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn recorded_files<P: AsRef<Path>>(directory: P, prefix: &str) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(prefix))
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+/// Returns a stream of the records in the configured files.
+///
+/// A file that cannot be opened or a line that cannot be parsed is yielded as an error, and the
+/// replay continues with the next one. An error while reading a file is yielded as well, but
+/// ends the replay.
+///
+/// # Panics
+///
+/// Panics if the stream is polled outside of a Tokio runtime.
+pub fn replay(config: ReplayConfig) -> impl Stream<Item = Result<Record>> {
+    stream! {
+        // The first record's timestamp and when it was yielded
+        let mut origin = None;
+
+        for path in config.files {
+            let (sender, mut lines) = mpsc::channel(READ_AHEAD);
+            let decompression = config.decompression.clone();
+            // Reading stops once the stream, and thus the receiver, is dropped
+            drop(task::spawn_blocking(move || {
+                read_lines(&path, decompression.as_ref(), &sender);
+            }));
+
+            while let Some(line) = lines.recv().await {
+                let record = match line {
+                    Line::Read(line) if line.trim().is_empty() => continue,
+                    Line::Read(line) => match serde_json::from_str::<Record>(&line) {
+                        Ok(record) => record,
+                        Err(e) => {
+                            yield Err(e.into());
+                            continue;
+                        }
+                    },
+                    Line::OpenFailed(e) => {
+                        yield Err(e.into());
+                        continue;
+                    }
+                    // Reading again would most likely fail the same way
+                    Line::ReadFailed(e) => {
+                        yield Err(e.into());
+                        return;
+                    }
+                };
+
+                let (recorded, started) =
+                    *origin.get_or_insert((record.timestamp, Instant::now()));
+                let elapsed = (record.timestamp - recorded).to_std().unwrap_or_default();
+                let delay = match config.pace {
+                    Pace::Immediate => None,
+                    Pace::Original => Some(elapsed),
+                    Pace::Accelerated(factor) => Some(elapsed / factor.get()),
+                };
+                if let Some(delay) = delay {
+                    time::sleep_until(started + delay).await;
+                }
+
+                yield Ok(record);
+            }
+        }
+    }
+}
+
+/// What [`read_lines`] read from a file.
+enum Line {
+    Read(String),
+    OpenFailed(io::Error),
+    ReadFailed(io::Error),
+}
+
+/// Sends the lines of the file at `path` to `lines`, blocking the current thread. Stops after the
+/// first error or once `lines` is closed.
+fn read_lines(path: &Path, decompression: Option<&Decompression>, lines: &mpsc::Sender<Line>) {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            _ = lines.blocking_send(Line::OpenFailed(e));
+            return;
+        }
+    };
+    let reader: Box<dyn Read + Send> = match decompression {
+        Some(decompression) => (decompression.decoder)(file),
+        None => Box::new(file),
+    };
+
+    for line in BufReader::new(reader).lines() {
+        let (line, failed) = match line {
+            Ok(line) => (Line::Read(line), false),
+            Err(e) => (Line::ReadFailed(e), true),
+        };
+        if lines.blocking_send(line).is_err() || failed {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::Duration;
+
+    use chrono::{DateTime, TimeDelta};
+    use futures::StreamExt as _;
+
+    use super::*;
+    use crate::clob::recorder::{Data, OutputConfig, Writer};
+    use crate::clob::types::TickSize;
+    use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+    use crate::types::{B256, Decimal, TokenId, U256};
+
+    fn record(seconds: i64, data: Data) -> Record {
+        Record {
+            timestamp: DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+                + TimeDelta::seconds(seconds),
+            token_id: TokenId::from(U256::from(1)),
+            data,
+        }
+    }
+
+    fn write(name: &str, records: &[Record]) -> PathBuf {
+        let directory = env::temp_dir().join(format!("playback-{name}-{}", std::process::id()));
+        _ = fs::remove_dir_all(&directory);
+
+        let mut writer = Writer::new(OutputConfig::builder().directory(&directory).build());
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        drop(writer);
+
+        directory
+    }
+
+    #[tokio::test]
+    async fn replay_should_yield_recorded_data() {
+        let book = OrderBookSummaryResponse::builder()
+            .market(B256::ZERO)
+            .asset_id(TokenId::from(U256::from(1)))
+            .timestamp(DateTime::from_timestamp_millis(1_700_000_000_123).unwrap())
+            .bids(vec![
+                OrderSummary::builder()
+                    .price(Decimal::new(45, 2))
+                    .size(Decimal::TEN)
+                    .build(),
+            ])
+            .min_order_size(Decimal::ONE)
+            .neg_risk(false)
+            .tick_size(TickSize::Hundredth)
+            .build();
+        let records = [
+            record(0, Data::Book { book }),
+            record(
+                0,
+                Data::Midpoint {
+                    price: Decimal::new(5, 1),
+                },
+            ),
+        ];
+        let directory = write("roundtrip", &records);
+
+        let config = ReplayConfig::builder()
+            .files(recorded_files(&directory, "market-data").unwrap())
+            .build();
+        let replayed: Vec<_> = replay(config).map(|record| record.unwrap()).collect().await;
+
+        assert_eq!(replayed, records);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn replay_should_accelerate_pace() {
+        let price = Decimal::new(5, 1);
+        let directory = write(
+            "pace",
+            &[
+                record(0, Data::Midpoint { price }),
+                record(2, Data::Midpoint { price }),
+            ],
+        );
+
+        let config = ReplayConfig::builder()
+            .files(recorded_files(&directory, "market-data").unwrap())
+            .pace(Pace::Accelerated(NonZeroU32::new(100).unwrap()))
+            .build();
+        let started = Instant::now();
+        let replayed = replay(config).count().await;
+
+        assert_eq!(replayed, 2);
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        assert!(started.elapsed() < Duration::from_secs(2));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn replay_should_end_after_read_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("corrupt"))
+            }
+        }
+
+        let price = Decimal::new(5, 1);
+        let directory = write("failing", &[record(0, Data::Midpoint { price })]);
+
+        let config = ReplayConfig::builder()
+            .files(recorded_files(&directory, "market-data").unwrap())
+            .decompression(Decompression::new(|_| Failing))
+            .build();
+        let replayed: Vec<_> = replay(config).collect().await;
+
+        assert_eq!(replayed.len(), 1);
+        replayed[0].as_ref().unwrap_err();
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
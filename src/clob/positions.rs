@@ -0,0 +1,642 @@
+//! Positions maintained locally from fills.
+//!
+//! The Data API reports positions only once the trades behind them have been indexed, which can
+//! lag behind the CLOB by several seconds. A [`PositionTracker`] starts from a snapshot of
+//! [`data::Client::positions`](crate::data::Client::positions) and applies this account's own
+//! fills as they are reported by [`Client::stream_trades`](crate::clob::Client::stream_trades)
+//! or the WebSocket user channel, so that the size, average price and exposure of every token
+//! are up to date as soon as a trade is matched.
+//!
+//! Positions are valued at their average cost: buys move the average price, while sells only
+//! reduce the size and realize the difference to the average price as profit or loss. Every
+//! trade is applied at most once, however often it is reported, and a trade that is later
+//! reported as failed is rolled back. Trades matched before the snapshot was taken are ignored,
+//! since the snapshot is assumed to include them.
+//!
+//! # Events
+//!
+//! The markets of a negative risk event are mutually exclusive: exactly one of them resolves to
+//! `Yes`. Adding up the cost of every token overstates the risk of positions across such an event,
+//! e.g. `No` shares in two of its markets cannot both lose. [`PositionTracker::event_exposure`]
+//! instead returns the most that can be lost on the positions of an event, by paying them out for
+//! every outcome that could win and taking the worst. Since the tracker does not know every
+//! market of an event, it always considers that a market without positions may win.
+//!
+//! Positions are only combined if their market, event, outcome index and whether they are
+//! negative risk are known. The snapshot provides these, while tokens first seen in fills must be
+//! registered with [`PositionTracker::register_token`]. Other positions count in full.
+//!
+//! # Risk limits
+//!
+//! A tracker with a risk [`Engine`] attached, via [`PositionTracker::with_risk`] or
+//! [`Client::position_tracker`](crate::clob::Client::position_tracker) when one is configured,
+//! pushes every position it changes into the engine, so that its position limits always see the
+//! latest fills. The profit or loss realized by sells, and reversed by rolled back trades, is
+//! recorded in the engine as well, which drives its daily loss limit and kill switch.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::StreamExt as _;
+//! use polymarket_client_sdk::clob::types::request::TradesRequest;
+//! # use polymarket_client_sdk::auth::{Normal, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # use polymarket_client_sdk::types::TokenId;
+//!
+//! # async fn example(client: Client<Authenticated<Normal>>, data: polymarket_client_sdk::data::Client, token_id: TokenId) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut tracker = client.position_tracker(&data).await?;
+//!
+//! let request = TradesRequest::builder().after(tracker.as_of()).build();
+//! let mut trades = Box::pin(client.stream_trades(&request));
+//! while let Some(trade) = trades.next().await {
+//!     tracker.apply_trade(&trade?);
+//! }
+//!
+//! if let Some(position) = tracker.position(token_id) {
+//!     println!("{} shares at {}", position.size, position.avg_price);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::auth::ApiKey;
+use crate::clob::risk::Engine;
+use crate::clob::types::response::TradeResponse;
+use crate::clob::types::{Side, TradeStatusType, TraderSide};
+#[cfg(feature = "ws")]
+use crate::clob::ws::types::response::TradeMessage;
+use crate::data::types::response::Position;
+#[cfg(feature = "ws")]
+use crate::types::Timestamp;
+use crate::types::{B256, Decimal, TokenId};
+
+/// The position in a single token, see the [module docs](self).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrackedPosition {
+    /// The market condition ID, if known.
+    pub market: Option<B256>,
+    /// The ID of the event the market belongs to, if known.
+    pub event: Option<String>,
+    /// Whether the market is part of a negative risk event, whose markets are mutually exclusive.
+    pub negative_risk: bool,
+    /// The index of the outcome within its market, `0` for `Yes` and `1` for `No`, if known.
+    pub outcome_index: Option<u32>,
+    /// The number of shares held.
+    pub size: Decimal,
+    /// The average price paid per share held.
+    pub avg_price: Decimal,
+    /// The profit or loss realized by sells since the tracker was created.
+    pub realized_pnl: Decimal,
+}
+
+impl TrackedPosition {
+    /// Returns the cost of the shares held, i.e. `size * avg_price`.
+    #[must_use]
+    pub fn exposure(&self) -> Decimal {
+        self.size * self.avg_price
+    }
+
+    fn buy(&mut self, size: Decimal, price: Decimal) {
+        let total = self.size + size;
+        if !total.is_zero() {
+            self.avg_price = (self.exposure() + size * price) / total;
+        }
+        self.size = total;
+    }
+
+    fn sell(&mut self, size: Decimal, price: Decimal) {
+        self.realized_pnl += (price - self.avg_price) * size;
+        self.size -= size;
+    }
+
+    /// Rolls back a [`Self::buy`] of `size` shares at `price`.
+    fn undo_buy(&mut self, size: Decimal, price: Decimal) {
+        let total = self.size - size;
+        if !total.is_zero() {
+            self.avg_price = (self.exposure() - size * price) / total;
+        }
+        self.size = total;
+    }
+
+    /// Rolls back a [`Self::sell`] of `size` shares at `price`.
+    fn undo_sell(&mut self, size: Decimal, price: Decimal) {
+        self.size += size;
+        self.realized_pnl -= (price - self.avg_price) * size;
+    }
+}
+
+/// A fill of one of this account's orders, as applied to a position.
+#[derive(Clone, Debug)]
+struct Applied {
+    token_id: TokenId,
+    side: Side,
+    size: Decimal,
+    price: Decimal,
+}
+
+/// Positions per token, maintained from a snapshot and this account's fills, see the
+/// [module docs](self).
+#[derive(Clone, Debug)]
+pub struct PositionTracker {
+    /// The API key of this account, which identifies its orders in trades.
+    owner: ApiKey,
+    /// When the snapshot the tracker started from was taken.
+    as_of: DateTime<Utc>,
+    positions: HashMap<TokenId, TrackedPosition>,
+    /// The fills applied per trade ID, so that trades are applied once and can be rolled back.
+    applied: HashMap<String, Vec<Applied>>,
+    /// The risk engine kept up to date with the positions, if any.
+    risk: Option<Arc<Engine>>,
+}
+
+impl PositionTracker {
+    /// Creates a tracker without positions for the account whose API key is `owner`, applying
+    /// trades matched from `as_of` on.
+    #[must_use]
+    pub fn new(owner: ApiKey, as_of: DateTime<Utc>) -> Self {
+        Self {
+            owner,
+            as_of,
+            positions: HashMap::new(),
+            applied: HashMap::new(),
+            risk: None,
+        }
+    }
+
+    /// Attaches `risk`, replacing its positions with those of this tracker and pushing every
+    /// position changed from then on into it, see the [module docs](self#risk-limits).
+    #[must_use]
+    pub fn with_risk(mut self, risk: Arc<Engine>) -> Self {
+        risk.sync_positions(&self);
+        self.risk = Some(risk);
+        self
+    }
+
+    /// Pushes the position in `token_id` into the attached risk engine, if any.
+    fn push(&self, token_id: TokenId) {
+        if let (Some(risk), Some(position)) = (&self.risk, self.positions.get(&token_id)) {
+            if let Some(market) = position.market {
+                risk.register_token(token_id, market, position.event.clone());
+            }
+            risk.set_position(token_id, position.size, position.exposure());
+        }
+    }
+
+    /// Records profit or loss `realized` by a fill, or by rolling one back, in the attached risk
+    /// engine, if any.
+    fn push_pnl(&self, realized: Decimal) {
+        if let Some(risk) = &self.risk
+            && !realized.is_zero()
+        {
+            risk.record_pnl(realized);
+        }
+    }
+
+    /// Creates a tracker starting from `positions`, as returned by the Data API at `as_of`.
+    #[must_use]
+    pub fn from_positions(owner: ApiKey, as_of: DateTime<Utc>, positions: &[Position]) -> Self {
+        let mut tracker = Self::new(owner, as_of);
+        for position in positions {
+            tracker.positions.insert(
+                position.asset,
+                TrackedPosition {
+                    market: Some(position.condition_id),
+                    event: position.event_id.clone(),
+                    negative_risk: position.negative_risk,
+                    outcome_index: u32::try_from(position.outcome_index).ok(),
+                    size: position.size,
+                    avg_price: position.avg_price,
+                    realized_pnl: Decimal::ZERO,
+                },
+            );
+        }
+
+        tracker
+    }
+
+    /// Returns when the snapshot the tracker started from was taken. Trades matched before then
+    /// are ignored.
+    #[must_use]
+    pub const fn as_of(&self) -> DateTime<Utc> {
+        self.as_of
+    }
+
+    /// Maps `token_id` to the `market` (condition ID), outcome and `event` it belongs to, so that
+    /// its position can be combined with the others of a negative risk event, see
+    /// [`Self::event_exposure`].
+    pub fn register_token(
+        &mut self,
+        token_id: TokenId,
+        market: B256,
+        outcome_index: u32,
+        event: Option<String>,
+        negative_risk: bool,
+    ) {
+        let position = self.positions.entry(token_id).or_default();
+        position.market = Some(market);
+        position.outcome_index = Some(outcome_index);
+        position.event = event;
+        position.negative_risk = negative_risk;
+        self.push(token_id);
+    }
+
+    /// Returns the position in `token_id`, if any shares of it were ever held or it was
+    /// registered.
+    #[must_use]
+    pub fn position(&self, token_id: TokenId) -> Option<&TrackedPosition> {
+        self.positions.get(&token_id)
+    }
+
+    /// Returns every position, including closed ones.
+    pub fn positions(&self) -> impl Iterator<Item = (TokenId, &TrackedPosition)> {
+        self.positions
+            .iter()
+            .map(|(token_id, position)| (*token_id, position))
+    }
+
+    /// Returns the total cost of the shares held across every position.
+    #[must_use]
+    pub fn exposure(&self) -> Decimal {
+        self.positions.values().map(TrackedPosition::exposure).sum()
+    }
+
+    /// Returns the most that can be lost on the positions in `event`, combining the mutually
+    /// exclusive markets of a negative risk event, see the [module docs](self#events).
+    #[must_use]
+    pub fn event_exposure(&self, event: &str) -> Decimal {
+        worst_case_loss(
+            self.positions
+                .values()
+                .filter(|position| position.event.as_deref() == Some(event)),
+        )
+    }
+
+    /// Returns the [exposure](Self::event_exposure) of every event with positions.
+    #[must_use]
+    pub fn event_exposures(&self) -> BTreeMap<String, Decimal> {
+        let mut events: BTreeMap<&str, Vec<&TrackedPosition>> = BTreeMap::new();
+        for position in self.positions.values() {
+            if let Some(event) = &position.event {
+                events.entry(event).or_default().push(position);
+            }
+        }
+
+        events
+            .into_iter()
+            .map(|(event, positions)| (event.to_owned(), worst_case_loss(positions)))
+            .collect()
+    }
+
+    /// Returns the total exposure like [`Self::exposure`], but with the positions of every event
+    /// combined, see [`Self::event_exposure`].
+    #[must_use]
+    pub fn net_exposure(&self) -> Decimal {
+        let unlisted: Decimal = self
+            .positions
+            .values()
+            .filter(|position| position.event.is_none())
+            .map(TrackedPosition::exposure)
+            .sum();
+
+        unlisted + self.event_exposures().values().sum::<Decimal>()
+    }
+
+    /// Applies this account's fills in `trade`, as returned by
+    /// [`Client::trades`](crate::clob::Client::trades). Returns whether any position changed.
+    pub fn apply_trade(&mut self, trade: &TradeResponse) -> bool {
+        let mut fills = Vec::new();
+        if trade.owner == self.owner && trade.trader_side == TraderSide::Taker {
+            fills.push(Applied {
+                token_id: trade.asset_id,
+                side: trade.side,
+                size: trade.size,
+                price: trade.price,
+            });
+        }
+        fills.extend(
+            trade
+                .maker_orders
+                .iter()
+                .filter(|order| order.owner == self.owner)
+                .map(|order| Applied {
+                    token_id: order.asset_id,
+                    side: order.side,
+                    size: order.matched_amount,
+                    price: order.price,
+                }),
+        );
+
+        self.apply(
+            &trade.id,
+            &trade.status,
+            trade.match_time,
+            Some(trade.market),
+            fills,
+        )
+    }
+
+    /// Applies this account's fills in `trade`, as received from the WebSocket user channel.
+    /// Returns whether any position changed.
+    ///
+    /// # Note
+    ///
+    /// Requires the `ws` feature to be enabled.
+    #[cfg(feature = "ws")]
+    pub fn apply_trade_message(&mut self, trade: &TradeMessage) -> bool {
+        let mut fills = Vec::new();
+        let is_taker = match &trade.trader_side {
+            Some(side) => {
+                *side == TraderSide::Taker && trade.owner.is_none_or(|owner| owner == self.owner)
+            }
+            None => trade.trade_owner == Some(self.owner),
+        };
+        if is_taker {
+            fills.push(Applied {
+                token_id: trade.asset_id,
+                side: trade.side,
+                size: trade.size,
+                price: trade.price,
+            });
+        }
+        fills.extend(
+            trade
+                .maker_orders
+                .iter()
+                .filter(|order| order.owner == self.owner)
+                .map(|order| Applied {
+                    token_id: order.asset_id,
+                    // A maker on the same token takes the other side, while a maker on the
+                    // complementary token was matched on the same side
+                    side: if order.asset_id == trade.asset_id {
+                        opposite(trade.side)
+                    } else {
+                        trade.side
+                    },
+                    size: order.matched_amount,
+                    price: order.price,
+                }),
+        );
+
+        let match_time = trade
+            .matchtime
+            .or(trade.timestamp)
+            .and_then(Timestamp::to_datetime)
+            .unwrap_or_else(Utc::now);
+
+        self.apply(
+            &trade.id,
+            &trade.status,
+            match_time,
+            Some(trade.market),
+            fills,
+        )
+    }
+
+    fn apply(
+        &mut self,
+        trade_id: &str,
+        status: &TradeStatusType,
+        match_time: DateTime<Utc>,
+        market: Option<B256>,
+        fills: Vec<Applied>,
+    ) -> bool {
+        if *status == TradeStatusType::Failed {
+            let Some(applied) = self.applied.remove(trade_id) else {
+                return false;
+            };
+            for fill in applied {
+                let position = self.positions.entry(fill.token_id).or_default();
+                let realized_pnl = position.realized_pnl;
+                match fill.side {
+                    Side::Buy => position.undo_buy(fill.size, fill.price),
+                    Side::Sell => position.undo_sell(fill.size, fill.price),
+                    Side::Unknown => {}
+                }
+                let realized = position.realized_pnl - realized_pnl;
+                self.push(fill.token_id);
+                self.push_pnl(realized);
+            }
+            return true;
+        }
+
+        if match_time < self.as_of || fills.is_empty() || self.applied.contains_key(trade_id) {
+            return false;
+        }
+
+        for fill in &fills {
+            let position = self.positions.entry(fill.token_id).or_default();
+            position.market = position.market.or(market);
+            let realized_pnl = position.realized_pnl;
+            match fill.side {
+                Side::Buy => position.buy(fill.size, fill.price),
+                Side::Sell => position.sell(fill.size, fill.price),
+                Side::Unknown => {}
+            }
+            let realized = position.realized_pnl - realized_pnl;
+            self.push(fill.token_id);
+            self.push_pnl(realized);
+        }
+        self.applied.insert(trade_id.to_owned(), fills);
+
+        true
+    }
+}
+
+/// Returns the most that can be lost on `positions` of a single event, given that exactly one of
+/// its negative risk markets resolves to `Yes`.
+fn worst_case_loss<'tracker>(
+    positions: impl IntoIterator<Item = &'tracker TrackedPosition>,
+) -> Decimal {
+    let mut cost = Decimal::ZERO;
+    // The `Yes` and `No` shares held in every negative risk market
+    let mut markets: HashMap<B256, (Decimal, Decimal)> = HashMap::new();
+    for position in positions {
+        cost += position.exposure();
+        match (
+            position.negative_risk,
+            position.market,
+            position.outcome_index,
+        ) {
+            (true, Some(market), Some(0)) => markets.entry(market).or_default().0 += position.size,
+            (true, Some(market), Some(1)) => markets.entry(market).or_default().1 += position.size,
+            // Positions that cannot be combined pay out nothing in the worst case
+            _ => {}
+        }
+    }
+
+    // If a market without positions wins, every `No` share pays out. If one with positions
+    // wins, its `Yes` shares pay out instead of its `No` shares.
+    let no: Decimal = markets.values().map(|(_, no)| *no).sum();
+    let payout = markets
+        .values()
+        .map(|(market_yes, market_no)| no - market_no + market_yes)
+        .fold(no, Decimal::min);
+
+    (cost - payout).max(Decimal::ZERO)
+}
+
+#[cfg(feature = "ws")]
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Buy => Side::Sell,
+        Side::Sell => Side::Buy,
+        Side::Unknown => Side::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ws")]
+    use alloy::primitives::U256;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn tracked_position_should_use_average_cost() {
+        let mut position = TrackedPosition::default();
+        position.buy(dec!(10), dec!(0.4));
+        position.buy(dec!(30), dec!(0.6));
+        assert_eq!(position.size, dec!(40));
+        assert_eq!(position.avg_price, dec!(0.55));
+
+        position.sell(dec!(20), dec!(0.65));
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.avg_price, dec!(0.55));
+        assert_eq!(position.realized_pnl, dec!(2));
+        assert_eq!(position.exposure(), dec!(11));
+
+        position.undo_sell(dec!(20), dec!(0.65));
+        position.undo_buy(dec!(30), dec!(0.6));
+        assert_eq!(position.size, dec!(10));
+        assert_eq!(position.avg_price, dec!(0.4));
+        assert_eq!(position.realized_pnl, Decimal::ZERO);
+    }
+
+    #[test]
+    fn event_exposure_should_combine_negative_risk_markets() {
+        let as_of = DateTime::from_timestamp(1_700_000_000, 0).expect("valid time");
+        let mut tracker = PositionTracker::new(ApiKey::nil(), as_of);
+        let token = |id: u8| TokenId::new(alloy::primitives::U256::from(id));
+        let hold = |tracker: &mut PositionTracker,
+                    id: u8,
+                    market: u8,
+                    outcome_index,
+                    event: &str,
+                    negative_risk,
+                    price| {
+            tracker.register_token(
+                token(id),
+                B256::with_last_byte(market),
+                outcome_index,
+                Some(event.to_owned()),
+                negative_risk,
+            );
+            tracker
+                .positions
+                .get_mut(&token(id))
+                .expect("registered")
+                .buy(dec!(10), price);
+        };
+
+        // `No` in two markets of the election: at most one of them can lose
+        hold(&mut tracker, 1, 1, 1, "election", true, dec!(0.7));
+        hold(&mut tracker, 2, 2, 1, "election", true, dec!(0.7));
+        // A separate market of the same event is not combined
+        hold(&mut tracker, 3, 3, 0, "election", false, dec!(0.5));
+        // `Yes` in a third market of another event, which pays out if any other market wins
+        hold(&mut tracker, 4, 4, 0, "weather", true, dec!(0.2));
+
+        assert_eq!(tracker.exposure(), dec!(21));
+        // 14 in `No` shares of which 10 pay out if either market wins, plus 5 in full
+        assert_eq!(tracker.event_exposure("election"), dec!(9));
+        assert_eq!(tracker.event_exposure("weather"), dec!(2));
+        assert_eq!(tracker.event_exposure("unknown"), Decimal::ZERO);
+
+        // `Yes` in a third market of the election is lost in the worst case, when another wins
+        hold(&mut tracker, 5, 5, 0, "election", true, dec!(0.2));
+        assert_eq!(tracker.event_exposure("election"), dec!(11));
+        // Adding `Yes` in both markets held `No` in pays out 20 whichever market wins
+        hold(&mut tracker, 6, 1, 0, "election", true, dec!(0.3));
+        hold(&mut tracker, 7, 2, 0, "election", true, dec!(0.3));
+        assert_eq!(tracker.event_exposure("election"), dec!(7));
+
+        let exposures = tracker.event_exposures();
+        assert_eq!(exposures.len(), 2);
+        assert_eq!(exposures["weather"], dec!(2));
+        assert_eq!(tracker.net_exposure(), dec!(9));
+    }
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn apply_trade_message_should_infer_maker_sides() -> crate::Result<()> {
+        let owner = ApiKey::nil();
+        let yes = TokenId::new(U256::from(1));
+        let no = TokenId::new(U256::from(2));
+        let as_of = DateTime::from_timestamp(1_700_000_000, 0).expect("valid time");
+        let mut tracker = PositionTracker::new(owner, as_of);
+        tracker.positions.insert(
+            yes,
+            TrackedPosition {
+                size: dec!(10),
+                avg_price: dec!(0.3),
+                ..TrackedPosition::default()
+            },
+        );
+
+        let message = |id: &str, matchtime: i64| {
+            serde_json::from_value::<TradeMessage>(serde_json::json!({
+                "id": id,
+                "market": B256::ZERO,
+                "asset_id": yes,
+                "side": "BUY",
+                "size": "10",
+                "price": "0.4",
+                "status": "MATCHED",
+                "matchtime": matchtime.to_string(),
+                "trader_side": "MAKER",
+                "maker_orders": [
+                    {
+                        "asset_id": yes,
+                        "matched_amount": "4",
+                        "order_id": "1",
+                        "outcome": "Yes",
+                        "owner": owner,
+                        "price": "0.4"
+                    },
+                    {
+                        "asset_id": no,
+                        "matched_amount": "6",
+                        "order_id": "2",
+                        "outcome": "No",
+                        "owner": owner,
+                        "price": "0.6"
+                    }
+                ]
+            }))
+        };
+
+        // Matched before the snapshot was taken
+        assert!(!tracker.apply_trade_message(&message("1", 1_600_000_000)?));
+        assert_eq!(tracker.positions().count(), 1);
+
+        // Our order on the same token sold into the taker's buy, while our order on the
+        // complementary token bought alongside it
+        assert!(tracker.apply_trade_message(&message("2", 1_800_000_000)?));
+        let sold = tracker.position(yes).expect("position");
+        assert_eq!(sold.size, dec!(6));
+        assert_eq!(sold.realized_pnl, dec!(0.4));
+        let bought = tracker.position(no).expect("position");
+        assert_eq!(bought.size, dec!(6));
+        assert_eq!(bought.avg_price, dec!(0.6));
+        assert_eq!(bought.market, Some(B256::ZERO));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,512 @@
+//! Market data recording.
+//!
+//! [`Client::record_market_data`](crate::clob::Client::record_market_data) polls the order books,
+//! midpoints and last trade prices of selected tokens and appends each observation as a
+//! timestamped [`Record`] to newline-delimited JSON files through a [`Writer`], building a
+//! historical dataset that can later be replayed with [`playback`](crate::clob::playback).
+//! Files are written on Tokio's blocking thread pool, so slow disks or compression do not stall
+//! the runtime.
+//!
+//! The [`Writer`] rotates files by size or age and accepts any [`Serialize`] value, so it can
+//! also record messages received over the WebSocket API. Files are written uncompressed unless a
+//! [`Compression`] is configured, which wraps every file in an encoder such as `flate2`'s
+//! `GzEncoder`.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufWriter, IntoInnerError, Write as _};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_stream::stream;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use tokio::task;
+use tokio::time;
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob::Client;
+use crate::clob::types::Side;
+use crate::clob::types::request::{
+    LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest,
+};
+use crate::clob::types::response::OrderBookSummaryResponse;
+use crate::error::{Error, Kind};
+use crate::types::{Decimal, TokenId};
+
+/// Wraps every file opened by a [`Writer`] in an encoder.
+#[derive(Clone)]
+pub struct Compression {
+    extension: String,
+    encoder: Arc<dyn Fn(File) -> Box<dyn std::io::Write + Send> + Send + Sync>,
+}
+
+impl Compression {
+    /// Creates a compression that appends `extension` to file names, e.g. `"gz"`, and wraps
+    /// files with `encoder`.
+    pub fn new<S, E, W>(extension: S, encoder: E) -> Self
+    where
+        S: Into<String>,
+        E: Fn(File) -> W + Send + Sync + 'static,
+        W: std::io::Write + Send + 'static,
+    {
+        Self {
+            extension: extension.into(),
+            encoder: Arc::new(move |file| Box::new(encoder(file))),
+        }
+    }
+}
+
+impl fmt::Debug for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Compression")
+            .field("extension", &self.extension)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Configuration for a [`Writer`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct OutputConfig {
+    /// The directory files are written to. It is created if it does not exist.
+    #[builder(into)]
+    directory: PathBuf,
+    /// The prefix of every file name. The default is `market-data`.
+    #[builder(into, default = "market-data".to_owned())]
+    prefix: String,
+    /// The number of bytes after which a new file is started. Unset by default.
+    max_bytes: Option<u64>,
+    /// The age after which a new file is started. Unset by default.
+    max_age: Option<Duration>,
+    /// How files are compressed. Files are not compressed by default.
+    compression: Option<Compression>,
+}
+
+/// The file currently written to.
+struct Output {
+    writer: BufWriter<Box<dyn std::io::Write + Send>>,
+    opened: Instant,
+    bytes: u64,
+}
+
+/// Appends serialized values, one per line, to a series of rotating files.
+///
+/// Files are named `<prefix>-<UTC time>-<sequence>.jsonl`, followed by the compression extension
+/// if any, so that they sort in the order they were written.
+pub struct Writer {
+    config: OutputConfig,
+    output: Option<Output>,
+    sequence: u32,
+}
+
+impl fmt::Debug for Writer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Writer")
+            .field("config", &self.config)
+            .field("sequence", &self.sequence)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Writer {
+    /// Creates a writer. No file is opened until the first value is written.
+    #[must_use]
+    pub const fn new(config: OutputConfig) -> Self {
+        Self {
+            config,
+            output: None,
+            sequence: 0,
+        }
+    }
+
+    /// Serializes `value` as a single line, starting a new file first if the current one is due
+    /// for rotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be serialized or the file cannot be written.
+    pub fn write<T: Serialize>(&mut self, value: &T) -> Result<()> {
+        let mut line = serde_json::to_vec(value)?;
+        line.push(b'\n');
+
+        if self
+            .output
+            .as_ref()
+            .is_none_or(|output| self.is_due(output))
+        {
+            self.rotate()?;
+        }
+
+        if let Some(output) = &mut self.output {
+            output.writer.write_all(&line)?;
+            output.bytes += line.len() as u64;
+        }
+
+        Ok(())
+    }
+
+    /// Flushes buffered lines to the current file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn flush(&mut self) -> Result<()> {
+        match &mut self.output {
+            Some(output) => output.writer.flush().map_err(Error::from),
+            None => Ok(()),
+        }
+    }
+
+    fn is_due(&self, output: &Output) -> bool {
+        self.config
+            .max_bytes
+            .is_some_and(|max_bytes| output.bytes >= max_bytes)
+            || self
+                .config
+                .max_age
+                .is_some_and(|max_age| output.opened.elapsed() >= max_age)
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        // Flushes the encoder of the previous file, e.g. writing the gzip trailer
+        if let Some(output) = self.output.take() {
+            let mut inner = output
+                .writer
+                .into_inner()
+                .map_err(IntoInnerError::into_error)?;
+            inner.flush()?;
+        }
+
+        fs::create_dir_all(&self.config.directory)?;
+
+        let mut name = format!(
+            "{}-{}-{:04}.jsonl",
+            self.config.prefix,
+            Utc::now().format("%Y%m%dT%H%M%S%.3fZ"),
+            self.sequence
+        );
+        if let Some(compression) = &self.config.compression {
+            name = format!("{name}.{}", compression.extension);
+        }
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let file = File::create(self.config.directory.join(name))?;
+        let inner: Box<dyn std::io::Write + Send> = match &self.config.compression {
+            Some(compression) => (compression.encoder)(file),
+            None => Box::new(file),
+        };
+
+        self.output = Some(Output {
+            writer: BufWriter::new(inner),
+            opened: Instant::now(),
+            bytes: 0,
+        });
+
+        Ok(())
+    }
+}
+
+impl Drop for Writer {
+    fn drop(&mut self) {
+        if let Some(output) = self.output.take()
+            && let Ok(mut inner) = output.writer.into_inner()
+        {
+            _ = inner.flush();
+        }
+    }
+}
+
+/// A single recorded observation.
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Record {
+    /// When the observation was received.
+    pub timestamp: DateTime<Utc>,
+    /// The token observed.
+    pub token_id: TokenId,
+    /// What was observed.
+    #[serde(flatten)]
+    pub data: Data,
+}
+
+/// The content of a [`Record`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Data {
+    /// A snapshot of the order book.
+    Book {
+        /// The order book.
+        book: OrderBookSummaryResponse,
+    },
+    /// The midpoint price.
+    Midpoint {
+        /// The midpoint between the best bid and ask.
+        price: Decimal,
+    },
+    /// The last trade.
+    LastTrade {
+        /// The price of the last trade.
+        price: Decimal,
+        /// The side of the taker of the last trade.
+        side: Side,
+    },
+}
+
+/// Configuration for [`Client::record_market_data`](crate::clob::Client::record_market_data).
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct RecordConfig {
+    /// The tokens to record.
+    tokens: Vec<TokenId>,
+    /// How often the tokens are polled. The default is one (1) second.
+    #[builder(default = Duration::from_secs(1))]
+    interval: Duration,
+    /// Whether order books are recorded. The default is `true`.
+    #[builder(default = true)]
+    books: bool,
+    /// Whether midpoints are recorded. The default is `true`.
+    #[builder(default = true)]
+    midpoints: bool,
+    /// Whether last trade prices are recorded. The default is `true`.
+    #[builder(default = true)]
+    last_trades: bool,
+}
+
+/// Polls the configured data once and returns every observation.
+async fn poll<S: State>(client: &Client<S>, config: &RecordConfig) -> Result<Vec<Record>> {
+    let mut records = Vec::new();
+
+    if config.books {
+        let requests: Vec<_> = config
+            .tokens
+            .iter()
+            .map(|token_id| {
+                OrderBookSummaryRequest::builder()
+                    .token_id(*token_id)
+                    .build()
+            })
+            .collect();
+        let books = client.order_books(&requests).await?;
+        let timestamp = Utc::now();
+        records.extend(books.into_iter().map(|book| Record {
+            timestamp,
+            token_id: book.asset_id,
+            data: Data::Book { book },
+        }));
+    }
+
+    if config.midpoints {
+        let requests: Vec<_> = config
+            .tokens
+            .iter()
+            .map(|token_id| MidpointRequest::builder().token_id(*token_id).build())
+            .collect();
+        let midpoints = client.midpoints(&requests).await?;
+        let timestamp = Utc::now();
+        records.extend(config.tokens.iter().filter_map(|token_id| {
+            midpoints.midpoints.get(token_id).map(|price| Record {
+                timestamp,
+                token_id: *token_id,
+                data: Data::Midpoint { price: *price },
+            })
+        }));
+    }
+
+    if config.last_trades {
+        let requests: Vec<_> = config
+            .tokens
+            .iter()
+            .map(|token_id| LastTradePriceRequest::builder().token_id(*token_id).build())
+            .collect();
+        let trades = client.last_trades_prices(&requests).await?;
+        let timestamp = Utc::now();
+        records.extend(trades.into_iter().map(|trade| Record {
+            timestamp,
+            token_id: trade.token_id,
+            data: Data::LastTrade {
+                price: trade.price,
+                side: trade.side,
+            },
+        }));
+    }
+
+    Ok(records)
+}
+
+/// Writes `records` and flushes them, returning those written before any failure.
+fn write_records(writer: &mut Writer, records: Vec<Record>) -> (Vec<Record>, Option<Error>) {
+    let mut written = Vec::with_capacity(records.len());
+    for record in records {
+        if let Err(e) = writer.write(&record) {
+            return (written, Some(e));
+        }
+        written.push(record);
+    }
+
+    (written, writer.flush().err())
+}
+
+pub(crate) fn record<S: State>(
+    client: &Client<S>,
+    config: RecordConfig,
+    mut writer: Writer,
+) -> impl Stream<Item = Result<Record>> + '_ {
+    stream! {
+        loop {
+            match poll(client, &config).await {
+                Ok(records) => {
+                    // Files are created, written and compressed off the runtime
+                    let written = task::spawn_blocking(move || {
+                        let written = write_records(&mut writer, records);
+                        (writer, written)
+                    })
+                    .await;
+                    let (written, failure) = match written {
+                        Ok((returned, written)) => {
+                            writer = returned;
+                            written
+                        }
+                        Err(e) => {
+                            yield Err(Error::with_source(Kind::Internal, e));
+                            return;
+                        }
+                    };
+
+                    for record in written {
+                        yield Ok(record);
+                    }
+                    if let Some(e) = failure {
+                        yield Err(e);
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::io::Read as _;
+
+    use super::*;
+    use crate::types::U256;
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("recorder-{name}-{}", std::process::id()));
+        _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn files(directory: &PathBuf) -> Vec<PathBuf> {
+        let mut files: Vec<_> = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn writer_should_rotate_by_size() {
+        let directory = directory("size");
+        let mut writer = Writer::new(
+            OutputConfig::builder()
+                .directory(&directory)
+                .prefix("test")
+                .max_bytes(16)
+                .build(),
+        );
+
+        // The first file exceeds its limit after two lines
+        for value in ["first", "second", "third"] {
+            writer.write(&value).unwrap();
+        }
+        drop(writer);
+
+        let files = files(&directory);
+        assert_eq!(files.len(), 2);
+        let mut contents = String::new();
+        File::open(&files[1])
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "\"third\"\n");
+        assert!(
+            files[0]
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("test-") && name.ends_with("-0000.jsonl"))
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn writer_should_apply_compression() {
+        let directory = directory("compression");
+        let compression = Compression::new("upper", UpperCase);
+        let mut writer = Writer::new(
+            OutputConfig::builder()
+                .directory(&directory)
+                .compression(compression)
+                .build(),
+        );
+
+        writer.write(&"value").unwrap();
+        writer.flush().unwrap();
+
+        let files = files(&directory);
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0]
+                .extension()
+                .and_then(|extension| extension.to_str()),
+            Some("upper")
+        );
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), "\"VALUE\"\n");
+
+        drop(writer);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    /// An "encoder" that upper-cases everything written through it.
+    struct UpperCase(File);
+
+    impl std::io::Write for UpperCase {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.write_all(&buf.to_ascii_uppercase())?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.0.flush()
+        }
+    }
+
+    #[test]
+    fn record_should_serialize_flat() {
+        let record = Record {
+            timestamp: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            token_id: TokenId::from(U256::from(1)),
+            data: Data::Midpoint {
+                price: Decimal::new(55, 2),
+            },
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json["type"], "midpoint");
+        assert_eq!(json["price"], "0.55");
+        assert_eq!(json["timestamp"], "2023-11-14T22:13:20Z");
+    }
+}
@@ -0,0 +1,301 @@
+//! Market resolution tracking.
+//!
+//! A market moves through a small number of lifecycle states once trading stops: it is first
+//! closed, a resolution is then proposed (and possibly disputed) on the UMA oracle, and finally
+//! a winning outcome is settled. [`Status`] captures these states and [`StatusChange`] describes
+//! a transition between two of them.
+//!
+//! The CLOB [`MarketResponse`] only exposes whether a market is closed and which token won, so
+//! [`Status::from_market`] can only ever yield [`Status::Open`], [`Status::Closed`] and
+//! [`Status::Finalized`]. The intermediate UMA states are available from the Gamma API's
+//! `uma_resolution_status` field and can be layered on with [`Status::with_uma_status`].
+//!
+//! With the `polling` feature enabled, [`Client::watch_resolutions`](crate::clob::Client::watch_resolutions)
+//! repeatedly polls [`Client::market`](crate::clob::Client::market) for a set of condition IDs and
+//! yields a [`StatusChange`] whenever a market's [`Status`] changes. With the `gamma` feature, a
+//! Gamma client can be set in its [`WatchConfig`], in which case the UMA resolution status is
+//! fetched alongside every market and applied, so that proposed and disputed resolutions are
+//! yielded too.
+
+#[cfg(feature = "polling")]
+use std::collections::HashMap;
+#[cfg(feature = "polling")]
+use std::time::Duration;
+
+#[cfg(feature = "polling")]
+use async_stream::stream;
+#[cfg(feature = "polling")]
+use bon::Builder;
+#[cfg(feature = "polling")]
+use futures::Stream;
+#[cfg(feature = "polling")]
+use tokio::time::{self, Instant};
+
+#[cfg(feature = "polling")]
+use crate::Result;
+#[cfg(feature = "polling")]
+use crate::auth::state::State;
+#[cfg(feature = "polling")]
+use crate::clob::Client;
+use crate::clob::types::response::MarketResponse;
+#[cfg(all(feature = "polling", feature = "gamma"))]
+use crate::gamma::types::request::MarketsRequest;
+use crate::types::{B256, TokenId};
+
+/// The resolution state of a single market.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The market is still open for trading.
+    Open,
+    /// Trading has stopped but no resolution has been settled yet.
+    Closed,
+    /// A resolution has been proposed on the UMA oracle and is in its challenge window.
+    Proposed,
+    /// The proposed resolution has been disputed.
+    Disputed,
+    /// The market has been settled in favour of `winner`.
+    Finalized {
+        /// The token ID of the winning outcome.
+        winner: TokenId,
+    },
+}
+
+impl Status {
+    /// Derives the [`Status`] of a market from its CLOB representation.
+    #[must_use]
+    pub fn from_market(market: &MarketResponse) -> Self {
+        if !market.closed {
+            return Self::Open;
+        }
+
+        market
+            .tokens
+            .iter()
+            .find(|token| token.winner)
+            .map_or(Self::Closed, |token| Self::Finalized {
+                winner: token.token_id,
+            })
+    }
+
+    /// Refines this status with a UMA resolution status as reported by the Gamma API, e.g.
+    /// `"proposed"` or `"disputed"`.
+    ///
+    /// [`Status::Finalized`] is never refined, since a settled market cannot move back into the
+    /// oracle's challenge flow. Unrecognized values leave `self` unchanged.
+    #[must_use]
+    pub fn with_uma_status(self, uma_status: &str) -> Self {
+        match self {
+            Self::Open | Self::Closed | Self::Proposed | Self::Disputed => {
+                match uma_status.to_ascii_lowercase().as_str() {
+                    "proposed" => Self::Proposed,
+                    "disputed" => Self::Disputed,
+                    _ => self,
+                }
+            }
+            Self::Finalized { .. } => self,
+        }
+    }
+
+    /// Whether this status is terminal, i.e. no further transitions are expected.
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
+        matches!(self, Self::Finalized { .. })
+    }
+}
+
+/// A transition in the resolution [`Status`] of a market.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusChange {
+    /// The condition ID of the market that changed.
+    pub condition_id: B256,
+    /// The previously observed status, or `None` if this is the first observation.
+    pub previous: Option<Status>,
+    /// The newly observed status.
+    pub current: Status,
+}
+
+/// Configuration for [`Client::watch_resolutions`](crate::clob::Client::watch_resolutions).
+#[cfg(feature = "polling")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct WatchConfig {
+    /// The condition IDs of the markets to watch.
+    condition_ids: Vec<B256>,
+    /// How often each market is polled unless overridden in `intervals`. The default is thirty
+    /// (30) seconds.
+    #[builder(default = Duration::from_secs(30))]
+    interval: Duration,
+    /// Per-market poll intervals that take precedence over `interval`.
+    #[builder(default)]
+    intervals: HashMap<B256, Duration>,
+    /// The Gamma client the UMA resolution status of each market is fetched from on every poll,
+    /// so that [`Status::Proposed`] and [`Status::Disputed`] are observed. If unset, only the CLOB
+    /// market is polled, which never reports these states.
+    ///
+    /// # Note
+    ///
+    /// Requires the `gamma` feature to be enabled.
+    #[cfg(feature = "gamma")]
+    gamma: Option<crate::gamma::Client>,
+}
+
+#[cfg(feature = "polling")]
+impl WatchConfig {
+    fn interval_for(&self, condition_id: &B256) -> Duration {
+        self.intervals
+            .get(condition_id)
+            .copied()
+            .unwrap_or(self.interval)
+    }
+}
+
+#[cfg(feature = "polling")]
+pub(crate) fn watch<S: State>(
+    client: &Client<S>,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<StatusChange>> + '_ {
+    stream! {
+        let now = Instant::now();
+        let mut schedule: HashMap<B256, Instant> =
+            config.condition_ids.iter().map(|id| (*id, now)).collect();
+        let mut statuses: HashMap<B256, Status> = HashMap::new();
+
+        while let Some((condition_id, due)) = schedule
+            .iter()
+            .min_by_key(|(_, due)| **due)
+            .map(|(id, due)| (*id, *due))
+        {
+            time::sleep_until(due).await;
+
+            let next = Instant::now() + config.interval_for(&condition_id);
+            let market = match client.market(&condition_id.to_string()).await {
+                Ok(market) => market,
+                Err(e) => {
+                    schedule.insert(condition_id, next);
+                    yield Err(e);
+                    continue;
+                }
+            };
+
+            #[cfg_attr(not(feature = "gamma"), expect(unused_mut, reason = "Only refined with Gamma"))]
+            let mut current = Status::from_market(&market);
+            #[cfg(feature = "gamma")]
+            if let Some(gamma) = &config.gamma {
+                match uma_status(gamma, condition_id).await {
+                    Ok(Some(uma_status)) => current = current.with_uma_status(&uma_status),
+                    Ok(None) => {}
+                    Err(e) => {
+                        schedule.insert(condition_id, next);
+                        yield Err(e);
+                        continue;
+                    }
+                }
+            }
+
+            if current.is_final() {
+                schedule.remove(&condition_id);
+            } else {
+                schedule.insert(condition_id, next);
+            }
+
+            let previous = statuses.insert(condition_id, current.clone());
+            if previous.as_ref() != Some(&current) {
+                yield Ok(StatusChange {
+                    condition_id,
+                    previous,
+                    current,
+                });
+            }
+        }
+    }
+}
+
+/// Fetches the UMA resolution status of the market with `condition_id` from Gamma, if it knows the
+/// market.
+#[cfg(all(feature = "polling", feature = "gamma"))]
+async fn uma_status(gamma: &crate::gamma::Client, condition_id: B256) -> Result<Option<String>> {
+    let request = MarketsRequest::builder()
+        .condition_ids(vec![condition_id])
+        .build();
+    let markets = gamma.markets(&request).await?;
+
+    Ok(markets
+        .into_iter()
+        .next()
+        .and_then(|market| market.uma_resolution_status))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::U256;
+
+    fn market(closed: bool, winner: bool) -> MarketResponse {
+        serde_json::from_value(json!({
+            "enable_order_book": true,
+            "active": !closed,
+            "closed": closed,
+            "archived": false,
+            "accepting_orders": !closed,
+            "accepting_order_timestamp": null,
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "question_id": "",
+            "question": "",
+            "description": "",
+            "market_slug": "",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": false,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false,
+            "tokens": [
+                { "token_id": "1", "outcome": "Yes", "price": "1", "winner": winner },
+                { "token_id": "2", "outcome": "No", "price": "0", "winner": false }
+            ],
+            "tags": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn from_market_should_succeed() {
+        assert_eq!(Status::from_market(&market(false, false)), Status::Open);
+        assert_eq!(Status::from_market(&market(true, false)), Status::Closed);
+        assert_eq!(
+            Status::from_market(&market(true, true)),
+            Status::Finalized {
+                winner: TokenId::new(U256::from(1))
+            }
+        );
+    }
+
+    #[test]
+    fn with_uma_status_should_succeed() {
+        assert_eq!(Status::Closed.with_uma_status("proposed"), Status::Proposed);
+        assert_eq!(
+            Status::Proposed.with_uma_status("Disputed"),
+            Status::Disputed
+        );
+        assert_eq!(Status::Closed.with_uma_status("unknown"), Status::Closed);
+
+        let finalized = Status::Finalized {
+            winner: TokenId::new(U256::from(1)),
+        };
+        assert_eq!(finalized.clone().with_uma_status("disputed"), finalized);
+    }
+}
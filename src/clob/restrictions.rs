@@ -0,0 +1,96 @@
+//! Closed-only mode monitoring.
+//!
+//! An account in closed-only mode can cancel orders and close existing positions, but cannot open
+//! new ones. [`Client::monitor_closed_only`](crate::clob::Client::monitor_closed_only) spawns a
+//! background task that periodically calls
+//! [`Client::closed_only_mode`](crate::clob::Client::closed_only_mode), which keeps
+//! [`Client::is_closed_only`](crate::clob::Client::is_closed_only) up to date. While the account
+//! is in closed-only mode, the client rejects buy orders before they are sent, so strategies
+//! switch to reduce-only behavior without further changes. The returned [`Monitor`] can also be
+//! awaited for status changes, e.g. to alert an operator.
+
+use std::time::Duration;
+
+use bon::Builder;
+use tokio::sync::watch;
+use tokio::task::{self, JoinHandle};
+use tokio::time;
+#[cfg(feature = "tracing")]
+use tracing::warn;
+
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+
+/// Configuration for [`Client::monitor_closed_only`](crate::clob::Client::monitor_closed_only).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct MonitorConfig {
+    /// How often the closed-only status is checked. The default is sixty (60) seconds.
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+}
+
+/// A handle to the background task spawned by
+/// [`Client::monitor_closed_only`](crate::clob::Client::monitor_closed_only). The task is
+/// stopped when the handle is dropped.
+#[derive(Debug)]
+pub struct Monitor {
+    status: watch::Receiver<bool>,
+    task: JoinHandle<()>,
+}
+
+impl Monitor {
+    /// Returns whether the account was in closed-only mode at the last check.
+    #[must_use]
+    pub fn is_closed_only(&self) -> bool {
+        *self.status.borrow()
+    }
+
+    /// Waits until the closed-only status changes and returns the new status, or `None` if the
+    /// monitor has stopped.
+    pub async fn changed(&mut self) -> Option<bool> {
+        self.status.changed().await.ok()?;
+        Some(*self.status.borrow_and_update())
+    }
+
+    /// Returns a receiver of the closed-only status that can be shared with other tasks.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.status.clone()
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+pub(crate) fn monitor<K: Kind>(client: Client<Authenticated<K>>, config: MonitorConfig) -> Monitor {
+    let (sender, status) = watch::channel(client.is_closed_only());
+
+    let task = task::spawn(async move {
+        loop {
+            match client.closed_only_mode().await {
+                Ok(response) => {
+                    sender.send_if_modified(|closed_only| {
+                        let changed = *closed_only != response.closed_only;
+                        *closed_only = response.closed_only;
+                        changed
+                    });
+                }
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    warn!("Unable to check closed-only mode: {e:?}");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &crate::error::Error = &e;
+                }
+            }
+
+            time::sleep(config.interval).await;
+        }
+    });
+
+    Monitor { status, task }
+}
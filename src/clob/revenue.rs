@@ -0,0 +1,213 @@
+//! Revenue and volume reports of builders.
+//!
+//! Builder partners reconcile the fees they are owed against the trades attributed to them.
+//! [`Client::builder_revenue`](crate::clob::Client::builder_revenue) fetches every page of
+//! [`builder_trades`](crate::clob::Client::builder_trades) matching a request and folds them into a
+//! [`Report`], which totals the volume and fees overall, by UTC day and by market.
+//!
+//! Failed trades earn no fee and are left out of the totals, but counted in
+//! [`Report::failed_trades`] so that the number of trades still adds up against the CLOB.
+//! A [`Report`] serializes to JSON with days and markets in ascending order, ready to be shared.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::clob::types::request::TradesRequest;
+//! # use polymarket_client_sdk::auth::{builder::Builder, state::Authenticated};
+//! # use polymarket_client_sdk::clob::Client;
+//! # use polymarket_client_sdk::types::Timestamp;
+//!
+//! # async fn example(client: Client<Authenticated<Builder>>, start: Timestamp, end: Timestamp) -> Result<(), Box<dyn std::error::Error>> {
+//! let request = TradesRequest::builder().after(start).before(end).build();
+//! let report = client.builder_revenue(&request).await?;
+//!
+//! println!("{} USDC in fees on {} USDC", report.total.fees, report.total.volume);
+//! for (day, totals) in &report.by_day {
+//!     println!("{day}: {} USDC over {} trades", totals.fees, totals.trades);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::clob::types::TradeStatusType;
+use crate::clob::types::response::BuilderTradeResponse;
+use crate::types::{B256, Decimal};
+
+/// Volume and fees of a set of builder trades.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Totals {
+    /// The number of trades.
+    pub trades: u64,
+    /// The traded size, in shares.
+    pub size: Decimal,
+    /// The traded value, in USDC.
+    pub volume: Decimal,
+    /// The fees collected by the builder, in USDC.
+    pub fees: Decimal,
+}
+
+impl Totals {
+    /// Returns the fees in basis points of the volume, or `None` if there is no volume.
+    #[must_use]
+    pub fn fee_rate_bps(&self) -> Option<Decimal> {
+        (!self.volume.is_zero()).then(|| self.fees / self.volume * Decimal::from(10_000))
+    }
+
+    fn add(&mut self, trade: &BuilderTradeResponse) {
+        self.trades += 1;
+        self.size += trade.size;
+        self.volume += trade.size_usdc;
+        self.fees += trade.fee_usdc;
+    }
+}
+
+/// Volume and fees of builder trades, overall and grouped, see the [module docs](self).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// The totals of every trade.
+    pub total: Totals,
+    /// The totals by the UTC day the trades were matched on.
+    pub by_day: BTreeMap<NaiveDate, Totals>,
+    /// The totals by market condition ID.
+    pub by_market: BTreeMap<B256, Totals>,
+    /// The number of failed trades, which are left out of every total.
+    pub failed_trades: u64,
+}
+
+impl Report {
+    /// Adds a trade to the report.
+    pub fn add(&mut self, trade: &BuilderTradeResponse) {
+        if trade.status == TradeStatusType::Failed {
+            self.failed_trades += 1;
+            return;
+        }
+
+        self.total.add(trade);
+        self.by_day
+            .entry(trade.match_time.date_naive())
+            .or_default()
+            .add(trade);
+        self.by_market.entry(trade.market).or_default().add(trade);
+    }
+}
+
+impl<'trade> FromIterator<&'trade BuilderTradeResponse> for Report {
+    fn from_iter<I: IntoIterator<Item = &'trade BuilderTradeResponse>>(iter: I) -> Self {
+        let mut report = Self::default();
+        for trade in iter {
+            report.add(trade);
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::types::Side;
+    use crate::types::{Address, b256};
+
+    fn trade(
+        market: B256,
+        match_time: &str,
+        size_usdc: Decimal,
+        fee_usdc: Decimal,
+        status: TradeStatusType,
+    ) -> BuilderTradeResponse {
+        BuilderTradeResponse::builder()
+            .id("1")
+            .trade_type("limit")
+            .taker_order_hash(B256::ZERO)
+            .builder(Address::ZERO)
+            .market(market)
+            .asset_id(crate::types::TokenId::default())
+            .side(Side::Buy)
+            .size(size_usdc * dec!(2))
+            .size_usdc(size_usdc)
+            .price(dec!(0.5))
+            .status(status)
+            .outcome("YES")
+            .outcome_index(0)
+            .owner(uuid::Uuid::nil())
+            .maker(Address::ZERO)
+            .transaction_hash(B256::ZERO)
+            .match_time(match_time.parse().expect("valid time"))
+            .bucket_index(0)
+            .fee(fee_usdc)
+            .fee_usdc(fee_usdc)
+            .build()
+    }
+
+    #[test]
+    fn report_should_group_by_day_and_market() -> anyhow::Result<()> {
+        let first = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let second = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+        let trades = [
+            trade(
+                first,
+                "2025-01-01T10:00:00Z",
+                dec!(100),
+                dec!(1),
+                TradeStatusType::Confirmed,
+            ),
+            trade(
+                second,
+                "2025-01-01T23:59:59Z",
+                dec!(50),
+                dec!(0.25),
+                TradeStatusType::Matched,
+            ),
+            trade(
+                first,
+                "2025-01-02T00:00:00Z",
+                dec!(200),
+                dec!(2),
+                TradeStatusType::Mined,
+            ),
+            trade(
+                first,
+                "2025-01-02T01:00:00Z",
+                dec!(1000),
+                dec!(10),
+                TradeStatusType::Failed,
+            ),
+        ];
+
+        let report: Report = trades.iter().collect();
+
+        assert_eq!(report.failed_trades, 1);
+        assert_eq!(report.total.trades, 3);
+        assert_eq!(report.total.size, dec!(700));
+        assert_eq!(report.total.volume, dec!(350));
+        assert_eq!(report.total.fees, dec!(3.25));
+
+        let day = |date: &str| date.parse::<NaiveDate>().expect("valid date");
+        assert_eq!(report.by_day.len(), 2);
+        assert_eq!(report.by_day[&day("2025-01-01")].volume, dec!(150));
+        assert_eq!(report.by_day[&day("2025-01-01")].fees, dec!(1.25));
+        assert_eq!(report.by_day[&day("2025-01-02")].trades, 1);
+
+        assert_eq!(report.by_market[&first].fees, dec!(3));
+        assert_eq!(report.by_market[&first].fee_rate_bps(), Some(dec!(100)));
+        assert_eq!(report.by_market[&second].fee_rate_bps(), Some(dec!(50)));
+        assert_eq!(Totals::default().fee_rate_bps(), None);
+
+        let json = serde_json::to_value(&report)?;
+        assert_eq!(json["byDay"]["2025-01-01"]["trades"], 2);
+        assert_eq!(json["failedTrades"], 1);
+
+        Ok(())
+    }
+}
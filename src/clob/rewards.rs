@@ -0,0 +1,386 @@
+//! Choosing quotes that earn liquidity rewards.
+//!
+//! Markets with a reward program (see [`Client::current_rewards`](crate::clob::Client::current_rewards))
+//! pay makers in proportion to a score of their resting orders. An order scores
+//! `((v - s) / v)^2 * size`, where `v` is the market's `rewards_max_spread` and `s` the order's
+//! distance from the midpoint, both in cents, provided the order is at least `rewards_min_size`
+//! and within `v` of the midpoint. The bid and ask scores of a maker are then combined so that
+//! one-sided quotes only earn a third of their score, and nothing at all once the midpoint is
+//! below 0.10 or above 0.90.
+//!
+//! Given the total size to quote, [`Config::allocate`] places each side at the tightest price
+//! allowed by `min_spread` and splits the size between the sides to maximize the combined score.
+//! The resulting [`Quote`]s can be applied to a limit [`OrderBuilder`].
+//!
+//! ```rust
+//! use polymarket_client_sdk::clob::rewards::Config;
+//! use polymarket_client_sdk::clob::types::{Side, TickSize};
+//! use rust_decimal_macros::dec;
+//!
+//! let allocation = Config::builder()
+//!     .midpoint(dec!(0.505))
+//!     .tick_size(TickSize::Hundredth)
+//!     .size(dec!(200))
+//!     .max_spread(dec!(3))
+//!     .min_size(dec!(50))
+//!     .min_spread(dec!(1))
+//!     .build()
+//!     .allocate()
+//!     .unwrap();
+//!
+//! let quotes: Vec<_> = allocation
+//!     .quotes
+//!     .iter()
+//!     .map(|quote| (quote.side, quote.price, quote.size))
+//!     .collect();
+//! assert_eq!(
+//!     quotes,
+//!     [(Side::Buy, dec!(0.49), dec!(100)), (Side::Sell, dec!(0.52), dec!(100))]
+//! );
+//! ```
+
+use bon::Builder;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::clob::order_builder::{LOT_SIZE_SCALE, Limit, OrderBuilder};
+use crate::clob::types::{Side, TickSize};
+use crate::error::Error;
+use crate::types::Decimal;
+
+/// The factor by which the score of a one-sided quote is divided.
+const SINGLE_SIDED_FACTOR: Decimal = Decimal::from_parts(3, 0, 0, false, 0);
+
+/// The midpoint range outside of which only two-sided quotes score.
+const SINGLE_SIDED_RANGE: (Decimal, Decimal) = (
+    Decimal::from_parts(10, 0, 0, false, 2),
+    Decimal::from_parts(90, 0, 0, false, 2),
+);
+
+/// Returns the reward score of an order of `size` at `spread` cents from the midpoint, in a
+/// market whose maximum spread is `max_spread` cents. Orders at or beyond the maximum spread do
+/// not score.
+#[must_use]
+pub fn order_score(max_spread: Decimal, spread: Decimal, size: Decimal) -> Decimal {
+    if max_spread <= Decimal::ZERO || spread >= max_spread {
+        return Decimal::ZERO;
+    }
+
+    let ratio = (max_spread - spread.max(Decimal::ZERO)) / max_spread;
+    ratio * ratio * size
+}
+
+/// Combines the scores of a maker's bids and asks, dividing one-sided quotes by three and
+/// ignoring them entirely once `midpoint` is outside of `[0.10, 0.90]`.
+#[must_use]
+pub fn combined_score(midpoint: Decimal, bid_score: Decimal, ask_score: Decimal) -> Decimal {
+    let two_sided = bid_score.min(ask_score);
+    if midpoint < SINGLE_SIDED_RANGE.0 || midpoint > SINGLE_SIDED_RANGE.1 {
+        return two_sided;
+    }
+
+    two_sided.max(bid_score.max(ask_score) / SINGLE_SIDED_FACTOR)
+}
+
+/// Configuration for [`Config::allocate`]. The reward parameters are those of the market's
+/// [`CurrentRewardResponse`](crate::clob::types::response::CurrentRewardResponse).
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// The current midpoint of the market.
+    midpoint: Decimal,
+    /// The market's tick size.
+    tick_size: TickSize,
+    /// The total size to quote across both sides.
+    size: Decimal,
+    /// The market's `rewards_max_spread`, in cents.
+    max_spread: Decimal,
+    /// The market's `rewards_min_size`, below which an order does not score. The default is
+    /// zero (0).
+    #[builder(default)]
+    min_size: Decimal,
+    /// The minimum distance of a quote from the midpoint, in cents, e.g. to stay clear of being
+    /// picked off. The default is zero (0), in which case quotes are placed on the first tick
+    /// strictly inside the midpoint.
+    #[builder(default)]
+    min_spread: Decimal,
+    /// The rewards the market pays per day, used to estimate [`Allocation::expected_reward`].
+    rate_per_day: Option<Decimal>,
+    /// An estimate of the combined score of the other makers in the market, used to estimate
+    /// [`Allocation::expected_reward`].
+    competing_score: Option<Decimal>,
+}
+
+/// A single order suggested by [`Config::allocate`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quote {
+    /// [`Side::Buy`] for the bid and [`Side::Sell`] for the ask, which requires holding the
+    /// tokens.
+    pub side: Side,
+    /// The limit price.
+    pub price: Decimal,
+    /// The size.
+    pub size: Decimal,
+    /// The reward score of the order on its own, see [`order_score`].
+    pub score: Decimal,
+}
+
+impl Quote {
+    /// Sets the side, price and size of `builder` to those of this quote. The token ID and any
+    /// other parameters are left to the caller.
+    #[must_use]
+    pub fn apply<K: Kind>(&self, builder: OrderBuilder<Limit, K>) -> OrderBuilder<Limit, K> {
+        builder.side(self.side).price(self.price).size(self.size)
+    }
+}
+
+/// The quotes suggested by [`Config::allocate`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Allocation {
+    /// The quotes to place, bid first. Empty if no allocation of the size scores.
+    pub quotes: Vec<Quote>,
+    /// The combined score of the quotes, see [`combined_score`].
+    pub score: Decimal,
+    /// The share of `rate_per_day` earned against `competing_score`, if both were given.
+    pub expected_reward: Option<Decimal>,
+}
+
+impl Config {
+    /// Returns the allocation of the size to the bid and ask that maximizes the combined score.
+    ///
+    /// Each side is quoted at the tightest tick at least `min_spread` from the midpoint. The
+    /// size is either split between both sides so that their scores are equal, or placed
+    /// entirely on one side if that scores more, which happens when one side has to be quoted
+    /// much wider than the other. Sizes are truncated to the lot size, and a side whose size
+    /// would fall below `min_size` is not quoted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the midpoint is outside of `(0, 1)`, or if the size or maximum spread
+    /// is not positive.
+    pub fn allocate(&self) -> Result<Allocation> {
+        if self.midpoint <= Decimal::ZERO || self.midpoint >= Decimal::ONE {
+            return Err(Error::validation(format!(
+                "Midpoint {} is outside of (0, 1)",
+                self.midpoint
+            )));
+        }
+        if self.size <= Decimal::ZERO || self.max_spread <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Size {} and maximum spread {} must be positive",
+                self.size, self.max_spread
+            )));
+        }
+
+        let bid = self.bid_price();
+        let ask = self.ask_price();
+        let per_share = |price: Option<Decimal>| {
+            price.map_or(Decimal::ZERO, |price| {
+                let spread = (price - self.midpoint).abs() * Decimal::ONE_HUNDRED;
+                order_score(self.max_spread, spread, Decimal::ONE)
+            })
+        };
+        let (bid_rate, ask_rate) = (per_share(bid), per_share(ask));
+
+        let size = self.size.trunc_with_scale(LOT_SIZE_SCALE);
+        let mut candidates = vec![(size, Decimal::ZERO), (Decimal::ZERO, size)];
+        if !(bid_rate + ask_rate).is_zero() {
+            let bid_size =
+                (size * ask_rate / (bid_rate + ask_rate)).trunc_with_scale(LOT_SIZE_SCALE);
+            candidates.push((bid_size, size - bid_size));
+        }
+
+        let mut best = Allocation::default();
+        for (bid_size, ask_size) in candidates {
+            let quotes: Vec<Quote> = [
+                (Side::Buy, bid, bid_size, bid_rate),
+                (Side::Sell, ask, ask_size, ask_rate),
+            ]
+            .into_iter()
+            .filter_map(|(side, price, size, rate)| {
+                let price = price?;
+                (size >= self.min_size && !size.is_zero() && !rate.is_zero()).then_some(Quote {
+                    side,
+                    price,
+                    size,
+                    score: rate * size,
+                })
+            })
+            .collect();
+
+            let score_of = |side: Side| {
+                quotes
+                    .iter()
+                    .filter(|quote| quote.side == side)
+                    .map(|quote| quote.score)
+                    .sum::<Decimal>()
+            };
+            let score = combined_score(self.midpoint, score_of(Side::Buy), score_of(Side::Sell));
+            if score > best.score {
+                best = Allocation {
+                    quotes,
+                    score,
+                    expected_reward: None,
+                };
+            }
+        }
+
+        if let (Some(rate), Some(competing)) = (self.rate_per_day, self.competing_score)
+            && !best.score.is_zero()
+        {
+            best.expected_reward = Some(rate * best.score / (best.score + competing));
+        }
+
+        Ok(best)
+    }
+
+    /// Returns the tightest bid on the tick grid that is strictly below the midpoint and at
+    /// least `min_spread` from it, if it is a valid price.
+    fn bid_price(&self) -> Option<Decimal> {
+        let tick = self.tick_size.as_decimal();
+        let mut price = ((self.midpoint - self.offset()) / tick).floor() * tick;
+        if price >= self.midpoint {
+            price -= tick;
+        }
+
+        self.valid_price(price)
+    }
+
+    /// Returns the tightest ask on the tick grid that is strictly above the midpoint and at
+    /// least `min_spread` from it, if it is a valid price.
+    fn ask_price(&self) -> Option<Decimal> {
+        let tick = self.tick_size.as_decimal();
+        let mut price = ((self.midpoint + self.offset()) / tick).ceil() * tick;
+        if price <= self.midpoint {
+            price += tick;
+        }
+
+        self.valid_price(price)
+    }
+
+    /// Returns `min_spread` as a price distance.
+    fn offset(&self) -> Decimal {
+        self.min_spread.max(Decimal::ZERO) / Decimal::ONE_HUNDRED
+    }
+
+    /// Returns `price` if it lies within `[tick size, 1 - tick size]`.
+    fn valid_price(&self, price: Decimal) -> Option<Decimal> {
+        let tick = self.tick_size.as_decimal();
+        (price >= tick && price <= Decimal::ONE - tick).then_some(price.normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn config(midpoint: Decimal, min_spread: Decimal) -> Config {
+        Config::builder()
+            .midpoint(midpoint)
+            .tick_size(TickSize::Hundredth)
+            .size(dec!(100))
+            .max_spread(dec!(3))
+            .min_spread(min_spread)
+            .build()
+    }
+
+    #[test]
+    fn order_score_should_follow_quadratic_formula() {
+        assert_eq!(order_score(dec!(3), dec!(0), dec!(100)), dec!(100));
+        assert_eq!(order_score(dec!(4), dec!(2), dec!(100)), dec!(25));
+        assert_eq!(order_score(dec!(3), dec!(3), dec!(100)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn combined_score_should_require_two_sides_near_extremes() {
+        assert_eq!(combined_score(dec!(0.5), dec!(30), dec!(0)), dec!(10));
+        assert_eq!(combined_score(dec!(0.5), dec!(30), dec!(20)), dec!(20));
+        assert_eq!(combined_score(dec!(0.95), dec!(30), dec!(0)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn allocate_should_balance_symmetric_quotes() -> anyhow::Result<()> {
+        let allocation = config(dec!(0.5), dec!(1)).allocate()?;
+
+        let [bid, ask] = allocation.quotes.as_slice() else {
+            panic!("expected two quotes, got {allocation:?}");
+        };
+        assert_eq!(
+            (bid.side, bid.price, bid.size),
+            (Side::Buy, dec!(0.49), dec!(50))
+        );
+        assert_eq!(
+            (ask.side, ask.price, ask.size),
+            (Side::Sell, dec!(0.51), dec!(50))
+        );
+        // Each side is 1 cent out of 3, scoring (2/3)^2 per share
+        assert_eq!(allocation.score.round_dp(4), dec!(22.2222));
+
+        Ok(())
+    }
+
+    #[test]
+    fn allocate_should_weight_sizes_by_spread() -> anyhow::Result<()> {
+        // The bid is 1.3 cents from the midpoint and the ask 0.7 cents, so the bid needs more
+        // size to score as much as the ask
+        let allocation = config(dec!(0.503), dec!(0.5)).allocate()?;
+
+        let [bid, ask] = allocation.quotes.as_slice() else {
+            panic!("expected two quotes, got {allocation:?}");
+        };
+        assert_eq!((bid.price, ask.price), (dec!(0.49), dec!(0.51)));
+        assert_eq!((bid.size, ask.size), (dec!(64.66), dec!(35.34)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn allocate_should_quote_one_side_when_the_other_scores_little() -> anyhow::Result<()> {
+        // The ask is 9.9 cents away, where it scores a quarter of the bid 0.1 cents away
+        let mut config = config(dec!(0.501), dec!(0));
+        config.tick_size = TickSize::Tenth;
+        config.max_spread = dec!(20);
+        let allocation = config.allocate()?;
+        let [bid] = allocation.quotes.as_slice() else {
+            panic!("expected one quote, got {allocation:?}");
+        };
+        assert_eq!(
+            (bid.side, bid.price, bid.size),
+            (Side::Buy, dec!(0.5), dec!(100))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn allocate_should_respect_min_size_and_estimate_rewards() -> anyhow::Result<()> {
+        let allocation = Config::builder()
+            .midpoint(dec!(0.5))
+            .tick_size(TickSize::Hundredth)
+            .size(dec!(100))
+            .max_spread(dec!(3))
+            .min_size(dec!(60))
+            .min_spread(dec!(1))
+            .rate_per_day(dec!(10))
+            .competing_score(dec!(1))
+            .build()
+            .allocate()?;
+
+        // Splitting would leave both sides below the minimum size
+        let [quote] = allocation.quotes.as_slice() else {
+            panic!("expected one quote, got {allocation:?}");
+        };
+        assert_eq!(quote.size, dec!(100));
+        let expected = allocation.expected_reward.expect("expected reward");
+        assert!(expected > dec!(9) && expected < dec!(10));
+
+        let allocation = config(dec!(0.95), dec!(0)).allocate()?;
+        assert_eq!(allocation.quotes.len(), 2);
+
+        Ok(())
+    }
+}
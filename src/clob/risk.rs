@@ -0,0 +1,852 @@
+//! Pre-trade risk limits.
+//!
+//! An [`Engine`] set via [`Config`](crate::clob::Config)'s `risk` checks every order passed to
+//! [`Client::post_order`](crate::clob::Client::post_order) or
+//! [`Client::post_orders`](crate::clob::Client::post_orders) against its [`Limits`] before the
+//! request is sent, failing with a [`RiskRejected`] error if any limit would be breached.
+//!
+//! The engine keeps track of the open exposure in every market and event, which is the notional
+//! of the open orders it accepted. Sells only count for the shares not covered by a held
+//! position, since selling held shares reduces rather than adds to the risk taken. Exposure is
+//! added when an order is accepted and released once the order is rejected, canceled via the
+//! client, expires (see [`Client::watch_expirations`](crate::clob::Client::watch_expirations)),
+//! is matched on submission, or as fills are observed via
+//! [`Client::stream_fills`](crate::clob::Client::stream_fills). Orders canceled or filled by other
+//! means can be released with [`Engine::release_order`] and [`Engine::record_fill`], and
+//! [`Engine::reduce_exposure`] adjusts the exposure directly. Markets and events are not part of
+//! an order, so tokens must be mapped to them with [`Engine::register_token`] when an exposure
+//! limit is configured.
+//!
+//! The daily loss limit and the kill threshold are fed with realized profit and loss through
+//! [`Engine::record_pnl`].
+//!
+//! Position limits cap the shares held and their cost per market and event, and are checked by
+//! [`Engine::check_position`] when a buy is built, taking the position already held and the buys
+//! still open into account. Positions are set with [`Engine::set_position`], or pushed by a
+//! [`PositionTracker`](crate::clob::positions::PositionTracker) the engine is attached to with
+//! [`PositionTracker::with_risk`](crate::clob::positions::PositionTracker::with_risk) as it
+//! applies fills. Sells only reduce a position, so they are never rejected.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use bon::Builder;
+use chrono::{NaiveDate, Utc};
+use dashmap::DashMap;
+
+use crate::Result;
+#[cfg(feature = "data")]
+use crate::clob::positions::PositionTracker;
+use crate::clob::types::{Side, SignedOrder};
+use crate::error::{Error, RiskLimit, RiskRejected};
+use crate::types::{B256, Decimal, Shares, TokenId, Usdc};
+
+/// The limits enforced by an [`Engine`]. Limits left unset are not enforced.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Limits {
+    /// The maximum notional of a single order, in USDC.
+    max_order_notional: Option<Decimal>,
+    /// The maximum open exposure in a single market, in USDC.
+    max_market_exposure: Option<Decimal>,
+    /// The maximum open exposure in a single event, in USDC.
+    max_event_exposure: Option<Decimal>,
+    /// The maximum number of shares held across the outcomes of a single market.
+    max_market_shares: Option<Decimal>,
+    /// The maximum number of shares held across the markets of a single event.
+    max_event_shares: Option<Decimal>,
+    /// The maximum cost of the shares held in a single market, in USDC.
+    max_market_position: Option<Decimal>,
+    /// The maximum cost of the shares held in a single event, in USDC.
+    max_event_position: Option<Decimal>,
+    /// The maximum loss realized within a UTC day, in USDC. Once reached, orders are rejected
+    /// until the next day.
+    max_daily_loss: Option<Decimal>,
+    /// The loss realized within a UTC day, in USDC, at which the kill switch trips. Once tripped,
+    /// orders are rejected until [`Engine::reset_kill_switch`] is called.
+    kill_threshold: Option<Decimal>,
+}
+
+/// The market, and optionally the event, that a token belongs to.
+#[derive(Clone, Debug)]
+struct Listing {
+    market: B256,
+    event: Option<String>,
+}
+
+/// The shares held in a token and their cost.
+#[derive(Clone, Copy, Debug, Default)]
+struct Holding {
+    shares: Decimal,
+    cost: Decimal,
+}
+
+/// The exposure held by a single order.
+#[derive(Clone, Copy, Debug)]
+struct Reservation {
+    token_id: TokenId,
+    side: Side,
+    /// The shares of the order that are still open
+    shares: Decimal,
+    /// The shares of a sell that are covered by a held position, and therefore not exposed
+    covered: Decimal,
+    /// The exposure added for the open shares
+    value: Decimal,
+}
+
+impl Reservation {
+    /// Removes `shares` from the open shares, returning the exposure they held.
+    fn fill(&mut self, shares: Decimal) -> Decimal {
+        if self.shares <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let fraction = shares.min(self.shares) / self.shares;
+        let released = self.value * fraction;
+        self.covered -= self.covered * fraction;
+        self.shares -= shares.min(self.shares);
+        self.value -= released;
+        released
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.shares += other.shares;
+        self.covered += other.covered;
+        self.value += other.value;
+    }
+}
+
+/// Open exposure per market and event.
+#[derive(Debug, Default)]
+struct Exposure {
+    markets: HashMap<B256, Decimal>,
+    events: HashMap<String, Decimal>,
+}
+
+impl Exposure {
+    fn add(&mut self, listing: &Listing, notional: Decimal) {
+        *self.markets.entry(listing.market).or_default() += notional;
+        if let Some(event) = &listing.event {
+            *self.events.entry(event.clone()).or_default() += notional;
+        }
+    }
+
+    fn reduce(&mut self, listing: &Listing, notional: Decimal) {
+        if let Some(exposure) = self.markets.get_mut(&listing.market) {
+            *exposure = (*exposure - notional).max(Decimal::ZERO);
+        }
+        if let Some(exposure) = listing
+            .event
+            .as_ref()
+            .and_then(|event| self.events.get_mut(event))
+        {
+            *exposure = (*exposure - notional).max(Decimal::ZERO);
+        }
+    }
+}
+
+/// Enforces [`Limits`] on orders before they are posted, see the [module docs](self).
+#[derive(Debug, Default)]
+pub struct Engine {
+    limits: Limits,
+    listings: DashMap<TokenId, Listing>,
+    exposure: Mutex<Exposure>,
+    /// Orders accepted by [`Self::check`] that were not posted yet, by idempotency key
+    pending: DashMap<B256, Reservation>,
+    /// Orders posted and still open, by order ID
+    open_orders: DashMap<String, Reservation>,
+    holdings: DashMap<TokenId, Holding>,
+    /// Realized profit and loss for the current UTC day
+    daily_pnl: Mutex<(NaiveDate, Decimal)>,
+    killed: AtomicBool,
+}
+
+impl Engine {
+    #[must_use]
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            ..Self::default()
+        }
+    }
+
+    /// Maps `token_id` to the `market` (condition ID) and `event` it belongs to, so that its
+    /// orders count towards their exposure.
+    pub fn register_token(&self, token_id: TokenId, market: B256, event: Option<String>) {
+        self.listings.insert(token_id, Listing { market, event });
+    }
+
+    /// Sets the position in `token_id` to `shares` that cost `cost` in USDC, so that it counts
+    /// towards the position limits.
+    pub fn set_position(&self, token_id: TokenId, shares: Decimal, cost: Decimal) {
+        if shares > Decimal::ZERO {
+            self.holdings.insert(token_id, Holding { shares, cost });
+        } else {
+            self.holdings.remove(&token_id);
+        }
+    }
+
+    /// Replaces every position with those of `tracker`, and registers the tokens whose market it
+    /// knows. Prefer attaching the engine to the tracker with
+    /// [`PositionTracker::with_risk`], which keeps it up to date.
+    ///
+    /// # Note
+    ///
+    /// Requires the `data` feature to be enabled.
+    #[cfg(feature = "data")]
+    pub fn sync_positions(&self, tracker: &PositionTracker) {
+        self.holdings.clear();
+        for (token_id, position) in tracker.positions() {
+            if let Some(market) = position.market {
+                self.register_token(token_id, market, position.event.clone());
+            }
+            self.set_position(token_id, position.size, position.exposure());
+        }
+    }
+
+    /// Returns the shares held and their cost across the tokens of `market`.
+    #[must_use]
+    pub fn market_position(&self, market: B256) -> (Decimal, Decimal) {
+        self.position_where(|listing| listing.market == market)
+    }
+
+    /// Returns the shares held and their cost across the tokens of `event`.
+    #[must_use]
+    pub fn event_position(&self, event: &str) -> (Decimal, Decimal) {
+        self.position_where(|listing| listing.event.as_deref() == Some(event))
+    }
+
+    fn position_where<F: Fn(&Listing) -> bool>(&self, filter: F) -> (Decimal, Decimal) {
+        self.holdings
+            .iter()
+            .filter(|holding| {
+                self.listings
+                    .get(holding.key())
+                    .is_some_and(|listing| filter(&listing))
+            })
+            .fold((Decimal::ZERO, Decimal::ZERO), |(shares, cost), holding| {
+                (shares + holding.shares, cost + holding.cost)
+            })
+    }
+
+    /// Checks that buying `shares` of `token_id` for `notional` USDC keeps the positions in its
+    /// market and event within the position limits, counting the open buys accepted by
+    /// [`Self::check`] as if they were filled. Sells always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RiskRejected`] error if a position limit would be breached, or a validation
+    /// error if a position limit is configured and the token is not registered.
+    pub fn check_position(
+        &self,
+        token_id: TokenId,
+        side: Side,
+        shares: Decimal,
+        notional: Decimal,
+    ) -> Result<()> {
+        let limits = &self.limits;
+        let market_limited =
+            limits.max_market_shares.is_some() || limits.max_market_position.is_some();
+        let event_limited =
+            limits.max_event_shares.is_some() || limits.max_event_position.is_some();
+        if side != Side::Buy || !(market_limited || event_limited) {
+            return Ok(());
+        }
+
+        let Some(listing) = self.listings.get(&token_id).map(|listing| listing.clone()) else {
+            return Err(Error::validation(format!(
+                "Token {token_id} is not registered with the risk engine"
+            )));
+        };
+
+        let (held_shares, held_cost) = self.committed_where(|other| other.market == listing.market);
+        check_limit(
+            RiskLimit::MarketShares,
+            limits.max_market_shares,
+            held_shares + shares,
+        )?;
+        check_limit(
+            RiskLimit::MarketPosition,
+            limits.max_market_position,
+            held_cost + notional,
+        )?;
+
+        if let Some(event) = &listing.event {
+            let (held_shares, held_cost) =
+                self.committed_where(|other| other.event.as_ref() == Some(event));
+            check_limit(
+                RiskLimit::EventShares,
+                limits.max_event_shares,
+                held_shares + shares,
+            )?;
+            check_limit(
+                RiskLimit::EventPosition,
+                limits.max_event_position,
+                held_cost + notional,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the shares and cost of the positions held and the open buys in the tokens whose
+    /// listing matches `filter`.
+    fn committed_where<F: Fn(&Listing) -> bool>(&self, filter: F) -> (Decimal, Decimal) {
+        let (held_shares, held_cost) = self.position_where(&filter);
+
+        let (open_shares, open_cost) = self
+            .pending
+            .iter()
+            .map(|entry| *entry.value())
+            .chain(self.open_orders.iter().map(|entry| *entry.value()))
+            .filter(|reservation| {
+                reservation.side == Side::Buy
+                    && self
+                        .listings
+                        .get(&reservation.token_id)
+                        .is_some_and(|listing| filter(&listing))
+            })
+            .fold(
+                (Decimal::ZERO, Decimal::ZERO),
+                |(shares, cost), reservation| {
+                    (shares + reservation.shares, cost + reservation.value)
+                },
+            );
+
+        (held_shares + open_shares, held_cost + open_cost)
+    }
+
+    /// Returns the open exposure in `market`.
+    #[must_use]
+    pub fn market_exposure(&self, market: B256) -> Decimal {
+        self.exposure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .markets
+            .get(&market)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the open exposure in `event`.
+    #[must_use]
+    pub fn event_exposure(&self, event: &str) -> Decimal {
+        self.exposure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .events
+            .get(event)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Reduces the open exposure of the market and event of `token_id` by `notional`, e.g. for an
+    /// order the engine does not know of.
+    pub fn reduce_exposure(&self, token_id: TokenId, notional: Decimal) {
+        if let Some(listing) = self.listings.get(&token_id) {
+            self.exposure
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .reduce(&listing, notional);
+        }
+    }
+
+    /// Adds `pnl` to the profit and loss realized today, where losses are negative. Trips the kill
+    /// switch if the resulting loss reaches the kill threshold.
+    pub fn record_pnl(&self, pnl: Decimal) {
+        let today = Utc::now().date_naive();
+        let mut daily_pnl = self
+            .daily_pnl
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if daily_pnl.0 != today {
+            *daily_pnl = (today, Decimal::ZERO);
+        }
+        daily_pnl.1 += pnl;
+
+        if self
+            .limits
+            .kill_threshold
+            .is_some_and(|threshold| -daily_pnl.1 >= threshold)
+        {
+            self.kill();
+        }
+    }
+
+    /// Returns the profit and loss realized today.
+    #[must_use]
+    pub fn daily_pnl(&self) -> Decimal {
+        let daily_pnl = self
+            .daily_pnl
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if daily_pnl.0 == Utc::now().date_naive() {
+            daily_pnl.1
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// Trips the kill switch, rejecting all orders until [`Self::reset_kill_switch`] is called.
+    pub fn kill(&self) {
+        self.killed.store(true, Ordering::SeqCst);
+    }
+
+    /// Resets a tripped kill switch.
+    pub fn reset_kill_switch(&self) {
+        self.killed.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns whether the kill switch is tripped.
+    #[must_use]
+    pub fn is_killed(&self) -> bool {
+        self.killed.load(Ordering::SeqCst)
+    }
+
+    /// Checks `orders` against the limits and, if all of them pass, adds them to the open
+    /// exposure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RiskRejected`] error if any limit would be breached, or a validation error if
+    /// an exposure limit is configured and an order's token is not registered.
+    pub fn check(&self, orders: &[SignedOrder]) -> Result<()> {
+        let loss = -self.daily_pnl();
+        if self.is_killed() {
+            return Err(RiskRejected {
+                limit: RiskLimit::KillSwitch,
+                threshold: self.limits.kill_threshold.unwrap_or_default(),
+                value: loss,
+            }
+            .into());
+        }
+        if let Some(threshold) = self.limits.max_daily_loss
+            && loss >= threshold
+        {
+            return Err(RiskRejected {
+                limit: RiskLimit::DailyLoss,
+                threshold,
+                value: loss,
+            }
+            .into());
+        }
+
+        let tracks_exposure =
+            self.limits.max_market_exposure.is_some() || self.limits.max_event_exposure.is_some();
+        let mut pending = Vec::with_capacity(orders.len());
+        let mut covered_sells: HashMap<TokenId, Decimal> = HashMap::new();
+        for order in orders {
+            let value = notional(order)?;
+            if let Some(threshold) = self.limits.max_order_notional
+                && value > threshold
+            {
+                return Err(RiskRejected {
+                    limit: RiskLimit::OrderNotional,
+                    threshold,
+                    value,
+                }
+                .into());
+            }
+
+            let token_id = TokenId::from(order.order.tokenId);
+            let Some(listing) = self.listings.get(&token_id).map(|listing| listing.clone()) else {
+                if tracks_exposure {
+                    return Err(Error::validation(format!(
+                        "Token {token_id} is not registered with the risk engine"
+                    )));
+                }
+                continue;
+            };
+
+            let side = Side::try_from(order.order.side)?;
+            let shares = shares(order)?;
+            let covered = if side == Side::Sell {
+                let covered_sells = covered_sells
+                    .entry(token_id)
+                    .or_insert_with(|| self.covered_sells(token_id));
+                let held = self
+                    .holdings
+                    .get(&token_id)
+                    .map_or(Decimal::ZERO, |holding| holding.shares);
+                let covered = (held - *covered_sells).clamp(Decimal::ZERO, shares);
+                *covered_sells += covered;
+                covered
+            } else {
+                Decimal::ZERO
+            };
+            let value = if shares > Decimal::ZERO {
+                value * (shares - covered) / shares
+            } else {
+                value
+            };
+
+            let reservation = Reservation {
+                token_id,
+                side,
+                shares,
+                covered,
+                value,
+            };
+            pending.push((order.idempotency_key(), listing, reservation));
+        }
+
+        let mut exposure = self.exposure.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut projected = Exposure::default();
+        for (_, listing, reservation) in &pending {
+            projected.add(listing, reservation.value);
+        }
+        for (market, added) in &projected.markets {
+            let value = exposure.markets.get(market).copied().unwrap_or_default() + added;
+            if let Some(threshold) = self.limits.max_market_exposure
+                && value > threshold
+            {
+                return Err(RiskRejected {
+                    limit: RiskLimit::MarketExposure,
+                    threshold,
+                    value,
+                }
+                .into());
+            }
+        }
+        for (event, added) in &projected.events {
+            let value = exposure.events.get(event).copied().unwrap_or_default() + added;
+            if let Some(threshold) = self.limits.max_event_exposure
+                && value > threshold
+            {
+                return Err(RiskRejected {
+                    limit: RiskLimit::EventExposure,
+                    threshold,
+                    value,
+                }
+                .into());
+            }
+        }
+
+        for (key, listing, reservation) in pending {
+            exposure.add(&listing, reservation.value);
+            self.pending
+                .entry(key)
+                .and_modify(|pending| pending.merge(reservation))
+                .or_insert(reservation);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the shares of `token_id` that accepted sells already count as covered by the held
+    /// position.
+    fn covered_sells(&self, token_id: TokenId) -> Decimal {
+        self.pending
+            .iter()
+            .map(|entry| *entry.value())
+            .chain(self.open_orders.iter().map(|entry| *entry.value()))
+            .filter(|reservation| {
+                reservation.token_id == token_id && reservation.side == Side::Sell
+            })
+            .map(|reservation| reservation.covered)
+            .sum()
+    }
+
+    /// Releases the exposure added by [`Self::check`] for `orders`, e.g. once the exchange
+    /// rejected them.
+    pub fn release<'order, I: IntoIterator<Item = &'order SignedOrder>>(&self, orders: I) {
+        for order in orders {
+            if let Some((_, reservation)) = self.pending.remove(&order.idempotency_key()) {
+                self.reduce_exposure(reservation.token_id, reservation.value);
+            }
+        }
+    }
+
+    /// Records that `order`, accepted by [`Self::check`], was posted as `order_id` and rests on
+    /// the book, so that its exposure can be released by ID.
+    pub fn open_order(&self, order_id: &str, order: &SignedOrder) {
+        if let Some((_, reservation)) = self.pending.remove(&order.idempotency_key()) {
+            self.open_orders
+                .entry(order_id.to_owned())
+                .and_modify(|open| open.merge(reservation))
+                .or_insert(reservation);
+        }
+    }
+
+    /// Releases the exposure of the open order `order_id`, e.g. once it was canceled or expired.
+    pub fn release_order(&self, order_id: &str) {
+        if let Some((_, reservation)) = self.open_orders.remove(order_id) {
+            self.reduce_exposure(reservation.token_id, reservation.value);
+        }
+    }
+
+    /// Releases the exposure of the `shares` of the open order `order_id` that were filled.
+    pub fn record_fill(&self, order_id: &str, shares: Decimal) {
+        let released = {
+            let Some(mut reservation) = self.open_orders.get_mut(order_id) else {
+                return;
+            };
+            (
+                reservation.token_id,
+                reservation.fill(shares),
+                reservation.shares,
+            )
+        };
+
+        let (token_id, value, remaining) = released;
+        if remaining <= Decimal::ZERO {
+            self.open_orders.remove(order_id);
+        }
+        self.reduce_exposure(token_id, value);
+    }
+}
+
+/// Rejects `value` if it exceeds `threshold`, when set.
+fn check_limit(limit: RiskLimit, threshold: Option<Decimal>, value: Decimal) -> Result<()> {
+    match threshold {
+        Some(threshold) if value > threshold => Err(RiskRejected {
+            limit,
+            threshold,
+            value,
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the number of shares bought or sold by `order`.
+fn shares(order: &SignedOrder) -> Result<Decimal> {
+    let amount = match Side::try_from(order.order.side)? {
+        Side::Buy => order.order.takerAmount,
+        _ => order.order.makerAmount,
+    };
+
+    Ok(Shares::from_base_units(amount)?.as_decimal())
+}
+
+/// Returns the USDC notional of `order`, which is the amount paid for a buy and received for a
+/// sell.
+fn notional(order: &SignedOrder) -> Result<Decimal> {
+    let amount = match Side::try_from(order.order.side)? {
+        Side::Buy => order.order.makerAmount,
+        _ => order.order.takerAmount,
+    };
+
+    Ok(Usdc::from_base_units(amount)?.as_decimal())
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::auth::ApiKey;
+    use crate::clob::types::{Order, OrderType};
+    use crate::types::{Signature, U256};
+
+    fn order(token_id: u64, usdc: u64) -> SignedOrder {
+        SignedOrder::builder()
+            .order(Order {
+                tokenId: U256::from(token_id),
+                makerAmount: U256::from(usdc * 1_000_000),
+                ..Order::default()
+            })
+            .signature(Signature::new(
+                U256::from(token_id),
+                U256::from(usdc),
+                false,
+            ))
+            .order_type(OrderType::GTC)
+            .owner(ApiKey::nil())
+            .build()
+    }
+
+    fn rejected_limit(error: &Error) -> RiskLimit {
+        error.downcast_ref::<RiskRejected>().unwrap().limit
+    }
+
+    #[test]
+    fn check_should_enforce_order_notional() {
+        let engine = Engine::new(Limits::builder().max_order_notional(dec!(100)).build());
+
+        engine.check(&[order(1, 100)]).unwrap();
+        let err = engine.check(&[order(1, 101)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::OrderNotional);
+    }
+
+    #[test]
+    fn check_should_enforce_market_and_event_exposure() {
+        let engine = Engine::new(
+            Limits::builder()
+                .max_market_exposure(dec!(150))
+                .max_event_exposure(dec!(200))
+                .build(),
+        );
+        let market_a = B256::repeat_byte(1);
+        let market_b = B256::repeat_byte(2);
+        engine.register_token(TokenId::from(U256::from(1)), market_a, Some("e".to_owned()));
+        engine.register_token(TokenId::from(U256::from(2)), market_b, Some("e".to_owned()));
+
+        engine.check(&[order(1, 100)]).unwrap();
+        let err = engine.check(&[order(1, 60)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::MarketExposure);
+
+        engine.check(&[order(2, 100)]).unwrap();
+        let err = engine.check(&[order(2, 10)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::EventExposure);
+
+        engine.release(&[order(2, 100)]);
+        assert_eq!(engine.market_exposure(market_b), Decimal::ZERO);
+        assert_eq!(engine.event_exposure("e"), dec!(100));
+
+        engine.check(&[order(3, 1)]).unwrap_err();
+    }
+
+    #[test]
+    fn open_orders_should_release_exposure_once_closed() {
+        let engine = Engine::new(Limits::builder().max_market_exposure(dec!(150)).build());
+        let market = B256::repeat_byte(1);
+        let token = TokenId::from(U256::from(1));
+        engine.register_token(token, market, None);
+
+        let first = SignedOrder {
+            order: Order {
+                takerAmount: U256::from(200_000_000),
+                ..order(1, 100).order
+            },
+            ..order(1, 100)
+        };
+        engine.check(std::slice::from_ref(&first)).unwrap();
+        engine.open_order("first", &first);
+        engine.check(&[order(1, 50)]).unwrap();
+        engine.open_order("second", &order(1, 50));
+        assert_eq!(engine.market_exposure(market), dec!(150));
+
+        // Half of the 200 shares of the first order are filled
+        engine.record_fill("first", dec!(100));
+        assert_eq!(engine.market_exposure(market), dec!(100));
+
+        engine.release_order("second");
+        engine.release_order("second");
+        assert_eq!(engine.market_exposure(market), dec!(50));
+
+        engine.record_fill("first", dec!(100));
+        assert_eq!(engine.market_exposure(market), Decimal::ZERO);
+    }
+
+    #[test]
+    fn check_position_should_count_open_buys() {
+        let engine = Engine::new(Limits::builder().max_market_position(dec!(100)).build());
+        let market = B256::repeat_byte(1);
+        let token = TokenId::from(U256::from(1));
+        engine.register_token(token, market, None);
+        engine.set_position(token, dec!(100), dec!(40));
+
+        engine.check(&[order(1, 50)]).unwrap();
+        engine.open_order("resting", &order(1, 50));
+        let err = engine
+            .check_position(token, Side::Buy, dec!(20), dec!(11))
+            .unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::MarketPosition);
+
+        engine.release_order("resting");
+        engine
+            .check_position(token, Side::Buy, dec!(20), dec!(11))
+            .unwrap();
+    }
+
+    #[test]
+    fn check_should_not_count_sells_of_held_shares() {
+        let engine = Engine::new(Limits::builder().max_market_exposure(dec!(10)).build());
+        let market = B256::repeat_byte(1);
+        let token = TokenId::from(U256::from(1));
+        engine.register_token(token, market, None);
+        engine.set_position(token, dec!(100), dec!(40));
+
+        // Sells 100 shares for 50 USDC
+        let sell = |salt: u64| SignedOrder {
+            order: Order {
+                salt: U256::from(salt),
+                side: Side::Sell as u8,
+                makerAmount: U256::from(100_000_000),
+                takerAmount: U256::from(50_000_000),
+                ..order(1, 0).order
+            },
+            signature: Signature::new(U256::from(salt), U256::ZERO, false),
+            ..order(1, 0)
+        };
+        engine.check(&[sell(1)]).unwrap();
+        assert_eq!(engine.market_exposure(market), Decimal::ZERO);
+
+        // The held shares are already being sold
+        let err = engine.check(&[sell(2)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::MarketExposure);
+
+        engine.release(&[sell(1)]);
+        engine.check(&[sell(2)]).unwrap();
+    }
+
+    #[test]
+    fn check_should_enforce_daily_loss_and_kill_switch() {
+        let engine = Engine::new(
+            Limits::builder()
+                .max_daily_loss(dec!(50))
+                .kill_threshold(dec!(80))
+                .build(),
+        );
+
+        engine.record_pnl(dec!(-50));
+        let err = engine.check(&[order(1, 1)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::DailyLoss);
+        assert!(!engine.is_killed());
+
+        engine.record_pnl(dec!(-30));
+        assert!(engine.is_killed());
+        let err = engine.check(&[order(1, 1)]).unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::KillSwitch);
+
+        engine.reset_kill_switch();
+        engine.record_pnl(dec!(80));
+        engine.check(&[order(1, 1)]).unwrap();
+    }
+
+    #[test]
+    fn check_position_should_count_held_shares() {
+        let engine = Engine::new(
+            Limits::builder()
+                .max_market_shares(dec!(100))
+                .max_event_position(dec!(60))
+                .build(),
+        );
+        let token = |id: u64| TokenId::from(U256::from(id));
+        let market_a = B256::repeat_byte(1);
+        let market_b = B256::repeat_byte(2);
+        engine.register_token(token(1), market_a, Some("e".to_owned()));
+        engine.register_token(token(2), market_a, Some("e".to_owned()));
+        engine.register_token(token(3), market_b, Some("e".to_owned()));
+
+        engine.set_position(token(1), dec!(60), dec!(30));
+        engine.set_position(token(2), dec!(30), dec!(15));
+        assert_eq!(engine.market_position(market_a), (dec!(90), dec!(45)));
+
+        engine
+            .check_position(token(1), Side::Buy, dec!(10), dec!(5))
+            .unwrap();
+        let err = engine
+            .check_position(token(2), Side::Buy, dec!(11), dec!(5))
+            .unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::MarketShares);
+
+        let err = engine
+            .check_position(token(3), Side::Buy, dec!(40), dec!(16))
+            .unwrap_err();
+        assert_eq!(rejected_limit(&err), RiskLimit::EventPosition);
+
+        // Sells only reduce the position
+        engine
+            .check_position(token(1), Side::Sell, dec!(1000), dec!(500))
+            .unwrap();
+        engine
+            .check_position(token(4), Side::Buy, dec!(1), dec!(1))
+            .unwrap_err();
+
+        engine.set_position(token(1), Decimal::ZERO, Decimal::ZERO);
+        assert_eq!(engine.market_position(market_a), (dec!(30), dec!(15)));
+    }
+}
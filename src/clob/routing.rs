@@ -0,0 +1,381 @@
+//! Best execution across the order book and RFQ.
+//!
+//! [`Client::execute_best`](crate::clob::Client::execute_best) compares the average price at which
+//! the order book can fill a given size against quotes solicited from market makers via RFQ, and
+//! executes on the better venue:
+//!
+//! 1. The order book is fetched and walked to find the average and worst price for the full
+//!    size. A book that is too shallow is not considered.
+//! 2. An RFQ request for the same size is created, priced at [`Constraints`]' `limit_price` or,
+//!    if unset, at the book's average price, and quotes are collected for `quote_window`.
+//! 3. The best quote is accepted if it beats the book, otherwise the RFQ request is canceled and
+//!    the size is posted to the book as a fill-or-kill market order capped at the worst price.
+//!
+//! Ties go to the order book, since RFQ trades are subject to the quoter's last look. Prices that
+//! are worse than `limit_price` are never executed on either venue.
+//!
+//! The order signed to accept a quote passes the same validators, risk limits and checks as one
+//! posted to the order book, such as the closed-only status and the order dedup window.
+
+use std::time::Duration;
+
+use alloy::signers::Signer;
+use bon::Builder;
+use futures::TryStreamExt as _;
+use tokio::time;
+#[cfg(feature = "tracing")]
+use tracing::warn;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::request::{
+    Asset, CancelRfqRequestRequest, CreateRfqRequestRequest, OrderBookSummaryRequest,
+    RfqQuotesRequest,
+};
+use crate::clob::types::response::{OrderSummary, PostOrderResponse, RfqQuote};
+use crate::clob::types::{AcceptRfqQuoteRequest, Amount, OrderType, Side, SignatureType};
+use crate::error::Error;
+use crate::types::{Decimal, Shares, TokenId, Usdc};
+
+/// Constraints for [`Client::execute_best`](crate::clob::Client::execute_best).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Constraints {
+    /// The worst acceptable price per share, the highest for buys and the lowest for sells. Any
+    /// price is acceptable by default.
+    limit_price: Option<Decimal>,
+    /// How long RFQ quotes are collected before the venues are compared. The default is five (5)
+    /// seconds.
+    #[builder(default = Duration::from_secs(5))]
+    quote_window: Duration,
+}
+
+/// The venue an order was executed on.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Venue {
+    /// The order was posted to the order book.
+    OrderBook,
+    /// An RFQ quote was accepted.
+    Rfq,
+}
+
+/// The outcome of [`Client::execute_best`](crate::clob::Client::execute_best).
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Execution {
+    /// The venue the order was executed on.
+    pub venue: Venue,
+    /// The expected average price per share on the chosen venue.
+    pub price: Decimal,
+    /// The average price per share at which the order book could fill the full size, if it was
+    /// deep enough.
+    pub book_price: Option<Decimal>,
+    /// The best RFQ quote received, if any.
+    pub quote: Option<RfqQuote>,
+    /// The response to the order book post, for [`Venue::OrderBook`] executions.
+    pub response: Option<PostOrderResponse>,
+}
+
+/// The average and worst price at which the order book fills the full size.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BookFill {
+    average: Decimal,
+    worst: Decimal,
+}
+
+/// Walks `levels`, ordered as returned by the CLOB with the best level last, until `size` shares
+/// are filled. Returns `None` if the levels are too shallow.
+fn book_fill(levels: &[OrderSummary], size: Decimal) -> Option<BookFill> {
+    let mut remaining = size;
+    let mut notional = Decimal::ZERO;
+
+    for level in levels.iter().rev() {
+        let filled = remaining.min(level.size);
+        notional += filled * level.price;
+        remaining -= filled;
+
+        if remaining.is_zero() {
+            return Some(BookFill {
+                average: notional / size,
+                worst: level.price,
+            });
+        }
+    }
+
+    None
+}
+
+/// Returns whether `price` is strictly better than `other` for `side`.
+fn is_better(side: Side, price: Decimal, other: Decimal) -> bool {
+    match side {
+        Side::Buy => price < other,
+        _ => price > other,
+    }
+}
+
+/// Returns whether `price` is acceptable for `side` given `limit`.
+fn within_limit(side: Side, price: Decimal, limit: Option<Decimal>) -> bool {
+    limit.is_none_or(|limit| price == limit || is_better(side, price, limit))
+}
+
+/// Returns the best of `quotes` for `side` that is within `limit`.
+fn best_quote(side: Side, quotes: Vec<RfqQuote>, limit: Option<Decimal>) -> Option<RfqQuote> {
+    quotes
+        .into_iter()
+        .filter(|quote| within_limit(side, quote.price, limit))
+        .reduce(|best, quote| {
+            if is_better(side, quote.price, best.price) {
+                quote
+            } else {
+                best
+            }
+        })
+}
+
+/// Creates an RFQ request for `size` shares at `price` and returns the quotes received within
+/// `window`.
+async fn solicit_quotes<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    token_id: TokenId,
+    side: Side,
+    size: Decimal,
+    price: Decimal,
+    user_type: SignatureType,
+    window: Duration,
+) -> Result<(String, Vec<RfqQuote>)> {
+    let shares = Shares::new(size)?;
+    let usdc = Usdc::new((size * price).round_dp(Usdc::DECIMALS))?;
+    let request = match side {
+        Side::Buy => CreateRfqRequestRequest::builder()
+            .asset_in(Asset::Asset(token_id))
+            .amount_in(shares)
+            .asset_out(Asset::Usdc)
+            .amount_out(usdc),
+        _ => CreateRfqRequestRequest::builder()
+            .asset_in(Asset::Usdc)
+            .amount_in(usdc)
+            .asset_out(Asset::Asset(token_id))
+            .amount_out(shares),
+    }
+    .user_type(user_type)
+    .build();
+
+    let request_id = client.create_request(&request).await?.request_id;
+
+    time::sleep(window).await;
+
+    let request = RfqQuotesRequest::builder()
+        .request_ids(vec![request_id.clone()])
+        .build();
+    let quotes = client
+        .stream_data(|client, cursor| {
+            let request = &request;
+            async move { client.quotes(request, cursor.as_deref()).await }
+        })
+        .try_collect()
+        .await?;
+
+    Ok((request_id, quotes))
+}
+
+pub(crate) async fn execute_best<K: Kind, S: Signer>(
+    client: &Client<Authenticated<K>>,
+    signer: &S,
+    token_id: TokenId,
+    side: Side,
+    size: Decimal,
+    constraints: Constraints,
+    user_type: SignatureType,
+) -> Result<Execution> {
+    if !matches!(side, Side::Buy | Side::Sell) {
+        return Err(Error::validation(format!("Invalid side: {side}")));
+    }
+    if size <= Decimal::ZERO {
+        return Err(Error::validation(format!(
+            "Unable to execute non-positive size {size}"
+        )));
+    }
+
+    let book = client
+        .order_book(&OrderBookSummaryRequest {
+            token_id,
+            side: None,
+        })
+        .await?;
+    let levels = match side {
+        Side::Buy => &book.asks,
+        _ => &book.bids,
+    };
+    let fill = book_fill(levels, size)
+        .filter(|fill| within_limit(side, fill.average, constraints.limit_price));
+
+    let Some(reference) = constraints.limit_price.or(fill.map(|fill| fill.average)) else {
+        return Err(Error::validation(format!(
+            "Unable to price an RFQ request for {token_id} without a limit price, the order book \
+             cannot fill {size}"
+        )));
+    };
+
+    let (request_id, quotes) = solicit_quotes(
+        client,
+        token_id,
+        side,
+        size,
+        reference,
+        user_type,
+        constraints.quote_window,
+    )
+    .await?;
+    let quote = best_quote(side, quotes, constraints.limit_price);
+
+    if let Some(quote) = quote.as_ref()
+        && fill.is_none_or(|fill| is_better(side, quote.price, fill.average))
+    {
+        let order = client
+            .limit_order()
+            .token_id(token_id)
+            .side(side)
+            .price(quote.price)
+            .size(size)
+            .build()
+            .await?;
+        let order = client.sign(signer, order).await?;
+
+        let request = AcceptRfqQuoteRequest::builder()
+            .request_id(request_id)
+            .quote_id(quote.quote_id.clone())
+            .maker_amount(order.order.makerAmount)
+            .taker_amount(order.order.takerAmount)
+            .token_id(token_id)
+            .maker(order.order.maker)
+            .signer(order.order.signer)
+            .taker(order.order.taker)
+            .nonce(order.order.nonce.saturating_to())
+            .expiration(order.order.expiration.saturating_to())
+            .side(side)
+            .fee_rate_bps(order.order.feeRateBps.saturating_to())
+            .signature(order.signature.to_string())
+            .salt(order.order.salt.to_string())
+            .owner(order.owner)
+            .build();
+        client.admit_quote_order(&order).await?;
+        let accepted = client.accept_quote(&request).await;
+        // An accepted quote is filled at once, so the order holds no exposure beyond the fill
+        client.release_quote_order(&order);
+        accepted?;
+
+        return Ok(Execution {
+            venue: Venue::Rfq,
+            price: quote.price,
+            book_price: fill.map(|fill| fill.average),
+            quote: Some(quote.clone()),
+            response: None,
+        });
+    }
+
+    // The RFQ lost, so stop soliciting quotes. A failure here does not prevent executing on the
+    // order book, since the request expires on its own.
+    let cancel = CancelRfqRequestRequest::builder()
+        .request_id(request_id)
+        .build();
+    if let Err(e) = client.cancel_request(&cancel).await {
+        #[cfg(feature = "tracing")]
+        warn!("Unable to cancel RFQ request: {e:?}");
+        #[cfg(not(feature = "tracing"))]
+        let _: &Error = &e;
+    }
+
+    let Some(fill) = fill else {
+        return Err(Error::validation(format!(
+            "Neither the order book nor RFQ can fill {size} of {token_id} within the constraints"
+        )));
+    };
+
+    let order = client
+        .market_order()
+        .token_id(token_id)
+        .side(side)
+        .amount(Amount::shares(size)?)
+        .price(fill.worst)
+        .order_type(OrderType::FOK)
+        .build()
+        .await?;
+    let order = client.sign(signer, order).await?;
+    let response = client.post_order(order).await?.into_response();
+
+    Ok(Execution {
+        venue: Venue::OrderBook,
+        price: fill.average,
+        book_price: Some(fill.average),
+        quote,
+        response: Some(response),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::types::{Address, B256};
+
+    fn level(price: Decimal, size: Decimal) -> OrderSummary {
+        OrderSummary::builder().price(price).size(size).build()
+    }
+
+    #[test]
+    fn book_fill_should_walk_levels_from_best() {
+        // Asks are returned with the best (lowest) price last
+        let asks = [
+            level(dec!(0.60), dec!(100)),
+            level(dec!(0.55), dec!(10)),
+            level(dec!(0.50), dec!(10)),
+        ];
+
+        assert_eq!(
+            book_fill(&asks, dec!(5)),
+            Some(BookFill {
+                average: dec!(0.50),
+                worst: dec!(0.50)
+            })
+        );
+        assert_eq!(
+            book_fill(&asks, dec!(40)),
+            Some(BookFill {
+                average: dec!(0.5625),
+                worst: dec!(0.60)
+            })
+        );
+        assert_eq!(book_fill(&asks, dec!(121)), None);
+    }
+
+    #[test]
+    fn best_quote_should_respect_side_and_limit() {
+        let quote = |id: &str, price: Decimal| {
+            RfqQuote::builder()
+                .quote_id(id)
+                .request_id("request")
+                .user_address(Address::ZERO)
+                .proxy_address(Address::ZERO)
+                .condition(B256::ZERO)
+                .token(TokenId::default())
+                .complement(TokenId::default())
+                .side(Side::Buy)
+                .size_in(dec!(10))
+                .size_out(dec!(5))
+                .price(price)
+                .build()
+        };
+        let quotes = || vec![quote("a", dec!(0.52)), quote("b", dec!(0.48))];
+
+        let best = |side, limit| best_quote(side, quotes(), limit).map(|quote| quote.quote_id);
+
+        assert_eq!(best(Side::Buy, None).as_deref(), Some("b"));
+        assert_eq!(best(Side::Sell, None).as_deref(), Some("a"));
+        assert_eq!(best(Side::Sell, Some(dec!(0.50))).as_deref(), Some("a"));
+        assert_eq!(best(Side::Buy, Some(dec!(0.45))), None);
+    }
+}
@@ -0,0 +1,67 @@
+//! Reward scoring polling.
+//!
+//! [`Client::watch_scoring`](crate::clob::Client::watch_scoring) periodically lists the user's
+//! open orders and checks which of them are scoring for market maker rewards, yielding the
+//! scoring status of every open order after each poll.
+
+use std::time::Duration;
+
+use async_stream::stream;
+use bon::Builder;
+use futures::{Stream, TryStreamExt as _};
+use tokio::time;
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::request::OrdersRequest;
+use crate::clob::types::response::OrdersScoringResponse;
+use crate::types::B256;
+
+/// Configuration for [`Client::watch_scoring`](crate::clob::Client::watch_scoring).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct WatchConfig {
+    /// How often the scoring status is polled. The default is sixty (60) seconds.
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+    /// Restricts the watched orders to those in this market. All open orders are watched by
+    /// default.
+    market: Option<B256>,
+}
+
+/// Returns the scoring status of every open order, optionally restricted to `market`.
+async fn poll<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    market: Option<B256>,
+) -> Result<OrdersScoringResponse> {
+    let order_ids: Vec<String> = client
+        .stream_data(move |client, cursor| {
+            let request = OrdersRequest::builder().maybe_market(market).build();
+            async move { client.orders(&request, cursor).await }
+        })
+        .map_ok(|order| order.id)
+        .try_collect()
+        .await?;
+
+    if order_ids.is_empty() {
+        return Ok(OrdersScoringResponse::new());
+    }
+
+    let order_ids: Vec<&str> = order_ids.iter().map(String::as_str).collect();
+    client.are_orders_scoring(&order_ids).await
+}
+
+pub(crate) fn watch<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    config: WatchConfig,
+) -> impl Stream<Item = Result<OrdersScoringResponse>> + '_ {
+    stream! {
+        loop {
+            yield poll(client, config.market).await;
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
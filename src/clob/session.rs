@@ -0,0 +1,338 @@
+//! Serializable session state.
+//!
+//! A [`Session`] captures everything an authenticated [`Client`](crate::clob::Client) needs to
+//! make L2 requests: the wallet address, the API [`Credentials`], and the funder and
+//! [`SignatureType`] used when building orders. Exporting it with
+//! [`Client::export_session`](crate::clob::Client::export_session) and resuming it later with
+//! [`Client::from_session`](crate::clob::Client::from_session) lets CLIs and short-lived jobs skip
+//! re-deriving API keys on every run.
+//!
+//! A [`Session`] serializes its secrets in plain text. With the `session-encryption` feature
+//! enabled, [`Session::encrypt`] produces an [`EncryptedSession`] instead, whose secret and
+//! passphrase are sealed with `ChaCha20-Poly1305` under a caller supplied 32 byte key. The
+//! session's address and format version are authenticated along with them, so a sealed secret
+//! cannot be moved to another address.
+//!
+//! ```rust,no_run
+//! use std::str::FromStr as _;
+//!
+//! use alloy::signers::Signer as _;
+//! use alloy::signers::local::LocalSigner;
+//! use polymarket_client_sdk::clob::session::Session;
+//! use polymarket_client_sdk::clob::{Client, Config};
+//! use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need a private key");
+//!     let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+//!     let client = Client::production(Config::default())?
+//!         .authentication_builder(&signer)
+//!         .authenticate()
+//!         .await?;
+//!
+//!     let saved = serde_json::to_string(&client.export_session())?;
+//!
+//!     // Later, e.g. on the next run
+//!     let session: Session = serde_json::from_str(&saved)?;
+//!     let client = Client::from_session("https://clob.polymarket.com", Config::default(), session).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::result::Result as StdResult;
+
+use secrecy::ExposeSecret as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "session-encryption")]
+use {
+    base64::Engine as _,
+    base64::engine::general_purpose::STANDARD,
+    chacha20poly1305::aead::{Aead as _, KeyInit as _, Payload},
+    chacha20poly1305::{ChaCha20Poly1305, Key, Nonce},
+};
+
+#[cfg(feature = "session-encryption")]
+use crate::Result;
+use crate::auth::{ApiKey, Credentials};
+use crate::clob::types::SignatureType;
+#[cfg(feature = "session-encryption")]
+use crate::error::Error;
+use crate::types::Address;
+
+/// The state needed to resume an authenticated [`Client`](crate::clob::Client).
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Session {
+    /// The address of the signer the `credentials` were issued to.
+    pub address: Address,
+    /// The API credentials used to sign L2 requests.
+    pub credentials: Credentials,
+    /// The funder used when building orders, if any.
+    pub funder: Option<Address>,
+    /// The signature type used when building orders.
+    pub signature_type: SignatureType,
+}
+
+/// The serialized form shared by [`Session`] and `EncryptedSession`, differing only in how the
+/// credentials are represented.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repr<C> {
+    address: Address,
+    credentials: C,
+    funder: Option<Address>,
+    signature_type: SignatureType,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlainCredentials {
+    key: ApiKey,
+    secret: String,
+    passphrase: String,
+}
+
+impl Session {
+    fn to_repr(&self) -> Repr<PlainCredentials> {
+        Repr {
+            address: self.address,
+            credentials: PlainCredentials {
+                key: self.credentials.key(),
+                secret: self.credentials.secret().expose_secret().to_owned(),
+                passphrase: self.credentials.passphrase().expose_secret().to_owned(),
+            },
+            funder: self.funder,
+            signature_type: self.signature_type,
+        }
+    }
+
+    fn from_repr(repr: Repr<PlainCredentials>) -> Self {
+        Self {
+            address: repr.address,
+            credentials: Credentials::new(
+                repr.credentials.key,
+                repr.credentials.secret,
+                repr.credentials.passphrase,
+            ),
+            funder: repr.funder,
+            signature_type: repr.signature_type,
+        }
+    }
+
+    #[cfg(feature = "session-encryption")]
+    /// Encrypts the secret and passphrase of this session with `key`.
+    ///
+    /// Deriving `key`, e.g. from a password with a KDF or from an OS keychain, is left to the
+    /// caller. A fresh random nonce is used on every call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secrets cannot be serialized or encrypted.
+    pub fn encrypt(&self, key: &[u8; 32]) -> Result<EncryptedSession> {
+        let secrets = serde_json::to_vec(&Secrets {
+            secret: self.credentials.secret().expose_secret().to_owned(),
+            passphrase: self.credentials.passphrase().expose_secret().to_owned(),
+        })?;
+
+        let nonce: [u8; 12] = rand::random();
+        let aad = associated_data(self.address);
+        let ciphertext = ChaCha20Poly1305::new(&Key::from(*key))
+            .encrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: &secrets,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_cipher_error| Error::validation("Unable to encrypt session"))?;
+
+        Ok(EncryptedSession {
+            repr: Repr {
+                address: self.address,
+                credentials: SealedCredentials {
+                    key: self.credentials.key(),
+                    nonce: STANDARD.encode(nonce),
+                    ciphertext: STANDARD.encode(ciphertext),
+                },
+                funder: self.funder,
+                signature_type: self.signature_type,
+            },
+        })
+    }
+}
+
+impl Serialize for Session {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        self.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Session {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Repr::deserialize(deserializer).map(Self::from_repr)
+    }
+}
+
+/// A [`Session`] whose secret and passphrase are encrypted, safe to write to disk.
+#[cfg(feature = "session-encryption")]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "Reads better than `session::Encrypted` at call sites"
+)]
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EncryptedSession {
+    repr: Repr<SealedCredentials>,
+}
+
+#[cfg(feature = "session-encryption")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SealedCredentials {
+    key: ApiKey,
+    /// Base64 encoded nonce used to seal `ciphertext`
+    nonce: String,
+    /// Base64 encoded `ChaCha20-Poly1305` ciphertext of the JSON encoded [`Secrets`]
+    ciphertext: String,
+}
+
+#[cfg(feature = "session-encryption")]
+#[derive(Serialize, Deserialize)]
+struct Secrets {
+    secret: String,
+    passphrase: String,
+}
+
+#[cfg(feature = "session-encryption")]
+impl EncryptedSession {
+    /// Decrypts this session with the `key` it was encrypted with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is wrong or the encrypted session has been tampered with.
+    pub fn decrypt(&self, key: &[u8; 32]) -> Result<Session> {
+        let sealed = &self.repr.credentials;
+        let nonce: [u8; 12] = STANDARD
+            .decode(&sealed.nonce)?
+            .try_into()
+            .map_err(|_nonce| Error::validation("Invalid encrypted session nonce"))?;
+        let ciphertext = STANDARD.decode(&sealed.ciphertext)?;
+
+        let aad = associated_data(self.repr.address);
+        let secrets = ChaCha20Poly1305::new(&Key::from(*key))
+            .decrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_cipher_error| Error::validation("Unable to decrypt session"))?;
+        let secrets: Secrets = serde_json::from_slice(&secrets)?;
+
+        Ok(Session {
+            address: self.repr.address,
+            credentials: Credentials::new(sealed.key, secrets.secret, secrets.passphrase),
+            funder: self.repr.funder,
+            signature_type: self.repr.signature_type,
+        })
+    }
+}
+
+/// Identifies the encrypted session format, bumped whenever the sealed [`Secrets`] change.
+#[cfg(feature = "session-encryption")]
+const ENCRYPTION_VERSION: &[u8] = b"polymarket-session-v1";
+
+/// The data authenticated, but not encrypted, along with the [`Secrets`] of a session.
+#[cfg(feature = "session-encryption")]
+fn associated_data(address: Address) -> Vec<u8> {
+    [ENCRYPTION_VERSION, address.as_slice()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::types::address;
+
+    fn session() -> Session {
+        Session {
+            address: address!("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            credentials: Credentials::new(
+                Uuid::nil(),
+                "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
+                "passphrase".to_owned(),
+            ),
+            funder: Some(address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316")),
+            signature_type: SignatureType::Proxy,
+        }
+    }
+
+    #[test]
+    fn session_round_trip_should_succeed() -> anyhow::Result<()> {
+        let value = serde_json::to_value(session())?;
+        assert_eq!(
+            value,
+            json!({
+                "address": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+                "credentials": {
+                    "key": "00000000-0000-0000-0000-000000000000",
+                    "secret": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+                    "passphrase": "passphrase"
+                },
+                "funder": "0x995c9b1f779c04e65af8ea3360f96c43b5e62316",
+                "signatureType": 1
+            })
+        );
+
+        let restored: Session = serde_json::from_value(value)?;
+        assert_eq!(restored.address, session().address);
+        assert_eq!(restored.credentials.key(), Uuid::nil());
+        assert_eq!(
+            restored.credentials.secret().expose_secret(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+        );
+        assert_eq!(
+            restored.credentials.passphrase().expose_secret(),
+            "passphrase"
+        );
+        assert_eq!(restored.funder, session().funder);
+        assert_eq!(restored.signature_type, SignatureType::Proxy);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "session-encryption")]
+    #[test]
+    fn encrypted_session_round_trip_should_succeed() -> anyhow::Result<()> {
+        let key = [7; 32];
+        let encrypted = session().encrypt(&key)?;
+
+        let serialized = serde_json::to_string(&encrypted)?;
+        assert!(!serialized.contains("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="));
+        assert!(!serialized.contains("passphrase\""));
+
+        let encrypted: EncryptedSession = serde_json::from_str(&serialized)?;
+        let restored = encrypted.decrypt(&key)?;
+        assert_eq!(
+            restored.credentials.secret().expose_secret(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+        );
+        assert_eq!(
+            restored.credentials.passphrase().expose_secret(),
+            "passphrase"
+        );
+        assert_eq!(restored.signature_type, SignatureType::Proxy);
+
+        encrypted.decrypt(&[8; 32]).unwrap_err();
+
+        let mut moved = encrypted;
+        moved.repr.address = Address::ZERO;
+        moved.decrypt(&key).unwrap_err();
+
+        Ok(())
+    }
+}
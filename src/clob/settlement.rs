@@ -0,0 +1,363 @@
+//! Correlation of API trades with on-chain fills.
+//!
+//! Trades reported by [`Client::trades`](crate::clob::Client::trades) move from `MATCHED` to
+//! `MINED` and `CONFIRMED` as the operator settles them on-chain. A [`Correlator`] matches these
+//! trades with the `OrderFilled` events of the exchange contracts, e.g. read via
+//! [`onchain::exchange::watch_events`](crate::onchain::exchange::watch_events), to confirm each
+//! trade from the chain, and flags trades that stay `MATCHED` or `RETRYING` without an on-chain
+//! fill for longer than expected, which usually warrants operational attention.
+//!
+//! Orders placed locally can be registered with [`Correlator::track_order`], so that settlements
+//! and stuck trades report whether they involve one of them.
+//!
+//! ```rust,no_run
+//! # fn run(
+//! #     trades: Vec<polymarket_client_sdk::clob::types::response::TradeResponse>,
+//! #     events: Vec<polymarket_client_sdk::onchain::exchange::Event>,
+//! # ) {
+//! use chrono::Utc;
+//! use polymarket_client_sdk::clob::settlement::{Config, Correlator};
+//!
+//! let mut correlator = Correlator::new(Config::builder().build());
+//! trades.into_iter().for_each(|trade| correlator.record_trade(trade));
+//! events.iter().for_each(|event| correlator.record_event(event));
+//!
+//! for stuck in correlator.stuck_trades(Utc::now()) {
+//!     eprintln!("trade {} is {} since {}", stuck.trade_id, stuck.status, stuck.match_time);
+//! }
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr as _;
+
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::clob::types::TradeStatusType;
+use crate::clob::types::response::TradeResponse;
+use crate::onchain::exchange::{Event, OrderFilled};
+use crate::types::{B256, Shares};
+
+/// Configuration for a [`Correlator`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Config {
+    /// How long a trade may stay `MATCHED` or `RETRYING` without an on-chain fill before it is
+    /// reported as stuck. The default is five (5) minutes.
+    #[builder(default = TimeDelta::minutes(5))]
+    stuck_after: TimeDelta,
+}
+
+/// The on-chain settlement of a trade, see [`Correlator::settlement`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Settlement {
+    /// The ID of the trade.
+    pub trade_id: String,
+    /// The status of the trade as last reported by the API.
+    pub status: TradeStatusType,
+    /// The `OrderFilled` events of the trade's taker order, in the trade's transaction if the API
+    /// reported one. Empty if the trade has not been observed on-chain.
+    pub fills: Vec<OrderFilled>,
+    /// The number of shares the taker order filled on-chain.
+    pub filled_size: Shares,
+    /// Whether the trade involves an order registered with [`Correlator::track_order`].
+    pub is_local: bool,
+}
+
+impl Settlement {
+    /// Returns whether the trade has been observed on-chain.
+    #[must_use]
+    pub const fn is_settled(&self) -> bool {
+        !self.fills.is_empty()
+    }
+}
+
+/// A trade that stayed `MATCHED` or `RETRYING` without an on-chain fill for longer than the
+/// configured threshold, see [`Correlator::stuck_trades`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StuckTrade {
+    /// The ID of the trade.
+    pub trade_id: String,
+    /// The status of the trade as last reported by the API.
+    pub status: TradeStatusType,
+    /// When the trade was matched.
+    pub match_time: DateTime<Utc>,
+    /// The transaction hash reported by the API, if any.
+    pub transaction_hash: Option<B256>,
+    /// Whether the trade involves an order registered with [`Correlator::track_order`].
+    pub is_local: bool,
+}
+
+/// Matches API trades with on-chain fills, see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct Correlator {
+    config: Config,
+    orders: HashSet<B256>,
+    trades: HashMap<String, TradeResponse>,
+    fills: HashMap<B256, Vec<OrderFilled>>,
+}
+
+impl Correlator {
+    /// Creates an empty correlator.
+    #[must_use]
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            orders: HashSet::new(),
+            trades: HashMap::new(),
+            fills: HashMap::new(),
+        }
+    }
+
+    /// Registers an order placed locally, e.g. the order ID of a
+    /// [`PostOrderResponse`](crate::clob::types::response::PostOrderResponse).
+    pub fn track_order(&mut self, order_id: B256) {
+        self.orders.insert(order_id);
+    }
+
+    /// Records `trade`, replacing a previously recorded trade with the same ID, e.g. to update
+    /// its status.
+    pub fn record_trade(&mut self, trade: TradeResponse) {
+        self.trades.insert(trade.id.clone(), trade);
+    }
+
+    /// Records an on-chain exchange event. Only `OrderFilled` events are used for correlation,
+    /// and events already recorded are ignored.
+    pub fn record_event(&mut self, event: &Event) {
+        if let Event::OrderFilled(filled) = event {
+            let fills = self.fills.entry(filled.order_hash).or_default();
+            if !fills.contains(filled) {
+                fills.push(*filled);
+            }
+        }
+    }
+
+    /// Returns the on-chain settlement of the trade with `trade_id`, or `None` if no such trade
+    /// has been recorded.
+    #[must_use]
+    pub fn settlement(&self, trade_id: &str) -> Option<Settlement> {
+        let trade = self.trades.get(trade_id)?;
+        let fills = self.fills_of(trade);
+        let filled_size = fills.iter().fold(Shares::ZERO, |total, fill| {
+            Shares::new(total.as_decimal() + fill.exchanged.size.as_decimal()).unwrap_or(total)
+        });
+
+        Some(Settlement {
+            trade_id: trade.id.clone(),
+            status: trade.status.clone(),
+            fills,
+            filled_size,
+            is_local: self.is_local(trade),
+        })
+    }
+
+    /// Returns the trades that are still `MATCHED` or `RETRYING` at `now` without an on-chain
+    /// fill, and were matched longer than the configured threshold ago, oldest first.
+    #[must_use]
+    pub fn stuck_trades(&self, now: DateTime<Utc>) -> Vec<StuckTrade> {
+        let mut stuck: Vec<StuckTrade> = self
+            .trades
+            .values()
+            .filter(|trade| {
+                matches!(
+                    trade.status,
+                    TradeStatusType::Matched | TradeStatusType::Retrying
+                )
+            })
+            .filter(|trade| now - trade.match_time >= self.config.stuck_after)
+            .filter(|trade| self.fills_of(trade).is_empty())
+            .map(|trade| StuckTrade {
+                trade_id: trade.id.clone(),
+                status: trade.status.clone(),
+                match_time: trade.match_time,
+                transaction_hash: transaction_hash(trade),
+                is_local: self.is_local(trade),
+            })
+            .collect();
+        stuck.sort_by_key(|trade| trade.match_time);
+
+        stuck
+    }
+
+    /// Forgets trades matched before `before`, and the fills of their orders, to bound memory
+    /// in long-running processes.
+    pub fn prune(&mut self, before: DateTime<Utc>) {
+        let stale: Vec<(String, Option<B256>)> = self
+            .trades
+            .values()
+            .filter(|trade| trade.match_time < before)
+            .map(|trade| (trade.id.clone(), order_hash(&trade.taker_order_id)))
+            .collect();
+
+        for (trade_id, order_hash) in stale {
+            self.trades.remove(&trade_id);
+            if let Some(order_hash) = order_hash {
+                self.fills.remove(&order_hash);
+            }
+        }
+    }
+
+    /// Returns the fills of the taker order of `trade`, restricted to the trade's transaction if
+    /// the API reported one.
+    fn fills_of(&self, trade: &TradeResponse) -> Vec<OrderFilled> {
+        let Some(fills) = order_hash(&trade.taker_order_id).and_then(|hash| self.fills.get(&hash))
+        else {
+            return Vec::new();
+        };
+        let transaction_hash = transaction_hash(trade);
+
+        fills
+            .iter()
+            .filter(|fill| {
+                transaction_hash.is_none_or(|hash| fill.location.transaction_hash == hash)
+            })
+            .copied()
+            .collect()
+    }
+
+    fn is_local(&self, trade: &TradeResponse) -> bool {
+        std::iter::once(trade.taker_order_id.as_str())
+            .chain(
+                trade
+                    .maker_orders
+                    .iter()
+                    .map(|order| order.order_id.as_str()),
+            )
+            .filter_map(order_hash)
+            .any(|hash| self.orders.contains(&hash))
+    }
+}
+
+fn order_hash(order_id: &str) -> Option<B256> {
+    B256::from_str(order_id).ok()
+}
+
+fn transaction_hash(trade: &TradeResponse) -> Option<B256> {
+    (!trade.transaction_hash.is_zero()).then_some(trade.transaction_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{U256, address};
+    use chrono::TimeZone as _;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::onchain::exchange::{EventLocation, Exchanged};
+
+    const TAKER: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+    const MAKER: &str = "0x2222222222222222222222222222222222222222222222222222222222222222";
+    const TRANSACTION: &str = "0x3333333333333333333333333333333333333333333333333333333333333333";
+
+    fn trade(id: &str, status: &str, transaction_hash: &str) -> TradeResponse {
+        serde_json::from_value(json!({
+            "id": id,
+            "taker_order_id": TAKER,
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "asset_id": "1",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "price": "0.5",
+            "status": status,
+            "match_time": "1705322096",
+            "last_update": "1705322096",
+            "outcome": "Yes",
+            "bucket_index": 0,
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "maker_orders": [{
+                "order_id": MAKER,
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x3333333333333333333333333333333333333333",
+                "matched_amount": "10",
+                "price": "0.5",
+                "fee_rate_bps": "0",
+                "asset_id": "1",
+                "outcome": "Yes",
+                "side": "SELL"
+            }],
+            "transaction_hash": transaction_hash,
+            "trader_side": "TAKER"
+        }))
+        .unwrap()
+    }
+
+    fn filled(transaction_hash: &str) -> Event {
+        Event::OrderFilled(OrderFilled {
+            order_hash: B256::from_str(TAKER).unwrap(),
+            maker: address!("0x2222222222222222222222222222222222222222"),
+            taker: address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
+            exchanged: Exchanged {
+                token_id: crate::types::TokenId::new(U256::from(1)),
+                is_buy: true,
+                size: Shares::new(dec!(10)).unwrap(),
+                amount: crate::types::Usdc::new(dec!(5)).unwrap(),
+                price: dec!(0.5),
+            },
+            fee: U256::ZERO,
+            location: EventLocation {
+                exchange: address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
+                transaction_hash: B256::from_str(transaction_hash).unwrap(),
+                block_number: 100,
+                log_index: 0,
+            },
+        })
+    }
+
+    fn matched_at() -> DateTime<Utc> {
+        Utc.timestamp_opt(1_705_322_096, 0).unwrap()
+    }
+
+    #[test]
+    fn settlement_should_match_fills_of_the_trade_transaction() {
+        let mut correlator = Correlator::new(Config::builder().build());
+        correlator.track_order(B256::from_str(MAKER).unwrap());
+        correlator.record_trade(trade("trade-1", "MINED", TRANSACTION));
+        correlator.record_event(&filled(TRANSACTION));
+        correlator.record_event(&filled(TRANSACTION));
+        correlator.record_event(&filled(MAKER));
+
+        let settlement = correlator.settlement("trade-1").unwrap();
+
+        assert!(settlement.is_settled());
+        assert!(settlement.is_local);
+        assert_eq!(settlement.fills.len(), 1);
+        assert_eq!(settlement.filled_size.as_decimal(), dec!(10));
+        assert_eq!(settlement.status, TradeStatusType::Mined);
+        assert!(correlator.settlement("unknown").is_none());
+    }
+
+    #[test]
+    fn stuck_trades_should_flag_matched_trades_without_fills() {
+        let zero = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        let mut correlator = Correlator::new(Config::builder().build());
+        correlator.record_trade(trade("trade-1", "MATCHED", zero));
+
+        assert!(
+            correlator
+                .stuck_trades(matched_at() + TimeDelta::minutes(1))
+                .is_empty()
+        );
+
+        let stuck = correlator.stuck_trades(matched_at() + TimeDelta::minutes(5));
+        assert_eq!(stuck.len(), 1);
+        assert_eq!(stuck[0].trade_id, "trade-1");
+        assert_eq!(stuck[0].transaction_hash, None);
+        assert!(!stuck[0].is_local);
+
+        correlator.record_event(&filled(TRANSACTION));
+        assert!(
+            correlator
+                .stuck_trades(matched_at() + TimeDelta::minutes(5))
+                .is_empty()
+        );
+
+        correlator.prune(matched_at() + TimeDelta::seconds(1));
+        assert!(correlator.settlement("trade-1").is_none());
+    }
+}
@@ -0,0 +1,340 @@
+//! A versioned storage format for orders.
+//!
+//! [`SignedOrder`] serializes to the request body the CLOB expects, which cannot be deserialized
+//! again, and [`SignableOrder`] only serializes the fields sent to the CLOB. Wrapping either in
+//! [`Stored`] instead serializes every field, including the client order ID, in a stable format
+//! tagged with [`VERSION`], so that orders can be queued in external systems such as Kafka or a
+//! database and submitted later by a different process.
+//!
+//! [`Stored`] works with any serde format. All 256-bit integers are written as decimal strings and
+//! the signature as a hex string, so that the format survives formats without 256-bit integers,
+//! e.g. JSON. With the `cbor` feature, [`Stored::to_cbor`] and [`Stored::from_cbor`] provide a
+//! compact binary encoding.
+//!
+//! Reading an order newer than [`VERSION`] fails instead of silently dropping fields. Stored
+//! orders should be checked with [`SignedOrder::validate`] before they are posted, as they may
+//! have expired in the meantime.
+//!
+//! ```rust,no_run
+//! use polymarket_client_sdk::clob::storage::Stored;
+//! use polymarket_client_sdk::clob::types::SignedOrder;
+//!
+//! # fn example(order: SignedOrder) -> anyhow::Result<()> {
+//! let queued = serde_json::to_string(&Stored(order))?;
+//!
+//! // Later, e.g. in another process
+//! let Stored(order): Stored<SignedOrder> = serde_json::from_str(&queued)?;
+//! order.validate()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::result::Result as StdResult;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_with::{DisplayFromStr, serde_as};
+
+#[cfg(feature = "cbor")]
+use crate::Result;
+use crate::auth::ApiKey;
+use crate::clob::types::{Order, OrderType, SignableOrder, SignedOrder};
+use crate::types::{Address, Signature, U256};
+
+/// The current version of the storage format, written with every stored order.
+pub const VERSION: u32 = 1;
+
+/// An order in the storage format, see the [module docs](self).
+///
+/// Implements [`Serialize`] and [`Deserialize`] for [`SignableOrder`] and [`SignedOrder`].
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Stored is a transparent wrapper that callers construct and destructure"
+)]
+#[derive(Debug, PartialEq)]
+pub struct Stored<T>(pub T);
+
+#[cfg(feature = "cbor")]
+impl<T> Stored<T> {
+    /// Encodes this order as CBOR.
+    pub fn to_cbor(&self) -> Result<Vec<u8>>
+    where
+        Self: Serialize,
+    {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Decodes an order encoded by [`Self::to_cbor`].
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self>
+    where
+        Self: for<'de> Deserialize<'de>,
+    {
+        Ok(ciborium::from_reader(bytes)?)
+    }
+}
+
+/// The stored form of [`Order`], which serializes to the CLOB's wire format instead.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderRepr {
+    #[serde_as(as = "DisplayFromStr")]
+    salt: U256,
+    maker: Address,
+    signer: Address,
+    taker: Address,
+    #[serde_as(as = "DisplayFromStr")]
+    token_id: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    maker_amount: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    taker_amount: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    expiration: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    nonce: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    fee_rate_bps: U256,
+    side: u8,
+    signature_type: u8,
+}
+
+impl From<&Order> for OrderRepr {
+    fn from(order: &Order) -> Self {
+        Self {
+            salt: order.salt,
+            maker: order.maker,
+            signer: order.signer,
+            taker: order.taker,
+            token_id: order.tokenId,
+            maker_amount: order.makerAmount,
+            taker_amount: order.takerAmount,
+            expiration: order.expiration,
+            nonce: order.nonce,
+            fee_rate_bps: order.feeRateBps,
+            side: order.side,
+            signature_type: order.signatureType,
+        }
+    }
+}
+
+impl From<OrderRepr> for Order {
+    fn from(repr: OrderRepr) -> Self {
+        Self {
+            salt: repr.salt,
+            maker: repr.maker,
+            signer: repr.signer,
+            taker: repr.taker,
+            tokenId: repr.token_id,
+            makerAmount: repr.maker_amount,
+            takerAmount: repr.taker_amount,
+            expiration: repr.expiration,
+            nonce: repr.nonce,
+            feeRateBps: repr.fee_rate_bps,
+            side: repr.side,
+            signatureType: repr.signature_type,
+        }
+    }
+}
+
+/// The serialized form shared by stored [`SignableOrder`]s and [`SignedOrder`]s, the latter
+/// carrying a signature and owner.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repr {
+    version: u32,
+    order: OrderRepr,
+    order_type: OrderType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    post_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_order_id: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<ApiKey>,
+}
+
+impl Repr {
+    fn deserialize_versioned<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> StdResult<Self, D::Error> {
+        let repr = <Self as Deserialize>::deserialize(deserializer)?;
+        if repr.version == 0 || repr.version > VERSION {
+            return Err(de::Error::custom(format!(
+                "unsupported stored order version {}, expected at most {VERSION}",
+                repr.version
+            )));
+        }
+
+        Ok(repr)
+    }
+}
+
+impl Serialize for Stored<SignableOrder> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let order = &self.0;
+        Repr {
+            version: VERSION,
+            order: OrderRepr::from(&order.order),
+            order_type: order.order_type.clone(),
+            post_only: order.post_only,
+            client_order_id: order.client_order_id.clone(),
+            signature: None,
+            owner: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Stored<SignableOrder> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let repr = Repr::deserialize_versioned(deserializer)?;
+        if repr.signature.is_some() {
+            return Err(de::Error::custom(
+                "stored order is signed, deserialize it as a SignedOrder",
+            ));
+        }
+
+        Ok(Self(SignableOrder {
+            order: repr.order.into(),
+            order_type: repr.order_type,
+            post_only: repr.post_only,
+            client_order_id: repr.client_order_id,
+        }))
+    }
+}
+
+impl Serialize for Stored<SignedOrder> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let order = &self.0;
+        Repr {
+            version: VERSION,
+            order: OrderRepr::from(&order.order),
+            order_type: order.order_type.clone(),
+            post_only: order.post_only,
+            client_order_id: order.client_order_id.clone(),
+            signature: Some(order.signature),
+            owner: Some(order.owner),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Stored<SignedOrder> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let repr = Repr::deserialize_versioned(deserializer)?;
+        let signature = repr
+            .signature
+            .ok_or_else(|| de::Error::missing_field("signature"))?;
+        let owner = repr
+            .owner
+            .ok_or_else(|| de::Error::missing_field("owner"))?;
+
+        Ok(Self(SignedOrder {
+            order: repr.order.into(),
+            signature,
+            order_type: repr.order_type,
+            owner,
+            post_only: repr.post_only,
+            client_order_id: repr.client_order_id,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn order() -> Order {
+        Order {
+            salt: U256::from(479_249_096_354_u64),
+            maker: Address::repeat_byte(0x11),
+            signer: Address::repeat_byte(0x22),
+            tokenId: U256::MAX,
+            makerAmount: U256::from(50_000_000),
+            takerAmount: U256::from(100_000_000),
+            side: 0,
+            ..Order::default()
+        }
+    }
+
+    fn signed_order() -> SignedOrder {
+        SignedOrder {
+            order: order(),
+            signature: Signature::new(U256::from(1), U256::from(2), true),
+            order_type: OrderType::GTC,
+            owner: ApiKey::nil(),
+            post_only: Some(true),
+            client_order_id: Some("alpha-1".to_owned()),
+        }
+    }
+
+    #[test]
+    fn signed_order_should_round_trip_through_json() {
+        let json = serde_json::to_value(Stored(signed_order())).unwrap();
+
+        assert_eq!(json["version"], VERSION);
+        assert_eq!(
+            json["order"]["tokenId"],
+            U256::MAX.to_string(),
+            "256-bit integers should be decimal strings"
+        );
+        assert_eq!(json["clientOrderId"], "alpha-1");
+
+        let Stored(order): Stored<SignedOrder> = serde_json::from_value(json).unwrap();
+        assert_eq!(order, signed_order());
+    }
+
+    #[test]
+    fn signable_order_should_round_trip_through_json() {
+        let signable = SignableOrder {
+            order: order(),
+            order_type: OrderType::FOK,
+            post_only: None,
+            client_order_id: None,
+        };
+
+        let json = serde_json::to_value(Stored(signable.clone())).unwrap();
+        assert!(json.get("signature").is_none());
+
+        let Stored(order): Stored<SignableOrder> = serde_json::from_value(json).unwrap();
+        assert_eq!(order, signable);
+    }
+
+    #[test]
+    fn deserialize_should_reject_mismatched_kinds() {
+        let signed = serde_json::to_value(Stored(signed_order())).unwrap();
+        serde_json::from_value::<Stored<SignableOrder>>(signed.clone()).unwrap_err();
+
+        let mut unsigned = signed;
+        unsigned.as_object_mut().unwrap().remove("signature");
+        let err = serde_json::from_value::<Stored<SignedOrder>>(unsigned).unwrap_err();
+        assert!(err.to_string().contains("signature"), "{err}");
+    }
+
+    #[test]
+    fn deserialize_should_reject_newer_versions() {
+        let mut json = serde_json::to_value(Stored(signed_order())).unwrap();
+        json["version"] = json!(VERSION + 1);
+
+        let err = serde_json::from_value::<Stored<SignedOrder>>(json).unwrap_err();
+        assert!(err.to_string().contains("unsupported"), "{err}");
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn signed_order_should_round_trip_through_cbor() -> crate::Result<()> {
+        let bytes = Stored(signed_order()).to_cbor()?;
+        let Stored(order) = Stored::<SignedOrder>::from_cbor(&bytes)?;
+
+        assert_eq!(order, signed_order());
+
+        Ok(())
+    }
+}
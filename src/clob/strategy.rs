@@ -0,0 +1,545 @@
+//! Trading strategies.
+//!
+//! A [`Strategy`] reacts to order books, prices, fills, timer ticks and, with the `rfq` feature,
+//! RFQ requests. The same strategy can trade live, replay recorded market data, or be
+//! [backtested](crate::clob::backtest) on price history.
+//!
+//! A [`Runner`], created via [`Client::strategy_runner`](crate::clob::Client::strategy_runner),
+//! polls these sources at the intervals of its [`Config`] and calls the strategy for every event
+//! until the strategy calls [`Context::stop`] or returns an error:
+//!
+//! - Books of the configured tokens are fetched via
+//!   [`Client::order_books`](crate::clob::Client::order_books) and passed to
+//!   [`Strategy::on_book`].
+//! - Trades of the account matched after the runner started are fetched via
+//!   [`Client::stream_trades`](crate::clob::Client::stream_trades) and passed to
+//!   [`Strategy::on_fill`], once per trade.
+//! - If a timer interval is configured, [`Strategy::on_timer`] is called at that interval.
+//! - If an RFQ interval is configured, active RFQ requests are fetched via
+//!   [`Client::requests`](crate::clob::Client::requests) and passed to
+//!   [`Strategy::on_rfq_request`], once per request.
+//!
+//! [`Runner::run_on`] instead drives the strategy with any stream of [`Event`]s, e.g. market data
+//! recorded with [`Client::record_market_data`](crate::clob::Client::record_market_data) and
+//! replayed with [`playback::replay`](crate::clob::playback::replay), whose records convert into
+//! events.
+//!
+//! Strategies trade through the [`Context`] passed to every call, whose
+//! [`post_order`](Context::post_order) and [`cancel_order`](Context::cancel_order) go through the
+//! client, so the client's [`risk`](crate::clob::risk) engine and
+//! [`hooks`](crate::clob::hooks) still apply.
+//!
+//! Every event, including posted orders, cancellations and failed polls, is also published on
+//! the runner's event bus, which any number of observers, e.g. loggers or dashboards, can
+//! [`subscribe`](Runner::subscribe) to. Failed polls are not passed to the strategy and are
+//! retried at the next interval.
+//!
+//! ```rust,no_run
+//! use async_trait::async_trait;
+//! use polymarket_client_sdk::Result;
+//! use polymarket_client_sdk::clob::Client;
+//! use polymarket_client_sdk::clob::strategy::{Config, Context, Strategy};
+//! use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+//! use polymarket_client_sdk::types::TokenId;
+//!
+//! struct Spread;
+//!
+//! #[async_trait]
+//! impl Strategy for Spread {
+//!     async fn on_book(&mut self, ctx: &Context<'_>, book: &OrderBookSummaryResponse) -> Result<()> {
+//!         println!("{}: {} bids, {} asks", book.asset_id, book.bids.len(), book.asks.len());
+//!         ctx.stop();
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # async fn example(client: Client<polymarket_client_sdk::auth::state::Authenticated<polymarket_client_sdk::auth::Normal>>, token_id: TokenId) -> Result<()> {
+//! let config = Config::builder().token_ids(vec![token_id]).build();
+//! client.strategy_runner(config).run(&mut Spread).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::pin::{Pin, pin};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use async_stream::stream;
+use async_trait::async_trait;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream, StreamExt as _};
+use tokio::sync::broadcast;
+use tokio::time;
+
+use crate::auth::state::Authenticated;
+use crate::auth::{Kind, Normal};
+use crate::clob::Client;
+use crate::clob::backtest::{Position, Simulation};
+use crate::clob::recorder::{Data, Record};
+use crate::clob::risk::Engine as RiskEngine;
+#[cfg(feature = "rfq")]
+use crate::clob::types::RfqState;
+use crate::clob::types::SignedOrder;
+#[cfg(feature = "rfq")]
+use crate::clob::types::request::RfqRequestsRequest;
+use crate::clob::types::request::{OrderBookSummaryRequest, TradesRequest};
+#[cfg(feature = "rfq")]
+use crate::clob::types::response::RfqRequest;
+use crate::clob::types::response::{
+    CancelOrdersResponse, OrderBookSummaryResponse, PostOrderResponse, TradeResponse,
+};
+use crate::types::{Decimal, Timestamp, TokenId};
+use crate::{Error, Result};
+
+/// A trading strategy driven by a [`Runner`].
+///
+/// Every method does nothing by default, so strategies only implement the events they react to.
+/// Returning an error stops the runner, which returns the error.
+#[async_trait]
+pub trait Strategy<K: Kind = Normal>: Send {
+    /// Called with every fetched book of the configured tokens.
+    async fn on_book(
+        &mut self,
+        _ctx: &Context<'_, K>,
+        _book: &OrderBookSummaryResponse,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with every observed price, e.g. a recorded midpoint or a point of price history.
+    async fn on_price(&mut self, _ctx: &Context<'_, K>, _price: &Price) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called once with every trade of the account matched after the runner started.
+    async fn on_fill(&mut self, _ctx: &Context<'_, K>, _trade: &TradeResponse) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called at the configured timer interval.
+    async fn on_timer(&mut self, _ctx: &Context<'_, K>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called once with every active RFQ request.
+    ///
+    /// # Note
+    ///
+    /// Requires the `rfq` feature to be enabled.
+    #[cfg(feature = "rfq")]
+    async fn on_rfq_request(&mut self, _ctx: &Context<'_, K>, _request: &RfqRequest) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A price observed for a token.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Price {
+    /// The token the price is for.
+    pub token_id: TokenId,
+    /// The price, e.g. a midpoint or the price of the last trade.
+    pub price: Decimal,
+    /// When the price was observed.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// An event published on a [`Runner`]'s event bus.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub enum Event {
+    /// A book was fetched.
+    Book(OrderBookSummaryResponse),
+    /// A price was observed.
+    Price(Price),
+    /// A trade of the account was matched.
+    Fill(TradeResponse),
+    /// The timer ticked.
+    Timer,
+    /// An active RFQ request was fetched.
+    #[cfg(feature = "rfq")]
+    RfqRequest(RfqRequest),
+    /// The strategy posted an order via [`Context::post_order`].
+    OrderPosted(PostOrderResponse),
+    /// The strategy canceled orders via [`Context::cancel_order`], with the IDs of the canceled
+    /// orders.
+    OrdersCanceled(Vec<String>),
+    /// A poll failed and is retried at the next interval.
+    Error(Arc<Error>),
+}
+
+impl From<Record> for Event {
+    /// Converts a recorded book into [`Event::Book`], and a recorded midpoint or last trade price
+    /// into [`Event::Price`].
+    fn from(record: Record) -> Self {
+        let price = |price| {
+            Self::Price(Price {
+                token_id: record.token_id,
+                price,
+                timestamp: record.timestamp,
+            })
+        };
+
+        match record.data {
+            Data::Book { book } => Self::Book(book),
+            Data::Midpoint { price: midpoint } => price(midpoint),
+            Data::LastTrade { price: last, .. } => price(last),
+        }
+    }
+}
+
+/// Configuration for [`Client::strategy_runner`](crate::clob::Client::strategy_runner).
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// The tokens whose books are passed to [`Strategy::on_book`]. The default is none.
+    #[builder(default)]
+    token_ids: Vec<TokenId>,
+    /// How often the books are fetched. The default is one (1) second.
+    #[builder(default = Duration::from_secs(1))]
+    book_interval: Duration,
+    /// How often the account's trades are fetched. The default is one (1) second.
+    #[builder(default = Duration::from_secs(1))]
+    fill_interval: Duration,
+    /// How often [`Strategy::on_timer`] is called. If unset, it is never called.
+    timer_interval: Option<Duration>,
+    /// How often active RFQ requests are fetched. If unset, they are never fetched.
+    #[cfg(feature = "rfq")]
+    rfq_interval: Option<Duration>,
+    /// How many events the event bus buffers for each subscriber before the oldest are dropped.
+    /// The default is 1024.
+    #[builder(default = 1024)]
+    event_capacity: usize,
+}
+
+/// Where the orders of a [`Context`] go.
+#[derive(Debug)]
+enum Venue<'client, K: Kind> {
+    /// The exchange, via the client
+    Live(&'client Client<Authenticated<K>>),
+    /// A backtest, see [`backtest`](crate::clob::backtest)
+    Simulated(&'client Mutex<Simulation>),
+}
+
+/// What a [`Strategy`] is called with, giving it access to the client and the runner.
+#[derive(Debug)]
+pub struct Context<'client, K: Kind = Normal> {
+    venue: Venue<'client, K>,
+    events: &'client broadcast::Sender<Event>,
+    stopped: &'client AtomicBool,
+}
+
+impl<'client, K: Kind> Context<'client, K> {
+    /// Creates a context whose orders are filled by `simulation`.
+    pub(crate) const fn simulated(
+        simulation: &'client Mutex<Simulation>,
+        events: &'client broadcast::Sender<Event>,
+        stopped: &'client AtomicBool,
+    ) -> Self {
+        Self {
+            venue: Venue::Simulated(simulation),
+            events,
+            stopped,
+        }
+    }
+
+    /// Returns the client the runner was created from, e.g. to query the API, or `None` in a
+    /// [`backtest`](crate::clob::backtest).
+    #[must_use]
+    pub const fn client(&self) -> Option<&'client Client<Authenticated<K>>> {
+        match self.venue {
+            Venue::Live(client) => Some(client),
+            Venue::Simulated(_) => None,
+        }
+    }
+
+    /// Returns the client's risk engine, if one is configured, e.g. to check the current limits.
+    #[must_use]
+    pub fn risk(&self) -> Option<&'client RiskEngine> {
+        self.client().and_then(Client::risk)
+    }
+
+    /// Returns the simulated position in a [`backtest`](crate::clob::backtest), or `None` when
+    /// trading live.
+    #[must_use]
+    pub fn simulated_position(&self) -> Option<Position> {
+        match self.venue {
+            Venue::Live(_) => None,
+            Venue::Simulated(simulation) => Some(
+                simulation
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .position(),
+            ),
+        }
+    }
+
+    /// Posts `order` via [`Client::post_order`], or fills it in a
+    /// [`backtest`](crate::clob::backtest), and publishes [`Event::OrderPosted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is rejected before being sent or the request fails.
+    pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        let response = match self.venue {
+            Venue::Live(client) => client.post_order(order).await?.into_response(),
+            Venue::Simulated(simulation) => simulation
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .post(&order)?,
+        };
+        self.publish(Event::OrderPosted(response.clone()));
+        Ok(response)
+    }
+
+    /// Cancels the order with `order_id` via [`Client::cancel_order`] and publishes
+    /// [`Event::OrdersCanceled`]. Orders never rest in a [`backtest`](crate::clob::backtest), so
+    /// nothing is canceled there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        let response = match self.venue {
+            Venue::Live(client) => client.cancel_order(order_id).await?,
+            Venue::Simulated(_) => CancelOrdersResponse::builder()
+                .not_canceled(HashMap::from([(
+                    order_id.to_owned(),
+                    "orders do not rest in a backtest".to_owned(),
+                )]))
+                .build(),
+        };
+        self.publish(Event::OrdersCanceled(response.canceled.clone()));
+        Ok(response)
+    }
+
+    /// Stops the runner once the current call returns.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    fn publish(&self, event: Event) {
+        // Sending only fails if there are no subscribers, in which case nobody misses the event
+        _ = self.events.send(event);
+    }
+}
+
+type Source<'client> = Pin<Box<dyn Stream<Item = Result<Event>> + Send + 'client>>;
+
+/// Drives a [`Strategy`], see the [module docs](self).
+#[derive(Debug)]
+pub struct Runner<'client, K: Kind = Normal> {
+    client: &'client Client<Authenticated<K>>,
+    config: Config,
+    events: broadcast::Sender<Event>,
+}
+
+impl<'client, K: Kind> Runner<'client, K> {
+    pub(crate) fn new(client: &'client Client<Authenticated<K>>, config: Config) -> Self {
+        let (events, _) = broadcast::channel(config.event_capacity.max(1));
+        Self {
+            client,
+            config,
+            events,
+        }
+    }
+
+    /// Returns a receiver of every [`Event`] published from now on.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.events.subscribe()
+    }
+
+    /// Runs `strategy` on the polled sources until it calls [`Context::stop`] or returns an
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `strategy`.
+    pub async fn run<S: Strategy<K> + ?Sized>(&self, strategy: &mut S) -> Result<()> {
+        self.run_on(stream::select_all(self.sources()), strategy)
+            .await
+    }
+
+    /// Runs `strategy` on `events` instead of the polled sources, until it calls
+    /// [`Context::stop`], returns an error, or `events` ends. Failed events are published as
+    /// [`Event::Error`] and skipped.
+    ///
+    /// Replaying recorded market data through a strategy looks like this:
+    ///
+    /// ```rust,no_run
+    /// # use futures::TryStreamExt as _;
+    /// # use polymarket_client_sdk::clob::playback::{self, ReplayConfig};
+    /// # use polymarket_client_sdk::clob::strategy::{Event, Runner, Strategy};
+    /// # async fn example(runner: Runner<'_>, strategy: &mut impl Strategy) -> polymarket_client_sdk::Result<()> {
+    /// let files = playback::recorded_files("recordings", "market-data")?;
+    /// let records = playback::replay(ReplayConfig::builder().files(files).build());
+    /// runner.run_on(records.map_ok(Event::from), strategy).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `strategy`.
+    pub async fn run_on<E, S>(&self, events: E, strategy: &mut S) -> Result<()>
+    where
+        E: Stream<Item = Result<Event>>,
+        S: Strategy<K> + ?Sized,
+    {
+        let stopped = AtomicBool::new(false);
+        let ctx = Context {
+            venue: Venue::Live(self.client),
+            events: &self.events,
+            stopped: &stopped,
+        };
+
+        let mut events = pin!(events);
+        while let Some(event) = events.next().await {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    ctx.publish(Event::Error(Arc::new(e)));
+                    continue;
+                }
+            };
+            ctx.publish(event.clone());
+
+            match &event {
+                Event::Book(book) => strategy.on_book(&ctx, book).await?,
+                Event::Price(price) => strategy.on_price(&ctx, price).await?,
+                Event::Fill(trade) => strategy.on_fill(&ctx, trade).await?,
+                Event::Timer => strategy.on_timer(&ctx).await?,
+                #[cfg(feature = "rfq")]
+                Event::RfqRequest(request) => strategy.on_rfq_request(&ctx, request).await?,
+                Event::OrderPosted(_) | Event::OrdersCanceled(_) | Event::Error(_) => {}
+            }
+
+            if stopped.load(Ordering::Relaxed) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn sources(&self) -> Vec<Source<'client>> {
+        let mut sources: Vec<Source<'client>> =
+            vec![Box::pin(fills(self.client, self.config.fill_interval))];
+
+        if !self.config.token_ids.is_empty() {
+            sources.push(Box::pin(books(
+                self.client,
+                self.config.token_ids.clone(),
+                self.config.book_interval,
+            )));
+        }
+        if let Some(interval) = self.config.timer_interval {
+            sources.push(Box::pin(timer(interval)));
+        }
+        #[cfg(feature = "rfq")]
+        if let Some(interval) = self.config.rfq_interval {
+            sources.push(Box::pin(rfq_requests(self.client, interval)));
+        }
+
+        sources
+    }
+}
+
+fn books<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    token_ids: Vec<TokenId>,
+    interval: Duration,
+) -> impl Stream<Item = Result<Event>> + Send + '_ {
+    stream! {
+        let requests: Vec<OrderBookSummaryRequest> = token_ids
+            .iter()
+            .map(|token_id| OrderBookSummaryRequest::builder().token_id(*token_id).build())
+            .collect();
+
+        loop {
+            match client.order_books(&requests).await {
+                Ok(books) => {
+                    for book in books {
+                        yield Ok(Event::Book(book));
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
+
+fn fills<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    interval: Duration,
+) -> impl Stream<Item = Result<Event>> + Send + '_ {
+    stream! {
+        let started = Utc::now();
+        let request = TradesRequest::builder()
+            .after(Timestamp::from(started))
+            .build();
+        let mut seen: HashSet<String> = HashSet::new();
+
+        loop {
+            let mut trades = pin!(client.stream_trades(&request));
+            while let Some(trade) = trades.next().await {
+                match trade {
+                    // `after` only has second precision
+                    Ok(trade) if trade.match_time < started => {}
+                    Ok(trade) => {
+                        if seen.insert(trade.id.clone()) {
+                            yield Ok(Event::Fill(trade));
+                        }
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
+
+fn timer(interval: Duration) -> impl Stream<Item = Result<Event>> + Send {
+    stream! {
+        loop {
+            time::sleep(interval).await;
+            yield Ok(Event::Timer);
+        }
+    }
+}
+
+#[cfg(feature = "rfq")]
+fn rfq_requests<K: Kind>(
+    client: &Client<Authenticated<K>>,
+    interval: Duration,
+) -> impl Stream<Item = Result<Event>> + Send + '_ {
+    stream! {
+        let request = RfqRequestsRequest::builder().state(RfqState::Active).build();
+        let mut seen: HashSet<String> = HashSet::new();
+
+        loop {
+            match client.requests(&request, None).await {
+                Ok(page) => {
+                    for request in page.data {
+                        if seen.insert(request.request_id.clone()) {
+                            yield Ok(Event::RfqRequest(request));
+                        }
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Enabling and disabling order submission per token, market or event at runtime.
+//!
+//! A [`Switchboard`] set via [`Config`](crate::clob::Config)'s `switchboard` is consulted by
+//! [`Client::sign`](crate::clob::Client::sign), [`Client::post_order`](crate::clob::Client::post_order)
+//! and [`Client::post_orders`](crate::clob::Client::post_orders), which fail with a
+//! [`Validation`](crate::error::Validation) error for orders in a disabled [`Scope`]. Since every
+//! other way of submitting orders, e.g. a [`strategy`](crate::clob::strategy) or a scheduled order,
+//! goes through these, a single market can be taken out of trading during an incident without
+//! restarting, while the rest keep trading. Cancellations are never blocked.
+//!
+//! Markets and events are not part of an order, so tokens must be mapped to them with
+//! [`Switchboard::register_token`] for disabling a market or event to affect them. Disabling a
+//! token always works.
+//!
+//! ```rust
+//! use polymarket_client_sdk::clob::switchboard::{Scope, Switchboard};
+//! use polymarket_client_sdk::types::{B256, TokenId, U256};
+//!
+//! let (yes, other) = (TokenId::new(U256::from(1)), TokenId::new(U256::from(2)));
+//! let market = B256::repeat_byte(1);
+//! let switchboard = Switchboard::default();
+//! switchboard.register_token(yes, market, None);
+//!
+//! switchboard.disable(Scope::Market(market), "oracle dispute");
+//! assert!(!switchboard.is_enabled(yes));
+//! assert!(switchboard.is_enabled(other));
+//!
+//! switchboard.enable(&Scope::Market(market));
+//! assert!(switchboard.is_enabled(yes));
+//! ```
+
+use std::fmt;
+
+use dashmap::DashMap;
+
+use crate::Result;
+use crate::clob::types::SignedOrder;
+use crate::error::Error;
+use crate::types::{B256, TokenId};
+
+/// What order submission is disabled for.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// A single outcome token.
+    Token(TokenId),
+    /// Every token registered to a market (condition ID).
+    Market(B256),
+    /// Every token registered to an event.
+    Event(String),
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Token(token_id) => write!(f, "token {token_id}"),
+            Self::Market(market) => write!(f, "market {market}"),
+            Self::Event(event) => write!(f, "event {event}"),
+        }
+    }
+}
+
+/// The market and event a token belongs to.
+#[derive(Debug)]
+struct Listing {
+    market: B256,
+    event: Option<String>,
+}
+
+/// Enables and disables order submission per [`Scope`], see the [module docs](self).
+///
+/// Everything is enabled until disabled.
+#[derive(Debug, Default)]
+pub struct Switchboard {
+    listings: DashMap<TokenId, Listing>,
+    /// The disabled scopes and why they were disabled
+    disabled: DashMap<Scope, String>,
+}
+
+impl Switchboard {
+    /// Maps `token_id` to the `market` (condition ID) and `event` it belongs to, so that disabling
+    /// either disables the token.
+    pub fn register_token(&self, token_id: TokenId, market: B256, event: Option<String>) {
+        self.listings.insert(token_id, Listing { market, event });
+    }
+
+    /// Disables order submission for `scope`, replacing the reason if it is already disabled.
+    pub fn disable<R: Into<String>>(&self, scope: Scope, reason: R) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(%scope, "disabling order submission");
+
+        self.disabled.insert(scope, reason.into());
+    }
+
+    /// Enables order submission for `scope` again. Returns whether it was disabled.
+    ///
+    /// Tokens stay disabled while any other scope they belong to is disabled.
+    #[cfg_attr(
+        not(feature = "tracing"),
+        expect(
+            clippy::must_use_candidate,
+            reason = "Enabling a scope is done for its effect, the result is informational"
+        )
+    )]
+    pub fn enable(&self, scope: &Scope) -> bool {
+        #[cfg(feature = "tracing")]
+        tracing::info!(%scope, "enabling order submission");
+
+        self.disabled.remove(scope).is_some()
+    }
+
+    /// Enables every disabled scope.
+    pub fn enable_all(&self) {
+        self.disabled.clear();
+    }
+
+    /// Returns the disabled scopes and why they were disabled.
+    #[must_use]
+    pub fn disabled(&self) -> Vec<(Scope, String)> {
+        self.disabled
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect()
+    }
+
+    /// Returns whether orders for `token_id` may be submitted.
+    #[must_use]
+    pub fn is_enabled(&self, token_id: TokenId) -> bool {
+        self.check(token_id).is_ok()
+    }
+
+    /// Fails if `token_id`, or the market or event it is registered to, is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Validation`](crate::error::Validation) error naming the disabled scope and the
+    /// reason it was disabled.
+    pub fn check(&self, token_id: TokenId) -> Result<()> {
+        if self.disabled.is_empty() {
+            return Ok(());
+        }
+
+        let mut scopes = vec![Scope::Token(token_id)];
+        if let Some(listing) = self.listings.get(&token_id) {
+            scopes.push(Scope::Market(listing.market));
+            if let Some(event) = &listing.event {
+                scopes.push(Scope::Event(event.clone()));
+            }
+        }
+
+        for scope in scopes {
+            if let Some(reason) = self.disabled.get(&scope) {
+                return Err(Error::validation(format!(
+                    "Order submission is disabled for {scope}: {}",
+                    reason.value()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fails if any of `orders` is for a disabled token, see [`Self::check`].
+    pub(crate) fn check_orders(&self, orders: &[SignedOrder]) -> Result<()> {
+        orders
+            .iter()
+            .try_for_each(|order| self.check(TokenId::from(order.order.tokenId)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::U256;
+
+    fn token(id: u64) -> TokenId {
+        TokenId::new(U256::from(id))
+    }
+
+    #[test]
+    fn disabling_should_cover_registered_tokens() {
+        let switchboard = Switchboard::default();
+        let market = B256::repeat_byte(1);
+        switchboard.register_token(token(1), market, Some("election".to_owned()));
+        switchboard.register_token(token(2), B256::repeat_byte(2), Some("election".to_owned()));
+
+        switchboard.disable(Scope::Event("election".to_owned()), "incident");
+        assert!(!switchboard.is_enabled(token(1)));
+        assert!(!switchboard.is_enabled(token(2)));
+        assert!(switchboard.is_enabled(token(3)));
+
+        switchboard.disable(Scope::Market(market), "oracle dispute");
+        assert!(switchboard.enable(&Scope::Event("election".to_owned())));
+        assert!(switchboard.is_enabled(token(2)));
+
+        let err = switchboard.check(token(1)).unwrap_err();
+        assert!(err.to_string().contains("oracle dispute"), "{err}");
+    }
+
+    #[test]
+    fn enable_should_report_whether_scope_was_disabled() {
+        let switchboard = Switchboard::default();
+        assert!(!switchboard.enable(&Scope::Token(token(1))));
+
+        switchboard.disable(Scope::Token(token(1)), "fat finger");
+        assert_eq!(
+            switchboard.disabled(),
+            [(Scope::Token(token(1)), "fat finger".to_owned())]
+        );
+
+        switchboard.enable_all();
+        assert!(switchboard.is_enabled(token(1)));
+        assert!(switchboard.disabled().is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+//! Server clock synchronization and scheduled order submission.
+//!
+//! The CLOB only reports its time with second precision, so
+//! [`Client::sync_clock`](crate::clob::Client::sync_clock) samples it several times at different
+//! sub-second phases. Each sample bounds the offset between the server and local clocks to a
+//! window of one second plus the round trip time, and intersecting these windows narrows the
+//! estimate down to a few milliseconds.
+//!
+//! The resulting [`ServerClock`] can then be passed to
+//! [`Client::schedule_order`](crate::clob::Client::schedule_order) to post an order at a given
+//! server time, e.g. right as a market opens or a data release is published.
+
+use std::time::Duration;
+
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+use tokio::task::{self, JoinHandle};
+use tokio::time::{self, Instant};
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::{Authenticated, State};
+use crate::clob::types::SignedOrder;
+use crate::clob::types::response::PostOrderResponse;
+use crate::clob::{Client, OrderHandle};
+use crate::error::{Error, Kind as ErrorKind};
+
+/// How early a scheduled order stops sleeping and starts yielding until its deadline, to make up
+/// for the millisecond granularity of the timer.
+const SPIN_THRESHOLD: Duration = Duration::from_millis(2);
+
+/// Configuration for [`Client::sync_clock`](crate::clob::Client::sync_clock).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct SyncConfig {
+    /// How many times the server time is sampled. The default is ten (10).
+    #[builder(default = 10)]
+    samples: u32,
+    /// How long to wait between samples. This should not evenly divide a second, so that the
+    /// samples land at different sub-second phases. The default is 110 milliseconds.
+    #[builder(default = Duration::from_millis(110))]
+    spacing: Duration,
+}
+
+/// An estimate of the offset between the CLOB's clock and the local clock.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ServerClock {
+    /// How far the server clock is ahead of the local clock.
+    pub offset: TimeDelta,
+    /// How far the actual offset may deviate from `offset`, in either direction.
+    pub uncertainty: TimeDelta,
+}
+
+impl ServerClock {
+    /// Returns the current server time.
+    #[must_use]
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+
+    /// Converts a server time into the corresponding local time.
+    #[must_use]
+    pub fn to_local(&self, server_time: DateTime<Utc>) -> DateTime<Utc> {
+        server_time - self.offset
+    }
+}
+
+/// A single server time sample. Local times are in milliseconds, the server time in seconds.
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    sent: i64,
+    received: i64,
+    server: i64,
+}
+
+/// Estimates the clock offset as the intersection of the windows allowed by each sample.
+///
+/// The server read its clock at some local time `t` between `sent` and `received`, and the true
+/// server time at that instant was within `[server, server + 1s)`. The offset must therefore lie
+/// within `(server - received, server + 1s - sent)`.
+fn estimate(samples: &[Sample]) -> Result<ServerClock> {
+    if samples.is_empty() {
+        return Err(Error::validation(
+            "Unable to sync the server clock without samples",
+        ));
+    }
+
+    let lower = samples
+        .iter()
+        .map(|sample| sample.server * 1000 - sample.received)
+        .max()
+        .unwrap_or_default();
+    let upper = samples
+        .iter()
+        .map(|sample| sample.server * 1000 + 1000 - sample.sent)
+        .min()
+        .unwrap_or_default();
+
+    if lower > upper {
+        return Err(Error::validation(
+            "Server time samples are inconsistent, the local or server clock may have jumped",
+        ));
+    }
+
+    Ok(ServerClock {
+        offset: TimeDelta::milliseconds(lower + (upper - lower) / 2),
+        uncertainty: TimeDelta::milliseconds((upper - lower) / 2),
+    })
+}
+
+pub(crate) async fn sync<S: State>(client: &Client<S>, config: SyncConfig) -> Result<ServerClock> {
+    let mut samples = Vec::with_capacity(config.samples as usize);
+
+    for i in 0..config.samples {
+        if i > 0 {
+            time::sleep(config.spacing).await;
+        }
+
+        let sent = Utc::now().timestamp_millis();
+        let server = client.server_time().await?.as_secs();
+        let received = Utc::now().timestamp_millis();
+
+        samples.push(Sample {
+            sent,
+            received,
+            server,
+        });
+    }
+
+    estimate(&samples)
+}
+
+/// An order waiting to be posted at a scheduled time, see
+/// [`Client::schedule_order`](crate::clob::Client::schedule_order).
+///
+/// Dropping this handle does not cancel the order, use [`Self::cancel`] instead.
+#[derive(Debug)]
+pub struct ScheduledOrder {
+    at: DateTime<Utc>,
+    task: JoinHandle<Result<PostOrderResponse>>,
+}
+
+impl ScheduledOrder {
+    /// Returns the server time at which the order is posted.
+    #[must_use]
+    pub const fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+
+    /// Cancels the submission if the order has not been posted yet.
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+
+    /// Waits for the order to be posted and returns the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if posting the order fails, or if the submission was cancelled.
+    pub async fn result(self) -> Result<PostOrderResponse> {
+        self.task
+            .await
+            .map_err(|e| Error::with_source(ErrorKind::Internal, e))?
+    }
+}
+
+pub(crate) fn schedule<K: Kind>(
+    client: Client<Authenticated<K>>,
+    order: SignedOrder,
+    at: DateTime<Utc>,
+    clock: &ServerClock,
+) -> ScheduledOrder {
+    let delay = (clock.to_local(at) - Utc::now())
+        .to_std()
+        .unwrap_or_default();
+    let deadline = Instant::now() + delay;
+
+    let task = task::spawn(async move {
+        if let Some(wake) = deadline.checked_sub(SPIN_THRESHOLD) {
+            time::sleep_until(wake).await;
+        }
+        while Instant::now() < deadline {
+            task::yield_now().await;
+        }
+
+        client
+            .post_order(order)
+            .await
+            .map(OrderHandle::into_response)
+    });
+
+    ScheduledOrder { at, task }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_should_intersect_sample_windows() {
+        // The server ticked from second 100 to 101 between the first and second samples
+        let samples = [
+            Sample {
+                sent: 99_290,
+                received: 99_310,
+                server: 100,
+            },
+            Sample {
+                sent: 99_400,
+                received: 99_420,
+                server: 101,
+            },
+        ];
+
+        let clock = estimate(&samples).unwrap();
+
+        // The first sample allows (690, 1710), the second (1580, 2600)
+        assert_eq!(clock.offset, TimeDelta::milliseconds(1645));
+        assert_eq!(clock.uncertainty, TimeDelta::milliseconds(65));
+    }
+
+    #[test]
+    fn estimate_should_fail_on_inconsistent_samples() {
+        let samples = [
+            Sample {
+                sent: 0,
+                received: 10,
+                server: 100,
+            },
+            Sample {
+                sent: 5_000,
+                received: 5_010,
+                server: 100,
+            },
+        ];
+
+        estimate(&samples).unwrap_err();
+        estimate(&[]).unwrap_err();
+    }
+}
@@ -1,8 +1,11 @@
 use std::fmt;
+use std::str::FromStr;
 
 use alloy::core::sol;
-use alloy::primitives::{Signature, U256};
+use alloy::primitives::{Signature, U256, keccak256};
 use bon::Builder;
+use chrono::{DateTime, Utc};
+use rust_decimal::RoundingStrategy;
 use rust_decimal_macros::dec;
 use serde::ser::{Error as _, SerializeStruct as _};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
@@ -14,7 +17,7 @@ use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
 use crate::error::Error;
-use crate::types::Decimal;
+use crate::types::{Address, B256, Decimal};
 
 pub mod request;
 pub mod response;
@@ -178,8 +181,10 @@ impl AmountInner {
 pub struct Amount(pub(crate) AmountInner);
 
 impl Amount {
-    pub fn usdc(value: Decimal) -> Result<Amount> {
-        let normalized = value.normalize();
+    /// Creates an amount denominated in USDC, from either a [`Decimal`] or a
+    /// [`Usdc`](crate::types::Usdc).
+    pub fn usdc<D: Into<Decimal>>(value: D) -> Result<Amount> {
+        let normalized = value.into().normalize();
         if normalized.scale() > USDC_DECIMALS {
             return Err(Error::validation(format!(
                 "Unable to build Amount with {} decimal points, must be <= {USDC_DECIMALS}",
@@ -190,8 +195,10 @@ impl Amount {
         Ok(Amount(AmountInner::Usdc(normalized)))
     }
 
-    pub fn shares(value: Decimal) -> Result<Amount> {
-        let normalized = value.normalize();
+    /// Creates an amount denominated in shares, from either a [`Decimal`] or
+    /// [`Shares`](crate::types::Shares).
+    pub fn shares<D: Into<Decimal>>(value: D) -> Result<Amount> {
+        let normalized = value.into().normalize();
         if normalized.scale() > LOT_SIZE_SCALE {
             return Err(Error::validation(format!(
                 "Unable to build Amount with {} decimal points, must be <= {LOT_SIZE_SCALE}",
@@ -220,17 +227,7 @@ impl Amount {
 
 #[non_exhaustive]
 #[derive(
-    Clone,
-    Copy,
-    Display,
-    Debug,
-    Default,
-    Eq,
-    Ord,
-    PartialEq,
-    PartialOrd,
-    Serialize_repr,
-    Deserialize,
+    Clone, Copy, Display, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
 )]
 #[repr(u8)]
 pub enum SignatureType {
@@ -240,6 +237,48 @@ pub enum SignatureType {
     GnosisSafe = 2,
 }
 
+impl FromStr for SignatureType {
+    type Err = Error;
+
+    /// Parses a signature type from its numeric value or its name, ignoring case, dashes and
+    /// underscores, e.g. `2`, `GnosisSafe`, `gnosis-safe` or `safe`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s
+            .trim()
+            .to_ascii_lowercase()
+            .replace(['-', '_'], "")
+            .as_str()
+        {
+            "0" | "eoa" => Ok(Self::Eoa),
+            "1" | "proxy" => Ok(Self::Proxy),
+            "2" | "gnosissafe" | "safe" => Ok(Self::GnosisSafe),
+            _ => Err(Error::validation(format!("Unknown signature type {s}"))),
+        }
+    }
+}
+
+/// Accepts the numeric value the type serializes as, as well as any name accepted by
+/// [`SignatureType::from_str`].
+impl<'de> Deserialize<'de> for SignatureType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(u8),
+            Name(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(number) => number.to_string().parse(),
+            Raw::Name(name) => name.parse(),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
 /// RFQ state filter for queries.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
@@ -292,7 +331,7 @@ pub enum OrderStatusType {
     Live,
     #[serde(alias = "matched")]
     Matched,
-    #[serde(alias = "canceled")]
+    #[serde(alias = "canceled", alias = "CANCELLED", alias = "cancelled")]
     Canceled,
     #[serde(alias = "delayed")]
     Delayed,
@@ -303,6 +342,15 @@ pub enum OrderStatusType {
     Unknown(String),
 }
 
+impl OrderStatusType {
+    /// Whether an order in this status can no longer change, i.e. it has been fully matched,
+    /// canceled, or failed to match. Unknown statuses are not considered terminal.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Matched | Self::Canceled | Self::Unmatched)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Display, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -325,7 +373,7 @@ pub enum TradeStatusType {
 
 #[non_exhaustive]
 #[derive(
-    Clone, Debug, Default, Display, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+    Clone, Debug, Default, Display, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
 )]
 #[serde(rename_all = "UPPERCASE")]
 #[strum(serialize_all = "UPPERCASE")]
@@ -422,6 +470,94 @@ impl<'de> Deserialize<'de> for TickSize {
     }
 }
 
+/// How a price that does not fall on a tick is rounded onto the tick grid, see [`Price::round`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Rounds away from the other side of the book: down for buys and up for sells. The order
+    /// is never priced worse than requested, but may not cross the spread.
+    TowardPassive,
+    /// Rounds toward the other side of the book: up for buys and down for sells. The order is
+    /// more likely to cross the spread, at a price up to one tick worse than requested.
+    TowardAggressive,
+    /// Rounds to the nearest tick, with midpoints rounded away from zero.
+    Nearest,
+}
+
+/// An order price that is a multiple of its market's [`TickSize`] and lies within
+/// `[tick size, 1 - tick size]`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Price {
+    value: Decimal,
+    tick_size: TickSize,
+}
+
+impl Price {
+    /// Creates a price that must already be a multiple of `tick_size`.
+    pub fn new(value: Decimal, tick_size: TickSize) -> Result<Self> {
+        let minimum_tick_size = tick_size.as_decimal();
+        if value.normalize().scale() > minimum_tick_size.scale() {
+            return Err(Error::validation(format!(
+                "Price {value} is not a multiple of the minimum tick size {minimum_tick_size}"
+            )));
+        }
+
+        if value < minimum_tick_size || value > Decimal::ONE - minimum_tick_size {
+            return Err(Error::validation(format!(
+                "Price {value} is too small or too large for the minimum tick size {minimum_tick_size}"
+            )));
+        }
+
+        Ok(Self {
+            value: value.trunc_with_scale(minimum_tick_size.scale()),
+            tick_size,
+        })
+    }
+
+    /// Rounds `value` onto the tick grid of `tick_size` for an order on `side`, using `mode`.
+    pub fn round(
+        value: Decimal,
+        tick_size: TickSize,
+        side: Side,
+        mode: RoundingMode,
+    ) -> Result<Self> {
+        let strategy = match (mode, side) {
+            (RoundingMode::Nearest, _) => RoundingStrategy::MidpointAwayFromZero,
+            (RoundingMode::TowardPassive, Side::Buy)
+            | (RoundingMode::TowardAggressive, Side::Sell) => RoundingStrategy::ToNegativeInfinity,
+            (RoundingMode::TowardPassive, Side::Sell)
+            | (RoundingMode::TowardAggressive, Side::Buy) => RoundingStrategy::ToPositiveInfinity,
+            (_, side) => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+
+        let scale = tick_size.as_decimal().scale();
+        Self::new(value.round_dp_with_strategy(scale, strategy), tick_size)
+    }
+
+    #[must_use]
+    pub fn as_decimal(&self) -> Decimal {
+        self.value
+    }
+
+    #[must_use]
+    pub fn tick_size(&self) -> TickSize {
+        self.tick_size
+    }
+}
+
+impl From<Price> for Decimal {
+    fn from(price: Price) -> Self {
+        price.value
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
 sol! {
     /// Alloy solidity type representing an order in the context of the Polymarket exchange
     ///
@@ -471,6 +607,12 @@ pub struct SignableOrder {
     pub order_type: OrderType,
     #[serde(rename = "postOnly", skip_serializing_if = "Option::is_none")]
     pub post_only: Option<bool>,
+    /// A caller-chosen identifier for this order. It is not sent to the CLOB, but once the order
+    /// is posted it can be used in place of the exchange order ID, see
+    /// [`Client::exchange_order_id`](crate::clob::Client::exchange_order_id).
+    #[serde(skip)]
+    #[builder(into)]
+    pub client_order_id: Option<String>,
 }
 
 #[non_exhaustive]
@@ -481,6 +623,219 @@ pub struct SignedOrder {
     pub order_type: OrderType,
     pub owner: ApiKey,
     pub post_only: Option<bool>,
+    /// The caller-chosen identifier carried over from [`SignableOrder::client_order_id`].
+    #[builder(into)]
+    pub client_order_id: Option<String>,
+}
+
+impl SignedOrder {
+    /// Returns a key that uniquely identifies this signed order, derived from its signature.
+    ///
+    /// Since every order carries a client-generated salt, two orders share a key only if they are
+    /// the exact same signed order, e.g. when a request is retried. The CLOB does not accept an
+    /// idempotency header, so the key is used locally by [`Client::post_order`] to refuse
+    /// resubmissions when [`Config`]'s `order_dedup_window` is set.
+    ///
+    /// [`Client::post_order`]: crate::clob::Client::post_order
+    /// [`Config`]: crate::clob::Config
+    #[must_use]
+    pub fn idempotency_key(&self) -> B256 {
+        keccak256(self.signature.as_bytes())
+    }
+
+    /// Checks that this order can still be posted, e.g. after it was loaded from storage with
+    /// [`Stored`](crate::clob::storage::Stored) by a process other than the one that signed it.
+    ///
+    /// The checks mirror what the order builder guarantees for freshly built orders: the side and
+    /// signature type are known, the amounts and token ID are non-zero, the salt fits the CLOB's
+    /// JSON number and the [`TimeInForce`] resolves, with a GTD expiration that has not passed
+    /// yet. The signature itself is not verified, as that requires the EIP-712 domain the order
+    /// was signed for.
+    pub fn validate(&self) -> Result<()> {
+        let order = &self.order;
+
+        if !matches!(Side::try_from(order.side), Ok(Side::Buy | Side::Sell)) {
+            return Err(Error::validation(format!("Invalid side: {}", order.side)));
+        }
+        if order.signatureType > SignatureType::GnosisSafe as u8 {
+            return Err(Error::validation(format!(
+                "Invalid signature type: {}",
+                order.signatureType
+            )));
+        }
+        if order.tokenId.is_zero() {
+            return Err(Error::validation("Token ID must be non-zero"));
+        }
+        if order.makerAmount.is_zero() || order.takerAmount.is_zero() {
+            return Err(Error::validation(format!(
+                "Maker amount {} and taker amount {} must be non-zero",
+                order.makerAmount, order.takerAmount
+            )));
+        }
+        if u64::try_from(order.salt).is_err() {
+            return Err(Error::validation(format!(
+                "Salt {} does not fit into u64",
+                order.salt
+            )));
+        }
+
+        if let TimeInForce::GoodTilDate { expiration, .. } = self.time_in_force()?
+            && expiration <= Utc::now()
+        {
+            return Err(Error::validation(format!(
+                "GTD order expired at {expiration}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the resolved [`TimeInForce`] of this order, failing if its order type, expiration
+    /// and post-only flag contradict each other.
+    pub fn time_in_force(&self) -> Result<TimeInForce> {
+        TimeInForce::resolve(&self.order_type, self.order.expiration, self.post_only)
+    }
+}
+
+impl SignableOrder {
+    /// Returns the resolved [`TimeInForce`] of this order, failing if its order type, expiration
+    /// and post-only flag contradict each other. [`Client::sign`](crate::clob::Client::sign)
+    /// checks this before signing.
+    pub fn time_in_force(&self) -> Result<TimeInForce> {
+        TimeInForce::resolve(&self.order_type, self.order.expiration, self.post_only)
+    }
+}
+
+/// How long an order may rest on the book, resolved from its [`OrderType`], expiration and
+/// post-only flag.
+///
+/// Only GTC and GTD orders rest on the book and may thus be post-only, and only GTD orders have an
+/// expiration, which they require.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeInForce {
+    /// Rests on the book until it is filled or cancelled.
+    GoodTilCancelled {
+        /// Whether the order is rejected instead of crossing the spread.
+        post_only: bool,
+    },
+    /// Rests on the book until it is filled, cancelled or expires.
+    GoodTilDate {
+        /// When the order expires.
+        expiration: DateTime<Utc>,
+        /// Whether the order is rejected instead of crossing the spread.
+        post_only: bool,
+    },
+    /// Fills in full immediately, or not at all.
+    FillOrKill,
+    /// Fills as much as possible immediately, cancelling the rest.
+    FillAndKill,
+}
+
+impl TimeInForce {
+    /// Resolves the time in force of an order from its `order_type`, its `expiration` in Unix
+    /// seconds (zero for none) and its `post_only` flag.
+    pub fn resolve(
+        order_type: &OrderType,
+        expiration: U256,
+        post_only: Option<bool>,
+    ) -> Result<Self> {
+        let post_only = post_only.unwrap_or(false);
+        if !matches!(order_type, OrderType::GTD) && !expiration.is_zero() {
+            return Err(Error::validation(
+                "Only GTD orders may have a non-zero expiration",
+            ));
+        }
+        if post_only && !matches!(order_type, OrderType::GTC | OrderType::GTD) {
+            return Err(Error::validation(
+                "postOnly is only supported for GTC and GTD orders",
+            ));
+        }
+
+        match order_type {
+            OrderType::GTC => Ok(Self::GoodTilCancelled { post_only }),
+            OrderType::GTD => {
+                let expiration = i64::try_from(expiration)
+                    .ok()
+                    .filter(|seconds| *seconds > 0)
+                    .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+                    .ok_or_else(|| {
+                        Error::validation(format!(
+                            "GTD orders require a valid expiration, got {expiration}"
+                        ))
+                    })?;
+
+                Ok(Self::GoodTilDate {
+                    expiration,
+                    post_only,
+                })
+            }
+            OrderType::FOK => Ok(Self::FillOrKill),
+            OrderType::FAK => Ok(Self::FillAndKill),
+            OrderType::Unknown(order_type) => Err(Error::validation(format!(
+                "Unknown order type: {order_type}"
+            ))),
+        }
+    }
+
+    /// Returns the order type of this time in force.
+    #[must_use]
+    pub fn order_type(&self) -> OrderType {
+        match self {
+            Self::GoodTilCancelled { .. } => OrderType::GTC,
+            Self::GoodTilDate { .. } => OrderType::GTD,
+            Self::FillOrKill => OrderType::FOK,
+            Self::FillAndKill => OrderType::FAK,
+        }
+    }
+
+    /// Returns when the order expires, if it is a GTD order.
+    #[must_use]
+    pub fn expiration(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::GoodTilDate { expiration, .. } => Some(*expiration),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the order is post-only.
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::GoodTilCancelled { post_only } | Self::GoodTilDate { post_only, .. } => {
+                *post_only
+            }
+            Self::FillOrKill | Self::FillAndKill => false,
+        }
+    }
+
+    /// Returns whether the order may rest on the book, i.e. is GTC or GTD.
+    #[must_use]
+    pub fn is_resting(&self) -> bool {
+        matches!(
+            self,
+            Self::GoodTilCancelled { .. } | Self::GoodTilDate { .. }
+        )
+    }
+}
+
+/// Overrides for the EIP-712 domain used to sign orders, e.g. to sign against a staging
+/// deployment or a forked exchange. Fields left unset fall back to the production values.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Builder, PartialEq, Eq)]
+pub struct OrderDomain {
+    /// The domain name. Defaults to `Polymarket CTF Exchange`.
+    #[builder(into)]
+    pub name: Option<String>,
+    /// The domain version. Defaults to `1`.
+    #[builder(into)]
+    pub version: Option<String>,
+    /// The verifying contract for regular markets. Defaults to the exchange in
+    /// [`contract_config`](crate::contract_config) for the signer's chain.
+    pub verifying_contract: Option<Address>,
+    /// The verifying contract for neg-risk markets. Defaults to the neg-risk exchange in
+    /// [`contract_config`](crate::contract_config) for the signer's chain.
+    pub neg_risk_verifying_contract: Option<Address>,
 }
 
 /// Helper struct for serializing Order with signature injected.
@@ -569,6 +924,61 @@ mod tests {
         assert_eq!(TickSize::TenThousandth.as_decimal().scale(), 4);
     }
 
+    #[test]
+    fn status_types_should_deserialize_known_and_unknown_values() {
+        let status: OrderStatusType = serde_json::from_str(r#""CANCELLED""#).unwrap();
+        assert_eq!(status, OrderStatusType::Canceled);
+
+        let status: TradeStatusType = serde_json::from_str(r#""failed""#).unwrap();
+        assert_eq!(status, TradeStatusType::Failed);
+
+        let status: TradeStatusType = serde_json::from_str(r#""SETTLING""#).unwrap();
+        assert_eq!(status, TradeStatusType::Unknown("SETTLING".to_owned()));
+    }
+
+    #[test]
+    fn price_should_round_toward_requested_side() -> Result<()> {
+        let round = |side, mode| {
+            Price::round(dec!(0.455), TickSize::Hundredth, side, mode).map(|p| p.as_decimal())
+        };
+
+        assert_eq!(round(Side::Buy, RoundingMode::TowardPassive)?, dec!(0.45));
+        assert_eq!(
+            round(Side::Buy, RoundingMode::TowardAggressive)?,
+            dec!(0.46)
+        );
+        assert_eq!(round(Side::Sell, RoundingMode::TowardPassive)?, dec!(0.46));
+        assert_eq!(
+            round(Side::Sell, RoundingMode::TowardAggressive)?,
+            dec!(0.45)
+        );
+        assert_eq!(round(Side::Sell, RoundingMode::Nearest)?, dec!(0.46));
+
+        Ok(())
+    }
+
+    #[test]
+    fn price_should_validate_tick_size() {
+        let price = Price::new(dec!(0.50), TickSize::Tenth).unwrap();
+        assert_eq!(price.as_decimal(), dec!(0.5));
+        assert_eq!(price.tick_size(), TickSize::Tenth);
+
+        let err = Price::new(dec!(0.55), TickSize::Tenth).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Price 0.55 is not a multiple of the minimum tick size 0.1"
+        );
+
+        Price::new(dec!(0.99), TickSize::Tenth).unwrap_err();
+        Price::round(
+            dec!(0.001),
+            TickSize::Hundredth,
+            Side::Buy,
+            RoundingMode::TowardPassive,
+        )
+        .unwrap_err();
+    }
+
     #[test]
     fn tick_size_should_display() {
         assert_eq!(format!("{}", TickSize::Tenth), "Tenth(0.1)");
@@ -716,6 +1126,7 @@ mod tests {
             order_type: OrderType::GTC,
             owner: ApiKey::nil(),
             post_only: None,
+            client_order_id: None,
         };
 
         let value = to_value(&signed_order).expect("serialize SignedOrder");
@@ -725,4 +1136,64 @@ mod tests {
 
         assert!(!object.contains_key("postOnly"));
     }
+
+    #[test]
+    fn time_in_force_should_resolve_and_reject_contradictions() {
+        let gtc = TimeInForce::resolve(&OrderType::GTC, U256::ZERO, Some(true)).unwrap();
+        assert_eq!(gtc, TimeInForce::GoodTilCancelled { post_only: true });
+        assert!(gtc.is_post_only());
+
+        let gtd = TimeInForce::resolve(&OrderType::GTD, U256::from(1_700_000_000), None).unwrap();
+        assert_eq!(gtd.order_type(), OrderType::GTD);
+        assert_eq!(gtd.expiration(), DateTime::from_timestamp(1_700_000_000, 0));
+        assert!(!gtd.is_post_only());
+
+        let fak = TimeInForce::resolve(&OrderType::FAK, U256::ZERO, Some(false)).unwrap();
+        assert_eq!(fak, TimeInForce::FillAndKill);
+        assert!(!fak.is_resting());
+
+        TimeInForce::resolve(&OrderType::FOK, U256::ZERO, Some(true)).unwrap_err();
+        TimeInForce::resolve(&OrderType::GTC, U256::from(1_700_000_000), None).unwrap_err();
+        TimeInForce::resolve(&OrderType::GTD, U256::ZERO, None).unwrap_err();
+        TimeInForce::resolve(&OrderType::Unknown("IOC".to_owned()), U256::ZERO, None).unwrap_err();
+    }
+
+    #[test]
+    fn signed_order_validate_should_reject_contradictions() {
+        let valid = || SignedOrder {
+            order: Order {
+                salt: U256::from(1),
+                tokenId: U256::from(2),
+                makerAmount: U256::from(50),
+                takerAmount: U256::from(100),
+                ..Order::default()
+            },
+            signature: Signature::new(U256::ZERO, U256::ZERO, false),
+            order_type: OrderType::GTC,
+            owner: ApiKey::nil(),
+            post_only: Some(true),
+            client_order_id: None,
+        };
+        valid().validate().unwrap();
+
+        let mut order = valid();
+        order.order.side = 2;
+        order.validate().unwrap_err();
+
+        let mut order = valid();
+        order.order.takerAmount = U256::ZERO;
+        order.validate().unwrap_err();
+
+        let mut order = valid();
+        order.order.expiration = U256::from(Utc::now().timestamp() + 60);
+        order.validate().unwrap_err();
+        order.order_type = OrderType::GTD;
+        order.validate().unwrap();
+        order.order.expiration = U256::from(Utc::now().timestamp() - 60);
+        order.validate().unwrap_err();
+
+        let mut order = valid();
+        order.order_type = OrderType::FOK;
+        order.validate().unwrap_err();
+    }
 }
@@ -6,67 +6,64 @@
 use bon::Builder;
 use chrono::NaiveDate;
 use serde::{Serialize, Serializer};
-use serde_with::{
-    DisplayFromStr, StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none,
-};
+use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
 #[cfg(feature = "rfq")]
 use {
-    crate::clob::types::{RfqSortBy, RfqSortDir, RfqState},
-    crate::{Timestamp, auth::ApiKey, types::Decimal},
+    crate::clob::types::{RfqSortBy, RfqSortDir, RfqState, TickSize},
+    crate::error::Error,
+    crate::serde_helpers::CanonicalDecimal,
+    crate::types::{Decimal, U256},
+    crate::{UnixSeconds, auth::ApiKey},
+    serde_with::DisplayFromStr,
 };
 
-use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
-use crate::types::U256;
-use crate::types::{Address, B256};
+use crate::clob::types::response::TradeResponse;
+use crate::clob::types::{AssetType, Side, SignatureType, TimeRange, TraderSide};
+use crate::types::{Address, B256, Timestamp, TokenId};
 
-#[serde_as]
 #[non_exhaustive]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct MidpointRequest {
-    #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub token_id: TokenId,
 }
 
-#[serde_as]
 #[non_exhaustive]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct PriceRequest {
-    #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub token_id: TokenId,
     pub side: Side,
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct SpreadRequest {
-    #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub token_id: TokenId,
     pub side: Option<Side>,
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrderBookSummaryRequest {
-    #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub token_id: TokenId,
     pub side: Option<Side>,
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct LastTradePriceRequest {
-    #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub token_id: TokenId,
 }
 
 #[non_exhaustive]
@@ -87,18 +84,19 @@ pub struct PriceHistoryRequest {
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[derive(Debug, Default, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct CancelMarketOrderRequest {
     /// The market condition ID to cancel orders for.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub asset_id: Option<U256>,
+    #[builder(into)]
+    pub asset_id: Option<TokenId>,
 }
 
+/// Filters for the user's trades. The `side` and `trader_side` filters are not supported by the
+/// API, and are instead applied locally by `trades` and `stream_trades`, see
+/// [`TradesRequest::matches`].
 #[non_exhaustive]
-#[serde_as]
 #[derive(Debug, Default, Clone, Builder, Serialize)]
 #[builder(on(String, into))]
 pub struct TradesRequest {
@@ -109,14 +107,58 @@ pub struct TradesRequest {
     pub maker_address: Option<Address>,
     /// The market condition ID to filter trades.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub asset_id: Option<U256>,
-    pub before: Option<i64>,
-    pub after: Option<i64>,
+    #[builder(into)]
+    pub asset_id: Option<TokenId>,
+    /// Only trades matched before this time, sent with second precision.
+    #[builder(into)]
+    #[serde(serialize_with = "serialize_secs")]
+    pub before: Option<Timestamp>,
+    /// Only trades matched after this time, sent with second precision.
+    #[builder(into)]
+    #[serde(serialize_with = "serialize_secs")]
+    pub after: Option<Timestamp>,
+    /// Only trades in which the user bought or sold, applied locally.
+    #[serde(skip)]
+    pub side: Option<Side>,
+    /// Only trades in which the user was the maker or the taker, applied locally.
+    #[serde(skip)]
+    pub trader_side: Option<TraderSide>,
+}
+
+impl TradesRequest {
+    /// Returns whether `trade` passes the `side` and `trader_side` filters, which are not
+    /// supported by the API.
+    ///
+    /// The side is the user's: that of the trade if the user was the taker, and that of the
+    /// user's own maker orders otherwise.
+    #[must_use]
+    pub fn matches(&self, trade: &TradeResponse) -> bool {
+        self.side.is_none_or(|side| match trade.trader_side {
+            TraderSide::Maker => trade
+                .maker_orders
+                .iter()
+                .any(|order| order.owner == trade.owner && order.side == side),
+            _ => trade.side == side,
+        }) && self
+            .trader_side
+            .as_ref()
+            .is_none_or(|trader_side| trade.trader_side == *trader_side)
+    }
+}
+
+/// Serializes an optional [`Timestamp`] as unix seconds.
+#[expect(clippy::ref_option, reason = "Need an explicit reference for serde")]
+fn serialize_secs<S: Serializer>(
+    timestamp: &Option<Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match timestamp {
+        Some(timestamp) => serializer.serialize_some(&timestamp.as_secs()),
+        None => serializer.serialize_none(),
+    }
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[derive(Debug, Default, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrdersRequest {
@@ -124,28 +166,45 @@ pub struct OrdersRequest {
     pub order_id: Option<String>,
     /// The market condition ID to filter orders.
     pub market: Option<B256>,
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub asset_id: Option<U256>,
+    #[builder(into)]
+    pub asset_id: Option<TokenId>,
 }
 
+/// The notifications to delete via `delete_notifications`, either those with the given IDs or,
+/// with `all` set, every notification of the user.
 #[non_exhaustive]
 #[serde_as]
-#[derive(Debug, Default, Serialize, Builder)]
+#[derive(Clone, Debug, Default, Serialize, Builder)]
 pub struct DeleteNotificationsRequest {
+    /// The IDs of the notifications to delete, given as strings or numbers.
     #[serde(rename = "ids", skip_serializing_if = "Vec::is_empty")]
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
-    #[builder(default)]
+    #[builder(default, with = |ids: impl IntoIterator<Item = impl ToString>| {
+        ids.into_iter().map(|id| id.to_string()).collect()
+    })]
     pub notification_ids: Vec<String>,
+    /// Whether to delete every notification, looking up their IDs first. Overrides
+    /// `notification_ids`.
+    #[serde(skip)]
+    #[builder(default)]
+    pub all: bool,
+}
+
+impl DeleteNotificationsRequest {
+    /// Returns a request deleting every notification of the user.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::builder().all(true).build()
+    }
 }
 
 #[non_exhaustive]
-#[serde_as]
 #[derive(Debug, Default, Clone, Builder, Serialize)]
 #[builder(on(String, into))]
 pub struct BalanceAllowanceRequest {
     pub asset_type: AssetType,
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub token_id: Option<U256>,
+    #[builder(into)]
+    pub token_id: Option<TokenId>,
     pub signature_type: Option<SignatureType>,
 }
 
@@ -168,7 +227,7 @@ pub struct UserRewardsEarningRequest {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Asset {
     Usdc,
-    Asset(U256),
+    Asset(TokenId),
 }
 
 impl Serialize for Asset {
@@ -188,6 +247,7 @@ impl Serialize for Asset {
 /// Creates an RFQ Request to buy or sell outcome tokens.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Builder)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateRfqRequestRequest {
@@ -195,10 +255,16 @@ pub struct CreateRfqRequestRequest {
     pub asset_in: Asset,
     /// Token ID the Requester wants to give. "0" indicates USDC.
     pub asset_out: Asset,
-    /// Amount of asset to receive (in base units).
-    pub amount_in: Decimal,
-    /// Amount of asset to give (in base units).
-    pub amount_out: Decimal,
+    /// Amount of asset to receive in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub amount_in: U256,
+    /// Amount of asset to give in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub amount_out: U256,
     /// Signature type (`EOA`, `Proxy`, or `GnosisSafe`).
     pub user_type: SignatureType,
 }
@@ -214,6 +280,21 @@ pub struct CancelRfqRequestRequest {
     pub request_id: String,
 }
 
+/// Checks that an RFQ price filter lies within `[0, 1]` and has no more decimal places than the
+/// smallest [`TickSize`], so that it is never rejected by the API.
+#[cfg(feature = "rfq")]
+fn price_filter(price: Decimal) -> Result<Decimal, Error> {
+    let price = price.normalize();
+    let decimals = TickSize::TenThousandth.as_decimal().scale();
+    if price.is_sign_negative() || price > Decimal::ONE || price.scale() > decimals {
+        return Err(Error::validation(format!(
+            "Unable to filter by price {price}: must be within [0, 1] with at most {decimals} decimal places"
+        )));
+    }
+
+    Ok(price)
+}
+
 /// Query parameters for getting RFQ requests.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
@@ -238,17 +319,29 @@ pub struct RfqRequestsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub markets: Vec<B256>,
-    /// Minimum size in tokens.
+    /// Minimum size in tokens, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Shares::new(size).map(Decimal::from) })]
     pub size_min: Option<Decimal>,
-    /// Maximum size in tokens.
+    /// Maximum size in tokens, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Shares::new(size).map(Decimal::from) })]
     pub size_max: Option<Decimal>,
-    /// Minimum size in USDC.
+    /// Minimum size in USDC, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Usdc::new(size).map(Decimal::from) })]
     pub size_usdc_min: Option<Decimal>,
-    /// Maximum size in USDC.
+    /// Maximum size in USDC, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Usdc::new(size).map(Decimal::from) })]
     pub size_usdc_max: Option<Decimal>,
-    /// Minimum price.
+    /// Minimum price, within `[0, 1]` with at most four decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |price: Decimal| -> Result<_, Error> { price_filter(price) })]
     pub price_min: Option<Decimal>,
-    /// Maximum price.
+    /// Maximum price, within `[0, 1]` with at most four decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |price: Decimal| -> Result<_, Error> { price_filter(price) })]
     pub price_max: Option<Decimal>,
     /// Sort field.
     pub sort_by: Option<RfqSortBy>,
@@ -259,6 +352,7 @@ pub struct RfqRequestsRequest {
 /// Request body for creating an RFQ quote.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[builder(on(String, into))]
@@ -269,10 +363,16 @@ pub struct CreateRfqQuoteRequest {
     pub asset_in: Asset,
     /// Token ID the Quoter wants to give. "0" indicates USDC.
     pub asset_out: Asset,
-    /// Amount of asset to receive (in base units).
-    pub amount_in: Decimal,
-    /// Amount of asset to give (in base units).
-    pub amount_out: Decimal,
+    /// Amount of asset to receive in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub amount_in: U256,
+    /// Amount of asset to give in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub amount_out: U256,
     /// Signature type (`EOA`, `Proxy`, or `GnosisSafe`).
     pub user_type: SignatureType,
 }
@@ -316,17 +416,29 @@ pub struct RfqQuotesRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub markets: Vec<B256>,
-    /// Minimum size in tokens.
+    /// Minimum size in tokens, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Shares::new(size).map(Decimal::from) })]
     pub size_min: Option<Decimal>,
-    /// Maximum size in tokens.
+    /// Maximum size in tokens, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Shares::new(size).map(Decimal::from) })]
     pub size_max: Option<Decimal>,
-    /// Minimum size in USDC.
+    /// Minimum size in USDC, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Usdc::new(size).map(Decimal::from) })]
     pub size_usdc_min: Option<Decimal>,
-    /// Maximum size in USDC.
+    /// Maximum size in USDC, with at most six decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |size: Decimal| -> Result<_, Error> { crate::types::Usdc::new(size).map(Decimal::from) })]
     pub size_usdc_max: Option<Decimal>,
-    /// Minimum price.
+    /// Minimum price, within `[0, 1]` with at most four decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |price: Decimal| -> Result<_, Error> { price_filter(price) })]
     pub price_min: Option<Decimal>,
-    /// Maximum price.
+    /// Maximum price, within `[0, 1]` with at most four decimal places.
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |price: Decimal| -> Result<_, Error> { price_filter(price) })]
     pub price_max: Option<Decimal>,
     /// Sort field.
     pub sort_by: Option<RfqSortBy>,
@@ -348,13 +460,19 @@ pub struct AcceptRfqQuoteRequest {
     pub request_id: String,
     /// ID of the Quote being accepted.
     pub quote_id: String,
-    /// Maker's amount in base units.
-    pub maker_amount: Decimal,
-    /// Taker's amount in base units.
-    pub taker_amount: Decimal,
-    /// Outcome token ID.
+    /// Maker's amount in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub maker_amount: U256,
+    /// Taker's amount in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
     #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub taker_amount: U256,
+    /// Outcome token ID.
+    #[builder(into)]
+    pub token_id: TokenId,
     /// Maker's address.
     pub maker: Address,
     /// Signer's address.
@@ -391,13 +509,19 @@ pub struct ApproveRfqOrderRequest {
     pub request_id: String,
     /// ID of the Quote being approved.
     pub quote_id: String,
-    /// Maker's amount in base units.
-    pub maker_amount: Decimal,
-    /// Taker's amount in base units.
-    pub taker_amount: Decimal,
-    /// Outcome token ID.
+    /// Maker's amount in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
+    #[serde_as(as = "DisplayFromStr")]
+    #[builder(into)]
+    pub maker_amount: U256,
+    /// Taker's amount in base units. Accepts [`Usdc`](crate::types::Usdc) and
+    /// [`Shares`](crate::types::Shares).
     #[serde_as(as = "DisplayFromStr")]
-    pub token_id: U256,
+    #[builder(into)]
+    pub taker_amount: U256,
+    /// Outcome token ID.
+    #[builder(into)]
+    pub token_id: TokenId,
     /// Maker's address.
     pub maker: Address,
     /// Signer's address.
@@ -407,7 +531,7 @@ pub struct ApproveRfqOrderRequest {
     /// Order nonce.
     pub nonce: u64,
     /// Unix timestamp for order expiration.
-    pub expiration: Timestamp,
+    pub expiration: UnixSeconds,
     /// Order side (BUY or SELL).
     pub side: Side,
     /// Fee rate in basis points.
@@ -424,7 +548,7 @@ pub struct ApproveRfqOrderRequest {
 mod tests {
     use super::*;
     use crate::ToQueryParams as _;
-    use crate::types::b256;
+    use crate::types::{U256, b256};
 
     #[test]
     fn trades_request_as_params_should_succeed() {
@@ -444,6 +568,17 @@ mod tests {
             request.query_params(Some("1")),
             "?id=aa-bb&maker=0x0000000000000000000000000000000000000000&market=0x0000000000000000000000000000000000000000000000000000000000010000&asset_id=100&next_cursor=1"
         );
+
+        let request = TradesRequest::builder()
+            .after(Timestamp::from_secs(1_700_000_000))
+            .before(Timestamp::from_millis(1_700_000_060_500))
+            .side(Side::Buy)
+            .trader_side(TraderSide::Maker)
+            .build();
+        assert_eq!(
+            request.query_params(None),
+            "?before=1700000060&after=1700000000"
+        );
     }
 
     #[test]
@@ -474,6 +609,12 @@ mod tests {
 
         assert_eq!(empty_request.query_params(None), "");
         assert_eq!(request.query_params(None), "?ids=1%2C2");
+
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids([1_u64, 2])
+            .build();
+        assert_eq!(request.query_params(None), "?ids=1%2C2");
+        assert_eq!(DeleteNotificationsRequest::all().query_params(None), "");
     }
 
     #[test]
@@ -501,4 +642,45 @@ mod tests {
             "?date=-262143-01-01&order_by=&position=&no_competition=false&next_cursor=1"
         );
     }
+
+    #[cfg(feature = "rfq")]
+    #[test]
+    fn rfq_requests_request_as_params_should_be_canonical() -> crate::Result<()> {
+        use rust_decimal_macros::dec;
+
+        let request = RfqRequestsRequest::builder()
+            .size_min(dec!(10.500))?
+            .size_usdc_max(dec!(1000.000000))?
+            .price_min(dec!(0.0100))?
+            .price_max(Decimal::ONE)?
+            .build();
+        assert_eq!(
+            request.query_params(None),
+            "?sizeMin=10.5&sizeUsdcMax=1000&priceMin=0.01&priceMax=1"
+        );
+
+        let request = RfqQuotesRequest::builder()
+            .size_max(dec!(0.000001))?
+            .build();
+        assert_eq!(request.query_params(None), "?sizeMax=0.000001");
+
+        assert!(
+            RfqRequestsRequest::builder()
+                .size_min(dec!(0.0000001))
+                .is_err()
+        );
+        assert!(
+            RfqRequestsRequest::builder()
+                .size_usdc_min(dec!(-1))
+                .is_err()
+        );
+        assert!(
+            RfqQuotesRequest::builder()
+                .price_min(dec!(0.00001))
+                .is_err()
+        );
+        assert!(RfqQuotesRequest::builder().price_max(dec!(1.01)).is_err());
+
+        Ok(())
+    }
 }
@@ -3,11 +3,14 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use serde_with::{
     DefaultOnError, DefaultOnNull, NoneAsEmptyString, TimestampMilliSeconds, TimestampSeconds,
     TryFromInto, serde_as,
@@ -18,8 +21,20 @@ use uuid::Uuid;
 use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TradeStatusType, TraderSide};
+use crate::error::{Error, InsufficientBalance};
 use crate::serde_helpers::StringFromAny;
-use crate::types::{Address, B256, Decimal, U256};
+#[cfg(feature = "rfq")]
+use crate::types::Timestamp;
+use crate::types::{Address, B256, Decimal, Shares, TokenId, Usdc};
+
+/// The raw response to a request made with `Client::send_raw`.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct RawResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
 
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
@@ -31,7 +46,7 @@ pub struct MidpointResponse {
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct MidpointsResponse {
-    pub midpoints: HashMap<U256, Decimal>,
+    pub midpoints: HashMap<TokenId, Decimal>,
 }
 
 #[non_exhaustive]
@@ -44,7 +59,7 @@ pub struct PriceResponse {
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct PricesResponse {
-    pub prices: Option<HashMap<U256, HashMap<Side, Decimal>>>,
+    pub prices: Option<HashMap<TokenId, HashMap<Side, Decimal>>>,
 }
 
 #[non_exhaustive]
@@ -56,7 +71,7 @@ pub struct SpreadResponse {
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct SpreadsResponse {
-    pub spreads: Option<HashMap<U256, Decimal>>,
+    pub spreads: Option<HashMap<TokenId, Decimal>>,
 }
 
 #[non_exhaustive]
@@ -115,7 +130,7 @@ pub struct GeoblockResponse {
 pub struct OrderBookSummaryResponse {
     /// The market condition ID.
     pub market: B256,
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     #[serde_as(as = "TimestampMilliSeconds<String>")]
     pub timestamp: DateTime<Utc>,
     #[serde(default)]
@@ -147,6 +162,96 @@ impl OrderBookSummaryResponse {
 
         Ok(format!("{result:x}"))
     }
+
+    /// Returns the highest bid, if any.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<&OrderSummary> {
+        self.bids.iter().max_by_key(|level| level.price)
+    }
+
+    /// Returns the lowest ask, if any.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<&OrderSummary> {
+        self.asks.iter().min_by_key(|level| level.price)
+    }
+
+    /// Returns the midpoint between the best bid and ask, or `None` if either side is empty.
+    ///
+    /// Together with [`Self::spread`], [`Self::best_bid`] and [`Self::best_ask`], this derives
+    /// from one fetched book what [`Client::midpoint`](crate::clob::Client::midpoint),
+    /// [`Client::spread`](crate::clob::Client::spread) and
+    /// [`Client::price`](crate::clob::Client::price) would otherwise request separately.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+
+        Some(((bid.price + ask.price) / Decimal::TWO).normalize())
+    }
+
+    /// Returns the difference between the best ask and bid, or `None` if either side is empty.
+    #[must_use]
+    pub fn spread(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+
+        Some((ask.price - bid.price).normalize())
+    }
+
+    /// Merges `complement`, the book of the other outcome token of the same binary market, into
+    /// this book and returns the resulting synthetic book.
+    ///
+    /// Since one share of each outcome can always be merged into or split from one USDC, a bid
+    /// for the complement at `p` is equivalent to an ask for this token at `1 - p`, and an ask for
+    /// the complement at `p` to a bid at `1 - p`. The merged book aggregates both sides by price,
+    /// so that its best bid and ask and its depth reflect all of the liquidity available for this
+    /// token. Levels are ordered as returned by the CLOB, with the best price last.
+    ///
+    /// The merged book keeps this book's metadata, except that its hash is cleared and its
+    /// timestamp is the later of both books'.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `complement` is not the other token of the same market.
+    pub fn merge_complement(&self, complement: &Self) -> Result<Self> {
+        if complement.market != self.market || complement.asset_id == self.asset_id {
+            return Err(Error::validation(format!(
+                "Unable to merge the book of {} into {}, which are not complementary tokens of \
+                 the same market",
+                complement.asset_id, self.asset_id
+            )));
+        }
+
+        let merge = |levels: &[OrderSummary], complement_levels: &[OrderSummary]| {
+            let mut merged: BTreeMap<Decimal, Decimal> = BTreeMap::new();
+            for level in levels {
+                *merged.entry(level.price).or_default() += level.size;
+            }
+            for level in complement_levels {
+                *merged.entry(Decimal::ONE - level.price).or_default() += level.size;
+            }
+
+            merged
+                .into_iter()
+                .map(|(price, size)| OrderSummary { price, size })
+                .collect::<Vec<_>>()
+        };
+
+        let bids = merge(&self.bids, &complement.asks);
+        let mut asks = merge(&self.asks, &complement.bids);
+        asks.reverse();
+
+        Ok(Self {
+            market: self.market,
+            asset_id: self.asset_id,
+            timestamp: self.timestamp.max(complement.timestamp),
+            hash: None,
+            bids,
+            asks,
+            min_order_size: self.min_order_size,
+            neg_risk: self.neg_risk,
+            tick_size: self.tick_size,
+            last_trade_price: self.last_trade_price,
+        })
+    }
 }
 
 #[non_exhaustive]
@@ -167,7 +272,7 @@ pub struct LastTradePriceResponse {
 #[derive(Debug, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct LastTradesPricesResponse {
-    pub token_id: U256,
+    pub token_id: TokenId,
     pub price: Decimal,
     pub side: Side,
 }
@@ -235,7 +340,7 @@ pub struct MarketResponse {
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct Token {
-    pub token_id: U256,
+    pub token_id: TokenId,
     pub outcome: String,
     pub price: Decimal,
     #[serde(default)]
@@ -305,6 +410,73 @@ pub struct PostOrderResponse {
     pub trade_ids: Vec<String>,
 }
 
+/// The outcome of posting an order, interpreting the `success`, `status` and `error_msg` fields
+/// of a [`PostOrderResponse`] together.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PostOrderOutcome {
+    /// The order rests on the book.
+    Live,
+    /// The order was matched on submission.
+    Matched,
+    /// The order is marketable, but matching it was delayed.
+    Delayed,
+    /// The order is marketable, but could not be matched.
+    Unmatched,
+    /// The order was canceled on submission, e.g. the unfilled remainder of a FAK order.
+    Canceled,
+    /// The order was accepted with a status this SDK does not know.
+    Unknown(String),
+    /// The order was rejected by the exchange.
+    Rejected {
+        /// The reason given by the exchange, which may be empty.
+        reason: String,
+    },
+}
+
+impl PostOrderResponse {
+    /// Returns whether the exchange accepted the order.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the ID assigned to the order, or `None` if the order was rejected before being
+    /// assigned one.
+    #[must_use]
+    pub fn order_id(&self) -> Option<&str> {
+        Some(self.order_id.as_str()).filter(|order_id| !order_id.is_empty())
+    }
+
+    /// Returns the outcome of the submission.
+    #[must_use]
+    pub fn outcome(&self) -> PostOrderOutcome {
+        if !self.success {
+            return PostOrderOutcome::Rejected {
+                reason: self.error_msg.clone().unwrap_or_default(),
+            };
+        }
+
+        match &self.status {
+            OrderStatusType::Live => PostOrderOutcome::Live,
+            OrderStatusType::Matched => PostOrderOutcome::Matched,
+            OrderStatusType::Delayed => PostOrderOutcome::Delayed,
+            OrderStatusType::Unmatched => PostOrderOutcome::Unmatched,
+            OrderStatusType::Canceled => PostOrderOutcome::Canceled,
+            OrderStatusType::Unknown(status) => PostOrderOutcome::Unknown(status.clone()),
+        }
+    }
+
+    /// Returns the parsed `error_msg` if the order was rejected for an insufficient balance or
+    /// allowance.
+    #[must_use]
+    pub fn insufficient_balance(&self) -> Option<InsufficientBalance> {
+        self.error_msg
+            .as_deref()
+            .and_then(InsufficientBalance::parse)
+    }
+}
+
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -329,7 +501,7 @@ pub struct OpenOrderResponse {
     pub maker_address: Address,
     /// The market condition ID.
     pub market: B256,
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     pub side: Side,
     pub original_size: Decimal,
     pub size_matched: Decimal,
@@ -361,6 +533,28 @@ pub struct CancelOrdersResponse {
     pub not_canceled: HashMap<String, String>,
 }
 
+/// The consolidated result of
+/// [`Client::cancel_orders_for_tokens`](crate::clob::Client::cancel_orders_for_tokens), merging
+/// the [`CancelOrdersResponse`]s of every token.
+#[non_exhaustive]
+#[derive(Debug, Default)]
+pub struct CancelTokensResponse {
+    /// The IDs of the canceled orders across all tokens.
+    pub canceled: Vec<String>,
+    /// The IDs of the orders that could not be canceled, with the reason.
+    pub not_canceled: HashMap<String, String>,
+    /// The tokens whose cancel request failed, whose orders may thus still be open.
+    pub failed: HashMap<TokenId, Error>,
+}
+
+impl CancelTokensResponse {
+    /// Returns whether every request succeeded and no order was left uncanceled.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.not_canceled.is_empty() && self.failed.is_empty()
+    }
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -370,7 +564,7 @@ pub struct TradeResponse {
     pub taker_order_id: String,
     /// The market condition ID.
     pub market: B256,
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     pub side: Side,
     pub size: Decimal,
     pub fee_rate_bps: Decimal,
@@ -395,8 +589,13 @@ pub struct TradeResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct NotificationResponse {
+    /// The notification ID, used to delete it via `delete_notifications`.
+    #[serde(default)]
+    #[serde_as(as = "Option<StringFromAny>")]
+    pub id: Option<String>,
     pub r#type: u32,
     pub owner: ApiKey,
     pub payload: NotificationPayload,
@@ -406,7 +605,7 @@ pub struct NotificationResponse {
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct NotificationPayload {
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     #[serde(rename = "eventSlug")]
@@ -435,6 +634,91 @@ pub struct NotificationPayload {
     pub order_type: OrderType,
 }
 
+/// Payload of a market resolution notification.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
+#[builder(on(String, into))]
+pub struct MarketResolvedPayload {
+    /// The market condition ID (unique market identifier).
+    pub condition_id: B256,
+    #[serde(default)]
+    pub market_slug: Option<String>,
+    #[serde(default)]
+    pub question: Option<String>,
+    /// The winning outcome, if reported.
+    #[serde(default)]
+    pub outcome: Option<String>,
+}
+
+/// The payload of a [`Notification`], parsed according to the notification's `type`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationKind {
+    /// One of the user's orders was cancelled (`type` 1).
+    OrderCancelled(NotificationPayload),
+    /// One of the user's orders was filled, fully or partially (`type` 2).
+    OrderFilled(NotificationPayload),
+    /// A market the user traded in was resolved (`type` 4).
+    MarketResolved(MarketResolvedPayload),
+    /// Unknown notification type, or a payload that does not match its type (captures the raw
+    /// value for debugging).
+    Unknown {
+        /// The raw notification type.
+        r#type: u32,
+        /// The raw notification payload.
+        payload: Value,
+    },
+}
+
+impl NotificationKind {
+    fn from_raw(r#type: u32, payload: Value) -> Self {
+        let parsed = match r#type {
+            1 => serde_json::from_value(payload.clone()).map(Self::OrderCancelled),
+            2 => serde_json::from_value(payload.clone()).map(Self::OrderFilled),
+            4 => serde_json::from_value(payload.clone()).map(Self::MarketResolved),
+            _ => return Self::Unknown { r#type, payload },
+        };
+
+        parsed.unwrap_or(Self::Unknown { r#type, payload })
+    }
+}
+
+/// A notification whose payload has been parsed into a typed [`NotificationKind`].
+///
+/// Unlike [`NotificationResponse`], deserializing a [`Notification`] never fails because of an
+/// unexpected payload shape; such payloads are captured as [`NotificationKind::Unknown`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(from = "RawNotification")]
+pub struct Notification {
+    /// The notification ID, used to delete it via `delete_notifications`.
+    pub id: Option<String>,
+    pub owner: ApiKey,
+    pub kind: NotificationKind,
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct RawNotification {
+    #[serde(default)]
+    #[serde_as(as = "Option<StringFromAny>")]
+    id: Option<String>,
+    r#type: u32,
+    owner: ApiKey,
+    #[serde(default)]
+    payload: Value,
+}
+
+impl From<RawNotification> for Notification {
+    fn from(raw: RawNotification) -> Self {
+        Self {
+            id: raw.id,
+            owner: raw.owner,
+            kind: NotificationKind::from_raw(raw.r#type, raw.payload),
+        }
+    }
+}
+
 #[non_exhaustive]
 #[allow(
     clippy::allow_attributes,
@@ -449,6 +733,32 @@ pub struct BalanceAllowanceResponse {
     pub allowances: HashMap<Address, String>,
 }
 
+impl BalanceAllowanceResponse {
+    /// Returns the balance as [`Usdc`], for responses to [`AssetType::Collateral`] requests.
+    ///
+    /// [`AssetType::Collateral`]: crate::clob::types::AssetType::Collateral
+    pub fn usdc(&self) -> Result<Usdc> {
+        Usdc::new(self.balance_decimal()?)
+    }
+
+    /// Returns the balance as [`Shares`], for responses to [`AssetType::Conditional`] requests.
+    ///
+    /// [`AssetType::Conditional`]: crate::clob::types::AssetType::Conditional
+    pub fn shares(&self) -> Result<Shares> {
+        Shares::new(self.balance_decimal()?)
+    }
+
+    /// The `balance` field is returned in base units, shift it to a human-readable value.
+    fn balance_decimal(&self) -> Result<Decimal> {
+        let mut balance = self.balance;
+        balance
+            .set_scale(balance.scale() + Usdc::DECIMALS)
+            .map_err(|e| Error::validation(format!("Invalid balance {}: {e}", self.balance)))?;
+
+        Ok(balance)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct OrderScoringResponse {
@@ -504,7 +814,7 @@ pub struct MakerOrder {
     pub matched_amount: Decimal,
     pub price: Decimal,
     pub fee_rate_bps: Decimal,
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     pub outcome: String,
     pub side: Side,
 }
@@ -657,7 +967,7 @@ pub struct BuilderTradeResponse {
     pub builder: Address,
     /// The market condition ID.
     pub market: B256,
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     pub side: Side,
     pub size: Decimal,
     pub size_usdc: Decimal,
@@ -673,7 +983,9 @@ pub struct BuilderTradeResponse {
     #[serde_as(as = "TimestampSeconds<String>")]
     pub match_time: DateTime<Utc>,
     pub bucket_index: u32,
+    /// The builder fee, as reported by the CLOB.
     pub fee: Decimal,
+    /// The builder fee in USDC, see [`Self::builder_fee`].
     pub fee_usdc: Decimal,
     #[serde(alias = "err_msg")]
     pub err_msg: Option<String>,
@@ -681,6 +993,38 @@ pub struct BuilderTradeResponse {
     pub updated_at: Option<DateTime<Utc>>,
 }
 
+impl BuilderTradeResponse {
+    /// Returns the fee the builder earned on this trade.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `fee_usdc` or `size_usdc` is negative.
+    pub fn builder_fee(&self) -> Result<BuilderFee> {
+        let amount = Usdc::new(self.fee_usdc.round_dp(Usdc::DECIMALS))?;
+        let notional = Usdc::new(self.size_usdc.round_dp(Usdc::DECIMALS))?;
+        let rate_bps = (!notional.as_decimal().is_zero())
+            .then(|| amount.as_decimal() / notional.as_decimal() * Decimal::from(10_000));
+
+        Ok(BuilderFee {
+            amount,
+            notional,
+            rate_bps,
+        })
+    }
+}
+
+/// The fee a builder earned on a trade, see [`BuilderTradeResponse::builder_fee`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuilderFee {
+    /// The fee, rounded to USDC precision.
+    pub amount: Usdc,
+    /// The USDC value of the trade the fee was charged on.
+    pub notional: Usdc,
+    /// The fee in basis points of `notional`, or `None` for trades without a value.
+    pub rate_bps: Option<Decimal>,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
@@ -713,8 +1057,8 @@ pub struct Page<T> {
 pub struct CreateRfqRequestResponse {
     /// Unique identifier for the created request.
     pub request_id: String,
-    /// Unix timestamp when the request expires.
-    pub expiry: i64,
+    /// When the request expires.
+    pub expiry: Timestamp,
 }
 
 /// Response from creating an RFQ quote.
@@ -763,9 +1107,9 @@ pub struct RfqRequest {
     /// Market condition ID.
     pub condition: B256,
     /// Token ID for the outcome token.
-    pub token: U256,
+    pub token: TokenId,
     /// Complement token ID.
-    pub complement: U256,
+    pub complement: TokenId,
     /// Order side (BUY or SELL).
     pub side: Side,
     /// Size of tokens to receive.
@@ -774,8 +1118,8 @@ pub struct RfqRequest {
     pub size_out: Decimal,
     /// Price for the request.
     pub price: Decimal,
-    /// Unix timestamp when the request expires.
-    pub expiry: i64,
+    /// When the request expires.
+    pub expiry: Timestamp,
 }
 
 /// An RFQ quote in the system.
@@ -796,9 +1140,9 @@ pub struct RfqQuote {
     /// Market condition ID.
     pub condition: B256,
     /// Token ID for the outcome token.
-    pub token: U256,
+    pub token: TokenId,
     /// Complement token ID.
-    pub complement: U256,
+    pub complement: TokenId,
     /// Order side (BUY or SELL).
     pub side: Side,
     /// Size of tokens to receive.
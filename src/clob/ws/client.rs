@@ -16,7 +16,7 @@ use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind as AuthKind, Normal};
 use crate::error::Error;
-use crate::types::{Address, B256, Decimal, U256};
+use crate::types::{Address, B256, Decimal, TokenId};
 use crate::ws::ConnectionManager;
 use crate::ws::config::Config;
 use crate::ws::connection::ConnectionState;
@@ -33,7 +33,7 @@ use crate::ws::connection::ConnectionState;
 /// use std::str::FromStr as _;
 ///
 /// use polymarket_client_sdk::clob::ws::Client;
-/// use polymarket_client_sdk::types::U256;
+/// use polymarket_client_sdk::types::TokenId;
 /// use futures::StreamExt;
 ///
 /// #[tokio::main]
@@ -41,7 +41,7 @@ use crate::ws::connection::ConnectionState;
 ///     // Create unauthenticated client
 ///     let client = Client::default();
 ///
-///     let stream = client.subscribe_orderbook(vec![U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548")?])?;
+///     let stream = client.subscribe_orderbook(vec![TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548")?])?;
 ///     let mut stream = Box::pin(stream);
 ///
 ///     while let Some(book) = stream.next().await {
@@ -152,7 +152,7 @@ impl<S: State> Client<S> {
     /// connection is not established.
     pub fn subscribe_orderbook(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<BookUpdate>>> {
         let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -181,7 +181,7 @@ impl<S: State> Client<S> {
     /// connection is not established.
     pub fn subscribe_last_trade_price(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<LastTradePrice>>> {
         let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -211,7 +211,7 @@ impl<S: State> Client<S> {
     /// connection is not established.
     pub fn subscribe_prices(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<PriceChange>>> {
         let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -240,7 +240,7 @@ impl<S: State> Client<S> {
     /// connection is not established.
     pub fn subscribe_tick_size_change(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<TickSizeChange>>> {
         let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -270,7 +270,7 @@ impl<S: State> Client<S> {
     /// connection is not established.
     pub fn subscribe_midpoints(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<MidpointUpdate>>> {
         let stream = self.subscribe_orderbook(asset_ids)?;
 
@@ -297,7 +297,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_best_bid_ask(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<BestBidAsk>>> {
         let stream = self
             .inner
@@ -319,7 +319,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_new_markets(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<NewMarket>>> {
         let stream = self
             .inner
@@ -341,7 +341,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_market_resolutions(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<MarketResolved>>> {
         let stream = self
             .inner
@@ -392,7 +392,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_orderbook(&self, asset_ids: &[U256]) -> Result<()> {
+    pub fn unsubscribe_orderbook(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.inner
             .unsubscribe_and_cleanup(ChannelType::Market, |subs| {
                 subs.unsubscribe_market(asset_ids)
@@ -403,7 +403,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_prices(&self, asset_ids: &[U256]) -> Result<()> {
+    pub fn unsubscribe_prices(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.unsubscribe_orderbook(asset_ids)
     }
 
@@ -411,7 +411,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_tick_size_change(&self, asset_ids: &[U256]) -> Result<()> {
+    pub fn unsubscribe_tick_size_change(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.unsubscribe_orderbook(asset_ids)
     }
 
@@ -419,7 +419,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_midpoints(&self, asset_ids: &[U256]) -> Result<()> {
+    pub fn unsubscribe_midpoints(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.unsubscribe_orderbook(asset_ids)
     }
 }
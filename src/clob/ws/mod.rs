@@ -14,8 +14,8 @@ pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
     BestBidAsk, BookUpdate, EventMessage, LastTradePrice, MakerOrder, MarketResolved,
-    MidpointUpdate, NewMarket, OrderMessage, OrderStatus, PriceChange, PriceChangeBatchEntry,
-    TickSizeChange, TradeMessage, WsMessage,
+    MidpointUpdate, NewMarket, OrderMessage, PriceChange, PriceChangeBatchEntry, TickSizeChange,
+    TradeMessage, WsMessage,
 };
 
 pub use crate::ws::WsError;
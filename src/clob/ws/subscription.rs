@@ -18,7 +18,7 @@ use super::types::request::SubscriptionRequest;
 use super::types::response::WsMessage;
 use crate::Result;
 use crate::auth::Credentials;
-use crate::types::{B256, U256};
+use crate::types::{B256, TokenId};
 use crate::ws::ConnectionManager;
 use crate::ws::WsError;
 use crate::ws::connection::ConnectionState;
@@ -28,7 +28,7 @@ use crate::ws::connection::ConnectionState;
 #[derive(Debug, Clone)]
 pub enum SubscriptionTarget {
     /// Subscribed to market data for specific assets.
-    Assets(Vec<U256>),
+    Assets(Vec<TokenId>),
     /// Subscribed to user events for specific markets.
     Markets(Vec<B256>),
 }
@@ -77,7 +77,7 @@ pub struct SubscriptionManager {
     active_subs: DashMap<String, SubscriptionInfo>,
     interest: Arc<InterestTracker>,
     /// Subscribed assets with reference counts (for multiplexing)
-    subscribed_assets: DashMap<U256, usize>,
+    subscribed_assets: DashMap<TokenId, usize>,
     /// Subscribed markets with reference counts (for multiplexing)
     subscribed_markets: DashMap<B256, usize>,
     last_auth: Arc<RwLock<Option<Credentials>>>,
@@ -146,7 +146,7 @@ impl SubscriptionManager {
     /// Re-send subscription requests for all tracked assets and markets.
     fn resubscribe_all(&self) {
         // Collect all subscribed assets
-        let assets: Vec<U256> = self.subscribed_assets.iter().map(|r| *r.key()).collect();
+        let assets: Vec<TokenId> = self.subscribed_assets.iter().map(|r| *r.key()).collect();
 
         if !assets.is_empty() {
             let custom_features = self.custom_features_enabled.load(Ordering::Relaxed);
@@ -198,7 +198,7 @@ impl SubscriptionManager {
     /// This will fail if `asset_ids` is empty.
     pub fn subscribe_market(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
         self.subscribe_market_with_options(asset_ids, false)
     }
@@ -211,7 +211,7 @@ impl SubscriptionManager {
     /// This will fail if `asset_ids` is empty.
     pub fn subscribe_market_with_options(
         &self,
-        asset_ids: Vec<U256>,
+        asset_ids: Vec<TokenId>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
         if asset_ids.is_empty() {
@@ -230,7 +230,7 @@ impl SubscriptionManager {
         }
 
         // Increment refcounts and determine which assets are truly new
-        let new_assets: Vec<U256> = asset_ids
+        let new_assets: Vec<TokenId> = asset_ids
             .iter()
             .filter_map(|id| match self.subscribed_assets.entry(*id) {
                 Entry::Occupied(mut o) => {
@@ -282,7 +282,7 @@ impl SubscriptionManager {
 
         // Create filtered stream with its own receiver
         let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<U256> = asset_ids.into_iter().collect();
+        let asset_ids_set: HashSet<TokenId> = asset_ids.into_iter().collect();
 
         Ok(try_stream! {
             loop {
@@ -445,7 +445,7 @@ impl SubscriptionManager {
     /// This decrements the reference count for each asset. Only sends an unsubscribe
     /// request to the server when the reference count reaches zero (no other streams
     /// are using that asset).
-    pub fn unsubscribe_market(&self, asset_ids: &[U256]) -> Result<()> {
+    pub fn unsubscribe_market(&self, asset_ids: &[TokenId]) -> Result<()> {
         if asset_ids.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for unsubscription"
@@ -2,7 +2,7 @@ use serde::Serialize;
 use serde_with::{DisplayFromStr, serde_as};
 use strum_macros::Display;
 
-use crate::types::{B256, U256};
+use crate::types::{B256, TokenId};
 use crate::ws::WithCredentials;
 
 #[non_exhaustive]
@@ -37,8 +37,7 @@ pub struct SubscriptionRequest {
     pub markets: Vec<B256>,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
-    #[serde_as(as = "Vec<DisplayFromStr>")]
-    pub asset_ids: Vec<U256>,
+    pub asset_ids: Vec<TokenId>,
     /// Request initial state dump
     #[serde(skip_serializing_if = "Option::is_none")]
     pub initial_dump: Option<bool>,
@@ -52,7 +51,7 @@ impl WithCredentials for SubscriptionRequest {}
 impl SubscriptionRequest {
     /// Create a market subscription request.
     #[must_use]
-    pub fn market(asset_ids: Vec<U256>) -> Self {
+    pub fn market(asset_ids: Vec<TokenId>) -> Self {
         Self {
             r#type: Channel::Market,
             operation: Some(Operation::Subscribe),
@@ -65,7 +64,7 @@ impl SubscriptionRequest {
 
     /// Create a market unsubscribe request.
     #[must_use]
-    pub fn market_unsubscribe(asset_ids: Vec<U256>) -> Self {
+    pub fn market_unsubscribe(asset_ids: Vec<TokenId>) -> Self {
         Self {
             r#type: Channel::Market,
             operation: Some(Operation::Unsubscribe),
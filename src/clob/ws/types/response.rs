@@ -6,10 +6,10 @@ use serde_with::{DefaultOnNull, DisplayFromStr, NoneAsEmptyString, serde_as};
 use tracing::warn;
 
 use crate::auth::ApiKey;
-use crate::clob::types::{OrderStatusType, Side, TraderSide};
+use crate::clob::types::{OrderStatusType, Side, TradeStatusType, TraderSide};
 use crate::clob::ws::interest::MessageInterest;
 use crate::error::Kind;
-use crate::types::{B256, Decimal, U256};
+use crate::types::{B256, Decimal, Timestamp, TokenId};
 
 /// Top-level WebSocket message wrapper.
 ///
@@ -70,7 +70,7 @@ impl WsMessage {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct BookUpdate {
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Market condition ID
     pub market: B256,
     /// Unix timestamp in milliseconds
@@ -115,7 +115,7 @@ pub struct PriceChange {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct PriceChangeBatchEntry {
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// New price
     pub price: Decimal,
     /// Total size affected by this price change (if provided)
@@ -140,7 +140,7 @@ pub struct PriceChangeBatchEntry {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct TickSizeChange {
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Market condition ID
     pub market: B256,
     /// Previous tick size
@@ -158,7 +158,7 @@ pub struct TickSizeChange {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct LastTradePrice {
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Market condition ID
     pub market: B256,
     /// Last trade price
@@ -184,7 +184,7 @@ pub struct BestBidAsk {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Current best bid price
     pub best_bid: Decimal,
     /// Current best ask price
@@ -215,7 +215,7 @@ pub struct NewMarket {
     pub description: String,
     /// List of asset IDs
     #[serde(rename = "assets_ids", alias = "asset_ids")]
-    pub asset_ids: Vec<U256>,
+    pub asset_ids: Vec<TokenId>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     pub outcomes: Vec<String>,
     /// Event message object
@@ -248,13 +248,13 @@ pub struct MarketResolved {
     pub description: Option<String>,
     /// List of asset IDs
     #[serde(rename = "assets_ids", alias = "asset_ids")]
-    pub asset_ids: Vec<U256>,
+    pub asset_ids: Vec<TokenId>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub outcomes: Vec<String>,
     /// Winning asset ID
-    pub winning_asset_id: U256,
+    pub winning_asset_id: TokenId,
     /// Winning outcome (e.g., "Yes" or "No")
     pub winning_outcome: String,
     /// Event message object
@@ -286,7 +286,7 @@ pub struct EventMessage {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct MakerOrder {
     /// Asset/token identifier of the maker order
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Amount of maker order matched in trade
     pub matched_amount: Decimal,
     /// Maker order ID
@@ -308,19 +308,6 @@ pub enum TradeMessageType {
     Unknown(String),
 }
 
-#[non_exhaustive]
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub enum TradeMessageStatus {
-    #[serde(alias = "matched", alias = "MATCHED")]
-    Matched,
-    #[serde(alias = "mined", alias = "MINED")]
-    Mined,
-    #[serde(alias = "confirmed", alias = "CONFIRMED")]
-    Confirmed,
-    #[serde(untagged)]
-    Unknown(String),
-}
-
 /// User trade execution message (authenticated channel only).
 #[non_exhaustive]
 #[serde_as]
@@ -331,7 +318,7 @@ pub struct TradeMessage {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Side of the trade
     pub side: Side,
     /// Size of the trade
@@ -339,22 +326,19 @@ pub struct TradeMessage {
     /// Execution price
     pub price: Decimal,
     /// Trade status
-    pub status: TradeMessageStatus,
+    pub status: TradeStatusType,
     /// Message type
     #[serde(rename = "type", default)]
     pub msg_type: Option<TradeMessageType>,
     /// Timestamp of last trade modification
     #[serde(default)]
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub last_update: Option<i64>,
+    pub last_update: Option<Timestamp>,
     /// Time trade was matched
     #[serde(default, alias = "match_time")]
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub matchtime: Option<i64>,
-    /// Unix timestamp of event
+    pub matchtime: Option<Timestamp>,
+    /// Time of the event
     #[serde(default)]
-    #[serde_as(as = "Option<DisplayFromStr>")]
-    pub timestamp: Option<i64>,
+    pub timestamp: Option<Timestamp>,
     /// Outcome (Yes/No)
     #[serde(default)]
     pub outcome: Option<String>,
@@ -405,7 +389,7 @@ pub struct OrderMessage {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Side of the order (BUY or SELL)
     pub side: Side,
     /// Order price
@@ -440,37 +424,13 @@ pub struct OrderMessage {
     pub status: Option<OrderStatusType>,
 }
 
-/// Order status for WebSocket order messages.
-#[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderStatus {
-    /// Order is open and active
-    Open,
-    /// Order has been matched with a counterparty
-    Matched,
-    /// Order has been partially filled
-    PartiallyFilled,
-    /// Order has been cancelled
-    Cancelled,
-    /// Order has been placed (initial status)
-    Placement,
-    /// Order update (partial match)
-    Update,
-    /// Order cancellation in progress
-    Cancellation,
-    /// Unknown order status from the API (captures the raw value for debugging).
-    #[serde(untagged)]
-    Unknown(String),
-}
-
 /// Calculated midpoint update (derived from orderbook).
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct MidpointUpdate {
     /// Asset/token identifier
-    pub asset_id: U256,
+    pub asset_id: TokenId,
     /// Market condition ID
     pub market: B256,
     /// Calculated midpoint price
@@ -578,7 +538,7 @@ mod tests {
         let msg: WsMessage = serde_json::from_str(json).unwrap();
         match msg {
             WsMessage::Book(book) => {
-                assert_eq!(book.asset_id, U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
+                assert_eq!(book.asset_id, TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
                 assert_eq!(book.market, TEST_MARKET);
                 assert_eq!(book.bids.len(), 1);
                 assert_eq!(book.asks.len(), 1);
@@ -606,7 +566,7 @@ mod tests {
             WsMessage::PriceChange(price) => {
                 let changes = &price.price_changes[0];
 
-                assert_eq!(changes.asset_id, U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
+                assert_eq!(changes.asset_id, TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
                 assert_eq!(changes.side, Side::Buy);
                 assert_eq!(changes.size.unwrap(), Decimal::TEN);
             }
@@ -650,12 +610,12 @@ mod tests {
                 let changes = &price.price_changes;
                 assert_eq!(changes.len(), 2);
 
-                assert_eq!(changes[0].asset_id, U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
+                assert_eq!(changes[0].asset_id, TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
                 assert_eq!(changes[0].best_bid, Some(dec!(0.11)));
                 assert_eq!(changes[0].price, dec!(0.10));
                 assert!(changes[0].size.is_none());
 
-                assert_eq!(changes[1].asset_id, U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
+                assert_eq!(changes[1].asset_id, TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap());
                 assert_eq!(changes[1].best_bid, None);
                 assert_eq!(changes[1].size, Some(dec!(5)));
                 assert_eq!(changes[1].price, dec!(0.90));
@@ -698,11 +658,11 @@ mod tests {
         assert_eq!(msgs.len(), 3);
 
         assert!(
-            matches!(&msgs[0], WsMessage::Book(b) if b.asset_id == U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap())
+            matches!(&msgs[0], WsMessage::Book(b) if b.asset_id == TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap())
         );
         assert!(matches!(&msgs[1], WsMessage::PriceChange(p) if p.market == TEST_MARKET));
         assert!(
-            matches!(&msgs[2], WsMessage::LastTradePrice(l) if l.asset_id == U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap())
+            matches!(&msgs[2], WsMessage::LastTradePrice(l) if l.asset_id == TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap())
         );
     }
 
@@ -843,7 +803,7 @@ mod tests {
                 assert_eq!(mr.winning_outcome, "Yes");
                 assert_eq!(
                     mr.winning_asset_id,
-                    U256::from_str("76043073756653678226373981964075571318267289248134717369284518995922789326425").unwrap()
+                    TokenId::from_str("76043073756653678226373981964075571318267289248134717369284518995922789326425").unwrap()
                 );
                 assert_eq!(mr.asset_ids.len(), 2);
             }
@@ -990,7 +950,7 @@ mod tests {
     fn matches_interest_custom_feature_messages() {
         let bba = WsMessage::BestBidAsk(BestBidAsk {
             market: TEST_MARKET,
-            asset_id: U256::from_str(
+            asset_id: TokenId::from_str(
                 "106585164761922456203746651621390029417453862034640469075081961934906147433548",
             )
             .unwrap(),
@@ -1025,7 +985,7 @@ mod tests {
             description: Some("d".to_owned()),
             asset_ids: vec![],
             outcomes: vec![],
-            winning_asset_id: U256::from_str(
+            winning_asset_id: TokenId::from_str(
                 "106585164761922456203746651621390029417453862034640469075081961934906147433548",
             )
             .unwrap(),
@@ -1138,7 +1098,7 @@ mod tests {
                 assert_eq!(nm.asset_ids.len(), 1);
                 assert_eq!(
                     nm.asset_ids[0],
-                    U256::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap()
+                    TokenId::from_str("106585164761922456203746651621390029417453862034640469075081961934906147433548").unwrap()
                 );
             }
             _ => panic!("Expected NewMarket message"),
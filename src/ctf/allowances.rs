@@ -0,0 +1,191 @@
+//! Exchange allowance monitoring.
+//!
+//! Orders are only accepted while the exchange contracts may transfer the maker's collateral
+//! (USDC) and outcome tokens. Fresh wallets have not granted these approvals yet, and migrated or
+//! compromised-then-cleaned wallets may have revoked them, in which case every order is rejected
+//! with an insufficient balance or allowance error.
+//!
+//! [`Client::watch_allowances`](super::Client::watch_allowances) repeatedly reads the collateral
+//! allowance and the outcome token approval that an owner granted to each spender, e.g. the
+//! [`exchange_spenders`] of a chain. It yields [`AllowanceEvent::Insufficient`] whenever one of
+//! them falls below the configured threshold or is revoked.
+//!
+//! [`Client::auto_approve_allowances`](super::Client::auto_approve_allowances) additionally
+//! resubmits the approval from the provider's wallet and yields [`AllowanceEvent::Approved`] once
+//! it is mined. Since an approval only changes the allowances of its sender, the owner must be the
+//! provider's default signer, i.e. an EOA account, and any other owner is rejected. Allowances of
+//! a proxy wallet or Safe must be approved through [`proxy`](super::proxy) or
+//! [`safe`](super::safe) transactions instead.
+
+use std::time::Duration;
+
+use alloy::primitives::ChainId;
+use alloy::providers::Provider;
+use async_stream::stream;
+use bon::Builder;
+use futures::Stream;
+use tokio::time;
+
+use super::Client;
+use crate::Result;
+use crate::contract_config;
+use crate::types::{Address, B256, U256};
+
+/// Configuration for [`Client::watch_allowances`](super::Client::watch_allowances).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Config {
+    /// How often the allowances are read. The default is sixty (60) seconds.
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+    /// The collateral allowance, in base units, below which an allowance is insufficient. The
+    /// default is one million (1,000,000) USDC.
+    #[builder(default = U256::from(1_000_000_000_000_u64))]
+    threshold: U256,
+}
+
+/// The token an allowance applies to.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Token {
+    /// The collateral (USDC), approved via ERC-20 `approve`.
+    Collateral,
+    /// The outcome tokens, approved via ERC-1155 `setApprovalForAll`.
+    Outcome,
+}
+
+/// An event of [`Client::watch_allowances`](super::Client::watch_allowances).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllowanceEvent {
+    /// The allowance of `spender` fell below the threshold or was revoked. Yielded once when the
+    /// allowance becomes insufficient, not on every read.
+    Insufficient {
+        /// The address the allowance was granted by.
+        owner: Address,
+        /// The address the allowance was granted to.
+        spender: Address,
+        /// The token the allowance applies to.
+        token: Token,
+        /// The remaining collateral allowance in base units, or zero (0) for a revoked outcome
+        /// token approval.
+        allowance: U256,
+    },
+    /// The approval of `spender` was resubmitted and mined.
+    Approved {
+        /// The address the allowance was granted by.
+        owner: Address,
+        /// The address the allowance was granted to.
+        spender: Address,
+        /// The token the allowance applies to.
+        token: Token,
+        /// The hash of the approval transaction.
+        transaction_hash: B256,
+        /// The block number where the approval was mined.
+        block_number: u64,
+    },
+}
+
+/// Returns the contracts that must be approved to trade on `chain_id`: the exchanges of regular
+/// and neg-risk markets, and the neg-risk adapter.
+#[must_use]
+pub fn exchange_spenders(chain_id: ChainId) -> Vec<Address> {
+    let mut spenders = Vec::new();
+    for config in [false, true]
+        .into_iter()
+        .filter_map(|is_neg_risk| contract_config(chain_id, is_neg_risk))
+    {
+        for spender in std::iter::once(config.exchange).chain(config.neg_risk_adapter) {
+            if !spenders.contains(&spender) {
+                spenders.push(spender);
+            }
+        }
+    }
+
+    spenders
+}
+
+pub(crate) fn watch<P: Provider + Clone>(
+    client: &Client<P>,
+    owner: Address,
+    spenders: Vec<Address>,
+    config: Config,
+    auto_approve: bool,
+) -> impl Stream<Item = Result<AllowanceEvent>> + '_ {
+    stream! {
+        // Whether each (spender, token) was insufficient on the last read, so that
+        // `Insufficient` is only yielded on the transition
+        let mut insufficient = vec![[false; 2]; spenders.len()];
+
+        loop {
+            for (spender, flags) in spenders.iter().copied().zip(insufficient.iter_mut()) {
+                for (token, flag) in [Token::Collateral, Token::Outcome].into_iter().zip(flags.iter_mut()) {
+                    let allowance = match token {
+                        Token::Collateral => client.collateral_allowance(owner, spender).await,
+                        Token::Outcome => client
+                            .is_approved_for_all(owner, spender)
+                            .await
+                            .map(|approved| if approved { U256::MAX } else { U256::ZERO }),
+                    };
+                    let allowance = match allowance {
+                        Ok(allowance) => allowance,
+                        Err(e) => {
+                            yield Err(e);
+                            continue;
+                        }
+                    };
+
+                    let threshold = match token {
+                        Token::Collateral => config.threshold,
+                        Token::Outcome => U256::from(1),
+                    };
+                    if allowance >= threshold {
+                        *flag = false;
+                        continue;
+                    }
+
+                    if !*flag {
+                        *flag = true;
+                        yield Ok(AllowanceEvent::Insufficient { owner, spender, token, allowance });
+                    }
+
+                    if auto_approve {
+                        let approval = match token {
+                            Token::Collateral => client.approve_collateral(spender, U256::MAX).await,
+                            Token::Outcome => client.set_approval_for_all(spender, true).await,
+                        };
+                        match approval {
+                            Ok(response) => {
+                                *flag = false;
+                                yield Ok(AllowanceEvent::Approved {
+                                    owner,
+                                    spender,
+                                    token,
+                                    transaction_hash: response.transaction_hash,
+                                    block_number: response.block_number,
+                                });
+                            }
+                            // Retried on the next read, as the allowance is still insufficient
+                            Err(e) => yield Err(e),
+                        }
+                    }
+                }
+            }
+
+            time::sleep(config.interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exchange_spenders_should_deduplicate() {
+        let spenders = exchange_spenders(crate::POLYGON);
+
+        assert_eq!(spenders.len(), 3);
+        assert!(exchange_spenders(1).is_empty());
+    }
+}
@@ -8,6 +8,15 @@
 //! - **Split**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merge**: Combine outcome token pairs back into USDC
 //! - **Redeem**: Redeem winning outcome tokens after market resolution
+//! - **Balances**: Read the collateral (USDC) balance of an address
+//! - **Approvals**: Read and grant exchange allowances, and watch them with the `polling`
+//!   feature
+//! - **Gas**: Price transactions from recent fee history, see [`gas`]
+//! - **Transactions**: Track nonces and replace stuck transactions, see [`transactions`](super::transactions)
+//! - **Safe**: Execute approvals and redemptions from a Gnosis Safe funder wallet, see
+//!   [`safe`]
+//! - **Proxy**: Execute approvals and redemptions from a Polymarket proxy wallet, see
+//!   [`proxy`](super::proxy)
 //!
 //! # Example
 //!
@@ -31,18 +40,33 @@
     reason = "Alloy sol! macro generates code that triggers these lints"
 )]
 
-use alloy::primitives::ChainId;
+use alloy::contract::SolCallBuilder;
+use alloy::primitives::{ChainId, TxHash, U256};
 use alloy::providers::Provider;
+#[cfg(feature = "polling")]
+use alloy::providers::WalletProvider;
+use alloy::signers::Signer;
 use alloy::sol;
+use alloy::sol_types::SolCall;
+#[cfg(feature = "polling")]
+use futures::Stream;
 
+#[cfg(feature = "polling")]
+use super::allowances::{self, AllowanceEvent, Config as AllowanceConfig};
 use super::error::CtfError;
+use super::gas::{self, Config as GasConfig, Fees as GasFees};
+use super::proxy::{Call as ProxyCall, IProxyWalletFactory};
+use super::safe::{self, ISafe, Transaction as SafeTransaction};
+use super::transactions::{Config as TransactionConfig, Manager};
 use super::types::{
-    CollectionIdRequest, CollectionIdResponse, ConditionIdRequest, ConditionIdResponse,
-    MergePositionsRequest, MergePositionsResponse, PositionIdRequest, PositionIdResponse,
+    ApprovalResponse, CollateralBalanceRequest, CollateralBalanceResponse, CollectionIdRequest,
+    CollectionIdResponse, ConditionIdRequest, ConditionIdResponse, MergePositionsRequest,
+    MergePositionsResponse, PositionIdRequest, PositionIdResponse, ProxyTransactionResponse,
     RedeemNegRiskRequest, RedeemNegRiskResponse, RedeemPositionsRequest, RedeemPositionsResponse,
-    SplitPositionRequest, SplitPositionResponse,
+    SafeTransactionResponse, SplitPositionRequest, SplitPositionResponse,
 };
-use crate::{Result, contract_config};
+use crate::types::Address;
+use crate::{Result, contract_config, wallet_contract_config};
 
 // CTF (Conditional Token Framework) contract interface
 //
@@ -112,6 +136,24 @@ sol! {
             bytes32 conditionId,
             uint256[] calldata indexSets
         ) external;
+
+        /// Returns whether `operator` may transfer all outcome tokens of `account`.
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+
+        /// Approves or revokes `operator` to transfer all outcome tokens of the sender.
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+
+    #[sol(rpc)]
+    interface IERC20 {
+        /// Returns the token balance of `account`.
+        function balanceOf(address account) external view returns (uint256);
+
+        /// Returns the amount `spender` may transfer on behalf of `owner`.
+        function allowance(address owner, address spender) external view returns (uint256);
+
+        /// Approves `spender` to transfer `value` tokens of the sender.
+        function approve(address spender, uint256 value) external returns (bool);
     }
 
     #[sol(rpc)]
@@ -131,8 +173,12 @@ sol! {
 #[derive(Clone, Debug)]
 pub struct Client<P: Provider> {
     contract: IConditionalTokens::IConditionalTokensInstance<P>,
+    collateral: IERC20::IERC20Instance<P>,
     neg_risk_adapter: Option<INegRiskAdapter::INegRiskAdapterInstance<P>>,
     provider: P,
+    chain_id: ChainId,
+    gas: Option<GasConfig>,
+    transactions: Option<Manager>,
 }
 
 impl<P: Provider + Clone> Client<P> {
@@ -154,11 +200,16 @@ impl<P: Provider + Clone> Client<P> {
         })?;
 
         let contract = IConditionalTokens::new(config.conditional_tokens, provider.clone());
+        let collateral = IERC20::new(config.collateral, provider.clone());
 
         Ok(Self {
             contract,
+            collateral,
             neg_risk_adapter: None,
             provider,
+            chain_id,
+            gas: None,
+            transactions: None,
         })
     }
 
@@ -183,6 +234,7 @@ impl<P: Provider + Clone> Client<P> {
         })?;
 
         let contract = IConditionalTokens::new(config.conditional_tokens, provider.clone());
+        let collateral = IERC20::new(config.collateral, provider.clone());
 
         let neg_risk_adapter = config
             .neg_risk_adapter
@@ -190,11 +242,102 @@ impl<P: Provider + Clone> Client<P> {
 
         Ok(Self {
             contract,
+            collateral,
             neg_risk_adapter,
             provider,
+            chain_id,
+            gas: None,
+            transactions: None,
         })
     }
 
+    /// Prices every transaction sent by this client from recent fee history as configured in
+    /// `config`, rather than leaving it to the provider, see [`gas`] for details.
+    #[must_use]
+    pub fn with_gas_config(mut self, config: GasConfig) -> Self {
+        self.gas = Some(config);
+        self
+    }
+
+    /// Sends every transaction of this client through a transaction manager that tracks nonces,
+    /// waits for receipts with a timeout, and replaces stuck transactions with higher fees, see
+    /// [`transactions`](super::transactions) for details.
+    #[must_use]
+    pub fn with_transaction_config(mut self, config: TransactionConfig) -> Self {
+        self.transactions = Some(Manager::new(config));
+        self
+    }
+
+    /// Returns the fees that would be offered for a transaction sent now, as configured in
+    /// `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fee history cannot be fetched, or if the fees cannot be
+    /// determined from it.
+    pub async fn gas_fees(&self, config: &GasConfig) -> Result<GasFees> {
+        gas::estimate(&self.provider, config).await
+    }
+
+    /// Applies the fees of the configured [`GasConfig`], if any, to `call`.
+    async fn priced<'client, C: SolCall>(
+        &self,
+        call: SolCallBuilder<&'client P, C>,
+    ) -> Result<SolCallBuilder<&'client P, C>> {
+        let Some(config) = &self.gas else {
+            return Ok(call);
+        };
+
+        let fees = self.gas_fees(config).await?;
+        Ok(call
+            .max_fee_per_gas(fees.max_fee_per_gas)
+            .max_priority_fee_per_gas(fees.max_priority_fee_per_gas))
+    }
+
+    /// Sends `call` and waits for its receipt, via the transaction manager if one is configured.
+    /// Returns the transaction hash and the number of the block it was included in.
+    async fn send<C: SolCall>(
+        &self,
+        call: SolCallBuilder<&P, C>,
+        action: &str,
+    ) -> Result<(TxHash, u64)> {
+        let receipt = if let Some(manager) = &self.transactions {
+            let fees = if let Some(config) = &self.gas {
+                self.gas_fees(config).await?
+            } else {
+                let estimate = self.provider.estimate_eip1559_fees().await.map_err(|e| {
+                    CtfError::ContractCall(format!("Failed to estimate {action} fees: {e}"))
+                })?;
+                GasFees {
+                    max_fee_per_gas: estimate.max_fee_per_gas,
+                    max_priority_fee_per_gas: estimate.max_priority_fee_per_gas,
+                }
+            };
+            let cap = self.gas.and_then(|config| config.max_fee_per_gas());
+
+            manager.send(call, fees, cap).await?
+        } else {
+            self.priced(call)
+                .await?
+                .send()
+                .await
+                .map_err(|e| {
+                    CtfError::ContractCall(format!("Failed to send {action} transaction: {e}"))
+                })?
+                .get_receipt()
+                .await
+                .map_err(|e| {
+                    CtfError::ContractCall(format!("Failed to get {action} receipt: {e}"))
+                })?
+        };
+
+        let block_number = receipt.block_number.ok_or_else(|| {
+            CtfError::ContractCall("Block number not available in receipt".to_owned())
+        })?;
+
+        Ok((receipt.transaction_hash, block_number))
+    }
+
     /// Calculates a condition ID.
     ///
     /// The condition ID is derived from the oracle address, question hash, and number of outcome slots.
@@ -283,6 +426,157 @@ impl<P: Provider + Clone> Client<P> {
         Ok(PositionIdResponse { position_id })
     }
 
+    /// Reads the collateral (USDC) balance of an address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(owner = %request.owner))
+    )]
+    pub async fn collateral_balance(
+        &self,
+        request: &CollateralBalanceRequest,
+    ) -> Result<CollateralBalanceResponse> {
+        let balance = self
+            .collateral
+            .balanceOf(request.owner)
+            .call()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to get collateral balance: {e}"))
+            })?;
+
+        Ok(CollateralBalanceResponse { balance })
+    }
+
+    /// Reads the amount of collateral (USDC) of `owner` that `spender` may transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    pub async fn collateral_allowance(&self, owner: Address, spender: Address) -> Result<U256> {
+        self.collateral
+            .allowance(owner, spender)
+            .call()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to get collateral allowance: {e}")).into()
+            })
+    }
+
+    /// Reads whether `operator` may transfer all outcome tokens of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    pub async fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool> {
+        self.contract
+            .isApprovedForAll(owner, operator)
+            .call()
+            .await
+            .map_err(|e| CtfError::ContractCall(format!("Failed to get approval: {e}")).into())
+    }
+
+    /// Approves `spender`, e.g. an exchange contract, to transfer `amount` of the collateral
+    /// (USDC) of the provider's wallet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(spender = %spender, amount = %amount))
+    )]
+    pub async fn approve_collateral(
+        &self,
+        spender: Address,
+        amount: U256,
+    ) -> Result<ApprovalResponse> {
+        let call = self.collateral.approve(spender, amount);
+        let (transaction_hash, block_number) = self.send(call, "approve").await?;
+
+        Ok(ApprovalResponse {
+            transaction_hash,
+            block_number,
+        })
+    }
+
+    /// Approves or revokes `operator`, e.g. an exchange contract, to transfer all outcome tokens
+    /// of the provider's wallet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(operator = %operator, approved))
+    )]
+    pub async fn set_approval_for_all(
+        &self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<ApprovalResponse> {
+        let call = self.contract.setApprovalForAll(operator, approved);
+        let (transaction_hash, block_number) = self.send(call, "approval").await?;
+
+        Ok(ApprovalResponse {
+            transaction_hash,
+            block_number,
+        })
+    }
+
+    /// Repeatedly reads the collateral allowances and outcome token approvals that `owner` granted
+    /// to `spenders`, yielding an [`AllowanceEvent`] whenever one becomes insufficient and, if
+    /// configured, after resubmitting the approval. See [`allowances`] for details.
+    #[cfg(feature = "polling")]
+    pub fn watch_allowances(
+        &self,
+        owner: Address,
+        spenders: Vec<Address>,
+        config: AllowanceConfig,
+    ) -> impl Stream<Item = Result<AllowanceEvent>> + '_ {
+        allowances::watch(self, owner, spenders, config, false)
+    }
+
+    /// Like [`Self::watch_allowances`], but also tops up insufficient collateral allowances to the
+    /// maximum and grants revoked outcome token approvals again, yielding
+    /// [`AllowanceEvent::Approved`] once the approval is mined. See [`allowances`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `owner` is not the provider's default signer, since approvals sent from
+    /// the provider's wallet would never change the allowances of `owner`.
+    #[cfg(feature = "polling")]
+    pub fn auto_approve_allowances(
+        &self,
+        owner: Address,
+        spenders: Vec<Address>,
+        config: AllowanceConfig,
+    ) -> Result<impl Stream<Item = Result<AllowanceEvent>> + '_>
+    where
+        P: WalletProvider,
+    {
+        let signer = self.provider.default_signer_address();
+        if owner != signer {
+            return Err(crate::error::Error::validation(format!(
+                "Unable to approve allowances of {owner} from the provider's signer {signer}, \
+                approve them through the owner's proxy wallet or Safe instead"
+            )));
+        }
+
+        Ok(allowances::watch(self, owner, spenders, config, true))
+    }
+
     /// Splits collateral into outcome tokens.
     ///
     /// Converts USDC collateral into matched outcome token pairs (YES/NO).
@@ -292,6 +586,8 @@ impl<P: Provider + Clone> Client<P> {
     /// Returns an error if:
     /// - The transaction fails to send
     /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
     /// - The wallet doesn't have sufficient collateral
     /// - The condition hasn't been prepared
     #[cfg_attr(
@@ -306,33 +602,18 @@ impl<P: Provider + Clone> Client<P> {
         &self,
         request: &SplitPositionRequest,
     ) -> Result<SplitPositionResponse> {
-        let pending_tx = self
-            .contract
-            .splitPosition(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.partition.clone(),
-                request.amount,
-            )
-            .send()
-            .await
-            .map_err(|e| {
-                CtfError::ContractCall(format!("Failed to send split transaction: {e}"))
-            })?;
-
-        let transaction_hash = *pending_tx.tx_hash();
-
-        let receipt = pending_tx
-            .get_receipt()
-            .await
-            .map_err(|e| CtfError::ContractCall(format!("Failed to get split receipt: {e}")))?;
+        let call = self.contract.splitPosition(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.partition.clone(),
+            request.amount,
+        );
+        let (transaction_hash, block_number) = self.send(call, "split").await?;
 
         Ok(SplitPositionResponse {
             transaction_hash,
-            block_number: receipt.block_number.ok_or_else(|| {
-                CtfError::ContractCall("Block number not available in receipt".to_owned())
-            })?,
+            block_number,
         })
     }
 
@@ -345,6 +626,8 @@ impl<P: Provider + Clone> Client<P> {
     /// Returns an error if:
     /// - The transaction fails to send
     /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
     /// - The wallet doesn't have sufficient outcome tokens
     #[cfg_attr(
         feature = "tracing",
@@ -358,33 +641,18 @@ impl<P: Provider + Clone> Client<P> {
         &self,
         request: &MergePositionsRequest,
     ) -> Result<MergePositionsResponse> {
-        let pending_tx = self
-            .contract
-            .mergePositions(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.partition.clone(),
-                request.amount,
-            )
-            .send()
-            .await
-            .map_err(|e| {
-                CtfError::ContractCall(format!("Failed to send merge transaction: {e}"))
-            })?;
-
-        let transaction_hash = *pending_tx.tx_hash();
-
-        let receipt = pending_tx
-            .get_receipt()
-            .await
-            .map_err(|e| CtfError::ContractCall(format!("Failed to get merge receipt: {e}")))?;
+        let call = self.contract.mergePositions(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.partition.clone(),
+            request.amount,
+        );
+        let (transaction_hash, block_number) = self.send(call, "merge").await?;
 
         Ok(MergePositionsResponse {
             transaction_hash,
-            block_number: receipt.block_number.ok_or_else(|| {
-                CtfError::ContractCall("Block number not available in receipt".to_owned())
-            })?,
+            block_number,
         })
     }
 
@@ -397,6 +665,8 @@ impl<P: Provider + Clone> Client<P> {
     /// Returns an error if:
     /// - The transaction fails to send
     /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
     /// - The condition hasn't been resolved
     /// - The wallet doesn't have the specified outcome tokens
     #[cfg_attr(
@@ -410,32 +680,17 @@ impl<P: Provider + Clone> Client<P> {
         &self,
         request: &RedeemPositionsRequest,
     ) -> Result<RedeemPositionsResponse> {
-        let pending_tx = self
-            .contract
-            .redeemPositions(
-                request.collateral_token,
-                request.parent_collection_id,
-                request.condition_id,
-                request.index_sets.clone(),
-            )
-            .send()
-            .await
-            .map_err(|e| {
-                CtfError::ContractCall(format!("Failed to send redeem transaction: {e}"))
-            })?;
-
-        let transaction_hash = *pending_tx.tx_hash();
-
-        let receipt = pending_tx
-            .get_receipt()
-            .await
-            .map_err(|e| CtfError::ContractCall(format!("Failed to get redeem receipt: {e}")))?;
+        let call = self.contract.redeemPositions(
+            request.collateral_token,
+            request.parent_collection_id,
+            request.condition_id,
+            request.index_sets.clone(),
+        );
+        let (transaction_hash, block_number) = self.send(call, "redeem").await?;
 
         Ok(RedeemPositionsResponse {
             transaction_hash,
-            block_number: receipt.block_number.ok_or_else(|| {
-                CtfError::ContractCall("Block number not available in receipt".to_owned())
-            })?,
+            block_number,
         })
     }
 
@@ -451,6 +706,8 @@ impl<P: Provider + Clone> Client<P> {
     /// - The client was not created with `with_neg_risk()` (adapter not available)
     /// - The transaction fails to send
     /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
     /// - The condition hasn't been resolved
     /// - The wallet doesn't have the specified outcome token amounts
     #[cfg_attr(
@@ -470,28 +727,205 @@ impl<P: Provider + Clone> Client<P> {
             )
         })?;
 
-        let pending_tx = adapter
-            .redeemPositions(request.condition_id, request.amounts.clone())
-            .send()
+        let call = adapter.redeemPositions(request.condition_id, request.amounts.clone());
+        let (transaction_hash, block_number) = self.send(call, "NegRisk redeem").await?;
+
+        Ok(RedeemNegRiskResponse {
+            transaction_hash,
+            block_number,
+        })
+    }
+
+    /// Returns the nonce of the next transaction of the Safe at `safe`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    pub async fn safe_nonce(&self, safe: Address) -> Result<U256> {
+        ISafe::new(safe, &self.provider)
+            .nonce()
+            .call()
             .await
-            .map_err(|e| {
-                CtfError::ContractCall(format!("Failed to send NegRisk redeem transaction: {e}"))
-            })?;
+            .map_err(|e| CtfError::ContractCall(format!("Failed to get Safe nonce: {e}")).into())
+    }
 
-        let transaction_hash = *pending_tx.tx_hash();
+    /// Signs `transaction` with `signer` as the single owner of the Safe at `safe`, and executes
+    /// it from the provider's wallet, which pays the gas. See [`safe`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `signer` fails to sign the transaction
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
+    /// - `signer` is not the Safe's only owner, the nonce is stale, or the inner call reverts
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, signer, transaction), fields(
+            safe = %safe,
+            to = %transaction.to,
+            nonce = %transaction.nonce
+        ))
+    )]
+    pub async fn execute_safe_transaction<S: Signer + Sync>(
+        &self,
+        signer: &S,
+        safe: Address,
+        transaction: &SafeTransaction,
+    ) -> Result<SafeTransactionResponse> {
+        let signatures = transaction.sign(signer, safe, self.chain_id).await?;
+        let contract = ISafe::new(safe, self.provider.clone());
+        let call = contract.call_builder(&transaction.exec_call(signatures));
+        let (transaction_hash, block_number) = self.send(call, "Safe").await?;
+
+        Ok(SafeTransactionResponse {
+            transaction_hash,
+            block_number,
+        })
+    }
 
-        let receipt = pending_tx.get_receipt().await.map_err(|e| {
-            CtfError::ContractCall(format!("Failed to get NegRisk redeem receipt: {e}"))
-        })?;
+    /// Redeems winning outcome tokens held by the Safe at `safe`, see
+    /// [`Client::redeem_positions`] and [`Client::execute_safe_transaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Safe nonce cannot be read, or if the Safe transaction fails as
+    /// described in [`Client::execute_safe_transaction`].
+    pub async fn redeem_positions_via_safe<S: Signer + Sync>(
+        &self,
+        signer: &S,
+        safe: Address,
+        request: &RedeemPositionsRequest,
+    ) -> Result<SafeTransactionResponse> {
+        let transaction = SafeTransaction::builder()
+            .to(*self.contract.address())
+            .data(safe::redeem_positions_calldata(request))
+            .nonce(self.safe_nonce(safe).await?)
+            .build();
+
+        self.execute_safe_transaction(signer, safe, &transaction)
+            .await
+    }
 
-        Ok(RedeemNegRiskResponse {
+    /// Approves `operator`, e.g. an exchange contract, to spend the collateral and the outcome
+    /// tokens held by the Safe at `safe`. Executes two Safe transactions: an unlimited collateral
+    /// approval followed by an outcome token approval for all.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Safe nonce cannot be read, or if either Safe transaction fails as
+    /// described in [`Client::execute_safe_transaction`].
+    pub async fn approve_via_safe<S: Signer + Sync>(
+        &self,
+        signer: &S,
+        safe: Address,
+        operator: Address,
+    ) -> Result<Vec<SafeTransactionResponse>> {
+        let nonce = self.safe_nonce(safe).await?;
+        let transactions = [
+            SafeTransaction::builder()
+                .to(*self.collateral.address())
+                .data(safe::approve_calldata(operator, U256::MAX))
+                .nonce(nonce)
+                .build(),
+            SafeTransaction::builder()
+                .to(*self.contract.address())
+                .data(safe::set_approval_for_all_calldata(operator, true))
+                .nonce(nonce + U256::from(1))
+                .build(),
+        ];
+
+        let mut responses = Vec::with_capacity(transactions.len());
+        for transaction in &transactions {
+            responses.push(
+                self.execute_safe_transaction(signer, safe, transaction)
+                    .await?,
+            );
+        }
+
+        Ok(responses)
+    }
+
+    /// Executes `calls` in order from the Polymarket proxy wallet of the provider's wallet, in a
+    /// single transaction to the proxy wallet factory. See [`proxy`](super::proxy) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No proxy wallet factory is configured for the chain
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - A transaction manager is configured and the transaction is not included after all
+    ///   replacements
+    /// - Any of the calls reverts
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, calls), fields(calls_len = calls.len()))
+    )]
+    pub async fn execute_via_proxy(&self, calls: &[ProxyCall]) -> Result<ProxyTransactionResponse> {
+        let factory = wallet_contract_config(self.chain_id)
+            .and_then(|config| config.proxy_factory)
+            .ok_or_else(|| {
+                CtfError::ContractCall(format!(
+                    "Proxy wallet factory not configured for chain ID {}",
+                    self.chain_id
+                ))
+            })?;
+
+        let contract = IProxyWalletFactory::new(factory, self.provider.clone());
+        let call = contract.proxy(calls.iter().map(Into::into).collect());
+        let (transaction_hash, block_number) = self.send(call, "proxy").await?;
+
+        Ok(ProxyTransactionResponse {
             transaction_hash,
-            block_number: receipt.block_number.ok_or_else(|| {
-                CtfError::ContractCall("Block number not available in receipt".to_owned())
-            })?,
+            block_number,
         })
     }
 
+    /// Redeems winning outcome tokens held by the proxy wallet of the provider's wallet, see
+    /// [`Client::redeem_positions`] and [`Client::execute_via_proxy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy transaction fails as described in
+    /// [`Client::execute_via_proxy`].
+    pub async fn redeem_positions_via_proxy(
+        &self,
+        request: &RedeemPositionsRequest,
+    ) -> Result<ProxyTransactionResponse> {
+        let call = ProxyCall::builder()
+            .to(*self.contract.address())
+            .data(safe::redeem_positions_calldata(request))
+            .build();
+
+        self.execute_via_proxy(&[call]).await
+    }
+
+    /// Approves `operator`, e.g. an exchange contract, to spend the collateral and the outcome
+    /// tokens held by the proxy wallet of the provider's wallet. Executes an unlimited collateral
+    /// approval and an outcome token approval for all in a single proxy transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy transaction fails as described in
+    /// [`Client::execute_via_proxy`].
+    pub async fn approve_via_proxy(&self, operator: Address) -> Result<ProxyTransactionResponse> {
+        let calls = [
+            ProxyCall::builder()
+                .to(*self.collateral.address())
+                .data(safe::approve_calldata(operator, U256::MAX))
+                .build(),
+            ProxyCall::builder()
+                .to(*self.contract.address())
+                .data(safe::set_approval_for_all_calldata(operator, true))
+                .build(),
+        ];
+
+        self.execute_via_proxy(&calls).await
+    }
+
     /// Returns a reference to the underlying provider.
     #[must_use]
     pub const fn provider(&self) -> &P {
@@ -0,0 +1,175 @@
+//! Gas pricing for CTF transactions.
+//!
+//! By default, transactions sent by the [`Client`](super::Client) are priced by the provider's
+//! fillers, which may underprice them on Polygon, where the minimum priority fee is far above
+//! that of Ethereum and base fees spike quickly. With a [`Config`] set via
+//! [`Client::with_gas_config`](super::Client::with_gas_config), every transaction is instead
+//! priced from the `eth_feeHistory` of recent blocks:
+//!
+//! - The priority fee is the median of the configured reward percentile across recent blocks,
+//!   but at least `min_priority_fee`.
+//! - The max fee is the next block's base fee times `base_fee_multiplier`, plus the priority fee.
+//!   The default multiplier of two (2) keeps a transaction includable through several blocks
+//!   of consecutive base fee increases, while only the actual base fee is paid.
+//! - The max fee is capped at `max_fee_per_gas`, if set.
+
+use alloy::eips::BlockNumberOrTag;
+use alloy::providers::Provider;
+use alloy::rpc::types::FeeHistory;
+use bon::Builder;
+
+use super::error::CtfError;
+use crate::Result;
+
+/// One gwei in wei.
+const GWEI: u128 = 1_000_000_000;
+
+/// Configuration for pricing CTF transactions, see the [module documentation](self).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Config {
+    /// The number of recent blocks whose fee history is considered. The default is ten (10).
+    #[builder(default = 10)]
+    block_count: u64,
+    /// The percentile of the priority fees paid within each block to target. The default is
+    /// fifty (50), i.e. the median.
+    #[builder(default = 50.0)]
+    reward_percentile: f64,
+    /// The minimum priority fee in wei. The default is thirty (30) gwei, the minimum accepted by
+    /// Polygon validators.
+    #[builder(default = 30 * GWEI)]
+    min_priority_fee: u128,
+    /// The factor by which the next block's base fee is multiplied to absorb base fee increases
+    /// until the transaction is included. The default is two (2).
+    #[builder(default = 2)]
+    base_fee_multiplier: u128,
+    /// The maximum fee per gas in wei that is ever offered. Uncapped by default.
+    max_fee_per_gas: Option<u128>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The EIP-1559 fees to offer for a transaction, in wei per gas.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fees {
+    /// The maximum total fee per gas.
+    pub max_fee_per_gas: u128,
+    /// The maximum priority fee per gas paid to the validator.
+    pub max_priority_fee_per_gas: u128,
+}
+
+impl Config {
+    /// Returns the maximum fee per gas that is ever offered, if capped.
+    pub(crate) const fn max_fee_per_gas(&self) -> Option<u128> {
+        self.max_fee_per_gas
+    }
+
+    /// Returns the fees to offer given the fee history of recent blocks, requested with
+    /// [`Self`]'s reward percentile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history contains no base fee, or if the next block's base fee
+    /// already exceeds `max_fee_per_gas`, so that a transaction would not be included.
+    pub fn fees(&self, history: &FeeHistory) -> Result<Fees> {
+        let base_fee = history.next_block_base_fee().ok_or_else(|| {
+            CtfError::ContractCall("Fee history does not contain a base fee".to_owned())
+        })?;
+
+        let mut rewards: Vec<u128> = history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|block| block.first().copied())
+            .collect();
+        rewards.sort_unstable();
+        let median = rewards.get(rewards.len() / 2).copied().unwrap_or_default();
+        let mut priority_fee = median.max(self.min_priority_fee);
+
+        let mut max_fee = base_fee
+            .saturating_mul(self.base_fee_multiplier)
+            .saturating_add(priority_fee);
+        if let Some(cap) = self.max_fee_per_gas {
+            if cap < base_fee {
+                return Err(CtfError::ContractCall(format!(
+                    "Base fee of {base_fee} wei exceeds the maximum fee of {cap} wei"
+                ))
+                .into());
+            }
+            max_fee = max_fee.min(cap);
+            priority_fee = priority_fee.min(cap - base_fee);
+        }
+
+        Ok(Fees {
+            max_fee_per_gas: max_fee,
+            max_priority_fee_per_gas: priority_fee,
+        })
+    }
+}
+
+/// Returns the fees to offer for a transaction sent now, based on the fee history from
+/// `provider`.
+pub(crate) async fn estimate<P: Provider>(provider: &P, config: &Config) -> Result<Fees> {
+    let history = provider
+        .get_fee_history(
+            config.block_count,
+            BlockNumberOrTag::Latest,
+            &[config.reward_percentile],
+        )
+        .await
+        .map_err(|e| CtfError::ContractCall(format!("Failed to get fee history: {e}")))?;
+
+    config.fees(&history)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(base_fees: Vec<u128>, rewards: &[u128]) -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: base_fees,
+            reward: Some(rewards.iter().map(|reward| vec![*reward]).collect()),
+            ..FeeHistory::default()
+        }
+    }
+
+    #[test]
+    fn fees_should_apply_floor_and_headroom() {
+        let config = Config::default();
+
+        // Priority fees below the floor are raised to 30 gwei
+        let fees = config
+            .fees(&history(vec![80 * GWEI, 100 * GWEI], &[GWEI, 2 * GWEI]))
+            .unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, 30 * GWEI);
+        assert_eq!(fees.max_fee_per_gas, 230 * GWEI);
+
+        // The median reward is used during congestion
+        let fees = config
+            .fees(&history(
+                vec![100 * GWEI],
+                &[40 * GWEI, 90 * GWEI, 50 * GWEI],
+            ))
+            .unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, 50 * GWEI);
+        assert_eq!(fees.max_fee_per_gas, 250 * GWEI);
+    }
+
+    #[test]
+    fn fees_should_respect_cap() {
+        let config = Config::builder().max_fee_per_gas(150 * GWEI).build();
+
+        let fees = config.fees(&history(vec![130 * GWEI], &[])).unwrap();
+        assert_eq!(fees.max_fee_per_gas, 150 * GWEI);
+        assert_eq!(fees.max_priority_fee_per_gas, 20 * GWEI);
+
+        config.fees(&history(vec![200 * GWEI], &[])).unwrap_err();
+        config.fees(&history(vec![], &[])).unwrap_err();
+    }
+}
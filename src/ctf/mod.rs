@@ -12,6 +12,10 @@
 //! - **Splitting**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merging**: Combine outcome token pairs back into USDC
 //! - **Redemption**: Redeem winning outcome tokens after market resolution
+//! - **Gas Pricing**: Price transactions from recent fee history so they confirm during gas
+//!   spikes, see [`gas`]
+//! - **Transaction Management**: Track nonces, wait for receipts with a timeout, and replace stuck
+//!   transactions, see [`transactions`]
 //!
 //! # Example
 //!
@@ -63,8 +67,14 @@
 //! - [CTF Documentation](https://docs.polymarket.com/developers/CTF/overview)
 //! - [Gnosis CTF Source Code](https://github.com/gnosis/conditional-tokens-contracts)
 
+#[cfg(feature = "polling")]
+pub mod allowances;
 pub mod client;
 mod error;
+pub mod gas;
+pub mod proxy;
+pub mod safe;
+pub mod transactions;
 pub mod types;
 
 pub use client::Client;
@@ -0,0 +1,117 @@
+//! Polymarket proxy wallet transactions.
+//!
+//! Accounts with the `Proxy` signature type (Magic/email wallets) hold their funds in a proxy
+//! wallet deployed by Polymarket's proxy wallet factory. The wallet only accepts calls forwarded by
+//! the factory's `proxy` function, which executes a batch of [`Call`]s from the wallet of the
+//! transaction sender. Proxy transactions therefore have to be sent by the controlling EOA itself,
+//! i.e. the provider's wallet must hold the EOA's key.
+//!
+//! [`Client::execute_via_proxy`](super::Client::execute_via_proxy) executes arbitrary calls, e.g.
+//! built with the calldata helpers of [`safe`](super::safe), and the
+//! [`Client::redeem_positions_via_proxy`](super::Client::redeem_positions_via_proxy) and
+//! [`Client::approve_via_proxy`](super::Client::approve_via_proxy) helpers cover the common funder
+//! operations. The proxy wallet address of an EOA is given by
+//! [`derive_proxy_wallet`](crate::derive_proxy_wallet).
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use alloy::primitives::{Bytes, U256};
+use alloy::sol;
+use bon::Builder;
+
+use crate::types::Address;
+
+sol! {
+    #[sol(rpc)]
+    interface IProxyWalletFactory {
+        /// A call executed by a proxy wallet.
+        struct ProxyCall {
+            uint8 typeCode;
+            address to;
+            uint256 value;
+            bytes data;
+        }
+
+        /// Executes `calls` from the proxy wallet of the sender, deploying it first if needed.
+        function proxy(ProxyCall[] memory calls)
+            external
+            payable
+            returns (bytes[] memory returnValues);
+    }
+}
+
+/// How a proxy wallet executes a [`Call`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CallType {
+    /// A regular call from the proxy wallet.
+    #[default]
+    Call = 1,
+    /// A delegate call, which runs the target's code in the context of the proxy wallet.
+    DelegateCall = 2,
+}
+
+/// A call to be executed by a proxy wallet.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Builder)]
+pub struct Call {
+    /// The contract to call.
+    pub to: Address,
+    /// The amount of native currency sent along.
+    #[builder(default)]
+    pub value: U256,
+    /// The calldata.
+    #[builder(into)]
+    pub data: Bytes,
+    /// How the call is executed. The default is [`CallType::Call`].
+    #[builder(default)]
+    pub call_type: CallType,
+}
+
+impl From<&Call> for IProxyWalletFactory::ProxyCall {
+    fn from(call: &Call) -> Self {
+        Self {
+            typeCode: call.call_type as u8,
+            to: call.to,
+            value: call.value,
+            data: call.data.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+    use alloy::sol_types::SolCall as _;
+
+    use super::*;
+
+    #[test]
+    fn proxy_call_should_match_factory_selector() {
+        // proxy((uint8,address,uint256,bytes)[])
+        assert_eq!(
+            IProxyWalletFactory::proxyCall::SELECTOR,
+            [0x34, 0xee, 0x97, 0x91]
+        );
+    }
+
+    #[test]
+    fn call_should_convert_to_proxy_call() {
+        let call = Call::builder()
+            .to(address!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045"))
+            .data(vec![1, 2, 3])
+            .build();
+
+        let proxy_call = IProxyWalletFactory::ProxyCall::from(&call);
+
+        assert_eq!(proxy_call.typeCode, 1);
+        assert_eq!(proxy_call.to, call.to);
+        assert_eq!(proxy_call.value, U256::ZERO);
+        assert_eq!(proxy_call.data, Bytes::from(vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,261 @@
+//! Gnosis Safe transactions.
+//!
+//! Accounts with the `GnosisSafe` signature type hold their funds in a 1-of-1 Safe wallet rather
+//! than in the EOA, so approvals and redemptions have to be executed by the Safe itself: the owner
+//! signs a [`Transaction`] over the Safe's EIP-712 domain, and any account submits it via the
+//! Safe's `execTransaction`, paying the gas.
+//!
+//! [`Client::execute_safe_transaction`](super::Client::execute_safe_transaction) signs and
+//! submits a transaction with the provider's wallet, and the
+//! [`Client::redeem_positions_via_safe`](super::Client::redeem_positions_via_safe) and
+//! [`Client::approve_via_safe`](super::Client::approve_via_safe) helpers cover the common funder
+//! operations. Only the single-owner path is supported, i.e. the signer must be the Safe's only
+//! owner with a threshold of one (1), as for wallets deployed by Polymarket.
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    clippy::too_many_arguments,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use alloy::dyn_abi::Eip712Domain;
+use alloy::primitives::{B256, Bytes, ChainId, U256};
+use alloy::signers::Signer;
+use alloy::sol;
+use alloy::sol_types::{SolCall as _, SolStruct as _};
+use bon::Builder;
+
+use super::types::RedeemPositionsRequest;
+use crate::Result;
+use crate::types::Address;
+
+sol! {
+    #[sol(rpc)]
+    interface ISafe {
+        /// Returns the nonce of the next Safe transaction.
+        function nonce() external view returns (uint256);
+
+        /// Executes a Safe transaction signed by the required owners.
+        function execTransaction(
+            address to,
+            uint256 value,
+            bytes calldata data,
+            uint8 operation,
+            uint256 safeTxGas,
+            uint256 baseGas,
+            uint256 gasPrice,
+            address gasToken,
+            address refundReceiver,
+            bytes memory signatures
+        ) external payable returns (bool success);
+    }
+
+    /// The EIP-712 struct signed by Safe owners.
+    struct SafeTx {
+        address to;
+        uint256 value;
+        bytes data;
+        uint8 operation;
+        uint256 safeTxGas;
+        uint256 baseGas;
+        uint256 gasPrice;
+        address gasToken;
+        address refundReceiver;
+        uint256 nonce;
+    }
+
+    interface IApprovals {
+        /// Approves `spender` to transfer `value` ERC-20 tokens.
+        function approve(address spender, uint256 value) external returns (bool);
+
+        /// Approves `operator` to transfer all ERC-1155 tokens.
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+
+    interface IRedemptions {
+        /// Redeems winning outcome tokens for collateral.
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] calldata indexSets
+        ) external;
+    }
+}
+
+/// How a Safe executes a [`Transaction`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Operation {
+    /// A regular call from the Safe.
+    #[default]
+    Call = 0,
+    /// A delegate call, which runs the target's code in the context of the Safe.
+    DelegateCall = 1,
+}
+
+/// A transaction to be executed by a Safe. Gas refunds are not supported, so the account
+/// submitting the transaction pays for its gas.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Builder)]
+pub struct Transaction {
+    /// The contract to call.
+    pub to: Address,
+    /// The amount of native currency sent along.
+    #[builder(default)]
+    pub value: U256,
+    /// The calldata.
+    #[builder(into)]
+    pub data: Bytes,
+    /// How the call is executed. The default is [`Operation::Call`].
+    #[builder(default)]
+    pub operation: Operation,
+    /// The Safe nonce, see [`Client::safe_nonce`](super::Client::safe_nonce).
+    pub nonce: U256,
+}
+
+impl Transaction {
+    /// Returns the EIP-712 hash that the owners of the Safe at `safe` sign to approve this
+    /// transaction.
+    #[must_use]
+    pub fn signing_hash(&self, safe: Address, chain_id: ChainId) -> B256 {
+        let domain = Eip712Domain {
+            chain_id: Some(U256::from(chain_id)),
+            verifying_contract: Some(safe),
+            ..Eip712Domain::default()
+        };
+
+        SafeTx {
+            to: self.to,
+            value: self.value,
+            data: self.data.clone(),
+            operation: self.operation as u8,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            nonce: self.nonce,
+        }
+        .eip712_signing_hash(&domain)
+    }
+
+    /// Signs this transaction as the single owner of the Safe at `safe`, returning the signature
+    /// in the format expected by `execTransaction`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signer` fails to sign.
+    pub async fn sign<S: Signer>(
+        &self,
+        signer: &S,
+        safe: Address,
+        chain_id: ChainId,
+    ) -> Result<Bytes> {
+        let signature = signer.sign_hash(&self.signing_hash(safe, chain_id)).await?;
+
+        Ok(Bytes::from(signature.as_bytes()))
+    }
+
+    /// Returns the `execTransaction` call that executes this transaction with `signatures`.
+    pub(crate) fn exec_call(&self, signatures: Bytes) -> ISafe::execTransactionCall {
+        ISafe::execTransactionCall {
+            to: self.to,
+            value: self.value,
+            data: self.data.clone(),
+            operation: self.operation as u8,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            signatures,
+        }
+    }
+}
+
+/// Returns the calldata approving `spender` to transfer `value` of an ERC-20 token.
+#[must_use]
+pub fn approve_calldata(spender: Address, value: U256) -> Bytes {
+    IApprovals::approveCall { spender, value }
+        .abi_encode()
+        .into()
+}
+
+/// Returns the calldata approving `operator` to transfer all ERC-1155 tokens, e.g. outcome
+/// tokens of the Conditional Tokens contract.
+#[must_use]
+pub fn set_approval_for_all_calldata(operator: Address, approved: bool) -> Bytes {
+    IApprovals::setApprovalForAllCall { operator, approved }
+        .abi_encode()
+        .into()
+}
+
+/// Returns the calldata redeeming positions on the Conditional Tokens contract.
+#[must_use]
+pub fn redeem_positions_calldata(request: &RedeemPositionsRequest) -> Bytes {
+    IRedemptions::redeemPositionsCall {
+        collateralToken: request.collateral_token,
+        parentCollectionId: request.parent_collection_id,
+        conditionId: request.condition_id,
+        indexSets: request.index_sets.clone(),
+    }
+    .abi_encode()
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, b256, keccak256};
+    use alloy::signers::local::PrivateKeySigner;
+
+    use super::*;
+    use crate::types::Signature;
+
+    #[test]
+    fn type_hashes_should_match_safe_contract() {
+        // SAFE_TX_TYPEHASH of Safe v1.3.0 and later
+        assert_eq!(
+            keccak256(SafeTx::eip712_encode_type().as_bytes()),
+            b256!("bb8310d486368db6bd6f849402fdd73ad53d316b5a4b2644ad6efe0f941286d8")
+        );
+
+        // DOMAIN_SEPARATOR_TYPEHASH of Safe v1.3.0 and later
+        let domain = Eip712Domain {
+            chain_id: Some(U256::from(137)),
+            verifying_contract: Some(Address::ZERO),
+            ..Eip712Domain::default()
+        };
+        assert_eq!(
+            domain.type_hash(),
+            b256!("47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218")
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_should_recover_owner() {
+        let signer = PrivateKeySigner::random();
+        let safe = address!("0x1111111111111111111111111111111111111111");
+        let transaction = Transaction::builder()
+            .to(address!("0x2222222222222222222222222222222222222222"))
+            .data(approve_calldata(safe, U256::MAX))
+            .nonce(U256::from(3))
+            .build();
+
+        let signature = transaction.sign(&signer, safe, 137).await.unwrap();
+        let recovered = Signature::from_raw(&signature)
+            .unwrap()
+            .recover_address_from_prehash(&transaction.signing_hash(safe, 137))
+            .unwrap();
+
+        assert_eq!(signature.len(), 65);
+        assert!(matches!(signature[64], 27 | 28), "v must be 27 or 28");
+        assert_eq!(recovered, signer.address());
+        assert_ne!(
+            transaction.signing_hash(safe, 137),
+            transaction.signing_hash(safe, 80002)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Transaction management.
+//!
+//! By default, the [`Client`](super::Client) sends a transaction and waits for its receipt for as
+//! long as it takes, leaving the nonce and fees to the provider's fillers. A transaction that is
+//! underpriced during a gas spike then stays pending, blocking every later transaction of the
+//! account. With a [`Config`] set via
+//! [`Client::with_transaction_config`](super::Client::with_transaction_config), transactions are
+//! instead sent as follows:
+//!
+//! 1. The nonce is taken from a local counter that is synchronized with the account's pending
+//!    transaction count, so that concurrent transactions sent through clones of one client do not
+//!    collide.
+//! 2. The transaction is priced via [`gas`](super::gas) if configured, and by the provider's
+//!    EIP-1559 estimate otherwise.
+//! 3. If no receipt arrives within `receipt_timeout`, the transaction is replaced by one with the
+//!    same nonce and fees bumped by `fee_bump_percent`, up to `max_replacements` times.
+//!
+//! Since all attempts share one nonce, at most one of them is ever included, which makes
+//! resubmission idempotent: the receipt of whichever attempt was included is returned, and a
+//! reverted transaction is reported as an error rather than retried.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use alloy::contract::SolCallBuilder;
+use alloy::primitives::TxHash;
+use alloy::providers::{PendingTransactionError, Provider, WatchTxError};
+use alloy::rpc::types::TransactionReceipt;
+use alloy::sol_types::SolCall;
+use bon::Builder;
+use futures_timer::Delay;
+
+use super::error::CtfError;
+use super::gas::Fees;
+use crate::Result;
+use crate::error::Error;
+use crate::types::Address;
+
+/// Configuration for [`Client::with_transaction_config`](super::Client::with_transaction_config).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct Config {
+    /// The address that signs the client's transactions, whose nonces are tracked.
+    sender: Address,
+    /// How long to wait for a receipt before replacing a transaction. The default is sixty (60)
+    /// seconds.
+    #[builder(default = Duration::from_secs(60))]
+    receipt_timeout: Duration,
+    /// How often a transaction is replaced before giving up. The default is three (3).
+    #[builder(default = 3)]
+    max_replacements: u32,
+    /// By how many percent the fees of a replacement exceed those of the transaction it replaces.
+    /// Nodes reject replacements with an increase below ten (10) percent. The default is twenty
+    /// (20).
+    #[builder(default = 20)]
+    fee_bump_percent: u32,
+}
+
+/// Sends transactions as described in the [module documentation](self).
+#[derive(Clone, Debug)]
+pub(crate) struct Manager {
+    config: Config,
+    /// The nonce of the next transaction, if known.
+    next_nonce: Arc<Mutex<Option<u64>>>,
+}
+
+/// Returns `fees` increased by `percent`, but not beyond `cap`.
+fn bump(fees: Fees, percent: u32, cap: Option<u128>) -> Fees {
+    let increase = |fee: u128| fee.saturating_mul(100 + u128::from(percent)) / 100;
+
+    let max_fee_per_gas = cap.map_or(increase(fees.max_fee_per_gas), |cap| {
+        increase(fees.max_fee_per_gas).min(cap)
+    });
+    Fees {
+        max_fee_per_gas,
+        max_priority_fee_per_gas: increase(fees.max_priority_fee_per_gas).min(max_fee_per_gas),
+    }
+}
+
+/// Returns `receipt` if its transaction succeeded.
+fn succeeded(receipt: TransactionReceipt) -> Result<TransactionReceipt> {
+    if receipt.status() {
+        Ok(receipt)
+    } else {
+        Err(
+            CtfError::ContractCall(format!("Transaction {} reverted", receipt.transaction_hash))
+                .into(),
+        )
+    }
+}
+
+impl Manager {
+    pub(crate) fn new(config: Config) -> Self {
+        Self {
+            config,
+            next_nonce: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Reserves the next nonce of the sender.
+    async fn reserve_nonce<P: Provider>(&self, provider: &P) -> Result<u64> {
+        let pending = provider
+            .get_transaction_count(self.config.sender)
+            .pending()
+            .await
+            .map_err(|e| CtfError::ContractCall(format!("Failed to get nonce: {e}")))?;
+
+        let mut next_nonce = self
+            .next_nonce
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let nonce = next_nonce.map_or(pending, |next| next.max(pending));
+        *next_nonce = Some(nonce + 1);
+
+        Ok(nonce)
+    }
+
+    /// Forgets the local nonce after a transaction could not be sent at all, so that the next
+    /// transaction resynchronizes with the chain rather than leaving a gap.
+    fn release_nonce(&self) {
+        *self
+            .next_nonce
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Returns the receipt of the first of `hashes` that was included, if any.
+    async fn included<P: Provider>(
+        provider: &P,
+        hashes: &[TxHash],
+    ) -> Result<Option<TransactionReceipt>> {
+        for hash in hashes {
+            let receipt = provider
+                .get_transaction_receipt(*hash)
+                .await
+                .map_err(|e| CtfError::ContractCall(format!("Failed to get receipt: {e}")))?;
+            if receipt.is_some() {
+                return Ok(receipt);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Waits up to the receipt timeout for any of `hashes` to be included, checking at the
+    /// provider's poll interval, and returns its receipt.
+    async fn await_included<P: Provider>(
+        &self,
+        provider: &P,
+        hashes: &[TxHash],
+    ) -> Result<Option<TransactionReceipt>> {
+        let deadline = Instant::now() + self.config.receipt_timeout;
+        loop {
+            if let Some(receipt) = Self::included(provider, hashes).await? {
+                return Ok(Some(receipt));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            Delay::new(provider.client().poll_interval().min(remaining)).await;
+        }
+    }
+
+    /// Sends `call` priced at `fees`, replacing it with bumped fees, capped at `cap`, until it is
+    /// included.
+    pub(crate) async fn send<P: Provider, C: SolCall>(
+        &self,
+        call: SolCallBuilder<&P, C>,
+        mut fees: Fees,
+        cap: Option<u128>,
+    ) -> Result<TransactionReceipt> {
+        let provider = call.provider;
+        let nonce = self.reserve_nonce(provider).await?;
+        let mut hashes = Vec::new();
+
+        for attempt in 0..=self.config.max_replacements {
+            if attempt > 0 {
+                fees = bump(fees, self.config.fee_bump_percent, cap);
+            }
+
+            let sent = call
+                .clone()
+                .nonce(nonce)
+                .max_fee_per_gas(fees.max_fee_per_gas)
+                .max_priority_fee_per_gas(fees.max_priority_fee_per_gas)
+                .send()
+                .await;
+            let pending = match sent {
+                Ok(pending) => pending,
+                Err(e) if hashes.is_empty() => {
+                    self.release_nonce();
+                    return Err(
+                        CtfError::ContractCall(format!("Failed to send transaction: {e}")).into(),
+                    );
+                }
+                // The replacement was rejected, e.g. because an earlier attempt was included
+                // in the meantime, or because the bump was capped. The earlier attempts then get
+                // as long to be included as a sent replacement would have had.
+                Err(_) => {
+                    if let Some(receipt) = self.await_included(provider, &hashes).await? {
+                        return succeeded(receipt);
+                    }
+                    continue;
+                }
+            };
+
+            hashes.push(*pending.tx_hash());
+            match pending
+                .with_timeout(Some(self.config.receipt_timeout))
+                .get_receipt()
+                .await
+            {
+                Ok(receipt) => return succeeded(receipt),
+                Err(PendingTransactionError::TxWatcher(WatchTxError::Timeout)) => {
+                    if let Some(receipt) = Self::included(provider, &hashes).await? {
+                        return succeeded(receipt);
+                    }
+                }
+                Err(e) => {
+                    return Err(
+                        CtfError::ContractCall(format!("Failed to get receipt: {e}")).into(),
+                    );
+                }
+            }
+        }
+
+        Err(Error::timeout(
+            self.config.receipt_timeout * (self.config.max_replacements + 1),
+            format!(
+                "transaction with nonce {nonce} from {} was not included",
+                self.config.sender
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bump_should_respect_cap() {
+        let fees = Fees {
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 30,
+        };
+
+        assert_eq!(
+            bump(fees, 20, None),
+            Fees {
+                max_fee_per_gas: 120,
+                max_priority_fee_per_gas: 36,
+            }
+        );
+        assert_eq!(
+            bump(fees, 20, Some(110)),
+            Fees {
+                max_fee_per_gas: 110,
+                max_priority_fee_per_gas: 36,
+            }
+        );
+        assert_eq!(
+            bump(fees, 500, Some(150)),
+            Fees {
+                max_fee_per_gas: 150,
+                max_priority_fee_per_gas: 150,
+            }
+        );
+    }
+}
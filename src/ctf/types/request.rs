@@ -50,6 +50,14 @@ pub struct PositionIdRequest {
     pub collection_id: B256,
 }
 
+/// Request to read the collateral (USDC) balance of an address.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct CollateralBalanceRequest {
+    /// The address whose balance is read, e.g. the funder of a CLOB account
+    pub owner: Address,
+}
+
 /// Request to split collateral into outcome tokens.
 ///
 /// Converts USDC collateral into matched outcome token pairs (YES/NO).
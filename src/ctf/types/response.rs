@@ -27,6 +27,14 @@ pub struct PositionIdResponse {
     pub position_id: U256,
 }
 
+/// Response from reading a collateral balance.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct CollateralBalanceResponse {
+    /// The balance in base units (USDC has 6 decimals)
+    pub balance: U256,
+}
+
 /// Response from a split position transaction.
 #[non_exhaustive]
 #[derive(Debug, Clone, Builder)]
@@ -57,6 +65,36 @@ pub struct RedeemPositionsResponse {
     pub block_number: u64,
 }
 
+/// Response from an approval transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct ApprovalResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
+
+/// Response from a Safe `execTransaction` transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct SafeTransactionResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
+
+/// Response from a proxy wallet factory `proxy` transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct ProxyTransactionResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
+
 /// Response from a `NegRisk` redeem transaction.
 #[non_exhaustive]
 #[derive(Debug, Clone, Builder)]
@@ -24,10 +24,8 @@
 //! # }
 //! ```
 
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use bon::bon;
+use reqwest::Method;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use url::Url;
@@ -41,8 +39,11 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
+use crate::transport::{self, HttpClient};
 use crate::{Result, ToQueryParams as _};
 
+const DEFAULT_HOST: &str = "https://data-api.polymarket.com";
+
 /// HTTP client for the Polymarket Data API.
 ///
 /// Provides methods for querying user positions, trades, activity, market holders,
@@ -66,16 +67,16 @@ use crate::{Result, ToQueryParams as _};
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    client: HttpClient,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://data-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
+#[bon]
 impl Client {
     /// Creates a new Data API client with a custom host URL.
     ///
@@ -87,17 +88,49 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
+        Self::builder().host(host).build()
+    }
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+    /// Creates a new Data API client for the production deployment at <https://data-api.polymarket.com>.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn production() -> Result<Client> {
+        Self::new(DEFAULT_HOST)
+    }
 
+    /// Returns a builder for a Data API client with custom transport options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use polymarket_client_sdk::data::Client;
+    /// use polymarket_client_sdk::transport::Config;
+    ///
+    /// let client = Client::builder()
+    ///     .transport(Config::builder().timeout(Duration::from_secs(10)).build())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The base URL for the Data API. Defaults to `https://data-api.polymarket.com`.
+        #[builder(default = DEFAULT_HOST)]
+        host: &str,
+        /// Options for the underlying HTTP transport.
+        #[builder(default)]
+        transport: transport::Config,
+    ) -> Result<Client> {
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: transport.build_client()?,
         })
     }
 
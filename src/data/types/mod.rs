@@ -4,7 +4,8 @@ use serde::de::StdError;
 use serde::{Deserialize, Serialize};
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as};
 
-use crate::types::{B256, Decimal};
+use crate::serde_helpers::CanonicalDecimal;
+use crate::types::{B256, Decimal, Usdc};
 
 pub mod request;
 pub mod response;
@@ -337,6 +338,42 @@ impl fmt::Display for BoundedIntError {
 
 impl StdError for BoundedIntError {}
 
+/// Error type for decimal values with more decimal places than the API accepts.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PrecisionError {
+    /// The value that was too precise.
+    pub value: Decimal,
+    /// The maximum number of decimal places.
+    pub max_decimals: u32,
+    /// The name of the parameter.
+    pub param_name: &'static str,
+}
+
+impl PrecisionError {
+    /// Creates a new `PrecisionError`.
+    #[must_use]
+    pub const fn new(value: Decimal, max_decimals: u32, param_name: &'static str) -> Self {
+        Self {
+            value,
+            max_decimals,
+            param_name,
+        }
+    }
+}
+
+impl fmt::Display for PrecisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} must have at most {} decimal places (got {})",
+            self.param_name, self.max_decimals, self.value
+        )
+    }
+}
+
+impl StdError for PrecisionError {}
+
 /// A filter for minimum trade size.
 ///
 /// Used to filter trades by a minimum value, either in USDC (cash) or tokens.
@@ -354,13 +391,15 @@ impl StdError for BoundedIntError {}
 /// // Filter trades with at least 50 tokens
 /// let filter = TradeFilter::tokens(dec!(50)).unwrap();
 /// ```
+#[serde_as]
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct TradeFilter {
     /// The type of filter (cash or tokens).
     pub filter_type: FilterType,
-    /// The minimum amount to filter by (must be >= 0).
+    /// The minimum amount to filter by (must be >= 0, with at most six decimal places).
+    #[serde_as(as = "CanonicalDecimal")]
     pub filter_amount: Decimal,
 }
 
@@ -369,14 +408,18 @@ impl TradeFilter {
     ///
     /// # Errors
     ///
-    /// Returns [`TradeFilterError`] if the amount is negative.
+    /// Returns [`TradeFilterError`] if the amount is negative or has more than six decimal
+    /// places.
     pub fn new(filter_type: FilterType, filter_amount: Decimal) -> Result<Self, TradeFilterError> {
         if filter_amount.is_sign_negative() {
             return Err(TradeFilterError::NegativeAmount(filter_amount));
         }
+        if filter_amount.normalize().scale() > Usdc::DECIMALS {
+            return Err(TradeFilterError::TooManyDecimals(filter_amount));
+        }
         Ok(Self {
             filter_type,
-            filter_amount,
+            filter_amount: filter_amount.normalize(),
         })
     }
 
@@ -384,7 +427,7 @@ impl TradeFilter {
     ///
     /// # Errors
     ///
-    /// Returns [`TradeFilterError`] if the amount is negative.
+    /// Returns [`TradeFilterError`] if the amount is negative or too precise.
     pub fn cash(amount: Decimal) -> Result<Self, TradeFilterError> {
         Self::new(FilterType::Cash, amount)
     }
@@ -393,7 +436,7 @@ impl TradeFilter {
     ///
     /// # Errors
     ///
-    /// Returns [`TradeFilterError`] if the amount is negative.
+    /// Returns [`TradeFilterError`] if the amount is negative or too precise.
     pub fn tokens(amount: Decimal) -> Result<Self, TradeFilterError> {
         Self::new(FilterType::Tokens, amount)
     }
@@ -405,6 +448,8 @@ impl TradeFilter {
 pub enum TradeFilterError {
     /// The filter amount was negative.
     NegativeAmount(Decimal),
+    /// The filter amount had more than six decimal places.
+    TooManyDecimals(Decimal),
 }
 
 impl fmt::Display for TradeFilterError {
@@ -413,6 +458,13 @@ impl fmt::Display for TradeFilterError {
             Self::NegativeAmount(amount) => {
                 write!(f, "filter amount must be >= 0 (got {amount})")
             }
+            Self::TooManyDecimals(amount) => {
+                write!(
+                    f,
+                    "filter amount must have at most {} decimal places (got {amount})",
+                    Usdc::DECIMALS
+                )
+            }
         }
     }
 }
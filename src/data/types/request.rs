@@ -14,9 +14,11 @@ use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_se
 
 use super::{
     ActivitySortBy, ActivityType, BoundedIntError, ClosedPositionSortBy, LeaderboardCategory,
-    LeaderboardOrderBy, MarketFilter, PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
+    LeaderboardOrderBy, MarketFilter, PositionSortBy, PrecisionError, Side, SortDirection,
+    TimePeriod, TradeFilter,
 };
-use crate::types::{Address, B256, Decimal};
+use crate::serde_helpers::CanonicalDecimal;
+use crate::types::{Address, B256, Decimal, Shares};
 
 /// Validates that an i32 value is within the specified bounds.
 fn validate_bound(
@@ -32,6 +34,21 @@ fn validate_bound(
     }
 }
 
+/// Validates that a decimal value has at most `max_decimals` decimal places, normalizing it so
+/// that it is sent exactly as validated.
+fn validate_precision(
+    value: Decimal,
+    max_decimals: u32,
+    param_name: &'static str,
+) -> Result<Decimal, PrecisionError> {
+    let normalized = value.normalize();
+    if normalized.scale() <= max_decimals {
+        Ok(normalized)
+    } else {
+        Err(PrecisionError::new(value, max_decimals, param_name))
+    }
+}
+
 /// Request parameters for the `/positions` endpoint.
 ///
 /// Fetches current (open) positions for a user. Positions represent holdings
@@ -66,6 +83,7 @@ fn validate_bound(
 ///     .sort_direction(SortDirection::Desc)
 ///     .build();
 /// ```
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -76,8 +94,10 @@ pub struct PositionsRequest {
     /// Filter by markets or events. Mutually exclusive options.
     #[serde(flatten, skip_serializing_if = "filter_is_none_or_empty")]
     pub filter: Option<MarketFilter>,
-    /// Minimum position size to include (default: 1).
+    /// Minimum position size to include, with at most six decimal places (default: 1).
     #[serde(rename = "sizeThreshold")]
+    #[serde_as(as = "Option<CanonicalDecimal>")]
+    #[builder(with = |v: Decimal| -> Result<_, PrecisionError> { validate_precision(v, Shares::DECIMALS, "size_threshold") })]
     pub size_threshold: Option<Decimal>,
     /// Only return positions that can be redeemed (default: false).
     pub redeemable: Option<bool>,
@@ -8,7 +8,7 @@ use serde::{Deserialize, Deserializer};
 use serde_with::{DefaultOnNull, DisplayFromStr, NoneAsEmptyString, serde_as};
 
 use super::{ActivityType, Side};
-use crate::types::{Address, B256, Decimal, U256};
+use crate::types::{Address, B256, Decimal, Timestamp, TokenId};
 
 /// Deserializes an optional Side, treating empty strings as None.
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
@@ -70,7 +70,7 @@ pub struct Position {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier
-    pub asset: U256,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     /// Number of outcome tokens held.
@@ -116,7 +116,7 @@ pub struct Position {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
-    pub opposite_asset: U256,
+    pub opposite_asset: TokenId,
     /// Market end/resolution date.
     pub end_date: NaiveDate,
     /// Whether this is a negative risk market.
@@ -134,7 +134,7 @@ pub struct ClosedPosition {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier (decimal string from API).
-    pub asset: U256,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     /// Average entry price for the position.
@@ -145,8 +145,8 @@ pub struct ClosedPosition {
     pub realized_pnl: Decimal,
     /// Final market price when position was closed.
     pub cur_price: Decimal,
-    /// Unix timestamp when the position was closed.
-    pub timestamp: i64,
+    /// When the position was closed.
+    pub timestamp: Timestamp,
     /// Market title/question.
     pub title: String,
     /// Market URL slug.
@@ -162,7 +162,7 @@ pub struct ClosedPosition {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
-    pub opposite_asset: U256,
+    pub opposite_asset: TokenId,
     /// Market end/resolution date.
     pub end_date: DateTime<Utc>,
 }
@@ -181,15 +181,15 @@ pub struct Trade {
     /// Trade side (BUY or SELL).
     pub side: Side,
     /// The outcome token asset identifier (decimal string from API).
-    pub asset: U256,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     /// Number of tokens traded.
     pub size: Decimal,
     /// Execution price per token.
     pub price: Decimal,
-    /// Unix timestamp when the trade occurred.
-    pub timestamp: i64,
+    /// When the trade occurred.
+    pub timestamp: Timestamp,
     /// Market title/question.
     pub title: String,
     /// Market URL slug.
@@ -237,8 +237,8 @@ pub struct Trade {
 pub struct Activity {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
-    /// Unix timestamp when the activity occurred.
-    pub timestamp: i64,
+    /// When the activity occurred.
+    pub timestamp: Timestamp,
     /// The market condition ID (unique market identifier).
     /// Can be empty for some activity types (e.g., rewards, conversions).
     #[serde(default)]
@@ -258,7 +258,7 @@ pub struct Activity {
     /// Outcome token asset identifier
     #[serde(default)]
     #[serde_as(as = "NoneAsEmptyString")]
-    pub asset: Option<U256>,
+    pub asset: Option<TokenId>,
     /// Trade side (for trades only).
     #[serde(default, deserialize_with = "deserialize_optional_side")]
     pub side: Option<Side>,
@@ -321,7 +321,7 @@ pub struct Holder {
     #[serde_as(as = "NoneAsEmptyString")]
     pub bio: Option<String>,
     /// The outcome token asset identifier (decimal string from API).
-    pub asset: U256,
+    pub asset: TokenId,
     /// Holder's pseudonym (if set).
     #[serde(default)]
     #[serde_as(as = "NoneAsEmptyString")]
@@ -355,7 +355,7 @@ pub struct Holder {
 #[non_exhaustive]
 pub struct MetaHolder {
     /// The outcome token identifier
-    pub token: U256,
+    pub token: TokenId,
     /// List of holders for this token.
     pub holders: Vec<Holder>,
 }
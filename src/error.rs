@@ -1,6 +1,7 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
 use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
@@ -10,6 +11,14 @@ pub use reqwest::Method;
 /// HTTP status code type, re-exported for use with error inspection.
 pub use reqwest::StatusCode;
 use reqwest::header;
+use rust_decimal::Decimal;
+
+#[cfg(feature = "clob")]
+use crate::clob::types::AssetType;
+#[cfg(feature = "clob")]
+use crate::clob::types::request::UpdateBalanceAllowanceRequest;
+#[cfg(feature = "clob")]
+use crate::types::TokenId;
 
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +35,12 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Error related to an operation not completing within its allotted time
+    Timeout,
+    /// Error related to an order being rejected by pre-trade risk limits
+    Risk,
+    /// Error related to market data being too old to trade on
+    StaleData,
 }
 
 #[derive(Debug)]
@@ -56,9 +71,21 @@ impl Error {
         self.source.as_deref()
     }
 
+    /// Returns the source of this error, or the first error in its chain of sources, of type
+    /// `E`.
     pub fn downcast_ref<E: StdError + 'static>(&self) -> Option<&E> {
-        let e = self.source.as_deref()?;
-        e.downcast_ref::<E>()
+        let mut current: Option<&(dyn StdError + 'static)> = self
+            .source
+            .as_deref()
+            .map(|e| e as &(dyn StdError + 'static));
+        while let Some(e) = current {
+            if let Some(e) = e.downcast_ref::<E>() {
+                return Some(e);
+            }
+            current = e.source();
+        }
+
+        None
     }
 
     pub fn validation<S: Into<String>>(message: S) -> Self {
@@ -83,6 +110,14 @@ impl Error {
         .into()
     }
 
+    pub fn timeout<S: Into<String>>(duration: Duration, message: S) -> Self {
+        Timeout {
+            duration,
+            message: message.into(),
+        }
+        .into()
+    }
+
     #[must_use]
     pub fn missing_contract_config(chain_id: ChainId, neg_risk: bool) -> Self {
         MissingContractConfig { chain_id, neg_risk }.into()
@@ -107,7 +142,7 @@ impl StdError for Error {
 }
 
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Status {
     pub status_code: StatusCode,
     pub method: Method,
@@ -156,6 +191,59 @@ impl fmt::Display for Synchronization {
 
 impl StdError for Synchronization {}
 
+/// Error indicating that an operation did not complete within its allotted time.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Timeout {
+    /// How long the operation was allowed to run
+    pub duration: Duration,
+    /// Description of the operation that timed out
+    pub message: String,
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out after {:?}: {}", self.duration, self.message)
+    }
+}
+
+impl StdError for Timeout {}
+
+impl From<Timeout> for Error {
+    fn from(err: Timeout) -> Self {
+        Error::with_source(Kind::Timeout, err)
+    }
+}
+
+/// Error indicating that market data was older than the maximum age allowed for its use, e.g.
+/// because the request that fetched it waited on a rate limiter or was retried.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct StaleData {
+    /// How long ago the data was received
+    pub age: Duration,
+    /// The maximum age allowed
+    pub max_age: Duration,
+}
+
+impl fmt::Display for StaleData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "data received {:?} ago is older than the maximum age of {:?}",
+            self.age, self.max_age
+        )
+    }
+}
+
+impl StdError for StaleData {}
+
+impl From<StaleData> for Error {
+    fn from(err: StaleData) -> Self {
+        Error::with_source(Kind::StaleData, err)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct MissingContractConfig {
@@ -214,6 +302,208 @@ impl From<Geoblock> for Error {
     }
 }
 
+/// Error indicating that an order was denied by an
+/// [`OrderValidator`](crate::clob::hooks::OrderValidator).
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct OrderDenied {
+    /// The reason given by the validator
+    pub reason: String,
+}
+
+impl fmt::Display for OrderDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "order denied: {}", self.reason)
+    }
+}
+
+impl StdError for OrderDenied {}
+
+impl From<OrderDenied> for Error {
+    fn from(err: OrderDenied) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
+/// The pre-trade risk limit that rejected an order, see [`RiskRejected`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskLimit {
+    /// The notional of a single order
+    OrderNotional,
+    /// The open exposure in a single market
+    MarketExposure,
+    /// The open exposure in a single event
+    EventExposure,
+    /// The shares held in a single market
+    MarketShares,
+    /// The shares held in a single event
+    EventShares,
+    /// The cost of the shares held in a single market
+    MarketPosition,
+    /// The cost of the shares held in a single event
+    EventPosition,
+    /// The loss realized today
+    DailyLoss,
+    /// The kill switch, tripped manually or once the loss realized today reached the kill
+    /// threshold
+    KillSwitch,
+}
+
+/// Error indicating that an order was rejected by the client's pre-trade risk limits before
+/// being sent.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RiskRejected {
+    /// The limit that rejected the order
+    pub limit: RiskLimit,
+    /// The configured value of the limit
+    pub threshold: Decimal,
+    /// The value that reached or would have exceeded the limit
+    pub value: Decimal,
+}
+
+impl fmt::Display for RiskRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "order rejected by {:?} risk limit: {} exceeds {}",
+            self.limit, self.value, self.threshold
+        )
+    }
+}
+
+impl StdError for RiskRejected {}
+
+impl From<RiskRejected> for Error {
+    fn from(err: RiskRejected) -> Self {
+        Error::with_source(Kind::Risk, err)
+    }
+}
+
+/// Error indicating that the CLOB rejected an order because the maker's balance or allowance
+/// does not cover it.
+///
+/// Amounts are parsed from the CLOB's error message when present and converted from base units.
+/// The asset is known when the error is returned by
+/// [`Client::post_order`](crate::clob::Client::post_order), which spends collateral for buys and
+/// the outcome token for sells.
+#[cfg(feature = "clob")]
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct InsufficientBalance {
+    /// The type of the asset the order spends, if known
+    pub asset_type: Option<AssetType>,
+    /// The outcome token the order spends, for sells
+    pub token_id: Option<TokenId>,
+    /// Whether the allowance, rather than the balance, falls short
+    pub allowance: bool,
+    /// The amount the order requires
+    pub required: Option<Decimal>,
+    /// The balance or allowance available to the order
+    pub available: Option<Decimal>,
+    /// The message returned by the CLOB
+    pub message: String,
+    /// The HTTP error this was parsed from, kept as the source so that it can still be
+    /// downcast to
+    status: Option<Status>,
+}
+
+#[cfg(feature = "clob")]
+impl InsufficientBalance {
+    /// Parses a CLOB error message, such as an HTTP error body or the `error_msg` of a
+    /// [`PostOrderResponse`](crate::clob::types::response::PostOrderResponse), returning `None`
+    /// if it does not report an insufficient balance or allowance.
+    #[must_use]
+    pub fn parse(message: &str) -> Option<Self> {
+        let lowercase = message.to_lowercase();
+        if !lowercase.contains("not enough balance") && !lowercase.contains("balance / allowance") {
+            return None;
+        }
+
+        let details = lowercase
+            .split_once("->")
+            .map_or("", |(_, details)| details);
+        let amount = |keys: &[&str]| {
+            details.split(',').find_map(|pair| {
+                let (key, value) = pair.split_once(':')?;
+                if !keys.contains(&key.trim()) {
+                    return None;
+                }
+                let digits: String = value
+                    .trim()
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect();
+                let mut amount: Decimal = digits.parse().ok()?;
+                amount.set_scale(amount.scale() + 6).ok()?;
+                Some(amount.normalize())
+            })
+        };
+
+        Some(Self {
+            asset_type: None,
+            token_id: None,
+            allowance: lowercase.contains("allowance is not enough"),
+            required: amount(&["order amount", "order value", "required"]),
+            available: amount(&["balance", "allowance"]),
+            message: message.to_owned(),
+            status: None,
+        })
+    }
+
+    /// Attaches the HTTP error `status` this was parsed from as the source.
+    pub(crate) fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Returns the request with which
+    /// [`Client::update_balance_allowance`](crate::clob::Client::update_balance_allowance)
+    /// refreshes the CLOB's view of the spent asset, if the asset is known.
+    #[must_use]
+    pub fn update_request(&self) -> Option<UpdateBalanceAllowanceRequest> {
+        Some(
+            UpdateBalanceAllowanceRequest::builder()
+                .asset_type(self.asset_type.clone()?)
+                .maybe_token_id(self.token_id)
+                .build(),
+        )
+    }
+}
+
+#[cfg(feature = "clob")]
+impl fmt::Display for InsufficientBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shortfall = if self.allowance {
+            "allowance"
+        } else {
+            "balance"
+        };
+        write!(f, "insufficient {shortfall}")?;
+        if let (Some(required), Some(available)) = (self.required, self.available) {
+            write!(f, ": {required} required, {available} available")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "clob")]
+impl StdError for InsufficientBalance {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.status
+            .as_ref()
+            .map(|status| status as &(dyn StdError + 'static))
+    }
+}
+
+#[cfg(feature = "clob")]
+impl From<InsufficientBalance> for Error {
+    fn from(err: InsufficientBalance) -> Self {
+        Error::with_source(Kind::Status, err)
+    }
+}
+
 impl From<base64::DecodeError> for Error {
     fn from(e: base64::DecodeError) -> Self {
         Error::with_source(Kind::Internal, e)
@@ -244,6 +534,41 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl From<simd_json::Error> for Error {
+    fn from(e: simd_json::Error) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl From<ciborium::ser::Error<std::io::Error>> for Error {
+    fn from(e: ciborium::ser::Error<std::io::Error>) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl From<ciborium::de::Error<std::io::Error>> for Error {
+    fn from(e: ciborium::de::Error<std::io::Error>) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
+#[cfg(feature = "signing")]
 impl From<alloy::signers::Error> for Error {
     fn from(e: alloy::signers::Error) -> Self {
         Error::with_source(Kind::Internal, e)
@@ -298,6 +623,31 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "clob")]
+    #[test]
+    fn insufficient_balance_should_parse_messages() {
+        use rust_decimal_macros::dec;
+
+        let parsed = InsufficientBalance::parse(
+            r#"{"error":"not enough balance / allowance: the allowance is not enough -> allowance: 0, order amount: 2500000"}"#,
+        )
+        .unwrap();
+        assert!(parsed.allowance);
+        assert_eq!(parsed.required, Some(dec!(2.5)));
+        assert_eq!(parsed.available, Some(Decimal::ZERO));
+        assert_eq!(
+            parsed.to_string(),
+            "insufficient allowance: 2.5 required, 0 available"
+        );
+
+        let parsed = InsufficientBalance::parse("not enough balance / allowance").unwrap();
+        assert_eq!(parsed.required, None);
+        assert_eq!(parsed.available, None);
+        assert!(parsed.update_request().is_none());
+
+        assert!(InsufficientBalance::parse("invalid signature").is_none());
+    }
+
     #[test]
     fn geoblock_into_error_should_succeed() {
         let geoblock = Geoblock {
@@ -28,11 +28,9 @@
 use std::future::Future;
 
 use async_stream::try_stream;
+use bon::bon;
 use futures::Stream;
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use reqwest::Method;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 #[cfg(feature = "tracing")]
@@ -47,12 +45,15 @@ use super::types::request::{
     TagBySlugRequest, TagsRequest, TeamsRequest,
 };
 use super::types::response::{
-    Comment, Event, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
-    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+    Comment, Event, GameState, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults,
+    Series, SportsMarketTypesResponse, SportsMetadata, Tag, Team,
 };
 use crate::error::Error;
+use crate::transport::{self, HttpClient};
 use crate::{Result, ToQueryParams as _};
 
+const DEFAULT_HOST: &str = "https://gamma-api.polymarket.com";
+
 const MAX_LIMIT: i32 = 500;
 
 /// HTTP client for the Polymarket Gamma API.
@@ -78,16 +79,16 @@ const MAX_LIMIT: i32 = 500;
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    client: HttpClient,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://gamma-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
+#[bon]
 impl Client {
     /// Creates a new Gamma API client with a custom host URL.
     ///
@@ -99,17 +100,49 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
+        Self::builder().host(host).build()
+    }
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+    /// Creates a new Gamma API client for the production deployment at <https://gamma-api.polymarket.com>.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn production() -> Result<Client> {
+        Self::new(DEFAULT_HOST)
+    }
 
+    /// Returns a builder for a Gamma API client with custom transport options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use polymarket_client_sdk::gamma::Client;
+    /// use polymarket_client_sdk::transport::Config;
+    ///
+    /// let client = Client::builder()
+    ///     .transport(Config::builder().timeout(Duration::from_secs(10)).build())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The base URL for the Gamma API. Defaults to `https://gamma-api.polymarket.com`.
+        #[builder(default = DEFAULT_HOST)]
+        host: &str,
+        /// Options for the underlying HTTP transport.
+        #[builder(default)]
+        transport: transport::Config,
+    ) -> Result<Client> {
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: transport.build_client()?,
         })
     }
 
@@ -339,6 +372,31 @@ impl Client {
             .await
     }
 
+    /// Retrieves the live score and status of the game behind a sports event by its ID.
+    ///
+    /// Returns `None` if the event carries no game data. The event ID links the game to the
+    /// event's markets, so its state can be joined with CLOB prices without a third-party feed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event ID is invalid or the request fails.
+    pub async fn game_state(&self, request: &EventByIdRequest) -> Result<Option<GameState>> {
+        Ok(self.event_by_id(request).await?.game_state())
+    }
+
+    /// Retrieves the live score and status of the game behind a sports event by its slug, see
+    /// [`Self::game_state`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slug is invalid or the request fails.
+    pub async fn game_state_by_slug(
+        &self,
+        request: &EventBySlugRequest,
+    ) -> Result<Option<GameState>> {
+        Ok(self.event_by_slug(request).await?.game_state())
+    }
+
     /// Retrieves all tags associated with an event.
     ///
     /// Returns the categorization tags for a specific event, helping understand
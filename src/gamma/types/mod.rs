@@ -46,6 +46,33 @@ pub enum RelatedTagsStatus {
     Unknown(String),
 }
 
+/// The status of a sports game, see [`GameState`](response::GameState).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum GameStatus {
+    Scheduled,
+    InProgress,
+    Final,
+    Postponed,
+    Canceled,
+    /// Other status from the data provider (captures the raw value).
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for GameStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "Scheduled" => Self::Scheduled,
+            "InProgress" => Self::InProgress,
+            "Final" => Self::Final,
+            "Postponed" => Self::Postponed,
+            "Canceled" => Self::Canceled,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display)]
 #[non_exhaustive]
 pub enum ParentEntityType {
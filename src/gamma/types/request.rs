@@ -6,10 +6,11 @@
 use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use serde_with::{DisplayFromStr, serde_as, skip_serializing_none};
+use serde_with::{serde_as, skip_serializing_none};
 
 use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
-use crate::types::{Address, B256, Decimal, U256};
+use crate::serde_helpers::CanonicalDecimal;
+use crate::types::{Address, B256, Decimal, TokenId};
 
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
@@ -84,6 +85,7 @@ pub struct RelatedTagsBySlugRequest {
     pub status: Option<RelatedTagsStatus>,
 }
 
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
 #[non_exhaustive]
@@ -115,9 +117,13 @@ pub struct EventsRequest {
     pub include_template: Option<bool>,
     pub recurrence: Option<String>,
     pub closed: Option<bool>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub liquidity_min: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub liquidity_max: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub volume_min: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub volume_max: Option<Decimal>,
     pub start_date_min: Option<DateTime<Utc>>,
     pub start_date_max: Option<DateTime<Utc>>,
@@ -170,19 +176,22 @@ pub struct MarketsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub slug: Vec<String>,
-    #[serde_as(as = "Vec<DisplayFromStr>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
-    pub clob_token_ids: Vec<U256>,
+    pub clob_token_ids: Vec<TokenId>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub condition_ids: Vec<B256>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub market_maker_address: Vec<Address>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub liquidity_num_min: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub liquidity_num_max: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub volume_num_min: Option<Decimal>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub volume_num_max: Option<Decimal>,
     pub start_date_min: Option<DateTime<Utc>>,
     pub start_date_max: Option<DateTime<Utc>>,
@@ -197,6 +206,7 @@ pub struct MarketsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub sports_market_types: Vec<String>,
+    #[serde_as(as = "Option<CanonicalDecimal>")]
     pub rewards_min_size: Option<Decimal>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
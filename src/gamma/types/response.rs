@@ -10,8 +10,9 @@ use serde_with::NoneAsEmptyString;
 use serde_with::json::JsonString;
 use serde_with::{DisplayFromStr, StringWithSeparator, formats::CommaSeparator, serde_as};
 
+use crate::gamma::types::GameStatus;
 use crate::serde_helpers::StringFromAny;
-use crate::types::{Address, B256, Decimal, U256};
+use crate::types::{Address, B256, Decimal, TokenId, U256};
 
 /// Image optimization metadata.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
@@ -336,6 +337,58 @@ pub struct Event {
     pub home_team_name: Option<String>,
 }
 
+/// The live state of the game behind a sports [`Event`], see [`Event::game_state`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GameState {
+    /// The ID of the event, which links the game to its markets and their CLOB token IDs.
+    pub event_id: String,
+    pub slug: Option<String>,
+    pub game_id: Option<i64>,
+    /// The game status as reported by the data provider.
+    pub status: Option<GameStatus>,
+    /// The score as reported by the data provider, e.g. `"2-1"`.
+    pub score: Option<String>,
+    pub period: Option<String>,
+    pub elapsed: Option<String>,
+    pub live: bool,
+    pub ended: bool,
+    pub home_team: Option<String>,
+    pub away_team: Option<String>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl Event {
+    /// Returns the live state of the game behind this event, or `None` if the event carries no
+    /// game data, e.g. because it is not a sports event.
+    #[must_use]
+    pub fn game_state(&self) -> Option<GameState> {
+        if self.game_id.is_none()
+            && self.game_status.is_none()
+            && self.score.is_none()
+            && self.live.is_none()
+        {
+            return None;
+        }
+
+        Some(GameState {
+            event_id: self.id.clone(),
+            slug: self.slug.clone(),
+            game_id: self.game_id,
+            status: self.game_status.as_deref().map(GameStatus::from),
+            score: self.score.clone(),
+            period: self.period.clone(),
+            elapsed: self.elapsed.clone(),
+            live: self.live.unwrap_or_default(),
+            ended: self.ended.unwrap_or_default(),
+            home_team: self.home_team_name.clone(),
+            away_team: self.away_team_name.clone(),
+            finished_at: self.finished_timestamp,
+        })
+    }
+}
+
 /// A prediction market.
 #[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
@@ -419,7 +472,7 @@ pub struct Market {
     pub game_start_time: Option<String>,
     pub seconds_delay: Option<i32>,
     #[serde_as(as = "Option<JsonString>")]
-    pub clob_token_ids: Option<Vec<U256>>,
+    pub clob_token_ids: Option<Vec<TokenId>>,
     pub disqus_thread: Option<String>,
     pub short_outcomes: Option<String>,
     #[serde(rename = "teamAID")]
@@ -589,7 +642,7 @@ pub struct Series {
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct CommentPosition {
-    pub token_id: Option<U256>,
+    pub token_id: Option<TokenId>,
     pub position_size: Option<Decimal>,
 }
 
@@ -1,5 +1,20 @@
 #![cfg_attr(doc, doc = include_str!("../README.md"))]
 
+#[cfg(all(
+    any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma",
+        feature = "ws",
+        feature = "rtds",
+        feature = "ctf"
+    ),
+    not(any(feature = "rustls", feature = "native-tls"))
+))]
+compile_error!("a TLS backend is required: enable either the `rustls` or the `native-tls` feature");
+
+pub mod addresses;
 pub mod auth;
 #[cfg(feature = "bridge")]
 pub mod bridge;
@@ -12,18 +27,41 @@ pub mod data;
 pub mod error;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(feature = "ctf")]
+pub mod onchain;
+#[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+pub mod polymarket;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub mod transport;
 pub mod types;
 #[cfg(any(feature = "ws", feature = "rtds"))]
 pub mod ws;
 
 use std::fmt::Write as _;
+use std::sync::LazyLock;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+use std::time::Instant;
 
 use alloy::primitives::ChainId;
 use alloy::primitives::{B256, b256, keccak256};
+use bon::Builder;
+use dashmap::DashMap;
 use phf::phf_map;
+#[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+pub use polymarket::Polymarket;
 #[cfg(any(
     feature = "bridge",
     feature = "clob",
@@ -41,7 +79,7 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::error::Error;
-use crate::types::{Address, address};
+use crate::types::Address;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -53,36 +91,36 @@ pub const AMOY: ChainId = 80002;
 
 pub const PRIVATE_KEY_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 
-/// Timestamp in seconds since [`std::time::UNIX_EPOCH`]
-pub(crate) type Timestamp = i64;
+/// Unix timestamp in seconds since [`std::time::UNIX_EPOCH`]
+pub(crate) type UnixSeconds = i64;
 
 static CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
     137_u64 => ContractConfig {
-        exchange: address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
-        collateral: address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
-        conditional_tokens: address!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045"),
+        exchange: addresses::polygon::EXCHANGE,
+        collateral: addresses::polygon::COLLATERAL,
+        conditional_tokens: addresses::polygon::CONDITIONAL_TOKENS,
         neg_risk_adapter: None,
     },
     80002_u64 => ContractConfig {
-        exchange: address!("0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"),
-        collateral: address!("0x9c4e1703476e875070ee25b56a58b008cfb8fa78"),
-        conditional_tokens: address!("0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB"),
+        exchange: addresses::amoy::EXCHANGE,
+        collateral: addresses::amoy::COLLATERAL,
+        conditional_tokens: addresses::amoy::CONDITIONAL_TOKENS,
         neg_risk_adapter: None,
     },
 };
 
 static NEG_RISK_CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
     137_u64 => ContractConfig {
-        exchange: address!("0xC5d563A36AE78145C45a50134d48A1215220f80a"),
-        collateral: address!("0x2791bca1f2de4661ed88a30c99a7a9449aa84174"),
-        conditional_tokens: address!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045"),
-        neg_risk_adapter: Some(address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296")),
+        exchange: addresses::polygon::NEG_RISK_EXCHANGE,
+        collateral: addresses::polygon::COLLATERAL,
+        conditional_tokens: addresses::polygon::CONDITIONAL_TOKENS,
+        neg_risk_adapter: Some(addresses::polygon::NEG_RISK_ADAPTER),
     },
     80002_u64 => ContractConfig {
-        exchange: address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"),
-        collateral: address!("0x9c4e1703476e875070ee25b56a58b008cfb8fa78"),
-        conditional_tokens: address!("0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB"),
-        neg_risk_adapter: Some(address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296")),
+        exchange: addresses::amoy::NEG_RISK_EXCHANGE,
+        collateral: addresses::amoy::COLLATERAL,
+        conditional_tokens: addresses::amoy::CONDITIONAL_TOKENS,
+        neg_risk_adapter: Some(addresses::amoy::NEG_RISK_ADAPTER),
     },
 };
 
@@ -90,16 +128,26 @@ static NEG_RISK_CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
 // Source: https://github.com/Polymarket/builder-relayer-client
 static WALLET_CONFIG: phf::Map<ChainId, WalletContractConfig> = phf_map! {
     137_u64 => WalletContractConfig {
-        proxy_factory: Some(address!("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052")),
-        safe_factory: address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"),
+        proxy_factory: Some(addresses::polygon::PROXY_FACTORY),
+        safe_factory: addresses::polygon::SAFE_FACTORY,
     },
     80002_u64 => WalletContractConfig {
         // Proxy factory unsupported on Amoy testnet
         proxy_factory: None,
-        safe_factory: address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"),
+        safe_factory: addresses::amoy::SAFE_FACTORY,
     },
 };
 
+/// Contract configurations registered at runtime via [`register_contract_config`], keyed by chain
+/// ID and whether the config is for neg-risk markets. These take precedence over the built-in
+/// configs.
+static REGISTERED_CONFIG: LazyLock<DashMap<(ChainId, bool), &'static ContractConfig>> =
+    LazyLock::new(DashMap::new);
+
+/// Wallet contract configurations registered at runtime via [`register_wallet_contract_config`].
+static REGISTERED_WALLET_CONFIG: LazyLock<DashMap<ChainId, &'static WalletContractConfig>> =
+    LazyLock::new(DashMap::new);
+
 /// Init code hash for Polymarket Proxy wallets (EIP-1167 minimal proxy)
 const PROXY_INIT_CODE_HASH: B256 =
     b256!("0xd21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b");
@@ -110,7 +158,7 @@ const SAFE_INIT_CODE_HASH: B256 =
 
 /// Helper struct to group the relevant deployed contract addresses
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Clone, Debug, Builder)]
 pub struct ContractConfig {
     pub exchange: Address,
     pub collateral: Address,
@@ -122,7 +170,7 @@ pub struct ContractConfig {
 
 /// Wallet contract configuration for CREATE2 address derivation
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Clone, Debug, Builder)]
 pub struct WalletContractConfig {
     /// Factory contract for Polymarket Proxy wallets (Magic/email wallets).
     /// Not available on all networks (e.g., Amoy testnet).
@@ -132,8 +180,15 @@ pub struct WalletContractConfig {
 }
 
 /// Given a `chain_id` and `is_neg_risk`, return the relevant [`ContractConfig`]
+///
+/// Configs registered via [`register_contract_config`] take precedence over the built-in Polygon
+/// and Amoy configs.
 #[must_use]
 pub fn contract_config(chain_id: ChainId, is_neg_risk: bool) -> Option<&'static ContractConfig> {
+    if let Some(config) = REGISTERED_CONFIG.get(&(chain_id, is_neg_risk)) {
+        return Some(*config);
+    }
+
     if is_neg_risk {
         NEG_RISK_CONFIG.get(&chain_id)
     } else {
@@ -141,12 +196,55 @@ pub fn contract_config(chain_id: ChainId, is_neg_risk: bool) -> Option<&'static
     }
 }
 
+/// Registers the [`ContractConfig`] to use for `chain_id` and `is_neg_risk`, e.g. for a local fork
+/// (anvil) or an additional chain. Replaces any previously registered or built-in config for the
+/// same chain and market type.
+///
+/// Registered configs live for the remainder of the process, so this is intended to be called a
+/// handful of times during setup rather than in a loop.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_client_sdk::types::address;
+/// use polymarket_client_sdk::{ContractConfig, contract_config, register_contract_config};
+///
+/// let config = ContractConfig::builder()
+///     .exchange(address!("0x0000000000000000000000000000000000000001"))
+///     .collateral(address!("0x0000000000000000000000000000000000000002"))
+///     .conditional_tokens(address!("0x0000000000000000000000000000000000000003"))
+///     .build();
+/// register_contract_config(31337, false, config);
+///
+/// assert!(contract_config(31337, false).is_some());
+/// ```
+pub fn register_contract_config(chain_id: ChainId, is_neg_risk: bool, config: ContractConfig) {
+    let config: &'static ContractConfig = Box::leak(Box::new(config));
+    REGISTERED_CONFIG.insert((chain_id, is_neg_risk), config);
+}
+
 /// Returns the wallet contract configuration for the given chain ID.
+///
+/// Configs registered via [`register_wallet_contract_config`] take precedence over the built-in
+/// ones.
 #[must_use]
 pub fn wallet_contract_config(chain_id: ChainId) -> Option<&'static WalletContractConfig> {
+    if let Some(config) = REGISTERED_WALLET_CONFIG.get(&chain_id) {
+        return Some(*config);
+    }
+
     WALLET_CONFIG.get(&chain_id)
 }
 
+/// Registers the [`WalletContractConfig`] used to derive proxy and Safe wallet addresses on
+/// `chain_id`. Replaces any previously registered or built-in config for the same chain.
+///
+/// Like [`register_contract_config`], registered configs live for the remainder of the process.
+pub fn register_wallet_contract_config(chain_id: ChainId, config: WalletContractConfig) {
+    let config: &'static WalletContractConfig = Box::leak(Box::new(config));
+    REGISTERED_WALLET_CONFIG.insert(chain_id, config);
+}
+
 /// Derives the Polymarket Proxy wallet address for an EOA using CREATE2.
 ///
 /// This is the deterministic address of the EIP-1167 minimal proxy wallet
@@ -252,11 +350,11 @@ impl<T: Serialize> ToQueryParams for T {}
         )
     )
 )]
-async fn request<Response: DeserializeOwned>(
-    client: &reqwest::Client,
+async fn request_with_metadata<Response: DeserializeOwned>(
+    client: &transport::HttpClient,
     mut request: Request,
     headers: Option<HeaderMap>,
-) -> Result<Response> {
+) -> Result<transport::ResponseEnvelope<Response>> {
     let method = request.method().clone();
     let path = request.url().path().to_owned();
 
@@ -264,8 +362,10 @@ async fn request<Response: DeserializeOwned>(
         *request.headers_mut() = h;
     }
 
+    let started = Instant::now();
     let response = client.execute(request).await?;
     let status_code = response.status();
+    let response_headers = response.headers().clone();
 
     #[cfg(feature = "tracing")]
     tracing::Span::current().record("status_code", status_code.as_u16());
@@ -285,11 +385,18 @@ async fn request<Response: DeserializeOwned>(
         return Err(Error::status(status_code, method, path, message));
     }
 
-    let json_value = response.json::<serde_json::Value>().await?;
-    let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)?;
+    let mut bytes = response.bytes().await?.to_vec();
+    let latency = started.elapsed();
+    let response_data: Option<Response> = serde_helpers::deserialize_bytes(&mut bytes)?;
 
     if let Some(response) = response_data {
-        Ok(response)
+        Ok(transport::ResponseEnvelope {
+            body: response,
+            status: status_code,
+            headers: response_headers,
+            latency,
+            received_at: started + latency,
+        })
     } else {
         #[cfg(feature = "tracing")]
         tracing::warn!(method = %method, path = %path, "API resource not found");
@@ -302,9 +409,26 @@ async fn request<Response: DeserializeOwned>(
     }
 }
 
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+async fn request<Response: DeserializeOwned>(
+    client: &transport::HttpClient,
+    request: Request,
+    headers: Option<HeaderMap>,
+) -> Result<Response> {
+    request_with_metadata(client, request, headers)
+        .await
+        .map(transport::ResponseEnvelope::into_body)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::address;
 
     #[test]
     fn config_contains_80002() {
@@ -324,6 +448,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn registered_config_should_be_returned() {
+        const CHAIN_ID: ChainId = 31_337;
+
+        assert!(contract_config(CHAIN_ID, false).is_none());
+
+        let exchange = address!("0x0000000000000000000000000000000000000001");
+        register_contract_config(
+            CHAIN_ID,
+            false,
+            ContractConfig::builder()
+                .exchange(exchange)
+                .collateral(address!("0x0000000000000000000000000000000000000002"))
+                .conditional_tokens(address!("0x0000000000000000000000000000000000000003"))
+                .build(),
+        );
+
+        let cfg = contract_config(CHAIN_ID, false).expect("missing config");
+        assert_eq!(cfg.exchange, exchange);
+        assert_eq!(cfg.neg_risk_adapter, None);
+        assert!(contract_config(CHAIN_ID, true).is_none());
+    }
+
+    #[test]
+    fn registered_wallet_config_should_be_used_for_derivation() {
+        const CHAIN_ID: ChainId = 31_338;
+
+        let eoa = address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        assert!(derive_safe_wallet(eoa, CHAIN_ID).is_none());
+
+        register_wallet_contract_config(
+            CHAIN_ID,
+            WalletContractConfig::builder()
+                .safe_factory(address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"))
+                .build(),
+        );
+
+        // Same factory as Polygon, so the derived Safe address matches
+        assert_eq!(
+            derive_safe_wallet(eoa, CHAIN_ID),
+            derive_safe_wallet(eoa, POLYGON)
+        );
+        assert!(derive_proxy_wallet(eoa, CHAIN_ID).is_none());
+    }
+
     #[test]
     fn wallet_contract_config_polygon() {
         let cfg = wallet_contract_config(POLYGON).expect("missing config");
@@ -0,0 +1,185 @@
+//! On-chain balance watching.
+//!
+//! [`watch`] repeatedly reads the collateral (USDC) balance and the outcome token balances of an
+//! address straight from the chain, and yields a [`BalanceChange`] for every balance that differs
+//! from the previous read. Unlike the balances reported by the CLOB API, these reads reflect
+//! deposits, withdrawals, transfers and settlements as soon as they are mined.
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use std::future::IntoFuture as _;
+use std::time::Duration;
+
+use alloy::primitives::ChainId;
+use alloy::providers::Provider;
+use alloy::sol;
+use async_stream::stream;
+use futures::Stream;
+use tokio::time;
+
+use crate::error::{Error, Kind};
+use crate::types::{Address, Decimal, Shares, TokenId, U256, Usdc};
+use crate::{Result, contract_config};
+
+sol! {
+    #[sol(rpc)]
+    interface IBalances {
+        /// ERC-20 balance of `account`.
+        function balanceOf(address account) external view returns (uint256);
+
+        /// ERC-1155 balances of `accounts` for `ids`, pairwise.
+        function balanceOfBatch(address[] calldata accounts, uint256[] calldata ids)
+            external
+            view
+            returns (uint256[] memory);
+    }
+}
+
+/// An asset whose balance is watched.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Asset {
+    /// The collateral token (USDC).
+    Collateral,
+    /// An outcome token of the Conditional Tokens contract.
+    Outcome(TokenId),
+}
+
+/// A change of the on-chain balance of an [`Asset`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// The address whose balance changed.
+    pub owner: Address,
+    /// The asset whose balance changed.
+    pub asset: Asset,
+    /// The balance before the change, or `None` for the first read.
+    pub previous: Option<Decimal>,
+    /// The balance after the change.
+    pub balance: Decimal,
+}
+
+impl BalanceChange {
+    fn new(owner: Address, asset: Asset, previous: Option<U256>, balance: U256) -> Result<Self> {
+        let to_decimal = |units: U256| -> Result<Decimal> {
+            Ok(match asset {
+                Asset::Collateral => Usdc::from_base_units(units)?.as_decimal(),
+                Asset::Outcome(_) => Shares::from_base_units(units)?.as_decimal(),
+            })
+        };
+
+        Ok(Self {
+            owner,
+            asset,
+            previous: previous.map(to_decimal).transpose()?,
+            balance: to_decimal(balance)?,
+        })
+    }
+
+    /// Returns the signed change of the balance, which is the full balance for the first read.
+    #[must_use]
+    pub fn delta(&self) -> Decimal {
+        self.balance - self.previous.unwrap_or_default()
+    }
+}
+
+/// Reads the balances of `owner` on `chain_id` every `interval`, and yields a [`BalanceChange`]
+/// for the collateral and each of `tokens` on the first read and whenever their balance changes.
+/// Failed reads are yielded as errors and retried on the next poll.
+///
+/// Both the collateral and the outcome tokens are read in the same block, so the changes yielded
+/// for a poll reflect a consistent snapshot.
+///
+/// # Errors
+///
+/// The stream yields an error if no contract configuration is found for `chain_id`.
+pub fn watch<P: Provider>(
+    provider: &P,
+    chain_id: ChainId,
+    owner: Address,
+    tokens: Vec<TokenId>,
+    interval: Duration,
+) -> impl Stream<Item = Result<BalanceChange>> + '_ {
+    stream! {
+        let Some(config) = contract_config(chain_id, false) else {
+            yield Err(Error::validation(format!(
+                "CTF contract configuration not found for chain ID {chain_id}"
+            )));
+            return;
+        };
+        let collateral = IBalances::new(config.collateral, provider);
+        let conditional_tokens = IBalances::new(config.conditional_tokens, provider);
+        let accounts = vec![owner; tokens.len()];
+        let ids: Vec<U256> = tokens.iter().copied().map(Into::into).collect();
+        let mut previous: Vec<Option<U256>> = vec![None; tokens.len() + 1];
+
+        loop {
+            let block = match provider.get_block_number().await {
+                Ok(block) => block,
+                Err(e) => {
+                    yield Err(Error::with_source(Kind::Internal, e));
+                    time::sleep(interval).await;
+                    continue;
+                }
+            };
+            let collateral_call = collateral.balanceOf(owner).block(block.into());
+            let token_call = conditional_tokens
+                .balanceOfBatch(accounts.clone(), ids.clone())
+                .block(block.into());
+
+            let balances = match futures::try_join!(
+                collateral_call.call().into_future(),
+                token_call.call().into_future()
+            ) {
+                Ok((collateral_balance, token_balances)) => {
+                    std::iter::once(collateral_balance).chain(token_balances).collect::<Vec<_>>()
+                }
+                Err(e) => {
+                    yield Err(Error::with_source(Kind::Internal, e));
+                    time::sleep(interval).await;
+                    continue;
+                }
+            };
+
+            let assets = std::iter::once(Asset::Collateral).chain(tokens.iter().copied().map(Asset::Outcome));
+            for ((asset, balance), last) in assets.zip(balances).zip(previous.iter_mut()) {
+                if *last != Some(balance) {
+                    yield BalanceChange::new(owner, asset, *last, balance);
+                    *last = Some(balance);
+                }
+            }
+
+            time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn balance_change_should_convert_base_units() -> Result<()> {
+        let change = BalanceChange::new(
+            Address::ZERO,
+            Asset::Outcome(TokenId::new(U256::from(1))),
+            Some(U256::from(2_500_000)),
+            U256::from(1_000_000),
+        )?;
+
+        assert_eq!(change.previous, Some(dec!(2.5)));
+        assert_eq!(change.balance, dec!(1));
+        assert_eq!(change.delta(), dec!(-1.5));
+
+        let first = BalanceChange::new(Address::ZERO, Asset::Collateral, None, U256::from(5))?;
+        assert_eq!(first.delta(), dec!(0.000005));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,441 @@
+//! Exchange contract events.
+//!
+//! Every fill of an order emits an `OrderFilled` event from the exchange contract that settled
+//! it, and every match of a taker order against maker orders additionally emits an
+//! `OrdersMatched` event. Reading these events confirms fills from the chain, independently of
+//! the trades reported by the CLOB API. [`events`] reads the events of a block range,
+//! and [`watch_events`] polls for new ones.
+//!
+//! Events are read from the exchange contracts of both regular and neg-risk markets, as
+//! configured by [`contract_config`].
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use alloy::primitives::{B256, ChainId, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::{Filter, Log};
+use alloy::sol;
+use alloy::sol_types::SolEvent;
+use futures::{Stream, StreamExt as _, stream};
+use rust_decimal::Decimal;
+
+use crate::error::{Error, Kind};
+use crate::types::{Address, Shares, TokenId, Usdc};
+use crate::{Result, contract_config};
+
+sol! {
+    interface IExchange {
+        /// Emitted for every order filled, maker and taker orders alike.
+        event OrderFilled(
+            bytes32 indexed orderHash,
+            address indexed maker,
+            address indexed taker,
+            uint256 makerAssetId,
+            uint256 takerAssetId,
+            uint256 makerAmountFilled,
+            uint256 takerAmountFilled,
+            uint256 fee
+        );
+
+        /// Emitted once for every taker order matched against maker orders.
+        event OrdersMatched(
+            bytes32 indexed takerOrderHash,
+            address indexed takerOrderMaker,
+            uint256 makerAssetId,
+            uint256 takerAssetId,
+            uint256 makerAmountFilled,
+            uint256 takerAmountFilled
+        );
+
+        /// Emitted when an order is cancelled on-chain.
+        event OrderCancelled(bytes32 indexed orderHash);
+    }
+}
+
+/// Where an [`Event`] was emitted.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventLocation {
+    /// The exchange contract that emitted the event.
+    pub exchange: Address,
+    /// The hash of the transaction that emitted the event.
+    pub transaction_hash: B256,
+    /// The number of the block that included the transaction.
+    pub block_number: u64,
+    /// The index of the event in the block.
+    pub log_index: u64,
+}
+
+/// The outcome tokens and collateral exchanged by an order, from the point of view of the
+/// order's maker.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exchanged {
+    /// The outcome token traded.
+    pub token_id: TokenId,
+    /// Whether the order bought outcome tokens, i.e. paid collateral.
+    pub is_buy: bool,
+    /// The number of outcome tokens exchanged.
+    pub size: Shares,
+    /// The amount of collateral exchanged.
+    pub amount: Usdc,
+    /// The price per outcome token, i.e. `amount / size`.
+    pub price: Decimal,
+}
+
+impl Exchanged {
+    /// Builds the exchanged amounts from the raw event fields, where asset ID zero (0) is the
+    /// collateral.
+    fn new(
+        maker_asset_id: U256,
+        taker_asset_id: U256,
+        maker_amount: U256,
+        taker_amount: U256,
+    ) -> Result<Self> {
+        let (is_buy, token_id, collateral, tokens) = if maker_asset_id.is_zero() {
+            (true, taker_asset_id, maker_amount, taker_amount)
+        } else {
+            (false, maker_asset_id, taker_amount, maker_amount)
+        };
+
+        let size = Shares::from_base_units(tokens)?;
+        let amount = Usdc::from_base_units(collateral)?;
+        let price = amount
+            .as_decimal()
+            .checked_div(size.as_decimal())
+            .unwrap_or_default()
+            .normalize();
+
+        Ok(Self {
+            token_id: TokenId::new(token_id),
+            is_buy,
+            size,
+            amount,
+            price,
+        })
+    }
+}
+
+/// An order filled, see `OrderFilled`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderFilled {
+    /// The hash of the filled order.
+    pub order_hash: B256,
+    /// The maker of the filled order, i.e. the funder of its signer.
+    pub maker: Address,
+    /// The counterparty of the fill, i.e. the taker order's maker, or the exchange itself for
+    /// the taker order of a match.
+    pub taker: Address,
+    /// What the filled order exchanged.
+    pub exchanged: Exchanged,
+    /// The fee paid by the maker, in base units of the asset the maker received.
+    pub fee: U256,
+    /// Where the event was emitted.
+    pub location: EventLocation,
+}
+
+/// A taker order matched against maker orders, see `OrdersMatched`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrdersMatched {
+    /// The hash of the taker order.
+    pub taker_order_hash: B256,
+    /// The maker of the taker order.
+    pub taker_order_maker: Address,
+    /// What the taker order exchanged.
+    pub exchanged: Exchanged,
+    /// Where the event was emitted.
+    pub location: EventLocation,
+}
+
+/// An order cancelled on-chain, see `OrderCancelled`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderCancelled {
+    /// The hash of the cancelled order.
+    pub order_hash: B256,
+    /// Where the event was emitted.
+    pub location: EventLocation,
+}
+
+/// An event emitted by an exchange contract.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// An order filled.
+    OrderFilled(OrderFilled),
+    /// A taker order matched against maker orders.
+    OrdersMatched(OrdersMatched),
+    /// An order cancelled on-chain.
+    OrderCancelled(OrderCancelled),
+}
+
+impl Event {
+    /// Decodes an exchange event from `log`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `log` is not a mined exchange event, or if its amounts cannot be
+    /// represented.
+    pub fn decode(log: &Log) -> Result<Self> {
+        let location = EventLocation {
+            exchange: log.address(),
+            transaction_hash: log
+                .transaction_hash
+                .ok_or_else(|| missing("transaction hash"))?,
+            block_number: log.block_number.ok_or_else(|| missing("block number"))?,
+            log_index: log.log_index.ok_or_else(|| missing("log index"))?,
+        };
+
+        match log.topic0() {
+            Some(&IExchange::OrderFilled::SIGNATURE_HASH) => {
+                let event = decode::<IExchange::OrderFilled>(log)?;
+                Ok(Self::OrderFilled(OrderFilled {
+                    order_hash: event.orderHash,
+                    maker: event.maker,
+                    taker: event.taker,
+                    exchanged: Exchanged::new(
+                        event.makerAssetId,
+                        event.takerAssetId,
+                        event.makerAmountFilled,
+                        event.takerAmountFilled,
+                    )?,
+                    fee: event.fee,
+                    location,
+                }))
+            }
+            Some(&IExchange::OrdersMatched::SIGNATURE_HASH) => {
+                let event = decode::<IExchange::OrdersMatched>(log)?;
+                Ok(Self::OrdersMatched(OrdersMatched {
+                    taker_order_hash: event.takerOrderHash,
+                    taker_order_maker: event.takerOrderMaker,
+                    exchanged: Exchanged::new(
+                        event.makerAssetId,
+                        event.takerAssetId,
+                        event.makerAmountFilled,
+                        event.takerAmountFilled,
+                    )?,
+                    location,
+                }))
+            }
+            Some(&IExchange::OrderCancelled::SIGNATURE_HASH) => {
+                let event = decode::<IExchange::OrderCancelled>(log)?;
+                Ok(Self::OrderCancelled(OrderCancelled {
+                    order_hash: event.orderHash,
+                    location,
+                }))
+            }
+            _ => Err(Error::validation(format!(
+                "Log {} of transaction {} is not an exchange event",
+                location.log_index, location.transaction_hash
+            ))),
+        }
+    }
+
+    /// Returns where the event was emitted.
+    #[must_use]
+    pub const fn location(&self) -> &EventLocation {
+        match self {
+            Self::OrderFilled(event) => &event.location,
+            Self::OrdersMatched(event) => &event.location,
+            Self::OrderCancelled(event) => &event.location,
+        }
+    }
+}
+
+fn missing(field: &str) -> Error {
+    Error::validation(format!("Exchange event log is missing its {field}"))
+}
+
+fn decode<E: SolEvent>(log: &Log) -> Result<E> {
+    E::decode_log_data(log.data()).map_err(|e| Error::with_source(Kind::Internal, e))
+}
+
+/// Returns a filter matching the events of the exchange contracts on `chain_id`.
+///
+/// # Errors
+///
+/// Returns an error if no contract configuration is found for `chain_id`.
+pub fn filter(chain_id: ChainId) -> Result<Filter> {
+    let exchanges: Vec<Address> = [false, true]
+        .into_iter()
+        .filter_map(|is_neg_risk| contract_config(chain_id, is_neg_risk))
+        .map(|config| config.exchange)
+        .collect();
+    if exchanges.is_empty() {
+        return Err(Error::validation(format!(
+            "Exchange contract configuration not found for chain ID {chain_id}"
+        )));
+    }
+
+    Ok(Filter::new().address(exchanges).event_signature(vec![
+        IExchange::OrderFilled::SIGNATURE_HASH,
+        IExchange::OrdersMatched::SIGNATURE_HASH,
+        IExchange::OrderCancelled::SIGNATURE_HASH,
+    ]))
+}
+
+/// Returns the exchange events on `chain_id` emitted from `from_block` to `to_block`, both
+/// inclusive, in the order they were emitted.
+///
+/// # Errors
+///
+/// Returns an error if no contract configuration is found for `chain_id`, if the RPC call fails,
+/// or if an event cannot be decoded.
+pub async fn events<P: Provider>(
+    provider: &P,
+    chain_id: ChainId,
+    from_block: u64,
+    to_block: u64,
+) -> Result<Vec<Event>> {
+    let log_filter = filter(chain_id)?.from_block(from_block).to_block(to_block);
+    let logs = provider
+        .get_logs(&log_filter)
+        .await
+        .map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+    logs.iter()
+        .filter(|log| !log.removed)
+        .map(Event::decode)
+        .collect()
+}
+
+/// Polls for new exchange events on `chain_id` at the provider's poll interval, yielding them in
+/// the order they were emitted. The stream ends if polling fails.
+///
+/// Logs removed by a reorg are skipped, so events of the replaced blocks are yielded again once
+/// re-included.
+///
+/// # Errors
+///
+/// Returns an error if no contract configuration is found for `chain_id`, or if the log filter
+/// cannot be installed. The stream yields an error for every event that cannot be decoded.
+pub async fn watch_events<P: Provider>(
+    provider: &P,
+    chain_id: ChainId,
+) -> Result<impl Stream<Item = Result<Event>> + use<P>> {
+    let log_filter = filter(chain_id)?;
+    let poller = provider
+        .watch_logs(&log_filter)
+        .await
+        .map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+    Ok(poller
+        .into_stream()
+        .flat_map(stream::iter)
+        .filter(|log| futures::future::ready(!log.removed))
+        .map(|log| Event::decode(&log)))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn exchanged_should_take_maker_point_of_view() -> Result<()> {
+        let token = U256::from(42);
+
+        let buy = Exchanged::new(
+            U256::ZERO,
+            token,
+            U256::from(550_000),
+            U256::from(1_000_000),
+        )?;
+        assert!(buy.is_buy);
+        assert_eq!(buy.token_id, TokenId::new(token));
+        assert_eq!(buy.size.as_decimal(), dec!(1));
+        assert_eq!(buy.amount.as_decimal(), dec!(0.55));
+        assert_eq!(buy.price, dec!(0.55));
+
+        let sell = Exchanged::new(
+            token,
+            U256::ZERO,
+            U256::from(2_000_000),
+            U256::from(900_000),
+        )?;
+        assert!(!sell.is_buy);
+        assert_eq!(sell.size.as_decimal(), dec!(2));
+        assert_eq!(sell.price, dec!(0.45));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_should_succeed() -> Result<()> {
+        let exchange = address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+        let event = IExchange::OrderFilled {
+            orderHash: B256::repeat_byte(1),
+            maker: address!("0x1111111111111111111111111111111111111111"),
+            taker: address!("0x2222222222222222222222222222222222222222"),
+            makerAssetId: U256::from(42),
+            takerAssetId: U256::ZERO,
+            makerAmountFilled: U256::from(10_000_000),
+            takerAmountFilled: U256::from(6_000_000),
+            fee: U256::from(1_000),
+        };
+        let log = Log {
+            inner: alloy::primitives::Log {
+                address: exchange,
+                data: event.encode_log_data(),
+            },
+            transaction_hash: Some(B256::repeat_byte(2)),
+            block_number: Some(100),
+            log_index: Some(3),
+            ..Log::default()
+        };
+
+        let Event::OrderFilled(filled) = Event::decode(&log)? else {
+            panic!("expected an OrderFilled event");
+        };
+
+        assert_eq!(filled.order_hash, event.orderHash);
+        assert_eq!(filled.maker, event.maker);
+        assert_eq!(filled.taker, event.taker);
+        assert!(!filled.exchanged.is_buy);
+        assert_eq!(filled.exchanged.token_id, TokenId::new(U256::from(42)));
+        assert_eq!(filled.exchanged.size.as_decimal(), dec!(10));
+        assert_eq!(filled.exchanged.price, dec!(0.6));
+        assert_eq!(filled.fee, U256::from(1_000));
+        assert_eq!(
+            filled.location,
+            EventLocation {
+                exchange,
+                transaction_hash: B256::repeat_byte(2),
+                block_number: 100,
+                log_index: 3,
+            }
+        );
+
+        let unrelated = Log {
+            inner: alloy::primitives::Log {
+                address: exchange,
+                data: alloy::primitives::LogData::new_unchecked(
+                    vec![B256::ZERO],
+                    Vec::new().into(),
+                ),
+            },
+            ..log
+        };
+        Event::decode(&unrelated).unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_should_include_both_exchanges() -> Result<()> {
+        let log_filter = filter(crate::POLYGON)?;
+
+        assert_eq!(log_filter.address.len(), 2);
+        filter(1).unwrap_err();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! On-chain checks that complement the off-chain APIs.
+//!
+//! **Feature flag:** `ctf` (required to use this module)
+//!
+//! [`derive_proxy_wallet`](crate::derive_proxy_wallet) and
+//! [`derive_safe_wallet`](crate::derive_safe_wallet) compute the address at which Polymarket
+//! deploys a user's wallet, but that address only holds a contract once the wallet has actually
+//! been deployed (typically on the user's first login to polymarket.com). Orders funded by an
+//! undeployed wallet are rejected by the exchange, so [`is_wallet_deployed`] can be used to
+//! verify the wallet up front.
+//!
+//! [`exchange`] reads the order fill and cancellation events of the exchange contracts, to
+//! confirm fills from the chain independently of the CLOB API, and [`watch_balances`] streams the
+//! collateral and outcome token balances of an address (requires the `polling` feature).
+
+#[cfg(feature = "polling")]
+pub mod balances;
+pub mod exchange;
+
+use alloy::providers::Provider;
+#[cfg(feature = "polling")]
+pub use balances::watch as watch_balances;
+
+use crate::Result;
+use crate::error::{Error, Kind};
+use crate::types::Address;
+
+/// Returns whether a contract is deployed at `address`, i.e. whether it has non-empty code.
+///
+/// # Errors
+///
+/// Returns an error if the RPC call fails.
+pub async fn is_wallet_deployed<P: Provider>(provider: &P, address: Address) -> Result<bool> {
+    let code = provider
+        .get_code_at(address)
+        .await
+        .map_err(|e| Error::with_source(Kind::Internal, e))?;
+
+    Ok(!code.is_empty())
+}
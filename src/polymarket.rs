@@ -0,0 +1,174 @@
+//! A single entry point bundling the CLOB, Data and Gamma API clients.
+//!
+//! [`Polymarket`] configures every REST client from one builder, so they share the same
+//! [`transport::Config`] (timeouts, headers, proxy, retry policy and global rate limit) instead
+//! of each being set up separately. Per-path rate limits apply to each client separately, as
+//! the hosts serve different endpoints under the same paths, such as `/markets`. Authenticating
+//! the facade upgrades its CLOB client, which also serves the RFQ endpoints when the `rfq`
+//! feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::str::FromStr as _;
+//!
+//! use alloy::signers::Signer as _;
+//! use alloy::signers::local::LocalSigner;
+//! use polymarket_client_sdk::{POLYGON, Polymarket};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let signer = LocalSigner::from_str("0x...")?.with_chain_id(Some(POLYGON));
+//! let polymarket = Polymarket::builder()
+//!     .build()?
+//!     .authentication()
+//!     .signer(&signer)
+//!     .authenticate()
+//!     .await?;
+//!
+//! let ok = polymarket.clob().ok().await?;
+//! let health = polymarket.data().health().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloy::signers::Signer;
+use bon::bon;
+
+use crate::auth::state::{Authenticated, State, Unauthenticated};
+use crate::auth::{Credentials, Normal};
+use crate::clob::types::SignatureType;
+use crate::types::Address;
+use crate::{Result, clob, data, gamma, transport};
+
+/// The CLOB, Data and Gamma API clients, configured together.
+///
+/// Build one with [`Polymarket::builder`] and upgrade it with [`Polymarket::authentication`].
+/// Every client is cheap to clone, so the accessors hand out references that can be cloned
+/// into tasks as needed.
+#[derive(Clone, Debug)]
+pub struct Polymarket<S: State = Unauthenticated> {
+    clob: clob::Client<S>,
+    data: data::Client,
+    gamma: gamma::Client,
+}
+
+#[bon]
+impl Polymarket<Unauthenticated> {
+    /// Returns a builder for unauthenticated clients sharing one transport configuration.
+    ///
+    /// Every host defaults to the production deployment of its API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any host URL is invalid or an HTTP client cannot be initialized.
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The CLOB API URL. Defaults to <https://clob.polymarket.com>.
+        clob_host: Option<&str>,
+        /// The Data API URL. Defaults to <https://data-api.polymarket.com>.
+        data_host: Option<&str>,
+        /// The Gamma API URL. Defaults to <https://gamma-api.polymarket.com>.
+        gamma_host: Option<&str>,
+        /// CLOB client configuration options.
+        #[builder(default)]
+        config: clob::Config,
+        /// Options for the HTTP transport shared by every client. A global limit given as a
+        /// quota is shared by the three clients, while every client gets its own per-path
+        /// limiters.
+        #[builder(default)]
+        transport: transport::Config,
+    ) -> Result<Self> {
+        let transport = transport.share_global_limit();
+        let clob = clob::Client::builder()
+            .maybe_host(clob_host)
+            .config(config)
+            .transport(transport.clone())
+            .build()?;
+        let data = data::Client::builder()
+            .maybe_host(data_host)
+            .transport(transport.for_another_host())
+            .build()?;
+        let gamma = gamma::Client::builder()
+            .maybe_host(gamma_host)
+            .transport(transport.for_another_host())
+            .build()?;
+
+        Ok(Self { clob, data, gamma })
+    }
+
+    /// Upgrades the CLOB client to an authenticated one, keeping the Data and Gamma clients.
+    ///
+    /// Without `credentials`, a set is created or derived for the signer. See
+    /// [`clob::Client::authentication_builder`] for the meaning of each option.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if authenticating the CLOB client fails.
+    #[builder(finish_fn = authenticate)]
+    pub async fn authentication<S: Signer>(
+        self,
+        /// The wallet signer used to generate authentication and order signatures.
+        signer: &S,
+        /// Existing API credentials to use instead of creating or deriving new ones.
+        credentials: Option<Credentials>,
+        /// The nonce to create or derive credentials with.
+        nonce: Option<u32>,
+        /// The wallet holding the funds, for proxy and Safe wallets.
+        funder: Option<Address>,
+        /// The signature type of orders signed by this client.
+        signature_type: Option<SignatureType>,
+    ) -> Result<Polymarket<Authenticated<Normal>>> {
+        let mut builder = self.clob.authentication_builder(signer);
+        if let Some(credentials) = credentials {
+            builder = builder.credentials(credentials);
+        }
+        if let Some(nonce) = nonce {
+            builder = builder.nonce(nonce);
+        }
+        if let Some(funder) = funder {
+            builder = builder.funder(funder);
+        }
+        if let Some(signature_type) = signature_type {
+            builder = builder.signature_type(signature_type);
+        }
+
+        Ok(Polymarket {
+            clob: builder.authenticate().await?,
+            data: self.data,
+            gamma: self.gamma,
+        })
+    }
+}
+
+impl<S: State> Polymarket<S> {
+    /// Returns the CLOB API client.
+    #[must_use]
+    pub fn clob(&self) -> &clob::Client<S> {
+        &self.clob
+    }
+
+    /// Returns the Data API client.
+    #[must_use]
+    pub fn data(&self) -> &data::Client {
+        &self.data
+    }
+
+    /// Returns the Gamma API client.
+    #[must_use]
+    pub fn gamma(&self) -> &gamma::Client {
+        &self.gamma
+    }
+}
+
+#[cfg(feature = "rfq")]
+impl<K: crate::auth::Kind> Polymarket<Authenticated<K>> {
+    /// Returns the client serving the RFQ endpoints, which is the authenticated CLOB client.
+    ///
+    /// # Note
+    ///
+    /// Requires the `rfq` feature to be enabled.
+    #[must_use]
+    pub fn rfq(&self) -> &clob::Client<Authenticated<K>> {
+        &self.clob
+    }
+}
@@ -189,7 +189,42 @@ impl<S: State> Client<S> {
         }))
     }
 
-    /// Subscribe to Chainlink price feed updates.
+    /// Subscribes to real-time cryptocurrency price updates from Chainlink.
+    ///
+    /// Chainlink data streams are the reference prices that crypto markets, e.g. whether BTC
+    /// closes above a threshold, resolve against, so strategies trading those markets should
+    /// prefer them over the Binance prices of [`Self::subscribe_crypto_prices`].
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Optional feed symbol (e.g., `"btc/usd"`). If `None`, subscribes to all
+    ///   available feeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::rtds::Client;
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::default();
+    /// let stream = client.subscribe_chainlink_prices(Some("btc/usd".to_owned()))?;
+    ///
+    /// pin!(stream);
+    ///
+    /// while let Some(price_result) = stream.next().await {
+    ///     let price = price_result?;
+    ///     println!("BTC reference price: ${}", price.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn subscribe_chainlink_prices(
         &self,
         symbol: Option<String>,
@@ -9,7 +9,17 @@
     feature = "data",
     feature = "gamma",
 ))]
-use {serde::de::DeserializeOwned, serde_json::Value};
+use serde::de::DeserializeOwned;
+#[cfg(all(
+    any(not(feature = "simd-json"), feature = "tracing"),
+    any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma",
+    )
+))]
+use serde_json::Value;
 
 /// A `serde_as` type that deserializes strings or integers as `String`.
 ///
@@ -80,6 +90,35 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// A `serde_as` type that serializes a [`Decimal`](crate::types::Decimal) as a plain string
+/// without trailing zeros, e.g. `"0.5"` for `0.500`, and never in scientific notation.
+///
+/// Use with `#[serde_as(as = "Option<CanonicalDecimal>")]` for the `Decimal` fields of requests,
+/// so that the APIs receive exactly the value that was validated when the request was built.
+#[cfg(any(
+    all(feature = "clob", feature = "rfq"),
+    feature = "data",
+    feature = "gamma"
+))]
+pub struct CanonicalDecimal;
+
+#[cfg(any(
+    all(feature = "clob", feature = "rfq"),
+    feature = "data",
+    feature = "gamma"
+))]
+impl serde_with::SerializeAs<crate::types::Decimal> for CanonicalDecimal {
+    fn serialize_as<S>(
+        source: &crate::types::Decimal,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&source.normalize())
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -174,6 +213,7 @@ pub fn deserialize_with_warnings<T: DeserializeOwned>(value: Value) -> crate::Re
 /// Pass-through deserialization when tracing is disabled.
 #[cfg(all(
     not(feature = "tracing"),
+    not(feature = "simd-json"),
     any(
         feature = "bridge",
         feature = "clob",
@@ -185,6 +225,43 @@ pub fn deserialize_with_warnings<T: DeserializeOwned>(value: Value) -> crate::Re
     Ok(serde_json::from_value(value)?)
 }
 
+/// Deserialize a raw JSON response body.
+///
+/// With the `simd-json` feature enabled (and `tracing` disabled) the body is parsed in place by
+/// [`simd_json`], skipping the intermediate [`Value`]. Otherwise the body is parsed into a
+/// [`Value`] and handed to [`deserialize_with_warnings`], since unknown field detection needs the
+/// original JSON tree.
+#[cfg(all(
+    feature = "simd-json",
+    not(feature = "tracing"),
+    any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    )
+))]
+pub fn deserialize_bytes<T: DeserializeOwned>(bytes: &mut [u8]) -> crate::Result<T> {
+    Ok(simd_json::serde::from_slice(bytes)?)
+}
+
+/// Deserialize a raw JSON response body.
+///
+/// The body is parsed into a [`Value`] and handed to [`deserialize_with_warnings`].
+#[cfg(all(
+    any(not(feature = "simd-json"), feature = "tracing"),
+    any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    )
+))]
+pub fn deserialize_bytes<T: DeserializeOwned>(bytes: &mut [u8]) -> crate::Result<T> {
+    let value: Value = serde_json::from_slice(bytes)?;
+    deserialize_with_warnings(value)
+}
+
 /// Look up a value in a JSON structure by path.
 ///
 /// Handles paths from both `serde_ignored` and `serde_path_to_error`:
@@ -294,11 +371,14 @@ mod tests {
     use super::{format_value, lookup_value};
 
     // ========== deserialize_with_warnings tests ==========
-    #[cfg(any(
-        feature = "bridge",
-        feature = "clob",
-        feature = "data",
-        feature = "gamma"
+    #[cfg(all(
+        any(not(feature = "simd-json"), feature = "tracing"),
+        any(
+            feature = "bridge",
+            feature = "clob",
+            feature = "data",
+            feature = "gamma"
+        )
     ))]
     mod deserialize_with_warnings_tests {
         use serde::Deserialize;
@@ -447,6 +527,54 @@ mod tests {
         }
     }
 
+    // ========== deserialize_bytes tests ==========
+    // These run against `simd-json` when it is enabled without `tracing`, and against
+    // `serde_json` otherwise
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    mod deserialize_bytes_tests {
+        use serde::Deserialize;
+
+        use super::super::deserialize_bytes;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct TestStruct {
+            name: String,
+            values: Vec<u32>,
+            #[serde(default)]
+            optional: Option<bool>,
+        }
+
+        #[test]
+        fn deserialize_bytes_should_parse_body() {
+            let mut body = br#"{"name": "test", "values": [1, 2], "unknown": {"a": 1}}"#.to_vec();
+
+            let result: TestStruct = deserialize_bytes(&mut body).expect("deserialization failed");
+
+            assert_eq!(
+                result,
+                TestStruct {
+                    name: "test".to_owned(),
+                    values: vec![1, 2],
+                    optional: None,
+                }
+            );
+        }
+
+        #[test]
+        fn deserialize_bytes_should_fail_on_type_mismatch() {
+            let mut body = br#"{"name": 1, "values": []}"#.to_vec();
+
+            let result = deserialize_bytes::<TestStruct>(&mut body);
+
+            result.unwrap_err();
+        }
+    }
+
     // ========== StringFromAny tests ==========
     #[cfg(any(feature = "clob", feature = "gamma"))]
     mod string_from_any_tests {
@@ -593,6 +721,50 @@ mod tests {
         }
     }
 
+    // ========== CanonicalDecimal tests ==========
+    #[cfg(any(
+        all(feature = "clob", feature = "rfq"),
+        feature = "data",
+        feature = "gamma"
+    ))]
+    mod canonical_decimal_tests {
+        use rust_decimal_macros::dec;
+        use serde::Serialize;
+
+        use super::super::CanonicalDecimal;
+        use crate::types::Decimal;
+
+        #[derive(Serialize)]
+        struct Request {
+            #[serde(with = "serde_with::As::<Option<CanonicalDecimal>>")]
+            value: Option<Decimal>,
+        }
+
+        fn query(value: Decimal) -> String {
+            serde_html_form::to_string(Request { value: Some(value) }).expect("serializable")
+        }
+
+        #[test]
+        fn canonical_decimal_should_strip_trailing_zeros() {
+            assert_eq!(query(dec!(0.500)), "value=0.5");
+            assert_eq!(query(dec!(100.00)), "value=100");
+            assert_eq!(query(-Decimal::ZERO), "value=0");
+        }
+
+        #[test]
+        fn canonical_decimal_should_never_use_scientific_notation() {
+            assert_eq!(query(dec!(1e-6)), "value=0.000001");
+            assert_eq!(query(dec!(1e20)), "value=100000000000000000000");
+            assert_eq!(
+                serde_json::to_string(&Request {
+                    value: Some(Decimal::new(1, 28))
+                })
+                .expect("serializable"),
+                r#"{"value":"0.0000000000000000000000000001"}"#
+            );
+        }
+    }
+
     // ========== lookup_value tests ==========
 
     #[cfg(feature = "tracing")]
@@ -0,0 +1,727 @@
+//! HTTP transport configuration shared by the REST clients.
+//!
+//! Every REST client in this crate ([`crate::clob::Client`], [`crate::data::Client`],
+//! [`crate::gamma::Client`] and [`crate::bridge::Client`]) sends its requests through the same
+//! transport layer. [`Config`] controls how that transport is built: timeouts, default headers,
+//! the `User-Agent` (or a suffix appended to it), an optional proxy, client-side [`RateLimiters`],
+//! an optional [`RetryPolicy`] and connection pool, TCP keepalive and HTTP/2 tuning.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::num::NonZeroU32;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! use polymarket_client_sdk::transport::{Config, GlobalLimit, Quota, RateLimiters, RetryPolicy};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let rate_limiters = RateLimiters::default()
+//!     .with_path("/order", Quota::per_second(NonZeroU32::new(10).expect("non-zero")));
+//!
+//! let transport = Config::builder()
+//!     .timeout(Duration::from_secs(10))
+//!     .user_agent_suffix("my-bot/1.2")
+//!     .global_limit("50/s".parse::<GlobalLimit>()?.into_shared())
+//!     .rate_limiters(rate_limiters)
+//!     .retry_policy(RetryPolicy::default())
+//!     .pool_max_idle_per_host(16)
+//!     .http2_adaptive_window(true)
+//!     .build();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroU32;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use bon::Builder;
+use futures_timer::Delay;
+pub use governor::Quota;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::{Client as ReqwestClient, Method, Proxy, Request, Response, StatusCode};
+
+use crate::Result;
+use crate::error::Error;
+
+/// The `User-Agent` sent by every client unless overridden via [`Config`].
+pub const DEFAULT_USER_AGENT: &str = "rs_clob_client";
+
+/// A direct (un-keyed) rate limiter, as used by [`RateLimiters`].
+pub type Limiter = governor::DefaultDirectRateLimiter;
+
+/// Transport configuration applied when building a client's underlying HTTP connection pool.
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// Total timeout applied to every request, from connecting until the body has been read.
+    timeout: Option<Duration>,
+    /// Timeout applied to the connect phase only.
+    connect_timeout: Option<Duration>,
+    /// Additional headers sent with every request. These take precedence over the defaults.
+    #[builder(default)]
+    headers: HeaderMap,
+    /// Overrides the default `User-Agent` of [`DEFAULT_USER_AGENT`].
+    #[builder(into)]
+    user_agent: Option<String>,
+    /// Appended to the `User-Agent`, separated by a space, e.g. `my-bot/1.2`. Useful for
+    /// telling deployments apart server-side while keeping the SDK identifier.
+    #[builder(into)]
+    user_agent_suffix: Option<String>,
+    /// Proxy URL through which all requests are routed, e.g. `http://localhost:8080`.
+    #[builder(into)]
+    proxy: Option<String>,
+    /// Client-side rate limits applied before a request is sent.
+    rate_limiters: Option<RateLimiters>,
+    /// Limit applied to every request, replacing the global limiter of `rate_limiters`. Pass a
+    /// [`GlobalLimit::Shared`] limiter to make several clients draw from one budget.
+    #[builder(into)]
+    global_limit: Option<GlobalLimit>,
+    /// Retry behavior for transient failures. Retries are disabled when absent.
+    retry_policy: Option<RetryPolicy>,
+    /// Maximum number of idle connections kept per host. Unlimited when absent.
+    pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept in the pool. The default is ninety (90) seconds.
+    #[builder(default = Duration::from_secs(90))]
+    pool_idle_timeout: Duration,
+    /// Interval of TCP keepalive probes on open connections. The default is thirty (30) seconds.
+    #[builder(default = Duration::from_secs(30))]
+    tcp_keepalive: Duration,
+    /// Whether HTTP/2 uses an adaptive flow-control window (BDP estimation) instead of a fixed
+    /// one. This can improve throughput for large responses. The default is `false`.
+    #[builder(default)]
+    http2_adaptive_window: bool,
+    /// Interval of HTTP/2 PING frames used to keep connections alive. Disabled when absent.
+    http2_keep_alive_interval: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Config {
+    /// Turns a [`GlobalLimit::Quota`] into a shared limiter, so that every client built from a
+    /// clone of this configuration draws from the same budget.
+    #[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+    pub(crate) fn share_global_limit(mut self) -> Self {
+        self.global_limit = self.global_limit.map(GlobalLimit::into_shared);
+        self
+    }
+
+    /// Returns a clone of this configuration with its own per-path limiters, for a client of
+    /// another host. Only the global limits remain shared with this configuration, see
+    /// [`Self::share_global_limit`].
+    #[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+    pub(crate) fn for_another_host(&self) -> Self {
+        let mut config = self.clone();
+        config.rate_limiters = config.rate_limiters.map(RateLimiters::with_own_paths);
+        config
+    }
+
+    pub(crate) fn build_client(self) -> Result<HttpClient> {
+        let mut headers = HeaderMap::new();
+
+        let user_agent = self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        let user_agent = match &self.user_agent_suffix {
+            Some(suffix) => format!("{user_agent} {suffix}"),
+            None => user_agent.to_owned(),
+        };
+
+        headers.insert(USER_AGENT, HeaderValue::from_str(&user_agent)?);
+        headers.insert("Accept", HeaderValue::from_static("*/*"));
+        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
+        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+        headers.extend(self.headers);
+
+        let mut builder = ReqwestClient::builder()
+            .tcp_nodelay(true) // Disable Nagle's algorithm
+            .tcp_keepalive(Some(self.tcp_keepalive))
+            .pool_idle_timeout(Some(self.pool_idle_timeout))
+            .http2_adaptive_window(self.http2_adaptive_window)
+            .default_headers(headers);
+
+        // `native-tls` takes precedence when both backends are enabled, matching the WebSocket
+        // clients
+        #[cfg(feature = "native-tls")]
+        {
+            builder = builder.tls_backend_native();
+        }
+        #[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+        {
+            builder = builder.tls_backend_rustls();
+        }
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(Proxy::all(&proxy)?);
+        }
+
+        let mut rate_limiters = self.rate_limiters.unwrap_or_default();
+        if let Some(limit) = self.global_limit {
+            rate_limiters = rate_limiters.with_global(limit);
+        }
+
+        Ok(HttpClient {
+            client: builder.build()?,
+            rate_limiters,
+            retry_policy: self.retry_policy,
+            idempotent_posts: Arc::default(),
+        })
+    }
+}
+
+/// Client-side rate limits, consisting of an optional global limiter shared by every request and
+/// optional per-path limiters.
+///
+/// A request waits on the global limiter first, followed by the limiter registered for its URL
+/// path (e.g. `/order`), if any. Path segments wrapped in braces, such as in
+/// `/markets/{condition_id}`, match any single segment. A [`GlobalLimit::Shared`] limiter can be
+/// shared between several clients to enforce a single budget.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiters {
+    global: Option<Arc<Limiter>>,
+    paths: HashMap<String, PathLimiter>,
+    /// Shared by clones, and thus by every client built from the same [`Config`]
+    counters: Arc<LimiterCounters>,
+}
+
+/// How many requests passed through [`RateLimiters`] and how long they were held back, see
+/// [`RateLimiters::stats`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimiterStats {
+    /// The number of requests that passed through the limiters.
+    pub requests: u64,
+    /// The number of requests that had to wait for a limiter.
+    pub throttled: u64,
+    /// The total time requests waited for a limiter.
+    pub waited: Duration,
+}
+
+impl RateLimiterStats {
+    /// Returns the share of requests that had to wait for a limiter, or zero if there were none.
+    #[must_use]
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "Ratios are only exported for monitoring"
+    )]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "A ratio does not need the precision of large counts"
+    )]
+    pub fn throttled_ratio(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.throttled as f64 / self.requests as f64
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct LimiterCounters {
+    requests: AtomicU64,
+    throttled: AtomicU64,
+    waited_nanos: AtomicU64,
+}
+
+impl RateLimiters {
+    /// Sets the limit applied to every request, from a [`Quota`], a shared [`Limiter`] or a
+    /// [`GlobalLimit`].
+    #[must_use]
+    pub fn with_global<L: Into<GlobalLimit>>(mut self, limit: L) -> Self {
+        self.global = Some(limit.into().into_limiter());
+        self
+    }
+
+    /// Limits requests to `path` (e.g. `/order`) to `quota`.
+    #[must_use]
+    pub fn with_path(mut self, path: &str, quota: Quota) -> Self {
+        self.paths.insert(path.to_owned(), PathLimiter::new(quota));
+        self
+    }
+
+    /// Replaces every per-path limiter with a new one of the same quota, keeping the global
+    /// limiter. Clients built with the result then share the global budget but not the path
+    /// budgets, which must not be shared across hosts that serve the same paths.
+    #[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+    fn with_own_paths(mut self) -> Self {
+        for limiter in self.paths.values_mut() {
+            *limiter = PathLimiter::new(limiter.quota);
+        }
+        self
+    }
+
+    /// Returns how many requests passed through these limiters, and clones of them, and how long
+    /// they were held back.
+    #[must_use]
+    pub fn stats(&self) -> RateLimiterStats {
+        RateLimiterStats {
+            requests: self.counters.requests.load(Ordering::Relaxed),
+            throttled: self.counters.throttled.load(Ordering::Relaxed),
+            waited: Duration::from_nanos(self.counters.waited_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    async fn until_ready(&self, path: &str) {
+        let started = Instant::now();
+        let mut throttled = false;
+        for limiter in self.global.iter().chain(self.limiter(path)) {
+            // Only wait if the limiter has no capacity left, which `check` consumes otherwise
+            if limiter.check().is_err() {
+                throttled = true;
+                limiter.until_ready().await;
+            }
+        }
+
+        self.counters.requests.fetch_add(1, Ordering::Relaxed);
+        if throttled {
+            let waited = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            self.counters.throttled.fetch_add(1, Ordering::Relaxed);
+            self.counters
+                .waited_nanos
+                .fetch_add(waited, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the limiter for `path`, preferring an exact match over a templated one.
+    fn limiter(&self, path: &str) -> Option<&Arc<Limiter>> {
+        self.paths
+            .get(path)
+            .or_else(|| {
+                self.paths
+                    .iter()
+                    .find(|(template, _)| matches_template(template, path))
+                    .map(|(_, limiter)| limiter)
+            })
+            .map(|limiter| &limiter.limiter)
+    }
+}
+
+/// A per-path limiter of [`RateLimiters`], along with its quota to create a new one from.
+#[derive(Clone, Debug)]
+struct PathLimiter {
+    #[cfg_attr(
+        not(all(feature = "clob", feature = "data", feature = "gamma")),
+        expect(
+            dead_code,
+            reason = "Only read to give the facade's clients their own limiters"
+        )
+    )]
+    quota: Quota,
+    limiter: Arc<Limiter>,
+}
+
+impl PathLimiter {
+    fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            limiter: Arc::new(Limiter::direct(quota)),
+        }
+    }
+}
+
+/// The limit applied to every request of a client, see [`Config`] and [`RateLimiters::with_global`].
+///
+/// It parses from a quota string of the form `<count>/<unit>`, where the unit is `s`, `min` or
+/// `h`, e.g. `50/s` or `600/min`.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub enum GlobalLimit {
+    /// A limiter with this quota, created separately for every client it is applied to.
+    Quota(Quota),
+    /// A limiter shared by every client it is applied to, which thus share one budget.
+    Shared(Arc<Limiter>),
+}
+
+impl GlobalLimit {
+    /// Turns a [`Self::Quota`] into a [`Self::Shared`] limiter with the same quota, so that
+    /// clones of this limit share one budget.
+    #[must_use]
+    pub fn into_shared(self) -> Self {
+        Self::Shared(self.into_limiter())
+    }
+
+    fn into_limiter(self) -> Arc<Limiter> {
+        match self {
+            Self::Quota(quota) => Arc::new(Limiter::direct(quota)),
+            Self::Shared(limiter) => limiter,
+        }
+    }
+}
+
+impl From<Quota> for GlobalLimit {
+    fn from(quota: Quota) -> Self {
+        Self::Quota(quota)
+    }
+}
+
+impl From<Arc<Limiter>> for GlobalLimit {
+    fn from(limiter: Arc<Limiter>) -> Self {
+        Self::Shared(limiter)
+    }
+}
+
+impl FromStr for GlobalLimit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            Error::validation(format!(
+                "Unable to parse a rate limit from {s}, expected e.g. 50/s, 600/min or 3600/h"
+            ))
+        };
+
+        let (count, unit) = s.trim().split_once('/').ok_or_else(invalid)?;
+        let count = count.trim().parse::<NonZeroU32>().map_err(|_e| invalid())?;
+        let quota = match unit.trim() {
+            "s" | "sec" | "second" => Quota::per_second(count),
+            "m" | "min" | "minute" => Quota::per_minute(count),
+            "h" | "hour" => Quota::per_hour(count),
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self::Quota(quota))
+    }
+}
+
+/// Returns whether `path` matches `template`, in which `{..}` segments match any single segment.
+fn matches_template(template: &str, path: &str) -> bool {
+    if !template.contains('{') || template.split('/').count() != path.split('/').count() {
+        return false;
+    }
+
+    template
+        .split('/')
+        .zip(path.split('/'))
+        .all(|(expected, actual)| {
+            if expected.starts_with('{') && expected.ends_with('}') {
+                !actual.is_empty()
+            } else {
+                expected == actual
+            }
+        })
+}
+
+/// Exponential backoff policy for retrying transient failures.
+///
+/// Connection errors, timeouts, `429 Too Many Requests` and `502`/`503`/`504` responses are
+/// retried. Only idempotent requests are ever retried, so an order submission is never sent twice.
+/// By default, these are requests with an idempotent method (`GET`, `HEAD`, `OPTIONS`, `PUT` and
+/// `DELETE`). The CLOB client instead classifies each of its endpoints individually (see
+/// `clob::endpoint::Endpoint::is_idempotent`), so that reads sent as `POST`, such as order book
+/// batches, are retried as well.
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt. The default is three (3).
+    #[builder(default = 3)]
+    max_retries: u32,
+    /// Backoff before the first retry, doubled for each subsequent one. The default is one
+    /// hundred (100) milliseconds.
+    #[builder(default = Duration::from_millis(100))]
+    initial_backoff: Duration,
+    /// Upper bound for the backoff between retries. The default is five (5) seconds.
+    #[builder(default = Duration::from_secs(5))]
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+
+    fn is_retryable_method(method: &Method) -> bool {
+        matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        )
+    }
+
+    fn is_retryable_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+}
+
+/// A successfully deserialized response body together with the metadata of the HTTP response it
+/// was read from, such as rate-limit hints and request IDs in its headers.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct ResponseEnvelope<T> {
+    /// The deserialized response body.
+    pub body: T,
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The headers of the response.
+    pub headers: HeaderMap,
+    /// The time from sending the request until its body had been read, including any retries
+    /// and rate-limiting waits.
+    pub latency: Duration,
+    /// The instant the body had been read, from which the age of the data it carries is measured,
+    /// see [`Fresh`](crate::clob::freshness::Fresh).
+    pub received_at: Instant,
+}
+
+impl<T> ResponseEnvelope<T> {
+    /// Discards the metadata, returning only the response body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// Wraps a response produced locally in dry-run mode, which was never sent.
+    #[cfg(feature = "clob")]
+    pub(crate) fn simulated(body: T) -> Self {
+        Self {
+            body,
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            latency: Duration::ZERO,
+            received_at: Instant::now(),
+        }
+    }
+}
+
+/// The HTTP client used by the REST clients, which applies the [`RateLimiters`] and
+/// [`RetryPolicy`] from [`Config`] to every request it executes.
+#[derive(Clone, Debug)]
+pub(crate) struct HttpClient {
+    client: ReqwestClient,
+    rate_limiters: RateLimiters,
+    retry_policy: Option<RetryPolicy>,
+    /// Exact URL paths of `POST` endpoints that are idempotent, and thus retryable, on top of
+    /// the requests with an idempotent method.
+    idempotent_posts: Arc<HashSet<String>>,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Config::default()
+            .build_client()
+            .expect("Default transport should succeed")
+    }
+}
+
+impl Deref for HttpClient {
+    type Target = ReqwestClient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl HttpClient {
+    /// Classifies `POST` requests to exactly these URL `paths` as idempotent, and thus
+    /// retryable.
+    #[cfg(feature = "clob")]
+    pub(crate) fn with_idempotent_posts<I: IntoIterator<Item = String>>(
+        mut self,
+        paths: I,
+    ) -> Self {
+        self.idempotent_posts = Arc::new(paths.into_iter().collect());
+        self
+    }
+
+    fn is_idempotent(&self, method: &Method, path: &str) -> bool {
+        RetryPolicy::is_retryable_method(method)
+            || (*method == Method::POST && self.idempotent_posts.contains(path))
+    }
+
+    /// Returns the rate limiters applied to every request.
+    #[cfg(all(feature = "clob", feature = "metrics"))]
+    pub(crate) fn rate_limiters(&self) -> &RateLimiters {
+        &self.rate_limiters
+    }
+
+    /// Executes `request`, waiting on any applicable rate limiters and retrying transient
+    /// failures according to the [`RetryPolicy`].
+    pub(crate) async fn execute(&self, request: Request) -> Result<Response> {
+        let path = request.url().path().to_owned();
+        let policy = self
+            .retry_policy
+            .as_ref()
+            .filter(|_| self.is_idempotent(request.method(), &path));
+
+        let mut attempt = 0;
+        let mut request = request;
+        loop {
+            let retry = policy
+                .filter(|policy| attempt < policy.max_retries)
+                .and_then(|_| request.try_clone());
+
+            self.rate_limiters.until_ready(&path).await;
+            let result = self.client.execute(request).await;
+
+            let (Some(policy), Some(next)) = (policy, retry) else {
+                return Ok(result?);
+            };
+
+            let retryable = match &result {
+                Ok(response) => RetryPolicy::is_retryable_status(response.status()),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if !retryable {
+                return Ok(result?);
+            }
+
+            let backoff = policy.backoff(attempt);
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                path = %path,
+                attempt = attempt + 1,
+                backoff_ms = backoff.as_millis(),
+                "retrying request"
+            );
+
+            Delay::new(backoff).await;
+            attempt += 1;
+            request = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_should_double_until_max() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .build();
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn global_limit_should_parse_quota_strings() -> anyhow::Result<()> {
+        let GlobalLimit::Quota(quota) = "50/s".parse::<GlobalLimit>()? else {
+            panic!("expected a quota");
+        };
+        assert_eq!(
+            quota,
+            Quota::per_second(NonZeroU32::new(50).expect("non-zero"))
+        );
+
+        let GlobalLimit::Quota(quota) = " 600 / min ".parse::<GlobalLimit>()? else {
+            panic!("expected a quota");
+        };
+        assert_eq!(
+            quota,
+            Quota::per_minute(NonZeroU32::new(600).expect("non-zero"))
+        );
+
+        "0/s".parse::<GlobalLimit>().unwrap_err();
+        "50/day".parse::<GlobalLimit>().unwrap_err();
+        "fifty".parse::<GlobalLimit>().unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_global_limit_should_reuse_limiter() {
+        let quota = Quota::per_second(NonZeroU32::MIN);
+        let shared = GlobalLimit::from(quota).into_shared();
+        let first = RateLimiters::default().with_global(shared.clone());
+        let second = RateLimiters::default().with_global(shared);
+
+        assert!(Arc::ptr_eq(
+            first.global.as_ref().expect("global limiter"),
+            second.global.as_ref().expect("global limiter"),
+        ));
+    }
+
+    #[test]
+    fn path_limiters_should_match_templates() {
+        let quota = Quota::per_second(std::num::NonZeroU32::MIN);
+        let limiters = RateLimiters::default()
+            .with_path("/markets", quota)
+            .with_path("/markets/{condition_id}", quota);
+
+        assert!(limiters.limiter("/markets").is_some());
+        assert!(limiters.limiter("/markets/0x1").is_some());
+        assert!(limiters.limiter("/markets/").is_none());
+        assert!(limiters.limiter("/markets/0x1/extra").is_none());
+        assert!(limiters.limiter("/order").is_none());
+    }
+
+    #[test]
+    fn rate_limiters_should_count_throttled_requests() {
+        let quota = Quota::with_period(Duration::from_millis(5)).expect("non-zero period");
+        let limiters = RateLimiters::default().with_path("/order", quota);
+        let clone = limiters.clone();
+
+        futures::executor::block_on(async {
+            limiters.until_ready("/order").await;
+            clone.until_ready("/order").await;
+            limiters.until_ready("/book").await;
+        });
+
+        let stats = limiters.stats();
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.throttled, 1);
+        assert!(stats.waited > Duration::ZERO);
+        assert_eq!(clone.stats(), stats);
+        assert!((stats.throttled_ratio() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn build_client_with_connection_tuning_should_succeed() -> anyhow::Result<()> {
+        let config = Config::builder()
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(10))
+            .tcp_keepalive(Duration::from_secs(5))
+            .http2_adaptive_window(true)
+            .http2_keep_alive_interval(Duration::from_secs(15))
+            .build();
+
+        config.build_client()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_idempotent_methods_should_be_retryable() {
+        assert!(RetryPolicy::is_retryable_method(&Method::GET));
+        assert!(RetryPolicy::is_retryable_method(&Method::DELETE));
+        assert!(!RetryPolicy::is_retryable_method(&Method::POST));
+    }
+}
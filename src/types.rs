@@ -1,8 +1,12 @@
-//! Re-exported types from external crates for convenience.
+//! Re-exported types from external crates for convenience, along with the [`TokenId`],
+//! [`Usdc`] and [`Shares`] types.
 //!
 //! These types are commonly used in this SDK and are re-exported here
 //! so users don't need to add these dependencies to their `Cargo.toml`.
 
+use std::fmt;
+use std::str::FromStr;
+
 /// Ethereum address type and the [`address!`] macro for compile-time address literals.
 /// [`ChainId`] is a type alias for `u64` representing EVM chain IDs.
 /// [`Signature`] represents cryptographic signatures for signed orders.
@@ -21,3 +25,397 @@ pub use rust_decimal::Decimal;
 /// let price = dec!(0.55);
 /// ```
 pub use rust_decimal_macros::dec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Result;
+use crate::error::Error;
+
+/// The ERC-1155 token ID of a market outcome, also called asset ID by the APIs.
+///
+/// Kept distinct from a bare [`U256`] so that it cannot be confused with a condition ID or an
+/// amount. It is serialized as a decimal string and deserialized from a decimal string, a
+/// `0x`-prefixed hex string or a number.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TokenId(U256);
+
+impl TokenId {
+    /// Creates a token ID from its numeric value.
+    #[must_use]
+    pub const fn new(id: U256) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric value of this token ID.
+    #[must_use]
+    pub const fn as_u256(&self) -> U256 {
+        self.0
+    }
+}
+
+impl From<U256> for TokenId {
+    fn from(id: U256) -> Self {
+        Self(id)
+    }
+}
+
+impl From<TokenId> for U256 {
+    fn from(id: TokenId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for TokenId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        U256::from_str(s.trim())
+            .map(Self)
+            .map_err(|e| Error::validation(format!("Unable to parse token ID from {s}: {e}")))
+    }
+}
+
+impl fmt::Display for TokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for TokenId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        U256::deserialize(deserializer).map(Self)
+    }
+}
+
+macro_rules! token_amount {
+    ($(#[$meta:meta])* $name:ident, $unit:literal) => {
+        $(#[$meta])*
+        ///
+        /// Values are non-negative and have at most [`Self::DECIMALS`] decimal places, so they
+        /// always convert losslessly to and from on-chain base units. Converting into a [`Decimal`]
+        /// yields the human-readable value, converting into a [`U256`] yields base units.
+        #[derive(
+            Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+        )]
+        #[serde(try_from = "Decimal", into = "Decimal")]
+        pub struct $name(Decimal);
+
+        impl $name {
+            /// Number of decimal places of the on-chain representation.
+            pub const DECIMALS: u32 = 6;
+
+            /// Zero
+            pub const ZERO: Self = Self(Decimal::ZERO);
+
+            #[doc = concat!("Creates an amount from a human-readable number of ", $unit, ".")]
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `value` is negative or has more than [`Self::DECIMALS`] decimal
+            /// places.
+            pub fn new(value: Decimal) -> Result<Self> {
+                let normalized = value.normalize();
+                if normalized.is_sign_negative() {
+                    return Err(Error::validation(format!(
+                        "Unable to build {} from negative value {value}",
+                        stringify!($name)
+                    )));
+                }
+                if normalized.scale() > Self::DECIMALS {
+                    return Err(Error::validation(format!(
+                        "Unable to build {} with {} decimal points, must be <= {}",
+                        stringify!($name),
+                        normalized.scale(),
+                        Self::DECIMALS
+                    )));
+                }
+
+                Ok(Self(normalized))
+            }
+
+            /// Creates an amount from on-chain base units, e.g. `1_000_000` for `1`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `units` is too large to be represented as a [`Decimal`].
+            pub fn from_base_units(units: U256) -> Result<Self> {
+                let too_large = || {
+                    Error::validation(format!(
+                        "Unable to build {} from {units} base units, value is too large",
+                        stringify!($name)
+                    ))
+                };
+
+                let units = i128::try_from(units).map_err(|_e| too_large())?;
+                let value = Decimal::try_from_i128_with_scale(units, Self::DECIMALS)
+                    .map_err(|_e| too_large())?;
+
+                Ok(Self(value.normalize()))
+            }
+
+            /// Parses an amount from a string of on-chain base units, as returned by the API.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `units` is not a non-negative integer or is too large to be
+            /// represented as a [`Decimal`].
+            pub fn from_base_units_str(units: &str) -> Result<Self> {
+                let parsed = U256::from_str_radix(units.trim(), 10).map_err(|e| {
+                    Error::validation(format!(
+                        "Unable to parse {} base units from {units}: {e}",
+                        stringify!($name)
+                    ))
+                })?;
+
+                Self::from_base_units(parsed)
+            }
+
+            /// Returns the amount in on-chain base units.
+            #[must_use]
+            pub fn to_base_units(self) -> U256 {
+                let mut value = self.0;
+                value.rescale(Self::DECIMALS);
+
+                U256::from(value.mantissa().unsigned_abs())
+            }
+
+            /// Returns the human-readable value.
+            #[must_use]
+            pub const fn as_decimal(self) -> Decimal {
+                self.0
+            }
+        }
+
+        impl TryFrom<Decimal> for $name {
+            type Error = Error;
+
+            fn try_from(value: Decimal) -> std::result::Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for Decimal {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$name> for U256 {
+            fn from(value: $name) -> Self {
+                value.to_base_units()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+token_amount!(
+    /// An amount of USDC collateral.
+    Usdc,
+    "USDC"
+);
+
+token_amount!(
+    /// An amount of outcome token shares.
+    Shares,
+    "shares"
+);
+
+/// A point in time, kept at millisecond precision.
+///
+/// The APIs return unix timestamps in seconds or in milliseconds depending on the endpoint.
+/// When deserialized from a number or a numeric string, values below `10^11` are read as seconds
+/// and larger ones as milliseconds, which only misreads millisecond timestamps before March 1973.
+/// It serializes as unix milliseconds.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// Values at or above this many units since the epoch are taken to be milliseconds.
+    const MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+    /// Creates a timestamp from unix seconds.
+    #[must_use]
+    pub const fn from_secs(secs: i64) -> Self {
+        Self(secs.saturating_mul(1000))
+    }
+
+    /// Creates a timestamp from unix milliseconds.
+    #[must_use]
+    pub const fn from_millis(millis: i64) -> Self {
+        Self(millis)
+    }
+
+    /// Returns the current time.
+    #[must_use]
+    pub fn now() -> Self {
+        Utc::now().into()
+    }
+
+    /// Returns the unix timestamp in whole seconds, rounded down.
+    #[must_use]
+    pub const fn as_secs(self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    /// Returns the unix timestamp in milliseconds.
+    #[must_use]
+    pub const fn as_millis(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the timestamp as a [`DateTime`], or `None` if it is out of range.
+    #[must_use]
+    pub const fn to_datetime(self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(self.0)
+    }
+
+    const fn from_unknown_unit(value: i64) -> Self {
+        if value.unsigned_abs() < Self::MILLIS_THRESHOLD.unsigned_abs() {
+            Self::from_secs(value)
+        } else {
+            Self::from_millis(value)
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value.timestamp_millis())
+    }
+}
+
+impl TryFrom<Timestamp> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> std::result::Result<Self, Self::Error> {
+        value
+            .to_datetime()
+            .ok_or_else(|| Error::validation(format!("Timestamp {} is out of range", value.0)))
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.trim()
+            .parse::<i64>()
+            .map(Self::from_unknown_unit)
+            .map_err(|e| Error::validation(format!("Unable to parse timestamp from {s}: {e}")))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_datetime() {
+            Some(datetime) => datetime.fmt(f),
+            None => self.0.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(value) => Ok(Self::from_unknown_unit(value)),
+            Raw::Text(text) => text.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_id_serde_should_use_decimal_strings() -> Result<()> {
+        let id: TokenId = serde_json::from_str("\"12345\"")?;
+
+        assert_eq!(id, TokenId::new(U256::from(12345)));
+        assert_eq!(serde_json::to_string(&id)?, "\"12345\"");
+        assert_eq!(serde_json::from_str::<TokenId>("\"0x3039\"")?, id);
+        assert_eq!("12345".parse::<TokenId>()?, id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_should_validate() {
+        assert_eq!(Usdc::new(dec!(1.500000)).unwrap().as_decimal(), dec!(1.5));
+        Usdc::new(dec!(0.0000001)).unwrap_err();
+        Shares::new(dec!(-1)).unwrap_err();
+    }
+
+    #[test]
+    fn base_units_should_round_trip() -> Result<()> {
+        let usdc = Usdc::from_base_units_str("1234567")?;
+
+        assert_eq!(usdc.as_decimal(), dec!(1.234567));
+        assert_eq!(usdc.to_base_units(), U256::from(1_234_567));
+        assert_eq!(
+            Shares::new(dec!(50))?.to_base_units(),
+            U256::from(50_000_000)
+        );
+        assert_eq!(Shares::ZERO.to_base_units(), U256::ZERO);
+
+        Usdc::from_base_units_str("-1").unwrap_err();
+        Usdc::from_base_units_str("1.5").unwrap_err();
+        Usdc::from_base_units(U256::MAX).unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_should_detect_seconds_and_milliseconds() -> Result<()> {
+        let secs: Timestamp = serde_json::from_str("1744936318")?;
+        let millis: Timestamp = serde_json::from_str("\"1744936318000\"")?;
+
+        assert_eq!(secs, millis);
+        assert_eq!(secs, Timestamp::from_secs(1_744_936_318));
+        assert_eq!(secs.as_millis(), 1_744_936_318_000);
+        assert_eq!(Timestamp::from_millis(1_999).as_secs(), 1);
+        assert_eq!(serde_json::to_string(&secs)?, "1744936318000");
+        assert_eq!(
+            secs.to_datetime(),
+            DateTime::from_timestamp(1_744_936_318, 0)
+        );
+        serde_json::from_str::<Timestamp>("\"soon\"").unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_should_use_decimal() -> Result<()> {
+        let usdc: Usdc = serde_json::from_str("\"2.5\"")?;
+
+        assert_eq!(usdc, Usdc::new(dec!(2.5))?);
+        assert_eq!(serde_json::to_string(&usdc)?, "\"2.5\"");
+        serde_json::from_str::<Usdc>("\"-2.5\"").unwrap_err();
+
+        Ok(())
+    }
+}
@@ -9,12 +9,17 @@ use alloy::signers::local::LocalSigner;
 use httpmock::MockServer;
 use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::auth::{Credentials, ExposeSecret as _};
+use polymarket_client_sdk::clob::session::Session;
 use polymarket_client_sdk::clob::{Client, Config};
 use polymarket_client_sdk::error::{Kind, Synchronization, Validation};
+use polymarket_client_sdk::types::{TokenId, U256};
 use reqwest::StatusCode;
 use serde_json::json;
 
-use crate::common::{API_KEY, PASSPHRASE, POLY_ADDRESS, PRIVATE_KEY, SECRET, create_authenticated};
+use crate::common::{
+    API_KEY, PASSPHRASE, POLY_ADDRESS, POLY_API_KEY, POLY_PASSPHRASE, PRIVATE_KEY, SECRET,
+    create_authenticated,
+};
 
 #[tokio::test]
 async fn authenticate_with_explicit_credentials_should_succeed() -> anyhow::Result<()> {
@@ -124,6 +129,87 @@ async fn authenticate_with_multiple_strong_references_should_fail() -> anyhow::R
     Ok(())
 }
 
+#[tokio::test]
+async fn authenticate_shared_with_multiple_strong_references_should_succeed() -> anyhow::Result<()>
+{
+    let server = MockServer::start();
+
+    let tick_size_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/tick-size");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "minimum_tick_size": 0.01 }));
+    });
+
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = Client::new(&server.base_url(), Config::default())?;
+    client.tick_size(TokenId::new(U256::from(1))).await?;
+
+    let client_clone = client.clone();
+
+    let authenticated = client
+        .authentication_builder(&signer)
+        .credentials(Credentials::default())
+        .authenticate_shared()
+        .await?;
+
+    // The tick size cache was carried over, so no further requests are made
+    authenticated.tick_size(TokenId::new(U256::from(1))).await?;
+    tick_size_mock.assert_calls(1);
+
+    // The original client is left untouched and keeps its own cache
+    client_clone.tick_size(TokenId::new(U256::from(1))).await?;
+    tick_size_mock.assert_calls(1);
+    assert_eq!(signer.address(), authenticated.address());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_and_resume_session_should_succeed() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = create_authenticated(&server).await?;
+
+    let session = serde_json::to_string(&client.export_session())?;
+    let session: Session = serde_json::from_str(&session)?;
+
+    let resumed = Client::from_session(&server.base_url(), Config::default(), session).await?;
+    assert_eq!(resumed.address(), client.address());
+    assert_eq!(resumed.credentials().key(), API_KEY);
+
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/auth/api-keys")
+            .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+            .header(POLY_API_KEY, API_KEY)
+            .header(POLY_PASSPHRASE, PASSPHRASE);
+        then.status(StatusCode::OK)
+            .json_body(json!({ "apiKeys": [API_KEY] }));
+    });
+
+    resumed.api_keys().await?;
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_session_without_funder_should_fail() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = create_authenticated(&server).await?;
+
+    let mut session = serde_json::to_value(client.export_session())?;
+    session["signatureType"] = json!(1);
+    let session: Session = serde_json::from_value(session)?;
+
+    let err = Client::from_session(&server.base_url(), Config::default(), session)
+        .await
+        .unwrap_err();
+    let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+    assert_eq!(msg, "A Proxy signature type requires a funder address");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn deauthenticated_with_multiple_strong_references_should_fail() -> anyhow::Result<()> {
     let server = MockServer::start();
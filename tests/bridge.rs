@@ -317,6 +317,188 @@ mod deposit_status {
     }
 }
 
+#[cfg(feature = "polling")]
+mod tracking {
+    use std::pin::pin;
+    use std::time::Duration;
+
+    use futures::StreamExt as _;
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::bridge::{
+        Client,
+        tracking::TrackConfig,
+        types::{DepositTransactionStatus, StatusRequest},
+    };
+    use polymarket_client_sdk::error::Kind;
+    use reqwest::StatusCode;
+    use serde_json::{Value, json};
+
+    const DEPOSIT_ADDRESS: &str = "0x9cb12Ec30568ab763ae5891ce4b8c5C96CeD72C9";
+
+    fn transaction(chain_id: &str, status: &str) -> Value {
+        json!({
+            "fromChainId": chain_id,
+            "fromTokenAddress": "11111111111111111111111111111111",
+            "fromAmountBaseUnit": "13566635",
+            "toChainId": "137",
+            "toTokenAddress": "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+            "status": status
+        })
+    }
+
+    fn config() -> TrackConfig {
+        TrackConfig::builder()
+            .interval(Duration::from_millis(10))
+            .timeout(Duration::from_secs(5))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn track_deposits_should_yield_status_changes() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let path = format!("/status/{DEPOSIT_ADDRESS}");
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [transaction("1", "PROCESSING")]
+            }));
+        });
+
+        let request = StatusRequest::builder().address(DEPOSIT_ADDRESS).build();
+        let mut updates = pin!(client.track_deposits(&request, config()));
+
+        let first = updates.next().await.unwrap()?;
+        assert_eq!(first.status, DepositTransactionStatus::Processing);
+
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [transaction("1", "COMPLETED"), transaction("2", "DEPOSIT_DETECTED")]
+            }));
+        });
+
+        let second = updates.next().await.unwrap()?;
+        let third = updates.next().await.unwrap()?;
+        assert_eq!(second.status, DepositTransactionStatus::Completed);
+        assert_eq!(third.from_chain_id, 2);
+        assert_eq!(third.status, DepositTransactionStatus::DepositDetected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_deposit_should_ignore_settled_deposits() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let path = format!("/status/{DEPOSIT_ADDRESS}");
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [transaction("1", "COMPLETED"), transaction("2", "PROCESSING")]
+            }));
+        });
+
+        let request = StatusRequest::builder().address(DEPOSIT_ADDRESS).build();
+        let waiter = tokio::spawn({
+            let client = client.clone();
+            let request = request.clone();
+            async move { client.wait_for_deposit(&request, config()).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(
+            !waiter.is_finished(),
+            "only a settled deposit has completed"
+        );
+
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [transaction("1", "COMPLETED"), transaction("2", "COMPLETED")]
+            }));
+        });
+
+        let deposit = waiter.await??;
+        assert_eq!(deposit.from_chain_id, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_deposit_should_await_identical_deposits() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let path = format!("/status/{DEPOSIT_ADDRESS}");
+
+        let mut settled = transaction("1", "COMPLETED");
+        settled["txHash"] = json!("0xaaa");
+        let mut completed = transaction("1", "COMPLETED");
+        completed["txHash"] = json!("0xbbb");
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [settled.clone(), transaction("1", "PROCESSING")]
+            }));
+        });
+
+        let request = StatusRequest::builder().address(DEPOSIT_ADDRESS).build();
+        let waiter = tokio::spawn({
+            let client = client.clone();
+            let request = request.clone();
+            async move { client.wait_for_deposit(&request, config()).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(
+            !waiter.is_finished(),
+            "only a settled deposit has completed"
+        );
+
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path(path.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [settled, completed]
+            }));
+        });
+
+        let deposit = waiter.await??;
+        assert_eq!(deposit.tx_hash.as_deref(), Some("0xbbb"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_deposit_should_time_out() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/status/{DEPOSIT_ADDRESS}"));
+            then.status(StatusCode::OK).json_body(json!({
+                "transactions": [transaction("1", "PROCESSING")]
+            }));
+        });
+
+        let request = StatusRequest::builder().address(DEPOSIT_ADDRESS).build();
+        let config = TrackConfig::builder()
+            .interval(Duration::from_millis(10))
+            .timeout(Duration::from_millis(50))
+            .build();
+        let err = client.wait_for_deposit(&request, config).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Timeout);
+
+        Ok(())
+    }
+}
+
 mod client {
     use polymarket_client_sdk::bridge::Client;
 
@@ -15,7 +15,7 @@ use httpmock::MockServer;
 use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::clob::types::SignatureType;
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::{Decimal, b256};
+use polymarket_client_sdk::types::{Decimal, TokenId, b256};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
@@ -44,7 +44,7 @@ mod unauthenticated {
     };
     use polymarket_client_sdk::clob::types::{Interval, Side, TickSize, TimeRange};
     use polymarket_client_sdk::error::Status;
-    use polymarket_client_sdk::types::address;
+    use polymarket_client_sdk::types::{B256, Timestamp, address};
     use reqwest::Method;
 
     use super::*;
@@ -67,6 +67,142 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn prewarm_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.prewarm(4).await?;
+
+        mock.assert_calls(4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builder_should_apply_transport_options() -> anyhow::Result<()> {
+        use polymarket_client_sdk::transport::Config as TransportConfig;
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let server = MockServer::start();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Deployment", HeaderValue::from_static("east-1"));
+        let client = Client::builder()
+            .host(&server.base_url())
+            .transport(
+                TransportConfig::builder()
+                    .headers(headers)
+                    .user_agent("my-bot/1.2")
+                    .build(),
+            )
+            .build()?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.2")
+                .header("x-deployment", "east-1");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let response = client.ok().await?;
+
+        assert_eq!(response, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_policy_should_retry_transient_failures() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::transport::{Config as TransportConfig, RetryPolicy};
+
+        let server = MockServer::start();
+        let client = Client::builder()
+            .host(&server.base_url())
+            .transport(
+                TransportConfig::builder()
+                    .retry_policy(
+                        RetryPolicy::builder()
+                            .max_retries(2)
+                            .initial_backoff(Duration::from_millis(1))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        client.ok().await.unwrap_err();
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_policy_should_retry_idempotent_posts() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::transport::{Config as TransportConfig, RetryPolicy};
+
+        let server = MockServer::start();
+        let client = Client::builder()
+            .host(&server.base_url())
+            .transport(
+                TransportConfig::builder()
+                    .retry_policy(
+                        RetryPolicy::builder()
+                            .max_retries(2)
+                            .initial_backoff(Duration::from_millis(1))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()?;
+
+        let midpoints_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/midpoints");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+        let raw_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/undocumented");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+        let suffix_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/undocumented/books");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        client.midpoints(&[request]).await.unwrap_err();
+        client
+            .send_raw(Method::POST, "/undocumented", Some(&json!({})))
+            .await?;
+        // Only the order book endpoint itself is idempotent, not any path ending like it
+        client
+            .send_raw(Method::POST, "/undocumented/books", Some(&json!({})))
+            .await?;
+
+        midpoints_mock.assert_calls(3);
+        raw_mock.assert_calls(1);
+        suffix_mock.assert_calls(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn server_time_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -79,7 +215,56 @@ mod unauthenticated {
 
         let response = client.server_time().await?;
 
-        assert_eq!(response, 1_764_612_536);
+        assert_eq!(response, Timestamp::from_secs(1_764_612_536));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_raw_should_return_unsuccessful_responses() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/undocumented")
+                .query_param("foo", "bar")
+                .json_body(json!({ "key": "value" }));
+            then.status(StatusCode::IM_A_TEAPOT)
+                .header("x-custom", "1")
+                .body("short and stout");
+        });
+
+        let response = client
+            .send_raw(
+                Method::POST,
+                "/undocumented?foo=bar",
+                Some(&json!({ "key": "value" })),
+            )
+            .await?;
+
+        assert_eq!(response.status, StatusCode::IM_A_TEAPOT);
+        assert_eq!(response.headers["x-custom"], "1");
+        assert_eq!(response.body, b"short and stout");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn host_with_path_prefix_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&format!("{}/clob", server.base_url()), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/clob/time");
+            then.status(StatusCode::OK).body("1764612536");
+        });
+
+        let response = client.server_time().await?;
+
+        assert_eq!(response, Timestamp::from_secs(1_764_612_536));
         mock.assert();
 
         Ok(())
@@ -504,6 +689,22 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn effective_price_should_apply_fee_rate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        client.set_fee_rate_bps(token_1(), 200);
+
+        let price = client
+            .effective_price(token_1(), Side::Sell, dec!(0.4))
+            .await?;
+
+        assert_eq!(price, dec!(0.392));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn invalidate_caches_should_clear_prepopulated_values() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -615,13 +816,135 @@ mod unauthenticated {
         assert_eq!(response, expected);
         assert_eq!(
             expected.hash()?,
-            "03196cc4f520d81c0748b4f042f2096441d160e8ef5eac4f0378cb5bd80fd183"
+            "5a4cc0af0e5f266c8678bf31cc4a9dc82ec238bb8af3f042cf191ed7d34c7bde"
         );
         mock.assert();
 
         Ok(())
     }
 
+    #[test]
+    fn merge_complement_should_combine_both_tokens() -> anyhow::Result<()> {
+        let book = |token_id, bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]| {
+            let levels = |levels: &[(Decimal, Decimal)]| {
+                levels
+                    .iter()
+                    .map(|&(price, size)| OrderSummary::builder().price(price).size(size).build())
+                    .collect::<Vec<_>>()
+            };
+
+            OrderBookSummaryResponse::builder()
+                .market(B256::ZERO)
+                .asset_id(token_id)
+                .timestamp(Utc.timestamp_millis_opt(123_456_789).unwrap())
+                .bids(levels(bids))
+                .asks(levels(asks))
+                .min_order_size(Decimal::ONE)
+                .neg_risk(false)
+                .tick_size(TickSize::Hundredth)
+                .build()
+        };
+        let yes = book(
+            token_1(),
+            &[(dec!(0.40), dec!(100)), (dec!(0.45), dec!(10))],
+            &[(dec!(0.60), dec!(100)), (dec!(0.55), dec!(10))],
+        );
+        // A NO bid at 0.48 is a YES ask at 0.52, and a NO ask at 0.55 is a YES bid at 0.45
+        let no = book(
+            token_2(),
+            &[(dec!(0.48), dec!(5))],
+            &[(dec!(0.55), dec!(20))],
+        );
+
+        let merged = yes.merge_complement(&no)?;
+
+        assert_eq!(merged.best_bid().unwrap().price, dec!(0.45));
+        assert_eq!(merged.best_bid().unwrap().size, dec!(30));
+        assert_eq!(merged.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(merged.best_ask().unwrap().size, dec!(5));
+        let asks: Vec<_> = merged.asks.iter().map(|level| level.price).collect();
+        assert_eq!(asks, [dec!(0.60), dec!(0.55), dec!(0.52)]);
+        let bids: Vec<_> = merged.bids.iter().map(|level| level.price).collect();
+        assert_eq!(bids, [dec!(0.40), dec!(0.45)]);
+
+        yes.merge_complement(&yes).unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn order_book_should_compute_midpoint_and_spread() -> anyhow::Result<()> {
+        let book = |bids, asks| {
+            serde_json::from_value::<OrderBookSummaryResponse>(json!({
+                "market": B256::ZERO,
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789",
+                "bids": bids,
+                "asks": asks
+            }))
+        };
+
+        let two_sided = book(
+            json!([{ "price": "0.4", "size": "100" }, { "price": "0.45", "size": "10" }]),
+            json!([{ "price": "0.6", "size": "100" }, { "price": "0.52", "size": "5" }]),
+        )?;
+        assert_eq!(two_sided.midpoint(), Some(dec!(0.485)));
+        assert_eq!(two_sided.spread(), Some(dec!(0.07)));
+
+        let one_sided = book(json!([{ "price": "0.4", "size": "100" }]), json!([]))?;
+        assert_eq!(one_sided.midpoint(), None);
+        assert_eq!(one_sided.spread(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn synthetic_order_book_should_merge_both_books() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        for (token_id, bids, asks) in [
+            (
+                token_1(),
+                json!([{ "price": "0.4", "size": "100" }]),
+                json!([]),
+            ),
+            (
+                token_2(),
+                json!([]),
+                json!([{ "price": "0.55", "size": "20" }]),
+            ),
+        ] {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/book")
+                    .query_param("token_id", token_id.to_string());
+                then.status(StatusCode::OK).json_body(json!({
+                    "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                    "asset_id": token_id,
+                    "tick_size": TickSize::Hundredth.as_decimal(),
+                    "min_order_size": "5",
+                    "neg_risk": false,
+                    "timestamp": "123456789",
+                    "bids": bids,
+                    "asks": asks
+                }));
+            });
+        }
+
+        let book = client.synthetic_order_book(token_1(), token_2()).await?;
+
+        assert_eq!(book.asset_id, token_1());
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.45));
+        assert_eq!(book.bids.len(), 2);
+        assert!(book.best_ask().is_none());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_books_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -853,585 +1176,2432 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[cfg(feature = "gamma")]
     #[tokio::test]
-    async fn sampling_markets_should_succeed() -> anyhow::Result<()> {
+    async fn enriched_market_should_join_gamma_metadata() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = Client::new(&server.base_url(), Config::default())?;
+        let client = Client::new(&server.base_url(), Config::builder().build())?;
+        let gamma = polymarket_client_sdk::gamma::Client::new(&server.base_url())?;
+        let condition_id =
+            b256!("0000000000000000000000000000000000000000000000000000000000000001");
 
-        let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET).path("/sampling-markets");
+        let clob_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{condition_id}"));
             then.status(StatusCode::OK).json_body(json!({
-                "data": [
-                    {
-                        "enable_order_book": true,
-                        "active": true,
-                        "closed": false,
-                        "archived": false,
-                        "accepting_orders": true,
-                        "accepting_order_timestamp": "2024-01-15T12:34:56Z",
-                        "minimum_order_size": "1",
-                        "minimum_tick_size": "0.01",
-                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
-                        "question_id": "0x0000000000000000000000000000000000000000000000000000000067890abc",
-                        "question": "Will BTC close above $50k today?",
-                        "description": "A market about BTC daily close price",
-                        "market_slug": "btc-close-above-50k",
-                        "end_date_iso": "2024-02-01T00:00:00Z",
-                        "game_start_time": null,
-                        "seconds_delay": 5,
-                        "fpmm": "0x0000000000000000000000000000000000abc123",
-                        "maker_base_fee": "0",
-                        "taker_base_fee": "0",
-                        "notifications_enabled": true,
-                        "neg_risk": false,
-                        "neg_risk_market_id": "",
-                        "neg_risk_request_id": "",
-                        "icon": "https://example.com/icon.png",
-                        "image": "https://example.com/image.png",
-                        "rewards": {
-                            "rates": null,
-                            "min_size": "10.0",
-                            "max_spread": "0.05"
-                        },
-                        "is_50_50_outcome": false,
-                        "tokens": [
-                            {
-                                "token_id": token_1(),
-                                "outcome": "YES",
-                                "price": "0.55",
-                                "winner": false
-                            },
-                            {
-                                "token_id": token_2(),
-                                "outcome": "NO",
-                                "price": "0.45",
-                                "winner": false
-                            }
-                        ],
-                        "tags": [
-                            "crypto",
-                            "btc",
-                            "price"
-                        ]
-                    }
-                ],
-                "limit": 1,
-                "count": 1,
-                "next_cursor": "next"
+                "enable_order_book": true,
+                "active": true,
+                "closed": false,
+                "archived": false,
+                "accepting_orders": true,
+                "minimum_order_size": "1",
+                "minimum_tick_size": "0.01",
+                "condition_id": condition_id,
+                "question": "Will BTC close above $50k today?",
+                "description": "A market about BTC daily close price",
+                "market_slug": "btc-close-above-50k",
+                "end_date_iso": "2024-02-01T00:00:00Z",
+                "seconds_delay": 5,
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": true,
+                "neg_risk": false,
+                "icon": "",
+                "image": "",
+                "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+                "is_50_50_outcome": false,
+                "tokens": [],
+                "tags": []
             }));
         });
+        let gamma_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/markets")
+                .query_param("condition_ids", condition_id.to_string());
+            then.status(StatusCode::OK).json_body(json!([{
+                "id": "42",
+                "question": "Will BTC close above $50k today?",
+                "conditionId": condition_id,
+                "slug": "btc-close-above-50k",
+                "category": "Crypto",
+                "events": [{
+                    "id": "7",
+                    "slug": "btc-daily",
+                    "title": "BTC daily close",
+                    "image": "https://example.com/event.png",
+                    "endDate": "2024-02-01T12:00:00Z",
+                    "series": [{ "id": "3", "slug": "btc-dailies", "title": "BTC dailies" }]
+                }]
+            }]));
+        });
 
-        let response = client.sampling_markets(None).await?;
+        let enriched = client.enriched_market(&gamma, condition_id).await?;
 
-        let market = MarketResponse::builder()
-            .enable_order_book(true)
-            .active(true)
-            .closed(false)
-            .archived(false)
-            .accepting_orders(true)
-            .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
-            .minimum_order_size(Decimal::ONE)
-            .minimum_tick_size(TickSize::Hundredth.as_decimal())
-            .condition_id(b256!(
-                "0000000000000000000000000000000000000000000000000000000000000001"
-            ))
-            .question_id(b256!(
-                "0000000000000000000000000000000000000000000000000000000067890abc"
-            ))
-            .question("Will BTC close above $50k today?")
-            .description("A market about BTC daily close price")
-            .market_slug("btc-close-above-50k")
-            .end_date_iso("2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap())
-            .seconds_delay(5)
-            .fpmm(address!("0000000000000000000000000000000000abc123"))
-            .maker_base_fee(Decimal::ZERO)
-            .taker_base_fee(Decimal::ZERO)
-            .notifications_enabled(true)
-            .neg_risk(false)
-            .icon("https://example.com/icon.png")
-            .image("https://example.com/image.png")
-            .rewards(
-                Rewards::builder()
-                    .min_size(dec!(10.0))
-                    .max_spread(dec!(0.05))
-                    .build(),
-            )
-            .is_50_50_outcome(false)
-            .tokens(vec![
-                Token::builder()
-                    .token_id(token_1())
-                    .outcome("YES")
-                    .price(dec!(0.55))
-                    .winner(false)
-                    .build(),
-                Token::builder()
-                    .token_id(token_2())
-                    .outcome("NO")
-                    .price(dec!(0.45))
-                    .winner(false)
-                    .build(),
-            ])
-            .tags(vec!["crypto".into(), "btc".into(), "price".into()])
-            .build();
-        let expected = Page::builder()
-            .data(vec![market])
-            .next_cursor("next")
-            .limit(1)
-            .count(1)
-            .build();
+        assert_eq!(enriched.market.condition_id, Some(condition_id));
+        assert_eq!(enriched.title, "BTC daily close");
+        assert_eq!(enriched.slug, "btc-daily");
+        assert_eq!(
+            enriched.image.as_deref(),
+            Some("https://example.com/event.png")
+        );
+        assert_eq!(enriched.category.as_deref(), Some("Crypto"));
+        assert_eq!(
+            enriched.end_date,
+            Some("2024-02-01T12:00:00Z".parse::<DateTime<Utc>>()?)
+        );
+        assert_eq!(enriched.event_id.as_deref(), Some("7"));
+        assert_eq!(enriched.series_slug.as_deref(), Some("btc-dailies"));
 
-        assert_eq!(response, expected);
-        mock.assert();
+        // Served from the cache
+        let cached = client.enriched_market(&gamma, condition_id).await?;
+        assert_eq!(cached, enriched);
+        clob_mock.assert_calls(1);
+        gamma_mock.assert_calls(1);
 
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn simplified_markets_should_succeed() -> anyhow::Result<()> {
+    async fn record_market_data_should_write_observations() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::recorder::{Data, OutputConfig, RecordConfig, Writer};
+
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
         let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET)
-                .path("/simplified-markets");
-            then.status(StatusCode::OK).json_body(json!({
-                "data": [
-                    {
-                        "condition_id": "0x00000000000000000000000000000000000000000000000000000000c0012345",
-                        "tokens": [
-                            {
-                                "token_id": token_1(),
-                                "outcome": "YES",
-                                "price": "0.55",
-                                "winner": false
-                            },
-                            {
-                                "token_id": token_2(),
-                                "outcome": "NO",
-                                "price": "0.45",
-                                "winner": false
-                            }
-                        ],
-                        "rewards": {
-                            "rates": null,
-                            "min_size": "10.0",
-                            "max_spread": "0.05"
-                        },
-                        "archived": false,
-                        "accepting_orders": true,
-                        "active": true,
-                        "closed": false
-                    }
-                ],
-                "limit": 1,
-                "count": 1,
-                "next_cursor": "next"
-            }));
+            when.method(httpmock::Method::POST).path("/midpoints");
+            then.status(StatusCode::OK).json_body(json!(
+                { token_1().to_string(): 0.5 }
+            ));
         });
 
-        let response = client.simplified_markets(None).await?;
-
-        let simplified = SimplifiedMarketResponse::builder()
-            .condition_id(b256!(
-                "00000000000000000000000000000000000000000000000000000000c0012345"
-            ))
-            .tokens(vec![
-                Token::builder()
-                    .token_id(token_1())
-                    .outcome("YES")
-                    .price(dec!(0.55))
-                    .winner(false)
-                    .build(),
-                Token::builder()
-                    .token_id(token_2())
-                    .outcome("NO")
-                    .price(dec!(0.45))
-                    .winner(false)
-                    .build(),
-            ])
-            .rewards(
-                Rewards::builder()
-                    .min_size(dec!(10.0))
-                    .max_spread(dec!(0.05))
-                    .build(),
-            )
-            .archived(false)
-            .accepting_orders(true)
-            .active(true)
-            .closed(false)
-            .build();
-        let expected = Page::builder()
-            .data(vec![simplified])
-            .next_cursor("next")
-            .limit(1)
-            .count(1)
+        let directory = std::env::temp_dir().join(format!("recorder-{}", std::process::id()));
+        let writer = Writer::new(OutputConfig::builder().directory(&directory).build());
+        let config = RecordConfig::builder()
+            .tokens(vec![token_1()])
+            .books(false)
+            .last_trades(false)
             .build();
 
-        assert_eq!(response, expected);
+        let records: Vec<_> = client
+            .record_market_data(config, writer)
+            .take(1)
+            .collect()
+            .await;
+        let record = records.into_iter().next().unwrap()?;
+
+        assert_eq!(record.token_id, token_1());
+        assert_eq!(record.data, Data::Midpoint { price: dec!(0.5) });
         mock.assert();
 
+        let file = std::fs::read_dir(&directory)?.next().unwrap()?.path();
+        let line: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(file)?)?;
+        assert_eq!(line["type"], "midpoint");
+        assert_eq!(line["token_id"], token_1().to_string());
+        std::fs::remove_dir_all(&directory)?;
+
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn sampling_simplified_markets_should_succeed() -> anyhow::Result<()> {
+    async fn watch_resolutions_should_succeed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::resolution::{Status, WatchConfig};
+
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
+        let condition_id =
+            b256!("0000000000000000000000000000000000000000000000000000000000000001");
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
-                .path("/sampling-simplified-markets");
+                .path(format!("/markets/{condition_id}"));
             then.status(StatusCode::OK).json_body(json!({
-                "data": [
+                "enable_order_book": true,
+                "active": false,
+                "closed": true,
+                "archived": false,
+                "accepting_orders": false,
+                "minimum_order_size": "1",
+                "minimum_tick_size": "0.01",
+                "condition_id": condition_id,
+                "question_id": "",
+                "question": "Will BTC close above $50k today?",
+                "description": "A market about BTC daily close price",
+                "market_slug": "btc-close-above-50k",
+                "seconds_delay": 5,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": true,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "https://example.com/icon.png",
+                "image": "https://example.com/image.png",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "0",
+                    "max_spread": "0"
+                },
+                "is_50_50_outcome": false,
+                "tokens": [
                     {
-                        "condition_id": "0x00000000000000000000000000000000000000000000000000000000c0012345",
-                        "tokens": [
-                            {
-                                "token_id": token_1(),
-                                "outcome": "YES",
-                                "price": "0.55",
-                                "winner": false
-                            },
-                            {
-                                "token_id": token_2(),
-                                "outcome": "NO",
-                                "price": "0.45",
-                                "winner": false
-                            }
-                        ],
-                        "rewards": {
-                            "rates": null,
-                            "min_size": "10.0",
-                            "max_spread": "0.05"
-                        },
-                        "archived": false,
-                        "accepting_orders": true,
-                        "active": true,
-                        "closed": false
+                        "token_id": token_1(),
+                        "outcome": "YES",
+                        "price": "1",
+                        "winner": true
+                    },
+                    {
+                        "token_id": token_2(),
+                        "outcome": "NO",
+                        "price": "0",
+                        "winner": false
                     }
-                ],
-                "limit": 1,
-                "count": 1,
-                "next_cursor": "next"
+                ]
             }));
         });
 
-        let response = client.sampling_simplified_markets(None).await?;
-
-        let simplified = SimplifiedMarketResponse::builder()
-            .condition_id(b256!(
-                "00000000000000000000000000000000000000000000000000000000c0012345"
-            ))
-            .tokens(vec![
-                Token::builder()
-                    .token_id(token_1())
-                    .outcome("YES")
-                    .price(dec!(0.55))
-                    .winner(false)
-                    .build(),
-                Token::builder()
-                    .token_id(token_2())
-                    .outcome("NO")
-                    .price(dec!(0.45))
-                    .winner(false)
-                    .build(),
-            ])
-            .rewards(
-                Rewards::builder()
-                    .min_size(dec!(10.0))
-                    .max_spread(dec!(0.05))
-                    .build(),
-            )
-            .archived(false)
-            .accepting_orders(true)
-            .active(true)
-            .closed(false)
-            .build();
-        let expected = Page::builder()
-            .data(vec![simplified])
-            .next_cursor("next")
-            .limit(1)
-            .count(1)
+        let config = WatchConfig::builder()
+            .condition_ids(vec![condition_id])
             .build();
+        let changes: Vec<_> = client.watch_resolutions(config).collect().await;
 
-        assert_eq!(response, expected);
+        assert_eq!(changes.len(), 1);
+        let change = changes.into_iter().next().unwrap()?;
+        assert_eq!(change.condition_id, condition_id);
+        assert_eq!(change.previous, None);
+        assert_eq!(change.current, Status::Finalized { winner: token_1() });
         mock.assert();
 
         Ok(())
     }
 
+    #[cfg(all(feature = "polling", feature = "gamma"))]
     #[tokio::test]
-    async fn stream_markets_should_succeed() -> anyhow::Result<()> {
-        const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+    async fn watch_resolutions_should_yield_uma_states() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::clob::resolution::{Status, WatchConfig};
 
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
-
-        let json = json!({
-            "data": [
-                {
+        let gamma = polymarket_client_sdk::gamma::Client::new(&server.base_url())?;
+        let condition_id =
+            b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        let mock_clob = |winner: bool| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path(format!("/markets/{condition_id}"));
+                then.status(StatusCode::OK).json_body(json!({
                     "enable_order_book": true,
-                    "active": true,
-                    "closed": false,
+                    "active": false,
+                    "closed": true,
                     "archived": false,
-                    "accepting_orders": true,
-                    "accepting_order_timestamp": "2024-01-15T12:34:56Z",
+                    "accepting_orders": false,
                     "minimum_order_size": "1",
                     "minimum_tick_size": "0.01",
-                    "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
-                    "question_id": "0x0000000000000000000000000000000000000000000000000000000067890abc",
+                    "condition_id": condition_id,
                     "question": "Will BTC close above $50k today?",
                     "description": "A market about BTC daily close price",
                     "market_slug": "btc-close-above-50k",
-                    "end_date_iso": "2024-02-01T00:00:00Z",
-                    "game_start_time": null,
                     "seconds_delay": 5,
-                    "fpmm": "0x0000000000000000000000000000000000abc123",
                     "maker_base_fee": "0",
                     "taker_base_fee": "0",
                     "notifications_enabled": true,
                     "neg_risk": false,
-                    "neg_risk_market_id": "",
-                    "neg_risk_request_id": "",
-                    "icon": "https://example.com/icon.png",
-                    "image": "https://example.com/image.png",
-                    "rewards": {
-                        "rates": null,
-                        "min_size": "10.0",
-                        "max_spread": "0.05"
-                    },
+                    "icon": "",
+                    "image": "",
+                    "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
                     "is_50_50_outcome": false,
                     "tokens": [
-                        {
-                            "token_id": token_1(),
-                            "outcome": "YES",
-                            "price": "0.55",
-                            "winner": false
-                        },
-                        {
-                            "token_id": token_2(),
-                            "outcome": "NO",
-                            "price": "0.45",
-                            "winner": false
-                        }
-                    ],
-                    "tags": [
-                        "crypto",
-                        "btc",
-                        "price"
+                        { "token_id": token_1(), "outcome": "YES", "price": "1", "winner": winner },
+                        { "token_id": token_2(), "outcome": "NO", "price": "0", "winner": false }
                     ]
-                }
-            ],
-            "limit": 1,
-            "count": 1
+                }));
+            })
+        };
+        let mock_gamma = |uma_status: &str| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/markets")
+                    .query_param("condition_ids", condition_id.to_string());
+                then.status(StatusCode::OK).json_body(json!([{
+                    "id": "42",
+                    "conditionId": condition_id,
+                    "umaResolutionStatus": uma_status
+                }]));
+            })
+        };
+
+        let config = WatchConfig::builder()
+            .condition_ids(vec![condition_id])
+            .interval(Duration::from_millis(10))
+            .gamma(gamma)
+            .build();
+        let changes = client.watch_resolutions(config);
+        let mut changes = std::pin::pin!(changes);
+
+        let mut clob = mock_clob(false);
+        let mut uma = mock_gamma("proposed");
+        let change = changes.next().await.unwrap()?;
+        assert_eq!(change.previous, None);
+        assert_eq!(change.current, Status::Proposed);
+
+        uma.delete();
+        uma = mock_gamma("disputed");
+        let change = changes.next().await.unwrap()?;
+        assert_eq!(change.previous, Some(Status::Proposed));
+        assert_eq!(change.current, Status::Disputed);
+
+        uma.delete();
+        clob.delete();
+        clob = mock_clob(true);
+        mock_gamma("resolved");
+        let change = changes.next().await.unwrap()?;
+        assert_eq!(change.previous, Some(Status::Disputed));
+        assert_eq!(change.current, Status::Finalized { winner: token_1() });
+        assert!(changes.next().await.is_none());
+        clob.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn watch_closing_should_warn_before_end_date() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use futures_util::stream::TryStreamExt as _;
+        use polymarket_client_sdk::clob::closing::{Stage, WatchConfig};
+        use polymarket_client_sdk::types::B256;
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market = |condition_id: B256, closed: bool, end_date: DateTime<Utc>| {
+            json!({
+                "enable_order_book": true,
+                "active": !closed,
+                "closed": closed,
+                "archived": false,
+                "accepting_orders": !closed,
+                "minimum_order_size": "1",
+                "minimum_tick_size": "0.01",
+                "condition_id": condition_id,
+                "question_id": "",
+                "question": "Will BTC close above $50k today?",
+                "description": "A market about BTC daily close price",
+                "market_slug": "btc-close-above-50k",
+                "end_date_iso": end_date,
+                "seconds_delay": 5,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": true,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "https://example.com/icon.png",
+                "image": "https://example.com/image.png",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "0",
+                    "max_spread": "0"
+                },
+                "is_50_50_outcome": false,
+                "tokens": []
+            })
+        };
+
+        let closed = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let closing = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+        let end_date = Utc::now() + TimeDelta::minutes(30);
+        let closed_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{closed}"));
+            then.status(StatusCode::OK)
+                .json_body(market(closed, true, end_date));
+        });
+        let closing_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{closing}"));
+            then.status(StatusCode::OK)
+                .json_body(market(closing, false, end_date));
+        });
+
+        let config = WatchConfig::builder()
+            .condition_ids(vec![closed, closing])
+            .build();
+        let warnings: Vec<_> = client.watch_closing(config).take(2).try_collect().await?;
+
+        let mut stages: Vec<_> = warnings
+            .iter()
+            .map(|warning| (warning.condition_id, warning.stage))
+            .collect();
+        stages.sort_by_key(|(condition_id, _)| *condition_id);
+        assert_eq!(
+            stages,
+            [
+                (closed, Stage::Closed),
+                (
+                    closing,
+                    Stage::Approaching {
+                        lead: Duration::from_secs(60 * 60)
+                    }
+                ),
+            ]
+        );
+        assert!(warnings.iter().all(|warning| warning.end_date.is_some()));
+        closed_mock.assert_calls(1);
+        closing_mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn watch_book_alerts_should_yield_triggered_conditions() -> anyhow::Result<()> {
+        use futures_util::stream::TryStreamExt as _;
+        use polymarket_client_sdk::clob::alerts::{Condition, Thresholds, WatchConfig};
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::OK).json_body(json!([{
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1",
+                "bids": [{ "price": "0.30", "size": "100" }],
+                "asks": [{ "price": "0.50", "size": "2" }]
+            }]));
+        });
+
+        let thresholds = Thresholds::builder()
+            .max_spread(dec!(0.1))
+            .min_top_size(dec!(5))
+            .build();
+        let config = WatchConfig::builder()
+            .token_ids(vec![token_1()])
+            .thresholds(thresholds)
+            .build();
+        let alerts: Vec<_> = client
+            .watch_book_alerts(config)
+            .take(2)
+            .try_collect()
+            .await?;
+
+        let conditions: Vec<_> = alerts.iter().map(|alert| alert.condition).collect();
+        assert_eq!(conditions, [Condition::WideSpread, Condition::ThinAsk]);
+        assert!(alerts.iter().all(|alert| alert.triggered));
+        assert_eq!(alerts[0].value, Some(dec!(0.2)));
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sampling_markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/sampling-markets");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "enable_order_book": true,
+                        "active": true,
+                        "closed": false,
+                        "archived": false,
+                        "accepting_orders": true,
+                        "accepting_order_timestamp": "2024-01-15T12:34:56Z",
+                        "minimum_order_size": "1",
+                        "minimum_tick_size": "0.01",
+                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "question_id": "0x0000000000000000000000000000000000000000000000000000000067890abc",
+                        "question": "Will BTC close above $50k today?",
+                        "description": "A market about BTC daily close price",
+                        "market_slug": "btc-close-above-50k",
+                        "end_date_iso": "2024-02-01T00:00:00Z",
+                        "game_start_time": null,
+                        "seconds_delay": 5,
+                        "fpmm": "0x0000000000000000000000000000000000abc123",
+                        "maker_base_fee": "0",
+                        "taker_base_fee": "0",
+                        "notifications_enabled": true,
+                        "neg_risk": false,
+                        "neg_risk_market_id": "",
+                        "neg_risk_request_id": "",
+                        "icon": "https://example.com/icon.png",
+                        "image": "https://example.com/image.png",
+                        "rewards": {
+                            "rates": null,
+                            "min_size": "10.0",
+                            "max_spread": "0.05"
+                        },
+                        "is_50_50_outcome": false,
+                        "tokens": [
+                            {
+                                "token_id": token_1(),
+                                "outcome": "YES",
+                                "price": "0.55",
+                                "winner": false
+                            },
+                            {
+                                "token_id": token_2(),
+                                "outcome": "NO",
+                                "price": "0.45",
+                                "winner": false
+                            }
+                        ],
+                        "tags": [
+                            "crypto",
+                            "btc",
+                            "price"
+                        ]
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "next"
+            }));
         });
 
+        let response = client.sampling_markets(None).await?;
+
+        let market = MarketResponse::builder()
+            .enable_order_book(true)
+            .active(true)
+            .closed(false)
+            .archived(false)
+            .accepting_orders(true)
+            .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
+            .minimum_order_size(Decimal::ONE)
+            .minimum_tick_size(TickSize::Hundredth.as_decimal())
+            .condition_id(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+            .question_id(b256!(
+                "0000000000000000000000000000000000000000000000000000000067890abc"
+            ))
+            .question("Will BTC close above $50k today?")
+            .description("A market about BTC daily close price")
+            .market_slug("btc-close-above-50k")
+            .end_date_iso("2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap())
+            .seconds_delay(5)
+            .fpmm(address!("0000000000000000000000000000000000abc123"))
+            .maker_base_fee(Decimal::ZERO)
+            .taker_base_fee(Decimal::ZERO)
+            .notifications_enabled(true)
+            .neg_risk(false)
+            .icon("https://example.com/icon.png")
+            .image("https://example.com/image.png")
+            .rewards(
+                Rewards::builder()
+                    .min_size(dec!(10.0))
+                    .max_spread(dec!(0.05))
+                    .build(),
+            )
+            .is_50_50_outcome(false)
+            .tokens(vec![
+                Token::builder()
+                    .token_id(token_1())
+                    .outcome("YES")
+                    .price(dec!(0.55))
+                    .winner(false)
+                    .build(),
+                Token::builder()
+                    .token_id(token_2())
+                    .outcome("NO")
+                    .price(dec!(0.45))
+                    .winner(false)
+                    .build(),
+            ])
+            .tags(vec!["crypto".into(), "btc".into(), "price".into()])
+            .build();
+        let expected = Page::builder()
+            .data(vec![market])
+            .next_cursor("next")
+            .limit(1)
+            .count(1)
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn simplified_markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
-                .path("/markets")
-                .is_true(|req| req.query_params().is_empty());
+                .path("/simplified-markets");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": "0x00000000000000000000000000000000000000000000000000000000c0012345",
+                        "tokens": [
+                            {
+                                "token_id": token_1(),
+                                "outcome": "YES",
+                                "price": "0.55",
+                                "winner": false
+                            },
+                            {
+                                "token_id": token_2(),
+                                "outcome": "NO",
+                                "price": "0.45",
+                                "winner": false
+                            }
+                        ],
+                        "rewards": {
+                            "rates": null,
+                            "min_size": "10.0",
+                            "max_spread": "0.05"
+                        },
+                        "archived": false,
+                        "accepting_orders": true,
+                        "active": true,
+                        "closed": false
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "next"
+            }));
+        });
 
-            let mut json_with_cursor = json.clone();
-            if let Some(obj) = json_with_cursor.as_object_mut() {
-                obj.insert(
-                    "next_cursor".to_owned(),
-                    serde_json::Value::String("1".to_owned()),
-                );
+        let response = client.simplified_markets(None).await?;
+
+        let simplified = SimplifiedMarketResponse::builder()
+            .condition_id(b256!(
+                "00000000000000000000000000000000000000000000000000000000c0012345"
+            ))
+            .tokens(vec![
+                Token::builder()
+                    .token_id(token_1())
+                    .outcome("YES")
+                    .price(dec!(0.55))
+                    .winner(false)
+                    .build(),
+                Token::builder()
+                    .token_id(token_2())
+                    .outcome("NO")
+                    .price(dec!(0.45))
+                    .winner(false)
+                    .build(),
+            ])
+            .rewards(
+                Rewards::builder()
+                    .min_size(dec!(10.0))
+                    .max_spread(dec!(0.05))
+                    .build(),
+            )
+            .archived(false)
+            .accepting_orders(true)
+            .active(true)
+            .closed(false)
+            .build();
+        let expected = Page::builder()
+            .data(vec![simplified])
+            .next_cursor("next")
+            .limit(1)
+            .count(1)
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sampling_simplified_markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/sampling-simplified-markets");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": "0x00000000000000000000000000000000000000000000000000000000c0012345",
+                        "tokens": [
+                            {
+                                "token_id": token_1(),
+                                "outcome": "YES",
+                                "price": "0.55",
+                                "winner": false
+                            },
+                            {
+                                "token_id": token_2(),
+                                "outcome": "NO",
+                                "price": "0.45",
+                                "winner": false
+                            }
+                        ],
+                        "rewards": {
+                            "rates": null,
+                            "min_size": "10.0",
+                            "max_spread": "0.05"
+                        },
+                        "archived": false,
+                        "accepting_orders": true,
+                        "active": true,
+                        "closed": false
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "next"
+            }));
+        });
+
+        let response = client.sampling_simplified_markets(None).await?;
+
+        let simplified = SimplifiedMarketResponse::builder()
+            .condition_id(b256!(
+                "00000000000000000000000000000000000000000000000000000000c0012345"
+            ))
+            .tokens(vec![
+                Token::builder()
+                    .token_id(token_1())
+                    .outcome("YES")
+                    .price(dec!(0.55))
+                    .winner(false)
+                    .build(),
+                Token::builder()
+                    .token_id(token_2())
+                    .outcome("NO")
+                    .price(dec!(0.45))
+                    .winner(false)
+                    .build(),
+            ])
+            .rewards(
+                Rewards::builder()
+                    .min_size(dec!(10.0))
+                    .max_spread(dec!(0.05))
+                    .build(),
+            )
+            .archived(false)
+            .accepting_orders(true)
+            .active(true)
+            .closed(false)
+            .build();
+        let expected = Page::builder()
+            .data(vec![simplified])
+            .next_cursor("next")
+            .limit(1)
+            .count(1)
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stream_markets_should_succeed() -> anyhow::Result<()> {
+        const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let json = json!({
+            "data": [
+                {
+                    "enable_order_book": true,
+                    "active": true,
+                    "closed": false,
+                    "archived": false,
+                    "accepting_orders": true,
+                    "accepting_order_timestamp": "2024-01-15T12:34:56Z",
+                    "minimum_order_size": "1",
+                    "minimum_tick_size": "0.01",
+                    "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                    "question_id": "0x0000000000000000000000000000000000000000000000000000000067890abc",
+                    "question": "Will BTC close above $50k today?",
+                    "description": "A market about BTC daily close price",
+                    "market_slug": "btc-close-above-50k",
+                    "end_date_iso": "2024-02-01T00:00:00Z",
+                    "game_start_time": null,
+                    "seconds_delay": 5,
+                    "fpmm": "0x0000000000000000000000000000000000abc123",
+                    "maker_base_fee": "0",
+                    "taker_base_fee": "0",
+                    "notifications_enabled": true,
+                    "neg_risk": false,
+                    "neg_risk_market_id": "",
+                    "neg_risk_request_id": "",
+                    "icon": "https://example.com/icon.png",
+                    "image": "https://example.com/image.png",
+                    "rewards": {
+                        "rates": null,
+                        "min_size": "10.0",
+                        "max_spread": "0.05"
+                    },
+                    "is_50_50_outcome": false,
+                    "tokens": [
+                        {
+                            "token_id": token_1(),
+                            "outcome": "YES",
+                            "price": "0.55",
+                            "winner": false
+                        },
+                        {
+                            "token_id": token_2(),
+                            "outcome": "NO",
+                            "price": "0.45",
+                            "winner": false
+                        }
+                    ],
+                    "tags": [
+                        "crypto",
+                        "btc",
+                        "price"
+                    ]
+                }
+            ],
+            "limit": 1,
+            "count": 1
+        });
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/markets")
+                .is_true(|req| req.query_params().is_empty());
+
+            let mut json_with_cursor = json.clone();
+            if let Some(obj) = json_with_cursor.as_object_mut() {
+                obj.insert(
+                    "next_cursor".to_owned(),
+                    serde_json::Value::String("1".to_owned()),
+                );
+            }
+
+            then.status(StatusCode::OK).json_body(json_with_cursor);
+        });
+
+        let mock2 = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/markets")
+                .query_param("next_cursor", "1");
+
+            let mut json_with_cursor = json.clone();
+            if let Some(obj) = json_with_cursor.as_object_mut() {
+                obj.insert(
+                    "next_cursor".to_owned(),
+                    serde_json::Value::String(TERMINAL_CURSOR.to_owned()),
+                );
+            }
+
+            then.status(StatusCode::OK).json_body(json_with_cursor);
+        });
+
+        let response: Vec<MarketResponse> = client
+            .stream_data(Client::markets)
+            .filter_map(|d| future::ready(d.ok()))
+            .collect()
+            .await;
+
+        let market = MarketResponse::builder()
+            .enable_order_book(true)
+            .active(true)
+            .closed(false)
+            .archived(false)
+            .accepting_orders(true)
+            .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
+            .minimum_order_size(Decimal::ONE)
+            .minimum_tick_size(TickSize::Hundredth.as_decimal())
+            .condition_id(b256!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+            .question_id(b256!(
+                "0000000000000000000000000000000000000000000000000000000067890abc"
+            ))
+            .question("Will BTC close above $50k today?")
+            .description("A market about BTC daily close price")
+            .market_slug("btc-close-above-50k")
+            .end_date_iso("2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap())
+            .seconds_delay(5)
+            .fpmm(address!("0000000000000000000000000000000000abc123"))
+            .maker_base_fee(Decimal::ZERO)
+            .taker_base_fee(Decimal::ZERO)
+            .notifications_enabled(true)
+            .neg_risk(false)
+            .icon("https://example.com/icon.png")
+            .image("https://example.com/image.png")
+            .rewards(
+                Rewards::builder()
+                    .min_size(dec!(10.0))
+                    .max_spread(dec!(0.05))
+                    .build(),
+            )
+            .is_50_50_outcome(false)
+            .tokens(vec![
+                Token::builder()
+                    .token_id(token_1())
+                    .outcome("YES")
+                    .price(dec!(0.55))
+                    .winner(false)
+                    .build(),
+                Token::builder()
+                    .token_id(token_2())
+                    .outcome("NO")
+                    .price(dec!(0.45))
+                    .winner(false)
+                    .build(),
+            ])
+            .tags(vec!["crypto".into(), "btc".into(), "price".into()])
+            .build();
+        let expected = vec![market.clone(), market];
+
+        assert_eq!(response, expected);
+        mock.assert();
+        mock2.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_geoblock_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": false,
+                "ip": "192.168.1.1",
+                "country": "US",
+                "region": "NY"
+            }));
+        });
+
+        let response = client.check_geoblock().await?;
+
+        let expected = GeoblockResponse::builder()
+            .blocked(false)
+            .ip("192.168.1.1".to_owned())
+            .country("US".to_owned())
+            .region("NY".to_owned())
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_geoblock_blocked_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": true,
+                "ip": "10.0.0.1",
+                "country": "CU",
+                "region": "HAV"
+            }));
+        });
+
+        let response = client.check_geoblock().await?;
+
+        assert!(response.blocked);
+        assert_eq!(response.country, "CU");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn health_report_should_check_api_clock_and_geoblock() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let ok = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+        let time = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(chrono::Utc::now().timestamp() + 60);
+        });
+        let geoblock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/geoblock");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let report = client.health_report().await;
+
+        assert!(report.api.ok);
+        assert!(report.clock.ok);
+        let skew = report.clock_skew.expect("clock skew").num_seconds();
+        assert!((58..=61).contains(&skew), "unexpected skew of {skew}s");
+        assert!(!report.geoblock.ok);
+        assert!(report.geoblock.error.is_some());
+        assert_eq!(report.blocked, None);
+        assert_eq!(report.auth, None);
+        assert!(!report.is_ready());
+        ok.assert();
+        time.assert();
+        geoblock.assert();
+
+        Ok(())
+    }
+}
+
+mod authenticated {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use alloy::primitives::Signature;
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use chrono::NaiveDate;
+    use httpmock::Method::{DELETE, GET, POST};
+    use polymarket_client_sdk::auth::Credentials;
+    use polymarket_client_sdk::clob::batch::Batch;
+    use polymarket_client_sdk::clob::types::request::{
+        BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
+        OrdersRequest, TradesRequest, UserRewardsEarningRequest,
+    };
+    use polymarket_client_sdk::clob::types::response::{
+        ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
+        CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder, MarketRewardResponse,
+        MarketRewardsConfig, NotificationPayload, NotificationResponse, OpenOrderResponse,
+        OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
+        TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
+    };
+    use polymarket_client_sdk::clob::types::{
+        AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
+        TradeStatusType, TraderSide,
+    };
+    #[cfg(feature = "heartbeats")]
+    use polymarket_client_sdk::error::Synchronization;
+    use polymarket_client_sdk::types::{Address, Usdc, address, b256};
+
+    use super::*;
+    use crate::common::{
+        API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
+        TIMESTAMP,
+    };
+
+    #[tokio::test]
+    async fn api_keys_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let response = client.api_keys().await?;
+
+        let expected = ApiKeysResponse::builder().keys(vec![API_KEY]).build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn health_report_should_check_api_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder().geoblock_host(server.base_url()).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(chrono::Utc::now().timestamp());
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": false,
+                "ip": "192.168.1.1",
+                "country": "US",
+                "region": "NY"
+            }));
+        });
+        let api_keys = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({ "error": "Unauthorized/Invalid api key" }));
+        });
+
+        let report = client.health_report().await;
+
+        assert!(report.api.ok);
+        assert!(report.clock.ok);
+        assert!(report.geoblock.ok);
+        assert_eq!(report.blocked, Some(false));
+        let auth = report.auth.as_ref().expect("auth check");
+        assert!(!auth.ok);
+        assert!(auth.error.is_some());
+        assert!(!report.is_ready());
+        api_keys.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_raw_should_sign_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/undocumented")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .header_exists(POLY_SIGNATURE)
+                .header_exists(POLY_TIMESTAMP);
+            then.status(StatusCode::OK).json_body(json!({ "ok": true }));
+        });
+
+        let response = client
+            .send_raw(reqwest::Method::GET, "undocumented", None)
+            .await?;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&response.body)?,
+            json!({ "ok": true })
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).body("\"\"");
+        });
+
+        client.delete_api_key().await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn closed_only_mode_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/ban-status/closed-only")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({"closed_only": true}));
+        });
+
+        let response = client.closed_only_mode().await?;
+
+        let expected = BanStatusResponse::builder().closed_only(true).build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    // Also fills in some other, less often used fields like nonce, and salt generator
+    #[tokio::test]
+    async fn sign_order_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase())
+                .header(POLY_NONCE, "0")
+                .header(POLY_SIGNATURE, SIGNATURE)
+                .header(POLY_TIMESTAMP, TIMESTAMP);
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let mock2 = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(TIMESTAMP.parse::<i64>().unwrap());
+        });
+
+        let funder = address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316");
+        let config = Config::builder().use_server_time(true).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .funder(funder)
+            .signature_type(SignatureType::Proxy)
+            .salt_generator(|| 1) // To ensure determinism
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        assert_eq!(
+            client.tick_size(token_1()).await?.minimum_tick_size,
+            TickSize::Thousandth
+        );
+
+        let taker = address!("0xf7fB45986800e2D259BAa25B56466bd02dA37a44");
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .taker(taker)
+            .nonce(2)
+            .build()
+            .await?;
+
+        let signed_order = client.sign(&signer, signable_order.clone()).await?;
+
+        let expected = SignedOrder::builder()
+            .owner(API_KEY)
+            .order(signable_order.order)
+            .order_type(OrderType::GTC)
+            .post_only(false)
+            .signature(Signature::new(
+                U256::from_str(
+                    "67938079796141091828598175285011746318151402208362009718761031231176791189384",
+                )?,
+                U256::from_str(
+                    "31661255856293674232712511615893783899761903915420680037924826147367342033568",
+                )?,
+                true,
+            ))
+            .build();
+
+        assert_eq!(signed_order.order.taker, taker);
+        assert_eq!(signed_order.order.maker, funder);
+        assert_ne!(signed_order.order.maker, client.address());
+        assert_eq!(signed_order.order.signatureType, SignatureType::Proxy as u8);
+        assert_eq!(signed_order.order.nonce, U256::from(2));
+        assert_eq!(signed_order.order.salt, U256::from(1));
+        assert_eq!(
+            client.address(),
+            address!("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266")
+        );
+
+        assert_eq!(signed_order, expected);
+        mock.assert();
+        mock2.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_should_reject_contradictory_time_in_force() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::types::{Order, TimeInForce};
+        use polymarket_client_sdk::error::{Kind, Validation};
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let signable_order = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::FOK)
+            .post_only(true)
+            .build();
+        let err = client.sign(&signer, signable_order).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "postOnly is only supported for GTC and GTD orders"
+        );
+
+        let signable_order = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::GTC)
+            .build();
+        assert_eq!(
+            signable_order.time_in_force()?,
+            TimeInForce::GoodTilCancelled { post_only: false }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_order_with_domain_override_should_succeed() -> anyhow::Result<()> {
+        use alloy::sol_types::{SolStruct as _, eip712_domain};
+        use polymarket_client_sdk::clob::types::OrderDomain;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let exchange = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let domain = OrderDomain::builder()
+            .name("Staging Exchange")
+            .version("2")
+            .verifying_contract(exchange)
+            .build();
+        let config = Config::builder().order_domain(domain).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, signable_order).await?;
+
+        let expected_domain = eip712_domain! {
+            name: "Staging Exchange",
+            version: "2",
+            chain_id: POLYGON,
+            verifying_contract: exchange,
+        };
+        let hash = signed_order.order.eip712_signing_hash(&expected_domain);
+        assert_eq!(
+            signed_order.signature.recover_address_from_prehash(&hash)?,
+            signer.address()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_parse_insufficient_balance() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::{InsufficientBalance, Kind, Status};
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::BAD_REQUEST).json_body(json!({
+                "error": "not enough balance / allowance: the balance is not enough -> balance: 1500000, order amount: 5000000"
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let insufficient = err.downcast_ref::<InsufficientBalance>().unwrap();
+        assert_eq!(insufficient.asset_type, Some(AssetType::Collateral));
+        assert!(!insufficient.allowance);
+        assert_eq!(insufficient.required, Some(dec!(5)));
+        assert_eq!(insufficient.available, Some(dec!(1.5)));
+
+        // The HTTP error is still available to existing handlers
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::BAD_REQUEST);
+
+        let request = insufficient.update_request().unwrap();
+        assert_eq!(request.asset_type, AssetType::Collateral);
+        assert_eq!(request.token_id, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_reject_buys_in_closed_only_mode() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::Kind;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/ban-status/closed-only");
+            then.status(StatusCode::OK)
+                .json_body(json!({"closed_only": true}));
+        });
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        assert!(!client.is_closed_only());
+        client.closed_only_mode().await?;
+        assert!(client.is_closed_only());
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        post_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn monitor_closed_only_should_report_changes() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::clob::restrictions::MonitorConfig;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/auth/ban-status/closed-only");
+            then.status(StatusCode::OK)
+                .json_body(json!({"closed_only": true}));
+        });
+
+        let mut monitor = client.monitor_closed_only(MonitorConfig::builder().build());
+        let status = tokio::time::timeout(Duration::from_secs(5), monitor.changed()).await?;
+
+        assert_eq!(status, Some(true));
+        assert!(monitor.is_closed_only());
+        assert!(client.is_closed_only());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_orders_should_report_outcomes() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::types::response::PostOrderOutcome;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "errorMsg": "",
+                    "makingAmount": "5",
+                    "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                    "status": "matched",
+                    "success": true,
+                    "takingAmount": "10"
+                },
+                {
+                    "errorMsg": "order couldn't be fully filled. FOK orders are fully filled or killed.",
+                    "makingAmount": "",
+                    "orderID": "",
+                    "status": "unmatched",
+                    "success": false,
+                    "takingAmount": ""
+                }
+            ]));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let first = client.sign(&signer, SignableOrder::default()).await?;
+        let second = client.sign(&signer, SignableOrder::default()).await?;
+        let responses = client.post_orders(vec![first, second]).await?;
+
+        assert!(responses[0].is_success());
+        assert_eq!(
+            responses[0].order_id(),
+            Some("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
+        );
+        assert_eq!(responses[0].outcome(), PostOrderOutcome::Matched);
+        assert!(!responses[1].is_success());
+        assert_eq!(responses[1].order_id(), None);
+        assert_eq!(
+            responses[1].outcome(),
+            PostOrderOutcome::Rejected {
+                reason: "order couldn't be fully filled. FOK orders are fully filled or killed."
+                    .to_owned()
+            }
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_ladder_should_post_and_cancel_levels() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::ladder::Config;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "errorMsg": "",
+                    "makingAmount": "",
+                    "orderID": "1",
+                    "status": "live",
+                    "success": true,
+                    "takingAmount": ""
+                },
+                {
+                    "errorMsg": "",
+                    "makingAmount": "",
+                    "orderID": "2",
+                    "status": "live",
+                    "success": true,
+                    "takingAmount": ""
+                }
+            ]));
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["1", "2"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1", "2"], "not_canceled": {} }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.60))
+            .size(dec!(20))
+            .levels(2)
+            .spacing(dec!(0.05))
+            .build();
+        let ladder = client.post_ladder(&signer, config).await?;
+
+        assert!(ladder.is_success());
+        assert_eq!(ladder.levels()[1].price, dec!(0.65));
+        assert_eq!(ladder.order_ids(), ["1", "2"]);
+
+        let response = ladder.cancel().await?;
+
+        assert_eq!(response.canceled, ["1", "2"]);
+        post_mock.assert();
+        cancel_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .json_body(json!({
+                    "order": {
+                        "expiration": "0",
+                        "feeRateBps": "0",
+                        "maker": Address::ZERO,
+                        "makerAmount": "0",
+                        "nonce": "0",
+                        "salt": 0,
+                        "side": Side::Buy,
+                        "signature": "0x0d18c04a653d89bf7375636adb7db69cffe362755960dc6ce8a0d46b04355b767958fae51c48e0e4b0908347442cb461e811d2f5a751303f7a8c1f75e17b3e701b",
+                        "signatureType": 0,
+                        "signer": Address::ZERO,
+                        "taker": Address::ZERO,
+                        "takerAmount": "0",
+                        "tokenId": "0"
+                    },
+                    "orderType": "FOK",
+                    "owner": "00000000-0000-0000-0000-000000000000"
+                }));
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        let expected = PostOrderResponse::builder()
+            .making_amount(Decimal::ZERO)
+            .taking_amount(Decimal::ZERO)
+            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
+            .status(OrderStatusType::Live)
+            .success(true)
+            .build();
+
+        assert_eq!(*response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_fail_when_geoblock_enforced() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::error::{Geoblock, Kind};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .geoblock_host(server.base_url())
+            .enforce_geoblock(true)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let geoblock_mock = server.mock(|when, then| {
+            when.method(GET).path("/api/geoblock");
+            then.status(StatusCode::OK).json_body(json!({
+                "blocked": true,
+                "ip": "192.168.1.1",
+                "country": "US",
+                "region": "NY"
+            }));
+        });
+        let order_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Geoblock);
+        let geoblock = err.downcast_ref::<Geoblock>().unwrap();
+        assert_eq!(geoblock.country, "US");
+
+        // The second attempt reuses the cached geoblock result
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        client.post_order(signed_order).await.unwrap_err();
+
+        geoblock_mock.assert_calls(1);
+        order_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_refuse_resubmission_within_dedup_window() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::types::Order;
+        use polymarket_client_sdk::error::Validation;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .order_dedup_window(Duration::from_secs(60))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        client.post_order(signed_order).await.unwrap_err();
+
+        // Retrying the same signed order after a failure is refused locally
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let key = signed_order.idempotency_key();
+        let err = client.post_order(signed_order).await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert!(msg.contains(&key.to_string()), "{msg}");
+
+        // A different order is still sent
+        let mut order = Order::default();
+        order.salt = U256::from(1);
+        let signable_order = SignableOrder::builder()
+            .order(order)
+            .order_type(OrderType::FOK)
+            .build();
+        let signed_order = client.sign(&signer, signable_order).await?;
+        client.post_order(signed_order).await.unwrap_err();
+
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn watch_expirations_should_cancel_expiring_orders() -> anyhow::Result<()> {
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::expirations::WatchConfig;
+        use polymarket_client_sdk::clob::types::Order;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order_id = "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0";
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": order_id,
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!([order_id]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": [order_id], "notCanceled": {} }));
+        });
+
+        let expiration = Utc::now().timestamp() + 30;
+        let mut order = Order::default();
+        order.expiration = U256::from(expiration);
+        let signable_order = SignableOrder::builder()
+            .order(order)
+            .order_type(OrderType::GTD)
+            .build();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, signable_order).await?;
+        client.post_order(signed_order).await?;
+
+        let config = WatchConfig::builder()
+            .lead(Duration::from_secs(60))
+            .interval(Duration::from_millis(10))
+            .build();
+        let expiries: Vec<_> = client.watch_expirations(config).take(1).collect().await;
+        let expiry = expiries[0].as_ref().unwrap();
+
+        assert_eq!(expiry.order_id, order_id);
+        assert_eq!(expiry.expiration.timestamp(), expiration);
+        assert!(expiry.canceled);
+        post_mock.assert();
+        cancel_mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn schedule_order_should_post_at_server_time() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::timing::SyncConfig;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let config = SyncConfig::builder()
+            .samples(3)
+            .spacing(Duration::from_millis(10))
+            .build();
+        let clock = client.sync_clock(config).await?;
+        let server_now = clock.now().timestamp();
+        assert!((TIMESTAMP.parse::<i64>()?..=TIMESTAMP.parse::<i64>()? + 1).contains(&server_now));
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let at = clock.now() + chrono::TimeDelta::milliseconds(100);
+        let scheduled = client
+            .schedule_signable_order(&signer, SignableOrder::default(), at, &clock)
+            .await?;
+        assert_eq!(scheduled.at(), at);
+
+        let response = scheduled.result().await?;
+        assert!(clock.now() >= at);
+        assert_eq!(response.status, OrderStatusType::Live);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn shutdown_should_cancel_orders_and_reject_new_ones() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::lifecycle::ShutdownConfig;
+        use polymarket_client_sdk::error::Validation;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE).path("/cancel-all");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": [], "notCanceled": {} }));
+        });
+        let order_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let shutdown =
+            client.shutdown_handle(ShutdownConfig::builder().cancel_orders(true).build());
+        shutdown.shutdown().await?;
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Unable to post orders after shutdown has started");
+
+        // Cancellations are still allowed
+        client.cancel_all_orders().await?;
+
+        cancel_mock.assert_calls(2);
+        order_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_fail_when_risk_limit_breached() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::risk::{Engine, Limits};
+        use polymarket_client_sdk::error::{Kind, RiskLimit, RiskRejected};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let risk = Arc::new(Engine::new(
+            Limits::builder().kill_threshold(dec!(100)).build(),
+        ));
+        let config = Config::builder().risk(Arc::clone(&risk)).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        risk.record_pnl(dec!(-100));
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Risk);
+        let rejected = err.downcast_ref::<RiskRejected>().unwrap();
+        assert_eq!(rejected.limit, RiskLimit::KillSwitch);
+        assert_eq!(rejected.value, dec!(100));
+
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_order_should_release_risk_exposure() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::risk::{Engine, Limits};
+        use polymarket_client_sdk::types::B256;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let risk = Arc::new(Engine::new(
+            Limits::builder().max_market_exposure(dec!(100)).build(),
+        ));
+        let market = B256::repeat_byte(1);
+        risk.register_token(token_1(), market, None);
+        let config = Config::builder()
+            .dry_run(true)
+            .risk(Arc::clone(&risk))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let buy = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let buy = client.post_order(client.sign(&signer, buy).await?).await?;
+        assert_eq!(risk.market_exposure(market), dec!(50));
+
+        // Selling held shares reduces the position, so it adds no exposure
+        risk.set_position(token_1(), Decimal::ONE_HUNDRED, dec!(40));
+        let sell = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.6))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Sell)
+            .build()
+            .await?;
+        let sell = client.post_order(client.sign(&signer, sell).await?).await?;
+        assert_eq!(risk.market_exposure(market), dec!(50));
+
+        client.cancel_order(&buy.order_id).await?;
+        assert_eq!(risk.market_exposure(market), Decimal::ZERO);
+        client.cancel_order(&sell.order_id).await?;
+        assert_eq!(risk.market_exposure(market), Decimal::ZERO);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn switchboard_should_block_disabled_markets() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::switchboard::{Scope, Switchboard};
+        use polymarket_client_sdk::error::Kind;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let switchboard = Arc::new(Switchboard::default());
+        let config = Config::builder()
+            .switchboard(Arc::clone(&switchboard))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, TokenId::default(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let market = b256!("0x0000000000000000000000000000000000000000000000000000000000000001");
+        switchboard.register_token(TokenId::default(), market, None);
+        let blocked = client.sign(&signer, SignableOrder::default()).await?;
+        let allowed = client.sign(&signer, SignableOrder::default()).await?;
+
+        switchboard.disable(Scope::Market(market), "oracle dispute");
+        let err = client.post_orders(vec![blocked]).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.to_string().contains("oracle dispute"), "{err}");
+        client
+            .sign(&signer, SignableOrder::default())
+            .await
+            .unwrap_err();
+        mock.assert_calls(0);
+
+        assert!(switchboard.enable(&Scope::Market(market)));
+        client.post_orders(vec![allowed]).await?;
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validators_should_deny_orders() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use async_trait::async_trait;
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::hooks::{OrderValidator, Verdict};
+        use polymarket_client_sdk::clob::types::Order;
+        use polymarket_client_sdk::error::{Kind, OrderDenied};
+
+        #[derive(Debug)]
+        struct Compliance;
+
+        #[async_trait]
+        impl OrderValidator for Compliance {
+            async fn validate_signable(&self, order: &SignableOrder) -> Verdict {
+                if order.order_type == OrderType::GTD {
+                    Verdict::deny("GTD orders are not allowed")
+                } else {
+                    Verdict::Allow
+                }
+            }
+
+            async fn validate_signed(&self, order: &SignedOrder) -> Verdict {
+                if order.post_only == Some(true) {
+                    Verdict::deny("post-only orders are not allowed")
+                } else {
+                    Verdict::Allow
+                }
             }
+        }
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .validators(vec![Arc::new(Compliance)])
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signable_order = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::GTD)
+            .build();
+        let err = client.sign(&signer, signable_order).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        let denied = err.downcast_ref::<OrderDenied>().unwrap();
+        assert_eq!(denied.reason, "GTD orders are not allowed");
+
+        let mut signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        signed_order.post_only = Some(true);
+        let err = client.post_order(signed_order).await.unwrap_err();
+        let denied = err.downcast_ref::<OrderDenied>().unwrap();
+        assert_eq!(denied.reason, "post-only orders are not allowed");
+
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dry_run_should_simulate_order_mutations() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder().dry_run(true).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE);
+            then.status(StatusCode::OK);
+        });
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let order_id = signed_order.idempotency_key().to_string();
+        let response = client.post_order(signed_order).await?;
+
+        assert!(response.success);
+        assert_eq!(response.status, OrderStatusType::Live);
+        assert_eq!(response.order_id, order_id);
+        assert_eq!(client.simulated_orders(), vec![order_id.clone()]);
+
+        let response = client.cancel_orders(&[&order_id, "unknown"]).await?;
+        assert_eq!(response.canceled, vec![order_id]);
+        assert!(response.not_canceled.contains_key("unknown"));
+        assert!(client.simulated_orders().is_empty());
+
+        post_mock.assert_calls(0);
+        cancel_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_order_id_should_resolve_to_order_id() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::types::Order;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order_id = "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0";
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": order_id,
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/order")
+                .json_body(json!({ "orderId": order_id }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": [order_id], "notCanceled": {} }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signable_order = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::FOK)
+            .client_order_id("my-order")
+            .build();
+        let signed_order = client.sign(&signer, signable_order).await?;
+        assert_eq!(signed_order.client_order_id.as_deref(), Some("my-order"));
+        client.post_order(signed_order).await?;
+
+        assert_eq!(
+            client.exchange_order_id("my-order").as_deref(),
+            Some(order_id)
+        );
+        assert_eq!(
+            client.client_order_id(order_id).as_deref(),
+            Some("my-order")
+        );
+        assert_eq!(client.exchange_order_id("unknown"), None);
+
+        let response = client.cancel_order("my-order").await?;
+        assert_eq!(response.canceled, vec![order_id.to_owned()]);
 
-            then.status(StatusCode::OK).json_body(json_with_cursor);
-        });
+        // Canceled orders are forgotten
+        assert_eq!(client.exchange_order_id("my-order"), None);
+        assert_eq!(client.client_order_id(order_id), None);
 
-        let mock2 = server.mock(|when, then| {
-            when.method(httpmock::Method::GET)
-                .path("/markets")
-                .query_param("next_cursor", "1");
+        post_mock.assert();
+        cancel_mock.assert();
 
-            let mut json_with_cursor = json.clone();
-            if let Some(obj) = json_with_cursor.as_object_mut() {
-                obj.insert(
-                    "next_cursor".to_owned(),
-                    serde_json::Value::String(TERMINAL_CURSOR.to_owned()),
-                );
-            }
+        Ok(())
+    }
 
-            then.status(StatusCode::OK).json_body(json_with_cursor);
-        });
+    #[tokio::test]
+    async fn client_order_id_should_not_be_kept_for_terminal_orders() -> anyhow::Result<()> {
+        use polymarket_client_sdk::clob::types::Order;
 
-        let response: Vec<MarketResponse> = client
-            .stream_data(Client::markets)
-            .filter_map(|d| future::ready(d.ok()))
-            .collect()
-            .await;
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
 
-        let market = MarketResponse::builder()
-            .enable_order_book(true)
-            .active(true)
-            .closed(false)
-            .archived(false)
-            .accepting_orders(true)
-            .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
-            .minimum_order_size(Decimal::ONE)
-            .minimum_tick_size(TickSize::Hundredth.as_decimal())
-            .condition_id(b256!(
-                "0000000000000000000000000000000000000000000000000000000000000001"
-            ))
-            .question_id(b256!(
-                "0000000000000000000000000000000000000000000000000000000067890abc"
-            ))
-            .question("Will BTC close above $50k today?")
-            .description("A market about BTC daily close price")
-            .market_slug("btc-close-above-50k")
-            .end_date_iso("2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap())
-            .seconds_delay(5)
-            .fpmm(address!("0000000000000000000000000000000000abc123"))
-            .maker_base_fee(Decimal::ZERO)
-            .taker_base_fee(Decimal::ZERO)
-            .notifications_enabled(true)
-            .neg_risk(false)
-            .icon("https://example.com/icon.png")
-            .image("https://example.com/image.png")
-            .rewards(
-                Rewards::builder()
-                    .min_size(dec!(10.0))
-                    .max_spread(dec!(0.05))
-                    .build(),
-            )
-            .is_50_50_outcome(false)
-            .tokens(vec![
-                Token::builder()
-                    .token_id(token_1())
-                    .outcome("YES")
-                    .price(dec!(0.55))
-                    .winner(false)
-                    .build(),
-                Token::builder()
-                    .token_id(token_2())
-                    .outcome("NO")
-                    .price(dec!(0.45))
-                    .winner(false)
-                    .build(),
-            ])
-            .tags(vec!["crypto".into(), "btc".into(), "price".into()])
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order_id = "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0";
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "100",
+                "orderID": order_id,
+                "status": "matched",
+                "success": true,
+                "takingAmount": "50"
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signable_order = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::FOK)
+            .client_order_id("filled")
             .build();
-        let expected = vec![market.clone(), market];
+        client
+            .post_order(client.sign(&signer, signable_order).await?)
+            .await?;
 
-        assert_eq!(response, expected);
-        mock.assert();
-        mock2.assert();
+        assert_eq!(client.exchange_order_id("filled"), None);
+        assert_eq!(client.client_order_id(order_id), None);
+        post_mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn check_geoblock_should_succeed() -> anyhow::Result<()> {
+    async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let config = Config::builder().geoblock_host(server.base_url()).build();
-        let client = Client::new(&server.base_url(), config)?;
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
 
         let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET).path("/api/geoblock");
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
             then.status(StatusCode::OK).json_body(json!({
-                "blocked": false,
-                "ip": "192.168.1.1",
-                "country": "US",
-                "region": "NY"
+                "error_msg": "",
+                "makingAmount": "100",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "50",
+                "transactionsHashes": ["0x2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"]
             }));
         });
 
-        let response = client.check_geoblock().await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
 
-        let expected = GeoblockResponse::builder()
-            .blocked(false)
-            .ip("192.168.1.1".to_owned())
-            .country("US".to_owned())
-            .region("NY".to_owned())
+        let expected = PostOrderResponse::builder()
+            .making_amount(Decimal::from(100))
+            .taking_amount(Decimal::from(50))
+            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
+            .status(OrderStatusType::Matched)
+            .success(true)
+            .transaction_hashes(vec![b256!(
+                "2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"
+            )])
             .build();
 
-        assert_eq!(response, expected);
+        assert_eq!(*response, expected);
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn check_geoblock_blocked_should_succeed() -> anyhow::Result<()> {
+    async fn post_order_handle_should_cancel_posted_order() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let config = Config::builder().geoblock_host(server.base_url()).build();
-        let client = Client::new(&server.base_url(), config)?;
+        let client = create_authenticated(&server).await?;
 
-        let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET).path("/api/geoblock");
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let post_mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
             then.status(StatusCode::OK).json_body(json!({
-                "blocked": true,
-                "ip": "10.0.0.1",
-                "country": "CU",
-                "region": "HAV"
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "1",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/order")
+                .json_body(json!({ "orderId": "1" }));
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1"],
+                "notCanceled": {}
             }));
         });
 
-        let response = client.check_geoblock().await?;
-
-        assert!(response.blocked);
-        assert_eq!(response.country, "CU");
-        mock.assert();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let handle = client.post_order(signed_order).await?;
 
-        Ok(())
-    }
-}
+        assert_eq!(handle.id(), "1");
+        assert_eq!(handle.status, OrderStatusType::Live);
 
-mod authenticated {
-    #[cfg(feature = "heartbeats")]
-    use std::time::Duration;
+        let response = handle.cancel().await?;
+        assert_eq!(response.canceled, vec!["1".to_owned()]);
+        assert_eq!(handle.into_response().order_id, "1");
 
-    use alloy::primitives::Signature;
-    use alloy::signers::Signer as _;
-    use alloy::signers::local::LocalSigner;
-    use chrono::NaiveDate;
-    use httpmock::Method::{DELETE, GET, POST};
-    use polymarket_client_sdk::clob::types::request::{
-        BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
-        OrdersRequest, TradesRequest, UserRewardsEarningRequest,
-    };
-    use polymarket_client_sdk::clob::types::response::{
-        ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
-        CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder, MarketRewardResponse,
-        MarketRewardsConfig, NotificationPayload, NotificationResponse, OpenOrderResponse,
-        OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
-        TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
-    };
-    use polymarket_client_sdk::clob::types::{
-        AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
-        TradeStatusType, TraderSide,
-    };
-    #[cfg(feature = "heartbeats")]
-    use polymarket_client_sdk::error::Synchronization;
-    use polymarket_client_sdk::types::{Address, address, b256};
+        post_mock.assert();
+        cancel_mock.assert();
 
-    use super::*;
-    use crate::common::{
-        API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
-        TIMESTAMP,
-    };
+        Ok(())
+    }
 
     #[tokio::test]
-    async fn api_keys_should_succeed() -> anyhow::Result<()> {
+    async fn order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
+        let json = json!({
+            "id": "1",
+            "status": "LIVE",
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+            "asset_id": token_1(),
+            "side": "buy",
+            "original_size": "10.0",
+            "size_matched": "2.5",
+            "price": "0.45",
+            "associate_trades": [
+                "0xtradehash1",
+                "0xtradehash2"
+            ],
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "1705708800",
+            "order_type": "gtd"
+        });
+
         let mock = server.mock(|when, then| {
             when.method(GET)
-                .path("/auth/api-keys")
+                .path("/data/order/1")
                 .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
                 .header(POLY_API_KEY, API_KEY)
                 .header(POLY_PASSPHRASE, PASSPHRASE);
-            then.status(StatusCode::OK)
-                .json_body(json!({"apiKeys": [API_KEY]}));
+            then.status(StatusCode::OK).json_body(json);
         });
 
-        let response = client.api_keys().await?;
+        let response = client.order("1").await?;
 
-        let expected = ApiKeysResponse::builder().keys(vec![API_KEY]).build();
+        let expected = OpenOrderResponse::builder()
+            .id("1")
+            .status(OrderStatusType::Live)
+            .owner(Uuid::max())
+            .maker_address(address!("0x2222222222222222222222222222222222222222"))
+            .market(b256!(
+                "000000000000000000000000000000000000000000000000006d61726b657461"
+            ))
+            .asset_id(token_1())
+            .side(Side::Buy)
+            .original_size(dec!(10.0))
+            .size_matched(dec!(2.5))
+            .price(dec!(0.45))
+            .associate_trades(vec!["0xtradehash1".into(), "0xtradehash2".into()])
+            .outcome("YES")
+            .created_at("2024-01-15T12:34:56Z".parse().unwrap())
+            .expiration("2024-01-20T00:00:00Z".parse().unwrap())
+            .order_type(OrderType::GTD)
+            .build();
 
         assert_eq!(response, expected);
         mock.assert();
@@ -1439,306 +3609,453 @@ mod authenticated {
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
+    async fn wait_for_order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
         let mock = server.mock(|when, then| {
-            when.method(DELETE)
-                .path("/auth/api-key")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
-            then.status(StatusCode::OK).body("\"\"");
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "1",
+                "status": "MATCHED",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "10.0",
+                "price": "0.45",
+                "associate_trades": ["0xtradehash1"],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            }));
         });
 
-        client.delete_api_key().await?;
+        let response = client
+            .wait_for_order("1", &[OrderStatusType::Matched], Duration::from_secs(5))
+            .await?;
 
-        mock.assert();
+        assert_eq!(response.status, OrderStatusType::Matched);
+        assert_eq!(response.size_matched, dec!(10.0));
+        mock.assert_calls(1);
 
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn closed_only_mode_should_succeed() -> anyhow::Result<()> {
+    async fn wait_for_order_should_time_out() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::{Kind, Timeout};
+
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
         let mock = server.mock(|when, then| {
-            when.method(GET)
-                .path("/auth/ban-status/closed-only")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
-            then.status(StatusCode::OK)
-                .json_body(json!({"closed_only": true}));
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "1",
+                "status": "LIVE",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "0",
+                "price": "0.45",
+                "associate_trades": [],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            }));
         });
 
-        let response = client.closed_only_mode().await?;
-
-        let expected = BanStatusResponse::builder().closed_only(true).build();
+        let err = client
+            .wait_for_order("1", &[OrderStatusType::Matched], Duration::from_millis(100))
+            .await
+            .unwrap_err();
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert_eq!(
+            err.downcast_ref::<Timeout>().unwrap().duration,
+            Duration::from_millis(100)
+        );
+        mock.assert_calls(2);
 
         Ok(())
     }
 
-    // Also fills in some other, less often used fields like nonce, and salt generator
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn sign_order_should_succeed() -> anyhow::Result<()> {
+    async fn wait_until_scoring_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let client = create_authenticated(&server).await?;
 
         let mock = server.mock(|when, then| {
             when.method(GET)
-                .path("/auth/derive-api-key")
-                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase())
-                .header(POLY_NONCE, "0")
-                .header(POLY_SIGNATURE, SIGNATURE)
-                .header(POLY_TIMESTAMP, TIMESTAMP);
-            then.status(StatusCode::OK).json_body(json!({
-                "apiKey": API_KEY.to_string(),
-                "passphrase": PASSPHRASE,
-                "secret": SECRET
-            }));
-        });
-        let mock2 = server.mock(|when, then| {
-            when.method(GET).path("/time");
+                .path("/order-scoring")
+                .query_param("order_id", "1");
             then.status(StatusCode::OK)
-                .json_body(TIMESTAMP.parse::<i64>().unwrap());
+                .json_body(json!({ "scoring": true }));
         });
 
-        let funder = address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316");
-        let config = Config::builder().use_server_time(true).build();
-        let client = Client::new(&server.base_url(), config)?
-            .authentication_builder(&signer)
-            .funder(funder)
-            .signature_type(SignatureType::Proxy)
-            .salt_generator(|| 1) // To ensure determinism
-            .authenticate()
+        client
+            .wait_until_scoring("1", Duration::from_secs(5))
             .await?;
 
-        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+        mock.assert_calls(1);
 
-        assert_eq!(
-            client.tick_size(token_1()).await?.minimum_tick_size,
-            TickSize::Thousandth
-        );
+        Ok(())
+    }
 
-        let taker = address!("0xf7fB45986800e2D259BAa25B56466bd02dA37a44");
-        let signable_order = client
-            .limit_order()
-            .token_id(token_1())
-            .price(dec!(0.512))
-            .size(Decimal::ONE_HUNDRED)
-            .side(Side::Buy)
-            .taker(taker)
-            .nonce(2)
-            .build()
-            .await?;
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn wait_until_scoring_should_time_out() -> anyhow::Result<()> {
+        use polymarket_client_sdk::error::Kind;
 
-        let signed_order = client.sign(&signer, signable_order.clone()).await?;
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
 
-        let expected = SignedOrder::builder()
-            .owner(API_KEY)
-            .order(signable_order.order)
-            .order_type(OrderType::GTC)
-            .post_only(false)
-            .signature(Signature::new(
-                U256::from_str(
-                    "67938079796141091828598175285011746318151402208362009718761031231176791189384",
-                )?,
-                U256::from_str(
-                    "31661255856293674232712511615893783899761903915420680037924826147367342033568",
-                )?,
-                true,
-            ))
-            .build();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/order-scoring");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "scoring": false }));
+        });
 
-        assert_eq!(signed_order.order.taker, taker);
-        assert_eq!(signed_order.order.maker, funder);
-        assert_ne!(signed_order.order.maker, client.address());
-        assert_eq!(signed_order.order.signatureType, SignatureType::Proxy as u8);
-        assert_eq!(signed_order.order.nonce, U256::from(2));
-        assert_eq!(signed_order.order.salt, U256::from(1));
-        assert_eq!(
-            client.address(),
-            address!("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266")
-        );
+        let err = client
+            .wait_until_scoring("1", Duration::from_millis(100))
+            .await
+            .unwrap_err();
 
-        assert_eq!(signed_order, expected);
-        mock.assert();
-        mock2.assert_calls(2);
+        assert_eq!(err.kind(), Kind::Timeout);
+        mock.assert_calls(2);
 
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn post_order_should_succeed() -> anyhow::Result<()> {
+    async fn stream_fills_should_succeed() -> anyhow::Result<()> {
+        use futures_util::stream::StreamExt as _;
+
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements(&server, token_1(), TickSize::Hundredth);
-
-        let mock = server.mock(|when, then| {
-            when.method(POST)
-                .path("/order")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .json_body(json!({
-                    "order": {
-                        "expiration": "0",
-                        "feeRateBps": "0",
-                        "maker": Address::ZERO,
-                        "makerAmount": "0",
-                        "nonce": "0",
-                        "salt": 0,
-                        "side": Side::Buy,
-                        "signature": "0x0d18c04a653d89bf7375636adb7db69cffe362755960dc6ce8a0d46b04355b767958fae51c48e0e4b0908347442cb461e811d2f5a751303f7a8c1f75e17b3e701b",
-                        "signatureType": 0,
-                        "signer": Address::ZERO,
-                        "taker": Address::ZERO,
-                        "takerAmount": "0",
-                        "tokenId": "0"
-                    },
-                    "orderType": "FOK",
-                    "owner": "00000000-0000-0000-0000-000000000000"
-                }));
+        let order_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/order/maker_002");
             then.status(StatusCode::OK).json_body(json!({
-                "error_msg": "",
-                "makingAmount": "",
-                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
-                "status": "live",
-                "success": true,
-                "takingAmount": ""
+                "id": "maker_002",
+                "status": "MATCHED",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x6666666666666666666666666666666666666666",
+                "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                "asset_id": token_1(),
+                "side": "sell",
+                "original_size": "7.5",
+                "size_matched": "7.5",
+                "price": "0.42",
+                "associate_trades": ["1"],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            }));
+        });
+        let trades_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/trades")
+                .query_param("id", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "id": "1",
+                        "taker_order_id": "taker_123",
+                        "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                        "asset_id": token_1(),
+                        "side": "BUY",
+                        "size": "12.5",
+                        "fee_rate_bps": "5",
+                        "price": "0.42",
+                        "status": "MATCHED",
+                        "match_time": "1705322096",
+                        "last_update": "1705322130",
+                        "outcome": "YES",
+                        "bucket_index": 2,
+                        "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                        "maker_address": "0x2222222222222222222222222222222222222222",
+                        "maker_orders": [
+                            {
+                                "order_id": "maker_002",
+                                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                                "maker_address": "0x6666666666666666666666666666666666666666",
+                                "matched_amount": "7.5",
+                                "price": "0.42",
+                                "fee_rate_bps": "5",
+                                "asset_id": token_1(),
+                                "outcome": "YES",
+                                "side": "SELL"
+                            }
+                        ],
+                        "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                        "trader_side": "MAKER"
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
             }));
         });
 
-        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
-        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
-        let response = client.post_order(signed_order).await?;
-
-        let expected = PostOrderResponse::builder()
-            .making_amount(Decimal::ZERO)
-            .taking_amount(Decimal::ZERO)
-            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
-            .status(OrderStatusType::Live)
-            .success(true)
-            .build();
+        let fills: Vec<_> = client
+            .stream_fills("maker_002", Duration::from_millis(10))
+            .collect()
+            .await;
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert_eq!(fills.len(), 1);
+        let fill = fills.into_iter().next().unwrap()?;
+        assert_eq!(fill.order_id, "maker_002");
+        assert_eq!(fill.trade_id, "1");
+        assert_eq!(fill.size, dec!(7.5));
+        assert_eq!(fill.price, dec!(0.42));
+        assert_eq!(fill.status, TradeStatusType::Matched);
+        order_mock.assert();
+        trades_mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
+    async fn stream_fills_should_retry_unindexed_trades() -> anyhow::Result<()> {
+        use futures_util::stream::StreamExt as _;
+
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements(&server, token_1(), TickSize::Hundredth);
-
-        let mock = server.mock(|when, then| {
-            when.method(POST)
-                .path("/order")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
+        let order_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/order/maker_002");
             then.status(StatusCode::OK).json_body(json!({
-                "error_msg": "",
-                "makingAmount": "100",
-                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
-                "status": "matched",
-                "success": true,
-                "takingAmount": "50",
-                "transactionsHashes": ["0x2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"]
+                "id": "maker_002",
+                "status": "MATCHED",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x6666666666666666666666666666666666666666",
+                "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                "asset_id": token_1(),
+                "side": "sell",
+                "original_size": "7.5",
+                "size_matched": "7.5",
+                "price": "0.42",
+                "associate_trades": ["1"],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
             }));
         });
+        // The trade is not indexed yet on the first lookup
+        let mut empty_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/trades").query_param("id", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 1,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+        let swap = async {
+            while empty_mock.calls() == 0 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            empty_mock.delete();
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/data/trades")
+                    .query_param("id", "1");
+                then.status(StatusCode::OK).json_body(json!({
+                    "data": [
+                        {
+                            "id": "1",
+                            "taker_order_id": "taker_123",
+                            "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                            "asset_id": token_1(),
+                            "side": "BUY",
+                            "size": "12.5",
+                            "fee_rate_bps": "5",
+                            "price": "0.42",
+                            "status": "MATCHED",
+                            "match_time": "1705322096",
+                            "last_update": "1705322130",
+                            "outcome": "YES",
+                            "bucket_index": 2,
+                            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                            "maker_address": "0x2222222222222222222222222222222222222222",
+                            "maker_orders": [
+                                {
+                                    "order_id": "maker_002",
+                                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                                    "maker_address": "0x6666666666666666666666666666666666666666",
+                                    "matched_amount": "7.5",
+                                    "price": "0.42",
+                                    "fee_rate_bps": "5",
+                                    "asset_id": token_1(),
+                                    "outcome": "YES",
+                                    "side": "SELL"
+                                }
+                            ],
+                            "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                            "trader_side": "MAKER"
+                        }
+                    ],
+                    "limit": 1,
+                    "count": 1,
+                    "next_cursor": "LTE="
+                }));
+            })
+        };
+
+        let (fills, trades_mock) = tokio::join!(
+            client
+                .stream_fills("maker_002", Duration::from_millis(50))
+                .collect::<Vec<_>>(),
+            swap
+        );
 
-        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
-        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
-        let response = client.post_order(signed_order).await?;
+        assert_eq!(fills.len(), 1);
+        let fill = fills.into_iter().next().unwrap()?;
+        assert_eq!(fill.order_id, "maker_002");
+        assert_eq!(fill.trade_id, "1");
+        assert_eq!(fill.size, dec!(7.5));
+        assert_eq!(fill.price, dec!(0.42));
+        assert_eq!(fill.status, TradeStatusType::Matched);
+        assert!(order_mock.calls() > 1);
+        trades_mock.assert();
 
-        let expected = PostOrderResponse::builder()
-            .making_amount(Decimal::from(100))
-            .taking_amount(Decimal::from(50))
-            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
-            .status(OrderStatusType::Matched)
-            .success(true)
-            .transaction_hashes(vec![b256!(
-                "2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"
-            )])
-            .build();
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn strategy_runner_should_call_strategy_until_stopped() -> anyhow::Result<()> {
+        use async_trait::async_trait;
+        use polymarket_client_sdk::clob::strategy::{Config, Context, Event, Strategy};
+        use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+
+        struct Books(Vec<TokenId>);
+
+        #[async_trait]
+        impl Strategy for Books {
+            async fn on_book(
+                &mut self,
+                ctx: &Context<'_>,
+                book: &OrderBookSummaryResponse,
+            ) -> polymarket_client_sdk::Result<()> {
+                self.0.push(book.asset_id);
+                ctx.stop();
+                Ok(())
+            }
+        }
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let books_mock = server.mock(|when, then| {
+            when.method(POST).path("/books");
+            then.status(StatusCode::OK).json_body(json!([{
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1",
+                "bids": [{ "price": "0.30", "size": "100" }],
+                "asks": [{ "price": "0.50", "size": "2" }]
+            }]));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/data/trades");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 0,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
 
-        assert_eq!(response, expected);
-        mock.assert();
+        let config = Config::builder()
+            .token_ids(vec![token_1()])
+            .fill_interval(Duration::from_secs(60))
+            .build();
+        let runner = client.strategy_runner(config);
+        let mut events = runner.subscribe();
+        let mut strategy = Books(Vec::new());
+        runner.run(&mut strategy).await?;
+
+        assert_eq!(strategy.0, [token_1()]);
+        let mut books = 0;
+        while let Ok(event) = events.try_recv() {
+            if let Event::Book(book) = event {
+                assert_eq!(book.asset_id, token_1());
+                books += 1;
+            }
+        }
+        assert_eq!(books, 1);
+        books_mock.assert_calls(1);
 
         Ok(())
     }
 
+    #[cfg(feature = "polling")]
     #[tokio::test]
-    async fn order_should_succeed() -> anyhow::Result<()> {
+    async fn strategy_runner_should_run_on_recorded_events() -> anyhow::Result<()> {
+        use async_trait::async_trait;
+        use futures::TryStreamExt as _;
+        use polymarket_client_sdk::clob::recorder::Record;
+        use polymarket_client_sdk::clob::strategy::{Config, Context, Event, Price, Strategy};
+
+        struct Prices(Vec<Decimal>);
+
+        #[async_trait]
+        impl Strategy for Prices {
+            async fn on_price(
+                &mut self,
+                _ctx: &Context<'_>,
+                price: &Price,
+            ) -> polymarket_client_sdk::Result<()> {
+                self.0.push(price.price);
+                Ok(())
+            }
+        }
+
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let json = json!({
-            "id": "1",
-            "status": "LIVE",
-            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
-            "maker_address": "0x2222222222222222222222222222222222222222",
-            "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
-            "asset_id": token_1(),
-            "side": "buy",
-            "original_size": "10.0",
-            "size_matched": "2.5",
-            "price": "0.45",
-            "associate_trades": [
-                "0xtradehash1",
-                "0xtradehash2"
-            ],
-            "outcome": "YES",
-            "created_at": 1_705_322_096,
-            "expiration": "1705708800",
-            "order_type": "gtd"
-        });
-
-        let mock = server.mock(|when, then| {
-            when.method(GET)
-                .path("/data/order/1")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
-            then.status(StatusCode::OK).json_body(json);
-        });
-
-        let response = client.order("1").await?;
+        let records: Vec<Record> = serde_json::from_value(json!([
+            {
+                "timestamp": "2024-01-15T12:00:00Z",
+                "token_id": token_1(),
+                "type": "midpoint",
+                "price": "0.5"
+            },
+            {
+                "timestamp": "2024-01-15T12:00:01Z",
+                "token_id": token_1(),
+                "type": "last_trade",
+                "price": "0.52",
+                "side": "BUY"
+            }
+        ]))?;
 
-        let expected = OpenOrderResponse::builder()
-            .id("1")
-            .status(OrderStatusType::Live)
-            .owner(Uuid::max())
-            .maker_address(address!("0x2222222222222222222222222222222222222222"))
-            .market(b256!(
-                "000000000000000000000000000000000000000000000000006d61726b657461"
-            ))
-            .asset_id(token_1())
-            .side(Side::Buy)
-            .original_size(dec!(10.0))
-            .size_matched(dec!(2.5))
-            .price(dec!(0.45))
-            .associate_trades(vec!["0xtradehash1".into(), "0xtradehash2".into()])
-            .outcome("YES")
-            .created_at("2024-01-15T12:34:56Z".parse().unwrap())
-            .expiration("2024-01-20T00:00:00Z".parse().unwrap())
-            .order_type(OrderType::GTD)
-            .build();
+        let runner = client.strategy_runner(Config::builder().build());
+        let mut strategy = Prices(Vec::new());
+        let events = futures::stream::iter(records.into_iter().map(Ok)).map_ok(Event::from);
+        runner.run_on(events, &mut strategy).await?;
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert_eq!(strategy.0, [dec!(0.5), dec!(0.52)]);
 
         Ok(())
     }
@@ -1821,6 +4138,45 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn with_server_time_should_override_config_per_call() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        let time = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(TIMESTAMP.parse::<i64>().unwrap());
+        });
+        let server_time = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/order")
+                .header(POLY_TIMESTAMP, TIMESTAMP);
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1"], "notCanceled": {} }));
+        });
+
+        client.with_server_time(true).cancel_order("1").await?;
+        time.assert_calls(1);
+        server_time.assert_calls(1);
+
+        // The local clock does not match the mocked server time
+        client.cancel_order("1").await.unwrap_err();
+        time.assert_calls(1);
+        server_time.assert_calls(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1960,6 +4316,28 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_all_orders_with_metadata_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/cancel-all");
+            then.status(StatusCode::OK)
+                .header("x-request-id", "abc-123")
+                .json_body(json!({ "canceled": ["2"], "notCanceled": {} }));
+        });
+
+        let response = client.cancel_all_orders_with_metadata().await?;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers["x-request-id"], "abc-123");
+        assert_eq!(response.body.canceled, vec!["2".to_owned()]);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_market_orders_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1991,6 +4369,441 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_orders_for_tokens_should_merge_responses() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let first = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/cancel-market-orders")
+                .body_includes(token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["0x1", "0x2"],
+                "not_canceled": { "0x3": "order is already matched" }
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/cancel-market-orders")
+                .body_includes(token_2().to_string());
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let response = client
+            .cancel_orders_for_tokens(&[token_1(), token_2(), token_1()])
+            .await;
+
+        assert_eq!(response.canceled, vec!["0x1", "0x2"]);
+        assert_eq!(
+            response.not_canceled.get("0x3").map(String::as_str),
+            Some("order is already matched")
+        );
+        assert_eq!(response.failed.len(), 1);
+        assert!(response.failed.contains_key(&token_2()));
+        assert!(!response.is_complete());
+        first.assert_calls(1);
+        second.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trades_should_filter_locally_by_user_side() -> anyhow::Result<()> {
+        use futures_util::stream::TryStreamExt as _;
+        use polymarket_client_sdk::types::Timestamp;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        // `side` is the user's, which is that of their maker order in maker trades
+        let trade = |id: &str, side: &str, trader_side: &str| {
+            let (taker_side, maker_orders) = if trader_side == "MAKER" {
+                let taker_side = if side == "BUY" { "SELL" } else { "BUY" };
+                let maker_order = json!({
+                    "order_id": format!("maker_{id}"),
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "matched_amount": "12.5",
+                    "price": "0.42",
+                    "fee_rate_bps": "5",
+                    "asset_id": token_1(),
+                    "outcome": "YES",
+                    "side": side
+                });
+                (taker_side, vec![maker_order])
+            } else {
+                (side, Vec::new())
+            };
+            json!({
+                "id": id,
+                "taker_order_id": "taker_123",
+                "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                "asset_id": token_1(),
+                "side": taker_side,
+                "size": "12.5",
+                "fee_rate_bps": "5",
+                "price": "0.42",
+                "status": "MATCHED",
+                "match_time": "1705322096",
+                "last_update": "1705322130",
+                "outcome": "YES",
+                "bucket_index": 2,
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "maker_orders": maker_orders,
+                "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                "trader_side": trader_side
+            })
+        };
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/trades")
+                .query_param("after", "1705300000")
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("1", "BUY", "MAKER"), trade("2", "SELL", "MAKER")],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "next"
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/trades")
+                .query_param("after", "1705300000")
+                .query_param("next_cursor", "next");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("3", "BUY", "TAKER"), trade("4", "BUY", "MAKER")],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let request = TradesRequest::builder()
+            .after(Timestamp::from_secs(1_705_300_000))
+            .side(Side::Buy)
+            .trader_side(TraderSide::Maker)
+            .build();
+        let trades: Vec<_> = client.stream_trades(&request).try_collect().await?;
+
+        let ids: Vec<_> = trades.iter().map(|trade| trade.id.as_str()).collect();
+        assert_eq!(ids, ["1", "4"]);
+
+        let page = client.trades(&request, None).await?;
+        let ids: Vec<_> = page.data.iter().map(|trade| trade.id.as_str()).collect();
+        assert_eq!(ids, ["1"]);
+        assert_eq!(page.count, 1);
+        first.assert_calls(2);
+        second.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn position_tracker_should_apply_fills_to_snapshot() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let data = polymarket_client_sdk::data::Client::new(&server.base_url())?;
+        let market = "0x000000000000000000000000000000000000000000000000000000006d61726b";
+
+        let positions = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", client.address().to_string().to_lowercase())
+                .query_param("sizeThreshold", "0")
+                .query_param("limit", "500")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([{
+                "proxyWallet": client.address(),
+                "asset": token_1(),
+                "conditionId": market,
+                "size": 10,
+                "avgPrice": 0.4,
+                "initialValue": 4,
+                "currentValue": 4,
+                "cashPnl": 0,
+                "percentPnl": 0,
+                "totalBought": 10,
+                "realizedPnl": 0,
+                "percentRealizedPnl": 0,
+                "curPrice": 0.4,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "Will BTC hit $100k?",
+                "slug": "btc-100k",
+                "icon": "https://example.com/btc.png",
+                "eventSlug": "crypto-prices",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": token_2(),
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            }]));
+        });
+
+        let risk = std::sync::Arc::new(polymarket_client_sdk::clob::risk::Engine::default());
+        let mut tracker = client
+            .position_tracker(&data)
+            .await?
+            .with_risk(std::sync::Arc::clone(&risk));
+        positions.assert();
+
+        let position = tracker
+            .position(token_1())
+            .expect("position from the snapshot");
+        assert_eq!(position.size, dec!(10));
+        assert_eq!(position.avg_price, dec!(0.4));
+
+        let trade = |id: &str, status: &str, side: &str, trader_side: &str, maker_orders| {
+            serde_json::from_value::<TradeResponse>(json!({
+                "id": id,
+                "taker_order_id": "taker_123",
+                "market": market,
+                "asset_id": token_1(),
+                "side": side,
+                "size": "5",
+                "fee_rate_bps": "0",
+                "price": "0.6",
+                "status": status,
+                // Matched after the snapshot
+                "match_time": "4102444800",
+                "last_update": "4102444800",
+                "outcome": "YES",
+                "bucket_index": 0,
+                "owner": API_KEY,
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "maker_orders": maker_orders,
+                "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                "trader_side": trader_side
+            }))
+        };
+        let maker_order = |owner: Uuid| {
+            json!({
+                "order_id": "maker_123",
+                "owner": owner,
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "matched_amount": "10",
+                "price": "0.5",
+                "fee_rate_bps": "0",
+                "asset_id": token_1(),
+                "outcome": "YES",
+                "side": "BUY"
+            })
+        };
+
+        // Our resting buy was filled for 10 shares at 0.5, next to another maker's order
+        let filled = trade(
+            "1",
+            "MATCHED",
+            "SELL",
+            "MAKER",
+            json!([maker_order(API_KEY), maker_order(Uuid::max())]),
+        )?;
+        assert!(tracker.apply_trade(&filled));
+        let position = tracker.position(token_1()).expect("position");
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.avg_price, dec!(0.45));
+
+        // The same trade is only applied once, however often it is reported
+        let confirmed = trade(
+            "1",
+            "CONFIRMED",
+            "SELL",
+            "MAKER",
+            json!([maker_order(API_KEY), maker_order(Uuid::max())]),
+        )?;
+        assert!(!tracker.apply_trade(&confirmed));
+
+        // We took 5 shares off the book at 0.6
+        let sold = trade(
+            "2",
+            "MATCHED",
+            "SELL",
+            "TAKER",
+            json!([maker_order(Uuid::max())]),
+        )?;
+        assert!(tracker.apply_trade(&sold));
+        let position = tracker.position(token_1()).expect("position");
+        assert_eq!(position.size, dec!(15));
+        assert_eq!(position.avg_price, dec!(0.45));
+        assert_eq!(position.realized_pnl, dec!(0.75));
+        assert_eq!(tracker.exposure(), dec!(6.75));
+        assert_eq!(
+            risk.market_position(market.parse()?),
+            (dec!(15), dec!(6.75))
+        );
+        assert_eq!(risk.daily_pnl(), dec!(0.75));
+
+        // A trade that fails on chain is rolled back
+        let failed = trade(
+            "2",
+            "FAILED",
+            "SELL",
+            "TAKER",
+            json!([maker_order(Uuid::max())]),
+        )?;
+        assert!(tracker.apply_trade(&failed));
+        let position = tracker.position(token_1()).expect("position");
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.realized_pnl, Decimal::ZERO);
+
+        // The attached risk engine follows every change, including rollbacks
+        assert_eq!(risk.market_position(market.parse()?), (dec!(20), dec!(9)));
+        assert_eq!(risk.daily_pnl(), Decimal::ZERO);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn position_tracker_losses_should_trip_daily_loss_limit() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::positions::PositionTracker;
+        use polymarket_client_sdk::clob::risk::{Engine, Limits};
+        use polymarket_client_sdk::error::{RiskLimit, RiskRejected};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let risk = Arc::new(Engine::new(
+            Limits::builder().max_daily_loss(dec!(1)).build(),
+        ));
+        let config = Config::builder()
+            .dry_run(true)
+            .risk(Arc::clone(&risk))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let as_of = DateTime::from_timestamp(1_700_000_000, 0).expect("valid time");
+        let mut tracker = PositionTracker::new(API_KEY, as_of).with_risk(Arc::clone(&risk));
+        let trade = |id: &str, side: &str, price: &str| {
+            serde_json::from_value::<TradeResponse>(json!({
+                "id": id,
+                "taker_order_id": "taker_123",
+                "market": "0x000000000000000000000000000000000000000000000000000000006d61726b",
+                "asset_id": token_1(),
+                "side": side,
+                "size": "10",
+                "fee_rate_bps": "0",
+                "price": price,
+                "status": "MATCHED",
+                "match_time": "1700000100",
+                "last_update": "1700000100",
+                "outcome": "YES",
+                "bucket_index": 0,
+                "owner": API_KEY,
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "maker_orders": [],
+                "transaction_hash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                "trader_side": "TAKER"
+            }))
+        };
+
+        // Bought 10 shares at 0.6 and sold them at 0.4, losing 2
+        assert!(tracker.apply_trade(&trade("1", "BUY", "0.6")?));
+        assert!(tracker.apply_trade(&trade("2", "SELL", "0.4")?));
+        assert_eq!(risk.daily_pnl(), dec!(-2));
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(Decimal::TEN)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let err = client
+            .post_order(client.sign(&signer, order).await?)
+            .await
+            .unwrap_err();
+
+        let rejected = err.downcast_ref::<RiskRejected>().unwrap();
+        assert_eq!(rejected.limit, RiskLimit::DailyLoss);
+        assert_eq!(rejected.value, dec!(2));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn serve_metrics_should_expose_prometheus_text() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let tick_size = server.mock(|when, then| {
+            when.method(GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+        let order = |id: &str| {
+            json!({
+                "id": id,
+                "status": "LIVE",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "2.5",
+                "price": "0.45",
+                "associate_trades": [],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "1705708800",
+                "order_type": "GTC"
+            })
+        };
+        let orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [order("1"), order("2")],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        client.tick_size(token_1()).await?;
+        client.tick_size(token_1()).await?;
+
+        let metrics = client.serve_metrics("127.0.0.1:0".parse()?).await?;
+        let url = format!("http://{}", metrics.local_addr());
+        let response = reqwest::get(format!("{url}/metrics")).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let text = response.text().await?;
+
+        assert!(text.contains("polymarket_cache_hits_total{cache=\"tick_size\"} 1\n"));
+        assert!(text.contains("polymarket_cache_misses_total{cache=\"tick_size\"} 1\n"));
+        assert!(text.contains("polymarket_cache_hit_ratio{cache=\"tick_size\"} 0.5\n"));
+        assert!(text.contains("polymarket_open_orders 2\n"));
+        tick_size.assert_calls(1);
+
+        let text = reqwest::get(format!("{url}/metrics")).await?.text().await?;
+        assert!(text.contains("polymarket_open_orders 2\n"));
+        orders.assert_calls(1);
+
+        let response = reqwest::get(format!("{url}/health")).await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn trades_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2175,7 +4988,7 @@ mod authenticated {
                 .r#type(1)
                 .owner(API_KEY)
                 .payload(NotificationPayload::builder()
-                    .asset_id(U256::from_str("71321045679252212594626385532706912750332728571942532289631379312455583992563").unwrap())
+                    .asset_id(TokenId::from_str("71321045679252212594626385532706912750332728571942532289631379312455583992563").unwrap())
                     .condition_id(b256!(
                         "5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
                     ))
@@ -2207,12 +5020,163 @@ mod authenticated {
                 .build(),
         ];
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn stream_notifications_should_succeed() -> anyhow::Result<()> {
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::notifications::StreamConfig;
+        use polymarket_client_sdk::clob::types::response::NotificationKind;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let get_mock = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": 1,
+                    "type": 4,
+                    "owner": API_KEY,
+                    "payload": {
+                        "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "market_slug": "will-trump-win-the-2024-iowa-caucus",
+                        "outcome": "YES"
+                    }
+                },
+                {
+                    "id": 2,
+                    "type": 99,
+                    "owner": API_KEY,
+                    "payload": { "amount": "1.5" }
+                }
+            ]));
+        });
+        let delete_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/notifications")
+                .query_param("ids", "1,2");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        let config = StreamConfig::builder()
+            .interval(Duration::from_millis(10))
+            .delete(true)
+            .build();
+        let notifications: Vec<_> = client.stream_notifications(config).take(2).collect().await;
+
+        let resolved = notifications[0].as_ref().unwrap();
+        assert_eq!(resolved.id.as_deref(), Some("1"));
+        match &resolved.kind {
+            NotificationKind::MarketResolved(payload) => {
+                assert_eq!(
+                    payload.condition_id,
+                    b256!("5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1")
+                );
+                assert_eq!(payload.outcome.as_deref(), Some("YES"));
+            }
+            other => panic!("unexpected notification kind {other:?}"),
+        }
+
+        let unknown = notifications[1].as_ref().unwrap();
+        assert_eq!(unknown.id.as_deref(), Some("2"));
+        assert_eq!(
+            unknown.kind,
+            NotificationKind::Unknown {
+                r#type: 99,
+                payload: json!({ "amount": "1.5" }),
+            }
+        );
+
+        get_mock.assert();
+        delete_mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn stream_notifications_should_yield_notifications_without_id_once() -> anyhow::Result<()>
+    {
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::notifications::StreamConfig;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                { "type": 99, "owner": API_KEY, "payload": { "amount": "1.5" } },
+                { "type": 99, "owner": API_KEY, "payload": { "amount": "2.5" } }
+            ]));
+        });
+
+        let config = StreamConfig::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let notifications: Vec<_> = client
+            .stream_notifications(config)
+            .take_until(tokio::time::sleep(Duration::from_millis(200)))
+            .collect()
+            .await;
+
+        assert_eq!(notifications.len(), 2);
+        assert!(notifications.iter().all(Result::is_ok));
+        assert!(mock.calls() > 1);
 
         Ok(())
     }
 
+    /// Mocks a notification list of `before` that shrinks to `after` once a deletion was received,
+    /// returning the deletion mock.
+    fn mock_notification_deletion<'server>(
+        server: &'server MockServer,
+        before: &[u64],
+        after: &[u64],
+    ) -> httpmock::Mock<'server> {
+        let deleted = Arc::new(AtomicBool::new(false));
+        let notifications = |ids: &[u64]| {
+            ids.iter()
+                .map(|id| json!({ "id": id, "type": 99, "owner": API_KEY, "payload": {} }))
+                .collect::<Vec<_>>()
+        };
+
+        let pending = Arc::clone(&deleted);
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/notifications")
+                .is_true(move |_| !pending.load(Ordering::SeqCst));
+            then.status(StatusCode::OK)
+                .json_body(json!(notifications(before)));
+        });
+        let done = Arc::clone(&deleted);
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/notifications")
+                .is_true(move |_| done.load(Ordering::SeqCst));
+            then.status(StatusCode::OK)
+                .json_body(json!(notifications(after)));
+        });
+        server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/notifications")
+                .header(POLY_API_KEY, API_KEY)
+                .is_true(move |request| {
+                    if request.method() == reqwest::Method::DELETE {
+                        deleted.store(true, Ordering::SeqCst);
+                    }
+                    true
+                });
+            then.status(StatusCode::OK).json_body(json!(null));
+        })
+    }
+
     #[tokio::test]
     async fn delete_notifications_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2227,6 +5191,10 @@ mod authenticated {
                 .query_param("ids", "1,2");
             then.status(StatusCode::OK).json_body(json!(null));
         });
+        server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
 
         let request = DeleteNotificationsRequest::builder()
             .notification_ids(vec!["1".to_owned(), "2".to_owned()])
@@ -2238,6 +5206,48 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn delete_notifications_should_count_deleted() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = mock_notification_deletion(&server, &[1, 2, 3], &[2]);
+
+        // 2 was not deleted and 4 did not exist
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids([1, 2, 4])
+            .build();
+        assert_eq!(client.delete_notifications(&request).await?, 1);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_all_notifications_should_delete_listed_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = mock_notification_deletion(&server, &[1, 2, 3], &[]);
+
+        let deleted = client
+            .delete_notifications(&DeleteNotificationsRequest::all())
+            .await?;
+        assert_eq!(deleted, 3);
+        mock.assert();
+
+        // Nothing is left to delete, so no deletion is sent
+        assert_eq!(
+            client
+                .delete_notifications(&DeleteNotificationsRequest::all())
+                .await?,
+            0
+        );
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn balance_allowance_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2271,11 +5281,180 @@ mod authenticated {
             .build();
 
         assert_eq!(response, expected);
+        assert_eq!(response.usdc()?, Usdc::ZERO);
         mock.assert();
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn batch_should_collect_every_requested_call() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let books = [token_1(), token_2()].map(|token_id| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/book")
+                    .query_param("token_id", token_id.to_string());
+                then.status(StatusCode::OK).json_body(json!({
+                    "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                    "asset_id": token_id,
+                    "tick_size": TickSize::Hundredth.as_decimal(),
+                    "min_order_size": "5",
+                    "neg_risk": false,
+                    "timestamp": "123456789",
+                    "bids": [{ "price": "0.4", "size": "100" }],
+                    "asks": [{ "price": "0.6", "size": "100" }]
+                }));
+            })
+        });
+        let balance = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "1000000",
+                "allowances": {}
+            }));
+        });
+        let orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 100,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let batch = Batch::builder()
+            .books(vec![token_1(), token_2()])
+            .balance(
+                BalanceAllowanceRequest::builder()
+                    .asset_type(AssetType::Collateral)
+                    .build(),
+            )
+            .open_orders(OrdersRequest::default())
+            .concurrency(2)
+            .build();
+        let snapshot = client.batch(&batch).await?;
+
+        assert_eq!(snapshot.books.len(), 2);
+        let book = snapshot.books[&token_2()].get(Duration::from_secs(60))?;
+        assert_eq!(book.asset_id, token_2());
+        assert_eq!(book.midpoint(), Some(dec!(0.5)));
+        assert_eq!(snapshot.balance.unwrap().usdc()?, Usdc::new(Decimal::ONE)?);
+        assert_eq!(snapshot.open_orders, Some(Vec::new()));
+        assert!(snapshot.prices.is_none());
+
+        for book in books {
+            book.assert();
+        }
+        balance.assert();
+        orders.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cached_balance_should_be_invalidated_by_cancel() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let balance_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL")
+                .query_param("signature_type", "0");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "1000000",
+                "allowances": {}
+            }));
+        });
+        let cancel_mock = server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1"],
+                "not_canceled": {}
+            }));
+        });
+
+        let request = || {
+            BalanceAllowanceRequest::builder()
+                .asset_type(AssetType::Collateral)
+                .build()
+        };
+
+        let first = client.cached_balance(request()).await?;
+        let second = client.cached_balance(request()).await?;
+        assert_eq!(first, second);
+        assert_eq!(second.usdc()?, Usdc::new(dec!(1))?);
+        balance_mock.assert_calls(1);
+
+        client.cancel_order("1").await?;
+        client.cached_balance(request()).await?;
+        balance_mock.assert_calls(2);
+        cancel_mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "ctf", feature = "polling"))]
+    #[tokio::test]
+    async fn watch_deposits_should_succeed() -> anyhow::Result<()> {
+        use alloy::providers::ProviderBuilder;
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::deposits::WatchConfig;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let ctf = polymarket_client_sdk::ctf::Client::new(provider, POLYGON)?;
+
+        // 1 USDC on the first read, 3 USDC afterwards
+        let first_read = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .json_body_includes(r#"{"id": 0}"#);
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x{:064x}", 1_000_000)
+            }));
+        });
+        let later_reads = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": format!("0x{:064x}", 3_000_000)
+            }));
+        });
+        let update_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance/update")
+                .query_param("asset_type", "COLLATERAL");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        let config = WatchConfig::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let deposits = client.watch_deposits(&ctf, config);
+        let mut deposits = std::pin::pin!(deposits);
+        let deposit = deposits.next().await.unwrap()?;
+
+        assert_eq!(deposit.owner, client.address());
+        assert_eq!(deposit.amount, Usdc::new(dec!(2))?);
+        assert_eq!(deposit.balance, Usdc::new(dec!(3))?);
+        first_read.assert();
+        later_reads.assert();
+        update_mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn update_balance_allowance_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2358,6 +5537,166 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn are_orders_scoring_should_chunk_large_sets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let first = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .body_includes("\"0\"");
+            then.status(StatusCode::OK).json_body(json!({ "0": true }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .body_includes("\"149\"");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "149": false }));
+        });
+
+        let order_ids: Vec<String> = (0..150).map(|i| i.to_string()).collect();
+        let order_ids: Vec<&str> = order_ids.iter().map(String::as_str).collect();
+        let response = client.are_orders_scoring(&order_ids).await?;
+
+        let expected = HashMap::from_iter([("0".to_owned(), true), ("149".to_owned(), false)]);
+
+        assert_eq!(response, expected);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn watch_scoring_should_poll_open_orders() -> anyhow::Result<()> {
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::scoring::WatchConfig;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let orders_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "id": "1",
+                        "status": "LIVE",
+                        "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                        "maker_address": "0x2222222222222222222222222222222222222222",
+                        "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                        "asset_id": token_1(),
+                        "side": "buy",
+                        "original_size": "10.0",
+                        "size_matched": "0",
+                        "price": "0.45",
+                        "associate_trades": [],
+                        "outcome": "YES",
+                        "created_at": 1_705_322_096,
+                        "expiration": "0",
+                        "order_type": "GTC"
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+        let scoring_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .json_body(json!(["1"]));
+            then.status(StatusCode::OK).json_body(json!({ "1": true }));
+        });
+
+        let statuses: Vec<_> = client
+            .watch_scoring(WatchConfig::builder().build())
+            .take(1)
+            .collect()
+            .await;
+        let status = statuses.into_iter().next().unwrap()?;
+
+        assert_eq!(status, HashMap::from_iter([("1".to_owned(), true)]));
+        orders_mock.assert();
+        scoring_mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "polling")]
+    #[tokio::test]
+    async fn watch_open_orders_should_emit_diffs() -> anyhow::Result<()> {
+        use std::pin::pin;
+
+        use futures_util::stream::StreamExt as _;
+        use polymarket_client_sdk::clob::open_orders::Event;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let order = |id: &str, status: &str, size_matched: &str| {
+            json!({
+                "id": id,
+                "status": status,
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": size_matched,
+                "price": "0.45",
+                "associate_trades": [],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            })
+        };
+        let page = |orders: Vec<serde_json::Value>| json!({ "data": orders, "limit": 100, "count": 2, "next_cursor": "LTE=" });
+
+        let mut first_mock = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK)
+                .json_body(page(vec![order("1", "LIVE", "0"), order("2", "LIVE", "0")]));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/data/order/2");
+            then.status(StatusCode::OK)
+                .json_body(order("2", "CANCELED", "0"));
+        });
+
+        let mut events = pin!(client.watch_open_orders(Duration::from_millis(10)));
+
+        let first = [events.next().await.unwrap()?, events.next().await.unwrap()?];
+        assert!(matches!(&first[0], Event::New(order) if order.id == "1"));
+        assert!(matches!(&first[1], Event::New(order) if order.id == "2"));
+
+        first_mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK)
+                .json_body(page(vec![order("1", "LIVE", "4")]));
+        });
+
+        match events.next().await.unwrap()? {
+            Event::Updated { previous, current } => {
+                assert_eq!(previous.size_matched, Decimal::ZERO);
+                assert_eq!(current.size_matched, dec!(4));
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        assert!(matches!(
+            events.next().await.unwrap()?,
+            Event::Canceled(order) if order.id == "2"
+        ));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn earnings_for_user_for_day_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2628,6 +5967,41 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn rewards_should_be_cached() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let percentages = server.mock(|when, then| {
+            when.method(GET).path("/rewards/user/percentages");
+            then.status(StatusCode::OK).json_body(json!({ "1": 2 }));
+        });
+        let current = server.mock(|when, then| {
+            when.method(GET).path("/rewards/markets/current");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "next_cursor": "LTE=",
+                "limit": 100,
+                "count": 0
+            }));
+        });
+
+        client.reward_percentages().await?;
+        client.reward_percentages().await?;
+        client.current_rewards(None).await?;
+        client.current_rewards(None).await?;
+        percentages.assert_calls(1);
+        current.assert_calls(1);
+
+        client.invalidate_internal_caches();
+        client.reward_percentages().await?;
+        client.current_rewards(None).await?;
+        percentages.assert_calls(2);
+        current.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn current_rewards_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2829,8 +6203,123 @@ mod authenticated {
             .data(vec![market_reward])
             .build();
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rewards_ledger_should_join_earnings_with_programs() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let condition_id = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let market = |earnings: &str| {
+            json!({
+                "condition_id": condition_id,
+                "question": "Will BTC reach $100k in 2025?",
+                "market_slug": "btc-100k-2025",
+                "event_slug": "btc-2025",
+                "image": "https://example.com/markets/btc.png",
+                "rewards_max_spread": "0.05",
+                "rewards_min_size": "15.0",
+                "market_competitiveness": "0.80",
+                "tokens": [],
+                "rewards_config": [],
+                "maker_address": "0x1111111111111111111111111111111111111111",
+                "earning_percentage": "0.25",
+                "earnings": [
+                    {
+                        "asset_address": "0x0000000000000000000000000000000000000001",
+                        "earnings": earnings,
+                        "asset_rate": "1"
+                    }
+                ]
+            })
+        };
+        let earnings = [
+            ("2024-12-31", "1.5"),
+            ("2025-01-01", "0"),
+            ("2025-01-02", "2"),
+        ]
+        .map(|(date, earnings)| {
+            let body = json!([market(earnings)]);
+            server.mock(move |when, then| {
+                when.method(GET)
+                    .path("/rewards/user/total")
+                    .query_param("date", date);
+                then.status(StatusCode::OK).json_body(body);
+            })
+        });
+        let programs = server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/rewards/markets/{condition_id}"))
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": condition_id,
+                        "question": "Will BTC reach $100k in 2025?",
+                        "market_slug": "btc-100k-2025",
+                        "event_slug": "btc-2025",
+                        "image": "https://example.com/markets/btc.png",
+                        "rewards_max_spread": "0.05",
+                        "rewards_min_size": "15.0",
+                        "market_competitiveness": 0.05,
+                        "tokens": [],
+                        "rewards_config": [
+                            {
+                                "id": "1",
+                                "asset_address": "0x0000000000000000000000000000000000000001",
+                                "start_date": "2024-01-01",
+                                "end_date": "2024-12-31",
+                                "rate_per_day": "1.25",
+                                "total_rewards": "400.0",
+                                "total_days": 10
+                            }
+                        ]
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let day = |date: &str| date.parse::<NaiveDate>();
+        let ledger = client
+            .rewards_ledger(day("2024-12-31")?, day("2025-01-02")?)
+            .await?;
+
+        let records: Vec<_> = ledger
+            .records
+            .iter()
+            .map(|record| (record.date, record.earnings, record.rate_per_day))
+            .collect();
+        assert_eq!(
+            records,
+            [
+                (day("2024-12-31")?, dec!(1.5), Some(dec!(1.25))),
+                (day("2025-01-02")?, dec!(2), None),
+            ]
+        );
+        assert_eq!(ledger.records[0].market_slug, "btc-100k-2025");
+        assert!(ledger.to_csv().lines().nth(1).is_some_and(|line| {
+            line.starts_with(&format!(
+                "2024-12-31,{condition_id},btc-100k-2025,btc-2025,"
+            ))
+        }));
+        for mock in earnings {
+            mock.assert();
+        }
+        programs.assert_calls(1);
+
+        let error = client
+            .rewards_ledger(day("2025-01-02")?, day("2025-01-01")?)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("before start date"));
 
         Ok(())
     }
@@ -2919,6 +6408,159 @@ mod authenticated {
 
         Ok(())
     }
+
+    #[cfg(feature = "heartbeats")]
+    #[tokio::test]
+    async fn dropping_a_clone_should_keep_heartbeats_running() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/v1/heartbeats");
+            then.status(StatusCode::OK).json_body(json!({
+                "heartbeat_id": Uuid::new_v4(),
+                "error": null
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .heartbeat_interval(Duration::from_millis(50))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        let client_clone = client.clone();
+        drop(client_clone);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(client.heartbeats_active());
+        assert!(mock.calls() >= 2);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "heartbeats")]
+    #[tokio::test]
+    async fn heartbeats_should_align_to_phase() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/v1/heartbeats");
+            then.status(StatusCode::OK).json_body(json!({
+                "heartbeat_id": Uuid::new_v4(),
+                "error": null
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .heartbeat_interval(Duration::from_millis(100))
+            .heartbeat_phase(Duration::from_millis(50))
+            .heartbeat_jitter(Duration::from_millis(10))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        assert_eq!(client.last_heartbeat_sent_at(), None);
+
+        tokio::time::sleep(Duration::from_millis(450)).await;
+
+        assert!(mock.calls() >= 3);
+        let sent_at = client
+            .last_heartbeat_sent_at()
+            .expect("a heartbeat was sent");
+        // Sent 50ms past a multiple of 100ms, plus up to 10ms of jitter and some scheduling delay
+        let phase = sent_at.timestamp_subsec_millis() % 100;
+        assert!(
+            (50..90).contains(&phase),
+            "sent {phase}ms into the interval"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ctf")]
+    #[tokio::test]
+    async fn authenticate_should_fail_when_wallet_is_not_deployed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::error::Validation;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("eth_getCode");
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": "0x" }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let funder = address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316");
+        let err = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .funder(funder)
+            .signature_type(SignatureType::Proxy)
+            .verify_wallet_deployed(server.base_url().parse()?)
+            .authenticate()
+            .await
+            .unwrap_err();
+
+        let reason = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert!(reason.starts_with(&format!(
+            "The Proxy funder wallet {funder} is not deployed on chain {POLYGON}"
+        )));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ctf")]
+    #[tokio::test]
+    async fn authenticate_should_succeed_when_wallet_is_deployed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("eth_getCode");
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": "0x6080" }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .signature_type(SignatureType::GnosisSafe)
+            .verify_wallet_deployed(server.base_url().parse()?)
+            .authenticate()
+            .await?;
+
+        assert_eq!(client.address(), signer.address());
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod builder_authenticated {
@@ -2931,7 +6573,7 @@ mod builder_authenticated {
         BuilderApiKeyResponse, BuilderTradeResponse, Page,
     };
     use polymarket_client_sdk::clob::types::{Side, TradeStatusType};
-    use polymarket_client_sdk::types::{address, b256};
+    use polymarket_client_sdk::types::{Usdc, address, b256};
 
     use super::*;
     use crate::common::{
@@ -3238,6 +6880,12 @@ mod builder_authenticated {
             .build();
 
         assert_eq!(response, expected);
+
+        let fee = response.data[0].builder_fee()?;
+        assert_eq!(fee.amount, Usdc::new(dec!(1))?);
+        assert_eq!(fee.notional, Usdc::new(dec!(100))?);
+        assert_eq!(fee.rate_bps, Some(dec!(100)));
+
         mock.assert();
         mock2.assert_calls(3);
         mock3.assert();
@@ -3245,4 +6893,330 @@ mod builder_authenticated {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn verify_builder_attribution_should_require_active_key() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::error::Kind;
+
+        let server = MockServer::start();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY,
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let builder_config = BuilderConfig::local(Credentials::new(
+            BUILDER_API_KEY,
+            SECRET.to_owned(),
+            BUILDER_PASSPHRASE.to_owned(),
+        ));
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?
+            .promote_to_builder(builder_config)
+            .await?;
+        assert_eq!(client.builder_config().api_key(), Some(BUILDER_API_KEY));
+
+        let mut mock2 = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/builder-api-key")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .header(POLY_BUILDER_PASSPHRASE, BUILDER_PASSPHRASE)
+                .header_exists(POLY_BUILDER_SIGNATURE)
+                .header_exists(POLY_BUILDER_TIMESTAMP);
+            then.status(StatusCode::OK).json_body(json!([
+                { "key": Uuid::nil(), "revokedAt": "2024-01-15T12:30:00Z" },
+                { "key": BUILDER_API_KEY, "createdAt": "2024-01-15T12:30:00Z" }
+            ]));
+        });
+
+        assert_eq!(client.verify_builder_attribution().await?, BUILDER_API_KEY);
+        mock2.assert();
+        mock2.delete();
+
+        let mock3 = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/builder-api-key")
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY);
+            then.status(StatusCode::OK).json_body(json!([
+                { "key": BUILDER_API_KEY, "revokedAt": "2024-01-15T12:30:00Z" }
+            ]));
+        });
+
+        let err = client.verify_builder_attribution().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.to_string().contains("revoked"));
+        mock.assert();
+        mock3.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builder_revenue_should_total_every_page() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY,
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let builder_config = BuilderConfig::local(Credentials::new(
+            BUILDER_API_KEY,
+            SECRET.to_owned(),
+            BUILDER_PASSPHRASE.to_owned(),
+        ));
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?
+            .promote_to_builder(builder_config)
+            .await?;
+
+        let trade = |id: &str, market: &str, time: &str, size_usdc: &str, fee_usdc: &str| {
+            json!({
+                "id": id,
+                "tradeType": "limit",
+                "takerOrderHash": "0x0000000000000000000000000000000000000000000000000074616b65726f72",
+                "builder": "0x00000000000000000000000000006275696c6431",
+                "market": market,
+                "assetId": token_1(),
+                "side": "buy",
+                "size": "10",
+                "sizeUsdc": size_usdc,
+                "price": "0.5",
+                "status": "CONFIRMED",
+                "outcome": "YES",
+                "outcomeIndex": 0,
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker": "0x2222222222222222222222222222222222222222",
+                "transactionHash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+                "matchTime": time,
+                "bucketIndex": 0,
+                "fee": fee_usdc,
+                "feeUsdc": fee_usdc
+            })
+        };
+        let market = "0x000000000000000000000000000000000000000000000000000000006d61726b";
+
+        let first = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/trades")
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("1", market, "1735725600", "100", "1")],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "next"
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/trades")
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .query_param("next_cursor", "next");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("2", market, "1735812000", "50", "0.5")],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let report = client.builder_revenue(&TradesRequest::default()).await?;
+
+        assert_eq!(report.total.trades, 2);
+        assert_eq!(report.total.volume, dec!(150));
+        assert_eq!(report.total.fees, dec!(1.5));
+        assert_eq!(report.total.fee_rate_bps(), Some(dec!(100)));
+        assert_eq!(report.by_day.len(), 2);
+        assert_eq!(report.by_market.len(), 1);
+        assert_eq!(report.failed_trades, 0);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "heartbeats")]
+    #[tokio::test]
+    async fn promote_to_builder_should_continue_heartbeat_session() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use polymarket_client_sdk::auth::Credentials;
+
+        let server = MockServer::start();
+        let id = Uuid::new_v4();
+
+        let first = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v1/heartbeats")
+                .json_body(json!({ "heartbeat_id": null }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "heartbeat_id": id, "error": null }));
+        });
+        let continued = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v1/heartbeats")
+                .header_exists(POLY_BUILDER_SIGNATURE)
+                .json_body(json!({ "heartbeat_id": id }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "heartbeat_id": id, "error": null }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder()
+            .heartbeat_interval(Duration::from_millis(100))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        // Wait for the first heartbeat to open a session
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        first.assert_calls(1);
+
+        let builder_config = BuilderConfig::local(Credentials::new(
+            BUILDER_API_KEY,
+            SECRET.to_owned(),
+            BUILDER_PASSPHRASE.to_owned(),
+        ));
+        let client = client.promote_to_builder(builder_config).await?;
+
+        // The promoted client continues the session right away instead of opening a new one
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(client.heartbeats_active());
+        continued.assert_calls(1);
+        first.assert_calls(1);
+
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "data", feature = "gamma"))]
+mod polymarket {
+    use std::num::NonZeroU32;
+    use std::str::FromStr as _;
+    use std::time::Duration;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::auth::Credentials;
+    use polymarket_client_sdk::transport::{self, Quota, RateLimiters};
+    use polymarket_client_sdk::{POLYGON, Polymarket};
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET};
+
+    #[tokio::test]
+    async fn authenticate_should_upgrade_clob_and_keep_other_clients() -> anyhow::Result<()> {
+        let clob = MockServer::start();
+        let data = MockServer::start();
+        let gamma = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let polymarket = Polymarket::builder()
+            .clob_host(&clob.base_url())
+            .data_host(&data.base_url())
+            .gamma_host(&gamma.base_url())
+            .build()?
+            .authentication()
+            .signer(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        let ok = clob.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).json_body(json!("OK"));
+        });
+        let health = data.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "data": "OK" }));
+        });
+
+        assert_eq!(polymarket.clob().address(), signer.address());
+        assert_eq!(polymarket.clob().ok().await?, "OK");
+        assert_eq!(polymarket.data().health().await?.data, "OK");
+        assert_eq!(
+            polymarket.gamma().host().as_str(),
+            format!("{}/", gamma.base_url())
+        );
+        ok.assert();
+        health.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn path_limits_should_not_be_shared_across_hosts() -> anyhow::Result<()> {
+        let clob = MockServer::start();
+        let data = MockServer::start();
+        let rate_limiters = RateLimiters::default()
+            .with_path("/", Quota::per_hour(NonZeroU32::new(1).expect("non-zero")));
+
+        let polymarket = Polymarket::builder()
+            .clob_host(&clob.base_url())
+            .data_host(&data.base_url())
+            .transport(
+                transport::Config::builder()
+                    .rate_limiters(rate_limiters)
+                    .build(),
+            )
+            .build()?;
+
+        clob.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).json_body(json!("OK"));
+        });
+        data.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "data": "OK" }));
+        });
+
+        // Both hosts serve `/`, but each has its own budget of one request per hour for it
+        let requests = async {
+            polymarket.clob().ok().await?;
+            polymarket.data().health().await?;
+            anyhow::Ok(())
+        };
+        tokio::time::timeout(Duration::from_secs(5), requests).await??;
+
+        Ok(())
+    }
 }
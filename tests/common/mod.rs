@@ -21,7 +21,7 @@ use polymarket_client_sdk::auth::Normal;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::clob::types::{SignatureType, TickSize};
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use reqwest::StatusCode;
 use serde_json::json;
 use uuid::Uuid;
@@ -56,15 +56,19 @@ pub const USDC_DECIMALS: u32 = 6;
 pub type TestClient = Client<Authenticated<Normal>>;
 
 #[must_use]
-pub fn token_1() -> U256 {
-    U256::from_str("15871154585880608648532107628464183779895785213830018178010423617714102767076")
-        .unwrap()
+pub fn token_1() -> TokenId {
+    TokenId::from_str(
+        "15871154585880608648532107628464183779895785213830018178010423617714102767076",
+    )
+    .unwrap()
 }
 
 #[must_use]
-pub fn token_2() -> U256 {
-    U256::from_str("99920934651435586775038877380223724073374199451810545861447160390199026872860")
-        .unwrap()
+pub fn token_2() -> TokenId {
+    TokenId::from_str(
+        "99920934651435586775038877380223724073374199451810545861447160390199026872860",
+    )
+    .unwrap()
 }
 
 pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestClient> {
@@ -101,7 +105,7 @@ pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestCli
     Ok(client)
 }
 
-pub fn ensure_requirements(server: &MockServer, token_id: U256, tick_size: TickSize) {
+pub fn ensure_requirements(server: &MockServer, token_id: TokenId, tick_size: TickSize) {
     server.mock(|when, then| {
         when.method(httpmock::Method::GET).path("/neg-risk");
         then.status(StatusCode::OK)
@@ -11,8 +11,9 @@ use serde_json::json;
 
 mod contract_calls {
     use alloy::primitives::b256;
+    use polymarket_client_sdk::ctf::gas;
     use polymarket_client_sdk::ctf::types::{
-        CollectionIdRequest, ConditionIdRequest, PositionIdRequest,
+        CollateralBalanceRequest, CollectionIdRequest, ConditionIdRequest, PositionIdRequest,
     };
 
     use super::*;
@@ -114,6 +115,68 @@ mod contract_calls {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn get_collateral_balance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                // balanceOf(address) selector, called on the collateral token
+                .body_includes("0x70a08231")
+                .body_includes("0x2791bca1f2de4661ed88a30c99a7a9449aa84174");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x00000000000000000000000000000000000000000000000000000000000f4240"
+            }));
+        });
+
+        let request = CollateralBalanceRequest::builder()
+            .owner(address!("0x0000000000000000000000000000000000000001"))
+            .build();
+
+        let response = client.collateral_balance(&request).await?;
+
+        assert_eq!(response.balance, U256::from(1_000_000));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_gas_fees() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("eth_feeHistory");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": {
+                    "oldestBlock": "0x1",
+                    // 80 and 100 gwei, the latter being the next block's base fee
+                    "baseFeePerGas": ["0x12a05f2000", "0x174876e800"],
+                    "gasUsedRatio": [0.9],
+                    // 40 gwei
+                    "reward": [["0x9502f9000"]]
+                }
+            }));
+        });
+
+        let fees = client.gas_fees(&gas::Config::default()).await?;
+
+        assert_eq!(fees.max_priority_fee_per_gas, 40_000_000_000);
+        assert_eq!(fees.max_fee_per_gas, 240_000_000_000);
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod client_creation {
@@ -268,6 +331,324 @@ mod binary_market_convenience_methods {
     }
 }
 
+mod transactions {
+    use std::str::FromStr as _;
+    use std::time::Duration;
+
+    use alloy::signers::local::PrivateKeySigner;
+    use polymarket_client_sdk::ctf::transactions;
+    use polymarket_client_sdk::ctf::types::RedeemPositionsRequest;
+
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0xe111111111111111111111111111111111111111111111111111111111111111";
+    const TRANSACTION_HASH: &str =
+        "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn rpc<'server>(
+        server: &'server MockServer,
+        method: &str,
+        result: &serde_json::Value,
+    ) -> httpmock::Mock<'server> {
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_includes(method);
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": result }));
+        })
+    }
+
+    #[tokio::test]
+    async fn redeem_positions_should_confirm_via_manager() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = PrivateKeySigner::from_str(PRIVATE_KEY)?;
+        let sender = signer.address();
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect(&server.base_url())
+            .await?;
+        let config = transactions::Config::builder()
+            .sender(sender)
+            .receipt_timeout(Duration::from_secs(5))
+            .build();
+        let client = Client::new(provider, POLYGON)?.with_transaction_config(config);
+
+        let nonce = rpc(&server, "eth_getTransactionCount", &json!("0x5"));
+        rpc(&server, "eth_chainId", &json!("0x89"));
+        rpc(&server, "eth_estimateGas", &json!("0x30d40"));
+        rpc(&server, "eth_blockNumber", &json!("0x10"));
+        rpc(
+            &server,
+            "eth_feeHistory",
+            &json!({
+                "oldestBlock": "0x1",
+                "baseFeePerGas": ["0x174876e800", "0x174876e800"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x6fc23ac00"]]
+            }),
+        );
+        let send = rpc(&server, "eth_sendRawTransaction", &json!(TRANSACTION_HASH));
+        rpc(
+            &server,
+            "eth_getTransactionReceipt",
+            &json!({
+                "transactionHash": TRANSACTION_HASH,
+                "transactionIndex": "0x0",
+                "blockHash": "0x2222222222222222222222222222222222222222222222222222222222222222",
+                "blockNumber": "0x10",
+                "from": sender,
+                "to": "0x4d97dcd97ec945f40cf65f87097ace5ea0476045",
+                "cumulativeGasUsed": "0x30d40",
+                "gasUsed": "0x30d40",
+                "effectiveGasPrice": "0x174876e800",
+                "contractAddress": null,
+                "logs": [],
+                "logsBloom": format!("0x{}", "0".repeat(512)),
+                "type": "0x2",
+                "status": "0x1"
+            }),
+        );
+
+        let request = RedeemPositionsRequest::for_binary_market(
+            address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
+            B256::ZERO,
+        );
+        let response = client.redeem_positions(&request).await?;
+
+        assert_eq!(response.transaction_hash, B256::from_str(TRANSACTION_HASH)?);
+        assert_eq!(response.block_number, 16);
+        nonce.assert();
+        send.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn redeem_positions_via_safe_should_execute_signed_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = PrivateKeySigner::from_str(PRIVATE_KEY)?;
+        let sender = signer.address();
+        let safe = address!("0x3333333333333333333333333333333333333333");
+        let provider = ProviderBuilder::new()
+            .wallet(signer.clone())
+            .connect(&server.base_url())
+            .await?;
+        let config = transactions::Config::builder()
+            .sender(sender)
+            .receipt_timeout(Duration::from_secs(5))
+            .build();
+        let client = Client::new(provider, POLYGON)?.with_transaction_config(config);
+
+        let safe_nonce = rpc(&server, "eth_call", &json!(format!("0x{:064x}", 7)));
+        rpc(&server, "eth_getTransactionCount", &json!("0x5"));
+        rpc(&server, "eth_chainId", &json!("0x89"));
+        rpc(&server, "eth_estimateGas", &json!("0x30d40"));
+        rpc(&server, "eth_blockNumber", &json!("0x10"));
+        rpc(
+            &server,
+            "eth_feeHistory",
+            &json!({
+                "oldestBlock": "0x1",
+                "baseFeePerGas": ["0x174876e800", "0x174876e800"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x6fc23ac00"]]
+            }),
+        );
+        // `execTransaction` selector
+        let send = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("eth_sendRawTransaction")
+                .body_includes("6a761202");
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": TRANSACTION_HASH }));
+        });
+        rpc(
+            &server,
+            "eth_getTransactionReceipt",
+            &json!({
+                "transactionHash": TRANSACTION_HASH,
+                "transactionIndex": "0x0",
+                "blockHash": "0x2222222222222222222222222222222222222222222222222222222222222222",
+                "blockNumber": "0x10",
+                "from": sender,
+                "to": safe,
+                "cumulativeGasUsed": "0x30d40",
+                "gasUsed": "0x30d40",
+                "effectiveGasPrice": "0x174876e800",
+                "contractAddress": null,
+                "logs": [],
+                "logsBloom": format!("0x{}", "0".repeat(512)),
+                "type": "0x2",
+                "status": "0x1"
+            }),
+        );
+
+        let request = RedeemPositionsRequest::for_binary_market(
+            address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
+            B256::ZERO,
+        );
+        let response = client
+            .redeem_positions_via_safe(&signer, safe, &request)
+            .await?;
+
+        assert_eq!(response.transaction_hash, B256::from_str(TRANSACTION_HASH)?);
+        assert_eq!(response.block_number, 16);
+        safe_nonce.assert();
+        send.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn approve_via_proxy_should_send_to_factory() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = PrivateKeySigner::from_str(PRIVATE_KEY)?;
+        let sender = signer.address();
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect(&server.base_url())
+            .await?;
+        let config = transactions::Config::builder()
+            .sender(sender)
+            .receipt_timeout(Duration::from_secs(5))
+            .build();
+        let client = Client::new(provider, POLYGON)?.with_transaction_config(config);
+
+        rpc(&server, "eth_getTransactionCount", &json!("0x5"));
+        rpc(&server, "eth_chainId", &json!("0x89"));
+        rpc(&server, "eth_estimateGas", &json!("0x30d40"));
+        rpc(&server, "eth_blockNumber", &json!("0x10"));
+        rpc(
+            &server,
+            "eth_feeHistory",
+            &json!({
+                "oldestBlock": "0x1",
+                "baseFeePerGas": ["0x174876e800", "0x174876e800"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x6fc23ac00"]]
+            }),
+        );
+        // `proxy` selector
+        let send = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("eth_sendRawTransaction")
+                .body_includes("34ee9791");
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": TRANSACTION_HASH }));
+        });
+        rpc(
+            &server,
+            "eth_getTransactionReceipt",
+            &json!({
+                "transactionHash": TRANSACTION_HASH,
+                "transactionIndex": "0x0",
+                "blockHash": "0x2222222222222222222222222222222222222222222222222222222222222222",
+                "blockNumber": "0x10",
+                "from": sender,
+                "to": "0xab45c5a4b0c941a2f231c04c3f49182e1a254052",
+                "cumulativeGasUsed": "0x30d40",
+                "gasUsed": "0x30d40",
+                "effectiveGasPrice": "0x174876e800",
+                "contractAddress": null,
+                "logs": [],
+                "logsBloom": format!("0x{}", "0".repeat(512)),
+                "type": "0x2",
+                "status": "0x1"
+            }),
+        );
+
+        let response = client
+            .approve_via_proxy(address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"))
+            .await?;
+
+        assert_eq!(response.transaction_hash, B256::from_str(TRANSACTION_HASH)?);
+        assert_eq!(response.block_number, 16);
+        send.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_replacements_should_wait_for_earlier_attempts() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = PrivateKeySigner::from_str(PRIVATE_KEY)?;
+        let sender = signer.address();
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect(&server.base_url())
+            .await?;
+        let receipt_timeout = Duration::from_millis(300);
+        let config = transactions::Config::builder()
+            .sender(sender)
+            .receipt_timeout(receipt_timeout)
+            .max_replacements(2)
+            .build();
+        let client = Client::new(provider, POLYGON)?.with_transaction_config(config);
+
+        rpc(&server, "eth_getTransactionCount", &json!("0x5"));
+        rpc(&server, "eth_chainId", &json!("0x89"));
+        rpc(&server, "eth_estimateGas", &json!("0x30d40"));
+        rpc(&server, "eth_blockNumber", &json!("0x10"));
+        rpc(
+            &server,
+            "eth_feeHistory",
+            &json!({
+                "oldestBlock": "0x1",
+                "baseFeePerGas": ["0x174876e800", "0x174876e800"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x6fc23ac00"]]
+            }),
+        );
+        rpc(&server, "eth_getBlockByNumber", &json!(null));
+        rpc(&server, "eth_getTransactionReceipt", &json!(null));
+        let mut send = rpc(&server, "eth_sendRawTransaction", &json!(TRANSACTION_HASH));
+
+        let started = tokio::time::Instant::now();
+        let request = RedeemPositionsRequest::for_binary_market(
+            address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
+            B256::ZERO,
+        );
+        let redeem = tokio::spawn(async move { client.redeem_positions(&request).await });
+
+        // Every replacement of the first attempt is rejected
+        while send.calls() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        send.delete();
+        let rejected = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("eth_sendRawTransaction");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "error": { "code": -32000, "message": "replacement transaction underpriced" }
+            }));
+        });
+
+        redeem.await?.unwrap_err();
+
+        assert!(started.elapsed() >= receipt_timeout * 3);
+        rejected.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_via_proxy_without_factory_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, polymarket_client_sdk::AMOY)?;
+
+        let err = client.execute_via_proxy(&[]).await.unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("Proxy wallet factory not configured")
+        );
+
+        Ok(())
+    }
+}
+
 mod neg_risk {
     use polymarket_client_sdk::ctf::types::RedeemNegRiskRequest;
 
@@ -298,3 +679,168 @@ mod neg_risk {
         Ok(())
     }
 }
+
+#[cfg(feature = "polling")]
+mod balances {
+    use std::time::Duration;
+
+    use futures_util::StreamExt as _;
+    use polymarket_client_sdk::onchain::balances::Asset;
+    use polymarket_client_sdk::onchain::watch_balances;
+    use polymarket_client_sdk::types::TokenId;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn watch_balances_should_yield_changes() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let owner = address!("0x1111111111111111111111111111111111111111");
+        let token = TokenId::new(U256::from(42));
+
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("eth_blockNumber");
+            then.json_body(json!({ "jsonrpc": "2.0", "id": 0, "result": "0x10" }));
+        });
+        // `balanceOf` selector, 2.5 USDC
+        let collateral = server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("0x70a08231");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": format!("0x{:064x}", 2_500_000)
+            }));
+        });
+        // `balanceOfBatch` selector, 10 shares
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("0x4e1273f4");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "result": format!("0x{:064x}{:064x}{:064x}", 0x20, 1, 10_000_000)
+            }));
+        });
+
+        let changes = watch_balances(
+            &provider,
+            POLYGON,
+            owner,
+            vec![token],
+            Duration::from_millis(10),
+        );
+        let mut changes = std::pin::pin!(changes);
+
+        let first = changes.next().await.unwrap()?;
+        assert_eq!(first.owner, owner);
+        assert_eq!(first.asset, Asset::Collateral);
+        assert_eq!(first.previous, None);
+        assert_eq!(first.balance, dec!(2.5));
+
+        let second = changes.next().await.unwrap()?;
+        assert_eq!(second.asset, Asset::Outcome(token));
+        assert_eq!(second.balance, dec!(10));
+
+        // Unchanged balances are not yielded again
+        let next = tokio::time::timeout(Duration::from_millis(100), changes.next()).await;
+        assert!(next.is_err(), "unexpected change {next:?}");
+        assert!(collateral.calls() > 1);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "polling")]
+mod allowances {
+    use std::time::Duration;
+
+    use futures_util::StreamExt as _;
+    use polymarket_client_sdk::ctf::allowances::{AllowanceEvent, Config, Token};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn watch_allowances_should_yield_insufficient_once() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+        let owner = address!("0x1111111111111111111111111111111111111111");
+        let spender = address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+
+        // `allowance` selector, 5 USDC
+        let allowance = server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("0xdd62ed3e");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": format!("0x{:064x}", 5_000_000)
+            }));
+        });
+        // `isApprovedForAll` selector, approved
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_includes("0xe985e9c5");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": format!("0x{:064x}", 1)
+            }));
+        });
+
+        let config = Config::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let events = client.watch_allowances(owner, vec![spender], config);
+        let mut events = std::pin::pin!(events);
+
+        let event = events.next().await.unwrap()?;
+        assert_eq!(
+            event,
+            AllowanceEvent::Insufficient {
+                owner,
+                spender,
+                token: Token::Collateral,
+                allowance: U256::from(5_000_000),
+            }
+        );
+
+        // Still insufficient, but not yielded again
+        let next = tokio::time::timeout(Duration::from_millis(100), events.next()).await;
+        assert!(next.is_err(), "unexpected event {next:?}");
+        assert!(allowance.calls() > 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn auto_approve_allowances_should_reject_other_owners() -> anyhow::Result<()> {
+        use std::str::FromStr as _;
+
+        use alloy::signers::local::PrivateKeySigner;
+        use polymarket_client_sdk::error::Kind;
+
+        let server = MockServer::start();
+        let signer = PrivateKeySigner::from_str(
+            "0xe111111111111111111111111111111111111111111111111111111111111111",
+        )?;
+        let owner = signer.address();
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect(&server.base_url())
+            .await?;
+        let client = Client::new(provider, POLYGON)?;
+        let spenders = vec![address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E")];
+
+        // A proxy wallet or Safe funder is not the sender of the approvals
+        let funder = address!("0x1111111111111111111111111111111111111111");
+        let Err(err) =
+            client.auto_approve_allowances(funder, spenders.clone(), Config::builder().build())
+        else {
+            panic!("approvals for {funder} should be rejected");
+        };
+        assert_eq!(err.kind(), Kind::Validation);
+
+        _ = client.auto_approve_allowances(owner, spenders, Config::builder().build())?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "data")]
 
-use polymarket_client_sdk::types::{Address, B256, U256, address, b256};
+use polymarket_client_sdk::types::{Address, B256, TokenId, address, b256};
 
 const TEST_USER: Address = address!("1234567890abcdef1234567890abcdef12345678");
 const TEST_CONDITION_ID: B256 =
@@ -39,6 +39,41 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn builder_should_send_custom_headers_and_user_agent_suffix() -> anyhow::Result<()> {
+        use polymarket_client_sdk::transport::Config;
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let server = MockServer::start();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Deployment", HeaderValue::from_static("east-1"));
+        let client = Client::builder()
+            .host(&server.base_url())
+            .transport(
+                Config::builder()
+                    .headers(headers)
+                    .user_agent_suffix("my-bot/1.2")
+                    .build(),
+            )
+            .build()?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/")
+                .header("user-agent", "rs_clob_client my-bot/1.2")
+                .header("x-deployment", "east-1");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "OK"
+            }));
+        });
+
+        client.health().await?;
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod positions {
@@ -140,6 +175,7 @@ mod positions {
 mod trades {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::Side, types::request::TradesRequest};
+    use polymarket_client_sdk::types::Timestamp;
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -187,7 +223,7 @@ mod trades {
         assert_eq!(trade.side, Side::Buy);
         assert_eq!(trade.size, dec!(50.0));
         assert_eq!(trade.price, dec!(0.55));
-        assert_eq!(trade.timestamp, 1_703_980_800);
+        assert_eq!(trade.timestamp, Timestamp::from_secs(1_703_980_800));
         mock.assert();
 
         Ok(())
@@ -269,7 +305,7 @@ mod holders {
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::{U256, address, test_condition_id, test_user};
+    use super::{TokenId, address, test_condition_id, test_user};
 
     #[tokio::test]
     async fn holders_should_succeed() -> anyhow::Result<()> {
@@ -320,7 +356,9 @@ mod holders {
         assert_eq!(response.len(), 1);
         assert_eq!(
             response[0].token,
-            U256::from_str("0x1111111111111111111111111111111111111111111111111111111111111111")?
+            TokenId::from_str(
+                "0x1111111111111111111111111111111111111111111111111111111111111111"
+            )?
         );
         let holders = &response[0].holders;
         assert_eq!(holders.len(), 2);
@@ -376,6 +414,7 @@ mod value {
 mod closed_positions {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::ClosedPositionsRequest};
+    use polymarket_client_sdk::types::Timestamp;
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -423,7 +462,7 @@ mod closed_positions {
         assert_eq!(response[0].condition_id, test_condition_id());
         assert_eq!(response[0].realized_pnl, dec!(55.0));
         assert_eq!(response[0].cur_price, dec!(1.0));
-        assert_eq!(response[0].timestamp, 1_703_980_800);
+        assert_eq!(response[0].timestamp, Timestamp::from_secs(1_703_980_800));
         mock.assert();
 
         Ok(())
@@ -1118,6 +1157,7 @@ mod types {
         TradeFilter::cash(dec!(100.0)).unwrap();
         TradeFilter::tokens(dec!(0.0)).unwrap();
         TradeFilter::cash(dec!(-1.0)).unwrap_err();
+        TradeFilter::tokens(dec!(0.0000001)).unwrap_err();
     }
 
     #[test]
@@ -1131,6 +1171,29 @@ mod types {
         assert!(qs.contains("filterAmount=100"));
     }
 
+    #[test]
+    fn decimal_params_should_be_canonical_and_bounded() {
+        let req = PositionsRequest::builder()
+            .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+            .size_threshold(dec!(1.500))
+            .unwrap()
+            .build();
+        assert!(req.query_params(None).contains("sizeThreshold=1.5"));
+
+        let err = PositionsRequest::builder()
+            .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+            .size_threshold(dec!(0.0000001));
+        let Err(err) = err else {
+            panic!("Expected an error")
+        };
+        assert!(err.to_string().contains("size_threshold"));
+
+        let req = TradesRequest::builder()
+            .trade_filter(TradeFilter::tokens(dec!(50.10)).unwrap())
+            .build();
+        assert!(req.query_params(None).ends_with("filterAmount=50.1"));
+    }
+
     #[test]
     fn activity_types_query_string() {
         let req = ActivityRequest::builder()
@@ -1262,6 +1325,9 @@ mod error_display {
     fn trade_filter_error_display() {
         let err = TradeFilter::cash(dec!(-1.0)).unwrap_err();
         assert!(err.to_string().contains("-1"));
+
+        let err = TradeFilter::cash(dec!(0.0000001)).unwrap_err();
+        assert!(err.to_string().contains("at most 6 decimal places"));
     }
 }
 
@@ -1293,13 +1359,14 @@ mod request_query_string_extended {
         let req = PositionsRequest::builder()
             .user(test_addr())
             .size_threshold(dec!(100))
+            .unwrap()
             .mergeable(true)
             .sort_by(PositionSortBy::Current)
             .title("test")
             .build();
 
         let qs = req.query_params(None);
-        assert!(qs.contains("sizeThreshold="));
+        assert!(qs.contains("sizeThreshold=100&"));
         assert!(qs.contains("mergeable="));
         assert!(qs.contains("sortBy="));
         assert!(qs.contains("title="));
@@ -360,6 +360,7 @@ mod events {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{
         Client,
+        types::GameStatus,
         types::request::{EventByIdRequest, EventBySlugRequest, EventsRequest},
     };
     use reqwest::StatusCode;
@@ -442,6 +443,55 @@ mod events {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn game_state_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/456");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "456",
+                "slug": "nba-lal-bos",
+                "gameId": 90_210,
+                "gameStatus": "InProgress",
+                "score": "88-91",
+                "period": "Q4",
+                "elapsed": "05:12",
+                "live": true,
+                "ended": false,
+                "homeTeamName": "Celtics",
+                "awayTeamName": "Lakers"
+            }));
+        });
+        let plain = server.mock(|when, then| {
+            when.method(GET).path("/events/789");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "789",
+                "title": "Not a game"
+            }));
+        });
+
+        let request = EventByIdRequest::builder().id("456").build();
+        let state = client.game_state(&request).await?.unwrap();
+
+        assert_eq!(state.event_id, "456");
+        assert_eq!(state.game_id, Some(90_210));
+        assert_eq!(state.status, Some(GameStatus::InProgress));
+        assert_eq!(state.score.as_deref(), Some("88-91"));
+        assert_eq!(state.period.as_deref(), Some("Q4"));
+        assert!(state.live);
+        assert!(!state.ended);
+        assert_eq!(state.home_team.as_deref(), Some("Celtics"));
+        mock.assert();
+
+        let request = EventByIdRequest::builder().id("789").build();
+        assert_eq!(client.game_state(&request).await?, None);
+        plain.assert();
+
+        Ok(())
+    }
 }
 
 mod markets {
@@ -1195,6 +1245,26 @@ mod query_string {
         assert!(qs.contains("end_date_max="));
     }
 
+    #[test]
+    fn decimal_params_should_be_canonical() {
+        let qs = EventsRequest::builder()
+            .liquidity_min(dec!(1000.50))
+            .volume_max(dec!(0.000001))
+            .build()
+            .query_params(None);
+        assert!(qs.contains("liquidity_min=1000.5&"));
+        assert!(qs.contains("volume_max=0.000001"));
+
+        let qs = MarketsRequest::builder()
+            .volume_num_min(dec!(1e3))
+            .rewards_min_size(dec!(20.00))
+            .build()
+            .query_params(None);
+        assert!(qs.contains("volume_num_min=1000&"));
+        assert!(qs.contains("rewards_min_size=20"));
+        assert!(!qs.contains("rewards_min_size=20."));
+    }
+
     #[test]
     fn events_request_empty_arrays_not_included() {
         let request = EventsRequest::builder()
@@ -12,8 +12,10 @@ use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use httpmock::MockServer;
 use polymarket_client_sdk::clob::types::response::OrderSummary;
-use polymarket_client_sdk::clob::types::{Amount, OrderType, Side, SignatureType, TickSize};
-use polymarket_client_sdk::types::{Address, Decimal, address};
+use polymarket_client_sdk::clob::types::{
+    Amount, OrderType, RoundingMode, Side, SignatureType, TickSize,
+};
+use polymarket_client_sdk::types::{Address, Decimal, TokenId, address};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 
@@ -559,14 +561,95 @@ mod lifecycle {
             .unwrap_err();
         let msg = &err.downcast_ref::<Validation>().unwrap().reason;
 
-        assert_eq!(msg, "Only Polygon and AMOY are supported, got 1");
+        assert_eq!(
+            msg,
+            "Only Polygon, AMOY and chains registered via `register_contract_config` are \
+             supported, got 1"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signer_with_mismatched_host_should_fail() -> anyhow::Result<()> {
+        use polymarket_client_sdk::AMOY;
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(AMOY));
+
+        let err = Client::new("https://clob.polymarket.com", Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Signer chain id 80002 does not match https://clob.polymarket.com/, which expects \
+             chain id 137"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signer_with_registered_chain_id_should_succeed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::{ContractConfig, register_contract_config};
+
+        const ANVIL: u64 = 31_337;
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(ANVIL));
+
+        register_contract_config(
+            ANVIL,
+            false,
+            ContractConfig::builder()
+                .exchange(address!("0x5FbDB2315678afecb367f032d93F642f64180aa3"))
+                .collateral(address!("0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512"))
+                .conditional_tokens(address!("0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"))
+                .build(),
+        );
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .size(Decimal::ONE_HUNDRED)
+            .price(dec!(0.5))
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, signable_order).await?;
+
+        assert_eq!(signed_order.order.maker, signer.address());
 
         Ok(())
     }
 }
 
 mod limit {
-    use polymarket_client_sdk::error::Validation;
+    use std::time::{Duration, Instant};
+
+    use polymarket_client_sdk::clob::freshness::Fresh;
+    use polymarket_client_sdk::error::{Kind, StaleData, Validation};
 
     use super::*;
 
@@ -595,6 +678,30 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_fail_on_missing_expiration_for_gtd() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .order_type(OrderType::GTD)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(msg, "GTD orders require a valid expiration, got 0");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_post_only_for_non_gtc_gtd() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -686,6 +793,179 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_round_too_granular_of_a_price_when_requested() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.345))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .rounding(RoundingMode::TowardPassive)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(34_000_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn normalize_should_report_what_is_signed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::types::{Shares, Usdc};
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let normalized = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.347))
+            .rounding(RoundingMode::TowardPassive)
+            .size(dec!(21.05))
+            .side(Side::Buy)
+            .normalize()
+            .await?;
+
+        assert_eq!(normalized.requested_price, Some(dec!(0.347)));
+        assert_eq!(normalized.price, dec!(0.34));
+        assert!(normalized.is_price_adjusted());
+        // 21.05 * 0.34 = 7.157, truncated to the tick and lot size precision
+        assert_eq!(normalized.usdc, Usdc::new(dec!(7.157))?);
+        assert_eq!(normalized.shares, Shares::new(dec!(21.05))?);
+        assert_eq!(normalized.maker_amount, U256::from(7_157_000));
+        assert_eq!(normalized.taker_amount, U256::from(21_050_000));
+        assert_eq!(normalized.order.order.makerAmount, normalized.maker_amount);
+
+        let normalized = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(10))
+            .side(Side::Sell)
+            .normalize()
+            .await?;
+
+        assert!(!normalized.is_price_adjusted());
+        assert_eq!(normalized.shares, Shares::new(dec!(10))?);
+        assert_eq!(normalized.usdc, Usdc::new(dec!(5))?);
+        assert_eq!(normalized.maker_amount, U256::from(10_000_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_should_enforce_position_limits() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use alloy::signers::Signer as _;
+        use alloy::signers::local::LocalSigner;
+        use polymarket_client_sdk::POLYGON;
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::risk::{Engine, Limits};
+        use polymarket_client_sdk::clob::{Client, Config};
+        use polymarket_client_sdk::error::{RiskLimit, RiskRejected};
+        use polymarket_client_sdk::types::B256;
+
+        use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let risk = Arc::new(Engine::new(
+            Limits::builder().max_market_position(dec!(50)).build(),
+        ));
+        let config = Config::builder().risk(Arc::clone(&risk)).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        risk.register_token(token_1(), B256::repeat_byte(1), None);
+        risk.set_position(token_1(), dec!(80), dec!(40));
+
+        let order = || {
+            client
+                .limit_order()
+                .token_id(token_1())
+                .price(dec!(0.5))
+                .size(dec!(21))
+        };
+
+        // 40 held and 10.5 to buy
+        let err = order().side(Side::Buy).build().await.unwrap_err();
+        let rejected = err.downcast_ref::<RiskRejected>().unwrap();
+        assert_eq!(rejected.limit, RiskLimit::MarketPosition);
+        assert_eq!(rejected.value, dec!(50.5));
+
+        order().side(Side::Sell).build().await?;
+        risk.set_position(token_1(), dec!(79), dec!(39.5));
+        order().side(Side::Buy).build().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_stale_fresh_price() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .fresh_price(Fresh::new(dec!(0.34)), Duration::from_secs(60))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(34_000_000));
+
+        let received_at = Instant::now().checked_sub(Duration::from_secs(5)).unwrap();
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .fresh_price(Fresh::at(dec!(0.34), received_at), Duration::from_secs(1))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::StaleData);
+        let stale = err.downcast_ref::<StaleData>().unwrap();
+        assert!(stale.age >= Duration::from_secs(5));
+        assert_eq!(stale.max_age, Duration::from_secs(1));
+
+        // Setting a plain price afterwards drops the guard
+        client
+            .limit_order()
+            .token_id(token_1())
+            .fresh_price(Fresh::at(dec!(0.34), received_at), Duration::from_secs(1))
+            .price(dec!(0.34))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_negative_price_and_size() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -757,7 +1037,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(10_520_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -797,7 +1077,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(11_782_400));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -837,7 +1117,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(1_178_240));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -877,7 +1157,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(117_824));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1019,7 +1299,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(10_520_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1059,7 +1339,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(11_782_400));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1099,7 +1379,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(1_178_240));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1139,7 +1419,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(117_824));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1294,7 +1574,7 @@ mod limit {
 
         assert_eq!(signable_order.order.maker, client.address());
         assert_eq!(signable_order.order.taker, Address::ZERO);
-        assert_eq!(signable_order.order.tokenId, token_1());
+        assert_eq!(signable_order.order.tokenId, token_1().as_u256());
         assert_eq!(signable_order.order.makerAmount, U256::from(51_200_000));
         assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000));
         assert_eq!(signable_order.order.expiration, U256::ZERO);
@@ -1314,7 +1594,7 @@ mod limit {
 
         assert_eq!(signable_order.order.maker, client.address());
         assert_eq!(signable_order.order.taker, Address::ZERO);
-        assert_eq!(signable_order.order.tokenId, token_2());
+        assert_eq!(signable_order.order.tokenId, token_2().as_u256());
         assert_eq!(signable_order.order.makerAmount, U256::from(9_999_600));
         assert_eq!(signable_order.order.takerAmount, U256::from(12_820_000));
         assert_eq!(signable_order.order.expiration, U256::ZERO);
@@ -1345,7 +1625,7 @@ mod market {
 
     fn ensure_requirements_for_market_price(
         server: &MockServer,
-        token_id: U256,
+        token_id: TokenId,
         bids: &[OrderSummary],
         asks: &[OrderSummary],
     ) {
@@ -1385,6 +1665,86 @@ mod market {
         });
     }
 
+    #[tokio::test]
+    async fn normalize_should_report_book_price() -> anyhow::Result<()> {
+        use polymarket_client_sdk::types::{Shares, Usdc};
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements_for_market_price(
+            &server,
+            token_1(),
+            &[],
+            &[OrderSummary::builder()
+                .price(dec!(0.3))
+                .size(Decimal::ONE_HUNDRED)
+                .build()],
+        );
+
+        let normalized = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::usdc(dec!(10))?)
+            .side(Side::Buy)
+            .normalize()
+            .await?;
+
+        assert_eq!(normalized.requested_price, None);
+        assert!(!normalized.is_price_adjusted());
+        assert_eq!(normalized.price, dec!(0.3));
+        assert_eq!(normalized.usdc, Usdc::new(dec!(10))?);
+        // 10 / 0.3 shares, truncated to the tick and lot size precision
+        assert_eq!(normalized.shares, Shares::new(dec!(33.333))?);
+        assert_eq!(normalized.taker_amount, U256::from(33_333_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn buy_should_never_sign_above_requested_price() -> anyhow::Result<()> {
+        use alloy::signers::Signer as _;
+        use alloy::signers::local::LocalSigner;
+        use polymarket_client_sdk::POLYGON;
+
+        use crate::common::PRIVATE_KEY;
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let order = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .price(dec!(0.35))
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed = client.sign(&signer, order).await?;
+
+        // Rounded down onto the 0.1 tick, never up to 0.4
+        let maker = Decimal::from(signed.order.makerAmount.to::<u64>());
+        let taker = Decimal::from(signed.order.takerAmount.to::<u64>());
+        assert!(maker / taker <= dec!(0.35), "signed at {}", maker / taker);
+        assert_eq!(signed.order.takerAmount, U256::from(333_333_000));
+
+        let aggressive = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .price(dec!(0.35))
+            .side(Side::Buy)
+            .rounding(RoundingMode::TowardAggressive)
+            .build()
+            .await?;
+        assert_eq!(aggressive.order.takerAmount, U256::from(250_000_000));
+
+        Ok(())
+    }
+
     mod buy {
         use super::*;
 
@@ -1958,7 +2318,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(200_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2007,7 +2367,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(178_571_400));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2056,7 +2416,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(1_785_714_280));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2105,7 +2465,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(
                 signable_order.order.takerAmount,
@@ -2925,7 +3285,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(50_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2974,7 +3334,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(56_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -3023,7 +3383,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(5_600_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -3072,7 +3432,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId, token_1());
+            assert_eq!(signable_order.order.tokenId, token_1().as_u256());
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(560_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -13,6 +13,7 @@ use polymarket_client_sdk::clob::types::{
     CreateRfqQuoteRequest, CreateRfqRequestRequest, RfqQuotesRequest, RfqRequestsRequest, Side,
     SignatureType,
 };
+use polymarket_client_sdk::types::{Shares, Usdc};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
@@ -24,7 +25,7 @@ mod request {
     use std::str::FromStr as _;
 
     use polymarket_client_sdk::clob::types::request::Asset;
-    use polymarket_client_sdk::types::U256;
+    use polymarket_client_sdk::types::{Timestamp, TokenId};
 
     use super::*;
 
@@ -51,17 +52,17 @@ mod request {
         });
 
         let request = CreateRfqRequestRequest::builder()
-            .asset_in(Asset::Asset(U256::from_str("12345")?))
+            .asset_in(Asset::Asset(TokenId::from_str("12345")?))
             .asset_out(Asset::Usdc)
-            .amount_in(dec!(50000000))
-            .amount_out(dec!(3000000))
+            .amount_in(Shares::new(dec!(50))?)
+            .amount_out(Usdc::new(dec!(3))?)
             .user_type(SignatureType::Eoa)
             .build();
 
         let response = client.create_request(&request).await?;
 
         assert_eq!(response.request_id, "0196464a-a1fa-75e6-821e-31aa0794f7ad");
-        assert_eq!(response.expiry, 1_744_936_318);
+        assert_eq!(response.expiry, Timestamp::from_secs(1_744_936_318));
         mock.assert();
 
         Ok(())
@@ -167,7 +168,7 @@ mod quote {
     use std::str::FromStr as _;
 
     use polymarket_client_sdk::clob::types::request::Asset;
-    use polymarket_client_sdk::types::U256;
+    use polymarket_client_sdk::types::TokenId;
 
     use super::*;
 
@@ -196,9 +197,9 @@ mod quote {
         let request = CreateRfqQuoteRequest::builder()
             .request_id("01968f1e-1182-71c4-9d40-172db9be82af")
             .asset_in(Asset::Usdc)
-            .asset_out(Asset::Asset(U256::from_str("12345")?))
-            .amount_in(dec!(3000000))
-            .amount_out(dec!(50000000))
+            .asset_out(Asset::Asset(TokenId::from_str("12345")?))
+            .amount_in(Usdc::new(dec!(3))?)
+            .amount_out(Shares::new(dec!(50))?)
             .user_type(SignatureType::Eoa)
             .build();
 
@@ -300,8 +301,8 @@ mod execution {
         let request = AcceptRfqQuoteRequest::builder()
             .request_id("01968f1e-1182-71c4-9d40-172db9be82af")
             .quote_id("0196f484-9fbd-74c1-bfc1-75ac21c1cf84")
-            .maker_amount(dec!(50000000))
-            .taker_amount(dec!(3000000))
+            .maker_amount(Shares::new(dec!(50))?)
+            .taker_amount(Usdc::new(dec!(3))?)
             .token_id(token_1())
             .maker(maker)
             .signer(maker)
@@ -340,8 +341,8 @@ mod execution {
         let request = ApproveRfqOrderRequest::builder()
             .request_id("01968f1e-1182-71c4-9d40-172db9be82af")
             .quote_id("0196f484-9fbd-74c1-bfc1-75ac21c1cf84")
-            .maker_amount(dec!(50000000))
-            .taker_amount(dec!(3000000))
+            .maker_amount(Shares::new(dec!(50))?)
+            .taker_amount(Usdc::new(dec!(3))?)
             .token_id(token_1())
             .maker(maker)
             .signer(maker)
@@ -373,7 +374,7 @@ mod error_handling {
 
     use polymarket_client_sdk::clob::types::request::Asset;
     use polymarket_client_sdk::error::Kind;
-    use polymarket_client_sdk::types::U256;
+    use polymarket_client_sdk::types::TokenId;
 
     use super::*;
 
@@ -389,10 +390,10 @@ mod error_handling {
         });
 
         let request = CreateRfqRequestRequest::builder()
-            .asset_in(Asset::Asset(U256::from_str("12345")?))
+            .asset_in(Asset::Asset(TokenId::from_str("12345")?))
             .asset_out(Asset::Usdc)
-            .amount_in(dec!(50000000))
-            .amount_out(dec!(3000000))
+            .amount_in(Shares::new(dec!(50))?)
+            .amount_out(Usdc::new(dec!(3))?)
             .user_type(SignatureType::Eoa)
             .build();
 
@@ -430,3 +431,243 @@ mod error_handling {
         Ok(())
     }
 }
+
+#[cfg(feature = "polling")]
+mod routing {
+    use std::str::FromStr as _;
+    use std::time::Duration;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use httpmock::Mock;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::clob::routing::{Constraints, Venue};
+    use polymarket_client_sdk::clob::types::TickSize;
+    use polymarket_client_sdk::types::Decimal;
+
+    use super::*;
+    use crate::common::{PRIVATE_KEY, ensure_requirements, token_1};
+
+    /// Mocks an order book whose asks fill 20 shares at an average of 0.525, and an RFQ request
+    /// that receives a single quote at `quote_price`.
+    fn mock_venues(server: &MockServer, quote_price: Decimal) -> Mock<'_> {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+                "asset_id": token_1(),
+                "timestamp": "1000",
+                "bids": [],
+                "asks": [
+                    { "price": "0.55", "size": "100" },
+                    { "price": "0.5", "size": "10" }
+                ],
+                "min_order_size": "5",
+                "neg_risk": false,
+                "tick_size": "0.01",
+            }));
+        });
+        let create_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/rfq/request")
+                .json_body_includes(
+                    json!({ "amountIn": "20000000", "amountOut": "10500000" }).to_string(),
+                );
+            then.status(StatusCode::OK).json_body(json!({
+                "requestId": "01968f1e-1182-71c4-9d40-172db9be82af",
+                "expiry": 1_744_936_318
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/rfq/data/quotes");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [{
+                    "quoteId": "0196f484-9fbd-74c1-bfc1-75ac21c1cf84",
+                    "requestId": "01968f1e-1182-71c4-9d40-172db9be82af",
+                    "userAddress": "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+                    "proxyAddress": "0x6e0c80c90ea6c15917308f820eac91ce2724b5b5",
+                    "condition": "0x37a6a2dd9f3469495d9ec2467b0a764c5905371a294ce544bc3b2c944eb3e84a",
+                    "token": token_1(),
+                    "complement": "32868290514114487320702931554221558599637733115139769311383916145370132125101",
+                    "side": "SELL",
+                    "sizeIn": 10.5,
+                    "sizeOut": 20,
+                    "price": quote_price
+                }],
+                "next_cursor": "LTE=",
+                "limit": 100,
+                "count": 1
+            }));
+        });
+
+        create_mock
+    }
+
+    fn constraints() -> Constraints {
+        Constraints::builder()
+            .quote_window(Duration::from_millis(10))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn execute_best_should_accept_better_quote() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let create_mock = mock_venues(&server, dec!(0.51));
+
+        let accept_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/rfq/request/accept")
+                .json_body_includes(
+                    json!({
+                        "quoteId": "0196f484-9fbd-74c1-bfc1-75ac21c1cf84",
+                        "makerAmount": "10200000",
+                        "takerAmount": "20000000",
+                        "side": "BUY"
+                    })
+                    .to_string(),
+                );
+            then.status(StatusCode::OK).body("OK");
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let execution = client
+            .execute_best(&signer, token_1(), Side::Buy, dec!(20), constraints())
+            .await?;
+
+        assert_eq!(execution.venue, Venue::Rfq);
+        assert_eq!(execution.price, dec!(0.51));
+        assert_eq!(execution.book_price, Some(dec!(0.525)));
+        create_mock.assert();
+        accept_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_best_should_fall_back_to_order_book() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        mock_venues(&server, dec!(0.53));
+
+        let cancel_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/rfq/request")
+                .json_body(json!({ "requestId": "01968f1e-1182-71c4-9d40-172db9be82af" }));
+            then.status(StatusCode::OK).body("OK");
+        });
+        let post_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .json_body_includes(json!({ "orderType": "FOK" }).to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "11",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "20"
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let execution = client
+            .execute_best(&signer, token_1(), Side::Buy, dec!(20), constraints())
+            .await?;
+
+        assert_eq!(execution.venue, Venue::OrderBook);
+        assert_eq!(execution.price, dec!(0.525));
+        assert_eq!(execution.quote.unwrap().price, dec!(0.53));
+        assert!(execution.response.unwrap().is_success());
+        cancel_mock.assert();
+        post_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_best_should_not_trade_in_dry_run() -> anyhow::Result<()> {
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::{Client, Config};
+
+        use crate::common::{API_KEY, PASSPHRASE, SECRET};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let config = Config::builder().dry_run(true).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        let create_mock = mock_venues(&server, dec!(0.51));
+
+        let post_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST);
+            then.status(StatusCode::OK).body("OK");
+        });
+
+        let execution = client
+            .execute_best(&signer, token_1(), Side::Buy, dec!(20), constraints())
+            .await?;
+
+        assert_eq!(execution.venue, Venue::Rfq);
+        assert_eq!(execution.price, dec!(0.51));
+        create_mock.assert_calls(0);
+        post_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_best_should_apply_risk_limits_to_quotes() -> anyhow::Result<()> {
+        use std::sync::Arc;
+
+        use polymarket_client_sdk::auth::Credentials;
+        use polymarket_client_sdk::clob::risk::{Engine, Limits};
+        use polymarket_client_sdk::clob::{Client, Config};
+        use polymarket_client_sdk::error::{RiskLimit, RiskRejected};
+
+        use crate::common::{API_KEY, PASSPHRASE, SECRET};
+
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let risk = Arc::new(Engine::new(
+            Limits::builder().max_order_notional(dec!(5)).build(),
+        ));
+        let config = Config::builder().risk(Arc::clone(&risk)).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .credentials(Credentials::new(
+                API_KEY,
+                SECRET.to_owned(),
+                PASSPHRASE.to_owned(),
+            ))
+            .authenticate()
+            .await?;
+        mock_venues(&server, dec!(0.51));
+
+        let accept_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/rfq/request/accept");
+            then.status(StatusCode::OK).body("OK");
+        });
+
+        let err = client
+            .execute_best(&signer, token_1(), Side::Buy, dec!(20), constraints())
+            .await
+            .unwrap_err();
+
+        let rejected = err.downcast_ref::<RiskRejected>().unwrap();
+        assert_eq!(rejected.limit, RiskLimit::OrderNotional);
+        accept_mock.assert_calls(0);
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use std::time::Duration;
 
 use futures_util::{SinkExt as _, StreamExt as _};
 use polymarket_client_sdk::clob::ws::{Client, WsMessage};
-use polymarket_client_sdk::types::{Address, U256, b256};
+use polymarket_client_sdk::types::{Address, TokenId, b256};
 use polymarket_client_sdk::ws::config::Config;
 use serde_json::json;
 use tokio::net::TcpListener;
@@ -118,7 +118,7 @@ impl MockWsServer {
 pub mod payloads {
     use std::str::FromStr as _;
 
-    use polymarket_client_sdk::types::{B256, U256, b256};
+    use polymarket_client_sdk::types::{B256, TokenId, b256};
     use serde_json::{Value, json};
 
     pub const ASSET_ID_STR: &str =
@@ -132,13 +132,13 @@ pub mod payloads {
         b256!("bd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af");
 
     #[must_use]
-    pub fn asset_id() -> U256 {
-        U256::from_str(ASSET_ID_STR).unwrap()
+    pub fn asset_id() -> TokenId {
+        TokenId::from_str(ASSET_ID_STR).unwrap()
     }
 
     #[must_use]
-    pub fn other_asset_id() -> U256 {
-        U256::from_str(OTHER_ASSET_ID_STR).unwrap()
+    pub fn other_asset_id() -> TokenId {
+        TokenId::from_str(OTHER_ASSET_ID_STR).unwrap()
     }
 
     #[must_use]
@@ -163,7 +163,7 @@ pub mod payloads {
     }
 
     #[must_use]
-    pub fn price_change_batch(asset_id: U256) -> Value {
+    pub fn price_change_batch(asset_id: TokenId) -> Value {
         json!({
             "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
             "price_changes": [
@@ -313,7 +313,7 @@ mod market_channel {
         let config = Config::default();
         let client = Client::new(&endpoint, config).unwrap();
 
-        let asset_id = U256::from_str(
+        let asset_id = TokenId::from_str(
             "71321045679252212594626385532706912750332728571942532289631379312455583992563",
         )
         .unwrap();
@@ -462,7 +462,8 @@ mod market_channel {
 mod user_channel {
     use polymarket_client_sdk::auth::Credentials;
     use polymarket_client_sdk::clob::types::Side;
-    use polymarket_client_sdk::clob::ws::types::response::{OrderMessageType, TradeMessageStatus};
+    use polymarket_client_sdk::clob::types::TradeStatusType;
+    use polymarket_client_sdk::clob::ws::types::response::OrderMessageType;
     use rust_decimal_macros::dec;
     use tokio::time::sleep;
 
@@ -551,7 +552,7 @@ mod user_channel {
                 assert_eq!(trade.price, dec!(0.57));
                 assert_eq!(trade.size, dec!(10));
                 assert_eq!(trade.side, Side::Buy);
-                assert_eq!(trade.status, TradeMessageStatus::Matched);
+                assert_eq!(trade.status, TradeStatusType::Matched);
                 assert_eq!(trade.outcome, Some("YES".to_owned()));
                 assert_eq!(trade.maker_orders.len(), 1);
                 assert_eq!(trade.maker_orders[0].matched_amount, dec!(10));
@@ -1689,8 +1690,8 @@ mod message_parsing {
             "71321045679252212594626385532706912750332728571942532289631379312455583992563";
         let asset_b_str =
             "88888888888888888888888888888888888888888888888888888888888888888888888888888";
-        let asset_a = U256::from_str(asset_a_str).unwrap();
-        let asset_b = U256::from_str(asset_b_str).unwrap();
+        let asset_a = TokenId::from_str(asset_a_str).unwrap();
+        let asset_b = TokenId::from_str(asset_b_str).unwrap();
 
         let stream = client.subscribe_prices(vec![asset_a, asset_b]).unwrap();
         let mut stream = Box::pin(stream);
@@ -1753,7 +1754,7 @@ mod message_parsing {
     fn parses_last_trade_price() {
         let asset_id_str =
             "114122071509644379678018727908709560226618148003371446110114509806601493071694";
-        let asset_id = U256::from_str(asset_id_str).unwrap();
+        let asset_id = TokenId::from_str(asset_id_str).unwrap();
         let payload = payloads::last_trade_price(asset_id_str).to_string();
         let ltp: LastTradePrice = serde_json::from_str(&payload).unwrap();
 